@@ -0,0 +1,9 @@
+mod api_token;
+mod api_token_create_params;
+mod api_token_create_result;
+mod api_token_scope;
+
+pub use self::{
+    api_token::ApiToken, api_token_create_params::ApiTokenCreateParams,
+    api_token_create_result::ApiTokenCreateResult, api_token_scope::ApiTokenScope,
+};
@@ -0,0 +1,109 @@
+use crate::api_tokens::ApiTokenScope;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Limited API token that third parties (e.g., partners pushing data) can use to create data
+/// revisions or trigger runs only for the trackers with specific tags. The token value itself is
+/// never stored and is returned only once, when the token is created.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiToken {
+    /// Unique API token id (UUIDv7).
+    pub id: Uuid,
+    /// Unique name of the API token (e.g., name of the partner it's issued for).
+    pub name: String,
+    /// Tags the tracker must have (all of them) to be accessible with the token.
+    pub tags: Vec<String>,
+    /// Operations the token allows to perform on the accessible trackers.
+    pub scopes: Vec<ApiTokenScope>,
+    /// Date and time when the API token was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+impl ApiToken {
+    /// Checks if the token allows to perform the specified operation.
+    pub fn has_scope(&self, scope: ApiTokenScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    /// Checks if the tracker with the specified tags is accessible with the token.
+    pub fn allows_tags(&self, tags: &[String]) -> bool {
+        self.tags.iter().all(|tag| tags.contains(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_tokens::{ApiToken, ApiTokenScope};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    fn mock_api_token() -> anyhow::Result<ApiToken> {
+        Ok(ApiToken {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "partner".to_string(),
+            tags: vec!["partner:acme".to_string(), "prod".to_string()],
+            scopes: vec![ApiTokenScope::CreateRevisions],
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(mock_api_token()?, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "partner",
+          "tags": [
+            "partner:acme",
+            "prod"
+          ],
+          "scopes": [
+            "createRevisions"
+          ],
+          "createdAt": 946720800
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ApiToken>(json!({
+                "id": "00000000-0000-0000-0000-000000000001",
+                "name": "partner",
+                "tags": ["partner:acme", "prod"],
+                "scopes": ["createRevisions"],
+                "createdAt": 946720800
+            }))?,
+            mock_api_token()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_scopes_and_tags() -> anyhow::Result<()> {
+        let api_token = mock_api_token()?;
+        assert!(api_token.has_scope(ApiTokenScope::CreateRevisions));
+        assert!(!api_token.has_scope(ApiTokenScope::RunTrackers));
+
+        assert!(api_token.allows_tags(&[
+            "prod".to_string(),
+            "partner:acme".to_string(),
+            "other".to_string()
+        ]));
+        assert!(!api_token.allows_tags(&["partner:acme".to_string()]));
+        assert!(!api_token.allows_tags(&[]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::api_tokens::ApiTokenScope;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Parameters for creating an API token.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTokenCreateParams {
+    /// Unique name of the API token.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    /// Tags the tracker must have (all of them) to be accessible with the token.
+    #[schema(min_items = 1, max_items = 20)]
+    pub tags: Vec<String>,
+    /// Operations the token allows to perform on the accessible trackers.
+    #[schema(min_items = 1)]
+    pub scopes: Vec<ApiTokenScope>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_tokens::{ApiTokenCreateParams, ApiTokenScope};
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ApiTokenCreateParams>(json!({
+                "name": "partner",
+                "tags": ["partner:acme"],
+                "scopes": ["createRevisions", "runTrackers"]
+            }))?,
+            ApiTokenCreateParams {
+                name: "partner".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::CreateRevisions, ApiTokenScope::RunTrackers],
+            }
+        );
+
+        Ok(())
+    }
+}
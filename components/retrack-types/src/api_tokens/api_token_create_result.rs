@@ -0,0 +1,54 @@
+use crate::api_tokens::ApiToken;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Newly created API token along with its value. The value isn't stored and cannot be retrieved
+/// later, it should be passed to the token holder right away.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTokenCreateResult {
+    /// Created API token.
+    pub token: ApiToken,
+    /// Value of the token that should be sent in the `Authorization: Bearer ...` header.
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_tokens::{ApiToken, ApiTokenCreateResult, ApiTokenScope};
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ApiTokenCreateResult {
+            token: ApiToken {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "partner".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::RunTrackers],
+                // January 1, 2000 10:00:00
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            },
+            value: "rtk_c29tZS12YWx1ZQ".to_string(),
+        }, @r###"
+        {
+          "token": {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "name": "partner",
+            "tags": [
+              "partner:acme"
+            ],
+            "scopes": [
+              "runTrackers"
+            ],
+            "createdAt": 946720800
+          },
+          "value": "rtk_c29tZS12YWx1ZQ"
+        }
+        "###);
+
+        Ok(())
+    }
+}
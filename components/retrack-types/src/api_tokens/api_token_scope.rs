@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Operation that the API token allows to perform on the trackers it grants access to.
+#[derive(
+    Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiTokenScope {
+    /// Allows creating data revisions for the individual trackers.
+    CreateRevisions,
+    /// Allows triggering runs for all trackers with the specific tags.
+    RunTrackers,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_tokens::ApiTokenScope;
+    use serde_json::json;
+
+    #[test]
+    fn serialization_and_deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::to_value(ApiTokenScope::CreateRevisions)?,
+            json!("createRevisions")
+        );
+        assert_eq!(
+            serde_json::to_value(ApiTokenScope::RunTrackers)?,
+            json!("runTrackers")
+        );
+
+        assert_eq!(
+            serde_json::from_value::<ApiTokenScope>(json!("createRevisions"))?,
+            ApiTokenScope::CreateRevisions
+        );
+        assert_eq!(
+            serde_json::from_value::<ApiTokenScope>(json!("runTrackers"))?,
+            ApiTokenScope::RunTrackers
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+mod auth_profile;
+mod auth_profile_create_params;
+mod auth_profile_kind;
+mod auth_profile_update_params;
+mod redacted_auth_profile;
+mod redacted_auth_profile_kind;
+
+pub use self::{
+    auth_profile::AuthProfile, auth_profile_create_params::AuthProfileCreateParams,
+    auth_profile_kind::AuthProfileKind, auth_profile_update_params::AuthProfileUpdateParams,
+    redacted_auth_profile::RedactedAuthProfile,
+    redacted_auth_profile_kind::RedactedAuthProfileKind,
+};
@@ -0,0 +1,85 @@
+use crate::auth_profiles::AuthProfileKind;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Named set of credentials that API target requests can reference to authenticate, instead of
+/// embedding tokens into the request headers.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthProfile {
+    /// Unique auth profile id (UUIDv7).
+    pub id: Uuid,
+    /// Unique name of the auth profile that target requests use to reference it.
+    pub name: String,
+    /// Authentication scheme of the profile along with its credentials.
+    pub kind: AuthProfileKind,
+    /// Date and time when the auth profile was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Date and time when the auth profile was last updated.
+    #[serde(with = "time::serde::timestamp")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth_profiles::{AuthProfile, AuthProfileKind};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(AuthProfile {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "github".to_string(),
+            kind: AuthProfileKind::Bearer {
+                token: "some-token".to_string(),
+            },
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            // January 1, 2000 10:00:10
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "github",
+          "kind": {
+            "type": "bearer",
+            "token": "some-token"
+          },
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<AuthProfile>(json!({
+                "id": "00000000-0000-0000-0000-000000000001",
+                "name": "github",
+                "kind": { "type": "bearer", "token": "some-token" },
+                "createdAt": 946720800,
+                "updatedAt": 946720810
+            }))?,
+            AuthProfile {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "github".to_string(),
+                kind: AuthProfileKind::Bearer {
+                    token: "some-token".to_string(),
+                },
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::auth_profiles::AuthProfileKind;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Parameters for creating an auth profile.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthProfileCreateParams {
+    /// Unique name of the auth profile.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    /// Authentication scheme of the profile along with its credentials.
+    pub kind: AuthProfileKind,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth_profiles::{AuthProfileCreateParams, AuthProfileKind};
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<AuthProfileCreateParams>(json!({
+                "name": "github",
+                "kind": { "type": "bearer", "token": "some-token" }
+            }))?,
+            AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: AuthProfileKind::Bearer {
+                    token: "some-token".to_string()
+                },
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Authentication scheme of the auth profile along with its credentials.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum AuthProfileKind {
+    /// HTTP Basic authentication (`Authorization: Basic ...`).
+    Basic {
+        /// Name of the user.
+        username: String,
+        /// Optional password of the user.
+        password: Option<String>,
+    },
+    /// Bearer token authentication (`Authorization: Bearer ...`).
+    Bearer {
+        /// Token to send with the requests.
+        token: String,
+    },
+    /// API key sent in the custom HTTP header.
+    ApiKey {
+        /// Name of the HTTP header to send the API key in (e.g., `X-API-Key`).
+        header: String,
+        /// API key to send with the requests.
+        value: String,
+    },
+    /// OAuth 2.0 Client Credentials grant. The access token is requested from the token endpoint
+    /// and cached until it expires, and is then sent as a bearer token.
+    #[serde(rename = "oauth2ClientCredentials", rename_all = "camelCase")]
+    OAuth2ClientCredentials {
+        /// URL of the token endpoint of the authorization server.
+        token_url: Url,
+        /// Client identifier.
+        client_id: String,
+        /// Client secret.
+        client_secret: String,
+        /// Optional space-delimited list of scopes to request.
+        scope: Option<String>,
+    },
+    /// OAuth 2.0 Refresh Token grant. The access token is obtained from the token endpoint in
+    /// exchange for the refresh token and cached until it expires, and is then sent as a bearer
+    /// token. If the authorization server rotates the refresh token, the new one is stored in the
+    /// auth profile.
+    #[serde(rename = "oauth2RefreshToken", rename_all = "camelCase")]
+    OAuth2RefreshToken {
+        /// URL of the token endpoint of the authorization server.
+        token_url: Url,
+        /// Client identifier.
+        client_id: String,
+        /// Optional client secret, public clients don't have one.
+        client_secret: Option<String>,
+        /// Refresh token to exchange for the access token.
+        refresh_token: String,
+        /// Optional space-delimited list of scopes to request.
+        scope: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth_profiles::AuthProfileKind;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(vec![
+            AuthProfileKind::Basic { username: "user".to_string(), password: Some("pass".to_string()) },
+            AuthProfileKind::Basic { username: "user".to_string(), password: None },
+            AuthProfileKind::Bearer { token: "some-token".to_string() },
+            AuthProfileKind::ApiKey { header: "X-API-Key".to_string(), value: "some-key".to_string() },
+            AuthProfileKind::OAuth2ClientCredentials {
+                token_url: "https://auth.retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: "some-secret".to_string(),
+                scope: Some("read write".to_string()),
+            },
+            AuthProfileKind::OAuth2RefreshToken {
+                token_url: "https://auth.retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: None,
+                refresh_token: "some-refresh-token".to_string(),
+                scope: None,
+            },
+        ], @r###"
+        [
+          {
+            "type": "basic",
+            "username": "user",
+            "password": "pass"
+          },
+          {
+            "type": "basic",
+            "username": "user"
+          },
+          {
+            "type": "bearer",
+            "token": "some-token"
+          },
+          {
+            "type": "apiKey",
+            "header": "X-API-Key",
+            "value": "some-key"
+          },
+          {
+            "type": "oauth2ClientCredentials",
+            "tokenUrl": "https://auth.retrack.dev/token",
+            "clientId": "some-client",
+            "clientSecret": "some-secret",
+            "scope": "read write"
+          },
+          {
+            "type": "oauth2RefreshToken",
+            "tokenUrl": "https://auth.retrack.dev/token",
+            "clientId": "some-client",
+            "refreshToken": "some-refresh-token"
+          }
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<Vec<AuthProfileKind>>(json!([
+                { "type": "basic", "username": "user", "password": "pass" },
+                { "type": "basic", "username": "user" },
+                { "type": "bearer", "token": "some-token" },
+                { "type": "apiKey", "header": "X-API-Key", "value": "some-key" },
+                {
+                    "type": "oauth2ClientCredentials",
+                    "tokenUrl": "https://auth.retrack.dev/token",
+                    "clientId": "some-client",
+                    "clientSecret": "some-secret"
+                },
+                {
+                    "type": "oauth2RefreshToken",
+                    "tokenUrl": "https://auth.retrack.dev/token",
+                    "clientId": "some-client",
+                    "clientSecret": "some-secret",
+                    "refreshToken": "some-refresh-token",
+                    "scope": "read"
+                }
+            ]))?,
+            vec![
+                AuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    password: Some("pass".to_string())
+                },
+                AuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    password: None
+                },
+                AuthProfileKind::Bearer {
+                    token: "some-token".to_string()
+                },
+                AuthProfileKind::ApiKey {
+                    header: "X-API-Key".to_string(),
+                    value: "some-key".to_string()
+                },
+                AuthProfileKind::OAuth2ClientCredentials {
+                    token_url: "https://auth.retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    client_secret: "some-secret".to_string(),
+                    scope: None,
+                },
+                AuthProfileKind::OAuth2RefreshToken {
+                    token_url: "https://auth.retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    client_secret: Some("some-secret".to_string()),
+                    refresh_token: "some-refresh-token".to_string(),
+                    scope: Some("read".to_string()),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::auth_profiles::AuthProfileKind;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Parameters for updating an auth profile.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct AuthProfileUpdateParams {
+    /// Unique name of the auth profile. Target requests that reference the profile by its previous
+    /// name should be updated as well.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: Option<String>,
+    /// Authentication scheme of the profile along with its credentials.
+    pub kind: Option<AuthProfileKind>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth_profiles::{AuthProfileKind, AuthProfileUpdateParams};
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<AuthProfileUpdateParams>(json!({ "name": "github" }))?,
+            AuthProfileUpdateParams {
+                name: Some("github".to_string()),
+                kind: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<AuthProfileUpdateParams>(json!({
+                "kind": { "type": "apiKey", "header": "X-API-Key", "value": "some-key" }
+            }))?,
+            AuthProfileUpdateParams {
+                name: None,
+                kind: Some(AuthProfileKind::ApiKey {
+                    header: "X-API-Key".to_string(),
+                    value: "some-key".to_string()
+                }),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::auth_profiles::{AuthProfile, RedactedAuthProfileKind};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Auth profile without its secrets, as returned by the API.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactedAuthProfile {
+    /// Unique auth profile id (UUIDv7).
+    pub id: Uuid,
+    /// Unique name of the auth profile that target requests use to reference it.
+    pub name: String,
+    /// Authentication scheme of the profile without its credentials.
+    pub kind: RedactedAuthProfileKind,
+    /// Date and time when the auth profile was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Date and time when the auth profile was last updated.
+    #[serde(with = "time::serde::timestamp")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<AuthProfile> for RedactedAuthProfile {
+    fn from(auth_profile: AuthProfile) -> Self {
+        Self {
+            id: auth_profile.id,
+            name: auth_profile.name,
+            kind: auth_profile.kind.into(),
+            created_at: auth_profile.created_at,
+            updated_at: auth_profile.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth_profiles::{AuthProfile, AuthProfileKind, RedactedAuthProfile};
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(RedactedAuthProfile::from(AuthProfile {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "github".to_string(),
+            kind: AuthProfileKind::ApiKey {
+                header: "X-API-Key".to_string(),
+                value: "some-key".to_string(),
+            },
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            // January 1, 2000 10:00:10
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        }), @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "github",
+          "kind": {
+            "type": "apiKey",
+            "header": "X-API-Key"
+          },
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use crate::auth_profiles::AuthProfileKind;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Authentication scheme of the auth profile without its secrets. Secrets are only accepted when
+/// the auth profile is created or updated, and never returned back.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum RedactedAuthProfileKind {
+    /// HTTP Basic authentication (`Authorization: Basic ...`).
+    #[serde(rename_all = "camelCase")]
+    Basic {
+        /// Name of the user.
+        username: String,
+        /// Indicates whether the password is set.
+        has_password: bool,
+    },
+    /// Bearer token authentication (`Authorization: Bearer ...`).
+    Bearer,
+    /// API key sent in the custom HTTP header.
+    ApiKey {
+        /// Name of the HTTP header to send the API key in (e.g., `X-API-Key`).
+        header: String,
+    },
+    /// OAuth 2.0 Client Credentials grant.
+    #[serde(rename = "oauth2ClientCredentials", rename_all = "camelCase")]
+    OAuth2ClientCredentials {
+        /// URL of the token endpoint of the authorization server.
+        token_url: Url,
+        /// Client identifier.
+        client_id: String,
+        /// Optional space-delimited list of scopes to request.
+        scope: Option<String>,
+    },
+    /// OAuth 2.0 Refresh Token grant.
+    #[serde(rename = "oauth2RefreshToken", rename_all = "camelCase")]
+    OAuth2RefreshToken {
+        /// URL of the token endpoint of the authorization server.
+        token_url: Url,
+        /// Client identifier.
+        client_id: String,
+        /// Indicates whether the client secret is set.
+        has_client_secret: bool,
+        /// Optional space-delimited list of scopes to request.
+        scope: Option<String>,
+    },
+}
+
+impl From<AuthProfileKind> for RedactedAuthProfileKind {
+    fn from(kind: AuthProfileKind) -> Self {
+        match kind {
+            AuthProfileKind::Basic { username, password } => Self::Basic {
+                username,
+                has_password: password.is_some(),
+            },
+            AuthProfileKind::Bearer { .. } => Self::Bearer,
+            AuthProfileKind::ApiKey { header, .. } => Self::ApiKey { header },
+            AuthProfileKind::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                scope,
+                ..
+            } => Self::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                scope,
+            },
+            AuthProfileKind::OAuth2RefreshToken {
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                ..
+            } => Self::OAuth2RefreshToken {
+                token_url,
+                client_id,
+                has_client_secret: client_secret.is_some(),
+                scope,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth_profiles::{AuthProfileKind, RedactedAuthProfileKind};
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(vec![
+            RedactedAuthProfileKind::Basic { username: "user".to_string(), has_password: true },
+            RedactedAuthProfileKind::Bearer,
+            RedactedAuthProfileKind::ApiKey { header: "X-API-Key".to_string() },
+            RedactedAuthProfileKind::OAuth2ClientCredentials {
+                token_url: "https://auth.retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                scope: Some("read write".to_string()),
+            },
+            RedactedAuthProfileKind::OAuth2RefreshToken {
+                token_url: "https://auth.retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                has_client_secret: false,
+                scope: None,
+            },
+        ], @r###"
+        [
+          {
+            "type": "basic",
+            "username": "user",
+            "hasPassword": true
+          },
+          {
+            "type": "bearer"
+          },
+          {
+            "type": "apiKey",
+            "header": "X-API-Key"
+          },
+          {
+            "type": "oauth2ClientCredentials",
+            "tokenUrl": "https://auth.retrack.dev/token",
+            "clientId": "some-client",
+            "scope": "read write"
+          },
+          {
+            "type": "oauth2RefreshToken",
+            "tokenUrl": "https://auth.retrack.dev/token",
+            "clientId": "some-client",
+            "hasClientSecret": false
+          }
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn redacts_secrets() -> anyhow::Result<()> {
+        assert_eq!(
+            vec![
+                AuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    password: Some("pass".to_string())
+                },
+                AuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    password: None
+                },
+                AuthProfileKind::Bearer {
+                    token: "some-token".to_string()
+                },
+                AuthProfileKind::ApiKey {
+                    header: "X-API-Key".to_string(),
+                    value: "some-key".to_string()
+                },
+                AuthProfileKind::OAuth2ClientCredentials {
+                    token_url: "https://auth.retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    client_secret: "some-secret".to_string(),
+                    scope: Some("read".to_string()),
+                },
+                AuthProfileKind::OAuth2RefreshToken {
+                    token_url: "https://auth.retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    client_secret: Some("some-secret".to_string()),
+                    refresh_token: "some-refresh-token".to_string(),
+                    scope: None,
+                },
+            ]
+            .into_iter()
+            .map(RedactedAuthProfileKind::from)
+            .collect::<Vec<_>>(),
+            vec![
+                RedactedAuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    has_password: true
+                },
+                RedactedAuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    has_password: false
+                },
+                RedactedAuthProfileKind::Bearer,
+                RedactedAuthProfileKind::ApiKey {
+                    header: "X-API-Key".to_string()
+                },
+                RedactedAuthProfileKind::OAuth2ClientCredentials {
+                    token_url: "https://auth.retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    scope: Some("read".to_string()),
+                },
+                RedactedAuthProfileKind::OAuth2RefreshToken {
+                    token_url: "https://auth.retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    has_client_secret: true,
+                    scope: None,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,18 @@
+pub mod api_tokens;
+pub mod auth_profiles;
+pub mod operations;
 pub mod scheduler;
+pub mod search;
+pub mod templates;
 pub mod trackers;
+pub mod views;
+
+mod serde_ext;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 
 #[cfg(test)]
 mod tests {
-    pub use crate::trackers::tests::*;
+    pub use crate::test_utils::*;
 }
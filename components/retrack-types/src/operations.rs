@@ -0,0 +1,7 @@
+mod operation;
+mod operation_error;
+mod operation_kind;
+
+pub use self::{
+    operation::Operation, operation_error::OperationError, operation_kind::OperationKind,
+};
@@ -0,0 +1,97 @@
+use crate::operations::{OperationError, OperationKind};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Represents a long-running operation (e.g., bulk run of trackers) that is executed in the
+/// background, and its progress.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// Unique operation id (UUIDv7).
+    pub id: Uuid,
+    /// Kind of the operation.
+    pub kind: OperationKind,
+    /// Total number of items the operation should process.
+    pub total: usize,
+    /// Number of items the operation has already processed, including the failed ones.
+    pub completed: usize,
+    /// List of errors for the items that couldn't be processed.
+    pub errors: Vec<OperationError>,
+    /// Date and time when the operation was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Date and time when the operation progress was last updated.
+    #[serde(with = "time::serde::timestamp")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl Operation {
+    /// Indicates whether operation has processed all items.
+    pub fn is_finished(&self) -> bool {
+        self.completed >= self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operations::{Operation, OperationError, OperationKind};
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(Operation {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            kind: OperationKind::TrackersRun,
+            total: 3,
+            completed: 2,
+            errors: vec![OperationError {
+                item_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                message: "Failed to fetch data.".to_string(),
+            }],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "kind": "trackersRun",
+          "total": 3,
+          "completed": 2,
+          "errors": [
+            {
+              "itemId": "00000000-0000-0000-0000-000000000002",
+              "message": "Failed to fetch data."
+            }
+          ],
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_finished() -> anyhow::Result<()> {
+        let operation = Operation {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            kind: OperationKind::TrackersRun,
+            total: 2,
+            completed: 1,
+            errors: vec![],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+        assert!(!operation.is_finished());
+        assert!(Operation {
+            completed: 2,
+            ..operation
+        }
+        .is_finished());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Describes a failure to process a single item of the operation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationError {
+    /// ID of the item that couldn't be processed (e.g., tracker ID).
+    pub item_id: Uuid,
+    /// Human-readable error message.
+    pub message: String,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Kind of the long-running operation.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum OperationKind {
+    /// Manually triggered run of multiple trackers.
+    TrackersRun,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operations::OperationKind;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(OperationKind::TrackersRun, @r###""trackersRun""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<OperationKind>(r#""trackersRun""#)?,
+            OperationKind::TrackersRun
+        );
+
+        Ok(())
+    }
+}
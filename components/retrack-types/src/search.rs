@@ -0,0 +1,4 @@
+mod search_match;
+mod search_params;
+
+pub use self::{search_match::SearchMatch, search_params::SearchParams};
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Tracker data revision that matches the full-text search query.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchMatch {
+    /// ID of the tracker the matching revision belongs to.
+    pub tracker_id: Uuid,
+    /// Name of the tracker the matching revision belongs to.
+    pub tracker_name: String,
+    /// ID of the matching tracker data revision.
+    pub revision_id: Uuid,
+    /// Timestamp indicating when data of the matching revision was fetched.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Fragment of the revision data with the matching words highlighted with `<b>` tags.
+    pub snippet: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::SearchMatch;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(SearchMatch {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_name: "tracker".to_string(),
+            revision_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            snippet: "Fixes <b>CVE-2024-1234</b> in parser".to_string(),
+        }, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "trackerName": "tracker",
+          "revisionId": "00000000-0000-0000-0000-000000000002",
+          "createdAt": 946720800,
+          "snippet": "Fixes <b>CVE-2024-1234</b> in parser"
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+use utoipa::IntoParams;
+
+/// Parameters for the full-text search across the data revisions of all trackers. Matching
+/// revisions are returned from the newest to the oldest.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchParams {
+    /// Search query (e.g., `CVE-2024-1234`, `"release notes" -beta` or `nginx or apache`).
+    /// Words are matched as a whole and case-insensitively, quoted phrases are matched as is,
+    /// `or` separates alternatives, and `-` excludes words.
+    #[param(min_length = 1, max_length = 200)]
+    pub query: String,
+    /// List of tags to filter trackers by.
+    #[param(max_items = 10, min_length = 1, max_length = 50)]
+    #[serde(default, rename = "tag")]
+    pub tags: Vec<String>,
+    /// Only search revisions created at or after the specified time (Unix timestamp in seconds).
+    #[param(value_type = Option<i64>)]
+    #[serde(default, with = "time::serde::timestamp::option")]
+    pub since: Option<OffsetDateTime>,
+    /// Only search revisions created before the specified time (Unix timestamp in seconds).
+    #[param(value_type = Option<i64>)]
+    #[serde(default, with = "time::serde::timestamp::option")]
+    pub until: Option<OffsetDateTime>,
+    /// Maximum number of matching revisions to return, by default 100.
+    #[param(minimum = 1, maximum = 1000)]
+    pub limit: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::SearchParams;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<SearchParams>(r#"{ "query": "CVE-2024-1234" }"#)?,
+            SearchParams {
+                query: "CVE-2024-1234".to_string(),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<SearchParams>(
+                r#"
+{
+    "query": "CVE-2024-1234",
+    "tag": ["tag_one", "tag_two"],
+    "since": 946720800,
+    "until": 946720900,
+    "limit": 10
+}
+          "#
+            )?,
+            SearchParams {
+                query: "CVE-2024-1234".to_string(),
+                tags: vec!["tag_one".to_string(), "tag_two".to_string()],
+                since: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                until: Some(OffsetDateTime::from_unix_timestamp(946720900)?),
+                limit: Some(10),
+            }
+        );
+
+        assert!(serde_json::from_str::<SearchParams>(r#"{}"#).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+/// Serializes optional `HeaderMap` with the header names sorted alphabetically, so that the
+/// serialized tracker definitions don't depend on the order the headers were inserted in.
+/// Values of the same header keep their original order.
+///
+/// `#[serde(with = "crate::serde_ext::sorted_header_map", default)]`
+pub(crate) mod sorted_header_map {
+    use http::HeaderMap;
+    use serde::Serializer;
+
+    pub use http_serde::option::header_map::deserialize;
+
+    /// Implementation detail. Use derive annotations instead.
+    pub fn serialize<S: Serializer>(
+        headers: &Option<HeaderMap>,
+        ser: S,
+    ) -> Result<S::Ok, S::Error> {
+        let sorted_headers = headers.as_ref().map(|headers| {
+            let mut names = headers.keys().collect::<Vec<_>>();
+            names.sort_by(|name_a, name_b| name_a.as_str().cmp(name_b.as_str()));
+
+            let mut sorted_headers = HeaderMap::with_capacity(headers.len());
+            for name in names {
+                for value in headers.get_all(name) {
+                    sorted_headers.append(name.clone(), value.clone());
+                }
+            }
+            sorted_headers
+        });
+
+        http_serde::option::header_map::serialize(&sorted_headers, ser)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, HeaderValue};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Headers {
+        #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+        headers: Option<HeaderMap>,
+    }
+
+    #[test]
+    fn serializes_headers_in_alphabetical_order() -> anyhow::Result<()> {
+        let mut headers = HeaderMap::new();
+        headers.append("x-b", HeaderValue::from_static("b"));
+        headers.append("x-a", HeaderValue::from_static("a-2"));
+        headers.append("x-c", HeaderValue::from_static("c"));
+        headers.append("x-a", HeaderValue::from_static("a-1"));
+
+        let headers = Headers {
+            headers: Some(headers),
+        };
+        assert_eq!(
+            serde_json::to_string(&headers)?,
+            r#"{"headers":{"x-a":["a-2","a-1"],"x-b":"b","x-c":"c"}}"#
+        );
+        assert_eq!(
+            serde_json::from_value::<Headers>(
+                json!({ "headers": { "x-b": "b", "x-a": ["a-2", "a-1"], "x-c": "c" } })
+            )?
+            .headers
+            .map(|headers| headers.len()),
+            Some(4)
+        );
+
+        let headers = Headers { headers: None };
+        assert_eq!(serde_json::to_string(&headers)?, r#"{"headers":null}"#);
+        assert_eq!(
+            serde_json::from_value::<Headers>(json!({}))?,
+            Headers { headers: None }
+        );
+
+        Ok(())
+    }
+}
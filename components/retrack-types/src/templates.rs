@@ -0,0 +1,8 @@
+mod template;
+mod template_create_params;
+mod template_update_params;
+
+pub use self::{
+    template::Template, template_create_params::TemplateCreateParams,
+    template_update_params::TemplateUpdateParams,
+};
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Named Handlebars email template that tracker email actions can use instead of the built-in
+/// one. Templates are rendered with the tracker (`tracker.id` and `tracker.name`), the latest
+/// tracker data (`value`), the unified diff with the previous data (`diff`), and the list of diff
+/// lines (`diffLines`, each with `kind` - `added`, `removed`, `unchanged` or `header`, and `line`).
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    /// Unique template id (UUIDv7).
+    pub id: Uuid,
+    /// Unique name of the template.
+    pub name: String,
+    /// Template of the email subject.
+    pub subject: String,
+    /// Template of the plain text email body.
+    pub text: String,
+    /// Optional template of the HTML email body. Unlike subject and text, values rendered into
+    /// HTML are escaped.
+    pub html: Option<String>,
+    /// Date and time when the template was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Date and time when the template was last updated.
+    #[serde(with = "time::serde::timestamp")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::templates::Template;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let template = Template {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "changes".to_string(),
+            subject: "{{tracker.name}} changed".to_string(),
+            text: "{{diff}}".to_string(),
+            html: None,
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            // January 1, 2000 10:00:10
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+        assert_json_snapshot!(template, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "changes",
+          "subject": "{{tracker.name}} changed",
+          "text": "{{diff}}",
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        assert_json_snapshot!(Template {
+            html: Some("<pre>{{diff}}</pre>".to_string()),
+            ..template
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "changes",
+          "subject": "{{tracker.name}} changed",
+          "text": "{{diff}}",
+          "html": "<pre>{{diff}}</pre>",
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<Template>(json!({
+                "id": "00000000-0000-0000-0000-000000000001",
+                "name": "changes",
+                "subject": "{{tracker.name}} changed",
+                "text": "{{diff}}",
+                "html": "<pre>{{diff}}</pre>",
+                "createdAt": 946720800,
+                "updatedAt": 946720810
+            }))?,
+            Template {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: Some("<pre>{{diff}}</pre>".to_string()),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            }
+        );
+
+        Ok(())
+    }
+}
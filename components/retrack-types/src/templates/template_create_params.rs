@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Parameters for creating an email template.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCreateParams {
+    /// Unique name of the template.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    /// Template of the email subject.
+    #[schema(min_length = 1, max_length = 1000)]
+    pub subject: String,
+    /// Template of the plain text email body.
+    #[schema(min_length = 1, max_length = 65536)]
+    pub text: String,
+    /// Optional template of the HTML email body.
+    #[schema(max_length = 65536)]
+    pub html: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::templates::TemplateCreateParams;
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TemplateCreateParams>(json!({
+                "name": "changes",
+                "subject": "{{tracker.name}} changed",
+                "text": "{{diff}}"
+            }))?,
+            TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TemplateCreateParams>(json!({
+                "name": "changes",
+                "subject": "{{tracker.name}} changed",
+                "text": "{{diff}}",
+                "html": "<pre>{{diff}}</pre>"
+            }))?,
+            TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: Some("<pre>{{diff}}</pre>".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+}
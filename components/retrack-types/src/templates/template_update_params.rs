@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Parameters for updating an email template.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TemplateUpdateParams {
+    /// Unique name of the template.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: Option<String>,
+    /// Template of the email subject.
+    #[schema(min_length = 1, max_length = 1000)]
+    pub subject: Option<String>,
+    /// Template of the plain text email body.
+    #[schema(min_length = 1, max_length = 65536)]
+    pub text: Option<String>,
+    /// Template of the HTML email body, empty template removes the HTML body.
+    #[schema(max_length = 65536)]
+    pub html: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::templates::TemplateUpdateParams;
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TemplateUpdateParams>(json!({ "name": "changes" }))?,
+            TemplateUpdateParams {
+                name: Some("changes".to_string()),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TemplateUpdateParams>(json!({
+                "subject": "{{tracker.name}} changed",
+                "text": "{{diff}}",
+                "html": ""
+            }))?,
+            TemplateUpdateParams {
+                name: None,
+                subject: Some("{{tracker.name}} changed".to_string()),
+                text: Some("{{diff}}".to_string()),
+                html: Some("".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+}
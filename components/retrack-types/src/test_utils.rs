@@ -0,0 +1,14 @@
+//! Test doubles for the Retrack API shapes: builders of the sample trackers and tracker creation
+//! parameters, and helpers to match requests Retrack sends to its components. Available with the
+//! `test-utils` feature, so that integrators can write tests against Retrack without copying its
+//! internal test code.
+
+mod mock_tracker_builder;
+mod tracker_create_params_builder;
+mod web_scraper_content_request;
+
+pub use self::{
+    mock_tracker_builder::MockTrackerBuilder,
+    tracker_create_params_builder::TrackerCreateParamsBuilder,
+    web_scraper_content_request::mock_web_scraper_content_request,
+};
@@ -0,0 +1,126 @@
+use crate::{
+    scheduler::SchedulerJobConfig,
+    trackers::{PageTarget, Tracker, TrackerAction, TrackerConfig, TrackerTarget},
+};
+use std::time::Duration;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Builder of the sample trackers. By default, the tracker has a web page target and logs data
+/// changes to the server log.
+pub struct MockTrackerBuilder {
+    tracker: Tracker,
+}
+
+impl MockTrackerBuilder {
+    /// Creates a builder of the enabled tracker with the specified ID, name, and number of
+    /// revisions to keep.
+    pub fn create<N: Into<String>>(id: Uuid, name: N, revisions: usize) -> anyhow::Result<Self> {
+        Ok(Self {
+            tracker: Tracker {
+                id,
+                name: name.into(),
+                enabled: true,
+                archived: false,
+                job_id: None,
+                target: TrackerTarget::Page(PageTarget {
+                    extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
+                    params: None,
+                    user_agent: Some("Retrack/1.0.0".to_string()),
+                    ignore_https_errors: false,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
+                }),
+                config: TrackerConfig {
+                    revisions,
+                    timeout: Some(Duration::from_millis(2000)),
+                    job: None,
+                    fail_on_shape_deviation: false,
+                    change_filter: None,
+                    semantic_filter: None,
+                    skip_first_revision_actions: false,
+                    confirmations: None,
+                    active_hours: None,
+                    active_days: None,
+                    max_content_size: None,
+                    action_concurrency: None,
+                    action_dependencies: None,
+                    action_rules: None,
+                    severity_routing: None,
+                    action_throttles: None,
+                    action_digests: None,
+                    workflow: None,
+                    escalation: None,
+                    quarantine: None,
+                    runbook_url: None,
+                    max_revision_age: None,
+                },
+                tags: vec![],
+                actions: vec![TrackerAction::ServerLog],
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+                shape_deviation: None,
+                state: None,
+                stats: None,
+            },
+        })
+    }
+
+    /// Sets the schedule of the tracker job, without any retry strategy.
+    pub fn with_schedule<S: Into<String>>(mut self, schedule: S) -> Self {
+        self.tracker.config.job = Some(SchedulerJobConfig {
+            schedule: schedule.into(),
+            retry_strategy: None,
+        });
+        self
+    }
+
+    /// Sets the full configuration of the tracker job.
+    pub fn with_job_config(mut self, job_config: SchedulerJobConfig) -> Self {
+        self.tracker.config.job = Some(job_config);
+        self
+    }
+
+    /// Sets the ID of the scheduler job the tracker is bound to.
+    pub fn with_job_id(mut self, job_id: Uuid) -> Self {
+        self.tracker.job_id = Some(job_id);
+        self
+    }
+
+    /// Sets the tracker target.
+    pub fn with_target(mut self, target: TrackerTarget) -> Self {
+        self.tracker.target = target;
+        self
+    }
+
+    /// Sets the tracker timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.tracker.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the tracker tags.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tracker.tags = tags;
+        self
+    }
+
+    /// Marks the tracker as archived.
+    pub fn archived(mut self) -> Self {
+        self.tracker.archived = true;
+        self
+    }
+
+    /// Sets the tracker actions.
+    pub fn with_actions(mut self, actions: Vec<TrackerAction>) -> Self {
+        self.tracker.actions = actions;
+        self
+    }
+
+    /// Returns the built tracker.
+    pub fn build(self) -> Tracker {
+        self.tracker
+    }
+}
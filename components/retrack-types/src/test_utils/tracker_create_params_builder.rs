@@ -0,0 +1,79 @@
+use crate::{
+    scheduler::SchedulerJobConfig,
+    trackers::{PageTarget, TrackerAction, TrackerConfig, TrackerCreateParams, TrackerTarget},
+};
+
+/// Builder of the sample tracker creation parameters. By default, the tracker has a web page
+/// target, a single `tag` tag, and logs data changes to the server log.
+pub struct TrackerCreateParamsBuilder {
+    params: TrackerCreateParams,
+}
+
+impl TrackerCreateParamsBuilder {
+    /// Creates a builder of the parameters for the enabled tracker with the specified name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            params: TrackerCreateParams {
+                name: name.into(),
+                enabled: true,
+                target: TrackerTarget::Page(PageTarget {
+                    extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
+                    params: None,
+                    user_agent: Some("Retrack/1.0.0".to_string()),
+                    ignore_https_errors: true,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
+                }),
+                config: Default::default(),
+                tags: vec!["tag".to_string()],
+                actions: vec![TrackerAction::ServerLog],
+            },
+        }
+    }
+
+    /// Sets the tracker configuration.
+    pub fn with_config(mut self, config: TrackerConfig) -> Self {
+        self.params.config = config;
+        self
+    }
+
+    /// Sets the tracker target.
+    pub fn with_target(mut self, target: TrackerTarget) -> Self {
+        self.params.target = target;
+        self
+    }
+
+    /// Sets the tracker tags.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.params.tags = tags;
+        self
+    }
+
+    /// Sets the tracker actions.
+    pub fn with_actions(mut self, actions: Vec<TrackerAction>) -> Self {
+        self.params.actions = actions;
+        self
+    }
+
+    /// Sets the schedule of the tracker job, without any retry strategy.
+    pub fn with_schedule<S: Into<String>>(mut self, schedule: S) -> Self {
+        self.params.config.job = Some(SchedulerJobConfig {
+            schedule: schedule.into(),
+            retry_strategy: None,
+        });
+        self
+    }
+
+    /// Marks the tracker as disabled.
+    pub fn disable(mut self) -> Self {
+        self.params.enabled = false;
+        self
+    }
+
+    /// Returns the built tracker creation parameters.
+    pub fn build(self) -> TrackerCreateParams {
+        self.params
+    }
+}
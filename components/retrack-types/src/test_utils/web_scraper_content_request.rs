@@ -0,0 +1,141 @@
+use crate::trackers::{Tracker, TrackerDataValue, TrackerTarget};
+use anyhow::bail;
+use serde_json::{json, Value as JsonValue};
+
+/// Returns the JSON body of the request Retrack sends to the web scraper to extract the content of
+/// the tracker with the `Page` target. Useful to match requests in the mock web scraper server.
+pub fn mock_web_scraper_content_request(
+    tracker: &Tracker,
+    previous_content: Option<&TrackerDataValue>,
+) -> anyhow::Result<JsonValue> {
+    let TrackerTarget::Page(ref target) = tracker.target else {
+        bail!(
+            "Tracker ('{}') target is not web page, instead got: {:?}",
+            tracker.id,
+            tracker.target
+        );
+    };
+
+    let mut request = json!({ "extractor": target.scenario()? });
+    if let Some(ref params) = target.params {
+        request["extractorParams"] = params.clone();
+    }
+    request["tags"] = json!(tracker.tags);
+    if let Some(ref user_agent) = target.user_agent {
+        request["userAgent"] = json!(user_agent);
+    }
+    if target.ignore_https_errors {
+        request["ignoreHTTPSErrors"] = json!(true);
+    }
+    if target.extract_article {
+        request["extractArticle"] = json!(true);
+    }
+    if let Some(engine) = target.engine {
+        request["engine"] = json!(engine);
+    }
+    if let Some(timeout) = tracker.config.timeout {
+        request["timeout"] = json!(timeout.as_millis() as u64);
+    }
+    if let Some(previous_content) = previous_content {
+        request["previousContent"] = serde_json::to_value(previous_content)?;
+    }
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock_web_scraper_content_request;
+    use crate::{
+        tests::MockTrackerBuilder,
+        trackers::{
+            ApiTarget, PageTarget, PageTargetEngine, TargetRequest, TrackerDataValue, TrackerTarget,
+        },
+    };
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn builds_request() -> anyhow::Result<()> {
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .build();
+        assert_json_snapshot!(mock_web_scraper_content_request(&tracker, None)?, @r###"
+        {
+          "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
+          "tags": [],
+          "timeout": 2000,
+          "userAgent": "Retrack/1.0.0"
+        }
+        "###);
+
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .with_target(TrackerTarget::Page(PageTarget {
+            extractor: "export async function execute(p) { return 'content'; }".to_string(),
+            params: Some(json!({ "param": "value" })),
+            user_agent: None,
+            ignore_https_errors: true,
+            extract_article: true,
+            engine: Some(PageTargetEngine::Camoufox),
+            steps: None,
+            selectors: None,
+        }))
+        .with_tags(vec!["tag".to_string()])
+        .build();
+        assert_json_snapshot!(
+            mock_web_scraper_content_request(
+                &tracker,
+                Some(&TrackerDataValue::new(json!("previous")))
+            )?,
+            @r###"
+        {
+          "engine": "camoufox",
+          "extractArticle": true,
+          "extractor": "export async function execute(p) { return 'content'; }",
+          "extractorParams": {
+            "param": "value"
+          },
+          "ignoreHTTPSErrors": true,
+          "previousContent": {
+            "original": "previous"
+          },
+          "tags": [
+            "tag"
+          ],
+          "timeout": 2000
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_non_page_target() -> anyhow::Result<()> {
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .with_target(TrackerTarget::Api(ApiTarget {
+            requests: vec![TargetRequest::new("https://retrack.dev".parse()?)],
+            configurator: None,
+            extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
+        }))
+        .build();
+        assert!(mock_web_scraper_content_request(&tracker, None).is_err());
+
+        Ok(())
+    }
+}
@@ -1,115 +1,108 @@
+mod tls_session_info;
 mod tracker;
 mod tracker_action;
+mod tracker_action_dependency;
+mod tracker_action_digest;
+mod tracker_action_history_entry;
+mod tracker_action_rule;
+mod tracker_action_test_params;
+mod tracker_action_test_result;
+mod tracker_action_throttle;
+mod tracker_active_day;
+mod tracker_active_hours;
+mod tracker_bundle;
+mod tracker_change_filter;
 mod tracker_config;
 mod tracker_create_params;
+mod tracker_data_diff_format;
+mod tracker_data_provenance;
 mod tracker_data_revision;
+mod tracker_data_revision_import;
 mod tracker_data_value;
+mod tracker_escalation;
+mod tracker_fixture;
+mod tracker_insight;
 mod tracker_list_revisions_params;
+mod tracker_max_content_size;
+mod tracker_quarantine;
+mod tracker_quarantined_revision;
+mod tracker_revisions_export_params;
+mod tracker_run_result;
+mod tracker_semantic_filter;
+mod tracker_severity_routing;
+mod tracker_stats;
 mod tracker_target;
+mod tracker_templates_preview;
+mod tracker_templates_preview_params;
 mod tracker_update_params;
+mod tracker_workflow;
+mod trackers_insights_params;
 mod trackers_list_params;
+mod trackers_scrub;
+mod trackers_scrub_params;
+mod trackers_sort;
 
 pub use self::{
+    tls_session_info::TlsSessionInfo,
     tracker::Tracker,
-    tracker_action::{EmailAction, TrackerAction, WebhookAction},
+    tracker_action::{
+        CommandAction, DatabaseAction, DatabaseActionColumn, DatabaseActionColumnValue,
+        EmailAction, EmailActionAttachment, EmailActionAttachmentFormat, FileAction,
+        FileActionMode, GoogleChatAction, IssueAction, IssueProvider, MqttAction, NtfyAction,
+        PublishAction, PublishBroker, PushoverAction, SemverAction, SlackAction, SummarizeAction,
+        TeamsAction, TelegramAction, TrackerAction, TranslateAction, TriggerTrackerAction,
+        WebhookAction, WebhookExpectedResponse, WebhookStatusRange,
+    },
+    tracker_action_dependency::{TrackerActionCondition, TrackerActionDependency},
+    tracker_action_digest::{TrackerActionDigest, TrackerDigestCadence},
+    tracker_action_history_entry::{TrackerActionHistoryEntry, TrackerActionHistoryStatus},
+    tracker_action_rule::TrackerActionRule,
+    tracker_action_test_params::TrackerActionTestParams,
+    tracker_action_test_result::TrackerActionTestResult,
+    tracker_action_throttle::TrackerActionThrottle,
+    tracker_active_day::TrackerActiveDay,
+    tracker_active_hours::TrackerActiveHours,
+    tracker_bundle::TrackerBundle,
+    tracker_change_filter::{TrackerChangeComparator, TrackerChangeDirection, TrackerChangeFilter},
     tracker_config::TrackerConfig,
     tracker_create_params::TrackerCreateParams,
+    tracker_data_diff_format::TrackerDataDiffFormat,
+    tracker_data_provenance::TrackerDataProvenance,
     tracker_data_revision::TrackerDataRevision,
+    tracker_data_revision_import::TrackerDataRevisionImport,
     tracker_data_value::TrackerDataValue,
+    tracker_escalation::{TrackerEscalation, TrackerEscalationStep},
+    tracker_fixture::{TrackerFixture, TrackerFixtureReplay, TrackerFixtureResponse},
+    tracker_insight::{TrackerInsight, TrackerInsightAction, TrackerInsightKind},
     tracker_list_revisions_params::TrackerListRevisionsParams,
+    tracker_max_content_size::{TrackerContentSizePolicy, TrackerMaxContentSize},
+    tracker_quarantine::{TrackerDataAssertion, TrackerDataAssertionValueType, TrackerQuarantine},
+    tracker_quarantined_revision::TrackerQuarantinedRevision,
+    tracker_revisions_export_params::{TrackerRevisionsExportFormat, TrackerRevisionsExportParams},
+    tracker_run_result::{
+        TrackerRunActionResult, TrackerRunActionStatus, TrackerRunOutcome, TrackerRunResult,
+        TrackerRunTimings,
+    },
+    tracker_semantic_filter::TrackerSemanticFilter,
+    tracker_severity_routing::{TrackerSeverity, TrackerSeverityRoute, TrackerSeverityRouting},
+    tracker_stats::TrackerStats,
     tracker_target::{
-        ApiTarget, ConfiguratorScriptArgs, ConfiguratorScriptRequest, ConfiguratorScriptResult,
-        ExtractorScriptArgs, ExtractorScriptResult, PageTarget, TargetRequest, TrackerTarget,
+        ApiTarget, CompositeTarget, ConfiguratorScriptArgs, ConfiguratorScriptRequest,
+        ConfiguratorScriptResult, EmailTarget, ExtractorScriptArgs, ExtractorScriptResult,
+        FeedTarget, FileTarget, GitTarget, GrpcDescriptorSource, GrpcTarget, MergerScriptArgs,
+        MergerScriptInput, MergerScriptResult, MonitorTarget, PageTarget, PageTargetEngine,
+        PageTargetStep, PrometheusTarget, RegistryTarget, S3Target, SitemapTarget, SqlTarget,
+        StreamTarget, TargetRequest, TargetRequestBodyFormat, TargetRequestFilePart,
+        TargetRequestPagination, TargetRequestPaginationStrategy, TargetResponseTransform,
+        TargetSelector, TrackerTarget,
     },
+    tracker_templates_preview::{TrackerEmailPreview, TrackerTemplatesPreview},
+    tracker_templates_preview_params::TrackerTemplatesPreviewParams,
     tracker_update_params::TrackerUpdateParams,
+    tracker_workflow::{TrackerWorkflow, TrackerWorkflowEvent, TrackerWorkflowTransition},
+    trackers_insights_params::TrackersInsightsParams,
     trackers_list_params::TrackersListParams,
+    trackers_scrub::TrackersScrub,
+    trackers_scrub_params::TrackersScrubParams,
+    trackers_sort::TrackersSort,
 };
-
-#[cfg(test)]
-pub mod tests {
-    use crate::{
-        scheduler::SchedulerJobConfig,
-        trackers::{PageTarget, Tracker, TrackerAction, TrackerConfig, TrackerTarget},
-    };
-    use std::time::Duration;
-    use time::OffsetDateTime;
-    use uuid::Uuid;
-
-    pub struct MockTrackerBuilder {
-        tracker: Tracker,
-    }
-
-    impl MockTrackerBuilder {
-        pub fn create<N: Into<String>>(
-            id: Uuid,
-            name: N,
-            revisions: usize,
-        ) -> anyhow::Result<Self> {
-            Ok(Self {
-                tracker: Tracker {
-                    id,
-                    name: name.into(),
-                    enabled: true,
-                    job_id: None,
-                    target: TrackerTarget::Page(PageTarget {
-                        extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
-                        params: None,
-                        user_agent: Some("Retrack/1.0.0".to_string()),
-                        ignore_https_errors: false,
-                    }),
-                    config: TrackerConfig {
-                        revisions,
-                        timeout: Some(Duration::from_millis(2000)),
-                        job: None,
-                    },
-                    tags: vec![],
-                    actions: vec![TrackerAction::ServerLog],
-                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
-                    updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
-                },
-            })
-        }
-
-        pub fn with_schedule<S: Into<String>>(mut self, schedule: S) -> Self {
-            self.tracker.config.job = Some(SchedulerJobConfig {
-                schedule: schedule.into(),
-                retry_strategy: None,
-            });
-            self
-        }
-
-        pub fn with_job_config(mut self, job_config: SchedulerJobConfig) -> Self {
-            self.tracker.config.job = Some(job_config);
-            self
-        }
-
-        pub fn with_job_id(mut self, job_id: Uuid) -> Self {
-            self.tracker.job_id = Some(job_id);
-            self
-        }
-
-        pub fn with_target(mut self, target: TrackerTarget) -> Self {
-            self.tracker.target = target;
-            self
-        }
-
-        pub fn with_timeout(mut self, timeout: Duration) -> Self {
-            self.tracker.config.timeout = Some(timeout);
-            self
-        }
-
-        pub fn with_tags(mut self, tags: Vec<String>) -> Self {
-            self.tracker.tags = tags;
-            self
-        }
-
-        pub fn with_actions(mut self, actions: Vec<TrackerAction>) -> Self {
-            self.tracker.actions = actions;
-            self
-        }
-
-        pub fn build(self) -> Tracker {
-            self.tracker
-        }
-    }
-}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+/// Details of the TLS session established with the target.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsSessionInfo {
+    /// Application protocol negotiated for the session (e.g., `HTTP/2.0` or `HTTP/1.1`).
+    pub protocol: String,
+    /// Hex-encoded SHA-256 fingerprint of the target (leaf) certificate.
+    pub certificate_fingerprint: String,
+    /// Timestamp indicating when the target (leaf) certificate expires.
+    #[serde(with = "time::serde::timestamp")]
+    pub certificate_expires_at: OffsetDateTime,
+}
@@ -1,4 +1,4 @@
-use crate::trackers::{TrackerAction, TrackerConfig, TrackerTarget};
+use crate::trackers::{TrackerAction, TrackerConfig, TrackerStats, TrackerTarget};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use utoipa::ToSchema;
@@ -14,6 +14,9 @@ pub struct Tracker {
     pub name: String,
     /// Whether the tracker is enabled. Disabled trackers are not scheduled.
     pub enabled: bool,
+    /// Whether the tracker is archived. Archived trackers are read-only: their revisions can still
+    /// be retrieved, but they aren't scheduled, can't be updated, and are hidden from listings.
+    pub archived: bool,
     /// Target of the tracker (web page, API, file).
     pub target: TrackerTarget,
     /// ID of the optional job that triggers tracker. If not set,then the job is not scheduled yet.
@@ -25,6 +28,17 @@ pub struct Tracker {
     pub tags: Vec<String>,
     /// List of actions to execute when the tracker fetches new data.
     pub actions: Vec<TrackerAction>,
+    /// Describes how the shape of the most recently extracted data deviates from the shape of the
+    /// previous data revisions, if it does. Usually indicates that the tracker extractor is broken.
+    /// The flag is cleared once the data shape is restored or the tracker target is updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shape_deviation: Option<String>,
+    /// Current state of the tracker workflow, only set if the tracker has a workflow configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Aggregated statistics of the tracker data revisions, only included if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<TrackerStats>,
     /// Date and time when the tracker was created.
     #[serde(with = "time::serde::timestamp")]
     pub created_at: OffsetDateTime,
@@ -58,6 +72,10 @@ mod tests {
             params: Some(serde_json::json!({ "param": "value" })),
             user_agent: Some("Retrack/2.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         }))
         .build();
         assert_json_snapshot!(tracker, @r###"
@@ -65,6 +83,7 @@ mod tests {
           "id": "00000000-0000-0000-0000-000000000001",
           "name": "some-name",
           "enabled": true,
+          "archived": false,
           "target": {
             "type": "page",
             "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
@@ -99,6 +118,10 @@ mod tests {
             params: None,
             user_agent: Some("Retrack/2.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         }))
         .with_schedule("0 0 * * *")
         .build();
@@ -107,6 +130,7 @@ mod tests {
           "id": "00000000-0000-0000-0000-000000000001",
           "name": "some-name",
           "enabled": true,
+          "archived": false,
           "target": {
             "type": "page",
             "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
@@ -141,6 +165,10 @@ mod tests {
             params: None,
             user_agent: Some("Retrack/2.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         }))
         .with_schedule("0 0 * * *")
         .build();
@@ -149,6 +177,7 @@ mod tests {
           "id": "00000000-0000-0000-0000-000000000001",
           "name": "some-name",
           "enabled": true,
+          "archived": false,
           "target": {
             "type": "page",
             "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
@@ -183,6 +212,10 @@ mod tests {
             params: Some(serde_json::json!("Hello, World!")),
             user_agent: Some("Retrack/2.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         }))
         .with_schedule("0 0 * * *")
         .build();
@@ -191,6 +224,7 @@ mod tests {
           "id": "00000000-0000-0000-0000-000000000001",
           "name": "some-name",
           "enabled": true,
+          "archived": false,
           "target": {
             "type": "page",
             "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
@@ -226,6 +260,10 @@ mod tests {
             params: None,
             user_agent: Some("Retrack/2.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         }))
         .with_schedule("0 0 * * *")
         .with_job_config(SchedulerJobConfig {
@@ -245,6 +283,11 @@ mod tests {
                     .collect::<HashMap<_, _>>())
                     .try_into()?,
             ),
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         })])
         .build();
         assert_json_snapshot!(tracker, @r###"
@@ -252,6 +295,7 @@ mod tests {
           "id": "00000000-0000-0000-0000-000000000001",
           "name": "some-name",
           "enabled": true,
+          "archived": false,
           "target": {
             "type": "page",
             "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
@@ -1,15 +1,53 @@
+mod command_action;
+mod database_action;
 mod email_action;
+mod file_action;
+mod google_chat_action;
+mod issue_action;
+mod mqtt_action;
+mod ntfy_action;
+mod publish_action;
+mod pushover_action;
+mod semver_action;
+mod slack_action;
+mod summarize_action;
+mod teams_action;
+mod telegram_action;
+mod translate_action;
+mod trigger_tracker_action;
 mod webhook_action;
 
-pub use self::{email_action::EmailAction, webhook_action::WebhookAction};
+pub use self::{
+    command_action::CommandAction,
+    database_action::{DatabaseAction, DatabaseActionColumn, DatabaseActionColumnValue},
+    email_action::{EmailAction, EmailActionAttachment, EmailActionAttachmentFormat},
+    file_action::{FileAction, FileActionMode},
+    google_chat_action::GoogleChatAction,
+    issue_action::{IssueAction, IssueProvider},
+    mqtt_action::MqttAction,
+    ntfy_action::NtfyAction,
+    publish_action::{PublishAction, PublishBroker},
+    pushover_action::PushoverAction,
+    semver_action::SemverAction,
+    slack_action::SlackAction,
+    summarize_action::SummarizeAction,
+    teams_action::TeamsAction,
+    telegram_action::TelegramAction,
+    translate_action::TranslateAction,
+    trigger_tracker_action::TriggerTrackerAction,
+    webhook_action::{WebhookAction, WebhookExpectedResponse, WebhookStatusRange},
+};
 use serde::{Deserialize, Serialize};
 
 use utoipa::ToSchema;
 
-/// Tracker's action (sending emails or HTTP request, logging, or transforming the tracker data).
+/// Tracker's action (sending emails, HTTP requests, chat messages, or push notifications,
+/// publishing to message brokers, writing to files or external databases, creating issues, running
+/// local commands, triggering other trackers, logging, or transforming the tracker data).
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum TrackerAction {
     /// Sends an email with the extracted data.
     Email(EmailAction),
@@ -18,21 +56,92 @@ pub enum TrackerAction {
     /// Records extracted data in a server log.
     #[serde(rename = "log")]
     ServerLog,
+    /// Parses and normalizes a version within the extracted data, and compares it to the previous
+    /// and currently used versions.
+    Semver(SemverAction),
+    /// Translates the text within the extracted data to the specified language.
+    Translate(TranslateAction),
+    /// Summarizes changes in the extracted data into a short human-readable explanation.
+    Summarize(SummarizeAction),
+    /// Posts a message with the changes in the extracted data to Slack.
+    Slack(SlackAction),
+    /// Posts a message with the changes in the extracted data to Microsoft Teams.
+    Teams(TeamsAction),
+    /// Posts a message with the changes in the extracted data to Google Chat.
+    GoogleChat(GoogleChatAction),
+    /// Sends a message with the changes in the extracted data to a Telegram chat.
+    Telegram(TelegramAction),
+    /// Publishes a push notification with the changes in the extracted data to the ntfy topic.
+    Ntfy(NtfyAction),
+    /// Sends a push notification with the changes in the extracted data via Pushover.
+    Pushover(PushoverAction),
+    /// Publishes the extracted data as a JSON to the message broker topic.
+    Publish(PublishAction),
+    /// Publishes the extracted data as a JSON to the MQTT broker topic.
+    Mqtt(MqttAction),
+    /// Writes the extracted data to a local file.
+    File(FileAction),
+    /// Inserts the extracted data into a table of an external PostgreSQL database.
+    Database(DatabaseAction),
+    /// Creates or updates an issue with the changes in the extracted data in the GitHub or GitLab
+    /// repository.
+    Issue(IssueAction),
+    /// Runs a local command with the extracted data as a JSON on the standard input.
+    Command(CommandAction),
+    /// Triggers an immediate check of other trackers.
+    TriggerTracker(TriggerTrackerAction),
+}
+
+impl TrackerAction {
+    /// Returns the type of the action, as it's specified in the serialized action (e.g., `email`).
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Email(_) => "email",
+            Self::Webhook(_) => "webhook",
+            Self::ServerLog => "log",
+            Self::Semver(_) => "semver",
+            Self::Translate(_) => "translate",
+            Self::Summarize(_) => "summarize",
+            Self::Slack(_) => "slack",
+            Self::Teams(_) => "teams",
+            Self::GoogleChat(_) => "googleChat",
+            Self::Telegram(_) => "telegram",
+            Self::Ntfy(_) => "ntfy",
+            Self::Pushover(_) => "pushover",
+            Self::Publish(_) => "publish",
+            Self::Mqtt(_) => "mqtt",
+            Self::File(_) => "file",
+            Self::Database(_) => "database",
+            Self::Issue(_) => "issue",
+            Self::Command(_) => "command",
+            Self::TriggerTracker(_) => "triggerTracker",
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::TrackerAction;
-    use crate::trackers::{EmailAction, WebhookAction};
+    use crate::trackers::{
+        CommandAction, DatabaseAction, DatabaseActionColumn, DatabaseActionColumnValue,
+        EmailAction, FileAction, FileActionMode, GoogleChatAction, IssueAction, IssueProvider,
+        MqttAction, NtfyAction, PublishAction, PublishBroker, PushoverAction, SemverAction,
+        SlackAction, SummarizeAction, TeamsAction, TelegramAction, TranslateAction,
+        TriggerTrackerAction, WebhookAction,
+    };
     use http::{header::CONTENT_TYPE, Method};
     use insta::assert_json_snapshot;
     use serde_json::json;
-    use std::collections::HashMap;
+    use std::{collections::HashMap, time::Duration};
+    use uuid::uuid;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
         let action = TrackerAction::Email(EmailAction {
             to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: None,
+            attachment: None,
         });
         assert_json_snapshot!(action, @r###"
         {
@@ -52,6 +161,11 @@ mod tests {
                     .collect::<HashMap<_, _>>())
                     .try_into()?,
             ),
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         });
         assert_json_snapshot!(action, @r###"
         {
@@ -68,6 +182,11 @@ mod tests {
             url: "https://retrack.dev".parse()?,
             method: None,
             headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         });
         assert_json_snapshot!(action, @r###"
         {
@@ -83,6 +202,257 @@ mod tests {
         }
         "###);
 
+        let action = TrackerAction::Semver(SemverAction {
+            path: "$.version".to_string(),
+            current_version: Some("1.2.3".to_string()),
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "semver",
+          "path": "$.version",
+          "currentVersion": "1.2.3"
+        }
+        "###);
+
+        let action = TrackerAction::Translate(TranslateAction {
+            target_language: "en".to_string(),
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "translate",
+          "targetLanguage": "en"
+        }
+        "###);
+
+        let action = TrackerAction::Summarize(SummarizeAction {
+            instructions: None,
+            max_tokens: Some(100),
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "summarize",
+          "maxTokens": 100
+        }
+        "###);
+
+        let action = TrackerAction::Slack(SlackAction {
+            webhook_url: None,
+            token: Some("xoxb-token".to_string()),
+            channel: Some("#releases".to_string()),
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "slack",
+          "token": "xoxb-token",
+          "channel": "#releases"
+        }
+        "###);
+
+        let action = TrackerAction::Teams(TeamsAction {
+            webhook_url: "https://retrack.webhook.office.com/webhookb2/XXX".parse()?,
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "teams",
+          "webhookUrl": "https://retrack.webhook.office.com/webhookb2/XXX"
+        }
+        "###);
+
+        let action = TrackerAction::GoogleChat(GoogleChatAction {
+            webhook_url: "https://chat.googleapis.com/v1/spaces/XXX/messages".parse()?,
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "googleChat",
+          "webhookUrl": "https://chat.googleapis.com/v1/spaces/XXX/messages",
+          "timeout": 2500
+        }
+        "###);
+
+        let action = TrackerAction::Telegram(TelegramAction {
+            token: "123456789:token".to_string(),
+            chat_id: "-1001234567890".to_string(),
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "telegram",
+          "token": "123456789:token",
+          "chatId": "-1001234567890"
+        }
+        "###);
+
+        let action = TrackerAction::Ntfy(NtfyAction {
+            server_url: None,
+            topic: "retrack-releases".to_string(),
+            priority: Some(4),
+            click_url: None,
+            token: None,
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "ntfy",
+          "topic": "retrack-releases",
+          "priority": 4
+        }
+        "###);
+
+        let action = TrackerAction::Pushover(PushoverAction {
+            token: "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string(),
+            user: "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string(),
+            device: None,
+            priority: None,
+            click_url: None,
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "pushover",
+          "token": "azGDORePK8gMaC0QOYAMyEEuzJnyUi",
+          "user": "uQiRzpo4DXghDmr9QzzfQu27cmVRsG"
+        }
+        "###);
+
+        let action = TrackerAction::Publish(PublishAction {
+            broker: PublishBroker::Kafka,
+            url: "https://kafka-rest.retrack.dev".parse()?,
+            topic: "retrack.releases".to_string(),
+            exchange: None,
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "publish",
+          "broker": "kafka",
+          "url": "https://kafka-rest.retrack.dev/",
+          "topic": "retrack.releases"
+        }
+        "###);
+
+        let action = TrackerAction::Mqtt(MqttAction {
+            url: "mqtt://mqtt.retrack.dev".parse()?,
+            topic: "retrack/releases".to_string(),
+            qos: Some(1),
+            retain: true,
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "mqtt",
+          "url": "mqtt://mqtt.retrack.dev",
+          "topic": "retrack/releases",
+          "qos": 1,
+          "retain": true
+        }
+        "###);
+
+        let action = TrackerAction::File(FileAction {
+            path: "/var/retrack/version.txt".to_string(),
+            mode: Some(FileActionMode::Overwrite),
+            template: Some("{{value.version}}".to_string()),
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "file",
+          "path": "/var/retrack/version.txt",
+          "mode": "overwrite",
+          "template": "{{value.version}}"
+        }
+        "###);
+
+        let action = TrackerAction::Database(DatabaseAction {
+            connection_secret: "analytics-db".to_string(),
+            table: "analytics.revisions".to_string(),
+            columns: vec![
+                DatabaseActionColumn {
+                    name: "tracker_id".to_string(),
+                    value: DatabaseActionColumnValue::TrackerId,
+                },
+                DatabaseActionColumn {
+                    name: "data".to_string(),
+                    value: DatabaseActionColumnValue::Data { path: None },
+                },
+            ],
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "database",
+          "connectionSecret": "analytics-db",
+          "table": "analytics.revisions",
+          "columns": [
+            {
+              "name": "tracker_id",
+              "value": {
+                "type": "trackerId"
+              }
+            },
+            {
+              "name": "data",
+              "value": {
+                "type": "data"
+              }
+            }
+          ]
+        }
+        "###);
+
+        let action = TrackerAction::Issue(IssueAction {
+            provider: IssueProvider::GitHub,
+            api_url: None,
+            repository: "secutils-dev/retrack".to_string(),
+            token: "ghp_token".to_string(),
+            title: Some("{{tracker.name}} has changed".to_string()),
+            body: None,
+            labels: vec!["retrack".to_string()],
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "issue",
+          "provider": "github",
+          "repository": "secutils-dev/retrack",
+          "token": "ghp_token",
+          "title": "{{tracker.name}} has changed",
+          "labels": [
+            "retrack"
+          ]
+        }
+        "###);
+
+        let action = TrackerAction::Command(CommandAction {
+            command: "/usr/local/bin/notify-release".to_string(),
+            args: vec!["--channel".to_string(), "releases".to_string()],
+            timeout: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "command",
+          "command": "/usr/local/bin/notify-release",
+          "args": [
+            "--channel",
+            "releases"
+          ]
+        }
+        "###);
+
+        let action = TrackerAction::TriggerTracker(TriggerTrackerAction {
+            trackers: Some(vec![uuid!("00000000-0000-0000-0000-000000000001")]),
+            tags: None,
+        });
+        assert_json_snapshot!(action, @r###"
+        {
+          "type": "triggerTracker",
+          "trackers": [
+            "00000000-0000-0000-0000-000000000001"
+          ]
+        }
+        "###);
+
         Ok(())
     }
 
@@ -90,6 +460,9 @@ mod tests {
     fn deserialization() -> anyhow::Result<()> {
         let action = TrackerAction::Email(EmailAction {
             to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: None,
+            attachment: None,
         });
         assert_eq!(
             serde_json::from_str::<TrackerAction>(
@@ -107,6 +480,11 @@ mod tests {
                     .collect::<HashMap<_, _>>())
                     .try_into()?,
             ),
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         });
         assert_eq!(
             serde_json::from_str::<TrackerAction>(
@@ -125,6 +503,11 @@ mod tests {
             url: "https://retrack.dev".parse()?,
             method: None,
             headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         });
         assert_eq!(
             serde_json::from_str::<TrackerAction>(
@@ -139,6 +522,306 @@ mod tests {
             action
         );
 
+        let action = TrackerAction::Semver(SemverAction {
+            path: "$.version".to_string(),
+            current_version: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({ "type": "semver", "path": "$.version" }).to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Translate(TranslateAction {
+            target_language: "en".to_string(),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({ "type": "translate", "targetLanguage": "en" }).to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Summarize(SummarizeAction {
+            instructions: None,
+            max_tokens: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(&json!({ "type": "summarize" }).to_string())?,
+            action
+        );
+
+        let action = TrackerAction::Slack(SlackAction {
+            webhook_url: Some("https://hooks.slack.com/services/T000/B000/XXX".parse()?),
+            token: None,
+            channel: None,
+            timeout: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "slack",
+                    "webhookUrl": "https://hooks.slack.com/services/T000/B000/XXX"
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Teams(TeamsAction {
+            webhook_url: "https://retrack.webhook.office.com/webhookb2/XXX".parse()?,
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "teams",
+                    "webhookUrl": "https://retrack.webhook.office.com/webhookb2/XXX",
+                    "timeout": 2500
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::GoogleChat(GoogleChatAction {
+            webhook_url: "https://chat.googleapis.com/v1/spaces/XXX/messages".parse()?,
+            timeout: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "googleChat",
+                    "webhookUrl": "https://chat.googleapis.com/v1/spaces/XXX/messages"
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Telegram(TelegramAction {
+            token: "123456789:token".to_string(),
+            chat_id: "@retrack_releases".to_string(),
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "telegram",
+                    "token": "123456789:token",
+                    "chatId": "@retrack_releases",
+                    "timeout": 2500
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Ntfy(NtfyAction {
+            server_url: Some("https://ntfy.retrack.dev".parse()?),
+            topic: "retrack-releases".to_string(),
+            priority: None,
+            click_url: Some("https://retrack.dev/releases".parse()?),
+            token: None,
+            timeout: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "ntfy",
+                    "serverUrl": "https://ntfy.retrack.dev",
+                    "topic": "retrack-releases",
+                    "clickUrl": "https://retrack.dev/releases"
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Pushover(PushoverAction {
+            token: "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string(),
+            user: "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string(),
+            device: Some("iphone".to_string()),
+            priority: Some(1),
+            click_url: None,
+            timeout: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "pushover",
+                    "token": "azGDORePK8gMaC0QOYAMyEEuzJnyUi",
+                    "user": "uQiRzpo4DXghDmr9QzzfQu27cmVRsG",
+                    "device": "iphone",
+                    "priority": 1
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Publish(PublishAction {
+            broker: PublishBroker::Nats,
+            url: "tls://nats.retrack.dev:4222".parse()?,
+            topic: "retrack.releases".to_string(),
+            exchange: None,
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "publish",
+                    "broker": "nats",
+                    "url": "tls://nats.retrack.dev:4222",
+                    "topic": "retrack.releases",
+                    "timeout": 2500
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Mqtt(MqttAction {
+            url: "mqtts://mqtt.retrack.dev".parse()?,
+            topic: "retrack/releases".to_string(),
+            qos: None,
+            retain: false,
+            timeout: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "mqtt",
+                    "url": "mqtts://mqtt.retrack.dev",
+                    "topic": "retrack/releases"
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::File(FileAction {
+            path: "/var/retrack/releases.jsonl".to_string(),
+            mode: None,
+            template: None,
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "file",
+                    "path": "/var/retrack/releases.jsonl"
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Database(DatabaseAction {
+            connection_secret: "analytics-db".to_string(),
+            table: "revisions".to_string(),
+            columns: vec![DatabaseActionColumn {
+                name: "version".to_string(),
+                value: DatabaseActionColumnValue::Data {
+                    path: Some("$.version".to_string()),
+                },
+            }],
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "database",
+                    "connectionSecret": "analytics-db",
+                    "table": "revisions",
+                    "columns": [{ "name": "version", "value": { "type": "data", "path": "$.version" } }],
+                    "timeout": 2500
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Issue(IssueAction {
+            provider: IssueProvider::GitLab,
+            api_url: None,
+            repository: "secutils/retrack".to_string(),
+            token: "glpat-token".to_string(),
+            title: None,
+            body: None,
+            labels: vec![],
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "issue",
+                    "provider": "gitlab",
+                    "repository": "secutils/retrack",
+                    "token": "glpat-token",
+                    "timeout": 2500
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::Command(CommandAction {
+            command: "/usr/local/bin/notify-release".to_string(),
+            args: vec![],
+            timeout: Some(Duration::from_millis(2500)),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "command",
+                    "command": "/usr/local/bin/notify-release",
+                    "timeout": 2500
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = TrackerAction::TriggerTracker(TriggerTrackerAction {
+            trackers: None,
+            tags: Some(vec!["app:changelog".to_string()]),
+        });
+        assert_eq!(
+            serde_json::from_str::<TrackerAction>(
+                &json!({
+                    "type": "triggerTracker",
+                    "tags": ["app:changelog"]
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_name_matches_serialized_type() -> anyhow::Result<()> {
+        let actions = [
+            TrackerAction::ServerLog,
+            TrackerAction::Command(CommandAction {
+                command: "/usr/local/bin/notify-release".to_string(),
+                args: vec![],
+                timeout: None,
+            }),
+            TrackerAction::TriggerTracker(TriggerTrackerAction {
+                trackers: None,
+                tags: Some(vec!["app:changelog".to_string()]),
+            }),
+        ];
+        for action in actions {
+            assert_eq!(
+                serde_json::to_value(&action)?["type"],
+                json!(action.type_name())
+            );
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Tracker's action to run the local command with the tracker data revision as a JSON on the
+/// standard input, so that self-hosted instances can integrate with anything without deploying
+/// a webhook receiver.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandAction {
+    /// Absolute path of the executable to run (e.g., `/usr/local/bin/notify-release`). The
+    /// executable should be allowed by the server configuration.
+    pub command: String,
+
+    /// Optional list of arguments to pass to the executable. Arguments are passed as is, without
+    /// being interpreted by a shell.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// Optional number of milliseconds to wait for the command to exit before it's killed and the
+    /// action is considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::CommandAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = CommandAction {
+            command: "/usr/local/bin/notify-release".to_string(),
+            args: vec![],
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "command": "/usr/local/bin/notify-release"
+        }
+        "###);
+
+        let action = CommandAction {
+            command: "/usr/local/bin/notify-release".to_string(),
+            args: vec!["--channel".to_string(), "releases".to_string()],
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "command": "/usr/local/bin/notify-release",
+          "args": [
+            "--channel",
+            "releases"
+          ],
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "command": "/usr/local/bin/notify-release" });
+        assert_eq!(
+            serde_json::from_value::<CommandAction>(action_json)?,
+            CommandAction {
+                command: "/usr/local/bin/notify-release".to_string(),
+                args: vec![],
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "command": "/usr/local/bin/notify-release",
+            "args": ["--channel", "releases"],
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<CommandAction>(action_json)?,
+            CommandAction {
+                command: "/usr/local/bin/notify-release".to_string(),
+                args: vec!["--channel".to_string(), "releases".to_string()],
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -1,19 +1,58 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
 use utoipa::ToSchema;
 
 /// Tracker's action to send an email.
+#[serde_as]
+#[skip_serializing_none]
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EmailAction {
     /// An email will be sent to the specified emails.
     pub to: Vec<String>,
+
+    /// Optional number of milliseconds to wait for the SMTP server to accept the email before the
+    /// delivery is considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+
+    /// Optional name of the email template to render the email with, instead of the built-in one.
+    pub template: Option<String>,
+
+    /// Optional attachment of the complete tracker data revision, for the data that is too large
+    /// to be included into the email inline.
+    pub attachment: Option<EmailActionAttachment>,
+}
+
+/// Attachment of the complete tracker data revision to the tracker email action notification.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailActionAttachment {
+    /// Format of the attached tracker data.
+    pub format: EmailActionAttachmentFormat,
+    /// Size of the tracker data, in bytes, above which the data is attached to the email and the
+    /// inline data is truncated. Zero means the data is always attached.
+    pub threshold: usize,
+}
+
+/// Format of the tracker data attached to the tracker email action notification.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum EmailActionAttachmentFormat {
+    /// Data is attached as a JSON document.
+    Json,
+    /// Data is attached as a CSV document. Arrays of objects are attached as rows with a column
+    /// per object property, other values are attached as rows with a single `value` column.
+    Csv,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::EmailAction;
+    use crate::trackers::{EmailAction, EmailActionAttachment, EmailActionAttachmentFormat};
     use insta::assert_json_snapshot;
     use serde_json::json;
+    use std::time::Duration;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
@@ -22,6 +61,9 @@ mod tests {
                 "dev@retrack.dev".to_string(),
                 "dev-2@retrack.dev".to_string(),
             ],
+            timeout: None,
+            template: None,
+            attachment: None,
         };
         assert_json_snapshot!(action, @r###"
         {
@@ -32,6 +74,57 @@ mod tests {
         }
         "###);
 
+        let action = EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: Some(Duration::from_millis(15000)),
+            template: None,
+            attachment: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "to": [
+            "dev@retrack.dev"
+          ],
+          "timeout": 15000
+        }
+        "###);
+
+        let action = EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: Some("changes".to_string()),
+            attachment: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "to": [
+            "dev@retrack.dev"
+          ],
+          "template": "changes"
+        }
+        "###);
+
+        let action = EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: None,
+            attachment: Some(EmailActionAttachment {
+                format: EmailActionAttachmentFormat::Csv,
+                threshold: 1024,
+            }),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "to": [
+            "dev@retrack.dev"
+          ],
+          "attachment": {
+            "format": "csv",
+            "threshold": 1024
+          }
+        }
+        "###);
+
         Ok(())
     }
 
@@ -42,6 +135,9 @@ mod tests {
                 "dev@retrack.dev".to_string(),
                 "dev-2@retrack.dev".to_string(),
             ],
+            timeout: None,
+            template: None,
+            attachment: None,
         };
         assert_eq!(
             serde_json::from_str::<EmailAction>(
@@ -50,6 +146,52 @@ mod tests {
             action
         );
 
+        let action = EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: Some(Duration::from_millis(15000)),
+            template: None,
+            attachment: None,
+        };
+        assert_eq!(
+            serde_json::from_str::<EmailAction>(
+                &json!({ "to": [ "dev@retrack.dev" ], "timeout": 15000 }).to_string()
+            )?,
+            action
+        );
+
+        let action = EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: Some("changes".to_string()),
+            attachment: None,
+        };
+        assert_eq!(
+            serde_json::from_str::<EmailAction>(
+                &json!({ "to": [ "dev@retrack.dev" ], "template": "changes" }).to_string()
+            )?,
+            action
+        );
+
+        let action = EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: None,
+            attachment: Some(EmailActionAttachment {
+                format: EmailActionAttachmentFormat::Json,
+                threshold: 0,
+            }),
+        };
+        assert_eq!(
+            serde_json::from_str::<EmailAction>(
+                &json!({
+                    "to": [ "dev@retrack.dev" ],
+                    "attachment": { "format": "json", "threshold": 0 }
+                })
+                .to_string()
+            )?,
+            action
+        );
+
         Ok(())
     }
 }
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Tracker's action to write the tracker data revision to the local file, so that tracker data
+/// can be picked up by other tools in air-gapped deployments.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAction {
+    /// Absolute path of the file to write to (e.g., `/var/retrack/releases.jsonl`). The path
+    /// should be within one of the directories allowed by the server configuration, and the
+    /// parent directory should exist.
+    pub path: String,
+
+    /// Optional mode that defines how the file is written. If not specified, the revision is
+    /// appended to the file.
+    pub mode: Option<FileActionMode>,
+
+    /// Optional Handlebars template used to render the revision (e.g., `{{value.version}}`). The
+    /// template can refer to the tracker (`tracker.id` and `tracker.name`) and the revision data
+    /// (`value`). If not specified, the revision data is written as a single line of JSON.
+    pub template: Option<String>,
+}
+
+/// Defines how the tracker file action writes the tracker data revision to the file.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum FileActionMode {
+    /// Appends the revision to the end of the file, creating the file if it doesn't exist.
+    Append,
+    /// Replaces the content of the file with the revision, creating the file if it doesn't exist.
+    Overwrite,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{FileAction, FileActionMode};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = FileAction {
+            path: "/var/retrack/releases.jsonl".to_string(),
+            mode: None,
+            template: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "path": "/var/retrack/releases.jsonl"
+        }
+        "###);
+
+        let action = FileAction {
+            path: "/var/retrack/version.txt".to_string(),
+            mode: Some(FileActionMode::Overwrite),
+            template: Some("{{value.version}}".to_string()),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "path": "/var/retrack/version.txt",
+          "mode": "overwrite",
+          "template": "{{value.version}}"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "path": "/var/retrack/releases.jsonl" });
+        assert_eq!(
+            serde_json::from_value::<FileAction>(action_json)?,
+            FileAction {
+                path: "/var/retrack/releases.jsonl".to_string(),
+                mode: None,
+                template: None,
+            }
+        );
+
+        let action_json = json!({
+            "path": "/var/retrack/releases.jsonl",
+            "mode": "append",
+            "template": "{{tracker.name}}: {{value.version}}\n"
+        });
+        assert_eq!(
+            serde_json::from_value::<FileAction>(action_json)?,
+            FileAction {
+                path: "/var/retrack/releases.jsonl".to_string(),
+                mode: Some(FileActionMode::Append),
+                template: Some("{{tracker.name}}: {{value.version}}\n".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+}
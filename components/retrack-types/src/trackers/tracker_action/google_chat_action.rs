@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's action to post a message about the tracker data changes to Google Chat. The message
+/// is a card that includes the diff between the previous and current tracker data, and is posted
+/// to the Google Chat space incoming webhook.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleChatAction {
+    /// URL of the Google Chat space incoming webhook to post the message to (e.g.,
+    /// `https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN`).
+    pub webhook_url: Url,
+
+    /// Optional number of milliseconds to wait for Google Chat to respond before the delivery is
+    /// considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::GoogleChatAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = GoogleChatAction {
+            webhook_url: "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN"
+                .parse()?,
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "webhookUrl": "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN"
+        }
+        "###);
+
+        let action = GoogleChatAction {
+            webhook_url: "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN"
+                .parse()?,
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "webhookUrl": "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN",
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "webhookUrl": "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN" });
+        assert_eq!(
+            serde_json::from_value::<GoogleChatAction>(action_json)?,
+            GoogleChatAction {
+                webhook_url:
+                    "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN"
+                        .parse()?,
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "webhookUrl": "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN",
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<GoogleChatAction>(action_json)?,
+            GoogleChatAction {
+                webhook_url:
+                    "https://chat.googleapis.com/v1/spaces/XXX/messages?key=KEY&token=TOKEN"
+                        .parse()?,
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
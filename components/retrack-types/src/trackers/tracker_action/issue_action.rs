@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's action to create an issue in the GitHub or GitLab repository when the tracker data
+/// changes or the tracker workflow transitions (e.g., on failure). If the repository already has
+/// an open issue with the same title, the issue is updated with a comment instead, so that the
+/// repeated changes are collected in a single issue.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueAction {
+    /// Issue tracker to create issues in.
+    pub provider: IssueProvider,
+
+    /// Optional base URL of the self-hosted issue tracker API (e.g.,
+    /// `https://github.retrack.dev/api/v3` or `https://gitlab.retrack.dev/api/v4`). If not
+    /// specified, the public GitHub or GitLab API is used.
+    pub api_url: Option<Url>,
+
+    /// Repository to create issues in: `owner/name` for GitHub, and either the numeric project ID
+    /// or the full project path (e.g., `group/subgroup/project`) for GitLab.
+    pub repository: String,
+
+    /// Access token that is allowed to create issues and comments in the repository.
+    pub token: String,
+
+    /// Optional Handlebars template of the issue title (e.g., `{{tracker.name}} has changed`). The
+    /// template can refer to the tracker (`tracker.id` and `tracker.name`), the tracker data
+    /// (`value`), and the diff between the previous and current tracker data (`diff`). If not
+    /// specified, the tracker name is used.
+    pub title: Option<String>,
+
+    /// Optional Handlebars template of the issue body and comments, with the same data as the
+    /// title template. If not specified, the diff between the previous and current tracker data
+    /// is used.
+    pub body: Option<String>,
+
+    /// Optional list of labels to assign to the created issues.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Optional number of milliseconds to wait for the issue tracker to respond before the
+    /// delivery is considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+/// Issue tracker the tracker issue action creates issues in.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueProvider {
+    /// GitHub or GitHub Enterprise Server, via the REST API.
+    GitHub,
+    /// GitLab, via the REST API (v4).
+    GitLab,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{IssueAction, IssueProvider};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = IssueAction {
+            provider: IssueProvider::GitHub,
+            api_url: None,
+            repository: "secutils-dev/retrack".to_string(),
+            token: "ghp_token".to_string(),
+            title: None,
+            body: None,
+            labels: vec![],
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "provider": "github",
+          "repository": "secutils-dev/retrack",
+          "token": "ghp_token"
+        }
+        "###);
+
+        let action = IssueAction {
+            provider: IssueProvider::GitLab,
+            api_url: Some("https://gitlab.retrack.dev/api/v4".parse()?),
+            repository: "secutils/retrack".to_string(),
+            token: "glpat-token".to_string(),
+            title: Some("{{tracker.name}} has changed".to_string()),
+            body: Some("New version: {{value.version}}".to_string()),
+            labels: vec!["retrack".to_string(), "triage".to_string()],
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "provider": "gitlab",
+          "apiUrl": "https://gitlab.retrack.dev/api/v4",
+          "repository": "secutils/retrack",
+          "token": "glpat-token",
+          "title": "{{tracker.name}} has changed",
+          "body": "New version: {{value.version}}",
+          "labels": [
+            "retrack",
+            "triage"
+          ],
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({
+            "provider": "github",
+            "repository": "secutils-dev/retrack",
+            "token": "ghp_token"
+        });
+        assert_eq!(
+            serde_json::from_value::<IssueAction>(action_json)?,
+            IssueAction {
+                provider: IssueProvider::GitHub,
+                api_url: None,
+                repository: "secutils-dev/retrack".to_string(),
+                token: "ghp_token".to_string(),
+                title: None,
+                body: None,
+                labels: vec![],
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "provider": "gitlab",
+            "apiUrl": "https://gitlab.retrack.dev/api/v4",
+            "repository": "42",
+            "token": "glpat-token",
+            "title": "{{tracker.name}} has changed",
+            "body": "New version: {{value.version}}",
+            "labels": ["retrack"],
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<IssueAction>(action_json)?,
+            IssueAction {
+                provider: IssueProvider::GitLab,
+                api_url: Some("https://gitlab.retrack.dev/api/v4".parse()?),
+                repository: "42".to_string(),
+                token: "glpat-token".to_string(),
+                title: Some("{{tracker.name}} has changed".to_string()),
+                body: Some("New version: {{value.version}}".to_string()),
+                labels: vec!["retrack".to_string()],
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
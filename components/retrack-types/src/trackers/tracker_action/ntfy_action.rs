@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's action to publish a push notification about the tracker data changes to the ntfy
+/// topic (https://ntfy.sh). The notification includes the diff between the previous and current
+/// tracker data.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NtfyAction {
+    /// Optional URL of the self-hosted ntfy server (e.g., `https://ntfy.retrack.dev`). If not
+    /// specified, the public `https://ntfy.sh` server is used.
+    pub server_url: Option<Url>,
+
+    /// Name of the topic to publish the notification to (e.g., `retrack-releases`).
+    pub topic: String,
+
+    /// Optional priority of the notification, from `1` (min) to `5` (max). If not specified, the
+    /// default priority (`3`) is used.
+    pub priority: Option<u8>,
+
+    /// Optional URL to open when the notification is clicked (e.g., the tracked page).
+    pub click_url: Option<Url>,
+
+    /// Optional access token to publish to the protected topic.
+    pub token: Option<String>,
+
+    /// Optional number of milliseconds to wait for the ntfy server to respond before the delivery
+    /// is considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::NtfyAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = NtfyAction {
+            server_url: None,
+            topic: "retrack-releases".to_string(),
+            priority: None,
+            click_url: None,
+            token: None,
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "topic": "retrack-releases"
+        }
+        "###);
+
+        let action = NtfyAction {
+            server_url: Some("https://ntfy.retrack.dev".parse()?),
+            topic: "retrack-releases".to_string(),
+            priority: Some(4),
+            click_url: Some("https://retrack.dev/releases".parse()?),
+            token: Some("tk_token".to_string()),
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "serverUrl": "https://ntfy.retrack.dev/",
+          "topic": "retrack-releases",
+          "priority": 4,
+          "clickUrl": "https://retrack.dev/releases",
+          "token": "tk_token",
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "topic": "retrack-releases" });
+        assert_eq!(
+            serde_json::from_value::<NtfyAction>(action_json)?,
+            NtfyAction {
+                server_url: None,
+                topic: "retrack-releases".to_string(),
+                priority: None,
+                click_url: None,
+                token: None,
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "serverUrl": "https://ntfy.retrack.dev",
+            "topic": "retrack-releases",
+            "priority": 4,
+            "clickUrl": "https://retrack.dev/releases",
+            "token": "tk_token",
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<NtfyAction>(action_json)?,
+            NtfyAction {
+                server_url: Some("https://ntfy.retrack.dev".parse()?),
+                topic: "retrack-releases".to_string(),
+                priority: Some(4),
+                click_url: Some("https://retrack.dev/releases".parse()?),
+                token: Some("tk_token".to_string()),
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's action to send a push notification about the tracker data changes via Pushover
+/// (https://pushover.net). The notification includes the diff between the previous and current
+/// tracker data.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PushoverAction {
+    /// API token of the Pushover application to send the notification with.
+    pub token: String,
+
+    /// Key of the Pushover user or group to send the notification to.
+    pub user: String,
+
+    /// Optional name of the user's device to send the notification to. If not specified, the
+    /// notification is sent to all user's devices.
+    pub device: Option<String>,
+
+    /// Optional priority of the notification, from `-2` (lowest) to `2` (emergency). If not
+    /// specified, the normal priority (`0`) is used. Emergency notifications are repeated until
+    /// acknowledged, for up to an hour.
+    pub priority: Option<i8>,
+
+    /// Optional URL to include as a supplementary link in the notification (e.g., the tracked
+    /// page).
+    pub click_url: Option<Url>,
+
+    /// Optional number of milliseconds to wait for Pushover to respond before the delivery is
+    /// considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::PushoverAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = PushoverAction {
+            token: "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string(),
+            user: "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string(),
+            device: None,
+            priority: None,
+            click_url: None,
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "token": "azGDORePK8gMaC0QOYAMyEEuzJnyUi",
+          "user": "uQiRzpo4DXghDmr9QzzfQu27cmVRsG"
+        }
+        "###);
+
+        let action = PushoverAction {
+            token: "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string(),
+            user: "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string(),
+            device: Some("iphone".to_string()),
+            priority: Some(-1),
+            click_url: Some("https://retrack.dev/releases".parse()?),
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "token": "azGDORePK8gMaC0QOYAMyEEuzJnyUi",
+          "user": "uQiRzpo4DXghDmr9QzzfQu27cmVRsG",
+          "device": "iphone",
+          "priority": -1,
+          "clickUrl": "https://retrack.dev/releases",
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({
+            "token": "azGDORePK8gMaC0QOYAMyEEuzJnyUi",
+            "user": "uQiRzpo4DXghDmr9QzzfQu27cmVRsG"
+        });
+        assert_eq!(
+            serde_json::from_value::<PushoverAction>(action_json)?,
+            PushoverAction {
+                token: "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string(),
+                user: "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string(),
+                device: None,
+                priority: None,
+                click_url: None,
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "token": "azGDORePK8gMaC0QOYAMyEEuzJnyUi",
+            "user": "uQiRzpo4DXghDmr9QzzfQu27cmVRsG",
+            "device": "iphone",
+            "priority": -1,
+            "clickUrl": "https://retrack.dev/releases",
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<PushoverAction>(action_json)?,
+            PushoverAction {
+                token: "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string(),
+                user: "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string(),
+                device: Some("iphone".to_string()),
+                priority: Some(-1),
+                click_url: Some("https://retrack.dev/releases".parse()?),
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
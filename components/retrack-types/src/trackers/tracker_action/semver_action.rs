@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Tracker's action to parse and normalize a version string within the tracker data and compare
+/// it to the previously tracked version and to the version currently in use.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SemverAction {
+    /// Path to the version within the tracker data, e.g. `$.version` or `$.releases[0].tag`.
+    pub path: String,
+
+    /// Version currently in use, if any. If specified, the version within the tracker data is
+    /// compared to it, e.g. to find out whether there is a newer version available to upgrade to.
+    pub current_version: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::SemverAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = SemverAction {
+            path: "$.version".to_string(),
+            current_version: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "path": "$.version"
+        }
+        "###);
+
+        let action = SemverAction {
+            path: "$.version".to_string(),
+            current_version: Some("1.2.3".to_string()),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "path": "$.version",
+          "currentVersion": "1.2.3"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<SemverAction>(&json!({ "path": "$.version" }).to_string())?,
+            SemverAction {
+                path: "$.version".to_string(),
+                current_version: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<SemverAction>(
+                &json!({ "path": "$.version", "currentVersion": "v1.2" }).to_string()
+            )?,
+            SemverAction {
+                path: "$.version".to_string(),
+                current_version: Some("v1.2".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+}
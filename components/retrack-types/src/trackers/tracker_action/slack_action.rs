@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's action to post a message about the tracker data changes to Slack. The message is
+/// formatted with Block Kit and includes the diff between the previous and current tracker data.
+/// The message is posted either to the incoming webhook, or to the specified channel with the bot
+/// token.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackAction {
+    /// URL of the Slack incoming webhook to post the message to (e.g.,
+    /// `https://hooks.slack.com/services/T000/B000/XXX`). Cannot be used together with the bot
+    /// token.
+    pub webhook_url: Option<Url>,
+
+    /// Slack bot token (e.g., `xoxb-...`) to post the message with. The bot must be a member of
+    /// the channel. Cannot be used together with the webhook URL.
+    pub token: Option<String>,
+
+    /// ID or name of the Slack channel to post the message to (e.g., `C0123456789` or
+    /// `#releases`). Required if the message is posted with the bot token.
+    pub channel: Option<String>,
+
+    /// Optional number of milliseconds to wait for Slack to respond before the delivery is
+    /// considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::SlackAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = SlackAction {
+            webhook_url: Some("https://hooks.slack.com/services/T000/B000/XXX".parse()?),
+            token: None,
+            channel: None,
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "webhookUrl": "https://hooks.slack.com/services/T000/B000/XXX"
+        }
+        "###);
+
+        let action = SlackAction {
+            webhook_url: None,
+            token: Some("xoxb-token".to_string()),
+            channel: Some("#releases".to_string()),
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "token": "xoxb-token",
+          "channel": "#releases",
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "webhookUrl": "https://hooks.slack.com/services/T000/B000/XXX" });
+        assert_eq!(
+            serde_json::from_value::<SlackAction>(action_json)?,
+            SlackAction {
+                webhook_url: Some("https://hooks.slack.com/services/T000/B000/XXX".parse()?),
+                token: None,
+                channel: None,
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "token": "xoxb-token",
+            "channel": "#releases",
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<SlackAction>(action_json)?,
+            SlackAction {
+                webhook_url: None,
+                token: Some("xoxb-token".to_string()),
+                channel: Some("#releases".to_string()),
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Tracker's action to summarize changes in the tracker data into a short human-readable
+/// explanation using the configured LLM API (OpenAI-compatible). If the changes cannot be
+/// summarized, the raw diff is used instead.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SummarizeAction {
+    /// Optional additional instructions for the summary, e.g. `Focus on the price and delivery
+    /// time`.
+    pub instructions: Option<String>,
+    /// Optional maximum number of tokens in the summary. Cannot exceed the limit defined in the
+    /// server configuration, which is also used by default.
+    pub max_tokens: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::SummarizeAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = SummarizeAction {
+            instructions: Some("Focus on the price.".to_string()),
+            max_tokens: Some(100),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "instructions": "Focus on the price.",
+          "maxTokens": 100
+        }
+        "###);
+
+        let action = SummarizeAction {
+            instructions: None,
+            max_tokens: None,
+        };
+        assert_json_snapshot!(action, @"{}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<SummarizeAction>(
+                &json!({ "instructions": "Focus on the price.", "maxTokens": 100 }).to_string()
+            )?,
+            SummarizeAction {
+                instructions: Some("Focus on the price.".to_string()),
+                max_tokens: Some(100),
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<SummarizeAction>(&json!({}).to_string())?,
+            SummarizeAction {
+                instructions: None,
+                max_tokens: None,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's action to post a message about the tracker data changes to Microsoft Teams. The
+/// message is an Adaptive Card that includes the diff between the previous and current tracker
+/// data, and is posted to the Teams incoming webhook (or the Workflows webhook).
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsAction {
+    /// URL of the Teams incoming webhook to post the message to (e.g.,
+    /// `https://retrack.webhook.office.com/webhookb2/XXX`).
+    pub webhook_url: Url,
+
+    /// Optional number of milliseconds to wait for Teams to respond before the delivery is
+    /// considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TeamsAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = TeamsAction {
+            webhook_url: "https://retrack.webhook.office.com/webhookb2/XXX".parse()?,
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "webhookUrl": "https://retrack.webhook.office.com/webhookb2/XXX"
+        }
+        "###);
+
+        let action = TeamsAction {
+            webhook_url: "https://retrack.webhook.office.com/webhookb2/XXX".parse()?,
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "webhookUrl": "https://retrack.webhook.office.com/webhookb2/XXX",
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json =
+            json!({ "webhookUrl": "https://retrack.webhook.office.com/webhookb2/XXX" });
+        assert_eq!(
+            serde_json::from_value::<TeamsAction>(action_json)?,
+            TeamsAction {
+                webhook_url: "https://retrack.webhook.office.com/webhookb2/XXX".parse()?,
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "webhookUrl": "https://retrack.webhook.office.com/webhookb2/XXX",
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<TeamsAction>(action_json)?,
+            TeamsAction {
+                webhook_url: "https://retrack.webhook.office.com/webhookb2/XXX".parse()?,
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Tracker's action to send a message about the tracker data changes to a Telegram chat via the
+/// Telegram Bot API. The message is formatted with MarkdownV2 and includes the diff between the
+/// previous and current tracker data. Diffs that don't fit into a single Telegram message are
+/// split into several consecutive messages.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TelegramAction {
+    /// Telegram bot token issued by the `@BotFather` (e.g., `123456789:AAE...`).
+    pub token: String,
+
+    /// Unique identifier of the target chat (e.g., `-1001234567890`) or username of the target
+    /// channel (e.g., `@retrack_releases`). The bot must be a member of the chat.
+    pub chat_id: String,
+
+    /// Optional number of milliseconds to wait for Telegram to respond before the delivery is
+    /// considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TelegramAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = TelegramAction {
+            token: "123456789:token".to_string(),
+            chat_id: "-1001234567890".to_string(),
+            timeout: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "token": "123456789:token",
+          "chatId": "-1001234567890"
+        }
+        "###);
+
+        let action = TelegramAction {
+            token: "123456789:token".to_string(),
+            chat_id: "@retrack_releases".to_string(),
+            timeout: Some(Duration::from_millis(2500)),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "token": "123456789:token",
+          "chatId": "@retrack_releases",
+          "timeout": 2500
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "token": "123456789:token", "chatId": "-1001234567890" });
+        assert_eq!(
+            serde_json::from_value::<TelegramAction>(action_json)?,
+            TelegramAction {
+                token: "123456789:token".to_string(),
+                chat_id: "-1001234567890".to_string(),
+                timeout: None,
+            }
+        );
+
+        let action_json = json!({
+            "token": "123456789:token",
+            "chatId": "@retrack_releases",
+            "timeout": 2500
+        });
+        assert_eq!(
+            serde_json::from_value::<TelegramAction>(action_json)?,
+            TelegramAction {
+                token: "123456789:token".to_string(),
+                chat_id: "@retrack_releases".to_string(),
+                timeout: Some(Duration::from_millis(2500)),
+            }
+        );
+
+        Ok(())
+    }
+}
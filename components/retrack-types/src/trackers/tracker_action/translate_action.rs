@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Tracker's action to translate the text within the tracker data to the specified language using
+/// the configured translation API.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateAction {
+    /// ISO 639-1 code of the language to translate the text to, e.g. `en`.
+    pub target_language: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TranslateAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = TranslateAction {
+            target_language: "en".to_string(),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "targetLanguage": "en"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TranslateAction>(
+                &json!({ "targetLanguage": "de" }).to_string()
+            )?,
+            TranslateAction {
+                target_language: "de".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+}
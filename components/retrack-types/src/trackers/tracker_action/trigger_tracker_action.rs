@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Tracker's action to trigger an immediate check of other trackers when the tracker data changes
+/// (e.g., re-check the changelog page when the version page changes). Triggered trackers are
+/// referenced by ID or by tags, and are checked by the scheduler as soon as possible. Only enabled
+/// trackers with a schedule can be triggered.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerTrackerAction {
+    /// Optional list of IDs of the trackers to trigger.
+    pub trackers: Option<Vec<Uuid>>,
+
+    /// Optional list of tags of the trackers to trigger. Trackers that have all the specified tags
+    /// are triggered.
+    pub tags: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TriggerTrackerAction;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let action = TriggerTrackerAction {
+            trackers: Some(vec![uuid!("00000000-0000-0000-0000-000000000001")]),
+            tags: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "trackers": [
+            "00000000-0000-0000-0000-000000000001"
+          ]
+        }
+        "###);
+
+        let action = TriggerTrackerAction {
+            trackers: Some(vec![uuid!("00000000-0000-0000-0000-000000000001")]),
+            tags: Some(vec!["app:changelog".to_string()]),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "trackers": [
+            "00000000-0000-0000-0000-000000000001"
+          ],
+          "tags": [
+            "app:changelog"
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let action_json = json!({ "tags": ["app:changelog"] });
+        assert_eq!(
+            serde_json::from_value::<TriggerTrackerAction>(action_json)?,
+            TriggerTrackerAction {
+                trackers: None,
+                tags: Some(vec!["app:changelog".to_string()]),
+            }
+        );
+
+        let action_json = json!({
+            "trackers": ["00000000-0000-0000-0000-000000000001"],
+            "tags": ["app:changelog"]
+        });
+        assert_eq!(
+            serde_json::from_value::<TriggerTrackerAction>(action_json)?,
+            TriggerTrackerAction {
+                trackers: Some(vec![uuid!("00000000-0000-0000-0000-000000000001")]),
+                tags: Some(vec!["app:changelog".to_string()]),
+            }
+        );
+
+        Ok(())
+    }
+}
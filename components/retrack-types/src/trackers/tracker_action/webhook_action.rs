@@ -1,10 +1,13 @@
+use crate::{scheduler::SchedulerJobRetryStrategy, trackers::TrackerDataDiffFormat};
 use http::{HeaderMap, Method};
 use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
 use url::Url;
 use utoipa::ToSchema;
 
 /// Tracker's action to send an HTTP request.
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -19,18 +22,76 @@ pub struct WebhookAction {
     pub method: Option<Method>,
 
     /// Optional headers to include in the request.
-    #[serde(with = "http_serde::option::header_map", default)]
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
     #[schema(value_type = HashMap<String, String>)]
     pub headers: Option<HeaderMap>,
+
+    /// Optional expectations about the response of the webhook receiver. If the response doesn't
+    /// meet them, the delivery is considered failed and is retried later. If not specified, any
+    /// response with a successful (`2xx`) status is considered delivered.
+    pub expected_response: Option<WebhookExpectedResponse>,
+
+    /// Optional number of milliseconds to wait for the webhook receiver to respond before the
+    /// delivery is considered failed. If not specified, the server default is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub timeout: Option<Duration>,
+
+    /// Optional name of the server secret to sign the requests with (HMAC-SHA256), so that the
+    /// webhook receiver can verify that the request is sent by Retrack and hasn't been tampered
+    /// with. Signed requests include the `X-Retrack-Signature` header in the
+    /// `t=<timestamp>,v1=<signature>` format, where `timestamp` is the Unix time (in seconds) the
+    /// request is sent at, and `signature` is the hex-encoded HMAC-SHA256 of the
+    /// `<timestamp>.<body>` string. To protect against replay attacks, receivers should reject
+    /// requests with the timestamp that differs from the current time by more than a few minutes
+    /// (e.g., 5 minutes).
+    pub signing_secret: Option<String>,
+
+    /// Optional strategy to retry failed deliveries. Once the retry attempts are exhausted, the
+    /// delivery is marked as failed and isn't retried anymore. If not specified, failed deliveries
+    /// are retried until they succeed.
+    pub retry_strategy: Option<SchedulerJobRetryStrategy>,
+
+    /// Optional format of the diff between the previous and the current tracker data to include
+    /// in the request. If specified, the request body is a JSON object with the tracker data in
+    /// the `value` property and the diff in the `diff` property (`null` if there is no previous
+    /// data), instead of the bare tracker data.
+    pub diff_format: Option<TrackerDataDiffFormat>,
+}
+
+/// Expectations about the response of the webhook receiver.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookExpectedResponse {
+    /// Range of the expected HTTP status codes. If not specified, any successful (`2xx`) status is
+    /// expected.
+    pub status: Option<WebhookStatusRange>,
+    /// Regular expression that the response body is expected to match, e.g. `"ok":\s*true`.
+    pub body_pattern: Option<String>,
+}
+
+/// Inclusive range of the HTTP status codes.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookStatusRange {
+    /// The lowest expected status code, e.g. `200`.
+    pub min: u16,
+    /// The highest expected status code, e.g. `204`.
+    pub max: u16,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::WebhookAction;
+    use crate::{
+        scheduler::SchedulerJobRetryStrategy,
+        trackers::{
+            TrackerDataDiffFormat, WebhookAction, WebhookExpectedResponse, WebhookStatusRange,
+        },
+    };
     use http::{header::CONTENT_TYPE, Method};
     use insta::assert_json_snapshot;
     use serde_json::json;
-    use std::collections::HashMap;
+    use std::{collections::HashMap, time::Duration};
     use url::Url;
 
     #[test]
@@ -39,6 +100,11 @@ mod tests {
             url: Url::parse("https://retrack.dev")?,
             method: None,
             headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         };
         assert_json_snapshot!(action, @r###"
         {
@@ -50,6 +116,11 @@ mod tests {
             url: Url::parse("https://retrack.dev")?,
             method: Some(Method::GET),
             headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         };
         assert_json_snapshot!(action, @r###"
         {
@@ -67,6 +138,11 @@ mod tests {
                     .collect::<HashMap<_, _>>())
                     .try_into()?,
             ),
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         };
         assert_json_snapshot!(action, @r###"
         {
@@ -78,6 +154,107 @@ mod tests {
         }
         "###);
 
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: Some(WebhookExpectedResponse {
+                status: Some(WebhookStatusRange { min: 200, max: 204 }),
+                body_pattern: Some(r#""ok":\s*true"#.to_string()),
+            }),
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "url": "https://retrack.dev/",
+          "expectedResponse": {
+            "status": {
+              "min": 200,
+              "max": 204
+            },
+            "bodyPattern": "\"ok\":\\s*true"
+          }
+        }
+        "###);
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: Some(Duration::from_millis(5000)),
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "url": "https://retrack.dev/",
+          "timeout": 5000
+        }
+        "###);
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: Some("WEBHOOK_SECRET".to_string()),
+            retry_strategy: None,
+            diff_format: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "url": "https://retrack.dev/",
+          "signingSecret": "WEBHOOK_SECRET"
+        }
+        "###);
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                interval: Duration::from_secs(60),
+                max_attempts: 3,
+            }),
+            diff_format: None,
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "url": "https://retrack.dev/",
+          "retryStrategy": {
+            "type": "constant",
+            "interval": 60000,
+            "maxAttempts": 3
+          }
+        }
+        "###);
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: Some(TrackerDataDiffFormat::JsonPatch),
+        };
+        assert_json_snapshot!(action, @r###"
+        {
+          "url": "https://retrack.dev/",
+          "diffFormat": "jsonPatch"
+        }
+        "###);
+
         Ok(())
     }
 
@@ -87,6 +264,11 @@ mod tests {
             url: Url::parse("https://retrack.dev")?,
             method: None,
             headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         };
         assert_eq!(
             serde_json::from_str::<WebhookAction>(
@@ -99,6 +281,11 @@ mod tests {
             url: Url::parse("https://retrack.dev")?,
             method: Some(Method::GET),
             headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         };
         assert_eq!(
             serde_json::from_str::<WebhookAction>(
@@ -116,6 +303,11 @@ mod tests {
                     .collect::<HashMap<_, _>>())
                     .try_into()?,
             ),
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
         };
         assert_eq!(
             serde_json::from_str::<WebhookAction>(
@@ -129,6 +321,106 @@ mod tests {
             action
         );
 
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: Some(WebhookExpectedResponse {
+                status: None,
+                body_pattern: Some("accepted".to_string()),
+            }),
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
+        };
+        assert_eq!(
+            serde_json::from_str::<WebhookAction>(
+                &json!({
+                    "url": "https://retrack.dev",
+                    "expectedResponse": { "bodyPattern": "accepted" }
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: Some(Duration::from_millis(5000)),
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: None,
+        };
+        assert_eq!(
+            serde_json::from_str::<WebhookAction>(
+                &json!({ "url": "https://retrack.dev", "timeout": 5000 }).to_string()
+            )?,
+            action
+        );
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: Some("WEBHOOK_SECRET".to_string()),
+            retry_strategy: None,
+            diff_format: None,
+        };
+        assert_eq!(
+            serde_json::from_str::<WebhookAction>(
+                &json!({ "url": "https://retrack.dev", "signingSecret": "WEBHOOK_SECRET" })
+                    .to_string()
+            )?,
+            action
+        );
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                interval: Duration::from_secs(60),
+                max_attempts: 3,
+            }),
+            diff_format: None,
+        };
+        assert_eq!(
+            serde_json::from_str::<WebhookAction>(
+                &json!({
+                    "url": "https://retrack.dev",
+                    "retryStrategy": { "type": "constant", "interval": 60000, "maxAttempts": 3 }
+                })
+                .to_string()
+            )?,
+            action
+        );
+
+        let action = WebhookAction {
+            url: Url::parse("https://retrack.dev")?,
+            method: None,
+            headers: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+            diff_format: Some(TrackerDataDiffFormat::Text),
+        };
+        assert_eq!(
+            serde_json::from_str::<WebhookAction>(
+                &json!({ "url": "https://retrack.dev", "diffFormat": "text" }).to_string()
+            )?,
+            action
+        );
+
         Ok(())
     }
 }
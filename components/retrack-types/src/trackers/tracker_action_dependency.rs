@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Dependency between tracker actions that makes an action execute only if another action, defined
+/// before it, had a specific outcome, e.g. send an email only if the webhook delivery has failed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionDependency {
+    /// Index of the dependent action within the tracker actions.
+    pub action: usize,
+    /// Index of the action the dependent action depends on within the tracker actions.
+    pub depends_on: usize,
+    /// Outcome of the action the dependent action depends on that allows it to execute.
+    pub condition: TrackerActionCondition,
+}
+
+/// Outcome of the tracker action execution.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerActionCondition {
+    /// Action has been executed successfully (e.g., webhook has been delivered).
+    Succeeded,
+    /// Action has been executed, but failed (e.g., webhook delivery has failed).
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerActionCondition, TrackerActionDependency};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActionDependency {
+            action: 1,
+            depends_on: 0,
+            condition: TrackerActionCondition::Failed,
+        }, @r###"
+        {
+          "action": 1,
+          "dependsOn": 0,
+          "condition": "failed"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActionDependency>(
+                json!({ "action": 2, "dependsOn": 1, "condition": "succeeded" })
+            )?,
+            TrackerActionDependency {
+                action: 2,
+                depends_on: 1,
+                condition: TrackerActionCondition::Succeeded,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Digest mode of the tracker action that accumulates data changes and delivers them as a single
+/// aggregated notification on a regular cadence, instead of a notification per change. Only email
+/// and webhook actions can be delivered as digests.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionDigest {
+    /// Index of the action within the tracker actions.
+    pub action: usize,
+    /// How often accumulated changes are delivered.
+    pub cadence: TrackerDigestCadence,
+    /// Optional tag of the tracker to aggregate changes of all trackers sharing this tag into the
+    /// same digest, as long as they are delivered to the same recipients. If not specified, digest
+    /// only includes changes of this tracker.
+    pub tag: Option<String>,
+}
+
+/// Cadence of the tracker action digest. Digests are delivered at the start of every hour or day
+/// (UTC).
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerDigestCadence {
+    /// Digest is delivered every hour.
+    Hourly,
+    /// Digest is delivered every day.
+    Daily,
+}
+
+impl TrackerDigestCadence {
+    /// Returns the period changes are accumulated for before the digest is delivered.
+    pub fn period(&self) -> Duration {
+        match self {
+            Self::Hourly => Duration::from_secs(3600),
+            Self::Daily => Duration::from_secs(86400),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerActionDigest, TrackerDigestCadence};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActionDigest {
+            action: 1,
+            cadence: TrackerDigestCadence::Hourly,
+            tag: None,
+        }, @r###"
+        {
+          "action": 1,
+          "cadence": "hourly"
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerActionDigest {
+            action: 0,
+            cadence: TrackerDigestCadence::Daily,
+            tag: Some("vendor".to_string()),
+        }, @r###"
+        {
+          "action": 0,
+          "cadence": "daily",
+          "tag": "vendor"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActionDigest>(
+                json!({ "action": 1, "cadence": "hourly" })
+            )?,
+            TrackerActionDigest {
+                action: 1,
+                cadence: TrackerDigestCadence::Hourly,
+                tag: None,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerActionDigest>(
+                json!({ "action": 0, "cadence": "daily", "tag": "vendor" })
+            )?,
+            TrackerActionDigest {
+                action: 0,
+                cadence: TrackerDigestCadence::Daily,
+                tag: Some("vendor".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_returns_period() {
+        assert_eq!(
+            TrackerDigestCadence::Hourly.period(),
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            TrackerDigestCadence::Daily.period(),
+            Duration::from_secs(86400)
+        );
+    }
+}
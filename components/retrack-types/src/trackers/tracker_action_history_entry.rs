@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Record of the tracker action execution, kept to find out whether notifications have actually
+/// been delivered (e.g., an email has been sent or a webhook has been called).
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionHistoryEntry {
+    /// Unique history entry ID (UUIDv7).
+    pub id: Uuid,
+    /// ID of the tracker the action belongs to.
+    pub tracker_id: Uuid,
+    /// Index of the action within the tracker actions.
+    pub action: usize,
+    /// Type of the action (e.g., `email` or `webhook`).
+    pub action_type: String,
+    /// ID of the task scheduled to deliver the action notification in the background, if any.
+    pub task_id: Option<Uuid>,
+    /// Status of the action execution.
+    pub status: TrackerActionHistoryStatus,
+    /// Error message, if the action or the delivery of its notification has failed.
+    pub error: Option<String>,
+    /// Time spent executing the action (not including the background delivery).
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub duration: Duration,
+    /// Date and time when the action was executed.
+    #[serde(with = "time::serde::timestamp")]
+    pub executed_at: OffsetDateTime,
+}
+
+/// Status of the tracker action execution.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerActionHistoryStatus {
+    /// Action notification is scheduled, but hasn't been delivered yet (e.g., it's waiting to be
+    /// retried after a failed delivery attempt).
+    Scheduled,
+    /// Action was executed, and its notification, if any, has been delivered.
+    Succeeded,
+    /// Action or the delivery of its notification has failed.
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerActionHistoryEntry, TrackerActionHistoryStatus};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActionHistoryEntry {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            action: 0,
+            action_type: "log".to_string(),
+            task_id: None,
+            status: TrackerActionHistoryStatus::Succeeded,
+            error: None,
+            duration: Duration::from_millis(3),
+            executed_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "trackerId": "00000000-0000-0000-0000-000000000002",
+          "action": 0,
+          "actionType": "log",
+          "status": "succeeded",
+          "duration": 3,
+          "executedAt": 946720800
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerActionHistoryEntry {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            action: 1,
+            action_type: "webhook".to_string(),
+            task_id: Some(uuid!("00000000-0000-0000-0000-000000000003")),
+            status: TrackerActionHistoryStatus::Failed,
+            error: Some("Connection refused.".to_string()),
+            duration: Duration::from_millis(25),
+            executed_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "trackerId": "00000000-0000-0000-0000-000000000002",
+          "action": 1,
+          "actionType": "webhook",
+          "taskId": "00000000-0000-0000-0000-000000000003",
+          "status": "failed",
+          "error": "Connection refused.",
+          "duration": 25,
+          "executedAt": 946720800
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActionHistoryEntry>(json!({
+                "id": "00000000-0000-0000-0000-000000000001",
+                "trackerId": "00000000-0000-0000-0000-000000000002",
+                "action": 1,
+                "actionType": "email",
+                "taskId": "00000000-0000-0000-0000-000000000003",
+                "status": "scheduled",
+                "duration": 25,
+                "executedAt": 946720800
+            }))?,
+            TrackerActionHistoryEntry {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                action: 1,
+                action_type: "email".to_string(),
+                task_id: Some(uuid!("00000000-0000-0000-0000-000000000003")),
+                status: TrackerActionHistoryStatus::Scheduled,
+                error: None,
+                duration: Duration::from_millis(25),
+                executed_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            }
+        );
+
+        Ok(())
+    }
+}
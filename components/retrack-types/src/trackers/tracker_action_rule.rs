@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Declarative rule that defines when the tracker action is executed, e.g. send an email only if
+/// the price has dropped below a threshold, or only if the check has failed. The action is executed
+/// only if all the rule criteria are met, and is skipped otherwise.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionRule {
+    /// Index of the action within the tracker actions.
+    pub action: usize,
+    /// Optional path to the value within the tracker data the rule checks (e.g.,
+    /// `$.releases[0].version`). Supported syntax is a subset of JSONPath. The rule isn't met if
+    /// the value at the path doesn't exist.
+    pub path: Option<String>,
+    /// Optional value the value at the path should be equal to.
+    pub equals: Option<JsonValue>,
+    /// Optional value the value at the path should contain: a substring if the value is a string,
+    /// or an item if the value is an array.
+    pub contains: Option<JsonValue>,
+    /// Optional regular expression the string value at the path should match.
+    pub matches: Option<String>,
+    /// Optional minimum numeric value (inclusive). Numeric strings are treated as numbers, other
+    /// non-numeric values don't meet the rule.
+    pub min: Option<f64>,
+    /// Optional maximum numeric value (inclusive). Numeric strings are treated as numbers, other
+    /// non-numeric values don't meet the rule.
+    pub max: Option<f64>,
+    /// Whether the action should only be executed when the data check fails, i.e. by the workflow
+    /// transition triggered by a failed data check.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub on_failure: bool,
+    /// Whether the action should only be executed for the very first tracker data revision.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub on_first_revision: bool,
+}
+
+// Minimum and maximum values are always finite numbers, they are validated when tracker is created
+// or updated.
+impl Eq for TrackerActionRule {}
+
+impl TrackerActionRule {
+    /// Creates a new rule for the action with the specified index that doesn't have any criteria.
+    pub fn new(action: usize) -> Self {
+        Self {
+            action,
+            path: None,
+            equals: None,
+            contains: None,
+            matches: None,
+            min: None,
+            max: None,
+            on_failure: false,
+            on_first_revision: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerActionRule;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActionRule {
+            path: Some("$.price".to_string()),
+            max: Some(100.0),
+            ..TrackerActionRule::new(1)
+        }, @r###"
+        {
+          "action": 1,
+          "path": "$.price",
+          "max": 100.0
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerActionRule {
+            path: Some("$.releases[0].version".to_string()),
+            equals: Some(json!("1.0.0")),
+            contains: Some(json!("1.")),
+            matches: Some("^\\d+\\.\\d+\\.\\d+$".to_string()),
+            min: Some(1.0),
+            max: Some(2.0),
+            on_failure: true,
+            on_first_revision: true,
+            ..TrackerActionRule::new(0)
+        }, @r###"
+        {
+          "action": 0,
+          "path": "$.releases[0].version",
+          "equals": "1.0.0",
+          "contains": "1.",
+          "matches": "^\\d+\\.\\d+\\.\\d+$",
+          "min": 1.0,
+          "max": 2.0,
+          "onFailure": true,
+          "onFirstRevision": true
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActionRule>(json!({ "action": 2, "onFailure": true }))?,
+            TrackerActionRule {
+                on_failure: true,
+                ..TrackerActionRule::new(2)
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerActionRule>(json!({
+                "action": 0,
+                "path": "$.tags",
+                "contains": "stable",
+                "min": 1
+            }))?,
+            TrackerActionRule {
+                path: Some("$.tags".to_string()),
+                contains: Some(json!("stable")),
+                min: Some(1.0),
+                ..TrackerActionRule::new(0)
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+
+/// Parameters for testing a single tracker action without creating a new tracker data revision.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionTestParams {
+    /// Optional data to execute the action with. If not specified, the data of the latest tracker
+    /// data revision is used, or synthetic test data if the tracker doesn't have any revisions yet.
+    pub data: Option<JsonValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerActionTestParams;
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActionTestParams>(json!({}))?,
+            TrackerActionTestParams { data: None }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TrackerActionTestParams>(json!({
+                "data": { "price": 10 }
+            }))?,
+            TrackerActionTestParams {
+                data: Some(json!({ "price": 10 }))
+            }
+        );
+
+        Ok(())
+    }
+}
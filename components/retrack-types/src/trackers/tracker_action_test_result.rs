@@ -0,0 +1,63 @@
+use crate::trackers::TrackerRunActionStatus;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Result of the tracker action test.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionTestResult {
+    /// Status of the action.
+    pub status: TrackerRunActionStatus,
+    /// Data the action was executed with.
+    pub data: JsonValue,
+    /// Data produced by the action, if the action transforms the tracker data (e.g., translate or
+    /// summarize actions).
+    pub output: Option<JsonValue>,
+    /// Error message, if the action failed.
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerActionTestResult, TrackerRunActionStatus};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActionTestResult {
+            status: TrackerRunActionStatus::Succeeded,
+            data: json!({ "price": 10 }),
+            output: Some(json!({ "price": "10" })),
+            error: None,
+        }, @r###"
+        {
+          "status": "succeeded",
+          "data": {
+            "price": 10
+          },
+          "output": {
+            "price": "10"
+          }
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerActionTestResult {
+            status: TrackerRunActionStatus::Failed,
+            data: json!("test"),
+            output: None,
+            error: Some("Connection refused.".to_string()),
+        }, @r###"
+        {
+          "status": "failed",
+          "data": "test",
+          "error": "Connection refused."
+        }
+        "###);
+
+        Ok(())
+    }
+}
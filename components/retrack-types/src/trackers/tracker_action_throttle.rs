@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Throttling of the tracker action that keeps flappy trackers from flooding the recipients, e.g.
+/// send at most one email per hour, or don't send the same data twice. Throttled action is skipped.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActionThrottle {
+    /// Index of the action within the tracker actions.
+    pub action: usize,
+    /// Optional minimum number of milliseconds between the consecutive executions of the action.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub interval: Option<Duration>,
+    /// Whether to skip the action if the tracker data is the same as the data the action was last
+    /// executed with.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deduplicate: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerActionThrottle;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActionThrottle {
+            action: 1,
+            interval: Some(Duration::from_secs(3600)),
+            deduplicate: false,
+        }, @r###"
+        {
+          "action": 1,
+          "interval": 3600000
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerActionThrottle {
+            action: 0,
+            interval: Some(Duration::from_secs(60)),
+            deduplicate: true,
+        }, @r###"
+        {
+          "action": 0,
+          "interval": 60000,
+          "deduplicate": true
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActionThrottle>(
+                json!({ "action": 1, "interval": 3600000 })
+            )?,
+            TrackerActionThrottle {
+                action: 1,
+                interval: Some(Duration::from_secs(3600)),
+                deduplicate: false,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerActionThrottle>(
+                json!({ "action": 0, "deduplicate": true })
+            )?,
+            TrackerActionThrottle {
+                action: 0,
+                interval: None,
+                deduplicate: true,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use time::Weekday;
+use utoipa::ToSchema;
+
+/// Day of the week (UTC) when the tracker is allowed to run.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerActiveDay {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<Weekday> for TrackerActiveDay {
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Monday => Self::Monday,
+            Weekday::Tuesday => Self::Tuesday,
+            Weekday::Wednesday => Self::Wednesday,
+            Weekday::Thursday => Self::Thursday,
+            Weekday::Friday => Self::Friday,
+            Weekday::Saturday => Self::Saturday,
+            Weekday::Sunday => Self::Sunday,
+        }
+    }
+}
+
+impl From<TrackerActiveDay> for Weekday {
+    fn from(day: TrackerActiveDay) -> Self {
+        match day {
+            TrackerActiveDay::Monday => Self::Monday,
+            TrackerActiveDay::Tuesday => Self::Tuesday,
+            TrackerActiveDay::Wednesday => Self::Wednesday,
+            TrackerActiveDay::Thursday => Self::Thursday,
+            TrackerActiveDay::Friday => Self::Friday,
+            TrackerActiveDay::Saturday => Self::Saturday,
+            TrackerActiveDay::Sunday => Self::Sunday,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerActiveDay;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::Weekday;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(vec![TrackerActiveDay::Monday, TrackerActiveDay::Sunday], @r###"
+        [
+          "monday",
+          "sunday"
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<Vec<TrackerActiveDay>>(json!(["friday", "saturday"]))?,
+            vec![TrackerActiveDay::Friday, TrackerActiveDay::Saturday]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_weekday() {
+        for weekday in [Weekday::Monday, Weekday::Wednesday, Weekday::Sunday] {
+            assert_eq!(Weekday::from(TrackerActiveDay::from(weekday)), weekday);
+        }
+    }
+}
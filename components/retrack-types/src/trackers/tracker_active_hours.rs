@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Range of hours of the day (UTC) within which the tracker is allowed to run, e.g. business
+/// hours. If `start` is greater than `end`, the range spans midnight (e.g., from 22 to 6).
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerActiveHours {
+    /// Hour of the day (`0`-`23`) when the tracker becomes active, inclusive.
+    pub start: u8,
+    /// Hour of the day (`0`-`23`) when the tracker becomes inactive, exclusive.
+    pub end: u8,
+}
+
+impl TrackerActiveHours {
+    /// Checks whether the specified hour of the day falls within the range.
+    pub fn contains(&self, hour: u8) -> bool {
+        if self.start <= self.end {
+            hour >= self.start && hour < self.end
+        } else {
+            hour >= self.start || hour < self.end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerActiveHours;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerActiveHours { start: 9, end: 17 }, @r###"
+        {
+          "start": 9,
+          "end": 17
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerActiveHours>(json!({ "start": 22, "end": 6 }))?,
+            TrackerActiveHours { start: 22, end: 6 }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_checks_hours() {
+        let business_hours = TrackerActiveHours { start: 9, end: 17 };
+        assert!(business_hours.contains(9));
+        assert!(business_hours.contains(16));
+        assert!(!business_hours.contains(17));
+        assert!(!business_hours.contains(8));
+
+        let night_hours = TrackerActiveHours { start: 22, end: 6 };
+        assert!(night_hours.contains(22));
+        assert!(night_hours.contains(0));
+        assert!(night_hours.contains(5));
+        assert!(!night_hours.contains(6));
+        assert!(!night_hours.contains(12));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::trackers::{Tracker, TrackerDataRevision};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Self-contained snapshot of the tracker that includes both its definition and the history of the
+/// data revisions. Bundles are used to hand off trackers between teams or Retrack instances.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerBundle {
+    /// Tracker definition.
+    pub tracker: Tracker,
+    /// Tracker data revisions, from the oldest to the newest.
+    pub revisions: Vec<TrackerDataRevision>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::MockTrackerBuilder,
+        trackers::{TrackerBundle, TrackerDataRevision, TrackerDataValue},
+    };
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let bundle = TrackerBundle {
+            tracker: MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                "some-name",
+                3,
+            )?
+            .build(),
+            revisions: vec![TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                data: TrackerDataValue::new(json!("some-data")),
+                // January 1, 2000 10:00:00
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: Some(1),
+            }],
+        };
+        assert_json_snapshot!(bundle, @r###"
+        {
+          "tracker": {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "name": "some-name",
+            "enabled": true,
+            "archived": false,
+            "target": {
+              "type": "page",
+              "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
+              "userAgent": "Retrack/1.0.0"
+            },
+            "config": {
+              "revisions": 3,
+              "timeout": 2000
+            },
+            "tags": [],
+            "actions": [
+              {
+                "type": "log"
+              }
+            ],
+            "createdAt": 946720800,
+            "updatedAt": 946720810
+          },
+          "revisions": [
+            {
+              "id": "00000000-0000-0000-0000-000000000002",
+              "trackerId": "00000000-0000-0000-0000-000000000001",
+              "data": {
+                "original": "some-data"
+              },
+              "createdAt": 946720800,
+              "sequence": 1
+            }
+          ]
+        }
+        "###);
+
+        assert_eq!(
+            serde_json::from_str::<TrackerBundle>(&serde_json::to_string(&bundle)?)?,
+            bundle
+        );
+
+        Ok(())
+    }
+}
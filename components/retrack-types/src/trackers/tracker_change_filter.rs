@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Declarative filter that defines which data changes produce a new tracker data revision based on
+/// the direction of change of a specific value, e.g. only when the price has decreased.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerChangeFilter {
+    /// Path to the value within the tracker data, e.g. `$.price` or `$.releases[0].version`.
+    pub path: String,
+    /// Direction of the change that produces a new revision.
+    pub direction: TrackerChangeDirection,
+    /// Comparator used to compare the previous and the new values.
+    pub comparator: TrackerChangeComparator,
+}
+
+/// Direction of the value change.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerChangeDirection {
+    /// The new value is greater than the previous one.
+    Increased,
+    /// The new value is less than the previous one.
+    Decreased,
+}
+
+/// Comparator used to compare values.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerChangeComparator {
+    /// Values are compared as numbers (JSON numbers or numeric strings).
+    Number,
+    /// Values are compared as semantic versions (e.g., `1.2.3` or `v1.2.3-beta.1`).
+    Semver,
+    /// Values are compared as dates (RFC 3339 strings or Unix timestamps in seconds).
+    Date,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerChangeComparator, TrackerChangeDirection, TrackerChangeFilter};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerChangeFilter {
+            path: "$.price".to_string(),
+            direction: TrackerChangeDirection::Decreased,
+            comparator: TrackerChangeComparator::Number,
+        }, @r###"
+        {
+          "path": "$.price",
+          "direction": "decreased",
+          "comparator": "number"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerChangeFilter>(
+                json!({ "path": "$.version", "direction": "increased", "comparator": "semver" })
+            )?,
+            TrackerChangeFilter {
+                path: "$.version".to_string(),
+                direction: TrackerChangeDirection::Increased,
+                comparator: TrackerChangeComparator::Semver,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerChangeFilter>(
+                json!({ "path": "$.updatedAt", "direction": "decreased", "comparator": "date" })
+            )?,
+            TrackerChangeFilter {
+                path: "$.updatedAt".to_string(),
+                direction: TrackerChangeDirection::Decreased,
+                comparator: TrackerChangeComparator::Date,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,17 @@
-use crate::scheduler::SchedulerJobConfig;
+use crate::{
+    scheduler::SchedulerJobConfig,
+    trackers::{
+        TrackerActionDependency, TrackerActionDigest, TrackerActionRule, TrackerActionThrottle,
+        TrackerActiveDay, TrackerActiveHours, TrackerChangeFilter, TrackerEscalation,
+        TrackerMaxContentSize, TrackerQuarantine, TrackerSemanticFilter, TrackerSeverityRouting,
+        TrackerWorkflow,
+    },
+};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
 use std::time::Duration;
+use time::{OffsetDateTime, UtcOffset};
+use url::Url;
 use utoipa::ToSchema;
 
 #[serde_as]
@@ -16,6 +26,72 @@ pub struct TrackerConfig {
     pub timeout: Option<Duration>,
     /// Configuration of the job that triggers tracker, if configured.
     pub job: Option<SchedulerJobConfig>,
+    /// Whether to treat a drastic change in the shape of the extracted data (e.g., suddenly empty
+    /// array or missing key) as a failed check. Such a change usually means that the target layout
+    /// has changed and the extractor is broken. Tracker is flagged regardless of this setting.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fail_on_shape_deviation: bool,
+    /// Optional filter that defines which data changes produce a new revision based on the
+    /// direction of change of a specific value. If not set, any change produces a new revision.
+    pub change_filter: Option<TrackerChangeFilter>,
+    /// Optional filter that drops data changes that don't alter the meaning of the data, based on
+    /// the text embeddings similarity. Requires embeddings API to be configured.
+    pub semantic_filter: Option<TrackerSemanticFilter>,
+    /// Whether to treat the very first data revision as a baseline and not execute tracker
+    /// actions for it, since there is nothing to compare the initial data with yet.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_first_revision_actions: bool,
+    /// Optional number of consecutive checks the changed data should be observed in before it's
+    /// persisted as a new revision and tracker actions are executed. Helps to ignore transient
+    /// changes caused by A/B tests or stale CDN caches. If not set, any change is accepted at once.
+    pub confirmations: Option<usize>,
+    /// Optional range of hours of the day (UTC) within which the scheduled tracker is allowed to
+    /// run. Scheduled runs outside of this range are skipped.
+    pub active_hours: Option<TrackerActiveHours>,
+    /// Optional list of days of the week (UTC) when the scheduled tracker is allowed to run.
+    /// Scheduled runs on other days are skipped.
+    pub active_days: Option<Vec<TrackerActiveDay>>,
+    /// Optional maximum size of the content the tracker accepts. The policy is applied to the
+    /// content before it's passed to the scripts and before it's stored as a new revision.
+    pub max_content_size: Option<TrackerMaxContentSize>,
+    /// Optional maximum number of tracker actions executed concurrently. Actions that transform
+    /// the tracker data are always executed on their own, after all preceding actions. If not set,
+    /// actions are executed one by one in the order they are defined.
+    pub action_concurrency: Option<usize>,
+    /// Optional list of dependencies between tracker actions. Dependent action is executed only if
+    /// the action it depends on had the expected outcome.
+    pub action_dependencies: Option<Vec<TrackerActionDependency>>,
+    /// Optional list of rules that define when tracker actions are executed based on the tracker
+    /// data or the outcome of the data check, e.g. notify only about significant changes. Action
+    /// with rules is executed only if all its rules are met.
+    pub action_rules: Option<Vec<TrackerActionRule>>,
+    /// Optional routing table that executes tracker actions based on the severity of the data
+    /// change, e.g. page on-call only when the change is critical.
+    pub severity_routing: Option<TrackerSeverityRouting>,
+    /// Optional list of throttles that limit how often tracker actions are executed, e.g. to keep
+    /// flappy trackers from flooding the recipients.
+    pub action_throttles: Option<Vec<TrackerActionThrottle>>,
+    /// Optional list of digests that accumulate data changes for tracker actions and deliver them
+    /// as a single aggregated notification per tracker or tag on a regular cadence.
+    pub action_digests: Option<Vec<TrackerActionDigest>>,
+    /// Optional workflow that moves the tracker between states based on the outcome of its data
+    /// checks. Current state of the tracker is exposed as the tracker `state`.
+    pub workflow: Option<TrackerWorkflow>,
+    /// Optional escalation that executes tracker actions once the tracker has failed a certain
+    /// number of consecutive data checks, e.g. notify the owner first and page on-call later.
+    pub escalation: Option<TrackerEscalation>,
+    /// Optional rules that put suspicious data revisions into quarantine instead of the revisions
+    /// history, keeping the history clean for downstream consumers.
+    pub quarantine: Option<TrackerQuarantine>,
+    /// Optional URL of the runbook that describes how to handle the tracker failures. The URL is
+    /// included into the failure notifications, and takes precedence over the runbook configured
+    /// for the tracker tags on the server.
+    pub runbook_url: Option<Url>,
+    /// Optional maximum age of the tracker data revisions (in milliseconds). Revisions older than
+    /// that are removed, in addition to the revisions beyond the `revisions` limit. If not set, the
+    /// server default is used, if any.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub max_revision_age: Option<Duration>,
 }
 
 impl Default for TrackerConfig {
@@ -24,16 +100,65 @@ impl Default for TrackerConfig {
             revisions: 3,
             timeout: None,
             job: None,
+            fail_on_shape_deviation: false,
+            change_filter: None,
+            semantic_filter: None,
+            skip_first_revision_actions: false,
+            confirmations: None,
+            active_hours: None,
+            active_days: None,
+            max_content_size: None,
+            action_concurrency: None,
+            action_dependencies: None,
+            action_rules: None,
+            severity_routing: None,
+            action_throttles: None,
+            action_digests: None,
+            workflow: None,
+            escalation: None,
+            quarantine: None,
+            runbook_url: None,
+            max_revision_age: None,
         }
     }
 }
 
+impl TrackerConfig {
+    /// Checks whether the tracker is allowed to run at the specified time according to its active
+    /// hours and days, if any.
+    pub fn is_active_at(&self, at: OffsetDateTime) -> bool {
+        let at = at.to_offset(UtcOffset::UTC);
+        let is_active_hour = self
+            .active_hours
+            .as_ref()
+            .is_none_or(|active_hours| active_hours.contains(at.hour()));
+        let is_active_day = self
+            .active_days
+            .as_ref()
+            .is_none_or(|active_days| active_days.contains(&at.weekday().into()));
+        is_active_hour && is_active_day
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{scheduler::SchedulerJobConfig, trackers::TrackerConfig};
+    use crate::{
+        scheduler::SchedulerJobConfig,
+        trackers::{
+            TrackerActionCondition, TrackerActionDependency, TrackerActionDigest,
+            TrackerActionRule, TrackerActionThrottle, TrackerActiveDay, TrackerActiveHours,
+            TrackerConfig, TrackerContentSizePolicy, TrackerDataAssertion,
+            TrackerDataAssertionValueType, TrackerDigestCadence, TrackerEscalation,
+            TrackerEscalationStep, TrackerMaxContentSize, TrackerQuarantine, TrackerSemanticFilter,
+            TrackerSeverity, TrackerSeverityRoute, TrackerSeverityRouting, TrackerWorkflow,
+            TrackerWorkflowEvent, TrackerWorkflowTransition,
+        },
+    };
     use insta::assert_json_snapshot;
     use serde_json::json;
     use std::time::Duration;
+    use time::OffsetDateTime;
+    use url::Url;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
@@ -51,6 +176,73 @@ mod tests {
                 schedule: "1 2 3 4 5 6 2035".to_string(),
                 retry_strategy: None,
             }),
+            fail_on_shape_deviation: true,
+            change_filter: None,
+            semantic_filter: Some(TrackerSemanticFilter { threshold: 0.95 }),
+            skip_first_revision_actions: true,
+            confirmations: Some(3),
+            active_hours: Some(TrackerActiveHours { start: 9, end: 17 }),
+            active_days: Some(vec![TrackerActiveDay::Monday, TrackerActiveDay::Friday]),
+            max_content_size: Some(TrackerMaxContentSize {
+                size: 1048576,
+                policy: TrackerContentSizePolicy::Truncate,
+            }),
+            action_concurrency: Some(2),
+            action_dependencies: Some(vec![TrackerActionDependency {
+                action: 1,
+                depends_on: 0,
+                condition: TrackerActionCondition::Failed,
+            }]),
+            action_rules: Some(vec![TrackerActionRule {
+                path: Some("$.version".to_string()),
+                matches: Some("^1\\.".to_string()),
+                ..TrackerActionRule::new(0)
+            }]),
+            severity_routing: Some(TrackerSeverityRouting {
+                path: "$.severity".to_string(),
+                default_severity: TrackerSeverity::Minor,
+                routes: vec![TrackerSeverityRoute {
+                    severity: TrackerSeverity::Critical,
+                    actions: vec![1],
+                }],
+            }),
+            action_throttles: Some(vec![TrackerActionThrottle {
+                action: 1,
+                interval: Some(Duration::from_secs(3600)),
+                deduplicate: true,
+            }]),
+            action_digests: Some(vec![TrackerActionDigest {
+                action: 1,
+                cadence: TrackerDigestCadence::Daily,
+                tag: Some("vendor".to_string()),
+            }]),
+            workflow: Some(TrackerWorkflow {
+                initial_state: "resolved".to_string(),
+                transitions: vec![TrackerWorkflowTransition {
+                    on: TrackerWorkflowEvent::Failure,
+                    from: Some(vec!["resolved".to_string()]),
+                    to: "open".to_string(),
+                    actions: Some(vec![1]),
+                }],
+            }),
+            escalation: Some(TrackerEscalation {
+                steps: vec![TrackerEscalationStep {
+                    failures: 3,
+                    actions: vec![1],
+                }],
+            }),
+            quarantine: Some(TrackerQuarantine {
+                shape_deviation: true,
+                assertions: Some(vec![TrackerDataAssertion {
+                    path: "$.version".to_string(),
+                    value_type: Some(TrackerDataAssertionValueType::String),
+                    not_empty: true,
+                    min: None,
+                    max: None,
+                }]),
+            }),
+            runbook_url: Some(Url::parse("https://retrack.dev/runbooks/tracker")?),
+            max_revision_age: Some(Duration::from_secs(604800)),
         };
         assert_json_snapshot!(config, @r###"
         {
@@ -58,7 +250,103 @@ mod tests {
           "timeout": 2500,
           "job": {
             "schedule": "1 2 3 4 5 6 2035"
-          }
+          },
+          "failOnShapeDeviation": true,
+          "semanticFilter": {
+            "threshold": 0.95
+          },
+          "skipFirstRevisionActions": true,
+          "confirmations": 3,
+          "activeHours": {
+            "start": 9,
+            "end": 17
+          },
+          "activeDays": [
+            "monday",
+            "friday"
+          ],
+          "maxContentSize": {
+            "size": 1048576,
+            "policy": "truncate"
+          },
+          "actionConcurrency": 2,
+          "actionDependencies": [
+            {
+              "action": 1,
+              "dependsOn": 0,
+              "condition": "failed"
+            }
+          ],
+          "actionRules": [
+            {
+              "action": 0,
+              "path": "$.version",
+              "matches": "^1\\."
+            }
+          ],
+          "severityRouting": {
+            "path": "$.severity",
+            "defaultSeverity": "minor",
+            "routes": [
+              {
+                "severity": "critical",
+                "actions": [
+                  1
+                ]
+              }
+            ]
+          },
+          "actionThrottles": [
+            {
+              "action": 1,
+              "interval": 3600000,
+              "deduplicate": true
+            }
+          ],
+          "actionDigests": [
+            {
+              "action": 1,
+              "cadence": "daily",
+              "tag": "vendor"
+            }
+          ],
+          "workflow": {
+            "initialState": "resolved",
+            "transitions": [
+              {
+                "on": "failure",
+                "from": [
+                  "resolved"
+                ],
+                "to": "open",
+                "actions": [
+                  1
+                ]
+              }
+            ]
+          },
+          "escalation": {
+            "steps": [
+              {
+                "failures": 3,
+                "actions": [
+                  1
+                ]
+              }
+            ]
+          },
+          "quarantine": {
+            "shapeDeviation": true,
+            "assertions": [
+              {
+                "path": "$.version",
+                "valueType": "string",
+                "notEmpty": true
+              }
+            ]
+          },
+          "runbookUrl": "https://retrack.dev/runbooks/tracker",
+          "maxRevisionAge": 604800000
         }
         "###);
 
@@ -71,6 +359,25 @@ mod tests {
             revisions: 3,
             timeout: None,
             job: None,
+            fail_on_shape_deviation: false,
+            change_filter: None,
+            semantic_filter: None,
+            skip_first_revision_actions: false,
+            confirmations: None,
+            active_hours: None,
+            active_days: None,
+            max_content_size: None,
+            action_concurrency: None,
+            action_dependencies: None,
+            action_rules: None,
+            severity_routing: None,
+            action_throttles: None,
+            action_digests: None,
+            workflow: None,
+            escalation: None,
+            quarantine: None,
+            runbook_url: None,
+            max_revision_age: None,
         };
         assert_eq!(
             serde_json::from_str::<TrackerConfig>(&json!({ "revisions": 3 }).to_string())?,
@@ -84,6 +391,73 @@ mod tests {
                 schedule: "1 2 3 4 5 6 2035".to_string(),
                 retry_strategy: None,
             }),
+            fail_on_shape_deviation: true,
+            change_filter: None,
+            semantic_filter: Some(TrackerSemanticFilter { threshold: 0.95 }),
+            skip_first_revision_actions: true,
+            confirmations: Some(3),
+            active_hours: Some(TrackerActiveHours { start: 9, end: 17 }),
+            active_days: Some(vec![TrackerActiveDay::Monday, TrackerActiveDay::Friday]),
+            max_content_size: Some(TrackerMaxContentSize {
+                size: 1048576,
+                policy: TrackerContentSizePolicy::Truncate,
+            }),
+            action_concurrency: Some(2),
+            action_dependencies: Some(vec![TrackerActionDependency {
+                action: 1,
+                depends_on: 0,
+                condition: TrackerActionCondition::Failed,
+            }]),
+            action_rules: Some(vec![TrackerActionRule {
+                path: Some("$.version".to_string()),
+                matches: Some("^1\\.".to_string()),
+                ..TrackerActionRule::new(0)
+            }]),
+            severity_routing: Some(TrackerSeverityRouting {
+                path: "$.severity".to_string(),
+                default_severity: TrackerSeverity::Minor,
+                routes: vec![TrackerSeverityRoute {
+                    severity: TrackerSeverity::Critical,
+                    actions: vec![1],
+                }],
+            }),
+            action_throttles: Some(vec![TrackerActionThrottle {
+                action: 1,
+                interval: Some(Duration::from_secs(3600)),
+                deduplicate: true,
+            }]),
+            action_digests: Some(vec![TrackerActionDigest {
+                action: 1,
+                cadence: TrackerDigestCadence::Daily,
+                tag: Some("vendor".to_string()),
+            }]),
+            workflow: Some(TrackerWorkflow {
+                initial_state: "resolved".to_string(),
+                transitions: vec![TrackerWorkflowTransition {
+                    on: TrackerWorkflowEvent::Failure,
+                    from: Some(vec!["resolved".to_string()]),
+                    to: "open".to_string(),
+                    actions: Some(vec![1]),
+                }],
+            }),
+            escalation: Some(TrackerEscalation {
+                steps: vec![TrackerEscalationStep {
+                    failures: 3,
+                    actions: vec![1],
+                }],
+            }),
+            quarantine: Some(TrackerQuarantine {
+                shape_deviation: true,
+                assertions: Some(vec![TrackerDataAssertion {
+                    path: "$.version".to_string(),
+                    value_type: Some(TrackerDataAssertionValueType::String),
+                    not_empty: true,
+                    min: None,
+                    max: None,
+                }]),
+            }),
+            runbook_url: Some(Url::parse("https://retrack.dev/runbooks/tracker")?),
+            max_revision_age: Some(Duration::from_secs(604800)),
         };
         assert_eq!(
             serde_json::from_str::<TrackerConfig>(
@@ -91,7 +465,35 @@ mod tests {
                     "revisions": 3,
                     "timeout": 2500,
                     "headers": { "cookie": "my-cookie" },
-                    "job": { "schedule": "1 2 3 4 5 6 2035" }
+                    "job": { "schedule": "1 2 3 4 5 6 2035" },
+                    "failOnShapeDeviation": true,
+                    "semanticFilter": { "threshold": 0.95 },
+                    "skipFirstRevisionActions": true,
+                    "confirmations": 3,
+                    "activeHours": { "start": 9, "end": 17 },
+                    "activeDays": ["monday", "friday"],
+                    "maxContentSize": { "size": 1048576, "policy": "truncate" },
+                    "actionConcurrency": 2,
+                    "actionDependencies": [{ "action": 1, "dependsOn": 0, "condition": "failed" }],
+                    "actionRules": [{ "action": 0, "path": "$.version", "matches": "^1\\." }],
+                    "severityRouting": {
+                        "path": "$.severity",
+                        "defaultSeverity": "minor",
+                        "routes": [{ "severity": "critical", "actions": [1] }]
+                    },
+                    "actionThrottles": [{ "action": 1, "interval": 3600000, "deduplicate": true }],
+                    "actionDigests": [{ "action": 1, "cadence": "daily", "tag": "vendor" }],
+                    "workflow": {
+                        "initialState": "resolved",
+                        "transitions": [{ "on": "failure", "from": ["resolved"], "to": "open", "actions": [1] }]
+                    },
+                    "escalation": { "steps": [{ "failures": 3, "actions": [1] }] },
+                    "quarantine": {
+                        "shapeDeviation": true,
+                        "assertions": [{ "path": "$.version", "valueType": "string", "notEmpty": true }]
+                    },
+                    "runbookUrl": "https://retrack.dev/runbooks/tracker",
+                    "maxRevisionAge": 604800000
                 })
                 .to_string()
             )?,
@@ -100,4 +502,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn properly_checks_active_time() -> anyhow::Result<()> {
+        // Monday, 2024-01-01 10:00:00 UTC.
+        let monday_morning = OffsetDateTime::from_unix_timestamp(1704103200)?;
+        // Saturday, 2024-01-06 20:00:00 UTC.
+        let saturday_evening = OffsetDateTime::from_unix_timestamp(1704571200)?;
+
+        let config = TrackerConfig::default();
+        assert!(config.is_active_at(monday_morning));
+        assert!(config.is_active_at(saturday_evening));
+
+        let config = TrackerConfig {
+            active_hours: Some(TrackerActiveHours { start: 9, end: 17 }),
+            ..Default::default()
+        };
+        assert!(config.is_active_at(monday_morning));
+        assert!(!config.is_active_at(saturday_evening));
+
+        let config = TrackerConfig {
+            active_days: Some(vec![TrackerActiveDay::Saturday, TrackerActiveDay::Sunday]),
+            ..Default::default()
+        };
+        assert!(!config.is_active_at(monday_morning));
+        assert!(config.is_active_at(saturday_evening));
+
+        let config = TrackerConfig {
+            active_hours: Some(TrackerActiveHours { start: 18, end: 2 }),
+            active_days: Some(vec![TrackerActiveDay::Saturday]),
+            ..Default::default()
+        };
+        assert!(!config.is_active_at(monday_morning));
+        assert!(config.is_active_at(saturday_evening));
+        assert!(!config.is_active_at(saturday_evening.replace_hour(12)?));
+
+        Ok(())
+    }
 }
@@ -53,6 +53,10 @@ mod tests {
                 params: None,
                 user_agent: None,
                 ignore_https_errors: false,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: Default::default(),
             tags: vec![],
@@ -83,6 +87,10 @@ mod tests {
                 params: None,
                 user_agent: None,
                 ignore_https_errors: false,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 10,
@@ -116,6 +124,10 @@ mod tests {
                 params: None,
                 user_agent: None,
                 ignore_https_errors: false,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 3,
@@ -151,6 +163,10 @@ mod tests {
                 params: Some(json!({ "param": "value" })),
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 3,
@@ -164,12 +180,36 @@ mod tests {
                         max_attempts: 5,
                     })
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             },
             tags: vec!["tag".to_string()],
             actions: vec![TrackerAction::ServerLog, TrackerAction::Webhook(WebhookAction {
                 url: "https://retrack.dev".parse()?,
                 method: None,
                 headers: None,
+                expected_response: None,
+                timeout: None,
+                signing_secret: None,
+                retry_strategy: None,
+                diff_format: None,
             })],
         };
         assert_eq!(
@@ -218,6 +258,10 @@ mod tests {
                     params: None,
                     user_agent: None,
                     ignore_https_errors: false,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 }),
                 config: Default::default(),
                 tags: vec![],
@@ -249,6 +293,10 @@ mod tests {
                     params: None,
                     user_agent: None,
                     ignore_https_errors: false,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 }),
                 config: TrackerConfig {
                     revisions: 10,
@@ -284,6 +332,10 @@ mod tests {
                     params: None,
                     user_agent: None,
                     ignore_https_errors: false,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 }),
                 config: TrackerConfig {
                     revisions: 3,
@@ -334,6 +386,10 @@ mod tests {
                     params: Some(json!({ "param": "value" })),
                     user_agent: Some("Retrack/1.0.0".to_string()),
                     ignore_https_errors: true,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 }),
                 config: TrackerConfig {
                     revisions: 3,
@@ -347,12 +403,36 @@ mod tests {
                             max_attempts: 5,
                         })
                     }),
+                    fail_on_shape_deviation: false,
+                    change_filter: None,
+                    semantic_filter: None,
+                    skip_first_revision_actions: false,
+                    confirmations: None,
+                    active_hours: None,
+                    active_days: None,
+                    max_content_size: None,
+                    action_concurrency: None,
+                    action_dependencies: None,
+                    action_rules: None,
+                    severity_routing: None,
+                    action_throttles: None,
+                    action_digests: None,
+                    workflow: None,
+                    escalation: None,
+                    quarantine: None,
+                    runbook_url: None,
+                    max_revision_age: None,
                 },
                 tags: vec!["tag".to_string()],
                 actions: vec![TrackerAction::ServerLog, TrackerAction::Webhook(WebhookAction {
                     url: "https://retrack.dev".parse()?,
                     method: None,
                     headers: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
+                    diff_format: None,
                 })],
             }
         );
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Format of the diff between two tracker data revisions.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerDataDiffFormat {
+    /// Unified diff of the pretty-printed data, as a string.
+    #[default]
+    Text,
+    /// JSON Patch (RFC 6902), as an array of operations (`add`, `remove` and `replace`) that
+    /// transform the previous data into the current one.
+    JsonPatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerDataDiffFormat;
+    use serde_json::json;
+
+    #[test]
+    fn serialization_and_deserialization() -> anyhow::Result<()> {
+        for (format, value) in [
+            (TrackerDataDiffFormat::Text, json!("text")),
+            (TrackerDataDiffFormat::JsonPatch, json!("jsonPatch")),
+        ] {
+            assert_eq!(serde_json::to_value(format)?, value);
+            assert_eq!(
+                serde_json::from_value::<TrackerDataDiffFormat>(value)?,
+                format
+            );
+        }
+
+        assert_eq!(
+            TrackerDataDiffFormat::default(),
+            TrackerDataDiffFormat::Text
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::trackers::TlsSessionInfo;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Describes how the tracker data revision was retrieved from the target.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerDataProvenance {
+    /// TLS session details for every target request, in the same order as requests were sent.
+    /// Requests that weren't sent over TLS don't have any details.
+    pub tls: Option<Vec<Option<TlsSessionInfo>>>,
+    /// Charsets of the target responses that were transcoded to UTF-8, in the same order as
+    /// requests were sent (e.g., `windows-1252` for responses served in `ISO-8859-1`). Responses
+    /// that didn't need transcoding don't have a charset.
+    pub charsets: Option<Vec<Option<String>>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TlsSessionInfo, TrackerDataProvenance};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerDataProvenance::default(), @"{}");
+        assert_json_snapshot!(TrackerDataProvenance {
+            tls: Some(vec![
+                Some(TlsSessionInfo {
+                    protocol: "HTTP/2.0".to_string(),
+                    certificate_fingerprint: "ab01".to_string(),
+                    certificate_expires_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                }),
+                None
+            ]),
+            charsets: Some(vec![None, Some("windows-1252".to_string())]),
+        }, @r###"
+        {
+          "tls": [
+            {
+              "protocol": "HTTP/2.0",
+              "certificateFingerprint": "ab01",
+              "certificateExpiresAt": 946720800
+            },
+            null
+          ],
+          "charsets": [
+            null,
+            "windows-1252"
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerDataProvenance>(json!({}))?,
+            TrackerDataProvenance::default()
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerDataProvenance>(json!({
+                "tls": [null, {
+                    "protocol": "HTTP/1.1",
+                    "certificateFingerprint": "ab01",
+                    "certificateExpiresAt": 946720800
+                }],
+                "charsets": ["windows-1252", null]
+            }))?,
+            TrackerDataProvenance {
+                tls: Some(vec![
+                    None,
+                    Some(TlsSessionInfo {
+                        protocol: "HTTP/1.1".to_string(),
+                        certificate_fingerprint: "ab01".to_string(),
+                        certificate_expires_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    })
+                ]),
+                charsets: Some(vec![Some("windows-1252".to_string()), None]),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
-use crate::trackers::TrackerDataValue;
+use crate::trackers::{TrackerDataProvenance, TrackerDataValue};
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 use time::OffsetDateTime;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Represents a tracker data revision.
+#[skip_serializing_none]
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TrackerDataRevision<TValue = serde_json::Value> {
@@ -18,11 +20,18 @@ pub struct TrackerDataRevision<TValue = serde_json::Value> {
     /// Timestamp indicating when data was fetched.
     #[serde(with = "time::serde::timestamp")]
     pub created_at: OffsetDateTime,
+    /// Optional details about how data was retrieved from the target (e.g., TLS session details).
+    pub provenance: Option<TrackerDataProvenance>,
+    /// Monotonically increasing per-tracker sequence number assigned when revision is persisted.
+    /// Consumers can rely on it to detect missed or out-of-order revisions.
+    pub sequence: Option<u64>,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::{TrackerDataRevision, TrackerDataValue};
+    use crate::trackers::{
+        TlsSessionInfo, TrackerDataProvenance, TrackerDataRevision, TrackerDataValue,
+    };
     use insta::assert_json_snapshot;
     use serde_json::json;
     use time::OffsetDateTime;
@@ -37,6 +46,8 @@ mod tests {
                 946720800,
             )?,
             data: TrackerDataValue::new(json!("some-data")),
+            provenance: None,
+            sequence: None,
         }, @r###"
         {
           "id": "00000000-0000-0000-0000-000000000001",
@@ -48,6 +59,43 @@ mod tests {
         }
         "###);
 
+        assert_json_snapshot!(TrackerDataRevision {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            created_at: OffsetDateTime::from_unix_timestamp(
+                946720800,
+            )?,
+            data: TrackerDataValue::new(json!("some-data")),
+            provenance: Some(TrackerDataProvenance {
+                tls: Some(vec![Some(TlsSessionInfo {
+                    protocol: "HTTP/2.0".to_string(),
+                    certificate_fingerprint: "ab01".to_string(),
+                    certificate_expires_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                })]),
+                charsets: None,
+            }),
+            sequence: Some(3),
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "trackerId": "00000000-0000-0000-0000-000000000002",
+          "data": {
+            "original": "some-data"
+          },
+          "createdAt": 946720800,
+          "provenance": {
+            "tls": [
+              {
+                "protocol": "HTTP/2.0",
+                "certificateFingerprint": "ab01",
+                "certificateExpiresAt": 946720900
+              }
+            ]
+          },
+          "sequence": 3
+        }
+        "###);
+
         Ok(())
     }
 }
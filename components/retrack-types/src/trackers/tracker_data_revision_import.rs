@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+/// Historical tracker data revision imported from another tool (e.g., when migrating to Retrack).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerDataRevisionImport {
+    /// Tracker data captured at the time the revision was created.
+    pub data: serde_json::Value,
+    /// Timestamp indicating when data was fetched.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerDataRevisionImport;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<Vec<TrackerDataRevisionImport>>(
+                r#"
+[
+    { "data": "some-data", "createdAt": 946720800 },
+    { "data": { "version": "1.0.0" }, "createdAt": 946720900 }
+]
+          "#
+            )?,
+            vec![
+                TrackerDataRevisionImport {
+                    data: json!("some-data"),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                },
+                TrackerDataRevisionImport {
+                    data: json!({ "version": "1.0.0" }),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                }
+            ]
+        );
+
+        Ok(())
+    }
+}
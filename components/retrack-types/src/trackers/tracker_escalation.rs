@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Ordered escalation of the repeatedly failing tracker, e.g. notify the owner after a few failed
+/// data checks in a row, and page on-call if the tracker keeps failing. The consecutive failures
+/// count is reset once the data check succeeds.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerEscalation {
+    /// List of escalation steps, in the order they are reached.
+    pub steps: Vec<TrackerEscalationStep>,
+}
+
+/// Step of the tracker escalation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerEscalationStep {
+    /// Number of consecutive failed data checks, counted from the previous step, after which the
+    /// step is reached.
+    pub failures: u32,
+    /// List of indexes of the tracker actions to execute when the step is reached. These actions
+    /// are reserved for the escalation and aren't executed for every new data revision. Actions
+    /// receive the number of consecutive failures and the error instead of the tracker data.
+    pub actions: Vec<usize>,
+}
+
+impl TrackerEscalation {
+    /// Returns the escalation step that is reached with exactly the specified number of
+    /// consecutive failures, if any.
+    pub fn step(&self, failures: u32) -> Option<&TrackerEscalationStep> {
+        let mut step_failures = 0;
+        self.steps.iter().find(|step| {
+            step_failures += step.failures;
+            step_failures == failures
+        })
+    }
+
+    /// Checks if the tracker action with the specified index is reserved for the escalation.
+    pub fn is_escalation_action(&self, action: usize) -> bool {
+        self.steps.iter().any(|step| step.actions.contains(&action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerEscalation, TrackerEscalationStep};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    fn escalation() -> TrackerEscalation {
+        TrackerEscalation {
+            steps: vec![
+                TrackerEscalationStep {
+                    failures: 3,
+                    actions: vec![0],
+                },
+                TrackerEscalationStep {
+                    failures: 2,
+                    actions: vec![1, 2],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(escalation(), @r###"
+        {
+          "steps": [
+            {
+              "failures": 3,
+              "actions": [
+                0
+              ]
+            },
+            {
+              "failures": 2,
+              "actions": [
+                1,
+                2
+              ]
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerEscalation>(json!({
+                "steps": [
+                    { "failures": 3, "actions": [0] },
+                    { "failures": 2, "actions": [1, 2] }
+                ]
+            }))?,
+            escalation()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_returns_step() {
+        let escalation = escalation();
+        assert_eq!(escalation.step(0), None);
+        assert_eq!(escalation.step(2), None);
+        assert_eq!(escalation.step(3), Some(&escalation.steps[0]));
+        assert_eq!(escalation.step(4), None);
+        assert_eq!(escalation.step(5), Some(&escalation.steps[1]));
+        assert_eq!(escalation.step(6), None);
+    }
+
+    #[test]
+    fn properly_detects_escalation_actions() {
+        let escalation = escalation();
+        assert!(escalation.is_escalation_action(0));
+        assert!(escalation.is_escalation_action(2));
+        assert!(!escalation.is_escalation_action(3));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::trackers::TrackerDataValue;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Raw upstream responses recorded during the tracker run, along with the data extracted from them.
+/// Fixtures can be replayed later to verify that the tracker still extracts the same data from the
+/// same responses, e.g. after the extractor script has been changed.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerFixture {
+    /// ID of the tracker the fixture was recorded for.
+    pub tracker_id: Uuid,
+    /// Raw responses of the tracker target requests, in the order requests were sent.
+    pub responses: Vec<TrackerFixtureResponse>,
+    /// Content of the latest tracker data revision at the time of recording, if any, that was
+    /// passed to the tracker scripts.
+    pub previous_content: Option<TrackerDataValue>,
+    /// Data extracted from the responses at the time of recording.
+    pub data: JsonValue,
+    /// Timestamp indicating when the fixture was recorded.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Raw response of the tracker target request recorded in the fixture.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerFixtureResponse {
+    /// Value of the `Content-Type` header of the response, if any.
+    pub content_type: Option<String>,
+    /// Raw response body, before any transformation or parsing.
+    pub body: Vec<u8>,
+}
+
+/// Result of replaying the tracker fixture.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerFixtureReplay {
+    /// Data extracted from the fixture responses.
+    pub data: JsonValue,
+    /// Whether the extracted data matches the data recorded in the fixture.
+    pub matches: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{
+        TrackerDataValue, TrackerFixture, TrackerFixtureReplay, TrackerFixtureResponse,
+    };
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let fixture = TrackerFixture {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            responses: vec![
+                TrackerFixtureResponse {
+                    content_type: Some("application/json".to_string()),
+                    body: b"[1]".to_vec(),
+                },
+                TrackerFixtureResponse {
+                    content_type: None,
+                    body: b"2".to_vec(),
+                },
+            ],
+            previous_content: None,
+            data: json!([[1], 2]),
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+        assert_json_snapshot!(fixture, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "responses": [
+            {
+              "contentType": "application/json",
+              "body": [
+                91,
+                49,
+                93
+              ]
+            },
+            {
+              "body": [
+                50
+              ]
+            }
+          ],
+          "data": [
+            [
+              1
+            ],
+            2
+          ],
+          "createdAt": 946720800
+        }
+        "###);
+
+        let fixture = TrackerFixture {
+            previous_content: Some(TrackerDataValue::new(json!([[0], 1]))),
+            responses: vec![],
+            ..fixture
+        };
+        assert_json_snapshot!(fixture, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "responses": [],
+          "previousContent": {
+            "original": [
+              [
+                0
+              ],
+              1
+            ]
+          },
+          "data": [
+            [
+              1
+            ],
+            2
+          ],
+          "createdAt": 946720800
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerFixtureReplay { data: json!([[1], 2]), matches: true }, @r###"
+        {
+          "data": [
+            [
+              1
+            ],
+            2
+          ],
+          "matches": true
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerFixture>(json!({
+                "trackerId": "00000000-0000-0000-0000-000000000001",
+                "responses": [
+                    { "contentType": "application/json", "body": [91, 49, 93] },
+                    { "body": [50] }
+                ],
+                "previousContent": { "original": 1 },
+                "data": [[1], 2],
+                "createdAt": 946720800
+            }))?,
+            TrackerFixture {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                responses: vec![
+                    TrackerFixtureResponse {
+                        content_type: Some("application/json".to_string()),
+                        body: b"[1]".to_vec(),
+                    },
+                    TrackerFixtureResponse {
+                        content_type: None,
+                        body: b"2".to_vec(),
+                    },
+                ],
+                previous_content: Some(TrackerDataValue::new(json!(1))),
+                data: json!([[1], 2]),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Maintenance insight about the tracker that likely needs attention, with a suggested cleanup
+/// action.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerInsight {
+    /// ID of the tracker.
+    pub tracker_id: Uuid,
+    /// Name of the tracker.
+    pub tracker_name: String,
+    /// Kind of the detected issue.
+    pub kind: TrackerInsightKind,
+    /// Date and time of the last tracker data revision, if any.
+    #[serde(
+        default,
+        with = "time::serde::timestamp::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_revision_at: Option<OffsetDateTime>,
+    /// Suggested cleanup action.
+    pub suggested_action: TrackerInsightAction,
+}
+
+/// Kind of the issue detected for the tracker.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerInsightKind {
+    /// Tracker is scheduled, but hasn't detected any changes for a long time.
+    Stale,
+    /// Tracker is scheduled and has been run, but has never successfully fetched any data.
+    Failing,
+    /// Tracker is disabled or isn't scheduled, and hasn't fetched any data for a long time.
+    Inactive,
+}
+
+/// Cleanup action suggested for the tracker.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerInsightAction {
+    /// Archive the tracker to keep its data revisions, but stop checking it.
+    Archive,
+    /// Remove the tracker, since it has no data worth keeping.
+    Remove,
+    /// Review the tracker target and extractor, since they're likely broken.
+    Review,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerInsight, TrackerInsightAction, TrackerInsightKind};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerInsight {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_name: "some-name".to_string(),
+            kind: TrackerInsightKind::Stale,
+            last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+            suggested_action: TrackerInsightAction::Archive,
+        }, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "trackerName": "some-name",
+          "kind": "stale",
+          "lastRevisionAt": 946720800,
+          "suggestedAction": "archive"
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerInsight {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_name: "some-name".to_string(),
+            kind: TrackerInsightKind::Failing,
+            last_revision_at: None,
+            suggested_action: TrackerInsightAction::Review,
+        }, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "trackerName": "some-name",
+          "kind": "failing",
+          "suggestedAction": "review"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerInsight>(json!({
+                "trackerId": "00000000-0000-0000-0000-000000000001",
+                "trackerName": "some-name",
+                "kind": "inactive",
+                "suggestedAction": "remove"
+            }))?,
+            TrackerInsight {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_name: "some-name".to_string(),
+                kind: TrackerInsightKind::Inactive,
+                last_revision_at: None,
+                suggested_action: TrackerInsightAction::Remove,
+            }
+        );
+
+        Ok(())
+    }
+}
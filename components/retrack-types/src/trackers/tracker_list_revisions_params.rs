@@ -1,25 +1,55 @@
+use crate::trackers::TrackerDataDiffFormat;
 use serde::Deserialize;
+use time::OffsetDateTime;
 use utoipa::IntoParams;
+use uuid::Uuid;
 
-/// Parameters for getting a list of revisions of a tracker.
+/// Parameters for getting a list of revisions of a tracker. Revisions are returned in the order
+/// they were persisted, and can be paged through by passing the ID of the last revision of the
+/// previous page as `after`.
 #[derive(Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct TrackerListRevisionsParams {
     /// Whether to calculate the diff between the returned data revisions.
     #[serde(default)]
     pub calculate_diff: bool,
+    /// Format of the diff between the returned data revisions, by default the unified text diff.
+    /// Only used if `calculateDiff` is set.
+    pub diff_format: Option<TrackerDataDiffFormat>,
+    /// Maximum number of revisions to return. If not specified, all matching revisions are
+    /// returned.
+    #[param(minimum = 1)]
+    pub page_size: Option<usize>,
+    /// ID of the revision to return the revisions persisted after (e.g., the last revision of the
+    /// previous page).
+    pub after: Option<Uuid>,
+    /// Only return revisions created at or after the specified time (Unix timestamp in seconds).
+    #[param(value_type = Option<i64>)]
+    #[serde(default, with = "time::serde::timestamp::option")]
+    pub since: Option<OffsetDateTime>,
+    /// Only return revisions created before the specified time (Unix timestamp in seconds).
+    #[param(value_type = Option<i64>)]
+    #[serde(default, with = "time::serde::timestamp::option")]
+    pub until: Option<OffsetDateTime>,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::TrackerListRevisionsParams;
+    use crate::trackers::{TrackerDataDiffFormat, TrackerListRevisionsParams};
+    use time::OffsetDateTime;
+    use uuid::uuid;
 
     #[test]
     fn deserialization() -> anyhow::Result<()> {
         assert_eq!(
             serde_json::from_str::<TrackerListRevisionsParams>(r#"{}"#)?,
             TrackerListRevisionsParams {
-                calculate_diff: false
+                calculate_diff: false,
+                diff_format: None,
+                page_size: None,
+                after: None,
+                since: None,
+                until: None,
             }
         );
 
@@ -32,7 +62,35 @@ mod tests {
           "#
             )?,
             TrackerListRevisionsParams {
-                calculate_diff: true
+                calculate_diff: true,
+                diff_format: None,
+                page_size: None,
+                after: None,
+                since: None,
+                until: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<TrackerListRevisionsParams>(
+                r#"
+{
+    "calculateDiff": true,
+    "diffFormat": "jsonPatch",
+    "pageSize": 10,
+    "after": "00000000-0000-0000-0000-000000000001",
+    "since": 946720800,
+    "until": 946724400
+}
+          "#
+            )?,
+            TrackerListRevisionsParams {
+                calculate_diff: true,
+                diff_format: Some(TrackerDataDiffFormat::JsonPatch),
+                page_size: Some(10),
+                after: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+                since: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                until: Some(OffsetDateTime::from_unix_timestamp(946724400)?),
             }
         );
 
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Maximum size of the content the tracker accepts, along with the policy that defines what
+/// happens to the content that exceeds it.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerMaxContentSize {
+    /// Maximum size of the content in bytes.
+    pub size: usize,
+    /// Policy applied to the content that exceeds the maximum size.
+    pub policy: TrackerContentSizePolicy,
+}
+
+/// Policy applied to the tracker content that exceeds the maximum size.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerContentSizePolicy {
+    /// The tracker check fails.
+    Fail,
+    /// The content is truncated to the maximum size, and a marker is appended to it.
+    Truncate,
+    /// The content is replaced with its SHA-256 hash, so that changes can still be detected.
+    HashOnly,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerContentSizePolicy, TrackerMaxContentSize};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerMaxContentSize { size: 1024, policy: TrackerContentSizePolicy::Fail }, @r###"
+        {
+          "size": 1024,
+          "policy": "fail"
+        }
+        "###);
+        assert_json_snapshot!(TrackerMaxContentSize { size: 1024, policy: TrackerContentSizePolicy::Truncate }, @r###"
+        {
+          "size": 1024,
+          "policy": "truncate"
+        }
+        "###);
+        assert_json_snapshot!(TrackerMaxContentSize { size: 1024, policy: TrackerContentSizePolicy::HashOnly }, @r###"
+        {
+          "size": 1024,
+          "policy": "hashOnly"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerMaxContentSize>(
+                json!({ "size": 1024, "policy": "fail" })
+            )?,
+            TrackerMaxContentSize {
+                size: 1024,
+                policy: TrackerContentSizePolicy::Fail
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerMaxContentSize>(
+                json!({ "size": 1024, "policy": "truncate" })
+            )?,
+            TrackerMaxContentSize {
+                size: 1024,
+                policy: TrackerContentSizePolicy::Truncate
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerMaxContentSize>(
+                json!({ "size": 1024, "policy": "hashOnly" })
+            )?,
+            TrackerMaxContentSize {
+                size: 1024,
+                policy: TrackerContentSizePolicy::HashOnly
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Rules that detect suspicious tracker data revisions. Revisions that break any of the rules are
+/// put into quarantine instead of the revisions history, and can be either accepted or discarded
+/// later. Quarantined revisions don't trigger tracker actions until they are accepted.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerQuarantine {
+    /// Whether to quarantine revisions with the shape of the data that drastically deviates from
+    /// the shape of the previous revisions (e.g., suddenly empty array or missing key).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub shape_deviation: bool,
+    /// Optional list of assertions the tracker data should satisfy.
+    pub assertions: Option<Vec<TrackerDataAssertion>>,
+}
+
+/// Assertion about the value within the tracker data.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerDataAssertion {
+    /// Path to the value within the tracker data (e.g., `$.releases[0].version`). Supported syntax
+    /// is a subset of JSONPath. The value at the path should always exist.
+    pub path: String,
+    /// Optional type the value should be of.
+    pub value_type: Option<TrackerDataAssertionValueType>,
+    /// Whether the value should not be empty (e.g., empty string, array, or object, or `null`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub not_empty: bool,
+    /// Optional minimum numeric value (inclusive). Non-numeric values don't satisfy the assertion.
+    pub min: Option<f64>,
+    /// Optional maximum numeric value (inclusive). Non-numeric values don't satisfy the assertion.
+    pub max: Option<f64>,
+}
+
+// Minimum and maximum values are always finite numbers, they are validated when tracker is created
+// or updated.
+impl Eq for TrackerDataAssertion {}
+
+/// Type of the value within the tracker data.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerDataAssertionValueType {
+    Null,
+    Boolean,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerDataAssertion, TrackerDataAssertionValueType, TrackerQuarantine};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let quarantine = TrackerQuarantine {
+            shape_deviation: false,
+            assertions: None,
+        };
+        assert_json_snapshot!(quarantine, @"{}");
+
+        let quarantine = TrackerQuarantine {
+            shape_deviation: true,
+            assertions: Some(vec![
+                TrackerDataAssertion {
+                    path: "$.releases".to_string(),
+                    value_type: Some(TrackerDataAssertionValueType::Array),
+                    not_empty: true,
+                    min: None,
+                    max: None,
+                },
+                TrackerDataAssertion {
+                    path: "$.price".to_string(),
+                    value_type: None,
+                    not_empty: false,
+                    min: Some(1.5),
+                    max: Some(100.0),
+                },
+            ]),
+        };
+        assert_json_snapshot!(quarantine, @r###"
+        {
+          "shapeDeviation": true,
+          "assertions": [
+            {
+              "path": "$.releases",
+              "valueType": "array",
+              "notEmpty": true
+            },
+            {
+              "path": "$.price",
+              "min": 1.5,
+              "max": 100.0
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerQuarantine>(json!({}))?,
+            TrackerQuarantine {
+                shape_deviation: false,
+                assertions: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TrackerQuarantine>(json!({
+                "shapeDeviation": true,
+                "assertions": [
+                    { "path": "$.releases", "valueType": "array", "notEmpty": true },
+                    { "path": "$.price", "min": 1.5, "max": 100 }
+                ]
+            }))?,
+            TrackerQuarantine {
+                shape_deviation: true,
+                assertions: Some(vec![
+                    TrackerDataAssertion {
+                        path: "$.releases".to_string(),
+                        value_type: Some(TrackerDataAssertionValueType::Array),
+                        not_empty: true,
+                        min: None,
+                        max: None,
+                    },
+                    TrackerDataAssertion {
+                        path: "$.price".to_string(),
+                        value_type: None,
+                        not_empty: false,
+                        min: Some(1.5),
+                        max: Some(100.0),
+                    },
+                ]),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::trackers::TrackerDataRevision;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Tracker data revision that has been put into quarantine, since it broke the tracker quarantine
+/// rules. Quarantined revision can be either accepted into the revisions history, or discarded.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerQuarantinedRevision {
+    /// Quarantined data revision, it doesn't have a sequence number until it's accepted.
+    pub revision: TrackerDataRevision,
+    /// Human-readable reason the revision has been quarantined for.
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerDataRevision, TrackerDataValue, TrackerQuarantinedRevision};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let quarantined_revision = TrackerQuarantinedRevision {
+            revision: TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: TrackerDataValue::new(json!({ "releases": [] })),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: None,
+            },
+            reason: "Value at `$.releases` is empty.".to_string(),
+        };
+        assert_json_snapshot!(quarantined_revision, @r###"
+        {
+          "revision": {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "trackerId": "00000000-0000-0000-0000-000000000002",
+            "data": {
+              "original": {
+                "releases": []
+              }
+            },
+            "createdAt": 946720800
+          },
+          "reason": "Value at `$.releases` is empty."
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Parameters for exporting all stored revisions of a tracker.
+#[derive(Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerRevisionsExportParams {
+    /// Format of the exported revisions, by default revisions are exported as JSON array.
+    pub format: Option<TrackerRevisionsExportFormat>,
+    /// Whether to include the diff between every revision and the revision that precedes it.
+    #[serde(default)]
+    pub calculate_diff: bool,
+}
+
+/// Format of the exported tracker revisions.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackerRevisionsExportFormat {
+    /// JSON array of revisions.
+    #[default]
+    Json,
+    /// Comma-separated values, one revision per row, with the header row.
+    Csv,
+    /// Newline-delimited JSON, one revision per line.
+    Ndjson,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerRevisionsExportFormat, TrackerRevisionsExportParams};
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TrackerRevisionsExportParams>(r#"{}"#)?,
+            TrackerRevisionsExportParams {
+                format: None,
+                calculate_diff: false,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<TrackerRevisionsExportParams>(
+                r#"
+{
+    "format": "ndjson",
+    "calculateDiff": true
+}
+          "#
+            )?,
+            TrackerRevisionsExportParams {
+                format: Some(TrackerRevisionsExportFormat::Ndjson),
+                calculate_diff: true,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_serialization_and_deserialization() -> anyhow::Result<()> {
+        for (format, value) in [
+            (TrackerRevisionsExportFormat::Json, json!("json")),
+            (TrackerRevisionsExportFormat::Csv, json!("csv")),
+            (TrackerRevisionsExportFormat::Ndjson, json!("ndjson")),
+        ] {
+            assert_eq!(serde_json::to_value(format)?, value);
+            assert_eq!(
+                serde_json::from_value::<TrackerRevisionsExportFormat>(value)?,
+                format
+            );
+        }
+
+        assert_eq!(
+            TrackerRevisionsExportFormat::default(),
+            TrackerRevisionsExportFormat::Json
+        );
+
+        Ok(())
+    }
+}
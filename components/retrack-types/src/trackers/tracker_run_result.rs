@@ -0,0 +1,198 @@
+use crate::trackers::TrackerDataRevision;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Detailed result of the tracker data check.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerRunResult {
+    /// Newly created tracker data revision, or the latest existing revision if the fetched data
+    /// hasn't produced a new one (see `outcome`).
+    pub revision: TrackerDataRevision,
+    /// Outcome of the tracker data check.
+    pub outcome: TrackerRunOutcome,
+    /// Status of the tracker actions executed during the data check, if any.
+    pub actions: Vec<TrackerRunActionResult>,
+    /// Time spent on the individual stages of the tracker data check.
+    pub timings: TrackerRunTimings,
+}
+
+/// Outcome of the tracker data check.
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerRunOutcome {
+    /// Data has changed, and a new data revision has been created.
+    Created,
+    /// Data hasn't changed since the latest data revision, and fetched data has been deduplicated.
+    Unchanged,
+    /// Data has changed, but the change hasn't been observed in enough consecutive checks yet.
+    Unconfirmed,
+    /// Data has changed, but the new data revision has been quarantined.
+    Quarantined,
+    /// Data has changed, but the change has been dropped by the tracker change or semantic filter.
+    Filtered,
+}
+
+/// Status of the tracker action executed during the tracker data check.
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerRunActionResult {
+    /// Index of the action within the tracker actions.
+    pub index: usize,
+    /// Status of the action.
+    pub status: TrackerRunActionStatus,
+}
+
+/// Status of the tracker action.
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerRunActionStatus {
+    /// Action wasn't executed, e.g. because data hasn't changed or its dependencies weren't met.
+    Skipped,
+    /// Action was executed successfully (email and webhook actions may only be scheduled).
+    Succeeded,
+    /// Action was executed, but failed.
+    Failed,
+}
+
+/// Time spent on the individual stages of the tracker data check.
+#[serde_as]
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerRunTimings {
+    /// Time spent fetching data from the tracker target.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub fetch: Duration,
+    /// Time spent processing fetched data (content size policy, deduplication, confirmations,
+    /// quarantine rules, and filters).
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub processing: Duration,
+    /// Time spent executing tracker actions and persisting the new data revision.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub actions: Duration,
+    /// Total time spent on the tracker data check.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub total: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{
+        TrackerDataRevision, TrackerDataValue, TrackerRunActionResult, TrackerRunActionStatus,
+        TrackerRunOutcome, TrackerRunResult, TrackerRunTimings,
+    };
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerRunResult {
+            revision: TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!("some-data")),
+                provenance: None,
+                sequence: Some(1),
+            },
+            outcome: TrackerRunOutcome::Created,
+            actions: vec![
+                TrackerRunActionResult {
+                    index: 0,
+                    status: TrackerRunActionStatus::Succeeded,
+                },
+                TrackerRunActionResult {
+                    index: 1,
+                    status: TrackerRunActionStatus::Skipped,
+                },
+                TrackerRunActionResult {
+                    index: 2,
+                    status: TrackerRunActionStatus::Failed,
+                },
+            ],
+            timings: TrackerRunTimings {
+                fetch: Duration::from_millis(1200),
+                processing: Duration::from_millis(3),
+                actions: Duration::from_millis(45),
+                total: Duration::from_millis(1250),
+            },
+        }, @r###"
+        {
+          "revision": {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "trackerId": "00000000-0000-0000-0000-000000000002",
+            "data": {
+              "original": "some-data"
+            },
+            "createdAt": 946720800,
+            "sequence": 1
+          },
+          "outcome": "created",
+          "actions": [
+            {
+              "index": 0,
+              "status": "succeeded"
+            },
+            {
+              "index": 1,
+              "status": "skipped"
+            },
+            {
+              "index": 2,
+              "status": "failed"
+            }
+          ],
+          "timings": {
+            "fetch": 1200,
+            "processing": 3,
+            "actions": 45,
+            "total": 1250
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerRunResult>(json!({
+                "revision": {
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "trackerId": "00000000-0000-0000-0000-000000000002",
+                    "data": { "original": "some-data" },
+                    "createdAt": 946720800
+                },
+                "outcome": "quarantined",
+                "actions": [],
+                "timings": { "fetch": 100, "processing": 2, "actions": 0, "total": 102 }
+            }))?,
+            TrackerRunResult {
+                revision: TrackerDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000001"),
+                    tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    data: TrackerDataValue::new(json!("some-data")),
+                    provenance: None,
+                    sequence: None,
+                },
+                outcome: TrackerRunOutcome::Quarantined,
+                actions: vec![],
+                timings: TrackerRunTimings {
+                    fetch: Duration::from_millis(100),
+                    processing: Duration::from_millis(2),
+                    actions: Duration::ZERO,
+                    total: Duration::from_millis(102),
+                },
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Filter that drops data changes that don't alter the meaning of the tracker data (e.g., trivial
+/// wording changes) based on the cosine similarity of the text embeddings of the previous and the
+/// new data.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerSemanticFilter {
+    /// Cosine similarity (between `0` and `1`) at or above which the change is considered trivial
+    /// and doesn't produce a new revision, e.g. `0.95`.
+    pub threshold: f64,
+}
+
+// Threshold is always a finite number, it's validated when tracker is created or updated.
+impl Eq for TrackerSemanticFilter {}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerSemanticFilter;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerSemanticFilter { threshold: 0.95 }, @r###"
+        {
+          "threshold": 0.95
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerSemanticFilter>(json!({ "threshold": 0.9 }))?,
+            TrackerSemanticFilter { threshold: 0.9 }
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerSemanticFilter>(json!({ "threshold": 1 }))?,
+            TrackerSemanticFilter { threshold: 1.0 }
+        );
+
+        Ok(())
+    }
+}
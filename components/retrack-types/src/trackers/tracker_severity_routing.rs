@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Routing table that executes tracker actions based on the severity of the data change, e.g. log
+/// minor changes, but page on-call only when the change is critical. Severity of the change is read
+/// from the tracker data, usually set by the extractor script.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerSeverityRouting {
+    /// Path to the severity of the change within the tracker data (e.g., `$.severity`). Supported
+    /// syntax is a subset of JSONPath.
+    pub path: String,
+    /// Severity of the change if the tracker data doesn't include a valid severity.
+    #[serde(default)]
+    pub default_severity: TrackerSeverity,
+    /// List of routes that define the minimum severity of the change for the tracker actions.
+    /// Actions that aren't included into any route are executed regardless of the severity.
+    pub routes: Vec<TrackerSeverityRoute>,
+}
+
+/// Route of the tracker actions for the changes of the specific severity.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerSeverityRoute {
+    /// Minimum severity of the change the actions are executed for.
+    pub severity: TrackerSeverity,
+    /// List of indexes of the tracker actions executed for the changes of this or higher severity.
+    pub actions: Vec<usize>,
+}
+
+/// Severity of the tracker data change, from the least to the most severe.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerSeverity {
+    /// Informational change that doesn't require any attention.
+    #[default]
+    Info,
+    /// Minor change that might require attention eventually.
+    Minor,
+    /// Major change that requires attention.
+    Major,
+    /// Critical change that requires immediate attention.
+    Critical,
+}
+
+impl TrackerSeverityRouting {
+    /// Checks if the tracker action with the specified index should be executed for the change of
+    /// the specified severity.
+    pub fn routes_action(&self, severity: TrackerSeverity, action: usize) -> bool {
+        self.routes
+            .iter()
+            .find(|route| route.actions.contains(&action))
+            .is_none_or(|route| severity >= route.severity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerSeverity, TrackerSeverityRoute, TrackerSeverityRouting};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    fn routing() -> TrackerSeverityRouting {
+        TrackerSeverityRouting {
+            path: "$.severity".to_string(),
+            default_severity: TrackerSeverity::Minor,
+            routes: vec![
+                TrackerSeverityRoute {
+                    severity: TrackerSeverity::Minor,
+                    actions: vec![0],
+                },
+                TrackerSeverityRoute {
+                    severity: TrackerSeverity::Critical,
+                    actions: vec![1],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(routing(), @r###"
+        {
+          "path": "$.severity",
+          "defaultSeverity": "minor",
+          "routes": [
+            {
+              "severity": "minor",
+              "actions": [
+                0
+              ]
+            },
+            {
+              "severity": "critical",
+              "actions": [
+                1
+              ]
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerSeverityRouting>(json!({
+                "path": "$.severity",
+                "defaultSeverity": "minor",
+                "routes": [
+                    { "severity": "minor", "actions": [0] },
+                    { "severity": "critical", "actions": [1] }
+                ]
+            }))?,
+            routing()
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TrackerSeverityRouting>(json!({
+                "path": "$.severity",
+                "routes": [{ "severity": "major", "actions": [0, 1] }]
+            }))?,
+            TrackerSeverityRouting {
+                path: "$.severity".to_string(),
+                default_severity: TrackerSeverity::Info,
+                routes: vec![TrackerSeverityRoute {
+                    severity: TrackerSeverity::Major,
+                    actions: vec![0, 1],
+                }],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_routes_actions() {
+        let routing = routing();
+
+        assert!(!routing.routes_action(TrackerSeverity::Info, 0));
+        assert!(!routing.routes_action(TrackerSeverity::Info, 1));
+        assert!(routing.routes_action(TrackerSeverity::Info, 2));
+
+        assert!(routing.routes_action(TrackerSeverity::Major, 0));
+        assert!(!routing.routes_action(TrackerSeverity::Major, 1));
+        assert!(routing.routes_action(TrackerSeverity::Major, 2));
+
+        assert!(routing.routes_action(TrackerSeverity::Critical, 0));
+        assert!(routing.routes_action(TrackerSeverity::Critical, 1));
+        assert!(routing.routes_action(TrackerSeverity::Critical, 2));
+    }
+}
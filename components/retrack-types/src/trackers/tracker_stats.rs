@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+/// Aggregated statistics of the tracker data revisions.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerStats {
+    /// Number of stored tracker data revisions.
+    pub revisions: usize,
+    /// Date and time of the last tracker data revision, if any.
+    #[serde(
+        default,
+        with = "time::serde::timestamp::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_revision_at: Option<OffsetDateTime>,
+    /// Date and time of the last tracker data revision with the data that differs from the data
+    /// of the preceding revision, if any. Unlike `lastRevisionAt`, it ignores revisions that only
+    /// have a different provenance (e.g., renewed TLS certificate).
+    #[serde(
+        default,
+        with = "time::serde::timestamp::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_change_at: Option<OffsetDateTime>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerStats;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerStats::default(), @r###"
+        {
+          "revisions": 0
+        }
+        "###);
+        assert_json_snapshot!(TrackerStats {
+            revisions: 3,
+            last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+            last_change_at: Some(OffsetDateTime::from_unix_timestamp(946720700)?),
+        }, @r###"
+        {
+          "revisions": 3,
+          "lastRevisionAt": 946720800,
+          "lastChangeAt": 946720700
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerStats>(json!({ "revisions": 0 }))?,
+            TrackerStats::default()
+        );
+        assert_eq!(
+            serde_json::from_value::<TrackerStats>(json!({
+                "revisions": 3,
+                "lastRevisionAt": 946720800,
+                "lastChangeAt": 946720700
+            }))?,
+            TrackerStats {
+                revisions: 3,
+                last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                last_change_at: Some(OffsetDateTime::from_unix_timestamp(946720700)?),
+            }
+        );
+
+        Ok(())
+    }
+}
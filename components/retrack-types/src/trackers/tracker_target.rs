@@ -1,17 +1,49 @@
 mod api_target;
+mod composite_target;
+mod email_target;
+mod feed_target;
+mod file_target;
+mod git_target;
+mod grpc_target;
+mod monitor_target;
 mod page_target;
+mod prometheus_target;
+mod registry_target;
+mod s3_target;
+mod sitemap_target;
+mod sql_target;
+mod stream_target;
+mod target_selector;
 
 pub use self::{
     api_target::{
         ApiTarget, ConfiguratorScriptArgs, ConfiguratorScriptRequest, ConfiguratorScriptResult,
-        ExtractorScriptArgs, ExtractorScriptResult, TargetRequest,
+        ExtractorScriptArgs, ExtractorScriptResult, TargetRequest, TargetRequestBodyFormat,
+        TargetRequestFilePart, TargetRequestPagination, TargetRequestPaginationStrategy,
+        TargetResponseTransform,
     },
-    page_target::PageTarget,
+    composite_target::{CompositeTarget, MergerScriptArgs, MergerScriptInput, MergerScriptResult},
+    email_target::EmailTarget,
+    feed_target::FeedTarget,
+    file_target::FileTarget,
+    git_target::GitTarget,
+    grpc_target::{GrpcDescriptorSource, GrpcTarget},
+    monitor_target::MonitorTarget,
+    page_target::{PageTarget, PageTargetEngine, PageTargetStep},
+    prometheus_target::PrometheusTarget,
+    registry_target::RegistryTarget,
+    s3_target::S3Target,
+    sitemap_target::SitemapTarget,
+    sql_target::SqlTarget,
+    stream_target::StreamTarget,
+    target_selector::TargetSelector,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-/// Tracker's target (web page, API, or file).
+/// Tracker's target (web page, API, feed, mailbox, SQL database, local file, object storage, git
+/// repository, container image registry, sitemap, streaming endpoint, gRPC server, other trackers,
+/// HTTP endpoint availability, or Prometheus metrics).
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "type")]
@@ -21,18 +53,49 @@ pub enum TrackerTarget {
     Page(PageTarget),
     /// HTTP API target.
     Api(ApiTarget),
+    /// RSS or Atom feed target.
+    Feed(FeedTarget),
+    /// IMAP mailbox target.
+    Email(EmailTarget),
+    /// SQL database query target.
+    Sql(SqlTarget),
+    /// Local file or directory target.
+    File(FileTarget),
+    /// S3-compatible object storage bucket target.
+    S3(S3Target),
+    /// Remote git repository target.
+    Git(GitTarget),
+    /// Container image registry target.
+    Registry(RegistryTarget),
+    /// XML sitemap or sitemap index target.
+    Sitemap(SitemapTarget),
+    /// WebSocket or Server-Sent Events endpoint target.
+    Stream(StreamTarget),
+    /// gRPC server unary method target.
+    Grpc(GrpcTarget),
+    /// Target that merges the data of other trackers.
+    Composite(CompositeTarget),
+    /// HTTP endpoint availability (uptime) target.
+    Monitor(MonitorTarget),
+    /// Prometheus or OpenMetrics metrics endpoint target.
+    Prometheus(PrometheusTarget),
 }
 
 #[cfg(test)]
 mod tests {
     use super::TrackerTarget;
-    use crate::trackers::{ApiTarget, PageTarget, TargetRequest};
+    use crate::trackers::{
+        ApiTarget, CompositeTarget, EmailTarget, FeedTarget, FileTarget, GitTarget,
+        GrpcDescriptorSource, GrpcTarget, MonitorTarget, PageTarget, PrometheusTarget,
+        RegistryTarget, S3Target, SitemapTarget, SqlTarget, StreamTarget, TargetRequest,
+    };
     use http::{
         header::{AUTHORIZATION, CONTENT_TYPE},
         Method,
     };
     use serde_json::json;
     use std::collections::HashMap;
+    use uuid::uuid;
 
     #[test]
     fn can_serialize_and_deserialize() -> anyhow::Result<()> {
@@ -41,6 +104,10 @@ mod tests {
             params: None,
             user_agent: None,
             ignore_https_errors: false,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         });
         let target_json = json!({
             "type": "page",
@@ -57,6 +124,10 @@ mod tests {
             params: Some(json!({ "param": "value" })),
             user_agent: Some("Retrack/1.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: false,
+            engine: None,
+            steps: None,
+            selectors: None,
         });
         let target_json = json!({
             "type": "page",
@@ -75,6 +146,9 @@ mod tests {
             requests: vec![TargetRequest::new("https://retrack.dev/".parse()?)],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         });
         let target_json = json!({ "type": "api", "requests": [{ "url": "https://retrack.dev/" }] });
         assert_eq!(serde_json::to_value(&target)?, target_json);
@@ -90,6 +164,9 @@ mod tests {
             }],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         });
         let target_json = json!({ "type": "api", "requests": [{ "url": "https://retrack.dev/", "method": "PUT" }] });
         assert_eq!(serde_json::to_value(&target)?, target_json);
@@ -114,6 +191,9 @@ mod tests {
             }],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         });
         let target_json = json!({
             "type": "api",
@@ -129,6 +209,212 @@ mod tests {
             target
         );
 
+        let target = TrackerTarget::Feed(FeedTarget {
+            max_items: Some(5),
+            ..FeedTarget::new("https://retrack.dev/feed.xml".parse()?)
+        });
+        let target_json = json!({
+            "type": "feed",
+            "url": "https://retrack.dev/feed.xml",
+            "maxItems": 5
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Email(EmailTarget {
+            search: Some("UNSEEN".to_string()),
+            ..EmailTarget::new("imap.retrack.dev", "user@retrack.dev", "imap-password")
+        });
+        let target_json = json!({
+            "type": "email",
+            "host": "imap.retrack.dev",
+            "username": "user@retrack.dev",
+            "passwordSecret": "imap-password",
+            "search": "UNSEEN"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Sql(SqlTarget {
+            max_rows: Some(10),
+            ..SqlTarget::new("db-url", "SELECT id, price FROM products")
+        });
+        let target_json = json!({
+            "type": "sql",
+            "connectionSecret": "db-url",
+            "query": "SELECT id, price FROM products",
+            "maxRows": 10
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::File(FileTarget {
+            capture_content: true,
+            ..FileTarget::new("/var/reports/*.csv")
+        });
+        let target_json = json!({
+            "type": "file",
+            "path": "/var/reports/*.csv",
+            "captureContent": true
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::S3(S3Target {
+            prefix: Some("reports/".to_string()),
+            ..S3Target::new(
+                "https://s3.us-east-1.amazonaws.com".parse()?,
+                "vendor-drops",
+            )
+        });
+        let target_json = json!({
+            "type": "s3",
+            "endpoint": "https://s3.us-east-1.amazonaws.com/",
+            "bucket": "vendor-drops",
+            "prefix": "reports/"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Git(GitTarget {
+            tag_pattern: Some("v*".to_string()),
+            ..GitTarget::new("https://github.com/secutils-dev/retrack.git".parse()?)
+        });
+        let target_json = json!({
+            "type": "git",
+            "url": "https://github.com/secutils-dev/retrack.git",
+            "tagPattern": "v*"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Registry(RegistryTarget {
+            tag: Some("latest".to_string()),
+            ..RegistryTarget::new("nginx")
+        });
+        let target_json = json!({
+            "type": "registry",
+            "image": "nginx",
+            "tag": "latest"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Sitemap(SitemapTarget {
+            max_urls: Some(100),
+            ..SitemapTarget::new("https://retrack.dev/sitemap.xml".parse()?)
+        });
+        let target_json = json!({
+            "type": "sitemap",
+            "url": "https://retrack.dev/sitemap.xml",
+            "maxUrls": 100
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Stream(StreamTarget {
+            max_messages: Some(10),
+            ..StreamTarget::new("wss://retrack.dev/ws".parse()?)
+        });
+        let target_json = json!({
+            "type": "stream",
+            "url": "wss://retrack.dev/ws",
+            "maxMessages": 10
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Grpc(GrpcTarget::new(
+            "https://grpc.retrack.dev".parse()?,
+            "retrack.v1.Status/GetStatus",
+            GrpcDescriptorSource::Url {
+                url: "https://retrack.dev/status.pb".parse()?,
+            },
+        ));
+        let target_json = json!({
+            "type": "grpc",
+            "url": "https://grpc.retrack.dev/",
+            "method": "retrack.v1.Status/GetStatus",
+            "descriptor": { "type": "url", "url": "https://retrack.dev/status.pb" }
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Composite(CompositeTarget::new(
+            vec![uuid!("00000000-0000-0000-0000-000000000001")],
+            "(() => ({ content: context.inputs.length }))();",
+        ));
+        let target_json = json!({
+            "type": "composite",
+            "trackers": ["00000000-0000-0000-0000-000000000001"],
+            "merger": "(() => ({ content: context.inputs.length }))();"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Monitor(MonitorTarget {
+            requests: Some(3),
+            ..MonitorTarget::new("https://retrack.dev/health".parse()?)
+        });
+        let target_json = json!({
+            "type": "monitor",
+            "url": "https://retrack.dev/health",
+            "requests": 3
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
+        let target = TrackerTarget::Prometheus(PrometheusTarget {
+            metrics: Some(vec!["process_open_fds".to_string()]),
+            ..PrometheusTarget::new("https://retrack.dev/metrics".parse()?)
+        });
+        let target_json = json!({
+            "type": "prometheus",
+            "url": "https://retrack.dev/metrics",
+            "metrics": ["process_open_fds"]
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<TrackerTarget>(target_json)?,
+            target
+        );
+
         Ok(())
     }
 }
@@ -1,5 +1,7 @@
+use crate::trackers::TargetSelector;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 
 mod configurator_script_args;
@@ -8,13 +10,20 @@ mod configurator_script_result;
 mod extractor_script_args;
 mod extractor_script_result;
 mod target_request;
+mod target_request_body_format;
+mod target_request_pagination;
+mod target_response_transform;
 
 pub use self::{
     configurator_script_args::ConfiguratorScriptArgs,
     configurator_script_request::ConfiguratorScriptRequest,
     configurator_script_result::ConfiguratorScriptResult,
-    extractor_script_args::ExtractorScriptArgs, extractor_script_result::ExtractorScriptResult,
+    extractor_script_args::ExtractorScriptArgs,
+    extractor_script_result::ExtractorScriptResult,
     target_request::TargetRequest,
+    target_request_body_format::{TargetRequestBodyFormat, TargetRequestFilePart},
+    target_request_pagination::{TargetRequestPagination, TargetRequestPaginationStrategy},
+    target_response_transform::TargetResponseTransform,
 };
 
 /// Tracker's target for HTTP API.
@@ -30,6 +39,27 @@ pub struct ApiTarget {
 
     /// Optional custom script (Deno) to extract only necessary data from the API response.
     pub extractor: Option<String>,
+
+    /// Whether to capture TLS session details (negotiated protocol, certificate fingerprint and
+    /// expiry) of the requests and include them into the revision provenance. Changes in the
+    /// TLS session details are treated as data changes.
+    #[serde(
+        rename = "captureTLS",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub capture_tls: bool,
+
+    /// Whether to keep the cookies set by the API responses in the tracker cookie jar and send
+    /// them with the subsequent requests. The cookie jar persists between tracker checks, so that
+    /// a session established by an auth request survives between requests and checks.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cookie_jar: bool,
+
+    /// Optional declarative selectors (field name to selector) to extract data from the HTML
+    /// responses instead of the custom extractor script. The data of every response is a JSON
+    /// object with the value extracted for every field.
+    pub selectors: Option<BTreeMap<String, TargetSelector>>,
 }
 
 #[cfg(test)]
@@ -49,6 +79,9 @@ mod tests {
             requests: vec![TargetRequest::new(Url::parse("https://retrack.dev")?)],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json = json!({ "requests": [{ "url": "https://retrack.dev/" }] });
         assert_eq!(serde_json::to_value(&target)?, target_json);
@@ -61,6 +94,9 @@ mod tests {
             }],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json =
             json!({ "requests": [{"url": "https://retrack.dev/", "method": "PUT" }] });
@@ -83,6 +119,9 @@ mod tests {
             }],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json = json!({
             "requests": [{
@@ -114,6 +153,9 @@ mod tests {
             }],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json = json!({
             "requests": [{
@@ -146,9 +188,18 @@ mod tests {
                 ),
                 body: Some(json!({ "key": "value" })),
                 media_type: Some("text/plain; charset=UTF-8".parse()?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }],
             configurator: None,
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json = json!({
             "requests": [{
@@ -182,12 +233,21 @@ mod tests {
                 ),
                 body: Some(json!({ "key": "value" })),
                 media_type: Some("text/plain; charset=UTF-8".parse()?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }],
             configurator: Some(
                 "(async () => ({ body: Deno.core.encode(JSON.stringify({ key: 'value' })) })();"
                     .to_string(),
             ),
             extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json = json!({
             "requests": [{
@@ -222,6 +282,12 @@ mod tests {
                 ),
                 body: Some(json!({ "key": "value" })),
                 media_type: Some("text/plain; charset=UTF-8".parse()?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }],
             configurator: Some(
                 "(async () => ({ body: Deno.core.encode(JSON.stringify({ key: 'value' })) })();"
@@ -231,6 +297,9 @@ mod tests {
                 "((context) => ({ body: Deno.core.encode(JSON.stringify({ key: 'value' })) })();"
                     .to_string(),
             ),
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
         };
         let target_json = json!({
             "requests": [{
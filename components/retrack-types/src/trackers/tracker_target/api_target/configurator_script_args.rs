@@ -46,6 +46,12 @@ mod tests {
                 headers: None,
                 media_type: None,
                 body: Some(serde_json::to_vec(&json!({ "body": "value" }))?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }],
         };
         let context_json = json!({
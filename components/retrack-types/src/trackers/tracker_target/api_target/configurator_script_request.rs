@@ -1,8 +1,11 @@
-use crate::trackers::TargetRequest;
+use crate::trackers::{
+    TargetRequest, TargetRequestBodyFormat, TargetRequestPagination, TargetResponseTransform,
+};
 use http::{HeaderMap, Method};
 use mediatype::MediaTypeBuf;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
 use url::Url;
 
 /// Structure of the request representation for the configurator script.
@@ -18,15 +21,33 @@ pub struct ConfiguratorScriptRequest {
     pub method: Option<Method>,
 
     /// Optional HTTP headers to send with the request.
-    #[serde(with = "http_serde::option::header_map", default)]
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
     pub headers: Option<HeaderMap>,
 
+    /// Optional cookies (name to value) to send with the request.
+    pub cookies: Option<BTreeMap<String, String>>,
+
+    /// Optional name of the auth profile to authenticate the request with.
+    pub auth_profile: Option<String>,
+
     /// The media type of the content returned by the API. By default, application/json is assumed.
     pub media_type: Option<MediaTypeBuf>,
 
     /// Optional HTTP body configured for the request.
     #[serde(with = "serde_bytes", default)]
     pub body: Option<Vec<u8>>,
+
+    /// Format in which the body is sent. By default, the body is sent as JSON.
+    pub body_format: Option<TargetRequestBodyFormat>,
+
+    /// Optional transformation to apply to the response before it's parsed.
+    pub response_transform: Option<TargetResponseTransform>,
+
+    /// Optional pagination to follow to retrieve all pages of the paginated API response.
+    pub pagination: Option<TargetRequestPagination>,
+
+    /// Optional JSONPath expression to select only the part of the JSON response to track.
+    pub response_path: Option<String>,
 }
 
 impl TryFrom<ConfiguratorScriptRequest> for TargetRequest {
@@ -42,6 +63,12 @@ impl TryFrom<ConfiguratorScriptRequest> for TargetRequest {
                 .body
                 .map(|body| serde_json::from_slice(&body))
                 .transpose()?,
+            body_format: request.body_format,
+            response_transform: request.response_transform,
+            auth_profile: request.auth_profile,
+            pagination: request.pagination,
+            cookies: request.cookies,
+            response_path: request.response_path,
         })
     }
 }
@@ -54,8 +81,14 @@ impl TryFrom<TargetRequest> for ConfiguratorScriptRequest {
             url: request.url,
             method: request.method,
             headers: request.headers,
+            cookies: request.cookies,
             media_type: request.media_type,
             body: request.body.as_ref().map(serde_json::to_vec).transpose()?,
+            body_format: request.body_format,
+            response_transform: request.response_transform,
+            auth_profile: request.auth_profile,
+            pagination: request.pagination,
+            response_path: request.response_path,
         })
     }
 }
@@ -76,6 +109,12 @@ mod tests {
             headers: None,
             body: None,
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({ "url": "https://retrack.dev/" });
         assert_eq!(serde_json::to_value(&request)?, request_json);
@@ -90,6 +129,12 @@ mod tests {
             headers: None,
             body: None,
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({ "url": "https://retrack.dev/", "method": "PUT" });
         assert_eq!(serde_json::to_value(&request)?, request_json);
@@ -109,6 +154,12 @@ mod tests {
             ),
             body: None,
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({
             "url": "https://retrack.dev/",
@@ -134,6 +185,12 @@ mod tests {
             ),
             body: Some(serde_json::to_vec(&json!({ "key": "value" }))?),
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({
             "url": "https://retrack.dev/",
@@ -160,6 +217,12 @@ mod tests {
             ),
             body: Some(serde_json::to_vec(&json!({ "key": "value" }))?),
             media_type: Some("text/plain; charset=UTF-8".parse()?),
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({
             "url": "https://retrack.dev/",
@@ -192,6 +255,12 @@ mod tests {
             ),
             body: Some(serde_json::to_vec(&json!({ "key": "value" }))?),
             media_type: Some("text/plain; charset=UTF-8".parse()?),
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
 
         assert_eq!(
@@ -206,7 +275,13 @@ mod tests {
                         .try_into()?,
                 ),
                 body: Some(json!({ "key": "value" })),
-                media_type: Some("text/plain; charset=UTF-8".parse()?)
+                media_type: Some("text/plain; charset=UTF-8".parse()?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }
         );
 
@@ -226,6 +301,12 @@ mod tests {
             ),
             body: Some(json!({ "key": "value" })),
             media_type: Some("text/plain; charset=UTF-8".parse()?),
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
 
         assert_eq!(
@@ -240,7 +321,13 @@ mod tests {
                         .try_into()?,
                 ),
                 body: Some(serde_json::to_vec(&json!({ "key": "value" }))?),
-                media_type: Some("text/plain; charset=UTF-8".parse()?)
+                media_type: Some("text/plain; charset=UTF-8".parse()?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }
         );
 
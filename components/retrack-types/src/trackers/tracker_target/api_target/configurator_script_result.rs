@@ -48,6 +48,12 @@ mod tests {
                 ),
                 media_type: None,
                 body: Some(vec![1, 2, 3]),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }])
         );
 
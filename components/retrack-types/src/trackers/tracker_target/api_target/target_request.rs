@@ -1,7 +1,9 @@
+use crate::trackers::{TargetRequestBodyFormat, TargetRequestPagination, TargetResponseTransform};
 use http::{HeaderMap, Method};
 use mediatype::MediaTypeBuf;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
 use url::Url;
 use utoipa::ToSchema;
 
@@ -19,16 +21,38 @@ pub struct TargetRequest {
     pub method: Option<Method>,
 
     /// Optional headers to include in the request.
-    #[serde(with = "http_serde::option::header_map", default)]
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
     #[schema(value_type = HashMap<String, String>)]
     pub headers: Option<HeaderMap>,
 
+    /// Optional cookies (name to value) to include in the request. Explicit cookies take
+    /// precedence over the cookies with the same name from the tracker cookie jar, if any.
+    pub cookies: Option<BTreeMap<String, String>>,
+
+    /// Optional name of the auth profile to authenticate the request with. The authentication
+    /// header produced by the profile overrides the header with the same name, if any.
+    pub auth_profile: Option<String>,
+
     /// The media type of the content returned by the API. By default, application/json is assumed.
     #[schema(value_type = String)]
     pub media_type: Option<MediaTypeBuf>,
 
     /// Optional body to include to the request.
     pub body: Option<serde_json::Value>,
+
+    /// Format in which the body is sent. By default, the body is sent as JSON.
+    pub body_format: Option<TargetRequestBodyFormat>,
+
+    /// Optional transformation to apply to the response before it's parsed (e.g., to verify JWS
+    /// signature or decrypt JWE payload).
+    pub response_transform: Option<TargetResponseTransform>,
+
+    /// Optional pagination to follow to retrieve all pages of the paginated API response.
+    pub pagination: Option<TargetRequestPagination>,
+
+    /// Optional JSONPath expression (e.g., `$.data.items[*].price`) to select only the part of the
+    /// JSON response to track. The path with wildcards selects the list of all matching values.
+    pub response_path: Option<String>,
 }
 
 impl TargetRequest {
@@ -38,8 +62,14 @@ impl TargetRequest {
             url,
             method: None,
             headers: None,
+            cookies: None,
             media_type: None,
             body: None,
+            body_format: None,
+            response_transform: None,
+            auth_profile: None,
+            pagination: None,
+            response_path: None,
         }
     }
 }
@@ -70,6 +100,12 @@ mod tests {
             headers: None,
             body: None,
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({ "url": "https://retrack.dev/", "method": "PUT" });
         assert_eq!(serde_json::to_value(&request)?, request_json);
@@ -78,6 +114,29 @@ mod tests {
             request
         );
 
+        let request = TargetRequest {
+            auth_profile: Some("github".to_string()),
+            ..TargetRequest::new("https://retrack.dev".parse()?)
+        };
+        let request_json = json!({ "url": "https://retrack.dev/", "authProfile": "github" });
+        assert_eq!(serde_json::to_value(&request)?, request_json);
+        assert_eq!(
+            serde_json::from_value::<TargetRequest>(request_json)?,
+            request
+        );
+
+        let request = TargetRequest {
+            response_path: Some("$.data.items[*].price".to_string()),
+            ..TargetRequest::new("https://retrack.dev".parse()?)
+        };
+        let request_json =
+            json!({ "url": "https://retrack.dev/", "responsePath": "$.data.items[*].price" });
+        assert_eq!(serde_json::to_value(&request)?, request_json);
+        assert_eq!(
+            serde_json::from_value::<TargetRequest>(request_json)?,
+            request
+        );
+
         let request = TargetRequest {
             url: "https://retrack.dev".parse()?,
             method: Some(Method::PUT),
@@ -92,6 +151,12 @@ mod tests {
             ),
             body: None,
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({
             "url": "https://retrack.dev/",
@@ -121,6 +186,12 @@ mod tests {
             ),
             body: Some(json!({ "key": "value" })),
             media_type: None,
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({
             "url": "https://retrack.dev/",
@@ -153,6 +224,12 @@ mod tests {
             ),
             body: Some(json!({ "key": "value" })),
             media_type: Some("text/plain; charset=UTF-8".parse()?),
+            response_transform: None,
+            body_format: None,
+            auth_profile: None,
+            pagination: None,
+            cookies: None,
+            response_path: None,
         };
         let request_json = json!({
             "url": "https://retrack.dev/",
@@ -172,6 +249,27 @@ mod tests {
             request
         );
 
+        let request = TargetRequest {
+            cookies: Some(
+                [
+                    ("session".to_string(), "abc".to_string()),
+                    ("locale".to_string(), "en".to_string()),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            ..TargetRequest::new("https://retrack.dev".parse()?)
+        };
+        let request_json = json!({
+            "url": "https://retrack.dev/",
+            "cookies": { "locale": "en", "session": "abc" }
+        });
+        assert_eq!(serde_json::to_value(&request)?, request_json);
+        assert_eq!(
+            serde_json::from_value::<TargetRequest>(request_json)?,
+            request
+        );
+
         Ok(())
     }
 }
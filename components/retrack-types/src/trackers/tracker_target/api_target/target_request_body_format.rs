@@ -0,0 +1,188 @@
+use mediatype::MediaTypeBuf;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Format in which the body of the API target request is sent to the server.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum TargetRequestBodyFormat {
+    /// Body is sent as JSON. This is the default format.
+    Json,
+    /// Body must be a JSON object with scalar values (or arrays of scalar values for the repeated
+    /// fields), and is sent as `application/x-www-form-urlencoded`.
+    Form,
+    /// Body must be a JSON object with scalar values (or arrays of scalar values for the repeated
+    /// fields), and is sent as text fields of the `multipart/form-data` along with the file parts.
+    Multipart {
+        /// Optional file parts to send along with the text fields.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        files: Vec<TargetRequestFilePart>,
+    },
+    /// Body must be either a JSON string or a JSON array of bytes, and is sent as is.
+    Raw {
+        /// The media type of the body, e.g. `text/xml`.
+        #[serde(rename = "contentType")]
+        #[schema(value_type = String)]
+        content_type: MediaTypeBuf,
+    },
+    /// Body must be a JSON string with the Base64-encoded binary content, and is sent decoded.
+    Base64 {
+        /// The media type of the body. By default, `application/octet-stream` is assumed.
+        #[serde(
+            rename = "contentType",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        #[schema(value_type = String)]
+        content_type: Option<MediaTypeBuf>,
+    },
+}
+
+/// File part of the `multipart/form-data` API target request body.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetRequestFilePart {
+    /// The name of the form field.
+    pub name: String,
+    /// The name of the file reported to the server.
+    pub file_name: String,
+    /// The media type of the file. By default, `application/octet-stream` is assumed.
+    #[schema(value_type = String)]
+    pub content_type: Option<MediaTypeBuf>,
+    /// The name of the secret with the file content.
+    pub secret: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TargetRequestBodyFormat, TargetRequestFilePart};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TargetRequestBodyFormat::Json, @r###"
+        {
+          "type": "json"
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestBodyFormat::Form, @r###"
+        {
+          "type": "form"
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestBodyFormat::Multipart { files: vec![] }, @r###"
+        {
+          "type": "multipart"
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestBodyFormat::Multipart {
+            files: vec![TargetRequestFilePart {
+                name: "file".to_string(),
+                file_name: "report.csv".to_string(),
+                content_type: Some("text/csv".parse()?),
+                secret: "report".to_string(),
+            }, TargetRequestFilePart {
+                name: "key".to_string(),
+                file_name: "key.pem".to_string(),
+                content_type: None,
+                secret: "key".to_string(),
+            }]
+        }, @r###"
+        {
+          "type": "multipart",
+          "files": [
+            {
+              "name": "file",
+              "fileName": "report.csv",
+              "contentType": "text/csv",
+              "secret": "report"
+            },
+            {
+              "name": "key",
+              "fileName": "key.pem",
+              "secret": "key"
+            }
+          ]
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestBodyFormat::Raw {
+            content_type: "text/xml; charset=utf-8".parse()?,
+        }, @r###"
+        {
+          "type": "raw",
+          "contentType": "text/xml; charset=utf-8"
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestBodyFormat::Base64 { content_type: None }, @r###"
+        {
+          "type": "base64"
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestBodyFormat::Base64 {
+            content_type: Some("image/png".parse()?),
+        }, @r###"
+        {
+          "type": "base64",
+          "contentType": "image/png"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(json!({ "type": "json" }))?,
+            TargetRequestBodyFormat::Json
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(json!({ "type": "form" }))?,
+            TargetRequestBodyFormat::Form
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(json!({ "type": "multipart" }))?,
+            TargetRequestBodyFormat::Multipart { files: vec![] }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(json!({
+                "type": "multipart",
+                "files": [{ "name": "file", "fileName": "report.csv", "secret": "report" }]
+            }))?,
+            TargetRequestBodyFormat::Multipart {
+                files: vec![TargetRequestFilePart {
+                    name: "file".to_string(),
+                    file_name: "report.csv".to_string(),
+                    content_type: None,
+                    secret: "report".to_string(),
+                }]
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(
+                json!({ "type": "raw", "contentType": "text/xml" })
+            )?,
+            TargetRequestBodyFormat::Raw {
+                content_type: "text/xml".parse()?,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(json!({ "type": "base64" }))?,
+            TargetRequestBodyFormat::Base64 { content_type: None }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestBodyFormat>(
+                json!({ "type": "base64", "contentType": "image/png" })
+            )?,
+            TargetRequestBodyFormat::Base64 {
+                content_type: Some("image/png".parse()?),
+            }
+        );
+
+        Ok(())
+    }
+}
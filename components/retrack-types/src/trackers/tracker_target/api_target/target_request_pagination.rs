@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Defines how the subsequent pages of the paginated API response are requested. Items from all
+/// pages are concatenated into a single JSON array that is treated as the response of the request.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetRequestPagination {
+    /// Defines how the next page is requested.
+    pub strategy: TargetRequestPaginationStrategy,
+    /// Optional path to the array of items within the page response (e.g., `$.data.items`). If not
+    /// specified, the page response itself must be an array.
+    pub items_path: Option<String>,
+    /// The maximum number of pages to request, including the first one.
+    pub max_pages: usize,
+}
+
+/// Strategy used to request the next page of the paginated API response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum TargetRequestPaginationStrategy {
+    /// URL of the next page is taken from the `Link` response header with `rel="next"`. Pagination
+    /// stops when the header doesn't have such a link.
+    LinkHeader,
+    /// Cursor of the next page is taken from the page response and sent as a query parameter.
+    /// Pagination stops when the cursor is missing, `null`, or empty.
+    Cursor {
+        /// Path to the cursor within the page response (e.g., `$.meta.nextCursor`).
+        path: String,
+        /// The name of the query parameter to send the cursor with.
+        param: String,
+    },
+    /// Page number is sent as a query parameter and incremented for every next page. Pagination
+    /// stops when the page doesn't have any items.
+    PageNumber {
+        /// The name of the query parameter to send the page number with.
+        param: String,
+        /// The number of the first page. By default, pages are numbered starting from `1`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start: Option<usize>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TargetRequestPagination, TargetRequestPaginationStrategy};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::LinkHeader,
+            items_path: None,
+            max_pages: 5,
+        }, @r###"
+        {
+          "strategy": {
+            "type": "linkHeader"
+          },
+          "maxPages": 5
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::Cursor {
+                path: "$.meta.next".to_string(),
+                param: "cursor".to_string(),
+            },
+            items_path: Some("$.data".to_string()),
+            max_pages: 10,
+        }, @r###"
+        {
+          "strategy": {
+            "type": "cursor",
+            "path": "$.meta.next",
+            "param": "cursor"
+          },
+          "itemsPath": "$.data",
+          "maxPages": 10
+        }
+        "###);
+        assert_json_snapshot!(TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::PageNumber {
+                param: "page".to_string(),
+                start: Some(0),
+            },
+            items_path: None,
+            max_pages: 3,
+        }, @r###"
+        {
+          "strategy": {
+            "type": "pageNumber",
+            "param": "page",
+            "start": 0
+          },
+          "maxPages": 3
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TargetRequestPagination>(json!({
+                "strategy": { "type": "linkHeader" },
+                "maxPages": 5
+            }))?,
+            TargetRequestPagination {
+                strategy: TargetRequestPaginationStrategy::LinkHeader,
+                items_path: None,
+                max_pages: 5,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestPagination>(json!({
+                "strategy": { "type": "cursor", "path": "$.meta.next", "param": "cursor" },
+                "itemsPath": "$.data",
+                "maxPages": 10
+            }))?,
+            TargetRequestPagination {
+                strategy: TargetRequestPaginationStrategy::Cursor {
+                    path: "$.meta.next".to_string(),
+                    param: "cursor".to_string(),
+                },
+                items_path: Some("$.data".to_string()),
+                max_pages: 10,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetRequestPagination>(json!({
+                "strategy": { "type": "pageNumber", "param": "page" },
+                "maxPages": 3
+            }))?,
+            TargetRequestPagination {
+                strategy: TargetRequestPaginationStrategy::PageNumber {
+                    param: "page".to_string(),
+                    start: None,
+                },
+                items_path: None,
+                max_pages: 3,
+            }
+        );
+
+        Ok(())
+    }
+}
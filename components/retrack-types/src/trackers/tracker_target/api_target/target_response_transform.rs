@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Transformation applied to the raw API target response right after it's fetched and before it's
+/// parsed or passed to the extractor script.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum TargetResponseTransform {
+    /// Verifies the signature of the JWS (compact serialization) response and replaces response
+    /// with the signed payload.
+    Jws {
+        /// The name of the secret with the verification key.
+        secret: String,
+    },
+    /// Decrypts the JWE (compact serialization) response and replaces response with the
+    /// decrypted plaintext.
+    Jwe {
+        /// The name of the secret with the decryption key.
+        secret: String,
+    },
+}
+
+impl TargetResponseTransform {
+    /// Returns the name of the secret the transform relies on.
+    pub fn secret(&self) -> &str {
+        match self {
+            Self::Jws { secret } | Self::Jwe { secret } => secret,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TargetResponseTransform;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TargetResponseTransform::Jws { secret: "jws-key".to_string() }, @r###"
+        {
+          "type": "jws",
+          "secret": "jws-key"
+        }
+        "###);
+        assert_json_snapshot!(TargetResponseTransform::Jwe { secret: "jwe-key".to_string() }, @r###"
+        {
+          "type": "jwe",
+          "secret": "jwe-key"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TargetResponseTransform>(
+                json!({ "type": "jws", "secret": "jws-key" })
+            )?,
+            TargetResponseTransform::Jws {
+                secret: "jws-key".to_string()
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<TargetResponseTransform>(
+                json!({ "type": "jwe", "secret": "jwe-key" })
+            )?,
+            TargetResponseTransform::Jwe {
+                secret: "jwe-key".to_string()
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn returns_secret() {
+        assert_eq!(
+            TargetResponseTransform::Jws {
+                secret: "jws-key".to_string()
+            }
+            .secret(),
+            "jws-key"
+        );
+        assert_eq!(
+            TargetResponseTransform::Jwe {
+                secret: "jwe-key".to_string()
+            }
+            .secret(),
+            "jwe-key"
+        );
+    }
+}
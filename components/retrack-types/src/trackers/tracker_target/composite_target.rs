@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+mod merger_script_args;
+mod merger_script_input;
+mod merger_script_result;
+
+pub use self::{
+    merger_script_args::MergerScriptArgs, merger_script_input::MergerScriptInput,
+    merger_script_result::MergerScriptResult,
+};
+
+/// Tracker's target that derives its data from the latest data revisions of other trackers (e.g.,
+/// the lowest price of the product across multiple stores). Input trackers are referenced by ID or
+/// by tags, and their data is merged with the "merger" script. Composite trackers are always run
+/// after their input trackers that are due at the same time.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositeTarget {
+    /// Optional list of IDs of the input trackers.
+    pub trackers: Option<Vec<Uuid>>,
+
+    /// Optional list of tags of the input trackers. Trackers that have all the specified tags are
+    /// used as inputs.
+    pub tags: Option<Vec<String>>,
+
+    /// Content of the script (or URL of the remote script) that merges the latest data revisions
+    /// of the input trackers into a single value. The script has access to the tracker tags,
+    /// previous content, and the inputs through the global `context` variable, and should return
+    /// an object with the `content` property.
+    pub merger: String,
+}
+
+impl CompositeTarget {
+    /// Creates a new composite target with the given input trackers and the merger script.
+    pub fn new(trackers: Vec<Uuid>, merger: impl Into<String>) -> Self {
+        Self {
+            trackers: Some(trackers),
+            tags: None,
+            merger: merger.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::CompositeTarget;
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = CompositeTarget::new(
+            vec![uuid!("00000000-0000-0000-0000-000000000001")],
+            "(() => ({ content: context.inputs.length }))();",
+        );
+        let target_json = json!({
+            "trackers": ["00000000-0000-0000-0000-000000000001"],
+            "merger": "(() => ({ content: context.inputs.length }))();"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<CompositeTarget>(target_json)?,
+            target
+        );
+
+        let target = CompositeTarget {
+            trackers: None,
+            tags: Some(vec!["product:one".to_string()]),
+            merger: "https://retrack.dev/merger.js".to_string(),
+        };
+        let target_json = json!({
+            "tags": ["product:one"],
+            "merger": "https://retrack.dev/merger.js"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<CompositeTarget>(target_json)?,
+            target
+        );
+
+        Ok(())
+    }
+}
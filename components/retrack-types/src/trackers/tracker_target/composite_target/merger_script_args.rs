@@ -0,0 +1,54 @@
+use crate::trackers::{MergerScriptInput, TrackerDataValue};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Context available to the "merger" scripts through global `context` variable.
+#[skip_serializing_none]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MergerScriptArgs {
+    /// Tags associated with the tracker.
+    pub tags: Vec<String>,
+
+    /// Optional previous content.
+    pub previous_content: Option<TrackerDataValue>,
+
+    /// Latest data revisions of the input trackers.
+    pub inputs: Vec<MergerScriptInput>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{MergerScriptArgs, MergerScriptInput, TrackerDataValue};
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let context = MergerScriptArgs::default();
+        let context_json = json!({ "tags": [], "inputs": [] });
+        assert_eq!(serde_json::to_value(&context)?, context_json);
+
+        let context = MergerScriptArgs {
+            tags: vec!["tag1".to_string()],
+            previous_content: Some(TrackerDataValue::new(json!({ "price": 9 }))),
+            inputs: vec![MergerScriptInput {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_name: "store-one".to_string(),
+                content: TrackerDataValue::new(json!({ "price": 10 })),
+            }],
+        };
+        let context_json = json!({
+            "tags": ["tag1"],
+            "previousContent": { "original": { "price": 9 } },
+            "inputs": [{
+                "trackerId": "00000000-0000-0000-0000-000000000001",
+                "trackerName": "store-one",
+                "content": { "original": { "price": 10 } }
+            }]
+        });
+        assert_eq!(serde_json::to_value(&context)?, context_json);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::trackers::TrackerDataValue;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Latest data revision of the composite target input tracker passed to the "merger" script.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MergerScriptInput {
+    /// ID of the input tracker.
+    pub tracker_id: Uuid,
+
+    /// Name of the input tracker.
+    pub tracker_name: String,
+
+    /// Latest data of the input tracker.
+    pub content: TrackerDataValue,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{MergerScriptInput, TrackerDataValue};
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let input = MergerScriptInput {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_name: "store-one".to_string(),
+            content: TrackerDataValue::new(json!({ "price": 10 })),
+        };
+        assert_eq!(
+            serde_json::to_value(&input)?,
+            json!({
+                "trackerId": "00000000-0000-0000-0000-000000000001",
+                "trackerName": "store-one",
+                "content": { "original": { "price": 10 } }
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+/// Result of the "merger" script execution.
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MergerScriptResult {
+    /// Content derived from the data of the input trackers.
+    pub content: JsonValue,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::MergerScriptResult;
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<MergerScriptResult>(
+                r#"
+{
+    "content": { "minPrice": 10 }
+}
+          "#
+            )?,
+            MergerScriptResult {
+                content: json!({ "minPrice": 10 }),
+            }
+        );
+
+        assert!(serde_json::from_str::<MergerScriptResult>(r#"{}"#).is_err());
+
+        Ok(())
+    }
+}
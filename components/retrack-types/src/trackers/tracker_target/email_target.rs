@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Tracker's target for an IMAP mailbox. Messages matching the search criteria are fetched from
+/// the mailbox folder and tracked as a JSON list of messages (headers and text body), with the
+/// most recent messages listed first.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTarget {
+    /// Host name of the IMAP server (e.g., `imap.example.com`). The connection is always
+    /// established over TLS.
+    pub host: String,
+
+    /// Optional port of the IMAP server, defaults to `993` (IMAP over implicit TLS).
+    pub port: Option<u16>,
+
+    /// Name of the user to log in to the mailbox with.
+    pub username: String,
+
+    /// Name of the secret from the server secrets store that holds the mailbox password.
+    pub password_secret: String,
+
+    /// Optional name of the mailbox folder to search messages in, defaults to `INBOX`.
+    pub folder: Option<String>,
+
+    /// Optional IMAP search criteria (RFC 3501), e.g. `FROM "billing@example.com" SINCE
+    /// 1-Jan-2025`, defaults to `ALL`.
+    pub search: Option<String>,
+
+    /// Optional maximum number of the most recent matched messages to track. If not set, the
+    /// maximum number allowed by the server is tracked.
+    pub max_messages: Option<usize>,
+}
+
+impl EmailTarget {
+    /// Creates a new email target for the specified IMAP server, user, and the name of the secret
+    /// that holds the user password.
+    pub fn new(
+        host: impl Into<String>,
+        username: impl Into<String>,
+        password_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port: None,
+            username: username.into(),
+            password_secret: password_secret.into(),
+            folder: None,
+            search: None,
+            max_messages: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::EmailTarget;
+    use serde_json::json;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = EmailTarget::new("imap.retrack.dev", "user@retrack.dev", "imap-password");
+        let target_json = json!({
+            "host": "imap.retrack.dev",
+            "username": "user@retrack.dev",
+            "passwordSecret": "imap-password"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<EmailTarget>(target_json)?, target);
+
+        let target = EmailTarget {
+            port: Some(1993),
+            folder: Some("Vendors".to_string()),
+            search: Some(r#"FROM "billing@retrack.dev" UNSEEN"#.to_string()),
+            max_messages: Some(10),
+            ..EmailTarget::new("imap.retrack.dev", "user@retrack.dev", "imap-password")
+        };
+        let target_json = json!({
+            "host": "imap.retrack.dev",
+            "port": 1993,
+            "username": "user@retrack.dev",
+            "passwordSecret": "imap-password",
+            "folder": "Vendors",
+            "search": "FROM \"billing@retrack.dev\" UNSEEN",
+            "maxMessages": 10
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<EmailTarget>(target_json)?, target);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for an RSS or Atom feed. The feed is parsed into a structured JSON object with
+/// the feed title, link, and the list of items (title, link, publication date, and content).
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedTarget {
+    /// URL of the RSS or Atom feed to track.
+    pub url: Url,
+
+    /// Optional headers to include in the feed request.
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: Option<HeaderMap>,
+
+    /// Optional maximum number of feed items to track, counting from the top of the feed where
+    /// the most recent items are usually listed. If not set, all items of the feed are tracked.
+    pub max_items: Option<usize>,
+}
+
+impl FeedTarget {
+    /// Creates a new feed target with the given URL.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            headers: None,
+            max_items: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::FeedTarget;
+    use http::header::AUTHORIZATION;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = FeedTarget::new("https://retrack.dev/feed.xml".parse()?);
+        let target_json = json!({ "url": "https://retrack.dev/feed.xml" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<FeedTarget>(target_json)?, target);
+
+        let target = FeedTarget {
+            url: "https://retrack.dev/feed.xml".parse()?,
+            headers: Some(
+                (&[(AUTHORIZATION, "Bearer token".to_string())]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>())
+                    .try_into()?,
+            ),
+            max_items: Some(10),
+        };
+        let target_json = json!({
+            "url": "https://retrack.dev/feed.xml",
+            "headers": { "authorization": "Bearer token" },
+            "maxItems": 10
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<FeedTarget>(target_json)?, target);
+
+        Ok(())
+    }
+}
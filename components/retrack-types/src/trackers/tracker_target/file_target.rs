@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Tracker's target for the files on the local file system of the Retrack server. Files matching
+/// the path are tracked as a JSON list of file metadata (size, modification time, and checksum)
+/// and optional content, sorted by the file path.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileTarget {
+    /// Absolute path to a local file or directory, or a glob pattern (e.g.,
+    /// `/var/reports/**/*.csv`). Directories are tracked recursively. The path should be within
+    /// one of the directories allowed by the server configuration.
+    pub path: String,
+
+    /// Indicates whether to track the text content of the files in addition to their metadata.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub capture_content: bool,
+
+    /// Optional maximum number of the files to track. If not set, the maximum number allowed by
+    /// the server is tracked.
+    pub max_files: Option<usize>,
+}
+
+impl FileTarget {
+    /// Creates a new file target for the specified path or glob pattern.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            capture_content: false,
+            max_files: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::FileTarget;
+    use serde_json::json;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = FileTarget::new("/etc/retrack/config.toml");
+        let target_json = json!({ "path": "/etc/retrack/config.toml" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<FileTarget>(target_json)?, target);
+
+        let target = FileTarget {
+            capture_content: true,
+            max_files: Some(10),
+            ..FileTarget::new("/var/reports/**/*.csv")
+        };
+        let target_json = json!({
+            "path": "/var/reports/**/*.csv",
+            "captureContent": true,
+            "maxFiles": 10
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<FileTarget>(target_json)?, target);
+
+        Ok(())
+    }
+}
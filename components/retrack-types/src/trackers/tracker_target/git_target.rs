@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for a remote git repository served over HTTP(S). Depending on the options,
+/// tracks the latest commit of a reference (hash, author, and message), the list of tags matching
+/// a pattern, or the content of a file at a reference.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GitTarget {
+    /// URL of the remote repository (e.g., `https://github.com/secutils-dev/retrack.git`). The
+    /// server should support the git protocol version 2.
+    pub url: Url,
+
+    /// Optional name of the branch or tag to track (e.g., `main`, `v1.0.0`, or `refs/heads/main`).
+    /// If not set, the default branch of the repository is tracked.
+    pub reference: Option<String>,
+
+    /// Optional glob pattern of the tag names to track (e.g., `v*`). If set, the list of matching
+    /// tags with their commits is tracked instead of a single reference.
+    pub tag_pattern: Option<String>,
+
+    /// Optional path of the file within the repository to track the text content of at the
+    /// reference (e.g., `docs/CHANGELOG.md`).
+    pub path: Option<String>,
+}
+
+impl GitTarget {
+    /// Creates a new git target that tracks the default branch of the repository.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            reference: None,
+            tag_pattern: None,
+            path: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::GitTarget;
+    use serde_json::json;
+    use url::Url;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = GitTarget::new(Url::parse("https://github.com/secutils-dev/retrack.git")?);
+        let target_json = json!({ "url": "https://github.com/secutils-dev/retrack.git" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<GitTarget>(target_json)?, target);
+
+        let target = GitTarget {
+            reference: Some("main".to_string()),
+            path: Some("docs/CHANGELOG.md".to_string()),
+            ..GitTarget::new(Url::parse("https://github.com/secutils-dev/retrack.git")?)
+        };
+        let target_json = json!({
+            "url": "https://github.com/secutils-dev/retrack.git",
+            "reference": "main",
+            "path": "docs/CHANGELOG.md"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<GitTarget>(target_json)?, target);
+
+        let target = GitTarget {
+            tag_pattern: Some("v*".to_string()),
+            ..GitTarget::new(Url::parse("https://github.com/secutils-dev/retrack.git")?)
+        };
+        let target_json = json!({
+            "url": "https://github.com/secutils-dev/retrack.git",
+            "tagPattern": "v*"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<GitTarget>(target_json)?, target);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+mod grpc_descriptor_source;
+
+pub use self::grpc_descriptor_source::GrpcDescriptorSource;
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for a gRPC server. The unary method is called with the request message
+/// converted from JSON using the protobuf descriptor set, and the response message is tracked as
+/// JSON (proto3 JSON mapping).
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcTarget {
+    /// URL of the gRPC server (e.g., `https://grpc.retrack.dev`). Servers with `http` URLs are
+    /// called over HTTP/2 without TLS.
+    pub url: Url,
+
+    /// Fully-qualified name of the unary method to call (e.g., `retrack.v1.Status/GetStatus`).
+    pub method: String,
+
+    /// Protobuf descriptor set that describes the method, and its request and response messages
+    /// along with all their dependencies (e.g., `protoc --include_imports --descriptor_set_out`).
+    pub descriptor: GrpcDescriptorSource,
+
+    /// Optional request message in JSON format (proto3 JSON mapping). If not set, the message with
+    /// all fields set to their default values is sent.
+    pub request: Option<JsonValue>,
+
+    /// Optional headers (metadata) to include in the call.
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: Option<HeaderMap>,
+}
+
+impl GrpcTarget {
+    /// Creates a new gRPC target for the specified server URL, method, and descriptor set.
+    pub fn new(url: Url, method: impl Into<String>, descriptor: GrpcDescriptorSource) -> Self {
+        Self {
+            url,
+            method: method.into(),
+            descriptor,
+            request: None,
+            headers: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{GrpcDescriptorSource, GrpcTarget};
+    use http::header::AUTHORIZATION;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = GrpcTarget::new(
+            "https://grpc.retrack.dev".parse()?,
+            "retrack.v1.Status/GetStatus",
+            GrpcDescriptorSource::Url {
+                url: "https://retrack.dev/status.pb".parse()?,
+            },
+        );
+        let target_json = json!({
+            "url": "https://grpc.retrack.dev/",
+            "method": "retrack.v1.Status/GetStatus",
+            "descriptor": { "type": "url", "url": "https://retrack.dev/status.pb" }
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<GrpcTarget>(target_json)?, target);
+
+        let target = GrpcTarget {
+            request: Some(json!({ "service": "api" })),
+            headers: Some(
+                (&[(AUTHORIZATION, "Bearer token".to_string())]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>())
+                    .try_into()?,
+            ),
+            ..GrpcTarget::new(
+                "http://localhost:50051".parse()?,
+                "retrack.v1.Status/GetStatus",
+                GrpcDescriptorSource::Inline {
+                    value: vec![1, 2, 3],
+                },
+            )
+        };
+        let target_json = json!({
+            "url": "http://localhost:50051/",
+            "method": "retrack.v1.Status/GetStatus",
+            "descriptor": { "type": "inline", "value": "AQID" },
+            "request": { "service": "api" },
+            "headers": { "authorization": "Bearer token" }
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<GrpcTarget>(target_json)?, target);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
+use url::Url;
+use utoipa::ToSchema;
+
+/// Source of the protobuf descriptor set (serialized `google.protobuf.FileDescriptorSet`).
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum GrpcDescriptorSource {
+    /// Descriptor set uploaded along with the target.
+    Inline {
+        /// Base64-encoded binary descriptor set.
+        #[serde_as(as = "Base64")]
+        #[schema(value_type = String, format = Byte)]
+        value: Vec<u8>,
+    },
+    /// Descriptor set that is fetched from the remote URL before every call.
+    Url {
+        /// URL of the binary descriptor set.
+        url: Url,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::GrpcDescriptorSource;
+    use serde_json::json;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let source = GrpcDescriptorSource::Inline {
+            value: b"descriptor".to_vec(),
+        };
+        let source_json = json!({ "type": "inline", "value": "ZGVzY3JpcHRvcg==" });
+        assert_eq!(serde_json::to_value(&source)?, source_json);
+        assert_eq!(
+            serde_json::from_value::<GrpcDescriptorSource>(source_json)?,
+            source
+        );
+
+        let source = GrpcDescriptorSource::Url {
+            url: "https://retrack.dev/descriptor.pb".parse()?,
+        };
+        let source_json = json!({ "type": "url", "url": "https://retrack.dev/descriptor.pb" });
+        assert_eq!(serde_json::to_value(&source)?, source_json);
+        assert_eq!(
+            serde_json::from_value::<GrpcDescriptorSource>(source_json)?,
+            source
+        );
+
+        Ok(())
+    }
+}
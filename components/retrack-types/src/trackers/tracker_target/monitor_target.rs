@@ -0,0 +1,94 @@
+use http::{HeaderMap, Method};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for HTTP availability (uptime) monitoring. Instead of the response body, the
+/// tracker records whether the endpoint is up, the response status code, latency, and the selected
+/// response headers. The request can be repeated several times within a single check to smooth
+/// out the latency and to detect intermittent failures.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorTarget {
+    /// URL of the HTTP endpoint to monitor (e.g., `https://retrack.dev/health`).
+    pub url: Url,
+
+    /// The HTTP method to use to send the request, either `GET` or `HEAD`. By default, `GET` is
+    /// used.
+    #[serde(with = "http_serde::option::method", default)]
+    #[schema(value_type = String)]
+    pub method: Option<Method>,
+
+    /// Optional headers to include in the request.
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: Option<HeaderMap>,
+
+    /// Optional list of the names of the response headers to record (e.g., `server`).
+    pub capture_headers: Option<Vec<String>>,
+
+    /// Optional number of requests to send within a single check. The endpoint is considered up
+    /// only if all requests succeed. By default, a single request is sent.
+    pub requests: Option<usize>,
+}
+
+impl MonitorTarget {
+    /// Creates a new monitor target with the given URL.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            method: None,
+            headers: None,
+            capture_headers: None,
+            requests: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::MonitorTarget;
+    use http::{header::AUTHORIZATION, Method};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = MonitorTarget::new("https://retrack.dev/health".parse()?);
+        let target_json = json!({ "url": "https://retrack.dev/health" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<MonitorTarget>(target_json)?,
+            target
+        );
+
+        let target = MonitorTarget {
+            url: "https://retrack.dev/health".parse()?,
+            method: Some(Method::HEAD),
+            headers: Some(
+                (&[(AUTHORIZATION, "Bearer token".to_string())]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>())
+                    .try_into()?,
+            ),
+            capture_headers: Some(vec!["server".to_string()]),
+            requests: Some(3),
+        };
+        let target_json = json!({
+            "url": "https://retrack.dev/health",
+            "method": "HEAD",
+            "headers": { "authorization": "Bearer token" },
+            "captureHeaders": ["server"],
+            "requests": 3
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<MonitorTarget>(target_json)?,
+            target
+        );
+
+        Ok(())
+    }
+}
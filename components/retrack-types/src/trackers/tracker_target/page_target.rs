@@ -1,13 +1,25 @@
+use crate::trackers::TargetSelector;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::{borrow::Cow, collections::BTreeMap};
 use utoipa::ToSchema;
 
+mod page_target_engine;
+mod page_target_step;
+
+pub use self::{page_target_engine::PageTargetEngine, page_target_step::PageTargetStep};
+
+/// Template of the Playwright scenario composed from the declarative page target steps.
+const PAGE_TARGET_SCENARIO_TEMPLATE: &str = include_str!("./page_target/page_target_scenario.js");
+
 /// Tracker's target for a web page.
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PageTarget {
-    /// A custom script (Playwright scenario) to extract data from the page.
+    /// A custom script (Playwright scenario) to extract data from the page. Must be empty if the
+    /// scenario is defined with the declarative steps instead.
+    #[serde(default)]
     pub extractor: String,
 
     /// Optional parameters to pass to the scripts as part of the context.
@@ -24,11 +36,44 @@ pub struct PageTarget {
         skip_serializing_if = "std::ops::Not::not"
     )]
     pub ignore_https_errors: bool,
+
+    /// Whether to extract the main article (title, byline, and text) from the page the extractor
+    /// script navigated to, and track it instead of the value returned by the script. Useful for
+    /// news and blog pages to ignore changes in ads, navigation, and layout.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub extract_article: bool,
+
+    /// Optional browser engine to load the page with. If not set, the default engine of the web
+    /// scraper (Chromium) is used.
+    pub engine: Option<PageTargetEngine>,
+
+    /// Optional list of steps (navigate, click, fill, wait for selector, and extract) that
+    /// declaratively define the page scenario instead of the custom extractor script.
+    pub steps: Option<Vec<PageTargetStep>>,
+
+    /// Optional declarative selectors (field name to selector) to extract data from the HTML
+    /// content returned by the scenario (the page content if the declarative steps don't extract
+    /// anything). The tracker data is a JSON object with the value extracted for every field.
+    pub selectors: Option<BTreeMap<String, TargetSelector>>,
+}
+
+impl PageTarget {
+    /// Returns the Playwright scenario the web scraper should execute: either the custom extractor
+    /// script, or the script composed from the declarative steps, if they are defined.
+    pub fn scenario(&self) -> serde_json::Result<Cow<'_, str>> {
+        Ok(match self.steps {
+            Some(ref steps) => Cow::Owned(
+                PAGE_TARGET_SCENARIO_TEMPLATE.replace("__STEPS__", &serde_json::to_string(steps)?),
+            ),
+            None => Cow::Borrowed(&self.extractor),
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::PageTarget;
+    use crate::trackers::{PageTarget, PageTargetEngine, PageTargetStep};
+    use insta::assert_snapshot;
     use serde_json::json;
 
     #[test]
@@ -43,16 +88,125 @@ mod tests {
             params: Some(json!({ "param": "value" })),
             user_agent: Some("Retrack/1.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: true,
+            engine: Some(PageTargetEngine::Camoufox),
+            steps: None,
+            selectors: None,
         };
         let target_json = json!({
             "extractor": "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }",
             "params": { "param": "value" },
             "userAgent": "Retrack/1.0.0",
-            "ignoreHTTPSErrors": true
+            "ignoreHTTPSErrors": true,
+            "extractArticle": true,
+            "engine": "camoufox"
         });
         assert_eq!(serde_json::to_value(&target)?, target_json);
         assert_eq!(serde_json::from_value::<PageTarget>(target_json)?, target);
 
+        let target = PageTarget {
+            steps: Some(vec![
+                PageTargetStep::Navigate {
+                    url: "https://retrack.dev/".parse()?,
+                },
+                PageTargetStep::Extract {
+                    selector: "h1".to_string(),
+                    attribute: None,
+                    all: false,
+                },
+            ]),
+            ..Default::default()
+        };
+        let target_json = json!({
+            "extractor": "",
+            "steps": [
+                { "type": "navigate", "url": "https://retrack.dev/" },
+                { "type": "extract", "selector": "h1" }
+            ]
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<PageTarget>(json!({
+                "steps": [
+                    { "type": "navigate", "url": "https://retrack.dev/" },
+                    { "type": "extract", "selector": "h1" }
+                ]
+            }))?,
+            target
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn composes_scenario() -> anyhow::Result<()> {
+        let target = PageTarget {
+            extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(target.scenario()?, target.extractor);
+
+        let target = PageTarget {
+            steps: Some(vec![
+                PageTargetStep::Navigate {
+                    url: "https://retrack.dev/login".parse()?,
+                },
+                PageTargetStep::Fill {
+                    selector: "#username".to_string(),
+                    value: "user's name".to_string(),
+                },
+                PageTargetStep::Click {
+                    selector: "button[type=submit]".to_string(),
+                },
+                PageTargetStep::Extract {
+                    selector: ".releases li".to_string(),
+                    attribute: None,
+                    all: true,
+                },
+            ]),
+            ..Default::default()
+        };
+        assert_snapshot!(target.scenario()?, @r###"
+        // Playwright scenario composed from the declarative page target steps.
+        const steps = [{"type":"navigate","url":"https://retrack.dev/login"},{"type":"fill","selector":"#username","value":"user's name"},{"type":"click","selector":"button[type=submit]"},{"type":"extract","selector":".releases li","all":true}];
+
+        export async function execute(page) {
+          const values = [];
+          for (const step of steps) {
+            switch (step.type) {
+              case 'navigate':
+                await page.goto(step.url);
+                break;
+              case 'click':
+                await page.locator(step.selector).first().click();
+                break;
+              case 'fill':
+                await page.locator(step.selector).first().fill(step.value);
+                break;
+              case 'waitForSelector':
+                await page.locator(step.selector).first().waitFor({ state: 'visible' });
+                break;
+              case 'extract': {
+                const extract = async (element) =>
+                  step.attribute
+                    ? await element.getAttribute(step.attribute)
+                    : ((await element.textContent())?.trim() ?? null);
+                const elements = page.locator(step.selector);
+                values.push(
+                  step.all ? await Promise.all((await elements.all()).map(extract)) : await extract(elements.first()),
+                );
+                break;
+              }
+            }
+          }
+
+          if (values.length === 0) {
+            return await page.content();
+          }
+          return values.length === 1 ? values[0] : values;
+        }
+        "###);
+
         Ok(())
     }
 }
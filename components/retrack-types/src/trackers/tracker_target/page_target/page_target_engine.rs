@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+/// Browser engine the web scraper should use to load the web page.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum PageTargetEngine {
+    /// Chromium browser. This is the default engine supported by any web scraper.
+    Chromium,
+    /// Camoufox, an anti-detect build of the Firefox browser. Should be used for pages that block
+    /// headless Chromium. Not every web scraper deployment supports it.
+    Camoufox,
+}
+
+impl Display for PageTargetEngine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Chromium => write!(f, "chromium"),
+            Self::Camoufox => write!(f, "camoufox"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::PageTargetEngine;
+    use serde_json::json;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        for (engine, engine_json) in [
+            (PageTargetEngine::Chromium, json!("chromium")),
+            (PageTargetEngine::Camoufox, json!("camoufox")),
+        ] {
+            assert_eq!(serde_json::to_value(engine)?, engine_json);
+            assert_eq!(engine.to_string(), engine_json.as_str().unwrap());
+            assert_eq!(
+                serde_json::from_value::<PageTargetEngine>(engine_json)?,
+                engine
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+// Playwright scenario composed from the declarative page target steps.
+const steps = __STEPS__;
+
+export async function execute(page) {
+  const values = [];
+  for (const step of steps) {
+    switch (step.type) {
+      case 'navigate':
+        await page.goto(step.url);
+        break;
+      case 'click':
+        await page.locator(step.selector).first().click();
+        break;
+      case 'fill':
+        await page.locator(step.selector).first().fill(step.value);
+        break;
+      case 'waitForSelector':
+        await page.locator(step.selector).first().waitFor({ state: 'visible' });
+        break;
+      case 'extract': {
+        const extract = async (element) =>
+          step.attribute
+            ? await element.getAttribute(step.attribute)
+            : ((await element.textContent())?.trim() ?? null);
+        const elements = page.locator(step.selector);
+        values.push(
+          step.all ? await Promise.all((await elements.all()).map(extract)) : await extract(elements.first()),
+        );
+        break;
+      }
+    }
+  }
+
+  if (values.length === 0) {
+    return await page.content();
+  }
+  return values.length === 1 ? values[0] : values;
+}
@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Single step of the declarative web page scenario. Steps are executed in the order they are
+/// defined, using the same browser page.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum PageTargetStep {
+    /// Navigates to the specified URL and waits until the page is loaded.
+    Navigate {
+        /// URL of the web page to navigate to.
+        url: Url,
+    },
+    /// Clicks the first element that matches the selector.
+    Click {
+        /// Selector of the element to click (CSS, XPath, or any other selector supported by
+        /// Playwright).
+        selector: String,
+    },
+    /// Fills the first input element that matches the selector with the specified value.
+    Fill {
+        /// Selector of the input element to fill.
+        selector: String,
+        /// Value to fill the input element with.
+        value: String,
+    },
+    /// Waits until an element that matches the selector becomes visible.
+    WaitForSelector {
+        /// Selector of the element to wait for.
+        selector: String,
+    },
+    /// Extracts the text content, or the value of the specified attribute, of the elements that
+    /// match the selector. If the scenario has a single `extract` step, its value becomes the
+    /// tracker data, otherwise the tracker data is the list of the values of all `extract` steps.
+    Extract {
+        /// Selector of the elements to extract data from.
+        selector: String,
+        /// Optional name of the attribute to extract instead of the text content.
+        attribute: Option<String>,
+        /// Whether to extract the list of values for all matching elements instead of the value of
+        /// the first matching element only.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        all: bool,
+    },
+}
+
+impl PageTargetStep {
+    /// Returns the selector the step operates on, if any.
+    pub fn selector(&self) -> Option<&str> {
+        match self {
+            Self::Navigate { .. } => None,
+            Self::Click { selector }
+            | Self::Fill { selector, .. }
+            | Self::WaitForSelector { selector }
+            | Self::Extract { selector, .. } => Some(selector),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::PageTargetStep;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(vec![
+            PageTargetStep::Navigate { url: "https://retrack.dev/login".parse()? },
+            PageTargetStep::Fill { selector: "#username".to_string(), value: "user".to_string() },
+            PageTargetStep::Click { selector: "button[type=submit]".to_string() },
+            PageTargetStep::WaitForSelector { selector: ".releases".to_string() },
+            PageTargetStep::Extract { selector: ".releases li".to_string(), attribute: None, all: true },
+            PageTargetStep::Extract { selector: "a.latest".to_string(), attribute: Some("href".to_string()), all: false },
+        ], @r###"
+        [
+          {
+            "type": "navigate",
+            "url": "https://retrack.dev/login"
+          },
+          {
+            "type": "fill",
+            "selector": "#username",
+            "value": "user"
+          },
+          {
+            "type": "click",
+            "selector": "button[type=submit]"
+          },
+          {
+            "type": "waitForSelector",
+            "selector": ".releases"
+          },
+          {
+            "type": "extract",
+            "selector": ".releases li",
+            "all": true
+          },
+          {
+            "type": "extract",
+            "selector": "a.latest",
+            "attribute": "href"
+          }
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<Vec<PageTargetStep>>(json!([
+                { "type": "navigate", "url": "https://retrack.dev/login" },
+                { "type": "fill", "selector": "#username", "value": "user" },
+                { "type": "click", "selector": "button[type=submit]" },
+                { "type": "waitForSelector", "selector": ".releases" },
+                { "type": "extract", "selector": ".releases li", "all": true },
+                { "type": "extract", "selector": "a.latest", "attribute": "href" }
+            ]))?,
+            vec![
+                PageTargetStep::Navigate {
+                    url: "https://retrack.dev/login".parse()?
+                },
+                PageTargetStep::Fill {
+                    selector: "#username".to_string(),
+                    value: "user".to_string()
+                },
+                PageTargetStep::Click {
+                    selector: "button[type=submit]".to_string()
+                },
+                PageTargetStep::WaitForSelector {
+                    selector: ".releases".to_string()
+                },
+                PageTargetStep::Extract {
+                    selector: ".releases li".to_string(),
+                    attribute: None,
+                    all: true
+                },
+                PageTargetStep::Extract {
+                    selector: "a.latest".to_string(),
+                    attribute: Some("href".to_string()),
+                    all: false
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn selector() -> anyhow::Result<()> {
+        assert_eq!(
+            PageTargetStep::Navigate {
+                url: "https://retrack.dev".parse()?
+            }
+            .selector(),
+            None
+        );
+        assert_eq!(
+            PageTargetStep::Fill {
+                selector: "#username".to_string(),
+                value: "user".to_string()
+            }
+            .selector(),
+            Some("#username")
+        );
+
+        Ok(())
+    }
+}
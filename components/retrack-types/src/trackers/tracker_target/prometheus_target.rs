@@ -0,0 +1,98 @@
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for a Prometheus metrics endpoint (text exposition or OpenMetrics format). The
+/// selected samples are recorded as a JSON object with the sample values keyed by the series name
+/// with the sorted labels (e.g., `http_requests_total{code="200",method="get"}`), so that the
+/// values can be watched with the tracker data paths. Sample timestamps are ignored.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PrometheusTarget {
+    /// URL of the metrics endpoint to scrape (e.g., `https://retrack.dev/metrics`).
+    pub url: Url,
+
+    /// Optional headers to include in the scrape request.
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: Option<HeaderMap>,
+
+    /// Optional list of the names of the metrics to record. Both the metric family names (e.g.,
+    /// `http_request_duration_seconds`) and the sample names (e.g.,
+    /// `http_request_duration_seconds_count`) are supported. If not set, all metrics are recorded.
+    pub metrics: Option<Vec<String>>,
+
+    /// Optional labels (name to value) the samples must have to be recorded.
+    pub labels: Option<BTreeMap<String, String>>,
+
+    /// Optional maximum number of samples to record. If not set, all selected samples are
+    /// recorded, up to the limit defined by the server.
+    pub max_samples: Option<usize>,
+}
+
+impl PrometheusTarget {
+    /// Creates a new Prometheus target with the given URL.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            headers: None,
+            metrics: None,
+            labels: None,
+            max_samples: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::PrometheusTarget;
+    use http::header::AUTHORIZATION;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = PrometheusTarget::new("https://retrack.dev/metrics".parse()?);
+        let target_json = json!({ "url": "https://retrack.dev/metrics" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<PrometheusTarget>(target_json)?,
+            target
+        );
+
+        let target = PrometheusTarget {
+            url: "https://retrack.dev/metrics".parse()?,
+            headers: Some(
+                (&[(AUTHORIZATION, "Bearer token".to_string())]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>())
+                    .try_into()?,
+            ),
+            metrics: Some(vec!["http_requests_total".to_string()]),
+            labels: Some(
+                [("method".to_string(), "get".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            max_samples: Some(100),
+        };
+        let target_json = json!({
+            "url": "https://retrack.dev/metrics",
+            "headers": { "authorization": "Bearer token" },
+            "metrics": ["http_requests_total"],
+            "labels": { "method": "get" },
+            "maxSamples": 100
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<PrometheusTarget>(target_json)?,
+            target
+        );
+
+        Ok(())
+    }
+}
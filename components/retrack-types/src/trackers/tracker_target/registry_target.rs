@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for the container image in a registry that implements Docker Registry HTTP API
+/// V2 (Docker Hub, GitHub Container Registry, etc.). Tracks either the list of image tags, with the
+/// latest versions listed first, or the manifest digest of a specific tag.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryTarget {
+    /// Optional URL of the registry (e.g., `https://ghcr.io`). If not set, Docker Hub is used.
+    pub registry: Option<Url>,
+
+    /// Name of the image repository within the registry (e.g., `secutils-dev/retrack`). Official
+    /// Docker Hub images can be referenced without the `library/` prefix (e.g., `nginx`).
+    pub image: String,
+
+    /// Optional tag to track the manifest digest of (e.g., `latest`). If set, the digest of the
+    /// tag is tracked instead of the list of tags, which allows detecting re-published tags.
+    pub tag: Option<String>,
+
+    /// Optional glob pattern of the tags to track (e.g., `1.*`). If not set, all tags are tracked.
+    pub tag_pattern: Option<String>,
+
+    /// Optional maximum number of the latest tags to track. If not set, the maximum number allowed
+    /// by the server is tracked.
+    pub max_tags: Option<usize>,
+
+    /// Optional username to authenticate with. If not set, the registry is accessed anonymously,
+    /// which only works for public images.
+    pub username: Option<String>,
+
+    /// Name of the secret from the server secrets store that holds the password or access token,
+    /// required if the username is set.
+    pub password_secret: Option<String>,
+}
+
+impl RegistryTarget {
+    /// Creates a new registry target that tracks tags of the specified Docker Hub image.
+    pub fn new(image: impl Into<String>) -> Self {
+        Self {
+            registry: None,
+            image: image.into(),
+            tag: None,
+            tag_pattern: None,
+            max_tags: None,
+            username: None,
+            password_secret: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::RegistryTarget;
+    use serde_json::json;
+    use url::Url;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = RegistryTarget::new("nginx");
+        let target_json = json!({ "image": "nginx" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<RegistryTarget>(target_json)?,
+            target
+        );
+
+        let target = RegistryTarget {
+            registry: Some(Url::parse("https://ghcr.io")?),
+            tag_pattern: Some("1.*".to_string()),
+            max_tags: Some(10),
+            username: Some("retrack".to_string()),
+            password_secret: Some("ghcr-token".to_string()),
+            ..RegistryTarget::new("secutils-dev/retrack")
+        };
+        let target_json = json!({
+            "registry": "https://ghcr.io/",
+            "image": "secutils-dev/retrack",
+            "tagPattern": "1.*",
+            "maxTags": 10,
+            "username": "retrack",
+            "passwordSecret": "ghcr-token"
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<RegistryTarget>(target_json)?,
+            target
+        );
+
+        let target = RegistryTarget {
+            tag: Some("latest".to_string()),
+            ..RegistryTarget::new("nginx")
+        };
+        let target_json = json!({ "image": "nginx", "tag": "latest" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<RegistryTarget>(target_json)?,
+            target
+        );
+
+        Ok(())
+    }
+}
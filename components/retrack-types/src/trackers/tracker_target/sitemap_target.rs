@@ -0,0 +1,80 @@
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for an XML sitemap or sitemap index. The sitemap is parsed into a structured
+/// JSON object with the list of page URLs (location and last modification date) sorted by the
+/// location, so that the pages added to or removed from the site are easy to spot. Sitemaps listed
+/// in the sitemap index are fetched and merged together.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SitemapTarget {
+    /// URL of the sitemap or sitemap index to track (e.g., `https://retrack.dev/sitemap.xml`).
+    /// Sitemaps compressed with gzip are supported as well.
+    pub url: Url,
+
+    /// Optional headers to include in the sitemap requests.
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: Option<HeaderMap>,
+
+    /// Optional maximum number of page URLs to track. If not set, all page URLs of the sitemap are
+    /// tracked, up to the limit defined by the server.
+    pub max_urls: Option<usize>,
+}
+
+impl SitemapTarget {
+    /// Creates a new sitemap target with the given URL.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            headers: None,
+            max_urls: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::SitemapTarget;
+    use http::header::AUTHORIZATION;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = SitemapTarget::new("https://retrack.dev/sitemap.xml".parse()?);
+        let target_json = json!({ "url": "https://retrack.dev/sitemap.xml" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<SitemapTarget>(target_json)?,
+            target
+        );
+
+        let target = SitemapTarget {
+            url: "https://retrack.dev/sitemap.xml".parse()?,
+            headers: Some(
+                (&[(AUTHORIZATION, "Bearer token".to_string())]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>())
+                    .try_into()?,
+            ),
+            max_urls: Some(100),
+        };
+        let target_json = json!({
+            "url": "https://retrack.dev/sitemap.xml",
+            "headers": { "authorization": "Bearer token" },
+            "maxUrls": 100
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(
+            serde_json::from_value::<SitemapTarget>(target_json)?,
+            target
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Tracker's target for a streaming endpoint: WebSocket (`ws` or `wss` URL) or Server-Sent Events
+/// (`http` or `https` URL). Messages are collected until either the sampling window elapses, the
+/// maximum number of messages is received, or the server closes the connection. Collected messages
+/// are stored as a JSON array, messages that are valid JSON are stored as parsed values, and as
+/// strings otherwise.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamTarget {
+    /// URL of the WebSocket (e.g., `wss://retrack.dev/ws`) or Server-Sent Events (e.g.,
+    /// `https://retrack.dev/events`) endpoint.
+    pub url: Url,
+
+    /// Optional headers to include in the connection request.
+    #[serde(with = "crate::serde_ext::sorted_header_map", default)]
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: Option<HeaderMap>,
+
+    /// Optional text messages to send right after the WebSocket connection is established, e.g. to
+    /// subscribe to the specific channels. Not supported for Server-Sent Events endpoints.
+    pub messages: Option<Vec<String>>,
+
+    /// Optional maximum number of messages to collect. If not set, messages are collected up to
+    /// the limit defined by the server.
+    pub max_messages: Option<usize>,
+
+    /// Optional number of milliseconds to collect messages for. If not set, the default window
+    /// defined by the server is used.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    pub window: Option<Duration>,
+}
+
+impl StreamTarget {
+    /// Creates a new stream target with the given URL.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            headers: None,
+            messages: None,
+            max_messages: None,
+            window: None,
+        }
+    }
+
+    /// Checks if the target is a WebSocket endpoint, otherwise it's a Server-Sent Events endpoint.
+    pub fn is_websocket(&self) -> bool {
+        matches!(self.url.scheme(), "ws" | "wss")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::StreamTarget;
+    use http::header::AUTHORIZATION;
+    use serde_json::json;
+    use std::{collections::HashMap, time::Duration};
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let target = StreamTarget::new("https://retrack.dev/events".parse()?);
+        let target_json = json!({ "url": "https://retrack.dev/events" });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<StreamTarget>(target_json)?, target);
+
+        let target = StreamTarget {
+            url: "wss://retrack.dev/ws".parse()?,
+            headers: Some(
+                (&[(AUTHORIZATION, "Bearer token".to_string())]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>())
+                    .try_into()?,
+            ),
+            messages: Some(vec![r#"{"subscribe":"prices"}"#.to_string()]),
+            max_messages: Some(10),
+            window: Some(Duration::from_secs(5)),
+        };
+        let target_json = json!({
+            "url": "wss://retrack.dev/ws",
+            "headers": { "authorization": "Bearer token" },
+            "messages": [r#"{"subscribe":"prices"}"#],
+            "maxMessages": 10,
+            "window": 5000
+        });
+        assert_eq!(serde_json::to_value(&target)?, target_json);
+        assert_eq!(serde_json::from_value::<StreamTarget>(target_json)?, target);
+
+        Ok(())
+    }
+
+    #[test]
+    fn detects_websocket_endpoints() -> anyhow::Result<()> {
+        assert!(StreamTarget::new("ws://retrack.dev/ws".parse()?).is_websocket());
+        assert!(StreamTarget::new("wss://retrack.dev/ws".parse()?).is_websocket());
+        assert!(!StreamTarget::new("https://retrack.dev/events".parse()?).is_websocket());
+
+        Ok(())
+    }
+}
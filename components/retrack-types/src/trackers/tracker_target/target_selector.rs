@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Declarative selector of the HTML elements to extract data from, without a custom extractor
+/// script. Exactly one of the CSS selector or XPath expression should be specified.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetSelector {
+    /// CSS selector of the elements to extract data from.
+    pub css: Option<String>,
+    /// XPath expression of the nodes to extract data from. Expressions that evaluate to a string,
+    /// number, or boolean (e.g., `count(//li)`) are extracted as is.
+    pub xpath: Option<String>,
+    /// Optional name of the attribute to extract instead of the text content.
+    pub attribute: Option<String>,
+    /// Whether to extract the list of values for all matching elements instead of the value of the
+    /// first matching element only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub all: bool,
+}
+
+impl TargetSelector {
+    /// Creates a new selector with the given CSS selector.
+    pub fn css(selector: impl Into<String>) -> Self {
+        Self {
+            css: Some(selector.into()),
+            xpath: None,
+            attribute: None,
+            all: false,
+        }
+    }
+
+    /// Creates a new selector with the given XPath expression.
+    pub fn xpath(expression: impl Into<String>) -> Self {
+        Self {
+            css: None,
+            xpath: Some(expression.into()),
+            attribute: None,
+            all: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TargetSelector;
+    use serde_json::json;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let selector = TargetSelector::css(".releases li");
+        let selector_json = json!({ "css": ".releases li" });
+        assert_eq!(serde_json::to_value(&selector)?, selector_json);
+        assert_eq!(
+            serde_json::from_value::<TargetSelector>(selector_json)?,
+            selector
+        );
+
+        let selector = TargetSelector {
+            attribute: Some("href".to_string()),
+            all: true,
+            ..TargetSelector::xpath("//a[@class='download']")
+        };
+        let selector_json =
+            json!({ "xpath": "//a[@class='download']", "attribute": "href", "all": true });
+        assert_eq!(serde_json::to_value(&selector)?, selector_json);
+        assert_eq!(
+            serde_json::from_value::<TargetSelector>(selector_json)?,
+            selector
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Notifications rendered for a tracker without sending them.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerTemplatesPreview {
+    /// Email notification sent by the tracker email actions.
+    pub email: TrackerEmailPreview,
+    /// JSON body of the request sent by the tracker webhook actions. Webhooks aren't called on
+    /// tracker check failures, so it's not rendered for the failure templates.
+    pub webhook: Option<JsonValue>,
+}
+
+/// Email notification rendered for a tracker.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerEmailPreview {
+    /// Email subject.
+    pub subject: String,
+    /// Email body in plain text.
+    pub text: String,
+    /// Email body in HTML, if any.
+    pub html: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerEmailPreview, TrackerTemplatesPreview};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackerTemplatesPreview {
+            email: TrackerEmailPreview {
+                subject: "[Retrack] Change detected: \"tracker\"".to_string(),
+                text: "\"tracker\" tracker detected content changes.".to_string(),
+                html: Some("<p>Changes</p>".to_string()),
+            },
+            webhook: Some(json!({ "price": 10 })),
+        }, @r###"
+        {
+          "email": {
+            "subject": "[Retrack] Change detected: \"tracker\"",
+            "text": "\"tracker\" tracker detected content changes.",
+            "html": "<p>Changes</p>"
+          },
+          "webhook": {
+            "price": 10
+          }
+        }
+        "###);
+
+        assert_json_snapshot!(TrackerTemplatesPreview {
+            email: TrackerEmailPreview {
+                subject: "[Retrack] Check failed: \"tracker\"".to_string(),
+                text: "\"tracker\" tracker failed to check for content changes.".to_string(),
+                html: None,
+            },
+            webhook: None,
+        }, @r###"
+        {
+          "email": {
+            "subject": "[Retrack] Check failed: \"tracker\"",
+            "text": "\"tracker\" tracker failed to check for content changes."
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Parameters for previewing the notification templates rendered for a tracker.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerTemplatesPreviewParams {
+    /// ID of the tracker to render the templates for.
+    pub tracker: Uuid,
+    /// Optional data to render the templates with. If not specified, the data of the latest
+    /// tracker data revision is used.
+    pub data: Option<JsonValue>,
+    /// Optional error message to render the tracker check failure templates with instead of the
+    /// tracker changes templates.
+    #[schema(min_length = 1, max_length = 1000)]
+    pub error_message: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackerTemplatesPreviewParams;
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerTemplatesPreviewParams>(json!({
+                "tracker": "00000000-0000-0000-0000-000000000001"
+            }))?,
+            TrackerTemplatesPreviewParams {
+                tracker: uuid!("00000000-0000-0000-0000-000000000001"),
+                data: None,
+                error_message: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TrackerTemplatesPreviewParams>(json!({
+                "tracker": "00000000-0000-0000-0000-000000000001",
+                "data": { "price": 10 },
+                "errorMessage": "Uh oh."
+            }))?,
+            TrackerTemplatesPreviewParams {
+                tracker: uuid!("00000000-0000-0000-0000-000000000001"),
+                data: Some(json!({ "price": 10 })),
+                error_message: Some("Uh oh.".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+}
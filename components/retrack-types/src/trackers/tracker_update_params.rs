@@ -14,6 +14,8 @@ pub struct TrackerUpdateParams {
     pub name: Option<String>,
     /// Whether the tracker is enabled.
     pub enabled: Option<bool>,
+    /// Whether the tracker is archived. Archived tracker can only be updated to be unarchived.
+    pub archived: Option<bool>,
     /// Target of the tracker (web page, API, or file).
     pub target: Option<TrackerTarget>,
     /// Tracker config.
@@ -43,6 +45,7 @@ mod tests {
         let params = TrackerUpdateParams {
             name: Some("tck".to_string()),
             enabled: None,
+            archived: None,
             target: None,
             config: None,
             tags: None,
@@ -58,6 +61,7 @@ mod tests {
         let params = TrackerUpdateParams {
             name: None,
             enabled: Some(true),
+            archived: None,
             target: None,
             config: None,
             tags: None,
@@ -70,14 +74,30 @@ mod tests {
             })
         );
 
+        let params = TrackerUpdateParams {
+            archived: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&params)?,
+            json!({
+                "archived": true
+            })
+        );
+
         let params = TrackerUpdateParams {
             name: None,
             enabled: None,
+            archived: None,
             target: Some(TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: Some(json!({ "param": "value" })),
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             })),
             config: None,
             tags: None,
@@ -99,11 +119,31 @@ mod tests {
         let params = TrackerUpdateParams {
             name: None,
             enabled: None,
+            archived: None,
             target: None,
             config: Some(TrackerConfig {
                 revisions: 3,
                 timeout: Some(Duration::from_millis(2000)),
                 job: None,
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             }),
             tags: None,
             actions: None,
@@ -121,11 +161,16 @@ mod tests {
         let params = TrackerUpdateParams {
             name: Some("tck".to_string()),
             enabled: Some(false),
+            archived: None,
             target: Some(TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: Some(json!({ "param": "value" })),
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             })),
             config: Some(TrackerConfig {
                 revisions: 3,
@@ -139,6 +184,25 @@ mod tests {
                         max_attempts: 5,
                     })
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             }),
             tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
             actions: None
@@ -176,11 +240,16 @@ mod tests {
         let params = TrackerUpdateParams {
             name: Some("tck".to_string()),
             enabled: Some(true),
+            archived: None,
             target: Some(TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: Some(json!({ "param": "value" })),
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             })),
             config: Some(TrackerConfig {
                 revisions: 3,
@@ -194,12 +263,36 @@ mod tests {
                         max_attempts: 5,
                     })
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             }),
             tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
             actions: Some(vec![TrackerAction::ServerLog, TrackerAction::Webhook(WebhookAction {
                 url: url::Url::parse("https://retrack.dev")?,
                 method: None,
                 headers: None,
+                expected_response: None,
+                timeout: None,
+                signing_secret: None,
+                retry_strategy: None,
+                diff_format: None,
             })])
         };
         assert_eq!(
@@ -249,6 +342,7 @@ mod tests {
             TrackerUpdateParams {
                 name: Some("tck".to_string()),
                 enabled: None,
+                archived: None,
                 target: None,
                 config: None,
                 tags: None,
@@ -267,6 +361,7 @@ mod tests {
             TrackerUpdateParams {
                 name: None,
                 enabled: Some(true),
+                archived: None,
                 target: None,
                 config: None,
                 tags: None,
@@ -291,11 +386,16 @@ mod tests {
             TrackerUpdateParams {
                 name: None,
                 enabled: None,
+                archived: None,
                 target: Some(TrackerTarget::Page(PageTarget {
                     extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                     params: Some(json!({ "param": "value" })),
                     user_agent: Some("Retrack/1.0.0".to_string()),
                     ignore_https_errors: true,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 })),
                 config: None,
                 tags: None,
@@ -316,11 +416,31 @@ mod tests {
             TrackerUpdateParams {
                 name: None,
                 enabled: None,
+                archived: None,
                 target: None,
                 config: Some(TrackerConfig {
                     revisions: 3,
                     timeout: Some(Duration::from_millis(2000)),
-                    job: None
+                    job: None,
+                    fail_on_shape_deviation: false,
+                    change_filter: None,
+                    semantic_filter: None,
+                    skip_first_revision_actions: false,
+                    confirmations: None,
+                    active_hours: None,
+                    active_days: None,
+                    max_content_size: None,
+                    action_concurrency: None,
+                    action_dependencies: None,
+                    action_rules: None,
+                    severity_routing: None,
+                    action_throttles: None,
+                    action_digests: None,
+                    workflow: None,
+                    escalation: None,
+                    quarantine: None,
+                    runbook_url: None,
+                    max_revision_age: None,
                 }),
                 tags: None,
                 actions: None
@@ -361,11 +481,16 @@ mod tests {
             TrackerUpdateParams {
                 name: Some("tck".to_string()),
                 enabled: Some(false),
+                archived: None,
                 target: Some(TrackerTarget::Page(PageTarget {
                     extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                     params: Some(serde_json::json!({ "param": "value" })),
                     user_agent: Some("Retrack/1.0.0".to_string()),
                     ignore_https_errors: true,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 })),
                 config: Some(TrackerConfig {
                     revisions: 3,
@@ -379,6 +504,25 @@ mod tests {
                             max_attempts: 5,
                         })
                     }),
+                    fail_on_shape_deviation: false,
+                    change_filter: None,
+                    semantic_filter: None,
+                    skip_first_revision_actions: false,
+                    confirmations: None,
+                    active_hours: None,
+                    active_days: None,
+                    max_content_size: None,
+                    action_concurrency: None,
+                    action_dependencies: None,
+                    action_rules: None,
+                    severity_routing: None,
+                    action_throttles: None,
+                    action_digests: None,
+                    workflow: None,
+                    escalation: None,
+                    quarantine: None,
+                    runbook_url: None,
+                    max_revision_age: None,
                 }),
                 tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
                 actions: None
@@ -420,11 +564,16 @@ mod tests {
             TrackerUpdateParams {
                 name: Some("tck".to_string()),
                 enabled: Some(true),
+                archived: None,
                 target: Some(TrackerTarget::Page(PageTarget {
                     extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                     params: Some(json!({ "param": "value" })),
                     user_agent: Some("Retrack/1.0.0".to_string()),
                     ignore_https_errors: true,
+                    extract_article: false,
+                    engine: None,
+                    steps: None,
+                    selectors: None,
                 })),
                 config: Some(TrackerConfig {
                     revisions: 3,
@@ -438,12 +587,36 @@ mod tests {
                             max_attempts: 5,
                         })
                     }),
+                    fail_on_shape_deviation: false,
+                    change_filter: None,
+                    semantic_filter: None,
+                    skip_first_revision_actions: false,
+                    confirmations: None,
+                    active_hours: None,
+                    active_days: None,
+                    max_content_size: None,
+                    action_concurrency: None,
+                    action_dependencies: None,
+                    action_rules: None,
+                    severity_routing: None,
+                    action_throttles: None,
+                    action_digests: None,
+                    workflow: None,
+                    escalation: None,
+                    quarantine: None,
+                    runbook_url: None,
+                    max_revision_age: None,
                 }),
                 tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
                 actions: Some(vec![TrackerAction::ServerLog, TrackerAction::Webhook(WebhookAction {
                     url: url::Url::parse("https://retrack.dev")?,
                     method: None,
                     headers: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
+                    diff_format: None,
                 })])
             }
         );
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Simple state machine that moves the tracker between arbitrary states based on the outcome of
+/// its data checks, e.g. to open an incident when the check fails and resolve it once the check
+/// succeeds again.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerWorkflow {
+    /// State the tracker is in before any transition happens (e.g., `resolved`).
+    pub initial_state: String,
+    /// List of transitions between the states. Transitions are evaluated in the order they are
+    /// defined after every data check, and only the first matching transition is applied.
+    pub transitions: Vec<TrackerWorkflowTransition>,
+}
+
+/// Transition of the tracker from one workflow state to another.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerWorkflowTransition {
+    /// Event that triggers the transition.
+    pub on: TrackerWorkflowEvent,
+    /// Optional list of states the transition applies to. If not set, the transition applies to
+    /// any state.
+    pub from: Option<Vec<String>>,
+    /// State the tracker transitions to.
+    pub to: String,
+    /// Optional list of indexes of the tracker actions to execute when the transition happens.
+    /// These actions are reserved for the workflow and aren't executed for every new data
+    /// revision. Unless the transition is triggered by a new data revision, actions receive the
+    /// new state and the error, if any, instead of the tracker data.
+    pub actions: Option<Vec<usize>>,
+}
+
+/// Event that triggers the tracker workflow transition.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackerWorkflowEvent {
+    /// Data check has succeeded, regardless of whether the data has changed.
+    Success,
+    /// Data check has produced a new data revision.
+    Revision,
+    /// Data check has failed.
+    Failure,
+}
+
+impl TrackerWorkflowEvent {
+    /// Checks if the transition triggered by the specified event should be triggered by this event
+    /// as well. New data revision is also a successful data check.
+    pub fn triggers(self, event: TrackerWorkflowEvent) -> bool {
+        self == event || (self == Self::Revision && event == Self::Success)
+    }
+}
+
+impl TrackerWorkflow {
+    /// Returns the first transition that applies to the specified state and is triggered by the
+    /// specified event, if any.
+    pub fn transition(
+        &self,
+        state: &str,
+        event: TrackerWorkflowEvent,
+    ) -> Option<&TrackerWorkflowTransition> {
+        self.transitions.iter().find(|transition| {
+            event.triggers(transition.on)
+                && transition
+                    .from
+                    .as_ref()
+                    .is_none_or(|from| from.iter().any(|from_state| from_state == state))
+        })
+    }
+
+    /// Checks if the tracker action with the specified index is reserved for the workflow.
+    pub fn is_workflow_action(&self, action: usize) -> bool {
+        self.transitions.iter().any(|transition| {
+            transition
+                .actions
+                .as_ref()
+                .is_some_and(|actions| actions.contains(&action))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::{TrackerWorkflow, TrackerWorkflowEvent, TrackerWorkflowTransition};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    fn incident_workflow() -> TrackerWorkflow {
+        TrackerWorkflow {
+            initial_state: "resolved".to_string(),
+            transitions: vec![
+                TrackerWorkflowTransition {
+                    on: TrackerWorkflowEvent::Failure,
+                    from: Some(vec!["resolved".to_string()]),
+                    to: "open".to_string(),
+                    actions: Some(vec![0]),
+                },
+                TrackerWorkflowTransition {
+                    on: TrackerWorkflowEvent::Revision,
+                    from: Some(vec!["open".to_string()]),
+                    to: "resolved".to_string(),
+                    actions: None,
+                },
+                TrackerWorkflowTransition {
+                    on: TrackerWorkflowEvent::Success,
+                    from: None,
+                    to: "resolved".to_string(),
+                    actions: Some(vec![1, 2]),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(incident_workflow(), @r###"
+        {
+          "initialState": "resolved",
+          "transitions": [
+            {
+              "on": "failure",
+              "from": [
+                "resolved"
+              ],
+              "to": "open",
+              "actions": [
+                0
+              ]
+            },
+            {
+              "on": "revision",
+              "from": [
+                "open"
+              ],
+              "to": "resolved"
+            },
+            {
+              "on": "success",
+              "to": "resolved",
+              "actions": [
+                1,
+                2
+              ]
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackerWorkflow>(json!({
+                "initialState": "resolved",
+                "transitions": [
+                    { "on": "failure", "from": ["resolved"], "to": "open", "actions": [0] },
+                    { "on": "revision", "from": ["open"], "to": "resolved" },
+                    { "on": "success", "to": "resolved", "actions": [1, 2] }
+                ]
+            }))?,
+            incident_workflow()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_finds_transitions() {
+        let workflow = incident_workflow();
+
+        let transition = workflow.transition("resolved", TrackerWorkflowEvent::Failure);
+        assert_eq!(transition, Some(&workflow.transitions[0]));
+        assert_eq!(
+            workflow.transition("open", TrackerWorkflowEvent::Failure),
+            None
+        );
+
+        // New data revision is also a successful check, but the first matching transition wins.
+        let transition = workflow.transition("open", TrackerWorkflowEvent::Revision);
+        assert_eq!(transition, Some(&workflow.transitions[1]));
+        let transition = workflow.transition("resolved", TrackerWorkflowEvent::Revision);
+        assert_eq!(transition, Some(&workflow.transitions[2]));
+        let transition = workflow.transition("open", TrackerWorkflowEvent::Success);
+        assert_eq!(transition, Some(&workflow.transitions[2]));
+    }
+
+    #[test]
+    fn properly_detects_workflow_actions() {
+        let workflow = incident_workflow();
+        assert!(workflow.is_workflow_action(0));
+        assert!(workflow.is_workflow_action(2));
+        assert!(!workflow.is_workflow_action(3));
+    }
+}
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Parameters for getting maintenance insights about trackers.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackersInsightsParams {
+    /// List of tags to filter trackers by.
+    #[param(max_items = 10, min_length = 1, max_length = 50)]
+    #[serde(default, rename = "tag")]
+    pub tags: Vec<String>,
+    /// Number of days without any data revisions after which the tracker is considered stale or
+    /// inactive. Defaults to 180 days.
+    #[param(minimum = 1)]
+    pub stale_days: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackersInsightsParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TrackersInsightsParams>(r#"{}"#)?,
+            TrackersInsightsParams::default()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<TrackersInsightsParams>(
+                r#"
+{
+    "tag": ["tag_one"],
+    "staleDays": 30
+}
+          "#
+            )?,
+            TrackersInsightsParams {
+                tags: vec!["tag_one".to_string()],
+                stale_days: Some(30)
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::trackers::TrackersSort;
 use serde::Deserialize;
 use utoipa::IntoParams;
 
@@ -9,17 +10,28 @@ pub struct TrackersListParams {
     #[param(max_items = 10, min_length = 1, max_length = 50)]
     #[serde(default, rename = "tag")]
     pub tags: Vec<String>,
+    /// Case-insensitive search query to filter trackers by name.
+    #[param(max_length = 100)]
+    pub query: Option<String>,
+    /// Order in which trackers are listed, by default trackers are sorted by the last update time.
+    pub sort: Option<TrackersSort>,
+    /// Whether to include archived trackers.
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Whether to include aggregated statistics of the tracker data revisions.
+    #[serde(default)]
+    pub include_stats: bool,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::TrackersListParams;
+    use crate::trackers::{TrackersListParams, TrackersSort};
 
     #[test]
     fn deserialization() -> anyhow::Result<()> {
         assert_eq!(
             serde_json::from_str::<TrackersListParams>(r#"{}"#)?,
-            TrackersListParams { tags: vec![] }
+            TrackersListParams::default()
         );
 
         assert_eq!(
@@ -31,7 +43,32 @@ mod tests {
           "#
             )?,
             TrackersListParams {
-                tags: vec!["tag_one".to_string(), "tag_two".to_string()]
+                tags: vec!["tag_one".to_string(), "tag_two".to_string()],
+                query: None,
+                sort: None,
+                include_archived: false,
+                include_stats: false
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<TrackersListParams>(
+                r#"
+{
+    "tag": ["tag_one"],
+    "query": "Prod",
+    "sort": "-updatedAt",
+    "includeArchived": true,
+    "includeStats": true
+}
+          "#
+            )?,
+            TrackersListParams {
+                tags: vec!["tag_one".to_string()],
+                query: Some("Prod".to_string()),
+                sort: Some(TrackersSort::UpdatedAtDesc),
+                include_archived: true,
+                include_stats: true
             }
         );
 
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Audit record of the trackers data scrub. The record intentionally doesn't include the scrub
+/// pattern itself, since it usually contains the very personal data that has been scrubbed.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackersScrub {
+    /// Unique scrub ID (UUIDv7).
+    pub id: Uuid,
+    /// Reason for the scrub (e.g., deletion request reference), if specified.
+    pub reason: Option<String>,
+    /// SHA-256 digest of the scrub pattern, if specified. Allows verifying whether data matching a
+    /// specific pattern has been scrubbed, without keeping the pattern.
+    pub pattern_digest: Option<String>,
+    /// IDs of the trackers whose data has been scrubbed.
+    pub trackers: Vec<Uuid>,
+    /// Number of the removed data revisions.
+    pub revisions: usize,
+    /// Number of the discarded data changes that were waiting for confirmation.
+    pub pending_data: usize,
+    /// Number of the removed alerts that were pending reporting.
+    pub alerts: usize,
+    /// Number of the removed pending tasks (e.g., email notifications and webhook calls).
+    pub tasks: usize,
+    /// Date and time when the scrub was performed.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackersScrub;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TrackersScrub {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            reason: Some("DSR-42".to_string()),
+            pattern_digest: Some("sha256:ab".to_string()),
+            trackers: vec![uuid!("00000000-0000-0000-0000-000000000002")],
+            revisions: 3,
+            pending_data: 1,
+            alerts: 2,
+            tasks: 4,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "reason": "DSR-42",
+          "patternDigest": "sha256:ab",
+          "trackers": [
+            "00000000-0000-0000-0000-000000000002"
+          ],
+          "revisions": 3,
+          "pendingData": 1,
+          "alerts": 2,
+          "tasks": 4,
+          "createdAt": 946720800
+        }
+        "###);
+
+        assert_json_snapshot!(TrackersScrub {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            reason: None,
+            pattern_digest: None,
+            trackers: vec![],
+            revisions: 0,
+            pending_data: 0,
+            alerts: 0,
+            tasks: 0,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "trackers": [],
+          "revisions": 0,
+          "pendingData": 0,
+          "alerts": 0,
+          "tasks": 0,
+          "createdAt": 946720800
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackersScrub>(json!({
+                "id": "00000000-0000-0000-0000-000000000001",
+                "patternDigest": "sha256:ab",
+                "trackers": ["00000000-0000-0000-0000-000000000002"],
+                "revisions": 3,
+                "pendingData": 1,
+                "alerts": 2,
+                "tasks": 4,
+                "createdAt": 946720800
+            }))?,
+            TrackersScrub {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                reason: None,
+                pattern_digest: Some("sha256:ab".to_string()),
+                trackers: vec![uuid!("00000000-0000-0000-0000-000000000002")],
+                revisions: 3,
+                pending_data: 1,
+                alerts: 2,
+                tasks: 4,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Parameters for scrubbing stored trackers data, e.g. to satisfy a personal data deletion request.
+/// At least one of `pattern` or `trackers` must be specified.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackersScrubParams {
+    /// Regular expression to match stored data against. Data revisions, pending changes, and alerts
+    /// are scrubbed only if any of their values match the pattern. Pending tasks (e.g., email
+    /// notifications and webhook calls) aren't linked to trackers and are scrubbed only if they
+    /// match the pattern. If not specified, all data of the specified trackers is scrubbed.
+    #[schema(min_length = 1, max_length = 1000)]
+    pub pattern: Option<String>,
+    /// List of IDs of the trackers to scrub data of. If empty, data of all trackers is scrubbed.
+    #[schema(max_items = 1000)]
+    #[serde(default)]
+    pub trackers: Vec<Uuid>,
+    /// Optional reason for the scrub (e.g., deletion request reference) recorded in the audit log.
+    #[schema(max_length = 1000)]
+    pub reason: Option<String>,
+    /// Whether to only report what would be scrubbed, without removing any data or recording the
+    /// scrub in the audit log.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackersScrubParams;
+    use serde_json::json;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<TrackersScrubParams>(
+                json!({ "pattern": "john@retrack\\.dev" })
+            )?,
+            TrackersScrubParams {
+                pattern: Some(r"john@retrack\.dev".to_string()),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<TrackersScrubParams>(json!({
+                "pattern": "John Doe",
+                "trackers": ["00000000-0000-0000-0000-000000000001"],
+                "reason": "DSR-42",
+                "dryRun": true
+            }))?,
+            TrackersScrubParams {
+                pattern: Some("John Doe".to_string()),
+                trackers: vec![uuid!("00000000-0000-0000-0000-000000000001")],
+                reason: Some("DSR-42".to_string()),
+                dry_run: true,
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Order in which trackers are listed. Descending variants are prefixed with a dash.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq, Hash, ToSchema)]
+pub enum TrackersSort {
+    /// Sort by tracker name (case-insensitive), A to Z.
+    #[serde(rename = "name")]
+    Name,
+    /// Sort by tracker name (case-insensitive), Z to A.
+    #[serde(rename = "-name")]
+    NameDesc,
+    /// Sort by tracker creation time, oldest first.
+    #[serde(rename = "createdAt")]
+    CreatedAt,
+    /// Sort by tracker creation time, newest first.
+    #[serde(rename = "-createdAt")]
+    CreatedAtDesc,
+    /// Sort by tracker last update time, least recently updated first.
+    #[default]
+    #[serde(rename = "updatedAt")]
+    UpdatedAt,
+    /// Sort by tracker last update time, most recently updated first.
+    #[serde(rename = "-updatedAt")]
+    UpdatedAtDesc,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trackers::TrackersSort;
+    use serde_json::json;
+
+    #[test]
+    fn serialization_and_deserialization() -> anyhow::Result<()> {
+        for (sort, value) in [
+            (TrackersSort::Name, json!("name")),
+            (TrackersSort::NameDesc, json!("-name")),
+            (TrackersSort::CreatedAt, json!("createdAt")),
+            (TrackersSort::CreatedAtDesc, json!("-createdAt")),
+            (TrackersSort::UpdatedAt, json!("updatedAt")),
+            (TrackersSort::UpdatedAtDesc, json!("-updatedAt")),
+        ] {
+            assert_eq!(serde_json::to_value(sort)?, value);
+            assert_eq!(serde_json::from_value::<TrackersSort>(value)?, sort);
+        }
+
+        assert_eq!(TrackersSort::default(), TrackersSort::UpdatedAt);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+mod view;
+mod view_create_params;
+mod view_trackers_params;
+mod view_update_params;
+
+pub use self::{
+    view::View, view_create_params::ViewCreateParams, view_trackers_params::ViewTrackersParams,
+    view_update_params::ViewUpdateParams,
+};
@@ -0,0 +1,163 @@
+use crate::trackers::{TrackersListParams, TrackersSort};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Named combination of the trackers list filters and sort order that can be shared between users
+/// (e.g., "failing prod trackers"), instead of recreating the same filters every time.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// Unique view id (UUIDv7).
+    pub id: Uuid,
+    /// Unique name of the view.
+    pub name: String,
+    /// List of tags the trackers should have to be included into the view.
+    pub tags: Vec<String>,
+    /// Case-insensitive search query to filter trackers by name.
+    pub query: Option<String>,
+    /// Order in which trackers of the view are listed.
+    pub sort: Option<TrackersSort>,
+    /// Whether to include archived trackers into the view.
+    pub include_archived: bool,
+    /// Date and time when the view was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Date and time when the view was last updated.
+    #[serde(with = "time::serde::timestamp")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl View {
+    /// Returns parameters to list the trackers that match the view.
+    pub fn trackers_list_params(&self, include_stats: bool) -> TrackersListParams {
+        TrackersListParams {
+            tags: self.tags.clone(),
+            query: self.query.clone(),
+            sort: self.sort,
+            include_archived: self.include_archived,
+            include_stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        trackers::{TrackersListParams, TrackersSort},
+        views::View,
+    };
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let view = View {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "failing prod".to_string(),
+            tags: vec!["env:prod".to_string(), "failing".to_string()],
+            query: None,
+            sort: None,
+            include_archived: false,
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            // January 1, 2000 10:00:10
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+        assert_json_snapshot!(view, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "failing prod",
+          "tags": [
+            "env:prod",
+            "failing"
+          ],
+          "includeArchived": false,
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        assert_json_snapshot!(View {
+            query: Some("api".to_string()),
+            sort: Some(TrackersSort::NameDesc),
+            include_archived: true,
+            ..view
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "failing prod",
+          "tags": [
+            "env:prod",
+            "failing"
+          ],
+          "query": "api",
+          "sort": "-name",
+          "includeArchived": true,
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<View>(json!({
+                "id": "00000000-0000-0000-0000-000000000001",
+                "name": "failing prod",
+                "tags": ["env:prod", "failing"],
+                "query": "api",
+                "sort": "-name",
+                "includeArchived": true,
+                "createdAt": 946720800,
+                "updatedAt": 946720810
+            }))?,
+            View {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "failing prod".to_string(),
+                tags: vec!["env:prod".to_string(), "failing".to_string()],
+                query: Some("api".to_string()),
+                sort: Some(TrackersSort::NameDesc),
+                include_archived: true,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_to_trackers_list_params() -> anyhow::Result<()> {
+        let view = View {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "failing prod".to_string(),
+            tags: vec!["env:prod".to_string()],
+            query: Some("api".to_string()),
+            sort: Some(TrackersSort::CreatedAtDesc),
+            include_archived: true,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+        assert_eq!(
+            view.trackers_list_params(true),
+            TrackersListParams {
+                tags: vec!["env:prod".to_string()],
+                query: Some("api".to_string()),
+                sort: Some(TrackersSort::CreatedAtDesc),
+                include_archived: true,
+                include_stats: true,
+            }
+        );
+
+        Ok(())
+    }
+}
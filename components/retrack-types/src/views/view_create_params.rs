@@ -0,0 +1,65 @@
+use crate::trackers::TrackersSort;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Parameters for creating a view.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewCreateParams {
+    /// Unique name of the view.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    /// List of tags the trackers should have to be included into the view.
+    #[serde(default)]
+    #[schema(max_items = 20, min_length = 1, max_length = 50)]
+    pub tags: Vec<String>,
+    /// Case-insensitive search query to filter trackers by name.
+    #[schema(max_length = 100)]
+    pub query: Option<String>,
+    /// Order in which trackers of the view are listed.
+    pub sort: Option<TrackersSort>,
+    /// Whether to include archived trackers into the view.
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{trackers::TrackersSort, views::ViewCreateParams};
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ViewCreateParams>(json!({ "name": "all" }))?,
+            ViewCreateParams {
+                name: "all".to_string(),
+                tags: vec![],
+                query: None,
+                sort: None,
+                include_archived: false,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<ViewCreateParams>(json!({
+                "name": "failing prod",
+                "tags": ["env:prod", "failing"],
+                "query": "api",
+                "sort": "-updatedAt",
+                "includeArchived": true
+            }))?,
+            ViewCreateParams {
+                name: "failing prod".to_string(),
+                tags: vec!["env:prod".to_string(), "failing".to_string()],
+                query: Some("api".to_string()),
+                sort: Some(TrackersSort::UpdatedAtDesc),
+                include_archived: true,
+            }
+        );
+
+        Ok(())
+    }
+}
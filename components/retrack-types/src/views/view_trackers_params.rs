@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Parameters for getting a list of trackers that match the view.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewTrackersParams {
+    /// Whether to include aggregated statistics of the tracker data revisions.
+    #[serde(default)]
+    pub include_stats: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::views::ViewTrackersParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<ViewTrackersParams>(r#"{}"#)?,
+            ViewTrackersParams::default()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<ViewTrackersParams>(r#"{ "includeStats": true }"#)?,
+            ViewTrackersParams {
+                include_stats: true
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::trackers::TrackersSort;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use utoipa::ToSchema;
+
+/// Parameters for updating a view.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ViewUpdateParams {
+    /// Unique name of the view.
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: Option<String>,
+    /// List of tags the trackers should have to be included into the view.
+    #[schema(max_items = 20, min_length = 1, max_length = 50)]
+    pub tags: Option<Vec<String>>,
+    /// Case-insensitive search query to filter trackers by name, empty query removes the filter.
+    #[schema(max_length = 100)]
+    pub query: Option<String>,
+    /// Order in which trackers of the view are listed.
+    pub sort: Option<TrackersSort>,
+    /// Whether to include archived trackers into the view.
+    pub include_archived: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{trackers::TrackersSort, views::ViewUpdateParams};
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ViewUpdateParams>(json!({ "name": "all" }))?,
+            ViewUpdateParams {
+                name: Some("all".to_string()),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_value::<ViewUpdateParams>(json!({
+                "tags": ["env:prod"],
+                "query": "",
+                "sort": "name",
+                "includeArchived": false
+            }))?,
+            ViewUpdateParams {
+                name: None,
+                tags: Some(vec!["env:prod".to_string()]),
+                query: Some("".to_string()),
+                sort: Some(TrackersSort::Name),
+                include_archived: Some(false),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -17,7 +17,11 @@ await test('[/api/status] returns version from the config', async () => {
 
   assert.strictEqual(
     response.body,
-    JSON.stringify({ version: configMock.version, browser: { protocol: 'playwright', url: 'ws://localhost:3000' } }),
+    JSON.stringify({
+      version: configMock.version,
+      browser: { protocol: 'playwright', url: 'ws://localhost:3000' },
+      capabilities: { engines: ['chromium'] },
+    }),
   );
   assert.strictEqual(response.statusCode, 200);
 });
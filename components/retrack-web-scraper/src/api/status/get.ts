@@ -1,3 +1,4 @@
+import { SUPPORTED_BROWSER_ENGINES } from '../../utilities/browser.js';
 import type { ApiRouteParams } from '../api_route_params.js';
 
 export function registerStatusGetRoutes({ server, config, getBrowserEndpoint }: ApiRouteParams) {
@@ -18,6 +19,12 @@ export function registerStatusGetRoutes({ server, config, getBrowserEndpoint }:
                   url: { type: 'string', nullable: true },
                 },
               },
+              capabilities: {
+                type: 'object',
+                properties: {
+                  engines: { type: 'array', items: { type: 'string' } },
+                },
+              },
             },
           },
         },
@@ -27,6 +34,7 @@ export function registerStatusGetRoutes({ server, config, getBrowserEndpoint }:
       return {
         version: config.version,
         browser: await getBrowserEndpoint({ launchServer: false }),
+        capabilities: { engines: SUPPORTED_BROWSER_ENGINES },
       };
     },
   );
@@ -0,0 +1,105 @@
+import type { Page } from 'playwright-core';
+
+/**
+ * Represents the main article extracted from a web page.
+ */
+export interface PageArticle {
+  // Article title.
+  title: string;
+  // Article author(s), if available.
+  byline?: string;
+  // Article text, paragraphs are separated by empty lines.
+  text: string;
+}
+
+/**
+ * Extracts the main article from the web page using Readability-style heuristics: page chrome (navigation, headers,
+ * footers, sidebars, ads etc.) is dropped, and the element with the highest density of paragraph text is picked as
+ * the article body.
+ */
+export async function getPageArticle(page: Page): Promise<PageArticle> {
+  // The function is executed in the browser, so it must be self-contained. The result is serialized to a string to
+  // avoid dealing with the Playwright serialization format for complex values.
+  const article = await page.evaluate(function extractArticleContent() {
+    const UNLIKELY_CANDIDATES =
+      /ad-break|agegate|banner|breadcrumbs|combx|comment|community|cookie|disqus|extra|footer|gdpr|header|menu|newsletter|pager|pagination|popup|promo|related|remark|replies|rss|share|shoutbox|sidebar|skyscraper|social|sponsor|supplemental/i;
+    const MAYBE_CANDIDATES = /and|article|body|column|content|main|shadow/i;
+    const IGNORED_ELEMENTS =
+      'script, style, noscript, template, iframe, svg, canvas, form, button, input, select, textarea, nav, aside, footer, header';
+    const BLOCK_ELEMENTS = 'h1, h2, h3, h4, h5, h6, p, pre, li, blockquote';
+
+    const getText = (element: Element) => (element.textContent ?? '').replace(/\s+/g, ' ').trim();
+    const getMeta = (selectors: string[]) =>
+      selectors
+        .map((selector) => document.querySelector<HTMLMetaElement>(selector)?.content?.trim())
+        .find((value) => !!value);
+
+    const title =
+      getMeta(['meta[property="og:title"]', 'meta[name="twitter:title"]']) ??
+      (document.querySelector('h1') ? getText(document.querySelector('h1')!) : document.title.trim());
+    const bylineElement = document.querySelector('[rel="author"], [itemprop="author"], .byline, .author');
+    const byline =
+      getMeta(['meta[name="author"]', 'meta[property="article:author"]']) ??
+      ((bylineElement ? getText(bylineElement) : '') || undefined);
+
+    // Work on a copy of the document body to not affect the page itself.
+    const root = document.body.cloneNode(true) as HTMLElement;
+    root.querySelectorAll(IGNORED_ELEMENTS).forEach((element) => element.remove());
+    root.querySelectorAll('*').forEach((element) => {
+      const matchString = `${element.getAttribute('class') ?? ''} ${element.id}`;
+      if (
+        element.tagName !== 'ARTICLE' &&
+        element.tagName !== 'MAIN' &&
+        UNLIKELY_CANDIDATES.test(matchString) &&
+        !MAYBE_CANDIDATES.test(matchString)
+      ) {
+        element.remove();
+      }
+    });
+
+    // Score paragraph containers based on the amount of text and commas in the paragraphs.
+    const scores = new Map<Element, number>();
+    root.querySelectorAll('p, pre, td, blockquote').forEach((paragraph) => {
+      const paragraphText = getText(paragraph);
+      if (paragraphText.length < 25) {
+        return;
+      }
+
+      const score = 1 + paragraphText.split(',').length + Math.min(Math.floor(paragraphText.length / 100), 3);
+      const parent = paragraph.parentElement;
+      if (parent) {
+        scores.set(parent, (scores.get(parent) ?? 0) + score);
+        const grandParent = parent.parentElement;
+        if (grandParent) {
+          scores.set(grandParent, (scores.get(grandParent) ?? 0) + score / 2);
+        }
+      }
+    });
+
+    // Pick the candidate with the highest score, penalizing elements that mostly consist of links.
+    let candidate: Element = root;
+    let candidateScore = 0;
+    for (const [element, score] of scores) {
+      const elementTextLength = getText(element).length;
+      const linksTextLength = Array.from(element.querySelectorAll('a')).reduce(
+        (length, link) => length + getText(link).length,
+        0,
+      );
+      const adjustedScore = score * (1 - (elementTextLength > 0 ? linksTextLength / elementTextLength : 0));
+      if (adjustedScore > candidateScore) {
+        candidate = element;
+        candidateScore = adjustedScore;
+      }
+    }
+
+    // Only keep innermost blocks to not duplicate text of the nested blocks.
+    const blocks = Array.from(candidate.querySelectorAll(BLOCK_ELEMENTS))
+      .filter((block) => !block.querySelector(BLOCK_ELEMENTS))
+      .map(getText)
+      .filter((blockText) => blockText.length > 0);
+
+    return JSON.stringify({ title, byline, text: blocks.length > 0 ? blocks.join('\n\n') : getText(candidate) });
+  });
+
+  return JSON.parse(article) as PageArticle;
+}
@@ -52,6 +52,8 @@ export interface WorkerData {
   userAgent?: string;
   // Whether to ignore HTTPS errors when sending network requests.
   ignoreHTTPSErrors?: boolean;
+  // Whether to extract the main article from the page instead of using the extractor script result.
+  extractArticle?: boolean;
   // Path to a folder where to save screenshots.
   screenshotsPath?: string;
 }
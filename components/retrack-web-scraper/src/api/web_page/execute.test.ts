@@ -93,6 +93,34 @@ await test('[/api/web_page/execute] accepts context overrides', async (t) => {
   assert.strictEqual(response.statusCode, 200);
 });
 
+await test('[/api/web_page/execute] can extract article', async (t) => {
+  t.mock.method(Date, 'now', () => 123000);
+
+  const article = { title: 'Retrack', byline: 'Retrack Team', text: 'Hello Retrack and world!' };
+  browserServerMock.runtimeCallFunctionOn.mock.mockImplementation((params) => {
+    if (params.arguments?.some((arg) => typeof arg.value === 'string' && arg.value.includes('extractArticleContent'))) {
+      return { type: 'string', value: JSON.stringify(article) };
+    }
+
+    throw new Error(`Unexpected objectId: ${params.objectId}`);
+  });
+
+  const response = await registerExecuteRoutes(
+    createMock({ browserEndpoint: { protocol: 'cdp', url: browserServerMock.endpoint } }),
+  ).inject({
+    method: 'POST',
+    url: '/api/web_page/execute',
+    payload: {
+      extractor: `export async function execute(page) { return 'ignored'; };`,
+      tags: [],
+      extractArticle: true,
+    },
+  });
+
+  assert.strictEqual(response.body, JSON.stringify(article));
+  assert.strictEqual(response.statusCode, 200);
+});
+
 await test('[/api/web_page/execute] can provide context', async (t) => {
   t.mock.method(Date, 'now', () => 123000);
 
@@ -338,3 +366,24 @@ export async function execute(page) {
   );
   assert.strictEqual(response.statusCode, 500);
 });
+
+await test('[/api/web_page/execute] rejects unsupported browser engines', async () => {
+  const response = await registerExecuteRoutes(
+    createMock({ browserEndpoint: { protocol: 'cdp', url: browserServerMock.endpoint } }),
+  ).inject({
+    method: 'POST',
+    url: '/api/web_page/execute',
+    payload: {
+      extractor: `export async function execute(page) { return 'some text'; };`,
+      tags: [],
+      engine: 'camoufox',
+    },
+  });
+
+  assert.strictEqual(
+    response.body,
+    JSON.stringify({ message: 'Browser engine (camoufox) is not supported by the web scraper.' }),
+  );
+  assert.strictEqual(response.statusCode, 400);
+  assert.strictEqual(browserServerMock.messages.length, 0);
+});
@@ -1,6 +1,7 @@
 import { resolve } from 'node:path';
 import * as process from 'node:process';
 import { Worker } from 'node:worker_threads';
+import { SUPPORTED_BROWSER_ENGINES } from '../../utilities/browser.js';
 import type { ApiRouteParams } from '../api_route_params.js';
 import { Diagnostics } from '../diagnostics.js';
 import type { WorkerData, WorkerLogMessage, WorkerResultMessage } from './constants.js';
@@ -48,6 +49,17 @@ interface RequestBodyType {
    * Whether to ignore HTTPS errors when sending network requests. Defaults to false.
    */
   ignoreHTTPSErrors?: boolean;
+
+  /**
+   * Whether to extract the main article (title, byline, and text) from the page the extractor script navigated to and
+   * use it as a new web page "content" instead of the value returned by the script. Defaults to false.
+   */
+  extractArticle?: boolean;
+
+  /**
+   * Optional browser engine to load the web page with. Defaults to Chromium.
+   */
+  engine?: string;
 }
 
 export function registerExecuteRoutes({ config, server, getBrowserEndpoint }: ApiRouteParams) {
@@ -65,6 +77,8 @@ export function registerExecuteRoutes({ config, server, getBrowserEndpoint }: Ap
             timeout: { type: 'number' },
             userAgent: { type: 'string' },
             ignoreHTTPSErrors: { type: 'boolean' },
+            extractArticle: { type: 'boolean' },
+            engine: { type: 'string' },
           },
           required: ['extractor', 'tags'],
         },
@@ -74,6 +88,11 @@ export function registerExecuteRoutes({ config, server, getBrowserEndpoint }: Ap
       const log = server.log.child({ provider: 'web_page_execute' });
       const workerLog = log.child({ provider: 'worker' });
 
+      if (request.body.engine && !SUPPORTED_BROWSER_ENGINES.includes(request.body.engine)) {
+        log.error(`Requested browser engine (${request.body.engine}) is not supported.`);
+        return reply.code(400).send({ message: `Browser engine (${request.body.engine}) is not supported by the web scraper.` });
+      }
+
       const workerData: WorkerData = {
         endpoint: await getBrowserEndpoint(),
         extractor: request.body.extractor,
@@ -82,6 +101,7 @@ export function registerExecuteRoutes({ config, server, getBrowserEndpoint }: Ap
         previousContent: request.body.previousContent,
         userAgent: request.body.userAgent,
         ignoreHTTPSErrors: request.body.ignoreHTTPSErrors,
+        extractArticle: request.body.extractArticle,
         screenshotsPath: config.browserScreenshotsPath,
       };
 
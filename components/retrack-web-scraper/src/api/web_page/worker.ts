@@ -14,8 +14,17 @@ if (!parentPort) {
 }
 
 // Load the extractor script as an ES module.
-const { endpoint, extractor, extractorParams, tags, previousContent, userAgent, ignoreHTTPSErrors, screenshotsPath } =
-  workerData as WorkerData;
+const {
+  endpoint,
+  extractor,
+  extractorParams,
+  tags,
+  previousContent,
+  userAgent,
+  ignoreHTTPSErrors,
+  extractArticle,
+  screenshotsPath,
+} = workerData as WorkerData;
 
 // SECURITY: Basic prototype pollution protection against the most common vectors until we can use Playwright with
 // `--frozen-intrinsics`. It DOES NOT protect against all prototype pollution vectors.
@@ -58,6 +67,7 @@ const log = {
 };
 
 const { connectToBrowser } = await import('../../utilities/browser.js');
+const { getPageArticle } = await import('./article.js');
 
 let browser: Browser | undefined;
 try {
@@ -100,12 +110,14 @@ delete contextPrototype.constructor;
 
 const page = await context.newPage();
 try {
+  const content = await extractorModule.execute(
+    page,
+    extractorParams ? { params: extractorParams, tags, previousContent } : { tags, previousContent },
+  );
   parentPort?.postMessage({
     type: WorkerMessageType.RESULT,
-    content: await extractorModule.execute(
-      page,
-      extractorParams ? { params: extractorParams, tags, previousContent } : { tags, previousContent },
-    ),
+    // If requested, track the main article of the page the extractor script navigated to instead.
+    content: extractArticle ? await getPageArticle(page) : content,
   });
 } catch (err) {
   // Capture screenshots.
@@ -8,6 +8,12 @@ export interface BrowserEndpoint {
   url: string;
 }
 
+/**
+ * Browser engines the Web Scraper can load web pages with. Retrack checks them before accepting trackers that require
+ * a specific engine.
+ */
+export const SUPPORTED_BROWSER_ENGINES: ReadonlyArray<string> = ['chromium'];
+
 // Timeout for connecting to a browser.
 const BROWSER_CONNECT_TIMEOUT_MS = 30000;
 
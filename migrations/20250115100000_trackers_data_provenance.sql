@@ -0,0 +1,2 @@
+-- Add optional provenance (e.g., TLS session details) to the tracker data revisions.
+ALTER TABLE trackers_data ADD COLUMN provenance BYTEA;
@@ -0,0 +1,17 @@
+-- Add per-tracker monotonically increasing sequence number to the tracker data revisions. The last
+-- assigned sequence number is kept in the trackers table, so that it's never reused even if
+-- revisions are removed.
+ALTER TABLE trackers ADD COLUMN revisions_sequence BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE trackers_data ADD COLUMN sequence BIGINT;
+
+-- Assign sequence numbers to the existing revisions based on their creation order.
+UPDATE trackers_data
+SET sequence = numbered.sequence
+FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY tracker_id ORDER BY created_at) AS sequence
+      FROM trackers_data) AS numbered
+WHERE trackers_data.id = numbered.id;
+UPDATE trackers
+SET revisions_sequence = COALESCE((SELECT MAX(sequence) FROM trackers_data WHERE tracker_id = trackers.id), 0);
+
+ALTER TABLE trackers_data ALTER COLUMN sequence SET NOT NULL;
+ALTER TABLE trackers_data ADD UNIQUE (tracker_id, sequence);
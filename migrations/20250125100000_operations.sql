@@ -0,0 +1,11 @@
+-- Table to store long-running operations (e.g., bulk run of trackers) and their progress.
+CREATE TABLE IF NOT EXISTS operations
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    kind       BYTEA            NOT NULL,
+    total      BIGINT           NOT NULL,
+    completed  BIGINT           NOT NULL,
+    errors     BYTEA            NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL,
+    updated_at TIMESTAMPTZ      NOT NULL
+);
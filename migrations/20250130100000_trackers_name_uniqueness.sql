@@ -0,0 +1,3 @@
+-- Uniqueness of the tracker names is configurable and enforced by the application.
+ALTER TABLE trackers DROP CONSTRAINT IF EXISTS trackers_name_key;
+CREATE INDEX IF NOT EXISTS trackers_name_idx ON trackers (name);
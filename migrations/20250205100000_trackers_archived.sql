@@ -0,0 +1,2 @@
+-- Archived trackers are read-only and hidden from default listings.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS archived BOOL NOT NULL DEFAULT FALSE;
@@ -0,0 +1,3 @@
+-- Shape of the last healthy tracker data revision and description of the detected deviation, if any.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS data_shape BYTEA;
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS shape_deviation TEXT;
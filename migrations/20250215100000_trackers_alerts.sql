@@ -0,0 +1,9 @@
+-- Table to store tracker failures that are pending correlation and reporting.
+CREATE TABLE IF NOT EXISTS trackers_alerts
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    tracker_id UUID             NOT NULL REFERENCES trackers (id) ON DELETE CASCADE,
+    error      TEXT             NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL
+);
+CREATE INDEX IF NOT EXISTS trackers_alerts_created_at_idx ON trackers_alerts (created_at);
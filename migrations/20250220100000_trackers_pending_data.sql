@@ -0,0 +1,2 @@
+-- Changed tracker data that is waiting for confirmation by the consecutive checks, if any.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS pending_data BYTEA;
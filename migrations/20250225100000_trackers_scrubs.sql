@@ -0,0 +1,14 @@
+-- Audit log of the trackers data scrubs (e.g., performed to satisfy personal data deletion requests).
+CREATE TABLE IF NOT EXISTS trackers_scrubs
+(
+    id             UUID PRIMARY KEY NOT NULL,
+    reason         TEXT,
+    pattern_digest TEXT,
+    trackers       UUID[]           NOT NULL,
+    revisions      BIGINT           NOT NULL,
+    pending_data   BIGINT           NOT NULL,
+    alerts         BIGINT           NOT NULL,
+    tasks          BIGINT           NOT NULL,
+    created_at     TIMESTAMPTZ      NOT NULL
+);
+CREATE INDEX IF NOT EXISTS trackers_scrubs_created_at_idx ON trackers_scrubs (created_at);
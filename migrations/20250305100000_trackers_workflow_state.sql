@@ -0,0 +1,2 @@
+-- Current state of the tracker workflow, if the tracker has transitioned from the initial state.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS workflow_state TEXT;
@@ -0,0 +1,11 @@
+-- Table to store tracker data revisions that broke the tracker quarantine rules and are pending review.
+CREATE TABLE IF NOT EXISTS trackers_quarantine
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    tracker_id UUID             NOT NULL REFERENCES trackers (id) ON DELETE CASCADE,
+    data       BYTEA            NOT NULL,
+    provenance BYTEA,
+    reason     TEXT             NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL
+);
+CREATE INDEX IF NOT EXISTS trackers_quarantine_tracker_id_idx ON trackers_quarantine (tracker_id);
@@ -0,0 +1,9 @@
+-- Table to store named auth profiles (credentials) that API target requests can reference.
+CREATE TABLE IF NOT EXISTS auth_profiles
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    name       TEXT             NOT NULL UNIQUE,
+    kind       BYTEA            NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL,
+    updated_at TIMESTAMPTZ      NOT NULL
+);
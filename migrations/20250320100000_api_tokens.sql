@@ -0,0 +1,11 @@
+-- Table to store limited API tokens that grant access only to the trackers with specific tags. Only
+-- the digest of the token value is stored.
+CREATE TABLE IF NOT EXISTS api_tokens
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    name       TEXT             NOT NULL UNIQUE,
+    digest     BYTEA            NOT NULL UNIQUE,
+    tags       TEXT[]           NOT NULL,
+    scopes     BYTEA            NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL
+);
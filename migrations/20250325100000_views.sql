@@ -0,0 +1,11 @@
+-- Table to store named views (combinations of the trackers list filters and sort order) that can be
+-- shared between users.
+CREATE TABLE IF NOT EXISTS views
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    name       TEXT             NOT NULL UNIQUE,
+    tags       TEXT[]           NOT NULL,
+    filters    BYTEA            NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL,
+    updated_at TIMESTAMPTZ      NOT NULL
+);
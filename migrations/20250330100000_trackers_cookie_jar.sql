@@ -0,0 +1,2 @@
+-- Cookies set by the tracker API target responses that are persisted between checks, if enabled.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS cookie_jar BYTEA;
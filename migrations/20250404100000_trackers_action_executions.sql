@@ -0,0 +1,2 @@
+-- Last executions of the throttled tracker actions that are persisted between checks, if any.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS action_executions BYTEA;
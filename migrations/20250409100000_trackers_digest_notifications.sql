@@ -0,0 +1,11 @@
+-- Table to store tracker action notifications that are pending delivery as a part of a digest.
+CREATE TABLE IF NOT EXISTS trackers_digest_notifications
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    tracker_id UUID             NOT NULL REFERENCES trackers (id) ON DELETE CASCADE,
+    tag        TEXT,
+    task_type  BYTEA            NOT NULL,
+    send_at    TIMESTAMPTZ      NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL
+);
+CREATE INDEX IF NOT EXISTS trackers_digest_notifications_send_at_idx ON trackers_digest_notifications (send_at);
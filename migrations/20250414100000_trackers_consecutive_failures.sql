@@ -0,0 +1,2 @@
+-- Number of consecutive failed data checks of the tracker with escalation, reset once the check succeeds.
+ALTER TABLE trackers ADD COLUMN IF NOT EXISTS consecutive_failures INTEGER NOT NULL DEFAULT 0;
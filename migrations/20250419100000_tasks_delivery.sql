@@ -0,0 +1,4 @@
+-- Delivery state of the tasks that have failed to be executed at least once.
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS last_error TEXT;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS failed BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,11 @@
+-- Table to store named email templates that tracker email actions can use instead of the built-in one.
+CREATE TABLE IF NOT EXISTS templates
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    name       TEXT             NOT NULL UNIQUE,
+    subject    TEXT             NOT NULL,
+    text       TEXT             NOT NULL,
+    html       TEXT,
+    created_at TIMESTAMPTZ      NOT NULL,
+    updated_at TIMESTAMPTZ      NOT NULL
+);
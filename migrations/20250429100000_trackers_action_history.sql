@@ -0,0 +1,15 @@
+-- History of the tracker action executions (e.g., sent emails and called webhooks).
+CREATE TABLE IF NOT EXISTS trackers_action_history
+(
+    id          UUID PRIMARY KEY NOT NULL,
+    tracker_id  UUID             NOT NULL REFERENCES trackers (id) ON DELETE CASCADE,
+    action      INTEGER          NOT NULL,
+    action_type TEXT             NOT NULL,
+    task_id     UUID,
+    status      BYTEA            NOT NULL,
+    error       TEXT,
+    duration    BIGINT           NOT NULL,
+    executed_at TIMESTAMPTZ      NOT NULL
+);
+CREATE INDEX IF NOT EXISTS trackers_action_history_tracker_id_idx ON trackers_action_history (tracker_id, executed_at);
+CREATE INDEX IF NOT EXISTS trackers_action_history_task_id_idx ON trackers_action_history (task_id);
@@ -0,0 +1,3 @@
+-- Text content of the tracker data revisions used for the full-text search. Content of the revisions
+-- persisted before the column was added is populated by the server in the background.
+ALTER TABLE trackers_data ADD COLUMN IF NOT EXISTS content TEXT;
@@ -0,0 +1,3 @@
+-- no-transaction
+-- Full-text search index for the text content of the tracker data revisions.
+CREATE INDEX CONCURRENTLY IF NOT EXISTS trackers_data_content_idx ON trackers_data USING GIN (to_tsvector('simple', content));
@@ -1,10 +1,13 @@
 use crate::{
+    auth_profiles::AuthProfileTokens,
     config::Config,
     database::Database,
     js_runtime::JsRuntime,
     network::{DnsResolver, EmailTransport, Network},
+    trackers::WebScraperCapabilities,
 };
 use handlebars::Handlebars;
+use tokio::sync::OnceCell;
 
 pub struct Api<DR: DnsResolver, ET: EmailTransport> {
     pub db: Database,
@@ -12,6 +15,10 @@ pub struct Api<DR: DnsResolver, ET: EmailTransport> {
     pub network: Network<DR, ET>,
     pub templates: Handlebars<'static>,
     pub js_runtime: JsRuntime,
+    /// Capabilities of the web scraper, detected once the web scraper is reachable.
+    pub web_scraper_capabilities: OnceCell<WebScraperCapabilities>,
+    /// Access tokens issued for the auth profiles, cached until they expire.
+    pub auth_profile_tokens: AuthProfileTokens,
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
@@ -29,6 +36,8 @@ impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
             network,
             templates,
             js_runtime,
+            web_scraper_capabilities: OnceCell::new(),
+            auth_profile_tokens: AuthProfileTokens::default(),
         }
     }
 }
@@ -0,0 +1,341 @@
+use crate::{
+    api::Api,
+    database::Database,
+    error::Error as RetrackError,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+};
+use anyhow::{anyhow, bail};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use retrack_types::api_tokens::{
+    ApiToken, ApiTokenCreateParams, ApiTokenCreateResult, ApiTokenScope,
+};
+use ring::{
+    digest::{digest, SHA256},
+    rand::{SecureRandom, SystemRandom},
+};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// Defines the maximum length of the API token name.
+pub const MAX_API_TOKEN_NAME_LENGTH: usize = 100;
+
+/// Defines the maximum number of tags the API token can be scoped to.
+pub const MAX_API_TOKEN_TAGS_COUNT: usize = 20;
+
+/// Defines the maximum length of the API token tag.
+pub const MAX_API_TOKEN_TAG_LENGTH: usize = 50;
+
+/// Prefix of the API token values, makes tokens easier to recognize (e.g., by secret scanners).
+const API_TOKEN_VALUE_PREFIX: &str = "rtk_";
+
+/// Number of random bytes in the API token value.
+const API_TOKEN_VALUE_BYTES: usize = 32;
+
+/// Describes the API to work with API tokens.
+pub struct ApiTokensApi<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> ApiTokensApi<'a, DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Creates API Tokens API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Returns all API tokens.
+    pub async fn get_api_tokens(&self) -> anyhow::Result<Vec<ApiToken>> {
+        self.api.db.get_api_tokens().await
+    }
+
+    /// Creates a new API token. The token value is returned only once, only its digest is stored.
+    pub async fn create_api_token(
+        &self,
+        params: ApiTokenCreateParams,
+    ) -> anyhow::Result<ApiTokenCreateResult> {
+        let api_token = ApiToken {
+            id: Uuid::now_v7(),
+            name: params.name,
+            tags: Self::normalize_api_token_tags(params.tags),
+            scopes: params
+                .scopes
+                .into_iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            created_at: Database::utc_now()?,
+        };
+
+        Self::validate_api_token(&api_token)?;
+
+        let mut value_bytes = [0u8; API_TOKEN_VALUE_BYTES];
+        SystemRandom::new()
+            .fill(&mut value_bytes)
+            .map_err(|_| anyhow!("Failed to generate API token value."))?;
+        let value = format!(
+            "{API_TOKEN_VALUE_PREFIX}{}",
+            URL_SAFE_NO_PAD.encode(value_bytes)
+        );
+
+        self.api
+            .db
+            .insert_api_token(&api_token, &Self::digest(&value))
+            .await?;
+
+        Ok(ApiTokenCreateResult {
+            token: api_token,
+            value,
+        })
+    }
+
+    /// Removes existing API token.
+    pub async fn remove_api_token(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api.db.remove_api_token(id).await
+    }
+
+    /// Returns the API token with the specified value if it's valid and grants the specified scope.
+    pub async fn authorize_api_token(
+        &self,
+        value: &str,
+        scope: ApiTokenScope,
+    ) -> anyhow::Result<ApiToken> {
+        let Some(api_token) = self
+            .api
+            .db
+            .get_api_token_by_digest(&Self::digest(value))
+            .await?
+        else {
+            bail!(RetrackError::unauthorized("API token is not valid."));
+        };
+
+        if !api_token.has_scope(scope) {
+            bail!(RetrackError::access_forbidden(format!(
+                "API token ('{}') doesn't allow this operation.",
+                api_token.name
+            )));
+        }
+
+        Ok(api_token)
+    }
+
+    /// Calculates the digest of the API token value that is stored in the database.
+    fn digest(value: &str) -> Vec<u8> {
+        digest(&SHA256, value.as_bytes()).as_ref().to_vec()
+    }
+
+    /// Normalizes API token tags the same way as tracker tags are normalized.
+    fn normalize_api_token_tags(tags: Vec<String>) -> Vec<String> {
+        tags.into_iter()
+            .map(|tag| tag.trim().to_lowercase())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Validates API token parameters.
+    fn validate_api_token(api_token: &ApiToken) -> anyhow::Result<()> {
+        if api_token.name.is_empty() {
+            bail!(RetrackError::client("API token name cannot be empty."));
+        }
+
+        if api_token.name.len() > MAX_API_TOKEN_NAME_LENGTH {
+            bail!(RetrackError::client(format!(
+                "API token name cannot be longer than {MAX_API_TOKEN_NAME_LENGTH} characters."
+            )));
+        }
+
+        if api_token.tags.is_empty() {
+            bail!(RetrackError::client(
+                "API token should be scoped to at least one tag."
+            ));
+        }
+
+        if api_token.tags.len() > MAX_API_TOKEN_TAGS_COUNT {
+            bail!(RetrackError::client(format!(
+                "API token cannot have more than {MAX_API_TOKEN_TAGS_COUNT} tags."
+            )));
+        }
+
+        if api_token
+            .tags
+            .iter()
+            .any(|tag| tag.is_empty() || tag.len() > MAX_API_TOKEN_TAG_LENGTH)
+        {
+            bail!(RetrackError::client(format!(
+                "API token tags cannot be empty or longer than {MAX_API_TOKEN_TAG_LENGTH} characters."
+            )));
+        }
+
+        if api_token.scopes.is_empty() {
+            bail!(RetrackError::client(
+                "API token should have at least one scope."
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Returns an API to work with API tokens.
+    pub fn api_tokens(&self) -> ApiTokensApi<'_, DR, ET> {
+        ApiTokensApi::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::Error as RetrackError, tests::mock_api};
+    use actix_web::{http::StatusCode, ResponseError};
+    use insta::assert_debug_snapshot;
+    use retrack_types::api_tokens::{ApiTokenCreateParams, ApiTokenScope};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn properly_creates_authorizes_and_removes_api_token(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let api_tokens = api.api_tokens();
+
+        let result = api_tokens
+            .create_api_token(ApiTokenCreateParams {
+                name: "acme".to_string(),
+                tags: vec![
+                    " Partner:ACME ".to_string(),
+                    "partner:acme".to_string(),
+                    "prod".to_string(),
+                ],
+                scopes: vec![
+                    ApiTokenScope::RunTrackers,
+                    ApiTokenScope::CreateRevisions,
+                    ApiTokenScope::RunTrackers,
+                ],
+            })
+            .await?;
+        assert!(result.value.starts_with("rtk_"));
+        assert_eq!(result.value.len(), 47);
+        assert_eq!(
+            result.token.tags,
+            vec!["partner:acme".to_string(), "prod".to_string()]
+        );
+        assert_eq!(
+            result.token.scopes,
+            vec![ApiTokenScope::CreateRevisions, ApiTokenScope::RunTrackers]
+        );
+        assert_eq!(
+            api_tokens.get_api_tokens().await?,
+            vec![result.token.clone()]
+        );
+
+        assert_eq!(
+            api_tokens
+                .authorize_api_token(&result.value, ApiTokenScope::RunTrackers)
+                .await?,
+            result.token
+        );
+
+        let error = api_tokens
+            .authorize_api_token("rtk_unknown", ApiTokenScope::RunTrackers)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+
+        api_tokens.remove_api_token(result.token.id).await?;
+        assert!(api_tokens.get_api_tokens().await?.is_empty());
+
+        let error = api_tokens
+            .authorize_api_token(&result.value, ApiTokenScope::RunTrackers)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_authorize_api_token_without_scope(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let api_tokens = api.api_tokens();
+
+        let result = api_tokens
+            .create_api_token(ApiTokenCreateParams {
+                name: "acme".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::CreateRevisions],
+            })
+            .await?;
+
+        let error = api_tokens
+            .authorize_api_token(&result.value, ApiTokenScope::RunTrackers)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_eq!(error.status_code(), StatusCode::FORBIDDEN);
+        assert_debug_snapshot!(error.root_cause.to_string(), @r###""API token ('acme') doesn't allow this operation.""###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn validates_api_token_params(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let api_tokens = &api.api_tokens();
+
+        let create_and_fail = move |params: ApiTokenCreateParams| async move {
+            api_tokens
+                .create_api_token(params)
+                .await
+                .unwrap_err()
+                .downcast::<RetrackError>()
+                .unwrap()
+                .root_cause
+                .to_string()
+        };
+
+        let params = ApiTokenCreateParams {
+            name: "acme".to_string(),
+            tags: vec!["partner:acme".to_string()],
+            scopes: vec![ApiTokenScope::CreateRevisions],
+        };
+
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            name: "".to_string(),
+            ..params.clone()
+        }).await, @r###""API token name cannot be empty.""###);
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            name: "a".repeat(101),
+            ..params.clone()
+        }).await, @r###""API token name cannot be longer than 100 characters.""###);
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            tags: vec![],
+            ..params.clone()
+        }).await, @r###""API token should be scoped to at least one tag.""###);
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            tags: (0..21).map(|i| format!("tag_{i}")).collect(),
+            ..params.clone()
+        }).await, @r###""API token cannot have more than 20 tags.""###);
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            tags: vec!["   ".to_string()],
+            ..params.clone()
+        }).await, @r###""API token tags cannot be empty or longer than 50 characters.""###);
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            tags: vec!["a".repeat(51)],
+            ..params.clone()
+        }).await, @r###""API token tags cannot be empty or longer than 50 characters.""###);
+        assert_debug_snapshot!(create_and_fail(ApiTokenCreateParams {
+            scopes: vec![],
+            ..params.clone()
+        }).await, @r###""API token should have at least one scope.""###);
+
+        api_tokens.create_api_token(params.clone()).await?;
+        assert_debug_snapshot!(create_and_fail(params).await, @r###""API token with such name ('acme') already exists.""###);
+
+        Ok(())
+    }
+}
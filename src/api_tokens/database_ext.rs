@@ -0,0 +1,158 @@
+mod raw_api_token;
+
+use crate::{
+    api_tokens::database_ext::raw_api_token::RawApiToken, database::Database,
+    error::Error as RetrackError,
+};
+use anyhow::{anyhow, bail};
+use retrack_types::api_tokens::ApiToken;
+use sqlx::{query, query_as};
+use uuid::Uuid;
+
+/// Name of the database constraint that enforces API token name uniqueness.
+const API_TOKENS_NAME_CONSTRAINT: &str = "api_tokens_name_key";
+
+/// Extends primary database with the API tokens-related methods.
+impl Database {
+    /// Retrieves all API tokens from the database, ordered by name.
+    pub async fn get_api_tokens(&self) -> anyhow::Result<Vec<ApiToken>> {
+        query_as!(
+            RawApiToken,
+            r#"SELECT id, name, tags, scopes, created_at FROM api_tokens ORDER BY name"#
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(ApiToken::try_from)
+        .collect()
+    }
+
+    /// Retrieves API token from the database using the digest of its value.
+    pub async fn get_api_token_by_digest(&self, digest: &[u8]) -> anyhow::Result<Option<ApiToken>> {
+        query_as!(
+            RawApiToken,
+            r#"SELECT id, name, tags, scopes, created_at FROM api_tokens WHERE digest = $1"#,
+            digest
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(ApiToken::try_from)
+        .transpose()
+    }
+
+    /// Inserts a new API token along with the digest of its value to the database.
+    pub async fn insert_api_token(
+        &self,
+        api_token: &ApiToken,
+        digest: &[u8],
+    ) -> anyhow::Result<()> {
+        let raw_api_token = RawApiToken::try_from(api_token)?;
+        let result = query!(
+            r#"
+INSERT INTO api_tokens (id, name, digest, tags, scopes, created_at)
+VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            raw_api_token.id,
+            raw_api_token.name,
+            digest,
+            &raw_api_token.tags,
+            raw_api_token.scopes,
+            raw_api_token.created_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        if let Err(err) = result {
+            bail!(match err.as_database_error() {
+                Some(database_error)
+                    if database_error.is_unique_violation()
+                        && database_error.constraint() == Some(API_TOKENS_NAME_CONSTRAINT) =>
+                {
+                    RetrackError::client_with_root_cause(anyhow!(err).context(format!(
+                        "API token with such name ('{}') already exists.",
+                        api_token.name
+                    )))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't create API token ('{}') due to unknown reason.",
+                    api_token.name
+                ))),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Removes API token from the database.
+    pub async fn remove_api_token(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(r#"DELETE FROM api_tokens WHERE id = $1"#, id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{database::Database, error::Error as RetrackError};
+    use insta::assert_debug_snapshot;
+    use retrack_types::api_tokens::{ApiToken, ApiTokenScope};
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    fn mock_api_token(id: u128, name: &str) -> anyhow::Result<ApiToken> {
+        Ok(ApiToken {
+            id: Uuid::from_u128(id),
+            name: name.to_string(),
+            tags: vec!["partner:acme".to_string()],
+            scopes: vec![ApiTokenScope::CreateRevisions],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    #[sqlx::test]
+    async fn can_add_and_remove_api_token(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let api_token_one = mock_api_token(1, "acme")?;
+        let api_token_two = mock_api_token(2, "globex")?;
+        assert!(db.get_api_token_by_digest(b"digest-one").await?.is_none());
+        assert!(db.get_api_tokens().await?.is_empty());
+
+        db.insert_api_token(&api_token_two, b"digest-two").await?;
+        db.insert_api_token(&api_token_one, b"digest-one").await?;
+        assert_eq!(
+            db.get_api_token_by_digest(b"digest-one").await?,
+            Some(api_token_one.clone())
+        );
+        assert_eq!(
+            db.get_api_tokens().await?,
+            vec![api_token_one.clone(), api_token_two.clone()]
+        );
+
+        db.remove_api_token(api_token_one.id).await?;
+        assert!(db.get_api_token_by_digest(b"digest-one").await?.is_none());
+        assert_eq!(db.get_api_tokens().await?, vec![api_token_two]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_add_api_token_with_duplicate_name(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        db.insert_api_token(&mock_api_token(1, "acme")?, b"digest-one")
+            .await?;
+
+        let insert_error = db
+            .insert_api_token(&mock_api_token(2, "acme")?, b"digest-two")
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(insert_error.root_cause.to_string(), @r###""API token with such name ('acme') already exists.""###);
+
+        Ok(())
+    }
+}
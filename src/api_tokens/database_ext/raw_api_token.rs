@@ -0,0 +1,106 @@
+use retrack_types::api_tokens::{ApiToken, ApiTokenScope};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawApiToken {
+    pub id: Uuid,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub scopes: Vec<u8>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+enum RawApiTokenScope {
+    CreateRevisions,
+    RunTrackers,
+}
+
+impl TryFrom<RawApiToken> for ApiToken {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawApiToken) -> Result<Self, Self::Error> {
+        Ok(ApiToken {
+            id: raw.id,
+            name: raw.name,
+            tags: raw.tags,
+            scopes: postcard::from_bytes::<Vec<RawApiTokenScope>>(&raw.scopes)?
+                .into_iter()
+                .map(|scope| match scope {
+                    RawApiTokenScope::CreateRevisions => ApiTokenScope::CreateRevisions,
+                    RawApiTokenScope::RunTrackers => ApiTokenScope::RunTrackers,
+                })
+                .collect(),
+            created_at: raw.created_at,
+        })
+    }
+}
+
+impl TryFrom<&ApiToken> for RawApiToken {
+    type Error = anyhow::Error;
+
+    fn try_from(item: &ApiToken) -> Result<Self, Self::Error> {
+        let raw_scopes = item
+            .scopes
+            .iter()
+            .map(|scope| match scope {
+                ApiTokenScope::CreateRevisions => RawApiTokenScope::CreateRevisions,
+                ApiTokenScope::RunTrackers => RawApiTokenScope::RunTrackers,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(RawApiToken {
+            id: item.id,
+            name: item.name.clone(),
+            tags: item.tags.clone(),
+            scopes: postcard::to_stdvec(&raw_scopes)?,
+            created_at: item.created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawApiToken;
+    use retrack_types::api_tokens::{ApiToken, ApiTokenScope};
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn can_convert_into_and_from_raw_api_token() -> anyhow::Result<()> {
+        let api_token = ApiToken {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "partner".to_string(),
+            tags: vec!["partner:acme".to_string()],
+            scopes: vec![ApiTokenScope::CreateRevisions, ApiTokenScope::RunTrackers],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+        assert_eq!(
+            RawApiToken::try_from(&api_token)?,
+            RawApiToken {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "partner".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![2, 0, 1],
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            }
+        );
+        assert_eq!(
+            ApiToken::try_from(RawApiToken::try_from(&api_token)?)?,
+            api_token
+        );
+
+        let api_token = ApiToken {
+            scopes: vec![ApiTokenScope::RunTrackers],
+            ..api_token
+        };
+        assert_eq!(
+            ApiToken::try_from(RawApiToken::try_from(&api_token)?)?,
+            api_token
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+mod api_ext;
+mod auth_profile_tokens;
+mod database_ext;
+
+pub use self::auth_profile_tokens::AuthProfileTokens;
@@ -0,0 +1,714 @@
+use crate::{
+    api::Api,
+    database::Database,
+    error::Error as RetrackError,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+};
+use anyhow::{bail, Context};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use http::{
+    header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
+    HeaderName, HeaderValue,
+};
+use reqwest_middleware::ClientBuilder;
+use reqwest_tracing::{SpanBackendWithUrl, TracingMiddleware};
+use retrack_types::auth_profiles::{
+    AuthProfile, AuthProfileCreateParams, AuthProfileKind, AuthProfileUpdateParams,
+};
+use serde::Deserialize;
+use std::{str::FromStr, time::Duration};
+use tracing::debug;
+use url::{form_urlencoded, Url};
+use uuid::Uuid;
+
+/// Defines the maximum length of the auth profile name.
+pub const MAX_AUTH_PROFILE_NAME_LENGTH: usize = 100;
+
+/// Defines the maximum duration of the OAuth2 access token request.
+const AUTH_PROFILE_TOKEN_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Successful response of the OAuth2 token endpoint (RFC 6749, section 5.1).
+#[derive(Deserialize)]
+struct AccessTokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+/// Describes the API to work with auth profiles.
+pub struct AuthProfilesApi<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> AuthProfilesApi<'a, DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Creates Auth Profiles API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Returns all auth profiles.
+    pub async fn get_auth_profiles(&self) -> anyhow::Result<Vec<AuthProfile>> {
+        self.api.db.get_auth_profiles().await
+    }
+
+    /// Returns auth profile by its ID.
+    pub async fn get_auth_profile(&self, id: Uuid) -> anyhow::Result<Option<AuthProfile>> {
+        self.api.db.get_auth_profile(id).await
+    }
+
+    /// Returns auth profile by its name.
+    pub async fn get_auth_profile_by_name(
+        &self,
+        name: &str,
+    ) -> anyhow::Result<Option<AuthProfile>> {
+        self.api.db.get_auth_profile_by_name(name).await
+    }
+
+    /// Creates a new auth profile.
+    pub async fn create_auth_profile(
+        &self,
+        params: AuthProfileCreateParams,
+    ) -> anyhow::Result<AuthProfile> {
+        let created_at = Database::utc_now()?;
+        let auth_profile = AuthProfile {
+            id: Uuid::now_v7(),
+            name: params.name,
+            kind: params.kind,
+            created_at,
+            updated_at: created_at,
+        };
+
+        self.validate_auth_profile(&auth_profile).await?;
+        self.api.db.insert_auth_profile(&auth_profile).await?;
+
+        Ok(auth_profile)
+    }
+
+    /// Updates existing auth profile.
+    pub async fn update_auth_profile(
+        &self,
+        id: Uuid,
+        params: AuthProfileUpdateParams,
+    ) -> anyhow::Result<AuthProfile> {
+        if params.name.is_none() && params.kind.is_none() {
+            bail!(RetrackError::client(format!(
+                "At least one auth profile property should be provided ({id})."
+            )));
+        }
+
+        let Some(existing_auth_profile) = self.api.db.get_auth_profile(id).await? else {
+            bail!(RetrackError::client(format!(
+                "Auth profile ('{id}') is not found."
+            )));
+        };
+
+        let auth_profile = AuthProfile {
+            name: params.name.unwrap_or(existing_auth_profile.name),
+            kind: params.kind.unwrap_or(existing_auth_profile.kind),
+            updated_at: Database::utc_now()?,
+            ..existing_auth_profile
+        };
+
+        self.validate_auth_profile(&auth_profile).await?;
+        self.api.db.update_auth_profile(&auth_profile).await?;
+        self.api.auth_profile_tokens.remove(auth_profile.id);
+
+        Ok(auth_profile)
+    }
+
+    /// Removes existing auth profile.
+    pub async fn remove_auth_profile(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api.db.remove_auth_profile(id).await?;
+        self.api.auth_profile_tokens.remove(id);
+
+        Ok(())
+    }
+
+    /// Returns the HTTP header that authenticates requests with the specified auth profile. For
+    /// OAuth2 profiles, the access token is requested from the token endpoint, unless there is a
+    /// valid cached token already.
+    pub async fn get_auth_header(
+        &self,
+        auth_profile: &AuthProfile,
+    ) -> anyhow::Result<(HeaderName, HeaderValue)> {
+        if !matches!(
+            auth_profile.kind,
+            AuthProfileKind::OAuth2ClientCredentials { .. }
+                | AuthProfileKind::OAuth2RefreshToken { .. }
+        ) {
+            return Self::static_auth_header(&auth_profile.kind);
+        }
+
+        let access_token = match self.api.auth_profile_tokens.get(auth_profile) {
+            Some(access_token) => access_token,
+            None => self.request_access_token(auth_profile).await?,
+        };
+
+        Ok((
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {access_token}"))?,
+        ))
+    }
+
+    /// Discards cached access token of the auth profile, if any, so that a new token is requested
+    /// the next time the auth profile is used (e.g., when the token is rejected by the server).
+    pub fn invalidate_auth_token(&self, auth_profile: &AuthProfile) {
+        self.api.auth_profile_tokens.remove(auth_profile.id);
+    }
+
+    /// Requests a new access token from the token endpoint of the OAuth2 auth profile, and caches
+    /// it. If the authorization server issues a new refresh token, it's stored in the auth profile.
+    async fn request_access_token(&self, auth_profile: &AuthProfile) -> anyhow::Result<String> {
+        let (token_url, client_id, client_secret, refresh_token, scope) = match auth_profile.kind {
+            AuthProfileKind::OAuth2ClientCredentials {
+                ref token_url,
+                ref client_id,
+                ref client_secret,
+                ref scope,
+            } => (token_url, client_id, Some(client_secret), None, scope),
+            AuthProfileKind::OAuth2RefreshToken {
+                ref token_url,
+                ref client_id,
+                ref client_secret,
+                ref refresh_token,
+                ref scope,
+            } => (
+                token_url,
+                client_id,
+                client_secret.as_ref(),
+                Some(refresh_token),
+                scope,
+            ),
+            _ => bail!("Auth profile ('{}') doesn't use OAuth2.", auth_profile.name),
+        };
+
+        let form = {
+            let mut serializer = form_urlencoded::Serializer::new(String::new());
+            if let Some(refresh_token) = refresh_token {
+                serializer.append_pair("grant_type", "refresh_token");
+                serializer.append_pair("refresh_token", refresh_token);
+            } else {
+                serializer.append_pair("grant_type", "client_credentials");
+            }
+            if let Some(scope) = scope {
+                serializer.append_pair("scope", scope);
+            }
+            // Public clients don't have a secret and identify themselves in the request body.
+            if client_secret.is_none() {
+                serializer.append_pair("client_id", client_id);
+            }
+            serializer.finish()
+        };
+
+        let client = ClientBuilder::new(reqwest::Client::new())
+            .with(TracingMiddleware::<SpanBackendWithUrl>::new())
+            .build();
+        let request_builder = client.post(token_url.clone());
+        let request_builder = if let Some(client_secret) = client_secret {
+            request_builder.header(
+                AUTHORIZATION,
+                basic_credentials(client_id, Some(client_secret)),
+            )
+        } else {
+            request_builder
+        };
+        let response = request_builder
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(form)
+            .timeout(AUTH_PROFILE_TOKEN_REQUEST_TIMEOUT)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let is_client_error = response.status().is_client_error();
+            let message = format!(
+                "Failed to obtain access token for the auth profile ('{}'): {}",
+                auth_profile.name,
+                response.text().await?
+            );
+            if is_client_error {
+                bail!(RetrackError::client(message));
+            } else {
+                bail!(message);
+            }
+        }
+
+        let token_response = response
+            .json::<AccessTokenResponse>()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to parse access token response for the auth profile ('{}').",
+                    auth_profile.name
+                )
+            })?;
+        debug!(
+            auth_profile.id = %auth_profile.id,
+            auth_profile.name = auth_profile.name,
+            "Obtained access token for the auth profile (expires in {:?} seconds).",
+            token_response.expires_in
+        );
+
+        // Store rotated refresh token, keeping the auth profile update time intact so that the
+        // access token issued with it stays cached.
+        if let (
+            AuthProfileKind::OAuth2RefreshToken {
+                ref token_url,
+                ref client_id,
+                ref client_secret,
+                ref scope,
+                ..
+            },
+            Some(new_refresh_token),
+        ) = (&auth_profile.kind, token_response.refresh_token)
+        {
+            if Some(&new_refresh_token) != refresh_token {
+                self.api
+                    .db
+                    .update_auth_profile(&AuthProfile {
+                        kind: AuthProfileKind::OAuth2RefreshToken {
+                            token_url: token_url.clone(),
+                            client_id: client_id.clone(),
+                            client_secret: client_secret.clone(),
+                            refresh_token: new_refresh_token,
+                            scope: scope.clone(),
+                        },
+                        ..auth_profile.clone()
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to store rotated refresh token for the auth profile ('{}').",
+                            auth_profile.name
+                        )
+                    })?;
+            }
+        }
+
+        self.api.auth_profile_tokens.insert(
+            auth_profile,
+            token_response.access_token.clone(),
+            token_response.expires_in.map(Duration::from_secs),
+        );
+
+        Ok(token_response.access_token)
+    }
+
+    /// Builds the HTTP header for the auth profiles that don't need to request tokens.
+    fn static_auth_header(kind: &AuthProfileKind) -> anyhow::Result<(HeaderName, HeaderValue)> {
+        Ok(match kind {
+            AuthProfileKind::Basic { username, password } => (
+                AUTHORIZATION,
+                basic_credentials(username, password.as_deref()).parse()?,
+            ),
+            AuthProfileKind::Bearer { token } => (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {token}"))?,
+            ),
+            AuthProfileKind::ApiKey { header, value } => {
+                (HeaderName::from_str(header)?, HeaderValue::from_str(value)?)
+            }
+            AuthProfileKind::OAuth2ClientCredentials { .. }
+            | AuthProfileKind::OAuth2RefreshToken { .. } => {
+                bail!("OAuth2 auth profile requires access token to build auth header.")
+            }
+        })
+    }
+
+    /// Validates auth profile parameters.
+    async fn validate_auth_profile(&self, auth_profile: &AuthProfile) -> anyhow::Result<()> {
+        if auth_profile.name.is_empty() {
+            bail!(RetrackError::client("Auth profile name cannot be empty."));
+        }
+
+        if auth_profile.name.len() > MAX_AUTH_PROFILE_NAME_LENGTH {
+            bail!(RetrackError::client(format!(
+                "Auth profile name cannot be longer than {MAX_AUTH_PROFILE_NAME_LENGTH} characters."
+            )));
+        }
+
+        match auth_profile.kind {
+            AuthProfileKind::Basic { ref username, .. } => {
+                if username.is_empty() || username.contains(':') {
+                    bail!(RetrackError::client(
+                        "Auth profile username cannot be empty or contain colons."
+                    ));
+                }
+            }
+            AuthProfileKind::Bearer { ref token } => {
+                if token.is_empty() {
+                    bail!(RetrackError::client("Auth profile token cannot be empty."));
+                }
+            }
+            AuthProfileKind::ApiKey {
+                ref header,
+                ref value,
+            } => {
+                if HeaderName::from_str(header).is_err() {
+                    bail!(RetrackError::client(format!(
+                        "Auth profile API key header name is not valid ('{header}')."
+                    )));
+                }
+
+                if value.is_empty() {
+                    bail!(RetrackError::client(
+                        "Auth profile API key cannot be empty."
+                    ));
+                }
+            }
+            AuthProfileKind::OAuth2ClientCredentials {
+                ref token_url,
+                ref client_id,
+                ref client_secret,
+                ..
+            } => {
+                if client_id.is_empty() || client_secret.is_empty() {
+                    bail!(RetrackError::client(
+                        "Auth profile client ID and client secret cannot be empty."
+                    ));
+                }
+
+                return self.validate_auth_profile_token_url(token_url).await;
+            }
+            AuthProfileKind::OAuth2RefreshToken {
+                ref token_url,
+                ref client_id,
+                ref client_secret,
+                ref refresh_token,
+                ..
+            } => {
+                if client_id.is_empty() || client_secret.as_ref().is_some_and(String::is_empty) {
+                    bail!(RetrackError::client(
+                        "Auth profile client ID and client secret, if specified, cannot be empty."
+                    ));
+                }
+
+                if refresh_token.is_empty() {
+                    bail!(RetrackError::client(
+                        "Auth profile refresh token cannot be empty."
+                    ));
+                }
+
+                return self.validate_auth_profile_token_url(token_url).await;
+            }
+        }
+
+        // Make sure that credentials can be sent in the HTTP headers.
+        if let Err(err) = Self::static_auth_header(&auth_profile.kind) {
+            bail!(RetrackError::client_with_root_cause(err.context(
+                "Auth profile credentials cannot be sent in HTTP headers."
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates that the OAuth2 token endpoint URL is public, if required by the configuration.
+    async fn validate_auth_profile_token_url(&self, token_url: &Url) -> anyhow::Result<()> {
+        if self.api.config.trackers.restrict_to_public_urls
+            && !self.api.network.is_public_web_url(token_url).await
+        {
+            bail!(RetrackError::client(format!(
+                "Auth profile token URL must be either `http` or `https` and have a valid public reachable domain name, but received {token_url}."
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Encodes credentials for the HTTP Basic authentication scheme.
+fn basic_credentials(username: &str, password: Option<&str>) -> String {
+    format!(
+        "Basic {}",
+        STANDARD.encode(format!("{username}:{}", password.unwrap_or_default()))
+    )
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Returns an API to work with auth profiles.
+    pub fn auth_profiles(&self) -> AuthProfilesApi<'_, DR, ET> {
+        AuthProfilesApi::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error as RetrackError,
+        tests::{mock_api, mock_api_with_network, mock_network_with_records},
+    };
+    use httpmock::MockServer;
+    use insta::assert_debug_snapshot;
+    use retrack_types::auth_profiles::{
+        AuthProfileCreateParams, AuthProfileKind, AuthProfileUpdateParams,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::net::Ipv4Addr;
+    use trust_dns_resolver::{
+        proto::rr::{rdata::A, RData, Record},
+        Name,
+    };
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn properly_creates_updates_and_removes_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let auth_profiles = api.auth_profiles();
+
+        let auth_profile = auth_profiles
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: AuthProfileKind::Basic {
+                    username: "user".to_string(),
+                    password: Some("pass".to_string()),
+                },
+            })
+            .await?;
+        assert_eq!(
+            auth_profiles.get_auth_profile(auth_profile.id).await?,
+            Some(auth_profile.clone())
+        );
+        assert_eq!(
+            auth_profiles.get_auth_profile_by_name("github").await?,
+            Some(auth_profile.clone())
+        );
+        assert_debug_snapshot!(auth_profiles.get_auth_header(&auth_profile).await?, @r###"
+        (
+            "authorization",
+            "Basic dXNlcjpwYXNz",
+        )
+        "###);
+
+        let updated_auth_profile = auth_profiles
+            .update_auth_profile(
+                auth_profile.id,
+                AuthProfileUpdateParams {
+                    name: Some("github-api".to_string()),
+                    kind: Some(AuthProfileKind::ApiKey {
+                        header: "X-API-Key".to_string(),
+                        value: "some-key".to_string(),
+                    }),
+                },
+            )
+            .await?;
+        assert_eq!(updated_auth_profile.id, auth_profile.id);
+        assert_eq!(updated_auth_profile.created_at, auth_profile.created_at);
+        assert_eq!(
+            auth_profiles.get_auth_profiles().await?,
+            vec![updated_auth_profile.clone()]
+        );
+        assert_debug_snapshot!(auth_profiles.get_auth_header(&updated_auth_profile).await?, @r###"
+        (
+            "x-api-key",
+            "some-key",
+        )
+        "###);
+
+        auth_profiles.remove_auth_profile(auth_profile.id).await?;
+        assert!(auth_profiles.get_auth_profiles().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_obtains_access_token_with_refresh_token(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let api = mock_api(pool).await?;
+        let auth_profiles = api.auth_profiles();
+
+        let auth_profile = auth_profiles
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "google".to_string(),
+                kind: AuthProfileKind::OAuth2RefreshToken {
+                    token_url: server.url("/oauth/token").parse()?,
+                    client_id: "some-client".to_string(),
+                    client_secret: None,
+                    refresh_token: "some-refresh-token".to_string(),
+                    scope: Some("read".to_string()),
+                },
+            })
+            .await?;
+
+        let token_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/oauth/token")
+                .body("grant_type=refresh_token&refresh_token=some-refresh-token&scope=read&client_id=some-client");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body(json!({
+                    "access_token": "oauth2-token",
+                    "token_type": "Bearer",
+                    "expires_in": 3600,
+                    "refresh_token": "rotated-refresh-token"
+                }));
+        });
+
+        // Access token should be requested only once and then reused.
+        let auth_header = auth_profiles.get_auth_header(&auth_profile).await?;
+        assert_debug_snapshot!(auth_header, @r###"
+        (
+            "authorization",
+            "Bearer oauth2-token",
+        )
+        "###);
+        assert_eq!(
+            auth_profiles.get_auth_header(&auth_profile).await?,
+            auth_header
+        );
+        token_mock.assert_hits(1);
+
+        // Rotated refresh token should be stored without invalidating the cached access token.
+        let updated_auth_profile = auth_profiles
+            .get_auth_profile(auth_profile.id)
+            .await?
+            .unwrap();
+        assert_eq!(updated_auth_profile.updated_at, auth_profile.updated_at);
+        assert!(matches!(
+            updated_auth_profile.kind,
+            AuthProfileKind::OAuth2RefreshToken { ref refresh_token, .. } if refresh_token == "rotated-refresh-token"
+        ));
+        assert_eq!(
+            auth_profiles.get_auth_header(&updated_auth_profile).await?,
+            auth_header
+        );
+        token_mock.assert_hits(1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool.clone()).await?;
+        let auth_profiles = api.auth_profiles();
+
+        let params = |name: &str, kind: AuthProfileKind| AuthProfileCreateParams {
+            name: name.to_string(),
+            kind,
+        };
+
+        let bearer = AuthProfileKind::Bearer {
+            token: "some-token".to_string(),
+        };
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("", bearer.clone())).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile name cannot be empty.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params(&"a".repeat(101), bearer.clone())).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile name cannot be longer than 100 characters.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::Basic { username: "user:name".to_string(), password: None })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile username cannot be empty or contain colons.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::Bearer { token: "".to_string() })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile token cannot be empty.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::Bearer { token: "some\ntoken".to_string() })).await.unwrap_err().downcast::<RetrackError>()?,
+            @r###"
+        Error {
+            context: "Auth profile credentials cannot be sent in HTTP headers.",
+            source: InvalidHeaderValue,
+        }
+        "###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::ApiKey { header: "X API Key".to_string(), value: "some-key".to_string() })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile API key header name is not valid ('X API Key').""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::ApiKey { header: "X-API-Key".to_string(), value: "".to_string() })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile API key cannot be empty.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::OAuth2ClientCredentials {
+                token_url: "https://retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: "".to_string(),
+                scope: None,
+            })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile client ID and client secret cannot be empty.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::OAuth2RefreshToken {
+                token_url: "https://retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: Some("".to_string()),
+                refresh_token: "some-refresh-token".to_string(),
+                scope: None,
+            })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile client ID and client secret, if specified, cannot be empty.""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", AuthProfileKind::OAuth2RefreshToken {
+                token_url: "https://retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: None,
+                refresh_token: "".to_string(),
+                scope: None,
+            })).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile refresh token cannot be empty.""###
+        );
+
+        // Auth profile names are unique.
+        auth_profiles
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: bearer.clone(),
+            })
+            .await?;
+        assert_debug_snapshot!(
+            auth_profiles.create_auth_profile(params("github", bearer.clone())).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile with such name ('github') already exists.""###
+        );
+
+        let unknown_id = uuid!("00000000-0000-0000-0000-000000000001");
+        assert_debug_snapshot!(
+            auth_profiles.update_auth_profile(unknown_id, AuthProfileUpdateParams::default()).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""At least one auth profile property should be provided (00000000-0000-0000-0000-000000000001).""###
+        );
+        assert_debug_snapshot!(
+            auth_profiles.update_auth_profile(unknown_id, AuthProfileUpdateParams { name: Some("gitlab".to_string()), kind: None }).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile ('00000000-0000-0000-0000-000000000001') is not found.""###
+        );
+
+        // Token URL should be public.
+        let mut api_with_local_network = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
+            )]),
+        )
+        .await?;
+        api_with_local_network
+            .config
+            .trackers
+            .restrict_to_public_urls = true;
+        assert_debug_snapshot!(
+            api_with_local_network.auth_profiles().create_auth_profile(AuthProfileCreateParams {
+                name: "gitlab".to_string(),
+                kind: AuthProfileKind::OAuth2ClientCredentials {
+                    token_url: "https://retrack.dev/token".parse()?,
+                    client_id: "some-client".to_string(),
+                    client_secret: "some-secret".to_string(),
+                    scope: None,
+                },
+            }).await.unwrap_err().downcast::<RetrackError>()?.root_cause.to_string(),
+            @r###""Auth profile token URL must be either `http` or `https` and have a valid public reachable domain name, but received https://retrack.dev/token.""###
+        );
+
+        Ok(())
+    }
+}
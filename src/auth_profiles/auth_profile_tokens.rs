@@ -0,0 +1,119 @@
+use retrack_types::auth_profiles::AuthProfile;
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Tokens are considered expired a bit earlier than they actually expire, so that they don't
+/// expire while the request is in flight.
+const TOKEN_EXPIRATION_LEEWAY: Duration = Duration::from_secs(30);
+
+/// Access token issued for the auth profile.
+#[derive(Debug, Clone)]
+struct AuthProfileToken {
+    value: String,
+    /// Time of the last auth profile update when the token was issued. Tokens issued before the
+    /// auth profile was updated are considered stale.
+    profile_updated_at: OffsetDateTime,
+    expires_at: Option<OffsetDateTime>,
+}
+
+/// In-memory cache of the access tokens issued for the auth profiles (e.g., OAuth2 client
+/// credentials), keyed by the auth profile ID.
+#[derive(Debug, Default)]
+pub struct AuthProfileTokens(Mutex<HashMap<Uuid, AuthProfileToken>>);
+
+impl AuthProfileTokens {
+    /// Returns cached access token for the auth profile, if it's still valid.
+    pub fn get(&self, auth_profile: &AuthProfile) -> Option<String> {
+        let tokens = self.0.lock().ok()?;
+        let token = tokens.get(&auth_profile.id)?;
+        let is_expired = token.expires_at.is_some_and(|expires_at| {
+            expires_at - TOKEN_EXPIRATION_LEEWAY <= OffsetDateTime::now_utc()
+        });
+        if is_expired || token.profile_updated_at != auth_profile.updated_at {
+            return None;
+        }
+
+        Some(token.value.clone())
+    }
+
+    /// Caches access token issued for the auth profile. Tokens without expiration time are cached
+    /// until the auth profile is updated, or the token is explicitly removed.
+    pub fn insert(&self, auth_profile: &AuthProfile, value: String, expires_in: Option<Duration>) {
+        if let Ok(mut tokens) = self.0.lock() {
+            tokens.insert(
+                auth_profile.id,
+                AuthProfileToken {
+                    value,
+                    profile_updated_at: auth_profile.updated_at,
+                    expires_at: expires_in.map(|expires_in| OffsetDateTime::now_utc() + expires_in),
+                },
+            );
+        }
+    }
+
+    /// Removes cached access token of the auth profile, e.g. when the token is rejected.
+    pub fn remove(&self, auth_profile_id: Uuid) {
+        if let Ok(mut tokens) = self.0.lock() {
+            tokens.remove(&auth_profile_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuthProfileTokens;
+    use retrack_types::auth_profiles::{AuthProfile, AuthProfileKind};
+    use std::time::Duration;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn properly_caches_tokens() -> anyhow::Result<()> {
+        let auth_profile = AuthProfile {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "github".to_string(),
+            kind: AuthProfileKind::OAuth2ClientCredentials {
+                token_url: "https://retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: "some-secret".to_string(),
+                scope: None,
+            },
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+
+        let tokens = AuthProfileTokens::default();
+        assert_eq!(tokens.get(&auth_profile), None);
+
+        tokens.insert(&auth_profile, "token-one".to_string(), None);
+        assert_eq!(tokens.get(&auth_profile), Some("token-one".to_string()));
+
+        // Tokens that are about to expire aren't returned.
+        tokens.insert(
+            &auth_profile,
+            "token-two".to_string(),
+            Some(Duration::from_secs(10)),
+        );
+        assert_eq!(tokens.get(&auth_profile), None);
+
+        tokens.insert(
+            &auth_profile,
+            "token-three".to_string(),
+            Some(Duration::from_secs(3600)),
+        );
+        assert_eq!(tokens.get(&auth_profile), Some("token-three".to_string()));
+
+        // Tokens issued before the auth profile update aren't returned.
+        let updated_auth_profile = AuthProfile {
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            ..auth_profile.clone()
+        };
+        assert_eq!(tokens.get(&updated_auth_profile), None);
+
+        tokens.remove(auth_profile.id);
+        assert_eq!(tokens.get(&auth_profile), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+mod raw_auth_profile;
+
+use crate::{
+    auth_profiles::database_ext::raw_auth_profile::RawAuthProfile, database::Database,
+    error::Error as RetrackError,
+};
+use anyhow::{anyhow, bail};
+use retrack_types::auth_profiles::AuthProfile;
+use sqlx::{query, query_as};
+use uuid::Uuid;
+
+/// Name of the database constraint that enforces auth profile name uniqueness.
+const AUTH_PROFILES_NAME_CONSTRAINT: &str = "auth_profiles_name_key";
+
+/// Extends primary database with the auth profiles-related methods.
+impl Database {
+    /// Retrieves all auth profiles from the database, ordered by name.
+    pub async fn get_auth_profiles(&self) -> anyhow::Result<Vec<AuthProfile>> {
+        query_as!(
+            RawAuthProfile,
+            r#"SELECT id, name, kind, created_at, updated_at FROM auth_profiles ORDER BY name"#
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(AuthProfile::try_from)
+        .collect()
+    }
+
+    /// Retrieves auth profile from the database using ID.
+    pub async fn get_auth_profile(&self, id: Uuid) -> anyhow::Result<Option<AuthProfile>> {
+        query_as!(
+            RawAuthProfile,
+            r#"SELECT id, name, kind, created_at, updated_at FROM auth_profiles WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(AuthProfile::try_from)
+        .transpose()
+    }
+
+    /// Retrieves auth profile from the database using name.
+    pub async fn get_auth_profile_by_name(
+        &self,
+        name: &str,
+    ) -> anyhow::Result<Option<AuthProfile>> {
+        query_as!(
+            RawAuthProfile,
+            r#"SELECT id, name, kind, created_at, updated_at FROM auth_profiles WHERE name = $1"#,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(AuthProfile::try_from)
+        .transpose()
+    }
+
+    /// Inserts a new auth profile to the database.
+    pub async fn insert_auth_profile(&self, auth_profile: &AuthProfile) -> anyhow::Result<()> {
+        let raw_auth_profile = RawAuthProfile::try_from(auth_profile)?;
+        let result = query!(
+            r#"
+INSERT INTO auth_profiles (id, name, kind, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5)
+            "#,
+            raw_auth_profile.id,
+            raw_auth_profile.name,
+            raw_auth_profile.kind,
+            raw_auth_profile.created_at,
+            raw_auth_profile.updated_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        if let Err(err) = result {
+            bail!(match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    let message =
+                        if database_error.constraint() == Some(AUTH_PROFILES_NAME_CONSTRAINT) {
+                            format!(
+                                "Auth profile with such name ('{}') already exists.",
+                                auth_profile.name
+                            )
+                        } else {
+                            format!(
+                                "Auth profile with such id ('{}') already exists.",
+                                auth_profile.id
+                            )
+                        };
+                    RetrackError::client_with_root_cause(anyhow!(err).context(message))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't create auth profile ('{}') due to unknown reason.",
+                    auth_profile.name
+                ))),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Updates auth profile in the database.
+    pub async fn update_auth_profile(&self, auth_profile: &AuthProfile) -> anyhow::Result<()> {
+        let raw_auth_profile = RawAuthProfile::try_from(auth_profile)?;
+        let result = query!(
+            r#"
+UPDATE auth_profiles
+SET name = $2, kind = $3, updated_at = $4
+WHERE id = $1
+            "#,
+            raw_auth_profile.id,
+            raw_auth_profile.name,
+            raw_auth_profile.kind,
+            raw_auth_profile.updated_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => {
+                bail!(RetrackError::client(format!(
+                    "Auth profile ('{}') doesn't exist.",
+                    auth_profile.id
+                )));
+            }
+            Ok(_) => Ok(()),
+            Err(err) => bail!(match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    RetrackError::client_with_root_cause(anyhow!(err).context(format!(
+                        "Auth profile with such name ('{}') already exists.",
+                        auth_profile.name
+                    )))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't update auth profile ('{}') due to unknown reason.",
+                    auth_profile.name
+                ))),
+            }),
+        }
+    }
+
+    /// Removes auth profile from the database.
+    pub async fn remove_auth_profile(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(r#"DELETE FROM auth_profiles WHERE id = $1"#, id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{database::Database, error::Error as RetrackError};
+    use insta::assert_debug_snapshot;
+    use retrack_types::auth_profiles::{AuthProfile, AuthProfileKind};
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    fn mock_auth_profile(id: u128, name: &str) -> anyhow::Result<AuthProfile> {
+        Ok(AuthProfile {
+            id: Uuid::from_u128(id),
+            name: name.to_string(),
+            kind: AuthProfileKind::Bearer {
+                token: "some-token".to_string(),
+            },
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    #[sqlx::test]
+    async fn can_add_update_and_remove_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let auth_profile_one = mock_auth_profile(1, "github")?;
+        let auth_profile_two = mock_auth_profile(2, "gitlab")?;
+        assert!(db.get_auth_profile(auth_profile_one.id).await?.is_none());
+        assert!(db.get_auth_profiles().await?.is_empty());
+
+        db.insert_auth_profile(&auth_profile_two).await?;
+        db.insert_auth_profile(&auth_profile_one).await?;
+        assert_eq!(
+            db.get_auth_profile(auth_profile_one.id).await?,
+            Some(auth_profile_one.clone())
+        );
+        assert_eq!(
+            db.get_auth_profile_by_name("gitlab").await?,
+            Some(auth_profile_two.clone())
+        );
+        assert_eq!(
+            db.get_auth_profiles().await?,
+            vec![auth_profile_one.clone(), auth_profile_two.clone()]
+        );
+
+        let auth_profile_one = AuthProfile {
+            name: "github-app".to_string(),
+            kind: AuthProfileKind::OAuth2ClientCredentials {
+                token_url: "https://github.com/login/oauth/access_token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: "some-secret".to_string(),
+                scope: None,
+            },
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            ..auth_profile_one
+        };
+        db.update_auth_profile(&auth_profile_one).await?;
+        assert_eq!(
+            db.get_auth_profile(auth_profile_one.id).await?,
+            Some(auth_profile_one.clone())
+        );
+        assert!(db.get_auth_profile_by_name("github").await?.is_none());
+
+        db.remove_auth_profile(auth_profile_one.id).await?;
+        assert!(db.get_auth_profile(auth_profile_one.id).await?.is_none());
+        assert_eq!(db.get_auth_profiles().await?, vec![auth_profile_two]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_add_or_update_auth_profile_with_duplicate_name(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        db.insert_auth_profile(&mock_auth_profile(1, "github")?)
+            .await?;
+        db.insert_auth_profile(&mock_auth_profile(2, "gitlab")?)
+            .await?;
+
+        let insert_error = db
+            .insert_auth_profile(&mock_auth_profile(3, "github")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(insert_error.root_cause.to_string(), @r###""Auth profile with such name ('github') already exists.""###);
+
+        let insert_error = db
+            .insert_auth_profile(&mock_auth_profile(1, "bitbucket")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(insert_error.root_cause.to_string(), @r###""Auth profile with such id ('00000000-0000-0000-0000-000000000001') already exists.""###);
+
+        let update_error = db
+            .update_auth_profile(&mock_auth_profile(2, "github")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(update_error.root_cause.to_string(), @r###""Auth profile with such name ('github') already exists.""###);
+
+        let update_error = db
+            .update_auth_profile(&mock_auth_profile(3, "bitbucket")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(update_error.root_cause.to_string(), @r###""Auth profile ('00000000-0000-0000-0000-000000000003') doesn't exist.""###);
+
+        Ok(())
+    }
+}
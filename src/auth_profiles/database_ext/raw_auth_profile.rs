@@ -0,0 +1,203 @@
+use retrack_types::auth_profiles::{AuthProfile, AuthProfileKind};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawAuthProfile {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: Vec<u8>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+enum RawAuthProfileKind<'s> {
+    Basic(Cow<'s, str>, Option<Cow<'s, str>>),
+    Bearer(Cow<'s, str>),
+    ApiKey(Cow<'s, str>, Cow<'s, str>),
+    OAuth2ClientCredentials(
+        Cow<'s, str>,
+        Cow<'s, str>,
+        Cow<'s, str>,
+        Option<Cow<'s, str>>,
+    ),
+    OAuth2RefreshToken(
+        Cow<'s, str>,
+        Cow<'s, str>,
+        Option<Cow<'s, str>>,
+        Cow<'s, str>,
+        Option<Cow<'s, str>>,
+    ),
+}
+
+impl TryFrom<RawAuthProfile> for AuthProfile {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawAuthProfile) -> Result<Self, Self::Error> {
+        Ok(AuthProfile {
+            id: raw.id,
+            name: raw.name,
+            kind: match postcard::from_bytes::<RawAuthProfileKind>(&raw.kind)? {
+                RawAuthProfileKind::Basic(username, password) => AuthProfileKind::Basic {
+                    username: username.into_owned(),
+                    password: password.map(Cow::into_owned),
+                },
+                RawAuthProfileKind::Bearer(token) => AuthProfileKind::Bearer {
+                    token: token.into_owned(),
+                },
+                RawAuthProfileKind::ApiKey(header, value) => AuthProfileKind::ApiKey {
+                    header: header.into_owned(),
+                    value: value.into_owned(),
+                },
+                RawAuthProfileKind::OAuth2ClientCredentials(
+                    token_url,
+                    client_id,
+                    client_secret,
+                    scope,
+                ) => AuthProfileKind::OAuth2ClientCredentials {
+                    token_url: token_url.parse()?,
+                    client_id: client_id.into_owned(),
+                    client_secret: client_secret.into_owned(),
+                    scope: scope.map(Cow::into_owned),
+                },
+                RawAuthProfileKind::OAuth2RefreshToken(
+                    token_url,
+                    client_id,
+                    client_secret,
+                    refresh_token,
+                    scope,
+                ) => AuthProfileKind::OAuth2RefreshToken {
+                    token_url: token_url.parse()?,
+                    client_id: client_id.into_owned(),
+                    client_secret: client_secret.map(Cow::into_owned),
+                    refresh_token: refresh_token.into_owned(),
+                    scope: scope.map(Cow::into_owned),
+                },
+            },
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        })
+    }
+}
+
+impl TryFrom<&AuthProfile> for RawAuthProfile {
+    type Error = anyhow::Error;
+
+    fn try_from(item: &AuthProfile) -> Result<Self, Self::Error> {
+        let raw_kind = match &item.kind {
+            AuthProfileKind::Basic { username, password } => RawAuthProfileKind::Basic(
+                Cow::Borrowed(username),
+                password.as_deref().map(Cow::Borrowed),
+            ),
+            AuthProfileKind::Bearer { token } => RawAuthProfileKind::Bearer(Cow::Borrowed(token)),
+            AuthProfileKind::ApiKey { header, value } => {
+                RawAuthProfileKind::ApiKey(Cow::Borrowed(header), Cow::Borrowed(value))
+            }
+            AuthProfileKind::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+            } => RawAuthProfileKind::OAuth2ClientCredentials(
+                Cow::Borrowed(token_url.as_str()),
+                Cow::Borrowed(client_id),
+                Cow::Borrowed(client_secret),
+                scope.as_deref().map(Cow::Borrowed),
+            ),
+            AuthProfileKind::OAuth2RefreshToken {
+                token_url,
+                client_id,
+                client_secret,
+                refresh_token,
+                scope,
+            } => RawAuthProfileKind::OAuth2RefreshToken(
+                Cow::Borrowed(token_url.as_str()),
+                Cow::Borrowed(client_id),
+                client_secret.as_deref().map(Cow::Borrowed),
+                Cow::Borrowed(refresh_token),
+                scope.as_deref().map(Cow::Borrowed),
+            ),
+        };
+
+        Ok(RawAuthProfile {
+            id: item.id,
+            name: item.name.clone(),
+            kind: postcard::to_stdvec(&raw_kind)?,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawAuthProfile;
+    use retrack_types::auth_profiles::{AuthProfile, AuthProfileKind};
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn can_convert_into_and_from_raw_auth_profile() -> anyhow::Result<()> {
+        let auth_profile = AuthProfile {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "github".to_string(),
+            kind: AuthProfileKind::Bearer {
+                token: "some-token".to_string(),
+            },
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+        assert_eq!(
+            RawAuthProfile::try_from(&auth_profile)?,
+            RawAuthProfile {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "github".to_string(),
+                kind: vec![1, 10, 115, 111, 109, 101, 45, 116, 111, 107, 101, 110],
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            }
+        );
+
+        for kind in [
+            AuthProfileKind::Basic {
+                username: "user".to_string(),
+                password: Some("pass".to_string()),
+            },
+            AuthProfileKind::Basic {
+                username: "user".to_string(),
+                password: None,
+            },
+            AuthProfileKind::ApiKey {
+                header: "X-API-Key".to_string(),
+                value: "some-key".to_string(),
+            },
+            AuthProfileKind::OAuth2ClientCredentials {
+                token_url: "https://auth.retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: "some-secret".to_string(),
+                scope: Some("read write".to_string()),
+            },
+            AuthProfileKind::OAuth2RefreshToken {
+                token_url: "https://auth.retrack.dev/token".parse()?,
+                client_id: "some-client".to_string(),
+                client_secret: None,
+                refresh_token: "some-refresh-token".to_string(),
+                scope: None,
+            },
+        ] {
+            let auth_profile = AuthProfile {
+                kind,
+                ..auth_profile.clone()
+            };
+            assert_eq!(
+                AuthProfile::try_from(RawAuthProfile::try_from(&auth_profile)?)?,
+                auth_profile
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,19 +1,37 @@
+mod api_config;
 mod cache_config;
 mod components_config;
 mod database_config;
 mod js_runtime_config;
 mod raw_config;
 mod scheduler_jobs_config;
+mod secrets_config;
 mod smtp_config;
 mod trackers_config;
 
 use url::Url;
 
+#[cfg(any(test, feature = "scheduler-redis-locks"))]
+pub use self::scheduler_jobs_config::SchedulerRedisLocksConfig;
 pub use self::{
-    cache_config::CacheConfig, components_config::ComponentsConfig,
-    database_config::DatabaseConfig, js_runtime_config::JsRuntimeConfig, raw_config::RawConfig,
-    scheduler_jobs_config::SchedulerJobsConfig, smtp_config::SmtpConfig,
-    trackers_config::TrackersConfig,
+    api_config::ApiConfig,
+    cache_config::{CacheConfig, HttpCacheStorageConfig},
+    components_config::ComponentsConfig,
+    database_config::{DatabaseConfig, DatabaseMigrationsConfig, DatabaseRetriesConfig},
+    js_runtime_config::JsRuntimeConfig,
+    raw_config::RawConfig,
+    scheduler_jobs_config::SchedulerJobsConfig,
+    secrets_config::SecretsConfig,
+    smtp_config::SmtpConfig,
+    trackers_config::{TrackerNameUniqueness, TrackersConfig},
+};
+#[cfg(test)]
+pub use self::{
+    components_config::{EmbeddingsConfig, SummarizerConfig, TranslatorConfig},
+    trackers_config::{
+        TrackerActionTimeouts, TrackerAlertsCorrelation, TrackerDefaultActions, TrackerPolicies,
+        TrackerWebhookDestinations,
+    },
 };
 
 /// Main server config.
@@ -21,6 +39,8 @@ pub use self::{
 pub struct Config {
     /// External/public URL through which service is being accessed.
     pub public_url: Url,
+    /// Configuration for the Retrack HTTP API.
+    pub api: ApiConfig,
     /// Database configuration.
     pub db: DatabaseConfig,
     /// Configuration for the various caches Retrack relies on.
@@ -35,6 +55,8 @@ pub struct Config {
     pub trackers: TrackersConfig,
     /// Configuration for the embedded JS Runtime.
     pub js_runtime: JsRuntimeConfig,
+    /// Named secrets that trackers can reference.
+    pub secrets: SecretsConfig,
 }
 
 impl AsRef<Config> for Config {
@@ -47,6 +69,7 @@ impl From<RawConfig> for Config {
     fn from(raw_config: RawConfig) -> Self {
         Self {
             public_url: raw_config.public_url,
+            api: raw_config.api,
             db: raw_config.db,
             cache: raw_config.cache,
             smtp: raw_config.smtp,
@@ -54,13 +77,14 @@ impl From<RawConfig> for Config {
             scheduler: raw_config.scheduler,
             trackers: raw_config.trackers,
             js_runtime: raw_config.js_runtime,
+            secrets: raw_config.secrets,
         }
     }
 }
 
 #[cfg(test)]
 pub mod tests {
-    pub use crate::config::smtp_config::SmtpCatchAllConfig;
+    pub use crate::config::smtp_config::{SmtpCatchAllConfig, SmtpRoutingAction, SmtpRoutingRule};
     use crate::config::{Config, RawConfig, SmtpConfig};
     use insta::assert_debug_snapshot;
     use regex::Regex;
@@ -76,6 +100,7 @@ pub mod tests {
                     recipient: "test@retrack.dev".to_string(),
                     text_matcher: Regex::new(r"test").unwrap(),
                 }),
+                routes: vec![],
             }),
             ..Default::default()
         };
@@ -99,6 +124,9 @@ pub mod tests {
                 query: None,
                 fragment: None,
             },
+            api: ApiConfig {
+                require_tokens: false,
+            },
             db: DatabaseConfig {
                 name: "retrack",
                 host: "localhost",
@@ -106,9 +134,21 @@ pub mod tests {
                 username: "postgres",
                 password: None,
                 max_connections: 100,
+                migrations: DatabaseMigrationsConfig {
+                    lock_timeout: 5s,
+                    max_attempts: 10,
+                },
+                retries: DatabaseRetriesConfig {
+                    max_attempts: 3,
+                    min_interval: 100ms,
+                    max_interval: 2s,
+                    circuit_breaker_threshold: 5,
+                    circuit_breaker_cooldown: 30s,
+                },
             },
             cache: CacheConfig {
                 http_cache_path: None,
+                http_cache_storage: None,
             },
             smtp: Some(
                 SmtpConfig {
@@ -123,6 +163,7 @@ pub mod tests {
                             ),
                         },
                     ),
+                    routes: [],
                 },
             ),
             components: ComponentsConfig {
@@ -143,26 +184,48 @@ pub mod tests {
                     query: None,
                     fragment: None,
                 },
+                translator: None,
+                summarizer: None,
+                embeddings: None,
             },
             scheduler: SchedulerJobsConfig {
                 trackers_schedule: "0/10 * * * * *",
                 trackers_run: "0/10 * * * * *",
                 tasks_run: "0/30 * * * * *",
+                trackers_digest: "0 * * * * *",
+                trackers_retention: "0 0 * * * *",
+                redis_locks: None,
             },
             trackers: TrackersConfig {
                 max_revisions: 30,
+                max_revision_age: None,
                 schedules: None,
                 min_schedule_interval: 10s,
                 restrict_to_public_urls: true,
                 max_script_size: Byte(
                     4096,
                 ),
+                name_uniqueness: Global,
+                alerts_correlation: None,
+                webhook_destinations: None,
+                file_roots: [],
+                file_action_roots: [],
+                command_action_programs: [],
+                default_tags: [],
+                default_actions: [],
+                policies: None,
+                action_timeouts: TrackerActionTimeouts {
+                    webhook: 30s,
+                    email: 30s,
+                },
+                runbook_urls: {},
             },
             js_runtime: JsRuntimeConfig {
                 max_heap_size: 10485760,
                 max_script_execution_time: 10s,
                 channel_buffer_size: 10,
             },
+            secrets: {},
         }
         "###);
     }
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Retrack HTTP API.
+#[derive(Deserialize, Serialize, Default, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct ApiConfig {
+    /// Indicates whether the endpoints that can be called by the third parties (running trackers
+    /// and creating tracker data revisions) reject requests that aren't authorized with a valid
+    /// API token.
+    pub require_tokens: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::api_config::ApiConfig;
+    use insta::assert_toml_snapshot;
+
+    #[test]
+    fn serialization_and_default() {
+        assert_toml_snapshot!(ApiConfig::default(), @"require_tokens = false");
+
+        let config = ApiConfig {
+            require_tokens: true,
+        };
+        assert_toml_snapshot!(config, @"require_tokens = true");
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: ApiConfig = toml::from_str(
+            r#"
+         require_tokens = true
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            ApiConfig {
+                require_tokens: true,
+            }
+        );
+
+        let config: ApiConfig = toml::from_str("").unwrap();
+        assert_eq!(config, ApiConfig::default());
+    }
+}
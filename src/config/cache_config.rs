@@ -1,24 +1,92 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use url::Url;
 
 /// Configuration for the various caches Retrack relies on.
 #[derive(Deserialize, Serialize, Default, Debug, Clone, PartialEq)]
 pub struct CacheConfig {
-    /// The directory where the HTTP cache will be stored.
+    /// The directory where the HTTP cache will be stored (used only if the HTTP cache storage isn't
+    /// explicitly configured).
     pub http_cache_path: Option<PathBuf>,
+    /// Optional storage for the HTTP cache that can be shared between multiple Retrack instances.
+    /// If not specified, the HTTP cache is stored in the local directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_cache_storage: Option<HttpCacheStorageConfig>,
+}
+
+/// Configuration for the shared HTTP cache storage.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HttpCacheStorageConfig {
+    /// HTTP cache is stored in Redis (requires `http-cache-redis` feature).
+    Redis {
+        /// Redis connection URL, e.g. `redis://localhost:6379/0`.
+        url: Url,
+        /// Optional prefix for the keys of the cached HTTP responses.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key_prefix: Option<String>,
+    },
+    /// HTTP cache is stored in S3-compatible object storage (requires `http-cache-s3` feature).
+    /// Credentials are read from the standard `AWS_*` environment variables.
+    S3 {
+        /// Name of the bucket to store cached HTTP responses in.
+        bucket: String,
+        /// Optional region of the bucket (taken from `AWS_REGION` if not specified).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        /// Optional custom endpoint for the S3-compatible storage (e.g., MinIO).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        endpoint: Option<Url>,
+        /// Optional prefix for the keys of the cached HTTP responses.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key_prefix: Option<String>,
+    },
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::cache_config::CacheConfig;
+    use crate::config::cache_config::{CacheConfig, HttpCacheStorageConfig};
     use insta::assert_toml_snapshot;
+    use url::Url;
 
     #[test]
     fn serialization_and_default() {
         let config = CacheConfig {
             http_cache_path: Some("./http-cache".into()),
+            http_cache_storage: None,
         };
         assert_toml_snapshot!(config, @"http_cache_path = './http-cache'");
+
+        let config = CacheConfig {
+            http_cache_path: None,
+            http_cache_storage: Some(HttpCacheStorageConfig::Redis {
+                url: Url::parse("redis://localhost:6379/0").unwrap(),
+                key_prefix: Some("retrack:http-cache:".to_string()),
+            }),
+        };
+        assert_toml_snapshot!(config, @r###"
+        [http_cache_storage]
+        type = 'redis'
+        url = 'redis://localhost:6379/0'
+        key_prefix = 'retrack:http-cache:'
+        "###);
+
+        let config = CacheConfig {
+            http_cache_path: None,
+            http_cache_storage: Some(HttpCacheStorageConfig::S3 {
+                bucket: "retrack".to_string(),
+                region: Some("eu-central-1".to_string()),
+                endpoint: Some(Url::parse("http://localhost:9000").unwrap()),
+                key_prefix: None,
+            }),
+        };
+        assert_toml_snapshot!(config, @r###"
+        [http_cache_storage]
+        type = 's3'
+        bucket = 'retrack'
+        region = 'eu-central-1'
+        endpoint = 'http://localhost:9000/'
+        "###);
     }
 
     #[test]
@@ -33,6 +101,48 @@ mod tests {
             config,
             CacheConfig {
                 http_cache_path: Some("./http-cache".into()),
+                http_cache_storage: None,
+            }
+        );
+
+        let config: CacheConfig = toml::from_str(
+            r#"
+         [http_cache_storage]
+         type = 'redis'
+         url = 'redis://localhost:6379/0'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            CacheConfig {
+                http_cache_path: None,
+                http_cache_storage: Some(HttpCacheStorageConfig::Redis {
+                    url: Url::parse("redis://localhost:6379/0").unwrap(),
+                    key_prefix: None,
+                }),
+            }
+        );
+
+        let config: CacheConfig = toml::from_str(
+            r#"
+         [http_cache_storage]
+         type = 's3'
+         bucket = 'retrack'
+         key_prefix = 'http-cache/'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            CacheConfig {
+                http_cache_path: None,
+                http_cache_storage: Some(HttpCacheStorageConfig::S3 {
+                    bucket: "retrack".to_string(),
+                    region: None,
+                    endpoint: None,
+                    key_prefix: Some("http-cache/".to_string()),
+                }),
             }
         );
     }
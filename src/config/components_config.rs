@@ -6,6 +6,78 @@ use url::Url;
 pub struct ComponentsConfig {
     /// The URL to access the Web Scraper component.
     pub web_scraper_url: Url,
+    /// Optional configuration of the translation API (LibreTranslate-compatible) used by the
+    /// tracker translate actions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translator: Option<TranslatorConfig>,
+    /// Optional configuration of the LLM API (OpenAI-compatible) used by the tracker summarize
+    /// actions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summarizer: Option<SummarizerConfig>,
+    /// Optional configuration of the text embeddings API (OpenAI-compatible) used by the tracker
+    /// semantic filters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeddings: Option<EmbeddingsConfig>,
+}
+
+/// Configuration for the translation API.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TranslatorConfig {
+    /// The base URL of the translation API, e.g. `https://libretranslate.com/`.
+    pub url: Url,
+    /// Optional API key to authenticate to the translation API.
+    pub api_key: Option<String>,
+}
+
+/// Configuration for the LLM API used to summarize tracker data changes.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SummarizerConfig {
+    /// The base URL of the LLM API, e.g. `https://api.openai.com/v1/`.
+    pub url: Url,
+    /// Optional API key to authenticate to the LLM API.
+    pub api_key: Option<String>,
+    /// The name of the model to use, e.g. `gpt-4o-mini`.
+    pub model: String,
+    /// The maximum number of tokens of the changes sent to the LLM API, larger changes are
+    /// truncated.
+    #[serde(default = "SummarizerConfig::default_max_input_tokens")]
+    pub max_input_tokens: u32,
+    /// The maximum number of tokens in the summary.
+    #[serde(default = "SummarizerConfig::default_max_output_tokens")]
+    pub max_output_tokens: u32,
+}
+
+impl SummarizerConfig {
+    fn default_max_input_tokens() -> u32 {
+        4000
+    }
+
+    fn default_max_output_tokens() -> u32 {
+        200
+    }
+}
+
+/// Configuration for the text embeddings API used to detect semantic changes in tracker data. Any
+/// OpenAI-compatible API works, including the ones serving local models (e.g., Ollama).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct EmbeddingsConfig {
+    /// The base URL of the embeddings API, e.g. `https://api.openai.com/v1/` or
+    /// `http://localhost:11434/v1/`.
+    pub url: Url,
+    /// Optional API key to authenticate to the embeddings API.
+    pub api_key: Option<String>,
+    /// The name of the embeddings model to use, e.g. `text-embedding-3-small`.
+    pub model: String,
+    /// The maximum number of tokens of the tracker data sent to the embeddings API, larger data
+    /// is truncated.
+    #[serde(default = "EmbeddingsConfig::default_max_input_tokens")]
+    pub max_input_tokens: u32,
+}
+
+impl EmbeddingsConfig {
+    fn default_max_input_tokens() -> u32 {
+        8000
+    }
 }
 
 impl Default for ComponentsConfig {
@@ -13,20 +85,75 @@ impl Default for ComponentsConfig {
         Self {
             web_scraper_url: Url::parse("http://localhost:7272")
                 .expect("Cannot parse Web Scraper URL parameter."),
+            translator: None,
+            summarizer: None,
+            embeddings: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::ComponentsConfig;
+    use crate::config::{ComponentsConfig, EmbeddingsConfig, SummarizerConfig, TranslatorConfig};
     use insta::assert_toml_snapshot;
+    use url::Url;
 
     #[test]
     fn serialization_and_default() {
         assert_toml_snapshot!(ComponentsConfig::default(), @r###"
         web_scraper_url = 'http://localhost:7272/'
         "###);
+
+        assert_toml_snapshot!(ComponentsConfig {
+            translator: Some(TranslatorConfig {
+                url: Url::parse("http://localhost:5000").unwrap(),
+                api_key: Some("some-key".to_string()),
+            }),
+            ..Default::default()
+        }, @r###"
+        web_scraper_url = 'http://localhost:7272/'
+
+        [translator]
+        url = 'http://localhost:5000/'
+        api_key = 'some-key'
+        "###);
+
+        assert_toml_snapshot!(ComponentsConfig {
+            summarizer: Some(SummarizerConfig {
+                url: Url::parse("https://api.openai.com/v1/").unwrap(),
+                api_key: Some("some-key".to_string()),
+                model: "gpt-4o-mini".to_string(),
+                max_input_tokens: 1000,
+                max_output_tokens: 100,
+            }),
+            ..Default::default()
+        }, @r###"
+        web_scraper_url = 'http://localhost:7272/'
+
+        [summarizer]
+        url = 'https://api.openai.com/v1/'
+        api_key = 'some-key'
+        model = 'gpt-4o-mini'
+        max_input_tokens = 1000
+        max_output_tokens = 100
+        "###);
+
+        assert_toml_snapshot!(ComponentsConfig {
+            embeddings: Some(EmbeddingsConfig {
+                url: Url::parse("http://localhost:11434/v1/").unwrap(),
+                api_key: None,
+                model: "nomic-embed-text".to_string(),
+                max_input_tokens: 2000,
+            }),
+            ..Default::default()
+        }, @r###"
+        web_scraper_url = 'http://localhost:7272/'
+
+        [embeddings]
+        url = 'http://localhost:11434/v1/'
+        model = 'nomic-embed-text'
+        max_input_tokens = 2000
+        "###);
     }
 
     #[test]
@@ -38,5 +165,73 @@ mod tests {
         )
         .unwrap();
         assert_eq!(config, ComponentsConfig::default());
+
+        let config: ComponentsConfig = toml::from_str(
+            r#"
+        web_scraper_url = 'http://localhost:7272/'
+
+        [translator]
+        url = 'http://localhost:5000/'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            ComponentsConfig {
+                translator: Some(TranslatorConfig {
+                    url: Url::parse("http://localhost:5000").unwrap(),
+                    api_key: None,
+                }),
+                ..Default::default()
+            }
+        );
+
+        let config: ComponentsConfig = toml::from_str(
+            r#"
+        web_scraper_url = 'http://localhost:7272/'
+
+        [summarizer]
+        url = 'https://api.openai.com/v1/'
+        model = 'gpt-4o-mini'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            ComponentsConfig {
+                summarizer: Some(SummarizerConfig {
+                    url: Url::parse("https://api.openai.com/v1/").unwrap(),
+                    api_key: None,
+                    model: "gpt-4o-mini".to_string(),
+                    max_input_tokens: 4000,
+                    max_output_tokens: 200,
+                }),
+                ..Default::default()
+            }
+        );
+
+        let config: ComponentsConfig = toml::from_str(
+            r#"
+        web_scraper_url = 'http://localhost:7272/'
+
+        [embeddings]
+        url = 'https://api.openai.com/v1/'
+        api_key = 'some-key'
+        model = 'text-embedding-3-small'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            ComponentsConfig {
+                embeddings: Some(EmbeddingsConfig {
+                    url: Url::parse("https://api.openai.com/v1/").unwrap(),
+                    api_key: Some("some-key".to_string()),
+                    model: "text-embedding-3-small".to_string(),
+                    max_input_tokens: 8000,
+                }),
+                ..Default::default()
+            }
+        );
     }
 }
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
 
 /// Configuration for the database connection.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -15,6 +17,68 @@ pub struct DatabaseConfig {
     pub password: Option<String>,
     /// Defines a maximum number of connections allowed.
     pub max_connections: u32,
+    /// Configuration for the database schema migrations.
+    #[serde(default)]
+    pub migrations: DatabaseMigrationsConfig,
+    /// Configuration for the retries of the database queries that failed with transient errors.
+    #[serde(default)]
+    pub retries: DatabaseRetriesConfig,
+}
+
+/// Configuration for the database schema migrations applied at startup or with `--migrate-only`.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DatabaseMigrationsConfig {
+    /// Defines how long a migration can wait for a table lock before it's aborted. Aborted
+    /// migration doesn't hold back the queries queued behind it, and is retried later.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub lock_timeout: Duration,
+    /// Defines how many times a migration is attempted if it cannot acquire a table lock in time.
+    pub max_attempts: u32,
+}
+
+impl Default for DatabaseMigrationsConfig {
+    fn default() -> Self {
+        Self {
+            lock_timeout: Duration::from_secs(5),
+            max_attempts: 10,
+        }
+    }
+}
+
+/// Configuration for the retries of the database queries issued by the scheduler jobs and tasks
+/// that failed with transient errors (e.g., serialization failures or dropped connections).
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DatabaseRetriesConfig {
+    /// Defines how many times a query is attempted before the error is returned, including the
+    /// first attempt.
+    pub max_attempts: u32,
+    /// Defines the interval before the first retry, the interval is doubled for every next retry.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub min_interval: Duration,
+    /// Defines the maximum interval between retries.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub max_interval: Duration,
+    /// Defines how many queries in a row should fail with transient errors, despite retries,
+    /// before the circuit breaker opens and subsequent queries fail fast without hitting the
+    /// database.
+    pub circuit_breaker_threshold: u32,
+    /// Defines how long the circuit breaker stays open before the database is tried again.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub circuit_breaker_cooldown: Duration,
+}
+
+impl Default for DatabaseRetriesConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            min_interval: Duration::from_millis(100),
+            max_interval: Duration::from_secs(2),
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown: Duration::from_secs(30),
+        }
+    }
 }
 
 impl Default for DatabaseConfig {
@@ -26,14 +90,17 @@ impl Default for DatabaseConfig {
             username: "postgres".to_string(),
             password: None,
             max_connections: 100,
+            migrations: DatabaseMigrationsConfig::default(),
+            retries: DatabaseRetriesConfig::default(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::DatabaseConfig;
+    use crate::config::{DatabaseConfig, DatabaseMigrationsConfig, DatabaseRetriesConfig};
     use insta::{assert_debug_snapshot, assert_toml_snapshot};
+    use std::time::Duration;
 
     #[test]
     fn serialization() {
@@ -44,6 +111,17 @@ mod tests {
         port = 5432
         username = 'postgres'
         max_connections = 100
+
+        [migrations]
+        lock_timeout = 5000
+        max_attempts = 10
+
+        [retries]
+        max_attempts = 3
+        min_interval = 100
+        max_interval = 2000
+        circuit_breaker_threshold = 5
+        circuit_breaker_cooldown = 30000
         "###);
 
         let config = DatabaseConfig {
@@ -57,6 +135,17 @@ mod tests {
         username = 'postgres'
         password = 'password'
         max_connections = 100
+
+        [migrations]
+        lock_timeout = 5000
+        max_attempts = 10
+
+        [retries]
+        max_attempts = 3
+        min_interval = 100
+        max_interval = 2000
+        circuit_breaker_threshold = 5
+        circuit_breaker_cooldown = 30000
         "###);
     }
 
@@ -83,7 +172,57 @@ mod tests {
                 "password",
             ),
             max_connections: 1000,
+            migrations: DatabaseMigrationsConfig {
+                lock_timeout: 5s,
+                max_attempts: 10,
+            },
+            retries: DatabaseRetriesConfig {
+                max_attempts: 3,
+                min_interval: 100ms,
+                max_interval: 2s,
+                circuit_breaker_threshold: 5,
+                circuit_breaker_cooldown: 30s,
+            },
         }
         "###);
+
+        let config: DatabaseConfig = toml::from_str(
+            r#"
+        name = 'retrack'
+        username = 'postgres'
+        host = 'localhost'
+        port = 5432
+        max_connections = 1000
+
+        [migrations]
+        lock_timeout = 1000
+        max_attempts = 3
+
+        [retries]
+        max_attempts = 5
+        min_interval = 50
+        max_interval = 1000
+        circuit_breaker_threshold = 10
+        circuit_breaker_cooldown = 60000
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.migrations,
+            DatabaseMigrationsConfig {
+                lock_timeout: Duration::from_secs(1),
+                max_attempts: 3,
+            }
+        );
+        assert_eq!(
+            config.retries,
+            DatabaseRetriesConfig {
+                max_attempts: 5,
+                min_interval: Duration::from_millis(50),
+                max_interval: Duration::from_secs(1),
+                circuit_breaker_threshold: 10,
+                circuit_breaker_cooldown: Duration::from_secs(60),
+            }
+        );
     }
 }
@@ -1,6 +1,6 @@
 use crate::config::{
-    database_config::DatabaseConfig, CacheConfig, ComponentsConfig, JsRuntimeConfig,
-    SchedulerJobsConfig, SmtpConfig, TrackersConfig,
+    database_config::DatabaseConfig, ApiConfig, CacheConfig, ComponentsConfig, JsRuntimeConfig,
+    SchedulerJobsConfig, SecretsConfig, SmtpConfig, TrackersConfig,
 };
 use figment::{providers, providers::Format, Figment};
 use serde::{Deserialize, Serialize};
@@ -13,6 +13,8 @@ pub struct RawConfig {
     pub port: u16,
     /// External/public URL through which service is being accessed.
     pub public_url: Url,
+    /// Configuration for the Retrack HTTP API.
+    pub api: ApiConfig,
     /// Database configuration.
     pub db: DatabaseConfig,
     /// Defines various caches related settings.
@@ -27,6 +29,8 @@ pub struct RawConfig {
     pub smtp: Option<SmtpConfig>,
     /// Configuration for the embedded JS Runtime.
     pub js_runtime: JsRuntimeConfig,
+    /// Named secrets that trackers can reference (e.g., keys to decrypt or verify responses).
+    pub secrets: SecretsConfig,
 }
 
 impl RawConfig {
@@ -49,12 +53,14 @@ impl Default for RawConfig {
             db: DatabaseConfig::default(),
             public_url: Url::parse(&format!("http://localhost:{port}"))
                 .expect("Cannot parse public URL parameter."),
+            api: ApiConfig::default(),
             components: ComponentsConfig::default(),
             scheduler: SchedulerJobsConfig::default(),
             trackers: TrackersConfig::default(),
             js_runtime: JsRuntimeConfig::default(),
             smtp: None,
             cache: CacheConfig::default(),
+            secrets: SecretsConfig::default(),
         }
     }
 }
@@ -72,12 +78,24 @@ mod tests {
         port = 7676
         public_url = 'http://localhost:7676/'
 
+        [api]
+        require_tokens = false
+
         [db]
         name = 'retrack'
         host = 'localhost'
         port = 5432
         username = 'postgres'
         max_connections = 100
+        [db.migrations]
+        lock_timeout = 5000
+        max_attempts = 10
+        [db.retries]
+        max_attempts = 3
+        min_interval = 100
+        max_interval = 2000
+        circuit_breaker_threshold = 5
+        circuit_breaker_cooldown = 30000
 
         [cache]
 
@@ -88,17 +106,25 @@ mod tests {
         trackers_schedule = '0/10 * * * * *'
         trackers_run = '0/10 * * * * *'
         tasks_run = '0/30 * * * * *'
+        trackers_digest = '0 * * * * *'
+        trackers_retention = '0 0 * * * *'
 
         [trackers]
         max_revisions = 30
         min_schedule_interval = 10000
         restrict_to_public_urls = true
         max_script_size = '4 KiB'
+        name_uniqueness = 'global'
+        [trackers.action_timeouts]
+        webhook = 30000
+        email = 30000
 
         [js_runtime]
         max_heap_size = 10485760
         max_script_execution_time = 10000
         channel_buffer_size = 10
+
+        [secrets]
         "###);
     }
 
@@ -109,6 +135,9 @@ mod tests {
         port = 7070
         public_url = 'http://localhost:7070/'
 
+        [api]
+        require_tokens = true
+
         [db]
         name = 'retrack'
         schema = 'retrack'
@@ -140,6 +169,9 @@ mod tests {
         min_schedule_interval = 10_000
         restrict_to_public_urls = true
         max_script_size = '4 KiB'
+
+        [secrets]
+        jws-key = 'c2VjcmV0'
     "#,
         )
         .unwrap();
@@ -164,6 +196,9 @@ mod tests {
                 query: None,
                 fragment: None,
             },
+            api: ApiConfig {
+                require_tokens: true,
+            },
             db: DatabaseConfig {
                 name: "retrack",
                 host: "localhost",
@@ -173,11 +208,23 @@ mod tests {
                     "password",
                 ),
                 max_connections: 1000,
+                migrations: DatabaseMigrationsConfig {
+                    lock_timeout: 5s,
+                    max_attempts: 10,
+                },
+                retries: DatabaseRetriesConfig {
+                    max_attempts: 3,
+                    min_interval: 100ms,
+                    max_interval: 2s,
+                    circuit_breaker_threshold: 5,
+                    circuit_breaker_cooldown: 30s,
+                },
             },
             cache: CacheConfig {
                 http_cache_path: Some(
                     "./http-cache",
                 ),
+                http_cache_storage: None,
             },
             components: ComponentsConfig {
                 web_scraper_url: Url {
@@ -197,14 +244,21 @@ mod tests {
                     query: None,
                     fragment: None,
                 },
+                translator: None,
+                summarizer: None,
+                embeddings: None,
             },
             scheduler: SchedulerJobsConfig {
                 trackers_schedule: "0 * * * * * *",
                 trackers_run: "0 * * * * * *",
                 tasks_run: "0/30 * * * * * *",
+                trackers_digest: "0 * * * * *",
+                trackers_retention: "0 0 * * * *",
+                redis_locks: None,
             },
             trackers: TrackersConfig {
                 max_revisions: 11,
+                max_revision_age: None,
                 schedules: Some(
                     {
                         "@hourly",
@@ -215,6 +269,20 @@ mod tests {
                 max_script_size: Byte(
                     4096,
                 ),
+                name_uniqueness: Global,
+                alerts_correlation: None,
+                webhook_destinations: None,
+                file_roots: [],
+                file_action_roots: [],
+                command_action_programs: [],
+                default_tags: [],
+                default_actions: [],
+                policies: None,
+                action_timeouts: TrackerActionTimeouts {
+                    webhook: 30s,
+                    email: 30s,
+                },
+                runbook_urls: {},
             },
             smtp: None,
             js_runtime: JsRuntimeConfig {
@@ -222,6 +290,9 @@ mod tests {
                 max_script_execution_time: 20s,
                 channel_buffer_size: 200,
             },
+            secrets: {
+                "jws-key": "***",
+            },
         }
         "###);
     }
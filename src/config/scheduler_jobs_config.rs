@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+use url::Url;
 
 /// Configuration for the Retrack scheduler jobs.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -9,6 +12,15 @@ pub struct SchedulerJobsConfig {
     pub trackers_run: String,
     /// The schedule to use for the `TasksRun` job.
     pub tasks_run: String,
+    /// The schedule to use for the `TrackersDigest` job.
+    pub trackers_digest: String,
+    /// The schedule to use for the `TrackersRetention` job.
+    pub trackers_retention: String,
+    /// Optional configuration for the Redis-based locks that make sure that only a single Retrack
+    /// instance executes a scheduler job at a time (requires `scheduler-redis-locks` feature). If
+    /// not specified, Postgres advisory locks are used instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_locks: Option<SchedulerRedisLocksConfig>,
 }
 
 impl Default for SchedulerJobsConfig {
@@ -17,14 +29,34 @@ impl Default for SchedulerJobsConfig {
             trackers_schedule: "0/10 * * * * *".to_string(),
             trackers_run: "0/10 * * * * *".to_string(),
             tasks_run: "0/30 * * * * *".to_string(),
+            trackers_digest: "0 * * * * *".to_string(),
+            trackers_retention: "0 0 * * * *".to_string(),
+            redis_locks: None,
         }
     }
 }
 
+/// Configuration for the Redis-based scheduler locks.
+#[serde_as]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct SchedulerRedisLocksConfig {
+    /// Redis connection URL, e.g. `redis://localhost:6379/0`.
+    pub url: Url,
+    /// Optional prefix for the lock keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_prefix: Option<String>,
+    /// The maximum time the lock is held. If the job takes longer, the lock expires and the job
+    /// can be executed by another instance.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub lock_ttl: Duration,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::config::SchedulerJobsConfig;
+    use crate::config::{SchedulerJobsConfig, SchedulerRedisLocksConfig};
     use insta::assert_toml_snapshot;
+    use std::time::Duration;
+    use url::Url;
 
     #[test]
     fn serialization_and_default() {
@@ -32,6 +64,29 @@ mod tests {
         trackers_schedule = '0/10 * * * * *'
         trackers_run = '0/10 * * * * *'
         tasks_run = '0/30 * * * * *'
+        trackers_digest = '0 * * * * *'
+        trackers_retention = '0 0 * * * *'
+        "###);
+
+        let config = SchedulerJobsConfig {
+            redis_locks: Some(SchedulerRedisLocksConfig {
+                url: Url::parse("redis://localhost:6379/0").unwrap(),
+                key_prefix: Some("retrack:locks:".to_string()),
+                lock_ttl: Duration::from_secs(300),
+            }),
+            ..Default::default()
+        };
+        assert_toml_snapshot!(config, @r###"
+        trackers_schedule = '0/10 * * * * *'
+        trackers_run = '0/10 * * * * *'
+        tasks_run = '0/30 * * * * *'
+        trackers_digest = '0 * * * * *'
+        trackers_retention = '0 0 * * * *'
+
+        [redis_locks]
+        url = 'redis://localhost:6379/0'
+        key_prefix = 'retrack:locks:'
+        lock_ttl = 300000
         "###);
     }
 
@@ -42,9 +97,37 @@ mod tests {
         trackers_schedule = '0/10 * * * * *'
         trackers_run = '0/10 * * * * *'
         tasks_run = '0/30 * * * * *'
+        trackers_digest = '0 * * * * *'
+        trackers_retention = '0 0 * * * *'
     "#,
         )
         .unwrap();
         assert_eq!(config, SchedulerJobsConfig::default());
+
+        let config: SchedulerJobsConfig = toml::from_str(
+            r#"
+        trackers_schedule = '0/10 * * * * *'
+        trackers_run = '0/10 * * * * *'
+        tasks_run = '0/30 * * * * *'
+        trackers_digest = '0 * * * * *'
+        trackers_retention = '0 0 * * * *'
+
+        [redis_locks]
+        url = 'redis://localhost:6379/0'
+        lock_ttl = 60000
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            SchedulerJobsConfig {
+                redis_locks: Some(SchedulerRedisLocksConfig {
+                    url: Url::parse("redis://localhost:6379/0").unwrap(),
+                    key_prefix: None,
+                    lock_ttl: Duration::from_secs(60),
+                }),
+                ..Default::default()
+            }
+        );
     }
 }
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// Configuration for the secrets store, a set of named secrets (keys, tokens, etc.) that trackers
+/// can reference by name instead of embedding secret values into the tracker configuration.
+#[derive(Deserialize, Serialize, Default, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct SecretsConfig(BTreeMap<String, String>);
+
+impl SecretsConfig {
+    /// Returns the value of the secret with the specified name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(|value| value.as_str())
+    }
+
+    /// Checks whether the secret with the specified name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+}
+
+// Secret values should never end up in logs, only names are printed.
+impl fmt::Debug for SecretsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.keys().map(|name| (name, "***")))
+            .finish()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for SecretsConfig {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::SecretsConfig;
+    use insta::assert_toml_snapshot;
+
+    #[test]
+    fn serialization_and_default() {
+        assert_eq!(
+            SecretsConfig::default(),
+            SecretsConfig::from_iter(Vec::<(String, String)>::new())
+        );
+
+        let config = SecretsConfig::from_iter([("jws-key", "c2VjcmV0"), ("jwe-key", "a2V5")]);
+        assert_toml_snapshot!(config, @r###"
+        jwe-key = 'a2V5'
+        jws-key = 'c2VjcmV0'
+        "###);
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: SecretsConfig = toml::from_str(
+            r#"
+        jws-key = 'c2VjcmV0'
+        jwe-key = 'a2V5'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            SecretsConfig::from_iter([("jws-key", "c2VjcmV0"), ("jwe-key", "a2V5")])
+        );
+        assert_eq!(config.get("jws-key"), Some("c2VjcmV0"));
+        assert!(config.contains("jwe-key"));
+        assert!(!config.contains("unknown-key"));
+    }
+
+    #[test]
+    fn debug_hides_secret_values() {
+        let config = SecretsConfig::from_iter([("jws-key", "c2VjcmV0")]);
+        assert_eq!(format!("{config:?}"), r#"{"jws-key": "***"}"#);
+    }
+}
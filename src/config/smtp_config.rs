@@ -13,6 +13,44 @@ pub struct SmtpConfig {
     pub address: String,
     /// Optional configuration for catch-all email recipient (used for troubleshooting only).
     pub catch_all: Option<SmtpCatchAllConfig>,
+    /// Optional list of rules to route emails based on the recipient address (e.g., to make sure
+    /// that staging instances never send emails to real customers). For every recipient, only the
+    /// first matching rule is applied. Recipients that don't match any rule are left intact.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes: Vec<SmtpRoutingRule>,
+}
+
+impl SmtpConfig {
+    /// Applies routing rules to the specified email recipients and returns the final list of `To`
+    /// and `Bcc` recipients.
+    pub fn route_recipients<'a>(&'a self, recipients: &'a [String]) -> SmtpRecipients<'a> {
+        let mut routed = SmtpRecipients::default();
+        for recipient in recipients {
+            let rule = self
+                .routes
+                .iter()
+                .find(|rule| rule.recipient_matcher.is_match(recipient));
+            match rule.map(|rule| &rule.action) {
+                Some(SmtpRoutingAction::Redirect { recipient }) => {
+                    routed.to.push(recipient.as_str())
+                }
+                Some(SmtpRoutingAction::Bcc { recipient: bcc }) => {
+                    routed.to.push(recipient.as_str());
+                    routed.bcc.push(bcc.as_str());
+                }
+                Some(SmtpRoutingAction::Drop) => {}
+                None => routed.to.push(recipient.as_str()),
+            }
+        }
+
+        routed.to.sort_unstable();
+        routed.to.dedup();
+        routed.bcc.sort_unstable();
+        routed.bcc.dedup();
+        routed.bcc.retain(|bcc| !routed.to.contains(bcc));
+
+        routed
+    }
 }
 
 /// Configuration for the SMTP catch-all functionality.
@@ -27,9 +65,45 @@ pub struct SmtpCatchAllConfig {
     pub text_matcher: Regex,
 }
 
+/// Rule that defines how to route emails for the matching recipients.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SmtpRoutingRule {
+    /// Rule is applied only to recipients whose address matches regular expression specified in
+    /// `recipient_matcher`.
+    #[serde_as(as = "DisplayFromStr")]
+    pub recipient_matcher: Regex,
+    /// Action to perform for the matching recipients.
+    pub action: SmtpRoutingAction,
+}
+
+/// Action to perform for the recipients that match routing rule.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SmtpRoutingAction {
+    /// Replaces the original recipient with the specified one.
+    Redirect { recipient: String },
+    /// Keeps the original recipient, and adds the specified one as a `Bcc` recipient.
+    Bcc { recipient: String },
+    /// Removes the original recipient.
+    Drop,
+}
+
+/// Email recipients after routing rules are applied.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SmtpRecipients<'a> {
+    /// List of `To` recipients.
+    pub to: Vec<&'a str>,
+    /// List of `Bcc` recipients.
+    pub bcc: Vec<&'a str>,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::config::{smtp_config::SmtpCatchAllConfig, SmtpConfig};
+    use crate::config::{
+        smtp_config::{SmtpCatchAllConfig, SmtpRecipients, SmtpRoutingAction, SmtpRoutingRule},
+        SmtpConfig,
+    };
     use insta::{assert_debug_snapshot, assert_toml_snapshot};
     use regex::Regex;
 
@@ -40,6 +114,7 @@ mod tests {
             password: "password".to_string(),
             address: "smtp.retrack.dev".to_string(),
             catch_all: None,
+            routes: vec![],
         };
         assert_toml_snapshot!(config, @r###"
         username = 'test@retrack.dev'
@@ -55,6 +130,18 @@ mod tests {
                 recipient: "test@retrack.dev".to_string(),
                 text_matcher: Regex::new(r"test").unwrap(),
             }),
+            routes: vec![
+                SmtpRoutingRule {
+                    recipient_matcher: Regex::new(r"@retrack\.dev$").unwrap(),
+                    action: SmtpRoutingAction::Bcc {
+                        recipient: "audit@retrack.dev".to_string(),
+                    },
+                },
+                SmtpRoutingRule {
+                    recipient_matcher: Regex::new(r".*").unwrap(),
+                    action: SmtpRoutingAction::Drop,
+                },
+            ],
         };
         assert_toml_snapshot!(config, @r###"
         username = 'test@retrack.dev'
@@ -64,6 +151,19 @@ mod tests {
         [catch_all]
         recipient = 'test@retrack.dev'
         text_matcher = 'test'
+
+        [[routes]]
+        recipient_matcher = '@retrack\.dev$'
+
+        [routes.action]
+        type = 'bcc'
+        recipient = 'audit@retrack.dev'
+
+        [[routes]]
+        recipient_matcher = '.*'
+
+        [routes.action]
+        type = 'drop'
         "###);
     }
 
@@ -78,6 +178,14 @@ mod tests {
         [catch_all]
         recipient = 'test@retrack.dev'
         text_matcher = 'test'
+
+        [[routes]]
+        recipient_matcher = '@customer\.com$'
+        action = { type = 'redirect', recipient = 'qa@retrack.dev' }
+
+        [[routes]]
+        recipient_matcher = '.*'
+        action = { type = 'drop' }
     "#,
         )
         .unwrap();
@@ -94,7 +202,97 @@ mod tests {
                     ),
                 },
             ),
+            routes: [
+                SmtpRoutingRule {
+                    recipient_matcher: Regex(
+                        "@customer\\.com$",
+                    ),
+                    action: Redirect {
+                        recipient: "qa@retrack.dev",
+                    },
+                },
+                SmtpRoutingRule {
+                    recipient_matcher: Regex(
+                        ".*",
+                    ),
+                    action: Drop,
+                },
+            ],
         }
         "###);
     }
+
+    #[test]
+    fn routes_recipients() -> anyhow::Result<()> {
+        let recipients = vec![
+            "one@customer.com".to_string(),
+            "two@customer.com".to_string(),
+            "dev@retrack.dev".to_string(),
+            "qa@retrack.dev".to_string(),
+            "someone@unknown.com".to_string(),
+        ];
+
+        let config = SmtpConfig {
+            username: "test@retrack.dev".to_string(),
+            password: "password".to_string(),
+            address: "smtp.retrack.dev".to_string(),
+            catch_all: None,
+            routes: vec![],
+        };
+        assert_eq!(
+            config.route_recipients(&recipients),
+            SmtpRecipients {
+                to: vec![
+                    "dev@retrack.dev",
+                    "one@customer.com",
+                    "qa@retrack.dev",
+                    "someone@unknown.com",
+                    "two@customer.com"
+                ],
+                bcc: vec![]
+            }
+        );
+
+        let config = SmtpConfig {
+            routes: vec![
+                SmtpRoutingRule {
+                    recipient_matcher: Regex::new(r"@customer\.com$")?,
+                    action: SmtpRoutingAction::Redirect {
+                        recipient: "qa@retrack.dev".to_string(),
+                    },
+                },
+                SmtpRoutingRule {
+                    recipient_matcher: Regex::new(r"^dev@")?,
+                    action: SmtpRoutingAction::Bcc {
+                        recipient: "audit@retrack.dev".to_string(),
+                    },
+                },
+                SmtpRoutingRule {
+                    recipient_matcher: Regex::new(r"@retrack\.dev$")?,
+                    action: SmtpRoutingAction::Bcc {
+                        recipient: "qa@retrack.dev".to_string(),
+                    },
+                },
+                SmtpRoutingRule {
+                    recipient_matcher: Regex::new(r".*")?,
+                    action: SmtpRoutingAction::Drop,
+                },
+            ],
+            ..config
+        };
+        assert_eq!(
+            config.route_recipients(&recipients),
+            SmtpRecipients {
+                to: vec!["dev@retrack.dev", "qa@retrack.dev"],
+                bcc: vec!["audit@retrack.dev"]
+            }
+        );
+
+        assert_eq!(
+            config.route_recipients(&["someone@unknown.com".to_string()]),
+            SmtpRecipients::default()
+        );
+
+        Ok(())
+    }
 }
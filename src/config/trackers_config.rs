@@ -1,13 +1,26 @@
+use crate::network::NetworkDestination;
 use byte_unit::Byte;
+use regex::Regex;
+use retrack_types::trackers::TrackerAction;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DurationMilliSeconds};
-use std::{collections::HashSet, time::Duration};
+use serde_with::{serde_as, DisplayFromStr, DurationMilliSeconds};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::PathBuf,
+    time::Duration,
+};
+use url::Url;
 
 #[serde_as]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct TrackersConfig {
     /// The max number of tracker revisions per tracker.
     pub max_revisions: usize,
+    /// The default max age of tracker revisions for trackers that don't define their own. Older
+    /// revisions are removed regardless of the revisions count. If not set, revisions don't expire.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_revision_age: Option<Duration>,
     /// The list of allowed schedules for the trackers.
     pub schedules: Option<HashSet<String>>,
     /// The minimum interval between two consequent scheduled tracker checks.
@@ -17,12 +30,181 @@ pub struct TrackersConfig {
     pub restrict_to_public_urls: bool,
     /// The maximum size of any give tracker script (configurators, extractors etc.).
     pub max_script_size: Byte,
+    /// Defines the scope within which tracker names should be unique.
+    #[serde(default)]
+    pub name_uniqueness: TrackerNameUniqueness,
+    /// Defines how failures of the trackers sharing a tag are correlated into a single incident
+    /// notification. If not set, every failure is reported individually.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alerts_correlation: Option<TrackerAlertsCorrelation>,
+    /// Defines which destinations tracker webhook actions are allowed to target. If not set, any
+    /// destination is allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_destinations: Option<TrackerWebhookDestinations>,
+    /// The list of the local directories that tracker file targets can access, including their
+    /// subdirectories. If empty, file targets aren't allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_roots: Vec<PathBuf>,
+    /// The list of the local directories that tracker file actions can write to, including their
+    /// subdirectories. If empty, file actions aren't allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_action_roots: Vec<PathBuf>,
+    /// The list of the absolute paths to the local executables that tracker command actions can
+    /// run. If empty, command actions aren't allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command_action_programs: Vec<PathBuf>,
+    /// The list of tags that are added to every new tracker in addition to the tags specified by
+    /// the client, e.g. to label all trackers of the instance with `env:prod` or `team:web`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_tags: Vec<String>,
+    /// The list of actions that are added to every new tracker matching the tag selector in
+    /// addition to the actions specified by the client, e.g. to notify the team owning the
+    /// trackers tagged with `team:infra`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_actions: Vec<TrackerDefaultActions>,
+    /// Defines naming and tagging policies that all trackers must comply with, e.g. to keep the
+    /// trackers of a large organization consistent and attributable. If not set, no policies are
+    /// enforced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policies: Option<TrackerPolicies>,
+    /// Defines how long tracker actions can take before they are considered failed, unless the
+    /// action defines its own timeout.
+    #[serde(default)]
+    pub action_timeouts: TrackerActionTimeouts,
+    /// The map of the tracker tags to the URLs of the runbooks included into the failure
+    /// notifications of the trackers with these tags, unless the tracker defines its own runbook.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub runbook_urls: BTreeMap<String, Url>,
+}
+
+/// Defines the scope within which tracker names should be unique (names are always compared
+/// case-insensitively).
+#[derive(Deserialize, Serialize, Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackerNameUniqueness {
+    /// Tracker names don't need to be unique.
+    None,
+    /// Tracker names should be unique across all trackers.
+    #[default]
+    Global,
+    /// Tracker names should be unique only among trackers with the same set of tags.
+    Tags,
+}
+
+/// Defines how failures of the trackers sharing a tag are correlated into a single incident, e.g.
+/// when all trackers for a single vendor fail during the vendor outage.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TrackerAlertsCorrelation {
+    /// The time window within which failures are correlated. Failures are reported once the window
+    /// that starts with the oldest unreported failure elapses.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub window: Duration,
+    /// The minimum number of distinct trackers sharing a tag that should fail within the window to
+    /// be reported as a single incident.
+    #[serde(default = "TrackerAlertsCorrelation::default_min_trackers")]
+    pub min_trackers: usize,
+}
+
+impl TrackerAlertsCorrelation {
+    /// Default minimum number of trackers that form an incident.
+    pub const fn default_min_trackers() -> usize {
+        2
+    }
+}
+
+/// Defines allow and deny lists of the destinations (domains or networks in CIDR notation) that
+/// tracker webhook actions can target, e.g. to prevent trackers from sending data to internal or
+/// arbitrary external endpoints.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TrackerWebhookDestinations {
+    /// If not empty, webhook actions can only target destinations from this list.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<NetworkDestination>,
+    /// Webhook actions can never target destinations from this list, even if they are allowed.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<NetworkDestination>,
+}
+
+/// Defines naming and tagging policies enforced for all trackers when they are created or updated.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct TrackerPolicies {
+    /// If set, tracker names must match this regular expression.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_pattern: Option<Regex>,
+    /// The list of tag keys every tracker must have a tag for, e.g. `owner` requires tracker to
+    /// have a tag like `owner:team-web`. Keys are compared case-insensitively.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_tag_keys: Vec<String>,
+}
+
+impl PartialEq for TrackerPolicies {
+    fn eq(&self, other: &Self) -> bool {
+        self.name_pattern.as_ref().map(Regex::as_str)
+            == other.name_pattern.as_ref().map(Regex::as_str)
+            && self.required_tag_keys == other.required_tag_keys
+    }
+}
+
+impl Eq for TrackerPolicies {}
+
+/// Defines the actions added to the new trackers matching the tag selector.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrackerDefaultActions {
+    /// The list of tags the tracker must have all of to get the default actions. Tags are
+    /// compared case-insensitively. If empty, the actions are added to every new tracker.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The list of actions added to the matching trackers.
+    pub actions: Vec<TrackerAction>,
+}
+
+impl TrackerDefaultActions {
+    /// Checks if the tracker with the specified normalized (trimmed and lowercased) tags matches
+    /// the tag selector.
+    pub fn matches(&self, tags: &[String]) -> bool {
+        self.tags
+            .iter()
+            .all(|tag| tags.contains(&tag.trim().to_lowercase()))
+    }
+}
+
+/// Defines the default timeouts of the tracker actions delivered to external services, so that a
+/// single slow receiver cannot hold up the delivery of the remaining actions.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct TrackerActionTimeouts {
+    /// The maximum time to wait for the webhook receiver to respond.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub webhook: Duration,
+    /// The maximum time to wait for the SMTP server to accept the email.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub email: Duration,
+}
+
+impl Default for TrackerActionTimeouts {
+    fn default() -> Self {
+        Self {
+            // Default to 30 seconds.
+            webhook: Duration::from_secs(30),
+            // Default to 30 seconds.
+            email: Duration::from_secs(30),
+        }
+    }
 }
 
 impl Default for TrackersConfig {
     fn default() -> Self {
         Self {
             max_revisions: 30,
+            // Default to None to keep revisions regardless of their age.
+            max_revision_age: None,
             // Default to None to allow all schedules.
             schedules: None,
             // Default to 10 seconds.
@@ -30,16 +212,32 @@ impl Default for TrackersConfig {
             restrict_to_public_urls: true,
             // Default is 4KiB.
             max_script_size: Byte::from_u64(4096),
+            name_uniqueness: TrackerNameUniqueness::default(),
+            alerts_correlation: None,
+            webhook_destinations: None,
+            file_roots: vec![],
+            file_action_roots: vec![],
+            command_action_programs: vec![],
+            default_tags: vec![],
+            default_actions: vec![],
+            policies: None,
+            action_timeouts: TrackerActionTimeouts::default(),
+            runbook_urls: BTreeMap::new(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::TrackersConfig;
+    use crate::config::{
+        TrackerActionTimeouts, TrackerAlertsCorrelation, TrackerDefaultActions,
+        TrackerNameUniqueness, TrackerPolicies, TrackerWebhookDestinations, TrackersConfig,
+    };
     use byte_unit::Byte;
     use insta::assert_toml_snapshot;
-    use std::time::Duration;
+    use regex::Regex;
+    use retrack_types::trackers::{TrackerAction, WebhookAction};
+    use std::{path::PathBuf, time::Duration};
 
     #[test]
     fn serialization_and_default() {
@@ -49,21 +247,89 @@ mod tests {
         min_schedule_interval = 10000
         restrict_to_public_urls = true
         max_script_size = '4 KiB'
+        name_uniqueness = 'global'
+
+        [action_timeouts]
+        webhook = 30000
+        email = 30000
         "###);
 
         let config = TrackersConfig {
             max_revisions: 10,
+            max_revision_age: Some(Duration::from_secs(90 * 24 * 60 * 60)),
             schedules: Some(["@hourly".to_string()].into_iter().collect()),
             min_schedule_interval: Duration::from_secs(2),
             restrict_to_public_urls: false,
             max_script_size: Byte::from_u64(8192),
+            name_uniqueness: TrackerNameUniqueness::Tags,
+            alerts_correlation: Some(TrackerAlertsCorrelation {
+                window: Duration::from_secs(300),
+                min_trackers: 3,
+            }),
+            webhook_destinations: Some(TrackerWebhookDestinations {
+                allow: vec!["hooks.retrack.dev".parse().unwrap()],
+                deny: vec!["10.0.0.0/8".parse().unwrap(), "::1".parse().unwrap()],
+            }),
+            file_roots: vec![PathBuf::from("/var/reports")],
+            file_action_roots: vec![PathBuf::from("/var/retrack")],
+            command_action_programs: vec![PathBuf::from("/usr/local/bin/notify-release")],
+            default_tags: vec!["env:prod".to_string(), "team:web".to_string()],
+            default_actions: vec![],
+            policies: Some(TrackerPolicies {
+                name_pattern: Some(Regex::new(r"^[a-z0-9-]+$").unwrap()),
+                required_tag_keys: vec!["owner".to_string(), "env".to_string()],
+            }),
+            action_timeouts: TrackerActionTimeouts {
+                webhook: Duration::from_secs(10),
+                email: Duration::from_secs(60),
+            },
+            runbook_urls: [(
+                "env:prod".to_string(),
+                "https://retrack.dev/runbooks/prod".parse().unwrap(),
+            )]
+            .into_iter()
+            .collect(),
         };
         assert_toml_snapshot!(config, @r###"
         max_revisions = 10
+        max_revision_age = 7776000000
         schedules = ['@hourly']
         min_schedule_interval = 2000
         restrict_to_public_urls = false
         max_script_size = '8 KiB'
+        name_uniqueness = 'tags'
+        file_roots = ['/var/reports']
+        file_action_roots = ['/var/retrack']
+        command_action_programs = ['/usr/local/bin/notify-release']
+        default_tags = [
+            'env:prod',
+            'team:web',
+        ]
+
+        [alerts_correlation]
+        window = 300000
+        min_trackers = 3
+
+        [webhook_destinations]
+        allow = ['hooks.retrack.dev']
+        deny = [
+            '10.0.0.0/8',
+            '::1/128',
+        ]
+
+        [policies]
+        name_pattern = '^[a-z0-9-]+$'
+        required_tag_keys = [
+            'owner',
+            'env',
+        ]
+
+        [action_timeouts]
+        webhook = 10000
+        email = 60000
+
+        [runbook_urls]
+        'env:prod' = 'https://retrack.dev/runbooks/prod'
         "###);
     }
 
@@ -83,10 +349,35 @@ mod tests {
         let config: TrackersConfig = toml::from_str(
             r#"
         max_revisions = 10
+        max_revision_age = 7_776_000_000
         min_schedule_interval = 2_000
         schedules = ['@', '@hourly']
         restrict_to_public_urls = false
         max_script_size = '8 KiB'
+        name_uniqueness = 'none'
+        file_roots = ['/etc/retrack', '/var/reports']
+        file_action_roots = ['/var/retrack']
+        command_action_programs = ['/usr/local/bin/notify-release']
+        default_tags = ['env:prod']
+
+        [alerts_correlation]
+        window = 600_000
+
+        [webhook_destinations]
+        deny = ['10.0.0.0/8', 'Internal.Retrack.dev']
+
+        [policies]
+        required_tag_keys = ['owner']
+
+        [action_timeouts]
+        webhook = 5_000
+
+        [runbook_urls]
+        'env:prod' = 'https://retrack.dev/runbooks/prod'
+
+        [[default_actions]]
+        tags = ['team:infra']
+        actions = [{ type = 'webhook', url = 'https://hooks.retrack.dev/infra' }]
     "#,
         )
         .unwrap();
@@ -94,6 +385,7 @@ mod tests {
             config,
             TrackersConfig {
                 max_revisions: 10,
+                max_revision_age: Some(Duration::from_secs(90 * 24 * 60 * 60)),
                 schedules: Some(
                     ["@".to_string(), "@hourly".to_string()]
                         .into_iter()
@@ -102,7 +394,73 @@ mod tests {
                 min_schedule_interval: Duration::from_secs(2),
                 restrict_to_public_urls: false,
                 max_script_size: Byte::from_u64(8192),
+                name_uniqueness: TrackerNameUniqueness::None,
+                alerts_correlation: Some(TrackerAlertsCorrelation {
+                    window: Duration::from_secs(600),
+                    min_trackers: 2,
+                }),
+                webhook_destinations: Some(TrackerWebhookDestinations {
+                    allow: vec![],
+                    deny: vec![
+                        "10.0.0.0/8".parse().unwrap(),
+                        "internal.retrack.dev".parse().unwrap()
+                    ],
+                }),
+                file_roots: vec![PathBuf::from("/etc/retrack"), PathBuf::from("/var/reports")],
+                file_action_roots: vec![PathBuf::from("/var/retrack")],
+                command_action_programs: vec![PathBuf::from("/usr/local/bin/notify-release")],
+                default_tags: vec!["env:prod".to_string()],
+                default_actions: vec![TrackerDefaultActions {
+                    tags: vec!["team:infra".to_string()],
+                    actions: vec![TrackerAction::Webhook(WebhookAction {
+                        url: "https://hooks.retrack.dev/infra".parse().unwrap(),
+                        method: None,
+                        headers: None,
+                        expected_response: None,
+                        timeout: None,
+                        signing_secret: None,
+                        retry_strategy: None,
+                        diff_format: None,
+                    })],
+                }],
+                policies: Some(TrackerPolicies {
+                    name_pattern: None,
+                    required_tag_keys: vec!["owner".to_string()],
+                }),
+                action_timeouts: TrackerActionTimeouts {
+                    webhook: Duration::from_secs(5),
+                    email: Duration::from_secs(30),
+                },
+                runbook_urls: [(
+                    "env:prod".to_string(),
+                    "https://retrack.dev/runbooks/prod".parse().unwrap(),
+                )]
+                .into_iter()
+                .collect(),
             }
         );
     }
+
+    #[test]
+    fn properly_matches_default_actions_tags() {
+        let default_actions = TrackerDefaultActions {
+            tags: vec![],
+            actions: vec![TrackerAction::ServerLog],
+        };
+        assert!(default_actions.matches(&[]));
+        assert!(default_actions.matches(&["team:infra".to_string()]));
+
+        let default_actions = TrackerDefaultActions {
+            tags: vec!["Team:Infra".to_string(), "env:prod ".to_string()],
+            actions: vec![TrackerAction::ServerLog],
+        };
+        assert!(default_actions.matches(&["env:prod".to_string(), "team:infra".to_string()]));
+        assert!(default_actions.matches(&[
+            "app:web".to_string(),
+            "env:prod".to_string(),
+            "team:infra".to_string()
+        ]));
+        assert!(!default_actions.matches(&["team:infra".to_string()]));
+        assert!(!default_actions.matches(&[]));
+    }
 }
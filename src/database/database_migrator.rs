@@ -0,0 +1,347 @@
+use crate::config::DatabaseMigrationsConfig;
+use anyhow::{bail, Context};
+use sqlx::{
+    migrate::{Migrate, MigrateError, Migrator},
+    query, query_scalar, PgConnection, PgPool,
+};
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+/// PostgreSQL error code reported when a lock cannot be acquired within `lock_timeout`.
+const LOCK_NOT_AVAILABLE_ERROR_CODE: &str = "55P03";
+
+/// Applies pending database schema migrations one by one without blocking the running instances
+/// for long:
+///
+/// * every migration waits for the table locks only up to the configured lock timeout, and is
+///   retried later if the lock isn't acquired in time, so that the queries queued behind the
+///   migration aren't stalled;
+/// * migrations marked with `-- no-transaction` directive run outside of the transaction, which
+///   allows building indexes on large tables with `CREATE INDEX CONCURRENTLY` without blocking
+///   writes. Such migrations should consist of a single idempotent statement (e.g. with
+///   `IF NOT EXISTS`).
+pub struct DatabaseMigrator<'m> {
+    migrator: &'m Migrator,
+    config: DatabaseMigrationsConfig,
+}
+
+impl<'m> DatabaseMigrator<'m> {
+    /// Creates a new migrator for the specified set of migrations.
+    pub fn new(migrator: &'m Migrator, config: DatabaseMigrationsConfig) -> Self {
+        Self { migrator, config }
+    }
+
+    /// Applies all pending migrations. Only one instance can apply migrations at a time, other
+    /// instances wait until migrations are applied.
+    pub async fn run(&self, pool: &PgPool) -> anyhow::Result<()> {
+        let mut connection = pool.acquire().await?;
+        let connection = &mut *connection;
+
+        connection
+            .lock()
+            .await
+            .context("Failed to acquire database migrations lock.")?;
+        let result = self.run_locked(connection).await;
+
+        // Reset session settings, since the connection is returned to the pool.
+        let reset_result = query!("SELECT set_config('lock_timeout', '0', false)")
+            .fetch_one(&mut *connection)
+            .await;
+        let unlock_result = connection.unlock().await;
+
+        result?;
+        reset_result?;
+        unlock_result.context("Failed to release database migrations lock.")?;
+
+        Ok(())
+    }
+
+    /// Applies pending migrations, expects the migrations lock to be already acquired.
+    async fn run_locked(&self, connection: &mut PgConnection) -> anyhow::Result<()> {
+        connection.ensure_migrations_table().await?;
+        if let Some(version) = connection.dirty_version().await? {
+            bail!(MigrateError::Dirty(version));
+        }
+
+        let applied_migrations = connection
+            .list_applied_migrations()
+            .await?
+            .into_iter()
+            .map(|migration| (migration.version, migration))
+            .collect::<HashMap<_, _>>();
+        for version in applied_migrations.keys() {
+            if !self.migrator.version_exists(*version) {
+                bail!(MigrateError::VersionMissing(*version));
+            }
+        }
+
+        let lock_timeout = format!("{}ms", self.config.lock_timeout.as_millis());
+        query!("SELECT set_config('lock_timeout', $1, false)", lock_timeout)
+            .fetch_one(&mut *connection)
+            .await?;
+
+        for migration in self.migrator.iter() {
+            if migration.migration_type.is_down_migration() {
+                continue;
+            }
+
+            if let Some(applied_migration) = applied_migrations.get(&migration.version) {
+                if applied_migration.checksum != migration.checksum {
+                    bail!(MigrateError::VersionMismatch(migration.version));
+                }
+                continue;
+            }
+
+            let mut attempt = 1;
+            loop {
+                match connection.apply(migration).await {
+                    Ok(elapsed) => {
+                        info!(
+                            migration.version = migration.version,
+                            migration.no_tx = migration.no_tx,
+                            "Applied database migration ({}) in {}ms.",
+                            migration.description,
+                            elapsed.as_millis()
+                        );
+                        break;
+                    }
+                    Err(err) if is_lock_timeout(&err) && attempt < self.config.max_attempts => {
+                        warn!(
+                            migration.version = migration.version,
+                            "Database migration ({}) couldn't acquire lock in {}ms, retrying (attempt {attempt} of {}).",
+                            migration.description,
+                            self.config.lock_timeout.as_millis(),
+                            self.config.max_attempts
+                        );
+
+                        // Index that failed to build concurrently is left in invalid state and
+                        // should be removed, otherwise `IF NOT EXISTS` will skip it on retry.
+                        if migration.no_tx {
+                            drop_invalid_indexes(connection).await?;
+                        }
+
+                        tokio::time::sleep(self.config.lock_timeout).await;
+                        attempt += 1;
+                    }
+                    Err(err) => {
+                        return Err(err).with_context(|| {
+                            format!(
+                                "Failed to apply database migration ({}).",
+                                migration.description
+                            )
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks if the migration failed because it couldn't acquire a lock within the lock timeout.
+fn is_lock_timeout(err: &MigrateError) -> bool {
+    let (MigrateError::Execute(err) | MigrateError::ExecuteMigration(err, _)) = err else {
+        return false;
+    };
+
+    err.as_database_error()
+        .and_then(|err| err.code())
+        .is_some_and(|code| code == LOCK_NOT_AVAILABLE_ERROR_CODE)
+}
+
+/// Drops indexes in the current schema left invalid by the failed concurrent index builds.
+async fn drop_invalid_indexes(connection: &mut PgConnection) -> anyhow::Result<()> {
+    let invalid_indexes = query_scalar!(
+        r#"
+SELECT format('%I.%I', namespaces.nspname, classes.relname) as "name!"
+FROM pg_index as indexes
+INNER JOIN pg_class as classes ON classes.oid = indexes.indexrelid
+INNER JOIN pg_namespace as namespaces ON namespaces.oid = classes.relnamespace
+WHERE NOT indexes.indisvalid AND namespaces.nspname = current_schema()
+        "#
+    )
+    .fetch_all(&mut *connection)
+    .await?;
+
+    for index in invalid_indexes {
+        warn!("Dropping invalid index ({index}) left by the failed migration.");
+        sqlx::query(&format!("DROP INDEX CONCURRENTLY IF EXISTS {index}"))
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DatabaseMigrator;
+    use crate::config::DatabaseMigrationsConfig;
+    use sqlx::{
+        migrate::{Migration, MigrationType, Migrator},
+        query_scalar, PgPool,
+    };
+    use std::{borrow::Cow, time::Duration};
+
+    fn mock_migrator(migrations: Vec<(i64, &'static str, bool)>) -> Migrator {
+        Migrator {
+            migrations: Cow::Owned(
+                migrations
+                    .into_iter()
+                    .map(|(version, sql, no_tx)| {
+                        Migration::new(
+                            version,
+                            Cow::Owned(format!("migration_{version}")),
+                            MigrationType::Simple,
+                            Cow::Borrowed(sql),
+                            no_tx,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            ..Migrator::DEFAULT
+        }
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn applies_pending_migrations(pool: PgPool) -> anyhow::Result<()> {
+        let config = DatabaseMigrationsConfig::default();
+
+        let migrator = mock_migrator(vec![
+            (
+                1,
+                "CREATE TABLE items (id INT PRIMARY KEY, name TEXT)",
+                false,
+            ),
+            (
+                2,
+                "CREATE INDEX CONCURRENTLY IF NOT EXISTS items_name_idx ON items (name)",
+                true,
+            ),
+        ]);
+        DatabaseMigrator::new(&migrator, config).run(&pool).await?;
+        assert_eq!(
+            query_scalar!(r#"SELECT MAX(version) FROM _sqlx_migrations WHERE success = TRUE"#)
+                .fetch_one(&pool)
+                .await?,
+            Some(2)
+        );
+
+        // Applied migrations are skipped, and new ones are applied.
+        let migrator = mock_migrator(vec![
+            (
+                1,
+                "CREATE TABLE items (id INT PRIMARY KEY, name TEXT)",
+                false,
+            ),
+            (
+                2,
+                "CREATE INDEX CONCURRENTLY IF NOT EXISTS items_name_idx ON items (name)",
+                true,
+            ),
+            (3, "ALTER TABLE items ADD COLUMN price INT", false),
+        ]);
+        DatabaseMigrator::new(&migrator, config).run(&pool).await?;
+        sqlx::query("SELECT id, name, price FROM items")
+            .fetch_all(&pool)
+            .await?;
+        assert_eq!(
+            query_scalar!(r#"SELECT COUNT(*) as "count!" FROM _sqlx_migrations"#)
+                .fetch_one(&pool)
+                .await?,
+            3
+        );
+
+        // Modified migration is rejected.
+        let migrator = mock_migrator(vec![
+            (
+                1,
+                "CREATE TABLE items (id BIGINT PRIMARY KEY, name TEXT)",
+                false,
+            ),
+            (
+                2,
+                "CREATE INDEX CONCURRENTLY IF NOT EXISTS items_name_idx ON items (name)",
+                true,
+            ),
+            (3, "ALTER TABLE items ADD COLUMN price INT", false),
+        ]);
+        let result = DatabaseMigrator::new(&migrator, config).run(&pool).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "migration 1 was previously applied but has been modified"
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn retries_migrations_that_cannot_acquire_lock(pool: PgPool) -> anyhow::Result<()> {
+        let config = DatabaseMigrationsConfig {
+            lock_timeout: Duration::from_millis(100),
+            max_attempts: 2,
+        };
+
+        let migrator = mock_migrator(vec![(
+            1,
+            "CREATE TABLE items (id INT PRIMARY KEY, name TEXT)",
+            false,
+        )]);
+        DatabaseMigrator::new(&migrator, config).run(&pool).await?;
+
+        // Hold the lock on the table for longer than all migration attempts take.
+        let mut transaction = pool.begin().await?;
+        sqlx::query("LOCK TABLE items IN ACCESS EXCLUSIVE MODE")
+            .execute(&mut *transaction)
+            .await?;
+
+        let migrator = mock_migrator(vec![
+            (
+                1,
+                "CREATE TABLE items (id INT PRIMARY KEY, name TEXT)",
+                false,
+            ),
+            (2, "ALTER TABLE items ADD COLUMN price INT", false),
+        ]);
+        let result = DatabaseMigrator::new(&migrator, config).run(&pool).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Failed to apply database migration (migration_2)."
+        );
+
+        // Release the lock while the migration is waiting for it.
+        let migrator_pool = pool.clone();
+        let migration = tokio::spawn(async move {
+            let migrator = mock_migrator(vec![
+                (
+                    1,
+                    "CREATE TABLE items (id INT PRIMARY KEY, name TEXT)",
+                    false,
+                ),
+                (2, "ALTER TABLE items ADD COLUMN price INT", false),
+            ]);
+            DatabaseMigrator::new(
+                &migrator,
+                DatabaseMigrationsConfig {
+                    max_attempts: 10,
+                    ..config
+                },
+            )
+            .run(&migrator_pool)
+            .await
+        });
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        transaction.rollback().await?;
+        migration.await??;
+
+        assert_eq!(
+            query_scalar!(r#"SELECT MAX(version) FROM _sqlx_migrations WHERE success = TRUE"#)
+                .fetch_one(&pool)
+                .await?,
+            Some(2)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use crate::config::DatabaseRetriesConfig;
+use std::{
+    cmp,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::{error, warn};
+
+/// PostgreSQL error code classes and codes that indicate transient failures: connection
+/// exceptions (`08`), serialization failures and deadlocks (`40001`, `40P01`), insufficient
+/// resources (`53`), operator intervention such as shutdown or failover (`57P01`-`57P03`).
+const TRANSIENT_ERROR_CODE_CLASSES: [&str; 2] = ["08", "53"];
+const TRANSIENT_ERROR_CODES: [&str; 5] = ["40001", "40P01", "57P01", "57P02", "57P03"];
+
+/// Error returned without querying the database while the circuit breaker is open.
+#[derive(thiserror::Error, Debug)]
+#[error("Database is temporarily unavailable, query is skipped.")]
+pub struct DatabaseUnavailableError;
+
+/// State of the circuit breaker shared between all clones of the retrier.
+#[derive(Debug, Default)]
+struct CircuitBreakerState {
+    /// Number of queries in a row that failed with transient errors, despite retries.
+    failures: u32,
+    /// Instant until which the circuit breaker is open, if it's open.
+    open_until: Option<Instant>,
+}
+
+/// Retries database queries that failed with transient errors with exponential backoff, and
+/// stops querying database for a while (opens the circuit breaker) if queries keep failing, so
+/// that a brief database failover doesn't translate into a flood of errors.
+#[derive(Clone, Debug)]
+pub struct DatabaseRetrier {
+    config: DatabaseRetriesConfig,
+    state: Arc<Mutex<CircuitBreakerState>>,
+}
+
+impl DatabaseRetrier {
+    /// Creates a new retrier with the specified settings.
+    pub fn new(config: DatabaseRetriesConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(CircuitBreakerState::default())),
+        }
+    }
+
+    /// Runs the specified query, and retries it if it fails with a transient error.
+    pub async fn run<T, F, Fut>(&self, mut query: F) -> anyhow::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        if self.is_circuit_open() {
+            return Err(DatabaseUnavailableError.into());
+        }
+
+        let mut attempt = 1;
+        loop {
+            let err = match query().await {
+                Ok(result) => {
+                    self.record_success();
+                    return Ok(result);
+                }
+                Err(err) if !Self::is_transient_error(&err) => return Err(err),
+                Err(err) => err,
+            };
+
+            if attempt >= self.config.max_attempts {
+                self.record_failure();
+                return Err(err);
+            }
+
+            let interval = self.retry_interval(attempt);
+            warn!(
+                "Database query failed with a transient error (attempt {attempt} out of {}), retrying in {}: {err:?}",
+                self.config.max_attempts,
+                humantime::format_duration(interval)
+            );
+            tokio::time::sleep(interval).await;
+            attempt += 1;
+        }
+    }
+
+    /// Checks if the error is caused by a transient database failure, or by the open circuit
+    /// breaker, and the operation can be retried later.
+    pub fn is_transient_error(err: &anyhow::Error) -> bool {
+        err.chain().any(|err| {
+            if err.is::<DatabaseUnavailableError>() {
+                return true;
+            }
+
+            match err.downcast_ref::<sqlx::Error>() {
+                Some(sqlx::Error::Io(_))
+                | Some(sqlx::Error::Tls(_))
+                | Some(sqlx::Error::PoolTimedOut)
+                | Some(sqlx::Error::PoolClosed)
+                | Some(sqlx::Error::WorkerCrashed) => true,
+                Some(sqlx::Error::Database(err)) => err.code().is_some_and(|code| {
+                    TRANSIENT_ERROR_CODES.contains(&code.as_ref())
+                        || TRANSIENT_ERROR_CODE_CLASSES
+                            .iter()
+                            .any(|class| code.starts_with(class))
+                }),
+                _ => false,
+            }
+        })
+    }
+
+    /// Returns the interval before the retry that follows the specified attempt.
+    fn retry_interval(&self, attempt: u32) -> Duration {
+        let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1));
+        cmp::min(
+            self.config.min_interval.saturating_mul(multiplier),
+            self.config.max_interval,
+        )
+    }
+
+    /// Checks if the circuit breaker is open. Once cooldown elapses, queries are let through
+    /// again, and a single failure is enough to open the circuit breaker again.
+    fn is_circuit_open(&self) -> bool {
+        let Ok(state) = self.state.lock() else {
+            return false;
+        };
+
+        state
+            .open_until
+            .is_some_and(|open_until| open_until > Instant::now())
+    }
+
+    fn record_success(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = CircuitBreakerState::default();
+        }
+    }
+
+    fn record_failure(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.failures = state.failures.saturating_add(1);
+            if state.failures >= self.config.circuit_breaker_threshold {
+                error!(
+                    "Database queries keep failing with transient errors ({} in a row), skipping queries for {}.",
+                    state.failures,
+                    humantime::format_duration(self.config.circuit_breaker_cooldown)
+                );
+                state.open_until = Some(Instant::now() + self.config.circuit_breaker_cooldown);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DatabaseRetrier, DatabaseUnavailableError};
+    use crate::config::DatabaseRetriesConfig;
+    use anyhow::{anyhow, Context};
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    fn mock_config() -> DatabaseRetriesConfig {
+        DatabaseRetriesConfig {
+            max_attempts: 3,
+            min_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(2),
+            circuit_breaker_threshold: 2,
+            circuit_breaker_cooldown: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn properly_detects_transient_errors() {
+        assert!(DatabaseRetrier::is_transient_error(&anyhow!(
+            sqlx::Error::PoolTimedOut
+        )));
+        assert!(DatabaseRetrier::is_transient_error(
+            &anyhow!(sqlx::Error::Io(std::io::ErrorKind::ConnectionReset.into()))
+                .context("Failed to run query.")
+        ));
+        assert!(DatabaseRetrier::is_transient_error(&anyhow!(
+            DatabaseUnavailableError
+        )));
+
+        assert!(!DatabaseRetrier::is_transient_error(&anyhow!(
+            sqlx::Error::RowNotFound
+        )));
+        assert!(!DatabaseRetrier::is_transient_error(&anyhow!("Some error")));
+    }
+
+    #[test]
+    fn properly_calculates_retry_interval() {
+        let retrier = DatabaseRetrier::new(DatabaseRetriesConfig {
+            min_interval: Duration::from_millis(100),
+            max_interval: Duration::from_millis(350),
+            ..Default::default()
+        });
+        assert_eq!(retrier.retry_interval(1), Duration::from_millis(100));
+        assert_eq!(retrier.retry_interval(2), Duration::from_millis(200));
+        assert_eq!(retrier.retry_interval(3), Duration::from_millis(350));
+        assert_eq!(retrier.retry_interval(100), Duration::from_millis(350));
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors() -> anyhow::Result<()> {
+        let retrier = DatabaseRetrier::new(mock_config());
+
+        // Succeeds after a few transient errors.
+        let attempts = AtomicU32::new(0);
+        let result = retrier
+            .run(|| async {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(anyhow!(sqlx::Error::PoolTimedOut))
+                } else {
+                    Ok(42)
+                }
+            })
+            .await?;
+        assert_eq!(result, 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // Non-transient errors aren't retried.
+        let attempts = AtomicU32::new(0);
+        let result = retrier
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(anyhow!(sqlx::Error::RowNotFound)).context("Failed to run query.")
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn opens_circuit_breaker() -> anyhow::Result<()> {
+        let retrier = DatabaseRetrier::new(mock_config());
+
+        let attempts = AtomicU32::new(0);
+        let failing_query = || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(anyhow!(sqlx::Error::PoolTimedOut))
+        };
+
+        // Circuit breaker opens after two failed queries in a row.
+        for _ in 0..2 {
+            assert!(retrier.run(failing_query).await.is_err());
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 6);
+
+        let err = retrier.run(failing_query).await.unwrap_err();
+        assert!(err.is::<DatabaseUnavailableError>());
+        assert_eq!(attempts.load(Ordering::SeqCst), 6);
+
+        // Once cooldown elapses, queries are let through again.
+        retrier.state.lock().unwrap().open_until = None;
+        assert_eq!(retrier.run(|| async { Ok(1) }).await?, 1);
+        assert_eq!(retrier.state.lock().unwrap().failures, 0);
+
+        Ok(())
+    }
+}
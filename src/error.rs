@@ -33,6 +33,28 @@ impl Error {
             kind: ErrorKind::ClientError,
         }
     }
+
+    /// Creates an Unauthorized error instance with the given message.
+    pub fn unauthorized<M>(message: M) -> Self
+    where
+        M: Display + Debug + Send + Sync + 'static,
+    {
+        Self {
+            root_cause: anyhow!(message),
+            kind: ErrorKind::Unauthorized,
+        }
+    }
+
+    /// Creates an Access Forbidden error instance with the given message.
+    pub fn access_forbidden<M>(message: M) -> Self
+    where
+        M: Display + Debug + Send + Sync + 'static,
+    {
+        Self {
+            root_cause: anyhow!(message),
+            kind: ErrorKind::AccessForbidden,
+        }
+    }
 }
 
 impl Display for Error {
@@ -51,6 +73,8 @@ impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self.kind {
             ErrorKind::ClientError => StatusCode::BAD_REQUEST,
+            ErrorKind::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorKind::AccessForbidden => StatusCode::FORBIDDEN,
             ErrorKind::Unknown => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -58,7 +82,9 @@ impl ResponseError for Error {
     fn error_response(&self) -> HttpResponse {
         HttpResponseBuilder::new(self.status_code()).json(json!({
             "message": match self.kind {
-                ErrorKind::ClientError => self.root_cause.to_string(),
+                ErrorKind::ClientError | ErrorKind::Unauthorized | ErrorKind::AccessForbidden => {
+                    self.root_cause.to_string()
+                }
                 ErrorKind::Unknown => "Internal Server Error".to_string(),
             }
         }))
@@ -135,6 +161,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn can_create_access_errors() -> anyhow::Result<()> {
+        let error = Error::unauthorized("API token is not valid.");
+        assert_eq!(error.kind, ErrorKind::Unauthorized);
+        assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+        let body = error.error_response().into_body().try_into_bytes().unwrap();
+        assert_eq!(body.as_ref(), b"{\"message\":\"API token is not valid.\"}");
+
+        let error = Error::access_forbidden("Access is forbidden.");
+        assert_eq!(error.kind, ErrorKind::AccessForbidden);
+        assert_eq!(error.status_code(), StatusCode::FORBIDDEN);
+        let body = error.error_response().into_body().try_into_bytes().unwrap();
+        assert_eq!(body.as_ref(), b"{\"message\":\"Access is forbidden.\"}");
+
+        Ok(())
+    }
+
     #[test]
     fn can_create_unknown_errors() -> anyhow::Result<()> {
         let error = Error::from(anyhow!("Something sensitive"));
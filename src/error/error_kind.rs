@@ -3,6 +3,10 @@
 pub enum ErrorKind {
     /// Error caused by the error on the client side.
     ClientError,
+    /// Error caused by the missing or invalid client credentials.
+    Unauthorized,
+    /// Error caused by the client credentials that don't grant access to the requested resource.
+    AccessForbidden,
     /// Unknown error.
     Unknown,
 }
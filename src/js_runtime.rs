@@ -71,6 +71,9 @@ impl JsRuntime {
                         Script::ApiTargetExtractor(def) => {
                             JsRuntime::handle_script(&task.config, def).await;
                         }
+                        Script::CompositeTargetMerger(def) => {
+                            JsRuntime::handle_script(&task.config, def).await;
+                        }
                         Script::Custom(def) => {
                             JsRuntime::handle_script(&task.config, def).await;
                         }
@@ -262,12 +265,14 @@ pub mod tests {
     use http::{header::CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, Method};
     use retrack_types::trackers::{
         ConfiguratorScriptArgs, ConfiguratorScriptRequest, ConfiguratorScriptResult,
-        ExtractorScriptArgs, ExtractorScriptResult, TrackerDataValue,
+        ExtractorScriptArgs, ExtractorScriptResult, MergerScriptArgs, MergerScriptInput,
+        MergerScriptResult, TargetResponseTransform, TrackerDataValue,
     };
     use serde::{Deserialize, Serialize};
     use serde_bytes::ByteBuf;
     use serde_json::json;
     use std::collections::HashMap;
+    use uuid::uuid;
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn can_execute_scripts() -> anyhow::Result<()> {
@@ -325,6 +330,12 @@ pub mod tests {
                         ),
                         body: Some(serde_json::to_vec(&json!({ "key": "body" }))?),
                         media_type: Some("text/plain; charset=UTF-8".parse()?),
+                        response_transform: None,
+                        body_format: None,
+                        auth_profile: None,
+                        pagination: None,
+                        cookies: None,
+                        response_path: None,
                     }],
                 },
                 config,
@@ -346,6 +357,12 @@ pub mod tests {
                     "requests": [{ "url": "https://retrack.dev/", "method": "PUT", "headers": { "content-type": "application/json" }, "mediaType": "text/plain; charset=UTF-8", "body": { "key": "body" } }]
                 }))?),
                 media_type: Some("application/json".parse()?),
+                response_transform: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
             }])
         );
 
@@ -439,18 +456,60 @@ pub mod tests {
                     method: None,
                     headers: None,
                     media_type: None,
+                    response_transform: None,
                     body: Some(serde_json::to_vec(&json!({ "key": "value" }))?),
+                    body_format: None,
+                    auth_profile: None,
+                    pagination: None,
+                    cookies: None,
+                    response_path: None,
                 },
                 ConfiguratorScriptRequest {
                     url: "https://retrack.dev/two".parse()?,
                     method: None,
                     headers: None,
                     media_type: None,
+                    response_transform: None,
                     body: Some(serde_json::to_vec(&json!({ "key": "value_2" }))?),
+                    body_format: None,
+                    auth_profile: None,
+                    pagination: None,
+                    cookies: None,
+                    response_path: None,
                 }
             ]
         );
 
+        // Supports configurator scripts that set response transforms.
+        let ConfiguratorScriptResult::Requests(requests) = js_runtime
+            .execute_script::<ConfiguratorScriptArgs, ConfiguratorScriptResult>(
+                r#"(() => {{ return { requests: [{ url: "https://retrack.dev/jws", responseTransform: { type: "jws", secret: "jws-key" } }] }; }})();"#,
+                ConfiguratorScriptArgs::default(),
+                config,
+            )
+            .await?
+            .unwrap() else {
+            panic!("Expected ConfiguratorScriptResult::Request");
+        };
+        assert_eq!(
+            requests,
+            vec![ConfiguratorScriptRequest {
+                url: "https://retrack.dev/jws".parse()?,
+                method: None,
+                headers: None,
+                media_type: None,
+                response_transform: Some(TargetResponseTransform::Jws {
+                    secret: "jws-key".to_string()
+                }),
+                body: None,
+                body_format: None,
+                auth_profile: None,
+                pagination: None,
+                cookies: None,
+                response_path: None,
+            }]
+        );
+
         // Supports configurator (overrides response) scripts.
         let ConfiguratorScriptResult::Response { body, ..} = js_runtime
             .execute_script::<ConfiguratorScriptArgs, ConfiguratorScriptResult>(
@@ -470,6 +529,42 @@ pub mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn can_execute_composite_target_scripts() -> anyhow::Result<()> {
+        let js_runtime = JsRuntime::init_platform(&JsRuntimeConfig::default())?;
+        let config = ScriptConfig {
+            max_heap_size: 10 * 1024 * 1024,
+            max_execution_time: std::time::Duration::from_secs(5),
+        };
+
+        // Supports merger scripts.
+        let MergerScriptResult { content } = js_runtime
+            .execute_script::<MergerScriptArgs, MergerScriptResult>(
+                r#"(() => {{ return { content: { minPrice: Math.min(...context.inputs.map((input) => input.content.original.price)) } }; }})();"#,
+                MergerScriptArgs {
+                    inputs: vec![
+                        MergerScriptInput {
+                            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                            tracker_name: "store-one".to_string(),
+                            content: TrackerDataValue::new(json!({ "price": 10 })),
+                        },
+                        MergerScriptInput {
+                            tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                            tracker_name: "store-two".to_string(),
+                            content: TrackerDataValue::new(json!({ "price": 8 })),
+                        },
+                    ],
+                    ..Default::default()
+                },
+                config,
+            )
+            .await?
+            .unwrap();
+        assert_eq!(content, json!({ "minPrice": 8 }));
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn can_limit_execution_time() -> anyhow::Result<()> {
         let js_runtime = JsRuntime::init_platform(&JsRuntimeConfig::default())?;
@@ -1,5 +1,6 @@
 use retrack_types::trackers::{
     ConfiguratorScriptArgs, ConfiguratorScriptResult, ExtractorScriptArgs, ExtractorScriptResult,
+    MergerScriptArgs, MergerScriptResult,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use serde_bytes::ByteBuf;
@@ -12,6 +13,8 @@ pub enum Script {
     ApiTargetConfigurator(ScriptDefinition<ConfiguratorScriptArgs, ConfiguratorScriptResult>),
     /// A script to preprocess response for API tracker target.
     ApiTargetExtractor(ScriptDefinition<ExtractorScriptArgs, ExtractorScriptResult>),
+    /// A script to merge data of the input trackers for composite tracker target.
+    CompositeTargetMerger(ScriptDefinition<MergerScriptArgs, MergerScriptResult>),
     /// A custom script for ad-hoc purposes.
     Custom(ScriptDefinition<ByteBuf, ByteBuf>),
 }
@@ -70,6 +73,24 @@ impl ScriptBuilder<ExtractorScriptArgs, ExtractorScriptResult> for ExtractorScri
     }
 }
 
+/// Implementation for composite target "merger" script.
+impl ScriptBuilder<MergerScriptArgs, MergerScriptResult> for MergerScriptArgs {
+    fn build(
+        self,
+        src: impl Into<String>,
+        result: ScriptResultSender<MergerScriptResult>,
+    ) -> (Script, PhantomData<MergerScriptArgs>) {
+        (
+            Script::CompositeTargetMerger(ScriptDefinition {
+                src: src.into(),
+                args: Some(self),
+                result,
+            }),
+            PhantomData,
+        )
+    }
+}
+
 /// Implementation for API target "extractor" script. Args are represented as `Uint8Array` in JS
 /// and should be decoded with `Deno.core.decode`. Result is converted to `Uint8Array` as well with
 /// `Deno.core.encode`.
@@ -1,20 +1,27 @@
 #![deny(warnings)]
 
 mod api;
+mod api_tokens;
+mod auth_profiles;
 mod config;
 mod database;
 mod error;
 mod js_runtime;
 mod network;
+mod operations;
 mod scheduler;
+mod search;
 mod server;
 mod tasks;
 mod templates;
 mod trackers;
+mod views;
 
 use crate::config::RawConfig;
 use anyhow::anyhow;
-use clap::{crate_authors, crate_description, crate_version, value_parser, Arg, Command};
+use clap::{
+    crate_authors, crate_description, crate_version, value_parser, Arg, ArgAction, Command,
+};
 use std::env;
 use tracing::info;
 
@@ -53,6 +60,15 @@ async fn main() -> Result<(), anyhow::Error> {
                 .value_parser(value_parser!(u16))
                 .help("Defines a TCP port to listen on."),
         )
+        .arg(
+            Arg::new("MIGRATE_ONLY")
+                .long("migrate-only")
+                .action(ArgAction::SetTrue)
+                .help("Applies pending database migrations and exits."),
+        )
+        .subcommand(Command::new("preflight").about(
+            "Verifies that all components Retrack relies on are properly configured and reachable, and exits.",
+        ))
         .get_matches();
 
     let mut raw_config = RawConfig::read_from_file(
@@ -68,6 +84,14 @@ async fn main() -> Result<(), anyhow::Error> {
 
     info!(config = ?raw_config, "Retrack raw configuration.");
 
+    if matches.get_flag("MIGRATE_ONLY") {
+        return server::migrate(raw_config).await;
+    }
+
+    if matches.subcommand_matches("preflight").is_some() {
+        return server::preflight(raw_config).await;
+    }
+
     server::run(raw_config).await
 }
 
@@ -88,7 +112,9 @@ mod tests {
 
     pub use crate::{config::tests::*, network::tests::*, scheduler::tests::*, trackers::tests::*};
     use crate::{
-        config::{CacheConfig, DatabaseConfig, JsRuntimeConfig, TrackersConfig},
+        config::{
+            ApiConfig, CacheConfig, DatabaseConfig, JsRuntimeConfig, SecretsConfig, TrackersConfig,
+        },
         js_runtime::JsRuntime,
         templates::create_templates,
     };
@@ -105,27 +131,34 @@ mod tests {
     pub fn mock_config() -> anyhow::Result<Config> {
         Ok(Config {
             public_url: Url::parse("http://localhost:1234")?,
+            api: ApiConfig::default(),
             db: DatabaseConfig::default(),
             cache: CacheConfig {
                 http_cache_path: Some("./target/http-cache".into()),
+                http_cache_storage: None,
             },
             smtp: Some(SmtpConfig {
                 username: "dev@retrack.dev".to_string(),
                 password: "password".to_string(),
                 address: "localhost".to_string(),
                 catch_all: None,
+                routes: vec![],
             }),
             components: ComponentsConfig::default(),
             scheduler: SchedulerJobsConfig {
                 trackers_schedule: "0 * 0 * * *".to_string(),
                 trackers_run: "0 * 1 * * *".to_string(),
                 tasks_run: "0 * 2 * * *".to_string(),
+                trackers_digest: "0 * 3 * * *".to_string(),
+                trackers_retention: "0 * 4 * * *".to_string(),
+                redis_locks: None,
             },
             trackers: TrackersConfig {
                 restrict_to_public_urls: false,
                 ..Default::default()
             },
             js_runtime: JsRuntimeConfig::default(),
+            secrets: SecretsConfig::default(),
         })
     }
 
@@ -1,13 +1,18 @@
 mod dns_resolver;
 mod email_transport;
 mod ip_addr_ext;
+mod network_destination;
+mod public_hosts_cache;
 
 pub use self::{
     dns_resolver::{DnsResolver, TokioDnsResolver},
     email_transport::{EmailTransport, EmailTransportError},
     ip_addr_ext::IpAddrExt,
+    network_destination::NetworkDestination,
 };
-use std::net::IpAddr;
+use futures::future;
+use public_hosts_cache::PublicHostsCache;
+use std::{collections::HashMap, net::IpAddr};
 use tracing::error;
 use url::{Host, Url};
 
@@ -16,6 +21,8 @@ use url::{Host, Url};
 pub struct Network<DR: DnsResolver, ET: EmailTransport> {
     pub resolver: DR,
     pub email_transport: ET,
+    /// Cache of the domain publicness checks shared between all clones of the `Network`.
+    public_hosts: PublicHostsCache,
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
@@ -24,6 +31,7 @@ impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
         Self {
             resolver,
             email_transport,
+            public_hosts: PublicHostsCache::default(),
         }
     }
 
@@ -35,25 +43,124 @@ impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
 
         // Checks if the specific hostname is a domain and public (not pointing to the local network).
         match url.host() {
-            Some(Host::Domain(domain)) => match self.resolver.lookup_ip(domain).await {
-                Ok(lookup) => lookup.iter().all(|ip| IpAddrExt::is_global(&ip)),
-                Err(err) => {
-                    error!("Cannot resolve domain ({domain}) to IP: {err}");
-                    false
-                }
-            },
+            Some(Host::Domain(domain)) => self.is_public_domain(domain).await,
             Some(Host::Ipv4(ip)) => IpAddrExt::is_global(&IpAddr::V4(ip)),
             Some(Host::Ipv6(ip)) => IpAddrExt::is_global(&IpAddr::V6(ip)),
             None => false,
         }
     }
+
+    /// Checks if all provided URLs are publicly accessible web URLs, and returns the first one
+    /// that isn't. URLs are checked concurrently, and every distinct host is checked only once.
+    pub async fn find_non_public_web_url<'u>(
+        &self,
+        urls: impl IntoIterator<Item = &'u Url>,
+    ) -> Option<&'u Url> {
+        let urls = urls.into_iter().collect::<Vec<_>>();
+
+        let mut unique_urls = HashMap::new();
+        for url in &urls {
+            unique_urls
+                .entry((url.scheme(), url.host_str()))
+                .or_insert(*url);
+        }
+
+        let non_public_hosts = future::join_all(
+            unique_urls
+                .into_iter()
+                .map(|(host, url)| async move { (host, self.is_public_web_url(url).await) }),
+        )
+        .await
+        .into_iter()
+        .filter_map(|(host, is_public)| (!is_public).then_some(host))
+        .collect::<Vec<_>>();
+
+        urls.into_iter()
+            .find(|url| non_public_hosts.contains(&(url.scheme(), url.host_str())))
+    }
+
+    /// Checks if the domain resolves only to public IP addresses. Results of the successful
+    /// lookups are cached for as long as the DNS records of the domain are valid.
+    async fn is_public_domain(&self, domain: &str) -> bool {
+        if let Some(is_public) = self.public_hosts.get(domain) {
+            return is_public;
+        }
+
+        match self.resolver.lookup_ip(domain).await {
+            Ok(lookup) => {
+                let is_public = lookup.iter().all(|ip| IpAddrExt::is_global(&ip));
+                self.public_hosts
+                    .insert(domain, is_public, lookup.valid_until());
+                is_public
+            }
+            Err(err) => {
+                error!("Cannot resolve domain ({domain}) to IP: {err}");
+                false
+            }
+        }
+    }
+
+    /// Checks if provided URL points to a destination that isn't denied and, if the allow list
+    /// isn't empty, is explicitly allowed. Domains match destinations either by name or, if all IP
+    /// addresses they resolve to match, by network.
+    pub async fn is_allowed_destination(
+        &self,
+        url: &Url,
+        allow: &[NetworkDestination],
+        deny: &[NetworkDestination],
+    ) -> bool {
+        let (domain, ips) = match url.host() {
+            Some(Host::Domain(domain)) => {
+                // Resolve domain only if there are network destinations to match against.
+                let has_networks = allow
+                    .iter()
+                    .chain(deny.iter())
+                    .any(|destination| matches!(destination, NetworkDestination::Network { .. }));
+                let ips = if has_networks {
+                    match self.resolver.lookup_ip(domain).await {
+                        Ok(lookup) => lookup.iter().collect::<Vec<_>>(),
+                        Err(err) => {
+                            error!("Cannot resolve domain ({domain}) to IP: {err}");
+                            return false;
+                        }
+                    }
+                } else {
+                    vec![]
+                };
+                (Some(domain), ips)
+            }
+            Some(Host::Ipv4(ip)) => (None, vec![IpAddr::V4(ip)]),
+            Some(Host::Ipv6(ip)) => (None, vec![IpAddr::V6(ip)]),
+            None => return false,
+        };
+
+        let is_denied = deny.iter().any(|destination| {
+            domain.is_some_and(|domain| destination.matches_domain(domain))
+                || ips.iter().any(|ip| destination.matches_ip(ip))
+        });
+        if is_denied {
+            return false;
+        }
+
+        allow.is_empty()
+            || allow
+                .iter()
+                .any(|destination| domain.is_some_and(|domain| destination.matches_domain(domain)))
+            || (!ips.is_empty()
+                && ips
+                    .iter()
+                    .all(|ip| allow.iter().any(|destination| destination.matches_ip(ip))))
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use super::Network;
+    use super::{Network, NetworkDestination};
     use lettre::transport::stub::AsyncStubTransport;
-    use std::net::Ipv4Addr;
+    use std::{
+        net::Ipv4Addr,
+        time::{Duration, Instant},
+    };
     use trust_dns_resolver::{
         error::{ResolveError, ResolveErrorKind},
         proto::rr::{rdata::A, RData, Record},
@@ -111,6 +218,82 @@ pub mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn caches_public_web_urls_checks() -> anyhow::Result<()> {
+        let network = Network::new(
+            MockResolver::new_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(172, 32, 0, 2))),
+            )]),
+            AsyncStubTransport::new_ok(),
+        );
+        assert_eq!(network.public_hosts.get("retrack.dev"), None);
+
+        let url = Url::parse("https://retrack.dev/my-page")?;
+        assert!(network.is_public_web_url(&url).await);
+        assert_eq!(network.public_hosts.get("retrack.dev"), Some(true));
+
+        // Cache is shared between clones and takes precedence over DNS lookup.
+        let network_clone = network.clone();
+        network_clone.public_hosts.insert(
+            "retrack.dev",
+            false,
+            Instant::now() + Duration::from_secs(60),
+        );
+        assert!(!network.is_public_web_url(&url).await);
+
+        // Failed lookups aren't cached.
+        let broken_network = Network::new(
+            MockResolver::new_with_error(ResolveError::from(ResolveErrorKind::Message(
+                "can not lookup IPs",
+            ))),
+            AsyncStubTransport::new_ok(),
+        );
+        assert!(!broken_network.is_public_web_url(&url).await);
+        assert_eq!(broken_network.public_hosts.get("retrack.dev"), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn correctly_finds_non_public_web_urls() -> anyhow::Result<()> {
+        let network = Network::new(
+            MockResolver::new_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(172, 32, 0, 2))),
+            )]),
+            AsyncStubTransport::new_ok(),
+        );
+        network.public_hosts.insert(
+            "local.retrack.dev",
+            false,
+            Instant::now() + Duration::from_secs(60),
+        );
+
+        let urls = [
+            "https://retrack.dev/one",
+            "https://retrack.dev/two",
+            "http://127.0.0.1/one",
+            "https://local.retrack.dev/one",
+            "http://127.0.0.1/two",
+        ]
+        .into_iter()
+        .map(Url::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(network.find_non_public_web_url(&urls[..2]).await, None);
+        assert_eq!(network.find_non_public_web_url(&urls).await, Some(&urls[2]));
+        assert_eq!(
+            network.find_non_public_web_url(&urls[3..]).await,
+            Some(&urls[3])
+        );
+        assert_eq!(network.find_non_public_web_url(&[]).await, None);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn correctly_checks_public_ips() -> anyhow::Result<()> {
         let network = Network::new(MockResolver::new(), AsyncStubTransport::new_ok());
@@ -130,4 +313,94 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn correctly_checks_allowed_destinations() -> anyhow::Result<()> {
+        let allow = vec![
+            "hooks.retrack.dev".parse::<NetworkDestination>()?,
+            "203.0.113.0/24".parse()?,
+        ];
+        let deny = vec!["internal.hooks.retrack.dev".parse()?, "10.0.0.0/8".parse()?];
+
+        let network = Network::new(
+            MockResolver::new_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(203, 0, 113, 10))),
+            )]),
+            AsyncStubTransport::new_ok(),
+        );
+        for (url, is_allowed) in [
+            ("https://hooks.retrack.dev/webhook", true),
+            ("https://eu.hooks.retrack.dev/webhook", true),
+            ("https://internal.hooks.retrack.dev/webhook", false),
+            // Allowed by the network the domain resolves to.
+            ("https://retrack.dev/webhook", true),
+            ("http://203.0.113.1/webhook", true),
+            ("http://10.0.0.1/webhook", false),
+            ("http://[::1]/webhook", false),
+        ] {
+            let url = Url::parse(url)?;
+            assert_eq!(
+                network.is_allowed_destination(&url, &allow, &deny).await,
+                is_allowed,
+                "{url}"
+            );
+        }
+
+        // Without allow list, everything that isn't denied is allowed.
+        for (url, is_allowed) in [
+            ("https://retrack.dev/webhook", true),
+            ("https://internal.hooks.retrack.dev/webhook", false),
+            ("http://10.0.0.1/webhook", false),
+        ] {
+            let url = Url::parse(url)?;
+            assert_eq!(
+                network.is_allowed_destination(&url, &[], &deny).await,
+                is_allowed,
+                "{url}"
+            );
+        }
+
+        // Domains that resolve to denied networks aren't allowed.
+        let local_network = Network::new(
+            MockResolver::new_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(10, 0, 0, 2))),
+            )]),
+            AsyncStubTransport::new_ok(),
+        );
+        let url = Url::parse("https://hooks.retrack.dev/webhook")?;
+        assert!(
+            !local_network
+                .is_allowed_destination(&url, &allow, &deny)
+                .await
+        );
+        assert!(
+            local_network
+                .is_allowed_destination(&url, &allow, &deny[..1])
+                .await
+        );
+
+        // Domains that fail to resolve aren't allowed if networks should be checked.
+        let broken_network = Network::new(
+            MockResolver::new_with_error(ResolveError::from(ResolveErrorKind::Message(
+                "can not lookup IPs",
+            ))),
+            AsyncStubTransport::new_ok(),
+        );
+        assert!(
+            !broken_network
+                .is_allowed_destination(&url, &allow, &deny)
+                .await
+        );
+        assert!(
+            broken_network
+                .is_allowed_destination(&url, &allow[..1], &deny[..1])
+                .await
+        );
+
+        Ok(())
+    }
 }
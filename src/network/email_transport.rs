@@ -1,3 +1,4 @@
+use anyhow::bail;
 use lettre::{
     transport::{
         smtp::Error as SmtpError,
@@ -7,9 +8,29 @@ use lettre::{
 };
 use std::error::Error as StdError;
 
-pub trait EmailTransport: AsyncTransport + Sync + Send + 'static {}
-impl EmailTransport for AsyncSmtpTransport<Tokio1Executor> {}
-impl EmailTransport for AsyncStubTransport {}
+#[async_trait::async_trait]
+pub trait EmailTransport: AsyncTransport + Sync + Send + 'static {
+    /// Verifies that the transport can connect to and authenticate with the email server.
+    async fn verify_connection(&self) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EmailTransport for AsyncSmtpTransport<Tokio1Executor> {
+    async fn verify_connection(&self) -> anyhow::Result<()> {
+        if !self.test_connection().await? {
+            bail!("SMTP server doesn't accept connections.");
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailTransport for AsyncStubTransport {
+    async fn verify_connection(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
 
 pub trait EmailTransportError: StdError + Sync + Send {}
 impl EmailTransportError for SmtpError {}
@@ -0,0 +1,166 @@
+use anyhow::{anyhow, bail};
+use std::{fmt, net::IpAddr, str::FromStr};
+
+/// Network destination that can be used to restrict the set of hosts the server can reach out to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkDestination {
+    /// Domain name along with all its subdomains, e.g. `example.com` matches both `example.com`
+    /// and `hooks.example.com`.
+    Domain(String),
+    /// Network in CIDR notation, e.g. `10.0.0.0/8`. A single IP address is treated as a network
+    /// that consists of this address only.
+    Network { ip: IpAddr, prefix_len: u8 },
+}
+
+impl NetworkDestination {
+    /// Checks if the destination matches the specified domain name.
+    pub fn matches_domain(&self, domain: &str) -> bool {
+        let Self::Domain(destination_domain) = self else {
+            return false;
+        };
+
+        let domain = domain.trim_end_matches('.').to_lowercase();
+        domain == *destination_domain
+            || domain
+                .strip_suffix(destination_domain.as_str())
+                .is_some_and(|subdomain| subdomain.ends_with('.'))
+    }
+
+    /// Checks if the destination matches the specified IP address.
+    pub fn matches_ip(&self, ip: &IpAddr) -> bool {
+        let Self::Network {
+            ip: network_ip,
+            prefix_len,
+        } = self
+        else {
+            return false;
+        };
+
+        match (network_ip, ip.to_canonical()) {
+            (IpAddr::V4(network_ip), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - *prefix_len as u32).unwrap_or(0);
+                u32::from(*network_ip) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network_ip), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - *prefix_len as u32).unwrap_or(0);
+                u128::from(*network_ip) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for NetworkDestination {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.is_empty() {
+            bail!("Network destination cannot be empty.");
+        }
+
+        let (ip, prefix_len) = match value.split_once('/') {
+            Some((ip, prefix_len)) => (
+                ip.parse::<IpAddr>().map_err(|_| {
+                    anyhow!("Network destination `{value}` has invalid IP address.")
+                })?,
+                Some(prefix_len.parse::<u8>().map_err(|_| {
+                    anyhow!("Network destination `{value}` has invalid prefix length.")
+                })?),
+            ),
+            None => match value.parse::<IpAddr>() {
+                Ok(ip) => (ip, None),
+                Err(_) => {
+                    return if value.contains(|c: char| c.is_whitespace() || c == ':') {
+                        Err(anyhow!("Network destination `{value}` is not valid."))
+                    } else {
+                        Ok(Self::Domain(value.trim_end_matches('.').to_lowercase()))
+                    };
+                }
+            },
+        };
+
+        let max_prefix_len = if ip.is_ipv4() { 32 } else { 128 };
+        let prefix_len = prefix_len.unwrap_or(max_prefix_len);
+        if prefix_len > max_prefix_len {
+            bail!("Network destination `{value}` has invalid prefix length.");
+        }
+
+        Ok(Self::Network { ip, prefix_len })
+    }
+}
+
+impl fmt::Display for NetworkDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Domain(domain) => f.write_str(domain),
+            Self::Network { ip, prefix_len } => write!(f, "{ip}/{prefix_len}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NetworkDestination;
+    use insta::assert_debug_snapshot;
+    use std::net::IpAddr;
+
+    #[test]
+    fn parsing_and_display() -> anyhow::Result<()> {
+        for (value, display) in [
+            ("Hooks.Retrack.dev.", "hooks.retrack.dev"),
+            ("localhost", "localhost"),
+            ("10.0.0.0/8", "10.0.0.0/8"),
+            ("192.168.1.1", "192.168.1.1/32"),
+            ("fd00::/8", "fd00::/8"),
+            ("::1", "::1/128"),
+        ] {
+            assert_eq!(value.parse::<NetworkDestination>()?.to_string(), display);
+        }
+
+        assert_debug_snapshot!("".parse::<NetworkDestination>().unwrap_err().to_string(), @r###""Network destination cannot be empty.""###);
+        assert_debug_snapshot!("10.0.0.0/33".parse::<NetworkDestination>().unwrap_err().to_string(), @r###""Network destination `10.0.0.0/33` has invalid prefix length.""###);
+        assert_debug_snapshot!("10.0.0.0/a".parse::<NetworkDestination>().unwrap_err().to_string(), @r###""Network destination `10.0.0.0/a` has invalid prefix length.""###);
+        assert_debug_snapshot!("retrack.dev/8".parse::<NetworkDestination>().unwrap_err().to_string(), @r###""Network destination `retrack.dev/8` has invalid IP address.""###);
+        assert_debug_snapshot!("retrack.dev:8080".parse::<NetworkDestination>().unwrap_err().to_string(), @r###""Network destination `retrack.dev:8080` is not valid.""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_domains() -> anyhow::Result<()> {
+        let destination = "retrack.dev".parse::<NetworkDestination>()?;
+        assert!(destination.matches_domain("retrack.dev"));
+        assert!(destination.matches_domain("RETRACK.dev."));
+        assert!(destination.matches_domain("hooks.retrack.dev"));
+        assert!(!destination.matches_domain("notretrack.dev"));
+        assert!(!destination.matches_domain("retrack.dev.evil.com"));
+        assert!(!destination.matches_ip(&"127.0.0.1".parse()?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_ips() -> anyhow::Result<()> {
+        let destination = "10.0.0.0/8".parse::<NetworkDestination>()?;
+        assert!(destination.matches_ip(&"10.0.0.1".parse()?));
+        assert!(destination.matches_ip(&"10.255.255.255".parse()?));
+        assert!(destination.matches_ip(&"::ffff:10.1.2.3".parse()?));
+        assert!(!destination.matches_ip(&"11.0.0.1".parse()?));
+        assert!(!destination.matches_ip(&"fd00::1".parse()?));
+        assert!(!destination.matches_domain("10.0.0.1"));
+
+        let destination = "0.0.0.0/0".parse::<NetworkDestination>()?;
+        assert!(destination.matches_ip(&"217.88.39.143".parse()?));
+
+        let destination = "fd00::/8".parse::<NetworkDestination>()?;
+        assert!(destination.matches_ip(&"fd12:3456::1".parse()?));
+        assert!(!destination.matches_ip(&"fe80::1".parse()?));
+
+        let destination = "192.168.1.1".parse::<NetworkDestination>()?;
+        assert!(destination.matches_ip(&"192.168.1.1".parse::<IpAddr>()?));
+        assert!(!destination.matches_ip(&"192.168.1.2".parse::<IpAddr>()?));
+
+        Ok(())
+    }
+}
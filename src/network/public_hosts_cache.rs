@@ -0,0 +1,80 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Defines the maximum time the host publicness is cached for, even if its DNS records can be
+/// cached for longer.
+const MAX_PUBLIC_HOST_TTL: Duration = Duration::from_secs(300);
+
+/// In-memory cache of the domain publicness checks keyed by the domain name, so that the same
+/// domain isn't resolved every time tracker is validated or remote script is fetched.
+#[derive(Clone, Debug, Default)]
+pub struct PublicHostsCache(Arc<Mutex<HashMap<String, (bool, Instant)>>>);
+
+impl PublicHostsCache {
+    /// Returns cached publicness of the domain, if it's not expired yet.
+    pub fn get(&self, domain: &str) -> Option<bool> {
+        let mut hosts = self.0.lock().ok()?;
+        match hosts.get(domain) {
+            Some((is_public, valid_until)) if *valid_until > Instant::now() => Some(*is_public),
+            Some(_) => {
+                hosts.remove(domain);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Caches publicness of the domain until the specified instant (usually, when its DNS records
+    /// expire), but no longer than `MAX_PUBLIC_HOST_TTL`.
+    pub fn insert(&self, domain: &str, is_public: bool, valid_until: Instant) {
+        if let Ok(mut hosts) = self.0.lock() {
+            let now = Instant::now();
+            hosts.retain(|_, (_, valid_until)| *valid_until > now);
+            hosts.insert(
+                domain.to_string(),
+                (is_public, valid_until.min(now + MAX_PUBLIC_HOST_TTL)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublicHostsCache, MAX_PUBLIC_HOST_TTL};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn properly_caches_hosts() {
+        let cache = PublicHostsCache::default();
+        assert_eq!(cache.get("retrack.dev"), None);
+
+        cache.insert(
+            "retrack.dev",
+            true,
+            Instant::now() + Duration::from_secs(60),
+        );
+        cache.insert(
+            "local.retrack.dev",
+            false,
+            Instant::now() + Duration::from_secs(60),
+        );
+        assert_eq!(cache.get("retrack.dev"), Some(true));
+        assert_eq!(cache.get("local.retrack.dev"), Some(false));
+
+        // Expired entries aren't returned.
+        cache.insert("retrack.dev", true, Instant::now() - Duration::from_secs(1));
+        assert_eq!(cache.get("retrack.dev"), None);
+
+        // TTL is capped.
+        cache.insert(
+            "retrack.dev",
+            true,
+            Instant::now() + Duration::from_secs(86400),
+        );
+        let (_, valid_until) = cache.0.lock().unwrap()["retrack.dev"];
+        assert!(valid_until <= Instant::now() + MAX_PUBLIC_HOST_TTL);
+    }
+}
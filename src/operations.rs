@@ -0,0 +1,2 @@
+mod api_ext;
+mod database_ext;
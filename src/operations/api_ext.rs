@@ -0,0 +1,121 @@
+use crate::{
+    api::Api,
+    database::Database,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+};
+use retrack_types::operations::{Operation, OperationError, OperationKind};
+use uuid::Uuid;
+
+/// Describes the API to work with long-running operations.
+pub struct OperationsApi<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> OperationsApi<'a, DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Creates Operations API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Creates and persists a new operation that should process `total` items.
+    pub async fn create_operation(
+        &self,
+        kind: OperationKind,
+        total: usize,
+    ) -> anyhow::Result<Operation> {
+        let created_at = Database::utc_now()?;
+        let operation = Operation {
+            id: Uuid::now_v7(),
+            kind,
+            total,
+            completed: 0,
+            errors: vec![],
+            created_at,
+            updated_at: created_at,
+        };
+
+        self.api.db.insert_operation(&operation).await?;
+
+        Ok(operation)
+    }
+
+    /// Returns operation by its ID.
+    pub async fn get_operation(&self, id: Uuid) -> anyhow::Result<Option<Operation>> {
+        self.api.db.get_operation(id).await
+    }
+
+    /// Records that one more item of the operation has been processed, either successfully or
+    /// with the specified error, and persists updated progress.
+    pub async fn complete_operation_item(
+        &self,
+        operation: &mut Operation,
+        error: Option<OperationError>,
+    ) -> anyhow::Result<()> {
+        operation.completed += 1;
+        operation.errors.extend(error);
+        operation.updated_at = Database::utc_now()?;
+
+        self.api.db.update_operation(operation).await
+    }
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Returns an API to work with operations.
+    pub fn operations(&self) -> OperationsApi<'_, DR, ET> {
+        OperationsApi::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::mock_api;
+    use retrack_types::operations::{OperationError, OperationKind};
+    use sqlx::PgPool;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn properly_tracks_operation_progress(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let operations = api.operations();
+
+        let mut operation = operations
+            .create_operation(OperationKind::TrackersRun, 2)
+            .await?;
+        assert_eq!(operation.kind, OperationKind::TrackersRun);
+        assert_eq!(operation.total, 2);
+        assert_eq!(operation.completed, 0);
+        assert!(operation.errors.is_empty());
+        assert!(!operation.is_finished());
+        assert_eq!(
+            operations.get_operation(operation.id).await?,
+            Some(operation.clone())
+        );
+
+        operations
+            .complete_operation_item(&mut operation, None)
+            .await?;
+        operations
+            .complete_operation_item(
+                &mut operation,
+                Some(OperationError {
+                    item_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                    message: "Failed to fetch data.".to_string(),
+                }),
+            )
+            .await?;
+        assert_eq!(operation.completed, 2);
+        assert!(operation.is_finished());
+        assert_eq!(
+            operations.get_operation(operation.id).await?,
+            Some(operation)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+mod raw_operation;
+
+use crate::{database::Database, operations::database_ext::raw_operation::RawOperation};
+use retrack_types::operations::Operation;
+use sqlx::{query, query_as};
+use uuid::Uuid;
+
+/// Extends primary database with the operations-related methods.
+impl Database {
+    /// Retrieves operation from the database using ID.
+    pub async fn get_operation(&self, id: Uuid) -> anyhow::Result<Option<Operation>> {
+        query_as!(
+            RawOperation,
+            r#"SELECT id, kind, total, completed, errors, created_at, updated_at FROM operations WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(Operation::try_from)
+        .transpose()
+    }
+
+    /// Inserts a new operation to the database.
+    pub async fn insert_operation(&self, operation: &Operation) -> anyhow::Result<()> {
+        let raw_operation = RawOperation::try_from(operation)?;
+        query!(
+            r#"
+INSERT INTO operations (id, kind, total, completed, errors, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            raw_operation.id,
+            raw_operation.kind,
+            raw_operation.total,
+            raw_operation.completed,
+            raw_operation.errors,
+            raw_operation.created_at,
+            raw_operation.updated_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Updates operation progress in the database.
+    pub async fn update_operation(&self, operation: &Operation) -> anyhow::Result<()> {
+        let raw_operation = RawOperation::try_from(operation)?;
+        query!(
+            r#"
+UPDATE operations
+SET completed = $2, errors = $3, updated_at = $4
+WHERE id = $1
+            "#,
+            raw_operation.id,
+            raw_operation.completed,
+            raw_operation.errors,
+            raw_operation.updated_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::Database;
+    use retrack_types::operations::{Operation, OperationError, OperationKind};
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_add_update_and_retrieve_operation(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let operation = Operation {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            kind: OperationKind::TrackersRun,
+            total: 2,
+            completed: 0,
+            errors: vec![],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+        assert!(db.get_operation(operation.id).await?.is_none());
+
+        db.insert_operation(&operation).await?;
+        assert_eq!(
+            db.get_operation(operation.id).await?,
+            Some(operation.clone())
+        );
+
+        let operation = Operation {
+            completed: 2,
+            errors: vec![OperationError {
+                item_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                message: "Failed to fetch data.".to_string(),
+            }],
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            ..operation
+        };
+        db.update_operation(&operation).await?;
+        assert_eq!(db.get_operation(operation.id).await?, Some(operation));
+
+        assert!(db
+            .get_operation(uuid!("00000000-0000-0000-0000-000000000002"))
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+}
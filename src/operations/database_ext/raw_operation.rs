@@ -0,0 +1,104 @@
+use retrack_types::operations::{Operation, OperationError};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawOperation {
+    pub id: Uuid,
+    pub kind: Vec<u8>,
+    pub total: i64,
+    pub completed: i64,
+    pub errors: Vec<u8>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+struct RawOperationError<'s>(Uuid, #[serde(borrow)] Cow<'s, str>);
+
+impl TryFrom<RawOperation> for Operation {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawOperation) -> Result<Self, Self::Error> {
+        Ok(Operation {
+            id: raw.id,
+            kind: postcard::from_bytes(&raw.kind)?,
+            total: usize::try_from(raw.total)?,
+            completed: usize::try_from(raw.completed)?,
+            errors: postcard::from_bytes::<Vec<RawOperationError>>(&raw.errors)?
+                .into_iter()
+                .map(|RawOperationError(item_id, message)| OperationError {
+                    item_id,
+                    message: message.into_owned(),
+                })
+                .collect(),
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        })
+    }
+}
+
+impl TryFrom<&Operation> for RawOperation {
+    type Error = anyhow::Error;
+
+    fn try_from(item: &Operation) -> Result<Self, Self::Error> {
+        Ok(RawOperation {
+            id: item.id,
+            kind: postcard::to_stdvec(&item.kind)?,
+            total: i64::try_from(item.total)?,
+            completed: i64::try_from(item.completed)?,
+            errors: postcard::to_stdvec(
+                &item
+                    .errors
+                    .iter()
+                    .map(|error| RawOperationError(error.item_id, Cow::Borrowed(&error.message)))
+                    .collect::<Vec<_>>(),
+            )?,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawOperation;
+    use retrack_types::operations::{Operation, OperationError, OperationKind};
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn can_convert_into_and_from_raw_operation() -> anyhow::Result<()> {
+        let operation = Operation {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            kind: OperationKind::TrackersRun,
+            total: 3,
+            completed: 0,
+            errors: vec![],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+        assert_eq!(
+            Operation::try_from(RawOperation::try_from(&operation)?)?,
+            operation
+        );
+
+        let operation = Operation {
+            completed: 2,
+            errors: vec![OperationError {
+                item_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                message: "Failed to fetch data.".to_string(),
+            }],
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            ..operation
+        };
+        assert_eq!(
+            Operation::try_from(RawOperation::try_from(&operation)?)?,
+            operation
+        );
+
+        Ok(())
+    }
+}
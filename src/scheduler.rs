@@ -2,6 +2,8 @@ mod api_ext;
 mod cron_ext;
 mod database_ext;
 mod job_ext;
+#[cfg(feature = "scheduler-redis-locks")]
+mod redis_lock;
 mod scheduler_job;
 mod scheduler_job_metadata;
 mod scheduler_job_retry_state;
@@ -25,7 +27,8 @@ use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport, EmailTransportError},
     scheduler::scheduler_jobs::{
-        TasksRunJob, TrackersRunJob, TrackersScheduleJob, TrackersTriggerJob,
+        TasksRunJob, TrackersDigestJob, TrackersRetentionJob, TrackersRunJob, TrackersScheduleJob,
+        TrackersTriggerJob,
     },
     server::SchedulerStatus,
 };
@@ -112,6 +115,20 @@ where
                 .await?;
         }
 
+        if !resumed_unique_jobs.contains(&SchedulerJob::TrackersDigest) {
+            scheduler
+                .inner_scheduler
+                .add(TrackersDigestJob::create(scheduler.api.clone()).await?)
+                .await?;
+        }
+
+        if !resumed_unique_jobs.contains(&SchedulerJob::TrackersRetention) {
+            scheduler
+                .inner_scheduler
+                .add(TrackersRetentionJob::create(scheduler.api.clone()).await?)
+                .await?;
+        }
+
         scheduler.inner_scheduler.start().await?;
         Ok(scheduler)
     }
@@ -169,6 +186,12 @@ where
                 SchedulerJob::TasksRun => {
                     TasksRunJob::try_resume(self.api.clone(), job_data).await?
                 }
+                SchedulerJob::TrackersDigest => {
+                    TrackersDigestJob::try_resume(self.api.clone(), job_data).await?
+                }
+                SchedulerJob::TrackersRetention => {
+                    TrackersRetentionJob::try_resume(self.api.clone(), job_data).await?
+                }
             };
 
             match job {
@@ -391,7 +414,7 @@ pub mod tests {
         Scheduler::start(api.clone()).await?;
 
         let jobs = api.db.get_scheduler_jobs(10).collect::<Vec<_>>().await;
-        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs.len(), 5);
 
         let mut jobs = jobs
             .into_iter()
@@ -437,6 +460,30 @@ pub mod tests {
                     "0 * 2 * * *",
                 ),
             ),
+            (
+                0,
+                Some(
+                    [
+                        4,
+                        0,
+                    ],
+                ),
+                Some(
+                    "0 * 3 * * *",
+                ),
+            ),
+            (
+                0,
+                Some(
+                    [
+                        5,
+                        0,
+                    ],
+                ),
+                Some(
+                    "0 * 4 * * *",
+                ),
+            ),
         ]
         "###);
 
@@ -498,7 +545,7 @@ pub mod tests {
             .is_none());
 
         let jobs = api.db.get_scheduler_jobs(10).collect::<Vec<_>>().await;
-        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs.len(), 5);
 
         let mut jobs = jobs
             .into_iter()
@@ -544,6 +591,30 @@ pub mod tests {
                     "0 * 2 * * *",
                 ),
             ),
+            (
+                0,
+                Some(
+                    [
+                        4,
+                        0,
+                    ],
+                ),
+                Some(
+                    "0 * 3 * * *",
+                ),
+            ),
+            (
+                0,
+                Some(
+                    [
+                        5,
+                        0,
+                    ],
+                ),
+                Some(
+                    "0 * 4 * * *",
+                ),
+            ),
         ]
         "###);
 
@@ -557,7 +628,7 @@ pub mod tests {
         let mut scheduler = Scheduler::start(api.clone()).await?;
 
         let jobs = api.db.get_scheduler_jobs(10).collect::<Vec<_>>().await;
-        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs.len(), 5);
 
         let status = scheduler.status().await?;
         assert!(status.time_till_next_job.is_some());
@@ -1,10 +1,14 @@
+#[cfg(feature = "scheduler-redis-locks")]
+use crate::scheduler::redis_lock::RedisLock;
 use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
-    scheduler::{SchedulerJobMetadata, SchedulerJobRetryState},
+    scheduler::{SchedulerJob, SchedulerJobMetadata, SchedulerJobRetryState},
 };
+#[cfg(not(feature = "scheduler-redis-locks"))]
+use anyhow::bail;
 use retrack_types::scheduler::SchedulerJobRetryStrategy;
-use std::ops::Add;
+use std::{future::Future, ops::Add};
 use time::OffsetDateTime;
 use tracing::{debug, warn};
 use uuid::Uuid;
@@ -66,6 +70,29 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> SchedulerApiExt<'a, DR, ET> {
 
         Ok(retry_state)
     }
+
+    /// Runs the specified scheduler job only if no other Retrack instance is executing the job of
+    /// the same type at the moment, otherwise returns `None`.
+    pub async fn run_exclusively<R>(
+        &self,
+        job_type: SchedulerJob,
+        job: impl Future<Output = R>,
+    ) -> anyhow::Result<Option<R>> {
+        let lock_key = format!("scheduler:{job_type:?}");
+        match self.api.config.scheduler.redis_locks {
+            #[cfg(feature = "scheduler-redis-locks")]
+            Some(ref config) => {
+                RedisLock::new(config)?
+                    .run_exclusively(&lock_key, job)
+                    .await
+            }
+            #[cfg(not(feature = "scheduler-redis-locks"))]
+            Some(_) => bail!(
+                "Redis-based scheduler locks require `scheduler-redis-locks` feature to be enabled."
+            ),
+            None => self.api.db.run_exclusively(&lock_key, job).await,
+        }
+    }
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
@@ -77,6 +104,11 @@ impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(not(feature = "scheduler-redis-locks"))]
+    use crate::{
+        config::SchedulerRedisLocksConfig,
+        tests::{mock_api_with_config, mock_config},
+    };
     use crate::{
         scheduler::{database_ext::RawSchedulerJobStoredData, SchedulerJob, SchedulerJobMetadata},
         tests::{mock_api, mock_upsert_scheduler_job},
@@ -85,6 +117,8 @@ mod tests {
     use sqlx::PgPool;
     use std::{ops::Add, time::Duration};
     use time::OffsetDateTime;
+    #[cfg(not(feature = "scheduler-redis-locks"))]
+    use url::Url;
     use uuid::uuid;
 
     #[sqlx::test]
@@ -151,4 +185,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn properly_runs_jobs_exclusively(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let scheduler = api.scheduler();
+
+        assert_eq!(
+            scheduler
+                .run_exclusively(SchedulerJob::TasksRun, async {
+                    // Nested job of the same type cannot acquire the lock.
+                    let nested_result = scheduler
+                        .run_exclusively(SchedulerJob::TasksRun, async { 2 })
+                        .await?;
+                    assert!(nested_result.is_none());
+
+                    // Job of a different type isn't blocked.
+                    scheduler
+                        .run_exclusively(SchedulerJob::TrackersRun, async { 3 })
+                        .await
+                })
+                .await?
+                .transpose()?,
+            Some(Some(3))
+        );
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "scheduler-redis-locks"))]
+    #[sqlx::test]
+    async fn fails_to_run_jobs_with_redis_locks_without_feature(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.redis_locks = Some(SchedulerRedisLocksConfig {
+            url: Url::parse("redis://localhost:6379")?,
+            key_prefix: None,
+            lock_ttl: Duration::from_secs(60),
+        });
+
+        let api = mock_api_with_config(pool, config).await?;
+        let result = api
+            .scheduler()
+            .run_exclusively(SchedulerJob::TasksRun, async { 1 })
+            .await;
+        assert_eq!(
+            result.map_err(|err| err.to_string()),
+            Err(
+                "Redis-based scheduler locks require `scheduler-redis-locks` feature to be enabled."
+                    .to_string()
+            )
+        );
+
+        Ok(())
+    }
 }
@@ -6,7 +6,8 @@ use crate::{database::Database, scheduler::SchedulerJobMetadata};
 use anyhow::{anyhow, bail};
 use async_stream::try_stream;
 use futures::Stream;
-use sqlx::{query, query_as};
+use sqlx::{query, query_as, query_scalar};
+use std::future::Future;
 use uuid::Uuid;
 
 /// Extends primary database with the Scheduler-related methods.
@@ -16,12 +17,17 @@ impl Database {
         &self,
         id: Uuid,
     ) -> anyhow::Result<Option<SchedulerJobMetadata>> {
-        query!(r#"SELECT extra FROM scheduler_jobs WHERE id = $1"#, id)
-            .fetch_optional(&self.pool)
-            .await?
-            .and_then(|record| record.extra)
-            .map(|extra| SchedulerJobMetadata::try_from(extra.as_slice()))
-            .transpose()
+        self.with_retries(|| async {
+            Ok(
+                query!(r#"SELECT extra FROM scheduler_jobs WHERE id = $1"#, id)
+                    .fetch_optional(&self.pool)
+                    .await?,
+            )
+        })
+        .await?
+        .and_then(|record| record.extra)
+        .map(|extra| SchedulerJobMetadata::try_from(extra.as_slice()))
+        .transpose()
     }
 
     /// Updates scheduler job metadata in the `scheduler_jobs` table using Job ID.
@@ -31,13 +37,17 @@ impl Database {
         meta: SchedulerJobMetadata,
     ) -> anyhow::Result<()> {
         let meta = Vec::try_from(meta)?;
-        let result = query!(
-            r#"UPDATE scheduler_jobs SET extra = $2 WHERE id = $1"#,
-            id,
-            meta
-        )
-        .execute(&self.pool)
-        .await?;
+        let result = self
+            .with_retries(|| async {
+                Ok(query!(
+                    r#"UPDATE scheduler_jobs SET extra = $2 WHERE id = $1"#,
+                    id,
+                    &meta
+                )
+                .execute(&self.pool)
+                .await?)
+            })
+            .await?;
 
         if result.rows_affected() == 0 {
             bail!(format!("A scheduler job ('{id}') doesn't exist."));
@@ -55,17 +65,21 @@ impl Database {
 
         // Every time the job state is reset, we should reset retry state.
         let metadata = Vec::try_from(SchedulerJobMetadata::new(metadata.job_type))?;
-        query!(
-            r#"
+        self.with_retries(|| async {
+            query!(
+                r#"
 UPDATE scheduler_jobs
 SET stopped = $2, extra = $3
 WHERE id = $1
         "#,
-            id,
-            stopped,
-            metadata
-        )
-        .execute(&self.pool)
+                id,
+                stopped,
+                &metadata
+            )
+            .execute(&self.pool)
+            .await?;
+            Ok(())
+        })
         .await?;
 
         Ok(())
@@ -100,6 +114,36 @@ WHERE id = $1
             }
         }
     }
+
+    /// Runs the specified job only if the Postgres advisory lock with the specified key can be
+    /// acquired, otherwise returns `None`. The lock is bound to the transaction, so it's released
+    /// automatically even if the instance that holds it goes away.
+    pub async fn run_exclusively<R>(
+        &self,
+        lock_key: &str,
+        job: impl Future<Output = R>,
+    ) -> anyhow::Result<Option<R>> {
+        let (transaction, is_locked) = self
+            .with_retries(|| async {
+                let mut transaction = self.pool.begin().await?;
+                let is_locked = query_scalar!(
+                    r#"SELECT pg_try_advisory_xact_lock(hashtext($1)) as "is_locked!""#,
+                    lock_key
+                )
+                .fetch_one(&mut *transaction)
+                .await?;
+                Ok((transaction, is_locked))
+            })
+            .await?;
+        if !is_locked {
+            return Ok(None);
+        }
+
+        let result = job.await;
+        transaction.commit().await?;
+
+        Ok(Some(result))
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +156,7 @@ pub mod tests {
     use futures::{Stream, StreamExt};
     use sqlx::{query, query_as, PgPool};
     use time::OffsetDateTime;
+    use tokio::sync::oneshot;
     use uuid::{uuid, Uuid};
 
     pub async fn mock_upsert_scheduler_job(
@@ -538,4 +583,39 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_run_jobs_exclusively(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let (started_tx, started_rx) = oneshot::channel();
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let first_job = {
+            let db = db.clone();
+            tokio::spawn(async move {
+                db.run_exclusively("lock", async move {
+                    started_tx.send(()).unwrap();
+                    release_rx.await.unwrap();
+                    1
+                })
+                .await
+            })
+        };
+        started_rx.await?;
+
+        // Lock is held by the first job.
+        assert_eq!(db.run_exclusively("lock", async { 2 }).await?, None);
+        assert_eq!(
+            db.run_exclusively("other-lock", async { 3 }).await?,
+            Some(3)
+        );
+
+        release_tx.send(()).unwrap();
+        assert_eq!(first_job.await??, Some(1));
+
+        // Lock is released once the first job is done.
+        assert_eq!(db.run_exclusively("lock", async { 4 }).await?, Some(4));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,153 @@
+use crate::config::SchedulerRedisLocksConfig;
+use redis::{Client, Cmd, Script};
+use std::{future::Future, time::Duration};
+use uuid::Uuid;
+
+/// Default prefix for the scheduler lock keys stored in Redis.
+const DEFAULT_KEY_PREFIX: &str = "retrack:locks:";
+
+/// Script that releases the lock only if it's still held by the current owner (it might have
+/// expired and been acquired by another instance in the meantime).
+const RELEASE_LOCK_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+else
+    return 0
+end
+"#;
+
+/// Redis-based lock that makes sure that only a single Retrack instance executes a job at a time.
+pub struct RedisLock {
+    client: Client,
+    key_prefix: String,
+    ttl: Duration,
+}
+
+impl RedisLock {
+    /// Creates a new Redis lock. Connection is established only when the lock is acquired.
+    pub fn new(config: &SchedulerRedisLocksConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: Client::open(config.url.as_str())?,
+            key_prefix: config
+                .key_prefix
+                .as_deref()
+                .unwrap_or(DEFAULT_KEY_PREFIX)
+                .to_string(),
+            ttl: config.lock_ttl,
+        })
+    }
+
+    /// Runs the specified job only if the lock with the specified key can be acquired, otherwise
+    /// returns `None`.
+    pub async fn run_exclusively<R>(
+        &self,
+        lock_key: &str,
+        job: impl Future<Output = R>,
+    ) -> anyhow::Result<Option<R>> {
+        let key = self.key(lock_key);
+        let owner = Uuid::now_v7().to_string();
+
+        let mut connection = self.client.get_multiplexed_async_connection().await?;
+        let is_locked: Option<String> = self
+            .acquire_command(&key, &owner)
+            .query_async(&mut connection)
+            .await?;
+        if is_locked.is_none() {
+            return Ok(None);
+        }
+
+        let result = job.await;
+
+        let _: i64 = Script::new(RELEASE_LOCK_SCRIPT)
+            .key(&key)
+            .arg(&owner)
+            .invoke_async(&mut connection)
+            .await?;
+
+        Ok(Some(result))
+    }
+
+    /// Returns the Redis key of the lock with the specified name.
+    fn key(&self, lock_key: &str) -> String {
+        format!("{}{lock_key}", self.key_prefix)
+    }
+
+    /// Returns the command that sets the lock key only if it doesn't exist yet, and makes it expire
+    /// after the lock TTL.
+    fn acquire_command(&self, key: &str, owner: &str) -> Cmd {
+        let mut command = redis::cmd("SET");
+        command
+            .arg(key)
+            .arg(owner)
+            .arg("NX")
+            .arg("PX")
+            .arg(self.ttl.as_millis() as u64);
+        command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedisLock;
+    use crate::config::SchedulerRedisLocksConfig;
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn uses_default_key_prefix() -> anyhow::Result<()> {
+        let lock = RedisLock::new(&SchedulerRedisLocksConfig {
+            url: Url::parse("redis://localhost:6379")?,
+            key_prefix: None,
+            lock_ttl: Duration::from_secs(60),
+        })?;
+        assert_eq!(
+            lock.key("scheduler:TasksRun"),
+            "retrack:locks:scheduler:TasksRun"
+        );
+        assert_eq!(lock.ttl, Duration::from_secs(60));
+
+        Ok(())
+    }
+
+    #[test]
+    fn uses_custom_key_prefix() -> anyhow::Result<()> {
+        let lock = RedisLock::new(&SchedulerRedisLocksConfig {
+            url: Url::parse("redis://localhost:6379/1")?,
+            key_prefix: Some("staging:".to_string()),
+            lock_ttl: Duration::from_millis(1500),
+        })?;
+        assert_eq!(lock.key("scheduler:TasksRun"), "staging:scheduler:TasksRun");
+
+        Ok(())
+    }
+
+    #[test]
+    fn acquires_lock_with_ttl() -> anyhow::Result<()> {
+        let lock = RedisLock::new(&SchedulerRedisLocksConfig {
+            url: Url::parse("redis://localhost:6379")?,
+            key_prefix: None,
+            lock_ttl: Duration::from_millis(1500),
+        })?;
+        assert_eq!(
+            String::from_utf8(
+                lock.acquire_command("retrack:locks:scheduler:TasksRun", "owner")
+                    .get_packed_command()
+            )?,
+            "*6\r\n$3\r\nSET\r\n$32\r\nretrack:locks:scheduler:TasksRun\r\n$5\r\nowner\r\n$2\r\nNX\r\n$2\r\nPX\r\n$4\r\n1500\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_invalid_url() -> anyhow::Result<()> {
+        assert!(RedisLock::new(&SchedulerRedisLocksConfig {
+            url: Url::parse("https://localhost:6379")?,
+            key_prefix: None,
+            lock_ttl: Duration::from_secs(60),
+        })
+        .is_err());
+
+        Ok(())
+    }
+}
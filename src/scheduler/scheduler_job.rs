@@ -7,6 +7,8 @@ pub enum SchedulerJob {
     TrackersSchedule,
     TrackersRun,
     TasksRun,
+    TrackersDigest,
+    TrackersRetention,
 }
 
 impl SchedulerJob {
@@ -17,6 +19,8 @@ impl SchedulerJob {
             Self::TrackersTrigger => false,
             Self::TrackersRun => true,
             Self::TasksRun => true,
+            Self::TrackersDigest => true,
+            Self::TrackersRetention => true,
         }
     }
 }
@@ -31,6 +35,8 @@ mod tests {
         assert!(SchedulerJob::TrackersSchedule.is_unique());
         assert!(SchedulerJob::TrackersRun.is_unique());
         assert!(SchedulerJob::TasksRun.is_unique());
+        assert!(SchedulerJob::TrackersDigest.is_unique());
+        assert!(SchedulerJob::TrackersRetention.is_unique());
 
         Ok(())
     }
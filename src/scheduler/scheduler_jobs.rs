@@ -1,9 +1,13 @@
 mod tasks_run_job;
+mod trackers_digest_job;
+mod trackers_retention_job;
 mod trackers_run_job;
 mod trackers_schedule_job;
 mod trackers_trigger_job;
 
 pub(crate) use tasks_run_job::TasksRunJob;
+pub(crate) use trackers_digest_job::TrackersDigestJob;
+pub(crate) use trackers_retention_job::TrackersRetentionJob;
 pub(crate) use trackers_run_job::TrackersRunJob;
 pub(crate) use trackers_schedule_job::TrackersScheduleJob;
 pub(crate) use trackers_trigger_job::TrackersTriggerJob;
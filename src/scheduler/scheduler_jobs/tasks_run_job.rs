@@ -10,7 +10,7 @@ use anyhow::Context;
 use croner::Cron;
 use std::{sync::Arc, time::Instant};
 use tokio_cron_scheduler::{Job, JobScheduler};
-use tracing::{error, info, trace};
+use tracing::{debug, error, info, trace};
 
 /// Defines a maximum number of tasks that can be executed during a single job tick.
 const MAX_TASKS_TO_SEND: usize = 100;
@@ -77,14 +77,25 @@ impl TasksRunJob {
         ET::Error: EmailTransportError,
     {
         let execute_start = Instant::now();
-        match api.tasks().execute_pending_tasks(MAX_TASKS_TO_SEND).await {
-            Ok(executed_tasks_count) if executed_tasks_count > 0 => {
+        let executed_tasks_count = api
+            .scheduler()
+            .run_exclusively(
+                SchedulerJob::TasksRun,
+                api.tasks().execute_pending_tasks(MAX_TASKS_TO_SEND),
+            )
+            .await
+            .and_then(|executed_tasks_count| executed_tasks_count.transpose());
+        match executed_tasks_count {
+            Ok(None) => {
+                debug!("Pending tasks are being executed by another instance, skipping.");
+            }
+            Ok(Some(executed_tasks_count)) if executed_tasks_count > 0 => {
                 info!(
                     "Executed {executed_tasks_count} tasks ({} elapsed).",
                     humantime::format_duration(execute_start.elapsed())
                 );
             }
-            Ok(_) => {
+            Ok(Some(_)) => {
                 trace!(
                     "No pending tasks to execute ({} elapsed).",
                     humantime::format_duration(execute_start.elapsed())
@@ -207,6 +218,8 @@ mod tests {
                             "subject".to_string(),
                             format!("message {n}"),
                         )),
+                        timeout: None,
+                        attachments: None,
                     }),
                     OffsetDateTime::from_unix_timestamp(946720800 + n)?,
                 )
@@ -0,0 +1,287 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+    scheduler::{
+        database_ext::RawSchedulerJobStoredData, job_ext::JobExt, scheduler_job::SchedulerJob,
+        CronExt,
+    },
+};
+use anyhow::Context;
+use croner::Cron;
+use std::{sync::Arc, time::Instant};
+use tokio_cron_scheduler::{Job, JobScheduler};
+use tracing::{debug, error, info, trace};
+
+/// The job run on a regular interval to send tracker action digests that are due.
+pub(crate) struct TrackersDigestJob;
+impl TrackersDigestJob {
+    /// Tries to resume existing `TrackersDigestJob` job.
+    pub async fn try_resume<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        existing_job_data: RawSchedulerJobStoredData,
+    ) -> anyhow::Result<Option<Job>>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // If the schedule has changed, remove existing job and create a new one.
+        let mut new_job = Self::create(api).await?;
+        Ok(if new_job.are_schedules_equal(&existing_job_data)? {
+            new_job.set_raw_job_data(existing_job_data)?;
+            Some(new_job)
+        } else {
+            None
+        })
+    }
+
+    /// Creates a new `TrackersDigestJob` job.
+    pub async fn create<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+    ) -> anyhow::Result<Job>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let mut job = Job::new_async(
+            Cron::parse_pattern(&api.config.scheduler.trackers_digest)
+                .with_context(|| {
+                    format!(
+                        "Cannot parse `trackers_digest` schedule: {}",
+                        api.config.scheduler.trackers_digest
+                    )
+                })?
+                .pattern
+                .to_string(),
+            move |_, scheduler| {
+                let api = api.clone();
+                Box::pin(async move {
+                    if let Err(err) = Self::execute(api, scheduler).await {
+                        error!("Failed to execute trackers digest job: {err:?}");
+                    }
+                })
+            },
+        )?;
+
+        job.set_job_type(SchedulerJob::TrackersDigest)?;
+
+        Ok(job)
+    }
+
+    /// Executes a `TrackersDigestJob` job.
+    async fn execute<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        _: JobScheduler,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let execute_start = Instant::now();
+        let scheduled_tasks_count = api
+            .scheduler()
+            .run_exclusively(
+                SchedulerJob::TrackersDigest,
+                api.trackers().send_tracker_digests(),
+            )
+            .await
+            .and_then(|scheduled_tasks_count| scheduled_tasks_count.transpose());
+        match scheduled_tasks_count {
+            Ok(None) => {
+                debug!("Tracker digests are being sent by another instance, skipping.");
+            }
+            Ok(Some(scheduled_tasks_count)) if scheduled_tasks_count > 0 => {
+                info!(
+                    "Scheduled {scheduled_tasks_count} tracker digest tasks ({} elapsed).",
+                    humantime::format_duration(execute_start.elapsed())
+                );
+            }
+            Ok(Some(_)) => {
+                trace!(
+                    "No tracker digests to send ({} elapsed).",
+                    humantime::format_duration(execute_start.elapsed())
+                );
+            }
+            Err(err) => {
+                error!(
+                    "Failed to send tracker digests ({} elapsed): {err:?}",
+                    humantime::format_duration(execute_start.elapsed())
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackersDigestJob;
+    use crate::{
+        scheduler::scheduler_job::SchedulerJob,
+        tasks::{EmailContent, EmailTaskType, EmailTemplate, TaskType},
+        tests::{
+            mock_api_with_config, mock_config, mock_schedule_in_sec, mock_scheduler,
+            mock_scheduler_job, TrackerCreateParamsBuilder,
+        },
+        trackers::TrackerDigestNotification,
+    };
+    use futures::StreamExt;
+    use insta::assert_debug_snapshot;
+    use sqlx::PgPool;
+    use std::{sync::Arc, time::Duration};
+    use time::OffsetDateTime;
+    use uuid::{uuid, Uuid};
+
+    #[sqlx::test]
+    async fn can_create_job_with_correct_parameters(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.trackers_digest = "1/5 * * * * *".to_string();
+
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mut job = TrackersDigestJob::create(Arc::new(api)).await?;
+        let job_data = job
+            .job_data()
+            .map(|job_data| (job_data.job_type, job_data.extra, job_data.job))?;
+        assert_debug_snapshot!(job_data, @r###"
+        (
+            0,
+            [
+                4,
+                0,
+            ],
+            Some(
+                CronJob(
+                    CronJob {
+                        schedule: "1/5 * * * * *",
+                    },
+                ),
+            ),
+        )
+        "###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_resume_job(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.trackers_digest = "0 0 * * * *".to_string();
+
+        let api = mock_api_with_config(pool, config).await?;
+
+        let job_id = uuid!("00000000-0000-0000-0000-000000000000");
+
+        let job = TrackersDigestJob::try_resume(
+            Arc::new(api),
+            mock_scheduler_job(job_id, SchedulerJob::TrackersDigest, "0 0 * * * *"),
+        )
+        .await?;
+        let job_data = job
+            .and_then(|mut job| job.job_data().ok())
+            .map(|job_data| (job_data.job_type, job_data.extra, job_data.job));
+        assert_debug_snapshot!(job_data, @r###"
+        Some(
+            (
+                3,
+                [
+                    4,
+                    0,
+                ],
+                Some(
+                    CronJob(
+                        CronJob {
+                            schedule: "0 0 * * * *",
+                        },
+                    ),
+                ),
+            ),
+        )
+        "###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_send_tracker_digests(pool: PgPool) -> anyhow::Result<()> {
+        let mut scheduler = mock_scheduler(&pool).await?;
+
+        let mut config = mock_config()?;
+        config.scheduler.trackers_digest = mock_schedule_in_sec(2);
+
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+        let tracker = api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("tracker").build())
+            .await?;
+
+        let notification = |content: &str, send_at: i64| -> anyhow::Result<_> {
+            Ok(TrackerDigestNotification {
+                id: Uuid::now_v7(),
+                tracker_id: tracker.id,
+                tracker_name: tracker.name.clone(),
+                tag: None,
+                task_type: TaskType::Email(EmailTaskType {
+                    to: vec!["dev@retrack.dev".to_string()],
+                    content: EmailContent::Template(EmailTemplate::TrackerChanges {
+                        tracker_name: tracker.name.clone(),
+                        content: Ok(content.to_string()),
+                    }),
+                    timeout: None,
+                    attachments: None,
+                }),
+                send_at: OffsetDateTime::from_unix_timestamp(send_at)?,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            })
+        };
+
+        // The last notification isn't due yet.
+        let trackers = api.db.trackers();
+        trackers
+            .insert_tracker_digest_notification(&notification("one", 946724400)?)
+            .await?;
+        trackers
+            .insert_tracker_digest_notification(&notification("two", 946724400)?)
+            .await?;
+        trackers
+            .insert_tracker_digest_notification(&notification("three", 32503680000)?)
+            .await?;
+
+        scheduler
+            .add(TrackersDigestJob::create(api.clone()).await?)
+            .await?;
+
+        // Start scheduler and wait until the due notifications are sent.
+        scheduler.start().await?;
+
+        while trackers.get_tracker_digest_notifications().await?.len() > 1 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        scheduler.shutdown().await?;
+
+        let tasks = api.db.get_tasks(10).collect::<Vec<_>>().await;
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(
+            tasks[0].as_ref().unwrap().task_type,
+            TaskType::Email(EmailTaskType {
+                to: vec!["dev@retrack.dev".to_string()],
+                content: EmailContent::Template(EmailTemplate::TrackersDigest {
+                    tag: None,
+                    changes: vec![
+                        ("tracker".to_string(), "one".to_string()),
+                        ("tracker".to_string(), "two".to_string()),
+                    ],
+                }),
+                timeout: None,
+                attachments: None,
+            })
+        );
+
+        let notifications = trackers.get_tracker_digest_notifications().await?;
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0].send_at,
+            OffsetDateTime::from_unix_timestamp(32503680000)?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+    scheduler::{
+        database_ext::RawSchedulerJobStoredData, job_ext::JobExt, scheduler_job::SchedulerJob,
+        CronExt,
+    },
+};
+use anyhow::Context;
+use croner::Cron;
+use std::{sync::Arc, time::Instant};
+use tokio_cron_scheduler::{Job, JobScheduler};
+use tracing::{debug, error, info, trace};
+
+/// The job run on a regular interval to remove tracker data revisions that exceeded their max age.
+pub(crate) struct TrackersRetentionJob;
+impl TrackersRetentionJob {
+    /// Tries to resume existing `TrackersRetentionJob` job.
+    pub async fn try_resume<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        existing_job_data: RawSchedulerJobStoredData,
+    ) -> anyhow::Result<Option<Job>>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // If the schedule has changed, remove existing job and create a new one.
+        let mut new_job = Self::create(api).await?;
+        Ok(if new_job.are_schedules_equal(&existing_job_data)? {
+            new_job.set_raw_job_data(existing_job_data)?;
+            Some(new_job)
+        } else {
+            None
+        })
+    }
+
+    /// Creates a new `TrackersRetentionJob` job.
+    pub async fn create<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+    ) -> anyhow::Result<Job>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let mut job = Job::new_async(
+            Cron::parse_pattern(&api.config.scheduler.trackers_retention)
+                .with_context(|| {
+                    format!(
+                        "Cannot parse `trackers_retention` schedule: {}",
+                        api.config.scheduler.trackers_retention
+                    )
+                })?
+                .pattern
+                .to_string(),
+            move |_, scheduler| {
+                let api = api.clone();
+                Box::pin(async move {
+                    if let Err(err) = Self::execute(api, scheduler).await {
+                        error!("Failed to execute trackers retention job: {err:?}");
+                    }
+                })
+            },
+        )?;
+
+        job.set_job_type(SchedulerJob::TrackersRetention)?;
+
+        Ok(job)
+    }
+
+    /// Executes a `TrackersRetentionJob` job.
+    async fn execute<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        _: JobScheduler,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let execute_start = Instant::now();
+        let removed_revisions_count = api
+            .scheduler()
+            .run_exclusively(
+                SchedulerJob::TrackersRetention,
+                api.trackers().remove_expired_trackers_data_revisions(),
+            )
+            .await
+            .and_then(|removed_revisions_count| removed_revisions_count.transpose());
+        match removed_revisions_count {
+            Ok(None) => {
+                debug!(
+                    "Expired tracker revisions are being removed by another instance, skipping."
+                );
+            }
+            Ok(Some(removed_revisions_count)) if removed_revisions_count > 0 => {
+                info!(
+                    "Removed {removed_revisions_count} expired tracker revisions ({} elapsed).",
+                    humantime::format_duration(execute_start.elapsed())
+                );
+            }
+            Ok(Some(_)) => {
+                trace!(
+                    "No expired tracker revisions to remove ({} elapsed).",
+                    humantime::format_duration(execute_start.elapsed())
+                );
+            }
+            Err(err) => {
+                error!(
+                    "Failed to remove expired tracker revisions ({} elapsed): {err:?}",
+                    humantime::format_duration(execute_start.elapsed())
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackersRetentionJob;
+    use crate::{
+        scheduler::scheduler_job::SchedulerJob,
+        tests::{
+            mock_api_with_config, mock_config, mock_schedule_in_sec, mock_scheduler,
+            mock_scheduler_job, TrackerCreateParamsBuilder,
+        },
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerConfig, TrackerDataRevision, TrackerDataValue};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::{sync::Arc, time::Duration};
+    use time::OffsetDateTime;
+    use uuid::{uuid, Uuid};
+
+    #[sqlx::test]
+    async fn can_create_job_with_correct_parameters(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.trackers_retention = "1/5 * * * * *".to_string();
+
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mut job = TrackersRetentionJob::create(Arc::new(api)).await?;
+        let job_data = job
+            .job_data()
+            .map(|job_data| (job_data.job_type, job_data.extra, job_data.job))?;
+        assert_debug_snapshot!(job_data, @r###"
+        (
+            0,
+            [
+                5,
+                0,
+            ],
+            Some(
+                CronJob(
+                    CronJob {
+                        schedule: "1/5 * * * * *",
+                    },
+                ),
+            ),
+        )
+        "###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_resume_job(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.trackers_retention = "0 0 * * * *".to_string();
+
+        let api = mock_api_with_config(pool, config).await?;
+
+        let job_id = uuid!("00000000-0000-0000-0000-000000000000");
+
+        let job = TrackersRetentionJob::try_resume(
+            Arc::new(api),
+            mock_scheduler_job(job_id, SchedulerJob::TrackersRetention, "0 0 * * * *"),
+        )
+        .await?;
+        let job_data = job
+            .and_then(|mut job| job.job_data().ok())
+            .map(|job_data| (job_data.job_type, job_data.extra, job_data.job));
+        assert_debug_snapshot!(job_data, @r###"
+        Some(
+            (
+                3,
+                [
+                    5,
+                    0,
+                ],
+                Some(
+                    CronJob(
+                        CronJob {
+                            schedule: "0 0 * * * *",
+                        },
+                    ),
+                ),
+            ),
+        )
+        "###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_remove_expired_tracker_revisions(pool: PgPool) -> anyhow::Result<()> {
+        let mut scheduler = mock_scheduler(&pool).await?;
+
+        let mut config = mock_config()?;
+        config.scheduler.trackers_retention = mock_schedule_in_sec(2);
+
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+        let tracker = api
+            .trackers()
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("tracker")
+                    .with_config(TrackerConfig {
+                        max_revision_age: Some(Duration::from_secs(24 * 3600)),
+                        ..Default::default()
+                    })
+                    .build(),
+            )
+            .await?;
+
+        // The first revision is expired, and the second one isn't.
+        let now = OffsetDateTime::now_utc();
+        let trackers = api.db.trackers();
+        for age in [Duration::from_secs(48 * 3600), Duration::from_secs(3600)] {
+            trackers
+                .insert_tracker_data_revision(&TrackerDataRevision {
+                    id: Uuid::now_v7(),
+                    tracker_id: tracker.id,
+                    data: TrackerDataValue::new(json!({ "age": age.as_secs() })),
+                    created_at: now - age,
+                    provenance: None,
+                    sequence: None,
+                })
+                .await?;
+        }
+
+        scheduler
+            .add(TrackersRetentionJob::create(api.clone()).await?)
+            .await?;
+
+        // Start scheduler and wait until the expired revision is removed.
+        scheduler.start().await?;
+
+        while trackers.get_tracker_data(tracker.id).await?.len() > 1 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        scheduler.shutdown().await?;
+
+        let revisions = trackers.get_tracker_data(tracker.id).await?;
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].data.value(), &json!({ "age": 3600 }));
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     api::Api,
+    database::Database,
     error::Error as RetrackError,
     network::{DnsResolver, EmailTransport, EmailTransportError},
     scheduler::{
@@ -7,15 +8,16 @@ use crate::{
         CronExt,
     },
     tasks::{EmailContent, EmailTaskType, EmailTemplate, TaskType},
+    trackers::TrackerAlertsGroup,
 };
-use anyhow::Context;
+use anyhow::{bail, Context};
 use croner::Cron;
 use futures::{pin_mut, StreamExt};
-use retrack_types::trackers::Tracker;
+use retrack_types::trackers::{Tracker, TrackerTarget};
 use std::{sync::Arc, time::Instant};
 use time::OffsetDateTime;
 use tokio_cron_scheduler::{Job, JobScheduler};
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 /// The job executes every minute by default to check if there are any trackers to run.
@@ -79,7 +81,15 @@ impl TrackersRunJob {
     where
         ET::Error: EmailTransportError,
     {
-        Self::run(api, scheduler).await?;
+        let is_executed = api
+            .scheduler()
+            .run_exclusively(SchedulerJob::TrackersRun, Self::run(api.clone(), scheduler))
+            .await?
+            .transpose()?
+            .is_some();
+        if !is_executed {
+            debug!("Trackers are being run by another instance, skipping.");
+        }
 
         Ok(())
     }
@@ -96,6 +106,9 @@ impl TrackersRunJob {
         let pending_trackers = trackers.get_trackers_to_run();
         pin_mut!(pending_trackers);
 
+        // Trackers with composite target are deferred until all other pending trackers are run,
+        // so that they use the fresh data of their input trackers.
+        let mut composite_trackers = vec![];
         while let Some(tracker) = pending_trackers.next().await {
             let Some((tracker, job_id)) =
                 Self::validate_tracker(&api, &scheduler, tracker?).await?
@@ -103,70 +116,114 @@ impl TrackersRunJob {
                 continue;
             };
 
-            // Try to create a new revision. If a revision is returned that means that tracker
-            // detected changes.
-            let run_start = Instant::now();
-            let new_revision = match api
-                .trackers()
-                .create_tracker_data_revision(tracker.id)
-                .await
-            {
-                Ok(new_revision) => new_revision,
-                Err(err) => {
-                    let execution_time = run_start.elapsed();
-                    error!(
-                        tracker.id = %tracker.id,
-                        tracker.name = tracker.name,
-                        metrics.job_execution_time = execution_time.as_nanos() as u64,
-                        "Failed to create tracker data revision: {err:?}"
-                    );
+            if matches!(tracker.target, TrackerTarget::Composite(_)) {
+                composite_trackers.push((tracker, job_id));
+            } else {
+                Self::run_tracker(&api, tracker, job_id).await?;
+            }
+        }
 
-                    // Check if the tracker has a retry strategy.
-                    let retry_strategy = tracker
-                        .config
-                        .job
-                        .as_ref()
-                        .and_then(|job_config| job_config.retry_strategy);
-                    let retry_state = if let Some(retry_strategy) = retry_strategy {
-                        api.scheduler()
-                            .schedule_retry(job_id, &retry_strategy)
-                            .await?
-                    } else {
-                        None
-                    };
+        for (tracker, job_id) in composite_trackers {
+            Self::run_tracker(&api, tracker, job_id).await?;
+        }
 
-                    if let Some(retry) = retry_state {
-                        warn!(
-                            tracker.id = %tracker.id,
-                            tracker.name = tracker.name,
-                            metrics.job_retries = retry.attempts,
-                            "Scheduled a retry to create tracker data revision at {}.",
-                            retry.next_at,
-                        );
-                    } else {
-                        // Re-schedule the job.
-                        api.db.reset_scheduler_job_state(job_id, false).await?;
-
-                        // Report the error.
-                        Self::report_error(&api, tracker, err).await;
-                    }
+        // Report failures that have been waiting for correlation long enough.
+        Self::report_correlated_errors(&api).await;
 
-                    continue;
-                }
-            };
+        Ok(())
+    }
 
-            let execution_time = run_start.elapsed();
-            info!(
+    async fn run_tracker<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tracker: Tracker,
+        job_id: Uuid,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Skip the run if the tracker isn't active at the moment, it will be triggered again
+        // according to its schedule.
+        if !tracker.config.is_active_at(OffsetDateTime::now_utc()) {
+            debug!(
                 tracker.id = %tracker.id,
                 tracker.name = tracker.name,
-                metrics.job_execution_time = execution_time.as_nanos() as u64,
-                metrics.tracker_data_size = new_revision.data.size(),
-                "Successfully checked tracker data."
+                "Tracker is outside of its active hours or days, skipping."
             );
-
             api.db.reset_scheduler_job_state(job_id, false).await?;
+            return Ok(());
         }
 
+        // Try to create a new revision. If a revision is returned that means that tracker
+        // detected changes.
+        let run_start = Instant::now();
+        let new_revision = match api
+            .trackers()
+            .create_tracker_data_revision(tracker.id)
+            .await
+        {
+            Ok(new_revision) => new_revision,
+            Err(err) => {
+                let execution_time = run_start.elapsed();
+                error!(
+                    tracker.id = %tracker.id,
+                    tracker.name = tracker.name,
+                    metrics.job_execution_time = execution_time.as_nanos() as u64,
+                    "Failed to create tracker data revision: {err:?}"
+                );
+
+                // If the database is temporarily unavailable, don't count it as a tracker
+                // failure: the tracker job stays pending and will be picked up by the next run.
+                if Database::is_transient_error(&err) {
+                    return Err(err.context(
+                        "Database is temporarily unavailable, trackers run is interrupted.",
+                    ));
+                }
+
+                // Check if the tracker has a retry strategy.
+                let retry_strategy = tracker
+                    .config
+                    .job
+                    .as_ref()
+                    .and_then(|job_config| job_config.retry_strategy);
+                let retry_state = if let Some(retry_strategy) = retry_strategy {
+                    api.scheduler()
+                        .schedule_retry(job_id, &retry_strategy)
+                        .await?
+                } else {
+                    None
+                };
+
+                if let Some(retry) = retry_state {
+                    warn!(
+                        tracker.id = %tracker.id,
+                        tracker.name = tracker.name,
+                        metrics.job_retries = retry.attempts,
+                        "Scheduled a retry to create tracker data revision at {}.",
+                        retry.next_at,
+                    );
+                } else {
+                    // Re-schedule the job.
+                    api.db.reset_scheduler_job_state(job_id, false).await?;
+
+                    // Report the error.
+                    Self::report_error(api, tracker, err).await;
+                }
+
+                return Ok(());
+            }
+        };
+
+        let execution_time = run_start.elapsed();
+        info!(
+            tracker.id = %tracker.id,
+            tracker.name = tracker.name,
+            metrics.job_execution_time = execution_time.as_nanos() as u64,
+            metrics.tracker_data_size = new_revision.data.size(),
+            "Successfully checked tracker data."
+        );
+
+        api.db.reset_scheduler_job_state(job_id, false).await?;
+
         Ok(())
     }
 
@@ -210,46 +267,143 @@ impl TrackersRunJob {
     ) where
         ET::Error: EmailTransportError,
     {
-        let Some(ref smtp_config) = api.config.smtp else {
-            warn!(
+        let error_message = error
+            .downcast::<RetrackError>()
+            .map(|err| format!("{err}"))
+            .unwrap_or_else(|_| "Unknown error".to_string());
+
+        // If alerts correlation is enabled, failures of the tagged trackers are reported later,
+        // once it's clear whether other trackers with the same tag have failed as well.
+        if api.config.trackers.alerts_correlation.is_some() && !tracker.tags.is_empty() {
+            match api
+                .trackers()
+                .add_tracker_alert(&tracker, error_message.clone())
+                .await
+            {
+                Ok(_) => return,
+                Err(err) => error!(
+                    tracker.id = %tracker.id,
+                    tracker.name = tracker.name,
+                    "Failed to store tracker alert, reporting it immediately: {err:?}"
+                ),
+            }
+        }
+
+        let email_template = EmailTemplate::TrackerChanges {
+            tracker_name: tracker.name.clone(),
+            content: Err(error_message),
+        }
+        .with_runbook_url(
+            api.trackers()
+                .get_tracker_runbook_url(&tracker)
+                .map(String::from),
+        );
+        if let Err(err) = Self::schedule_report_email(api, email_template).await {
+            error!(
                 tracker.id = %tracker.id,
                 tracker.name = tracker.name,
-                "Failed to report failed tracker data check: SMTP configuration is missing."
+                "Failed to report failed tracker data check: {err:?}"
             );
-            return;
+        }
+    }
+
+    /// Reports tracker failures within the elapsed correlation windows, failures of the trackers
+    /// sharing the same tag are reported as a single incident.
+    async fn report_correlated_errors<DR: DnsResolver, ET: EmailTransport>(api: &Api<DR, ET>)
+    where
+        ET::Error: EmailTransportError,
+    {
+        let alerts_groups = match api.trackers().take_tracker_alerts().await {
+            Ok(alerts_groups) => alerts_groups,
+            Err(err) => {
+                error!("Failed to retrieve tracker alerts: {err:?}");
+                return;
+            }
+        };
+
+        for alerts_group in alerts_groups {
+            let email_template = match alerts_group {
+                TrackerAlertsGroup::Single(alert) => {
+                    // Tracker might have been removed or updated since the failure was recorded.
+                    let runbook_url = match api.trackers().get_tracker(alert.tracker_id).await {
+                        Ok(tracker) => tracker
+                            .and_then(|tracker| api.trackers().get_tracker_runbook_url(&tracker)),
+                        Err(err) => {
+                            error!(
+                                tracker.id = %alert.tracker_id,
+                                tracker.name = alert.tracker_name,
+                                "Failed to retrieve tracker to resolve its runbook: {err:?}"
+                            );
+                            None
+                        }
+                    };
+                    EmailTemplate::TrackerChanges {
+                        tracker_name: alert.tracker_name,
+                        content: Err(alert.error),
+                    }
+                    .with_runbook_url(runbook_url.map(String::from))
+                }
+                TrackerAlertsGroup::Incident { tag, alerts } => {
+                    // Report every tracker only once, with its latest error.
+                    let mut trackers: Vec<(String, String)> = vec![];
+                    for alert in alerts {
+                        match trackers
+                            .iter_mut()
+                            .find(|(tracker_name, _)| *tracker_name == alert.tracker_name)
+                        {
+                            Some((_, error)) => *error = alert.error,
+                            None => trackers.push((alert.tracker_name, alert.error)),
+                        }
+                    }
+
+                    warn!(
+                        "Detected an incident: {} trackers tagged \"{tag}\" failed.",
+                        trackers.len()
+                    );
+                    let runbook_url = api
+                        .config
+                        .trackers
+                        .runbook_urls
+                        .get(&tag)
+                        .map(|runbook_url| runbook_url.to_string());
+                    EmailTemplate::TrackersIncident { tag, trackers }.with_runbook_url(runbook_url)
+                }
+            };
+
+            if let Err(err) = Self::schedule_report_email(api, email_template).await {
+                error!("Failed to report failed tracker data checks: {err:?}");
+            }
+        }
+    }
+
+    /// Schedules an email with the specified template to the catch-all recipient.
+    async fn schedule_report_email<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        email_template: EmailTemplate,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let Some(ref smtp_config) = api.config.smtp else {
+            bail!("SMTP configuration is missing.");
         };
 
         let Some(ref catch_all_recipient) = smtp_config.catch_all else {
-            warn!(
-                tracker.id = %tracker.id,
-                tracker.name = tracker.name,
-                "Failed to report failed tracker data check: catch-all recipient is missing."
-            );
-            return;
+            bail!("Catch-all recipient is missing.");
         };
 
         let email_task = TaskType::Email(EmailTaskType {
             to: vec![catch_all_recipient.recipient.clone()],
-            content: EmailContent::Template(EmailTemplate::TrackerChanges {
-                tracker_name: tracker.name.clone(),
-                content: Err(error
-                    .downcast::<RetrackError>()
-                    .map(|err| format!("{err}"))
-                    .unwrap_or_else(|_| "Unknown error".to_string())),
-            }),
+            content: EmailContent::Template(email_template),
+            timeout: None,
+            attachments: None,
         });
 
-        let tasks_schedule_result = api
-            .tasks()
+        api.tasks()
             .schedule_task(email_task, OffsetDateTime::now_utc())
-            .await;
-        if let Err(err) = tasks_schedule_result {
-            error!(
-                tracker.id = %tracker.id,
-                tracker.name = tracker.name,
-                "Failed to report failed tracker data check: {err:?}."
-            );
-        }
+            .await?;
+
+        Ok(())
     }
 }
 
@@ -257,14 +411,17 @@ impl TrackersRunJob {
 mod tests {
     use super::TrackersRunJob;
     use crate::{
-        config::SmtpConfig,
+        config::{SmtpConfig, TrackerAlertsCorrelation},
+        error::Error as RetrackError,
         scheduler::{scheduler_job::SchedulerJob, scheduler_jobs::TrackersTriggerJob},
         tests::{
             mock_api_with_config, mock_config, mock_get_scheduler_job, mock_schedule_in_sec,
             mock_schedule_in_secs, mock_scheduler, mock_scheduler_job, SmtpCatchAllConfig,
             TrackerCreateParamsBuilder, WebScraperContentRequest, WebScraperErrorResponse,
         },
+        trackers::TrackerAlert,
     };
+    use anyhow::anyhow;
     use futures::StreamExt;
     use httpmock::MockServer;
     use insta::assert_debug_snapshot;
@@ -272,13 +429,18 @@ mod tests {
     use retrack_types::{
         scheduler::{SchedulerJobConfig, SchedulerJobRetryStrategy},
         trackers::{
-            EmailAction, PageTarget, Tracker, TrackerAction, TrackerConfig, TrackerDataRevision,
-            TrackerDataValue, TrackerTarget,
+            EmailAction, PageTarget, Tracker, TrackerAction, TrackerActiveHours, TrackerConfig,
+            TrackerDataRevision, TrackerDataValue, TrackerTarget, TrackerUpdateParams,
         },
     };
     use serde_json::json;
     use sqlx::PgPool;
-    use std::{default::Default, ops::Add, sync::Arc, time::Duration};
+    use std::{
+        default::Default,
+        ops::{Add, Sub},
+        sync::Arc,
+        time::Duration,
+    };
     use time::OffsetDateTime;
     use url::Url;
     use uuid::{uuid, Uuid};
@@ -479,11 +641,16 @@ mod tests {
             id: Uuid::now_v7(),
             name: "tracker".to_string(),
             enabled: true,
+            archived: false,
             target: TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: None,
                 user_agent: Some("Retrack/1.0.0".parse()?),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 1,
@@ -492,6 +659,25 @@ mod tests {
                     schedule: tracker_schedule,
                     retry_strategy: None
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             },
             actions: vec![TrackerAction::ServerLog],
             tags: vec![],
@@ -503,6 +689,9 @@ mod tests {
             updated_at: OffsetDateTime::from_unix_timestamp(
                 OffsetDateTime::now_utc().unix_timestamp(),
             )?,
+            shape_deviation: None,
+            state: None,
+            stats: None,
         };
 
         // Insert tracker directly to DB to bypass schedule validation.
@@ -564,6 +753,102 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn skips_run_outside_active_hours(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        let server = MockServer::start();
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let scheduler = mock_scheduler(&pool).await?;
+
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+
+        // Create tracker that isn't active within the next couple of hours.
+        let current_hour = OffsetDateTime::now_utc().hour();
+        let trigger_job_id = scheduler
+            .add(TrackersTriggerJob::create(api.clone(), "0 0 * * * *").await?)
+            .await?;
+        let trackers = api.trackers();
+        let tracker = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("tracker")
+                    .with_config(TrackerConfig {
+                        active_hours: Some(TrackerActiveHours {
+                            start: (current_hour + 2) % 24,
+                            end: (current_hour + 4) % 24,
+                        }),
+                        ..Default::default()
+                    })
+                    .with_schedule("0 0 * * * *")
+                    .build(),
+            )
+            .await?;
+        trackers
+            .update_tracker_job(tracker.id, Some(trigger_job_id))
+            .await?;
+
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/execute");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body(json!("some-content"));
+        });
+
+        // Mark tracker job as pending and run trackers.
+        api.db
+            .reset_scheduler_job_state(trigger_job_id, true)
+            .await?;
+        TrackersRunJob::run(api.clone(), scheduler.clone()).await?;
+
+        content_mock.assert_hits(0);
+        assert!(trackers
+            .get_tracker_data(tracker.id, Default::default())
+            .await?
+            .is_empty());
+
+        // Check that the tracker job was marked as NOT stopped.
+        let trigger_job = mock_get_scheduler_job(&api.db, trigger_job_id).await?;
+        assert_eq!(
+            trigger_job.map(|job| (job.id, job.stopped)),
+            Some((trigger_job_id, Some(false)))
+        );
+
+        // Make tracker active and run trackers again.
+        trackers
+            .update_tracker(
+                tracker.id,
+                TrackerUpdateParams {
+                    config: Some(TrackerConfig {
+                        active_hours: Some(TrackerActiveHours {
+                            start: current_hour,
+                            end: (current_hour + 2) % 24,
+                        }),
+                        ..tracker.config.clone()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        api.db
+            .reset_scheduler_job_state(trigger_job_id, true)
+            .await?;
+        TrackersRunJob::run(api.clone(), scheduler.clone()).await?;
+
+        content_mock.assert_hits(1);
+        assert_eq!(
+            trackers
+                .get_tracker_data(tracker.id, Default::default())
+                .await?
+                .into_iter()
+                .map(|rev| rev.data)
+                .collect::<Vec<_>>(),
+            vec![TrackerDataValue::new(json!("some-content"))]
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn schedules_task_when_content_change(pool: PgPool) -> anyhow::Result<()> {
         let mut config = mock_config()?;
@@ -587,11 +872,16 @@ mod tests {
             id: Uuid::now_v7(),
             name: "tracker-one".to_string(),
             enabled: true,
+            archived: false,
             target: TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: None,
                 user_agent: Some("Retrack/1.0.0".parse()?),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 2,
@@ -600,10 +890,34 @@ mod tests {
                     schedule: tracker_schedule,
                     retry_strategy: None
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             },
             actions: vec![
                 TrackerAction::ServerLog,
-                TrackerAction::Email(EmailAction { to: vec!["dev@retrack.dev".to_string()] })
+                TrackerAction::Email(EmailAction {
+                    to: vec!["dev@retrack.dev".to_string()],
+                    timeout: None,
+                    template: None,
+                    attachment: None,
+                })
             ],
             tags: vec![],
             job_id: Some(trigger_job_id),
@@ -614,6 +928,9 @@ mod tests {
             updated_at: OffsetDateTime::from_unix_timestamp(
                 OffsetDateTime::now_utc().unix_timestamp(),
             )?,
+            shape_deviation: None,
+            state: None,
+            stats: None,
         };
 
         // Insert tracker directly to DB to bypass schedule validation.
@@ -625,6 +942,8 @@ mod tests {
                 tracker_id: tracker.id,
                 created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 data: TrackerDataValue::new(json!("some-content")),
+                provenance: None,
+                sequence: None,
             })
             .await?;
 
@@ -697,6 +1016,8 @@ mod tests {
                             ),
                         },
                     ),
+                    timeout: None,
+                    attachments: None,
                 },
             ),
         )
@@ -748,11 +1069,16 @@ mod tests {
             id: Uuid::now_v7(),
             name: "tracker-one".to_string(),
             enabled: true,
+            archived: false,
             target: TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: None,
                 user_agent: Some("Retrack/1.0.0".parse()?),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 2,
@@ -761,6 +1087,25 @@ mod tests {
                     schedule: tracker_schedule,
                     retry_strategy: None
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             },
             tags: vec![],
             actions: vec![TrackerAction::ServerLog],
@@ -772,6 +1117,9 @@ mod tests {
             updated_at: OffsetDateTime::from_unix_timestamp(
                 OffsetDateTime::now_utc().unix_timestamp(),
             )?,
+            shape_deviation: None,
+            state: None,
+            stats: None,
         };
 
         // Insert tracker directly to DB to bypass schedule validation.
@@ -783,6 +1131,8 @@ mod tests {
                 tracker_id: tracker.id,
                 created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 data: TrackerDataValue::new(json!("some-content")),
+                provenance: None,
+                sequence: None,
             })
             .await?;
 
@@ -855,6 +1205,8 @@ mod tests {
                             ),
                         },
                     ),
+                    timeout: None,
+                    attachments: None,
                 },
             ),
         )
@@ -905,11 +1257,16 @@ mod tests {
             id: Uuid::now_v7(),
             name: "tracker-one".to_string(),
             enabled: true,
+            archived: false,
             target: TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: None,
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 2,
@@ -921,6 +1278,25 @@ mod tests {
                         max_attempts: 1,
                     })
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             },
             actions: vec![TrackerAction::ServerLog],
             tags: vec![],
@@ -933,6 +1309,9 @@ mod tests {
             updated_at: OffsetDateTime::from_unix_timestamp(
                 OffsetDateTime::now_utc().unix_timestamp(),
             )?,
+            shape_deviation: None,
+            state: None,
+            stats: None,
         };
 
         // Insert tracker directly to DB to bypass schedule validation.
@@ -944,6 +1323,8 @@ mod tests {
                 tracker_id: tracker.id,
                 created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 data: TrackerDataValue::new(json!("some-content")),
+                provenance: None,
+                sequence: None,
             })
             .await?;
 
@@ -1049,6 +1430,8 @@ mod tests {
                             ),
                         },
                     ),
+                    timeout: None,
+                    attachments: None,
                 },
             ),
         )
@@ -1094,11 +1477,16 @@ mod tests {
             id: Uuid::now_v7(),
             name: "tracker-one".to_string(),
             enabled: true,
+            archived: false,
             target: TrackerTarget::Page(PageTarget {
                 extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
                 params: None,
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             }),
             config: TrackerConfig {
                 revisions: 2,
@@ -1110,11 +1498,35 @@ mod tests {
                         max_attempts: 1,
                     })
                 }),
+                fail_on_shape_deviation: false,
+                change_filter: None,
+                semantic_filter: None,
+                skip_first_revision_actions: false,
+                confirmations: None,
+                active_hours: None,
+                active_days: None,
+                max_content_size: None,
+                action_concurrency: None,
+                action_dependencies: None,
+                action_rules: None,
+                severity_routing: None,
+                action_throttles: None,
+                action_digests: None,
+                workflow: None,
+                escalation: None,
+                quarantine: None,
+                runbook_url: None,
+                max_revision_age: None,
             },
             tags: vec![],
             actions: vec![
                 TrackerAction::ServerLog,
-                TrackerAction::Email(EmailAction { to: vec!["dev@retrack.dev".to_string()] })
+                TrackerAction::Email(EmailAction {
+                    to: vec!["dev@retrack.dev".to_string()],
+                    timeout: None,
+                    template: None,
+                    attachment: None,
+                })
             ],
             job_id: Some(trigger_job_id),
             // Preserve timestamp only up to seconds.
@@ -1124,6 +1536,9 @@ mod tests {
             updated_at: OffsetDateTime::from_unix_timestamp(
                 OffsetDateTime::now_utc().unix_timestamp(),
             )?,
+            shape_deviation: None,
+            state: None,
+            stats: None,
         };
 
         // Insert tracker directly to DB to bypass schedule validation.
@@ -1135,6 +1550,8 @@ mod tests {
                 tracker_id: tracker.id,
                 created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 data: TrackerDataValue::new(json!("some-content")),
+                provenance: None,
+                sequence: None,
             })
             .await?;
 
@@ -1261,6 +1678,8 @@ mod tests {
                             ),
                         },
                     ),
+                    timeout: None,
+                    attachments: None,
                 },
             ),
         )
@@ -1280,4 +1699,152 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn reports_correlated_errors_as_incident(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.smtp = config.smtp.map(|config| SmtpConfig {
+            catch_all: Some(SmtpCatchAllConfig {
+                recipient: "dev@retrack.dev".to_string(),
+                text_matcher: Regex::new(r"alpha").unwrap(),
+            }),
+            ..config
+        });
+        config.trackers.alerts_correlation = Some(TrackerAlertsCorrelation {
+            window: Duration::from_secs(600),
+            min_trackers: 2,
+        });
+
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mut trackers = vec![];
+        for (name, tags) in [
+            ("tracker-one", vec!["vendor".to_string()]),
+            ("tracker-two", vec!["vendor".to_string()]),
+            ("tracker-three", vec![]),
+        ] {
+            trackers.push(
+                api.trackers()
+                    .create_tracker(
+                        TrackerCreateParamsBuilder::new(name)
+                            .with_tags(tags)
+                            .build(),
+                    )
+                    .await?,
+            );
+        }
+
+        for tracker in trackers {
+            let error = anyhow!(RetrackError::client(format!("{} failed", tracker.name)));
+            TrackersRunJob::report_error(&api, tracker, error).await;
+        }
+
+        // Failure of the tracker without tags is reported immediately.
+        let tasks_ids = api
+            .db
+            .get_tasks_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(tasks_ids.len(), 1);
+        let task = api.db.get_task(*tasks_ids[0].as_ref().unwrap()).await?;
+        assert_debug_snapshot!(task.map(|task| task.task_type), @r###"
+        Some(
+            Email(
+                EmailTaskType {
+                    to: [
+                        "dev@retrack.dev",
+                    ],
+                    content: Template(
+                        TrackerChanges {
+                            tracker_name: "tracker-three",
+                            content: Err(
+                                "tracker-three failed",
+                            ),
+                        },
+                    ),
+                    timeout: None,
+                    attachments: None,
+                },
+            ),
+        )
+        "###);
+
+        // Failures of the tagged trackers wait until the correlation window elapses.
+        TrackersRunJob::report_correlated_errors(&api).await;
+        let alerts = api.db.trackers().get_tracker_alerts().await?;
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(
+            api.db
+                .get_tasks_ids(
+                    OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                    10,
+                )
+                .collect::<Vec<_>>()
+                .await
+                .len(),
+            1
+        );
+
+        // Move alerts back in time, as if the correlation window has elapsed.
+        api.db
+            .trackers()
+            .remove_tracker_alerts(&alerts.iter().map(|alert| alert.id).collect::<Vec<_>>())
+            .await?;
+        for alert in alerts {
+            api.db
+                .trackers()
+                .insert_tracker_alert(&TrackerAlert {
+                    created_at: alert.created_at.sub(Duration::from_secs(3600)),
+                    ..alert
+                })
+                .await?;
+        }
+
+        TrackersRunJob::report_correlated_errors(&api).await;
+        assert!(api.db.trackers().get_tracker_alerts().await?.is_empty());
+
+        let tasks_ids = api
+            .db
+            .get_tasks_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(tasks_ids.len(), 2);
+        let task = api.db.get_task(*tasks_ids[1].as_ref().unwrap()).await?;
+        assert_debug_snapshot!(task.map(|task| task.task_type), @r###"
+        Some(
+            Email(
+                EmailTaskType {
+                    to: [
+                        "dev@retrack.dev",
+                    ],
+                    content: Template(
+                        TrackersIncident {
+                            tag: "vendor",
+                            trackers: [
+                                (
+                                    "tracker-one",
+                                    "tracker-one failed",
+                                ),
+                                (
+                                    "tracker-two",
+                                    "tracker-two failed",
+                                ),
+                            ],
+                        },
+                    ),
+                    timeout: None,
+                    attachments: None,
+                },
+            ),
+        )
+        "###);
+
+        Ok(())
+    }
 }
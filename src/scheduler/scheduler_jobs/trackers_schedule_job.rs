@@ -74,13 +74,23 @@ impl TrackersScheduleJob {
     where
         ET::Error: EmailTransportError,
     {
-        let trackers = api.trackers();
-        Self::schedule_trackers(
-            api.clone(),
-            &scheduler,
-            trackers.get_trackers_to_schedule().await?,
-        )
-        .await?;
+        let is_executed = api
+            .scheduler()
+            .run_exclusively(SchedulerJob::TrackersSchedule, async {
+                let trackers = api.trackers();
+                Self::schedule_trackers(
+                    api.clone(),
+                    &scheduler,
+                    trackers.get_trackers_to_schedule().await?,
+                )
+                .await
+            })
+            .await?
+            .transpose()?
+            .is_some();
+        if !is_executed {
+            debug!("Trackers are being scheduled by another instance, skipping.");
+        }
 
         Ok(())
     }
@@ -0,0 +1,5 @@
+mod api_ext;
+mod database_ext;
+mod search_content;
+
+pub use self::search_content::search_content;
@@ -0,0 +1,380 @@
+use crate::{
+    api::Api,
+    error::Error as RetrackError,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+    search::search_content,
+};
+use anyhow::bail;
+use retrack_types::{
+    search::{SearchMatch, SearchParams},
+    trackers::TrackersListParams,
+};
+use tracing::debug;
+
+/// Defines the maximum length of the full-text search query.
+const MAX_SEARCH_QUERY_LENGTH: usize = 200;
+
+/// Defines the number of matching revisions returned by default.
+const DEFAULT_SEARCH_LIMIT: usize = 100;
+
+/// Defines the maximum number of matching revisions that can be returned at once.
+const MAX_SEARCH_LIMIT: usize = 1000;
+
+/// Defines the number of tracker data revisions indexed for the full-text search at once.
+const INDEX_BATCH_SIZE: usize = 100;
+
+/// Describes the API to search tracker data revisions.
+pub struct SearchApi<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> SearchApi<'a, DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Creates Search API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Searches the data revisions of all trackers, or only trackers with the specified tags, for
+    /// the specified query (e.g., to find every tracker whose data mentions a specific CVE).
+    pub async fn search(&self, params: SearchParams) -> anyhow::Result<Vec<SearchMatch>> {
+        let query = params.query.trim();
+        if query.is_empty() || query.len() > MAX_SEARCH_QUERY_LENGTH {
+            bail!(RetrackError::client(format!(
+                "Search query cannot be empty or longer than {MAX_SEARCH_QUERY_LENGTH} characters."
+            )));
+        }
+
+        let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        if limit == 0 || limit > MAX_SEARCH_LIMIT {
+            bail!(RetrackError::client(format!(
+                "Search limit should be between 1 and {MAX_SEARCH_LIMIT}."
+            )));
+        }
+
+        let tags = self
+            .api
+            .trackers()
+            .normalize_trackers_list_params(TrackersListParams {
+                tags: params.tags,
+                ..Default::default()
+            })?
+            .tags;
+        self.api
+            .db
+            .search_trackers_data(query, &tags, params.since, params.until, limit)
+            .await
+    }
+
+    /// Populates the text content used for the full-text search for the tracker data revisions
+    /// that don't have it yet (e.g., persisted before the search was introduced). Returns the
+    /// number of indexed revisions.
+    pub async fn index_trackers_data(&self) -> anyhow::Result<u64> {
+        let mut indexed_revisions = 0;
+        loop {
+            let revisions = self
+                .api
+                .db
+                .trackers()
+                .get_tracker_data_without_content(INDEX_BATCH_SIZE)
+                .await?;
+            if revisions.is_empty() {
+                break;
+            }
+
+            for revision in revisions {
+                self.api
+                    .db
+                    .update_tracker_data_content(
+                        revision.tracker_id,
+                        revision.id,
+                        &search_content(revision.data.value()),
+                    )
+                    .await?;
+                indexed_revisions += 1;
+            }
+
+            debug!("Indexed {indexed_revisions} tracker data revisions for the full-text search.");
+        }
+
+        Ok(indexed_revisions)
+    }
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Returns an API to search tracker data revisions.
+    pub fn search(&self) -> SearchApi<'_, DR, ET> {
+        SearchApi::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error as RetrackError,
+        tests::{mock_api, TrackerCreateParamsBuilder},
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::{
+        search::SearchParams,
+        trackers::{TrackerDataRevision, TrackerDataValue},
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::{uuid, Uuid};
+
+    #[sqlx::test]
+    async fn properly_searches_trackers_data(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let trackers = api.trackers();
+        let tracker_one = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("tracker_one")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+        let tracker_two = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("tracker_two")
+                    .with_tags(vec!["env:staging".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        for (id, tracker_id, data, created_at) in [
+            (
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_one.id,
+                json!("Fixes CVE-2024-1234 in parser"),
+                946720800,
+            ),
+            (
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                tracker_one.id,
+                json!({ "notes": "Update nginx to 1.27" }),
+                946720900,
+            ),
+            (
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                tracker_two.id,
+                json!(["Backport of CVE-2024-1234 fix"]),
+                946721000,
+            ),
+            (
+                uuid!("00000000-0000-0000-0000-000000000004"),
+                tracker_two.id,
+                json!("Nothing to see here"),
+                946721100,
+            ),
+        ] {
+            api.db
+                .trackers()
+                .insert_tracker_data_revision(&TrackerDataRevision {
+                    id,
+                    tracker_id,
+                    data: TrackerDataValue::new(data),
+                    created_at: OffsetDateTime::from_unix_timestamp(created_at)?,
+                    provenance: None,
+                    sequence: None,
+                })
+                .await?;
+        }
+
+        let search = |params: SearchParams| {
+            let api = &api;
+            async move {
+                Ok::<_, anyhow::Error>(
+                    api.search()
+                        .search(params)
+                        .await?
+                        .into_iter()
+                        .map(|search_match| search_match.revision_id)
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+
+        let params = SearchParams {
+            query: " CVE-2024-1234 ".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            search(params.clone()).await?,
+            vec![
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ]
+        );
+        assert_eq!(
+            search(SearchParams {
+                tags: vec!["Env:Prod".to_string()],
+                ..params.clone()
+            })
+            .await?,
+            vec![uuid!("00000000-0000-0000-0000-000000000001")]
+        );
+        assert_eq!(
+            search(SearchParams {
+                since: Some(OffsetDateTime::from_unix_timestamp(946720900)?),
+                ..params.clone()
+            })
+            .await?,
+            vec![uuid!("00000000-0000-0000-0000-000000000003")]
+        );
+        assert_eq!(
+            search(SearchParams {
+                until: Some(OffsetDateTime::from_unix_timestamp(946720900)?),
+                ..params.clone()
+            })
+            .await?,
+            vec![uuid!("00000000-0000-0000-0000-000000000001")]
+        );
+        assert_eq!(
+            search(SearchParams {
+                limit: Some(1),
+                ..params.clone()
+            })
+            .await?,
+            vec![uuid!("00000000-0000-0000-0000-000000000003")]
+        );
+        assert_eq!(
+            search(SearchParams {
+                query: "cve-2024-1234 -backport".to_string(),
+                ..Default::default()
+            })
+            .await?,
+            vec![uuid!("00000000-0000-0000-0000-000000000001")]
+        );
+        assert!(search(SearchParams {
+            query: "apache".to_string(),
+            ..Default::default()
+        })
+        .await?
+        .is_empty());
+
+        let matches = api
+            .search()
+            .search(SearchParams {
+                query: "NGINX".to_string(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].tracker_id, tracker_one.id);
+        assert_eq!(matches[0].tracker_name, "tracker_one");
+        assert_eq!(
+            matches[0].revision_id,
+            uuid!("00000000-0000-0000-0000-000000000002")
+        );
+        assert_eq!(
+            matches[0].created_at,
+            OffsetDateTime::from_unix_timestamp(946720900)?
+        );
+        assert_eq!(matches[0].snippet, "Update <b>nginx</b> to 1.27");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_search_with_invalid_params(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let search_and_fail = |params: SearchParams| {
+            let api = &api;
+            async move {
+                Ok::<_, anyhow::Error>(
+                    api.search()
+                        .search(params)
+                        .await
+                        .unwrap_err()
+                        .downcast::<RetrackError>()?
+                        .root_cause
+                        .to_string(),
+                )
+            }
+        };
+
+        assert_debug_snapshot!(
+            search_and_fail(SearchParams {
+                query: "  ".to_string(),
+                ..Default::default()
+            })
+            .await?,
+            @r###""Search query cannot be empty or longer than 200 characters.""###
+        );
+        assert_debug_snapshot!(
+            search_and_fail(SearchParams {
+                query: "a".repeat(201),
+                ..Default::default()
+            })
+            .await?,
+            @r###""Search query cannot be empty or longer than 200 characters.""###
+        );
+        assert_debug_snapshot!(
+            search_and_fail(SearchParams {
+                query: "nginx".to_string(),
+                limit: Some(1001),
+                ..Default::default()
+            })
+            .await?,
+            @r###""Search limit should be between 1 and 1000.""###
+        );
+        assert_debug_snapshot!(
+            search_and_fail(SearchParams {
+                query: "nginx".to_string(),
+                tags: vec!["".to_string()],
+                ..Default::default()
+            })
+            .await?,
+            @r###""Tracker tags cannot be empty or longer than 50 characters.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_indexes_trackers_data(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let tracker = api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("tracker").build())
+            .await?;
+        api.db
+            .trackers()
+            .insert_tracker_data_revision(&TrackerDataRevision {
+                id: Uuid::now_v7(),
+                tracker_id: tracker.id,
+                data: TrackerDataValue::new(json!("Fixes CVE-2024-1234 in parser")),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: None,
+            })
+            .await?;
+
+        // Emulate revision persisted before the full-text search was introduced.
+        sqlx::query("UPDATE trackers_data SET content = NULL")
+            .execute(&api.db.pool)
+            .await?;
+
+        let params = SearchParams {
+            query: "CVE-2024-1234".to_string(),
+            ..Default::default()
+        };
+        assert!(api.search().search(params.clone()).await?.is_empty());
+
+        assert_eq!(api.search().index_trackers_data().await?, 1);
+        assert_eq!(api.search().index_trackers_data().await?, 0);
+        assert_eq!(api.search().search(params).await?.len(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::database::Database;
+use retrack_types::search::SearchMatch;
+use sqlx::{query, query_as};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Extends primary database with the full-text search methods.
+impl Database {
+    /// Searches the text content of the data revisions of the trackers that have all specified
+    /// tags (all trackers if `tags` is empty). Matching revisions are ordered from the newest to
+    /// the oldest. If specified, only revisions created within the `since` (inclusive) and `until`
+    /// (exclusive) time range are searched.
+    pub async fn search_trackers_data(
+        &self,
+        search_query: &str,
+        tags: &[String],
+        since: Option<OffsetDateTime>,
+        until: Option<OffsetDateTime>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<SearchMatch>> {
+        Ok(query_as!(
+            SearchMatch,
+            r#"
+SELECT data.tracker_id, trackers.name AS tracker_name, data.id AS revision_id, data.created_at,
+       ts_headline('simple', data.content, websearch_to_tsquery('simple', $1)) AS "snippet!"
+FROM trackers_data as data
+INNER JOIN trackers
+ON data.tracker_id = trackers.id
+WHERE to_tsvector('simple', data.content) @@ websearch_to_tsquery('simple', $1)
+  AND trackers.tags @> $2
+  AND ($3::timestamptz IS NULL OR data.created_at >= $3)
+  AND ($4::timestamptz IS NULL OR data.created_at < $4)
+ORDER BY data.created_at DESC, data.id DESC
+LIMIT $5
+                "#,
+            search_query,
+            tags,
+            since,
+            until,
+            i64::try_from(limit)?
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Sets the text content of the tracker data revision used for the full-text search.
+    pub async fn update_tracker_data_content(
+        &self,
+        tracker_id: Uuid,
+        id: Uuid,
+        content: &str,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+UPDATE trackers_data
+SET content = $3
+WHERE tracker_id = $1 AND id = $2
+                "#,
+            tracker_id,
+            id,
+            content
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
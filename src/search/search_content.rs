@@ -0,0 +1,81 @@
+use serde_json::Value as JsonValue;
+
+/// Defines the maximum size of the text content of the tracker data revision used for the
+/// full-text search. Content beyond that isn't searchable, since the size of the search index
+/// entry is limited.
+const MAX_SEARCH_CONTENT_SIZE: usize = 256 * 1024;
+
+/// Extracts the text content of the tracker data value used for the full-text search: all strings,
+/// numbers, and booleans of the value, one per line, in the order they appear in the value.
+pub fn search_content(value: &JsonValue) -> String {
+    let mut content = String::new();
+    append_search_content(value, &mut content);
+
+    // Postgres doesn't allow NUL characters in the text values.
+    let mut content = content.replace('\0', "");
+    if content.len() > MAX_SEARCH_CONTENT_SIZE {
+        let mut len = MAX_SEARCH_CONTENT_SIZE;
+        while !content.is_char_boundary(len) {
+            len -= 1;
+        }
+        content.truncate(len);
+    }
+
+    content
+}
+
+/// Appends the text content of the value to the specified content.
+fn append_search_content(value: &JsonValue, content: &mut String) {
+    let line = match value {
+        JsonValue::Null => return,
+        JsonValue::String(value) => value.clone(),
+        JsonValue::Number(_) | JsonValue::Bool(_) => value.to_string(),
+        JsonValue::Array(items) => {
+            for item in items {
+                append_search_content(item, content);
+            }
+            return;
+        }
+        JsonValue::Object(map) => {
+            for value in map.values() {
+                append_search_content(value, content);
+            }
+            return;
+        }
+    };
+
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{search_content, MAX_SEARCH_CONTENT_SIZE};
+    use serde_json::json;
+
+    #[test]
+    fn extracts_search_content() {
+        assert_eq!(search_content(&json!(null)), "");
+        assert_eq!(search_content(&json!("CVE-2024-1234")), "CVE-2024-1234");
+        assert_eq!(
+            search_content(&json!({
+                "name": "retrack",
+                "releases": [
+                    { "beta": false, "notes": "Fixes CVE-2024-1234.", "version": "1.0.0" },
+                    { "downloads": 10, "notes": null, "version": "1.1.0" }
+                ]
+            })),
+            "retrack\nfalse\nFixes CVE-2024-1234.\n1.0.0\n10\n1.1.0"
+        );
+        assert_eq!(search_content(&json!(["one\0", "two"])), "one\ntwo");
+    }
+
+    #[test]
+    fn truncates_search_content() {
+        let content = search_content(&json!("ñ".repeat(MAX_SEARCH_CONTENT_SIZE)));
+        assert_eq!(content.len(), MAX_SEARCH_CONTENT_SIZE);
+        assert_eq!(content, "ñ".repeat(MAX_SEARCH_CONTENT_SIZE / 2));
+    }
+}
@@ -1,4 +1,6 @@
+mod bearer_token;
 mod handlers;
+mod self_check;
 mod server_state;
 
 use crate::{
@@ -6,18 +8,19 @@ use crate::{
     database::Database,
     network::{Network, TokioDnsResolver},
     scheduler::Scheduler,
+    server::self_check::self_check,
     templates::create_templates,
 };
 use actix_cors::Cors;
 use actix_web::{middleware, web, App, HttpServer, Result};
-use anyhow::Context;
+use anyhow::{bail, Context};
 use lettre::{
     message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
     Tokio1Executor,
 };
 use sqlx::postgres::PgPoolOptions;
 use std::{str::FromStr, sync::Arc};
-use tracing::info;
+use tracing::{error, info};
 use tracing_actix_web::TracingLogger;
 use utoipa::OpenApi;
 use utoipa_rapidoc::RapiDoc;
@@ -27,16 +30,28 @@ use crate::{
     js_runtime::JsRuntime,
     server::handlers::RetrackOpenApi,
 };
-pub use server_state::{GetStatusParams, SchedulerStatus, ServerState, Status};
+pub use bearer_token::BearerToken;
+pub use server_state::{
+    ComponentsStatus, GetStatusParams, JsRuntimeStatus, SchedulerStatus, ServerState, Status,
+};
 
-pub async fn run(raw_config: RawConfig) -> Result<(), anyhow::Error> {
-    let database = Database::create(
+/// Connects to the database and applies pending migrations.
+async fn create_database(raw_config: &RawConfig) -> anyhow::Result<Database> {
+    Database::create_with_config(
         PgPoolOptions::new()
             .max_connections(raw_config.db.max_connections)
             .connect(&Database::connection_url(&raw_config.db))
             .await?,
+        &raw_config.db,
     )
-    .await?;
+    .await
+}
+
+/// Creates APIs collection with the real database, network, and JS runtime based on the config.
+async fn create_api(
+    raw_config: RawConfig,
+) -> anyhow::Result<Api<TokioDnsResolver, AsyncSmtpTransport<Tokio1Executor>>> {
+    let database = create_database(&raw_config).await?;
 
     let email_transport = if let Some(ref smtp_config) = raw_config.smtp {
         if let Some(ref catch_all_config) = smtp_config.catch_all {
@@ -54,15 +69,67 @@ pub async fn run(raw_config: RawConfig) -> Result<(), anyhow::Error> {
         AsyncSmtpTransport::<Tokio1Executor>::unencrypted_localhost()
     };
 
-    let http_port = raw_config.port;
     let js_runtime = JsRuntime::init_platform(&raw_config.js_runtime)?;
-    let api = Arc::new(Api::new(
+    Ok(Api::new(
         Config::from(raw_config),
         database,
         Network::new(TokioDnsResolver::create(), email_transport),
         create_templates()?,
         js_runtime,
-    ));
+    ))
+}
+
+/// Applies pending database migrations and exits. Allows upgrading the database schema before the
+/// new server version is rolled out.
+pub async fn migrate(raw_config: RawConfig) -> Result<(), anyhow::Error> {
+    let database = create_database(&raw_config).await?;
+    info!(
+        schema.version = database.get_schema_version().await?,
+        "Database migrations applied."
+    );
+
+    Ok(())
+}
+
+/// Runs the self-check of all components Retrack relies on and exits. Fails if any check fails.
+pub async fn preflight(raw_config: RawConfig) -> Result<(), anyhow::Error> {
+    let api = create_api(raw_config).await?;
+
+    let report = self_check(&api).await;
+    report.log();
+    if !report.is_passed() {
+        bail!(
+            "Preflight self-check failed: {} of {} checks didn't pass.",
+            report.failed_count(),
+            report.checks.len()
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn run(raw_config: RawConfig) -> Result<(), anyhow::Error> {
+    let http_port = raw_config.port;
+    let api = Arc::new(create_api(raw_config).await?);
+
+    // Report misconfigured components before the scheduler starts running trackers. Failed checks
+    // don't prevent server from starting, since components may become available later.
+    self_check(&api).await.log();
+
+    // Revisions persisted before the full-text search was introduced are indexed in background,
+    // so that the server doesn't wait for the potentially long indexing to start.
+    let search_api = api.clone();
+    tokio::spawn(async move {
+        match search_api.search().index_trackers_data().await {
+            Ok(0) => {}
+            Ok(indexed_revisions) => info!(
+                "Indexed {indexed_revisions} tracker data revisions for the full-text search."
+            ),
+            Err(err) => {
+                error!("Failed to index tracker data revisions for the full-text search: {err:?}")
+            }
+        }
+    });
 
     let scheduler = Scheduler::start(api.clone()).await?;
     let state = web::Data::new(ServerState::new(api, scheduler));
@@ -77,15 +144,57 @@ pub async fn run(raw_config: RawConfig) -> Result<(), anyhow::Error> {
                 RetrackOpenApi::openapi(),
             ))
             .service(handlers::status_get::status_get)
+            .service(handlers::operations_get::operations_get)
+            .service(handlers::search_get::search_get)
+            .service(handlers::auth_profiles_list::auth_profiles_list)
+            .service(handlers::auth_profiles_get::auth_profiles_get)
+            .service(handlers::auth_profiles_create::auth_profiles_create)
+            .service(handlers::auth_profiles_update::auth_profiles_update)
+            .service(handlers::auth_profiles_remove::auth_profiles_remove)
+            .service(handlers::api_tokens_list::api_tokens_list)
+            .service(handlers::api_tokens_create::api_tokens_create)
+            .service(handlers::api_tokens_remove::api_tokens_remove)
             .service(handlers::trackers_list::trackers_list)
             .service(handlers::trackers_get::trackers_get)
+            .service(handlers::trackers_get_by_name::trackers_get_by_name)
             .service(handlers::trackers_create::trackers_create)
             .service(handlers::trackers_update::trackers_update)
             .service(handlers::trackers_remove::trackers_remove)
             .service(handlers::trackers_bulk_remove::trackers_bulk_remove)
+            .service(handlers::trackers_bulk_run::trackers_bulk_run)
             .service(handlers::trackers_list_revisions::trackers_list_revisions)
+            .service(handlers::trackers_export_revisions::trackers_export_revisions)
+            .service(handlers::trackers_import_revisions::trackers_import_revisions)
+            .service(handlers::trackers_list_action_history::trackers_list_action_history)
             .service(handlers::trackers_create_revision::trackers_create_revision)
             .service(handlers::trackers_clear_revisions::trackers_clear_revisions)
+            .service(handlers::trackers_list_quarantine::trackers_list_quarantine)
+            .service(
+                handlers::trackers_accept_quarantined_revision::trackers_accept_quarantined_revision,
+            )
+            .service(
+                handlers::trackers_discard_quarantined_revision::trackers_discard_quarantined_revision,
+            )
+            .service(handlers::trackers_record_fixture::trackers_record_fixture)
+            .service(handlers::trackers_replay_fixture::trackers_replay_fixture)
+            .service(handlers::trackers_export_bundle::trackers_export_bundle)
+            .service(handlers::trackers_import_bundle::trackers_import_bundle)
+            .service(handlers::trackers_insights::trackers_insights)
+            .service(handlers::trackers_scrub::trackers_scrub)
+            .service(handlers::trackers_list_scrubs::trackers_list_scrubs)
+            .service(handlers::trackers_preview_templates::trackers_preview_templates)
+            .service(handlers::trackers_test_action::trackers_test_action)
+            .service(handlers::templates_list::templates_list)
+            .service(handlers::templates_get::templates_get)
+            .service(handlers::templates_create::templates_create)
+            .service(handlers::templates_update::templates_update)
+            .service(handlers::templates_remove::templates_remove)
+            .service(handlers::views_list::views_list)
+            .service(handlers::views_get::views_get)
+            .service(handlers::views_create::views_create)
+            .service(handlers::views_update::views_update)
+            .service(handlers::views_remove::views_remove)
+            .service(handlers::views_list_trackers::views_list_trackers)
             .wrap(Cors::permissive())
     });
 
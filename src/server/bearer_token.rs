@@ -0,0 +1,110 @@
+use crate::{config::ApiConfig, error::Error as RetrackError};
+use actix_web::{dev::Payload, http::header::AUTHORIZATION, FromRequest, HttpRequest};
+use std::future::{ready, Ready};
+
+/// Extracts the optional bearer token (e.g., the API token of the external submitter) from the
+/// `Authorization` request header. Requests with the malformed header are always rejected, and
+/// requests without the header are rejected by the endpoints that accept API tokens if
+/// `api.require_tokens` is enabled (see [`BearerToken::into_token`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BearerToken(pub Option<String>);
+
+impl BearerToken {
+    /// Returns the bearer token, if any. Fails with `401 Unauthorized` if the token is missing, but
+    /// the API config requires requests to be authorized with API tokens.
+    pub fn into_token(self, config: &ApiConfig) -> Result<Option<String>, RetrackError> {
+        match self.0 {
+            None if config.require_tokens => Err(RetrackError::unauthorized(
+                "Request must be authorized with an API token.",
+            )),
+            token => Ok(token),
+        }
+    }
+}
+
+impl FromRequest for BearerToken {
+    type Error = RetrackError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let Some(header_value) = req.headers().get(AUTHORIZATION) else {
+            return ready(Ok(BearerToken(None)));
+        };
+
+        ready(
+            header_value
+                .to_str()
+                .ok()
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+                .map(|token| BearerToken(Some(token.to_string())))
+                .ok_or_else(|| {
+                    RetrackError::unauthorized("Authorization header must be a bearer token.")
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BearerToken;
+    use crate::config::ApiConfig;
+    use actix_web::{
+        http::{header::AUTHORIZATION, StatusCode},
+        test::TestRequest,
+        FromRequest, ResponseError,
+    };
+
+    #[tokio::test]
+    async fn extracts_bearer_token() -> anyhow::Result<()> {
+        let (req, mut payload) = TestRequest::default().to_http_parts();
+        assert_eq!(
+            BearerToken::from_request(&req, &mut payload).await?,
+            BearerToken(None)
+        );
+
+        let (req, mut payload) = TestRequest::default()
+            .insert_header((AUTHORIZATION, "Bearer rtk_token"))
+            .to_http_parts();
+        assert_eq!(
+            BearerToken::from_request(&req, &mut payload).await?,
+            BearerToken(Some("rtk_token".to_string()))
+        );
+
+        for header_value in ["Basic dXNlcjpwYXNz", "Bearer ", "rtk_token"] {
+            let (req, mut payload) = TestRequest::default()
+                .insert_header((AUTHORIZATION, header_value))
+                .to_http_parts();
+            let error = BearerToken::from_request(&req, &mut payload)
+                .await
+                .unwrap_err();
+            assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn requires_token_if_configured() -> anyhow::Result<()> {
+        let config = ApiConfig::default();
+        assert_eq!(BearerToken(None).into_token(&config)?, None);
+        assert_eq!(
+            BearerToken(Some("rtk_token".to_string())).into_token(&config)?,
+            Some("rtk_token".to_string())
+        );
+
+        let config = ApiConfig {
+            require_tokens: true,
+        };
+        assert_eq!(
+            BearerToken(Some("rtk_token".to_string())).into_token(&config)?,
+            Some("rtk_token".to_string())
+        );
+
+        let error = BearerToken(None).into_token(&config).unwrap_err();
+        assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+
+        Ok(())
+    }
+}
@@ -1,22 +1,92 @@
+pub mod api_tokens_create;
+pub mod api_tokens_list;
+pub mod api_tokens_remove;
+pub mod auth_profiles_create;
+pub mod auth_profiles_get;
+pub mod auth_profiles_list;
+pub mod auth_profiles_remove;
+pub mod auth_profiles_update;
+pub mod operations_get;
+pub mod search_get;
 pub mod status_get;
+pub mod templates_create;
+pub mod templates_get;
+pub mod templates_list;
+pub mod templates_remove;
+pub mod templates_update;
+pub mod trackers_accept_quarantined_revision;
 pub mod trackers_bulk_remove;
+pub mod trackers_bulk_run;
 pub mod trackers_clear_revisions;
 pub mod trackers_create;
 pub mod trackers_create_revision;
+pub mod trackers_discard_quarantined_revision;
+pub mod trackers_export_bundle;
+pub mod trackers_export_revisions;
 pub mod trackers_get;
+pub mod trackers_get_by_name;
+pub mod trackers_import_bundle;
+pub mod trackers_import_revisions;
+pub mod trackers_insights;
 pub mod trackers_list;
+pub mod trackers_list_action_history;
+pub mod trackers_list_quarantine;
 pub mod trackers_list_revisions;
+pub mod trackers_list_scrubs;
+pub mod trackers_preview_templates;
+pub mod trackers_record_fixture;
 pub mod trackers_remove;
+pub mod trackers_replay_fixture;
+pub mod trackers_scrub;
+pub mod trackers_test_action;
 pub mod trackers_update;
+pub mod views_create;
+pub mod views_get;
+pub mod views_list;
+pub mod views_list_trackers;
+pub mod views_remove;
+pub mod views_update;
 
 use crate::server::Status;
 use retrack_types::{
+    api_tokens::{ApiToken, ApiTokenCreateParams, ApiTokenCreateResult, ApiTokenScope},
+    auth_profiles::{
+        AuthProfileCreateParams, AuthProfileKind, AuthProfileUpdateParams, RedactedAuthProfile,
+        RedactedAuthProfileKind,
+    },
+    operations::{Operation, OperationError, OperationKind},
     scheduler::{SchedulerJobConfig, SchedulerJobRetryStrategy},
+    search::SearchMatch,
+    templates::{Template, TemplateCreateParams, TemplateUpdateParams},
     trackers::{
-        ApiTarget, EmailAction, PageTarget, TargetRequest, Tracker, TrackerAction, TrackerConfig,
-        TrackerCreateParams, TrackerDataRevision, TrackerDataValue, TrackerTarget,
-        TrackerUpdateParams, WebhookAction,
+        ApiTarget, CommandAction, CompositeTarget, DatabaseAction, DatabaseActionColumn,
+        DatabaseActionColumnValue, EmailAction, EmailActionAttachment, EmailActionAttachmentFormat,
+        EmailTarget, FeedTarget, FileAction, FileActionMode, FileTarget, GitTarget,
+        GoogleChatAction, GrpcDescriptorSource, GrpcTarget, IssueAction, IssueProvider,
+        MonitorTarget, MqttAction, NtfyAction, PageTarget, PageTargetEngine, PageTargetStep,
+        PrometheusTarget, PublishAction, PublishBroker, PushoverAction, RegistryTarget, S3Target,
+        SemverAction, SitemapTarget, SlackAction, SqlTarget, StreamTarget, SummarizeAction,
+        TargetRequest, TargetRequestBodyFormat, TargetRequestFilePart, TargetRequestPagination,
+        TargetRequestPaginationStrategy, TargetResponseTransform, TargetSelector, TeamsAction,
+        TelegramAction, TlsSessionInfo, Tracker, TrackerAction, TrackerActionCondition,
+        TrackerActionDependency, TrackerActionDigest, TrackerActionHistoryEntry,
+        TrackerActionHistoryStatus, TrackerActionRule, TrackerActionTestParams,
+        TrackerActionTestResult, TrackerActionThrottle, TrackerActiveDay, TrackerActiveHours,
+        TrackerBundle, TrackerChangeComparator, TrackerChangeDirection, TrackerChangeFilter,
+        TrackerConfig, TrackerContentSizePolicy, TrackerCreateParams, TrackerDataAssertion,
+        TrackerDataAssertionValueType, TrackerDataDiffFormat, TrackerDataProvenance,
+        TrackerDataRevision, TrackerDataRevisionImport, TrackerDataValue, TrackerDigestCadence,
+        TrackerEmailPreview, TrackerEscalation, TrackerEscalationStep, TrackerFixture,
+        TrackerFixtureReplay, TrackerFixtureResponse, TrackerInsight, TrackerInsightAction,
+        TrackerInsightKind, TrackerMaxContentSize, TrackerQuarantine, TrackerQuarantinedRevision,
+        TrackerRevisionsExportFormat, TrackerRunActionResult, TrackerRunActionStatus,
+        TrackerRunOutcome, TrackerRunResult, TrackerRunTimings, TrackerSeverity,
+        TrackerSeverityRoute, TrackerSeverityRouting, TrackerStats, TrackerTarget,
+        TrackerTemplatesPreview, TrackerTemplatesPreviewParams, TrackerUpdateParams,
+        TrackerWorkflow, TrackerWorkflowEvent, TrackerWorkflowTransition, TrackersScrub,
+        TrackersScrubParams, TrackersSort, TranslateAction, TriggerTrackerAction, WebhookAction,
     },
+    views::{View, ViewCreateParams, ViewUpdateParams},
 };
 use utoipa::OpenApi;
 
@@ -31,32 +101,187 @@ use utoipa::OpenApi;
     ),
     paths(
         status_get::status_get,
+        operations_get::operations_get,
+        search_get::search_get,
+        auth_profiles_list::auth_profiles_list,
+        auth_profiles_get::auth_profiles_get,
+        auth_profiles_create::auth_profiles_create,
+        auth_profiles_update::auth_profiles_update,
+        auth_profiles_remove::auth_profiles_remove,
+        api_tokens_list::api_tokens_list,
+        api_tokens_create::api_tokens_create,
+        api_tokens_remove::api_tokens_remove,
         trackers_list::trackers_list,
         trackers_get::trackers_get,
+        trackers_get_by_name::trackers_get_by_name,
         trackers_create::trackers_create,
         trackers_update::trackers_update,
         trackers_remove::trackers_remove,
         trackers_bulk_remove::trackers_bulk_remove,
+        trackers_bulk_run::trackers_bulk_run,
         trackers_list_revisions::trackers_list_revisions,
+        trackers_export_revisions::trackers_export_revisions,
+        trackers_import_revisions::trackers_import_revisions,
+        trackers_list_action_history::trackers_list_action_history,
         trackers_create_revision::trackers_create_revision,
-        trackers_clear_revisions::trackers_clear_revisions
+        trackers_clear_revisions::trackers_clear_revisions,
+        trackers_list_quarantine::trackers_list_quarantine,
+        trackers_accept_quarantined_revision::trackers_accept_quarantined_revision,
+        trackers_discard_quarantined_revision::trackers_discard_quarantined_revision,
+        trackers_record_fixture::trackers_record_fixture,
+        trackers_replay_fixture::trackers_replay_fixture,
+        trackers_export_bundle::trackers_export_bundle,
+        trackers_import_bundle::trackers_import_bundle,
+        trackers_insights::trackers_insights,
+        trackers_scrub::trackers_scrub,
+        trackers_list_scrubs::trackers_list_scrubs,
+        trackers_preview_templates::trackers_preview_templates,
+        trackers_test_action::trackers_test_action,
+        templates_list::templates_list,
+        templates_get::templates_get,
+        templates_create::templates_create,
+        templates_update::templates_update,
+        templates_remove::templates_remove,
+        views_list::views_list,
+        views_get::views_get,
+        views_create::views_create,
+        views_update::views_update,
+        views_remove::views_remove,
+        views_list_trackers::views_list_trackers
     ),
     components(schemas(
         ApiTarget,
+        ApiToken,
+        ApiTokenCreateParams,
+        ApiTokenCreateResult,
+        ApiTokenScope,
+        AuthProfileCreateParams,
+        AuthProfileKind,
+        AuthProfileUpdateParams,
+        CommandAction,
+        DatabaseAction,
+        DatabaseActionColumn,
+        DatabaseActionColumnValue,
         EmailAction,
+        EmailActionAttachment,
+        EmailActionAttachmentFormat,
+        FileAction,
+        FileActionMode,
+        GoogleChatAction,
+        IssueAction,
+        IssueProvider,
+        MqttAction,
+        NtfyAction,
+        Operation,
+        OperationError,
+        OperationKind,
+        PublishAction,
+        PublishBroker,
+        PushoverAction,
+        RedactedAuthProfile,
+        RedactedAuthProfileKind,
         SchedulerJobConfig,
         SchedulerJobRetryStrategy,
+        SearchMatch,
+        SemverAction,
+        SlackAction,
+        SummarizeAction,
         Status,
         TargetRequest,
+        TargetRequestBodyFormat,
+        TargetRequestFilePart,
+        TargetRequestPagination,
+        TargetRequestPaginationStrategy,
+        TargetResponseTransform,
+        TargetSelector,
+        TeamsAction,
+        TelegramAction,
+        Template,
+        TemplateCreateParams,
+        TemplateUpdateParams,
+        TlsSessionInfo,
         Tracker,
         TrackerAction,
+        TrackerActionCondition,
+        TrackerActionDependency,
+        TrackerActionDigest,
+        TrackerActionHistoryEntry,
+        TrackerActionHistoryStatus,
+        TrackerActionRule,
+        TrackerActionTestParams,
+        TrackerActionTestResult,
+        TrackerActionThrottle,
+        TrackerActiveDay,
+        TrackerActiveHours,
+        TrackerBundle,
+        TrackerChangeComparator,
+        TrackerChangeDirection,
+        TrackerChangeFilter,
         TrackerConfig,
+        TrackerContentSizePolicy,
         TrackerCreateParams,
+        TrackerDataDiffFormat,
+        TrackerDataProvenance,
         TrackerDataRevision,
+        TrackerDataRevisionImport,
+        TrackerDataAssertion,
+        TrackerDataAssertionValueType,
         TrackerDataValue,
+        TrackerDigestCadence,
+        TrackerFixture,
+        TrackerFixtureReplay,
+        TrackerFixtureResponse,
+        TrackerInsight,
+        TrackerInsightAction,
+        TrackerInsightKind,
+        TrackerMaxContentSize,
+        TrackerQuarantine,
+        TrackerQuarantinedRevision,
+        TrackerRevisionsExportFormat,
+        TrackerRunActionResult,
+        TrackerRunActionStatus,
+        TrackerRunOutcome,
+        TrackerRunResult,
+        TrackerRunTimings,
+        TrackerSeverity,
+        TrackerSeverityRoute,
+        TrackerSeverityRouting,
+        TrackerStats,
         TrackerTarget,
         TrackerUpdateParams,
+        TrackerWorkflow,
+        TrackerWorkflowEvent,
+        TrackerWorkflowTransition,
+        TrackersScrub,
+        TrackersScrubParams,
+        TrackersSort,
+        TrackerTemplatesPreview,
+        TrackerTemplatesPreviewParams,
+        TrackerEmailPreview,
+        TrackerEscalation,
+        TrackerEscalationStep,
+        TranslateAction,
+        TriggerTrackerAction,
         PageTarget,
+        PageTargetEngine,
+        PageTargetStep,
+        FeedTarget,
+        EmailTarget,
+        SqlTarget,
+        FileTarget,
+        S3Target,
+        GitTarget,
+        RegistryTarget,
+        SitemapTarget,
+        StreamTarget,
+        GrpcTarget,
+        GrpcDescriptorSource,
+        CompositeTarget,
+        MonitorTarget,
+        PrometheusTarget,
+        View,
+        ViewCreateParams,
+        ViewUpdateParams,
         WebhookAction
     ))
 )]
@@ -0,0 +1,115 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::api_tokens::{ApiTokenCreateParams, ApiTokenCreateResult};
+use tracing::error;
+
+/// Creates a new API token with the specified parameters. The token value is returned only once
+/// and cannot be retrieved later.
+#[utoipa::path(
+    tags = ["api_tokens"],
+    request_body = ApiTokenCreateParams,
+    responses(
+        (status = 200, description = "API token was successfully created.", body = ApiTokenCreateResult),
+        (status = BAD_REQUEST, description = "Cannot create an API token with the specified properties.")
+    )
+)]
+#[post("/api/api_tokens")]
+pub async fn api_tokens_create(
+    state: web::Data<ServerState>,
+    params: web::Json<ApiTokenCreateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .api_tokens()
+        .create_api_token(params.into_inner())
+        .await
+    {
+        Ok(result) => Ok(HttpResponse::Ok().json(result)),
+        Err(err) => {
+            error!("Failed to create API token: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::api_tokens_create::api_tokens_create, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::api_tokens::{ApiTokenCreateResult, ApiTokenScope};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_create_api_token(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(api_tokens_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/api_tokens")
+                .method(Method::POST)
+                .set_json(json!({
+                    "name": "acme",
+                    "tags": ["partner:acme"],
+                    "scopes": ["createRevisions"]
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let result = serde_json::from_slice::<ApiTokenCreateResult>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(result.token.name, "acme");
+        assert_eq!(result.token.tags, vec!["partner:acme".to_string()]);
+        assert_eq!(result.token.scopes, vec![ApiTokenScope::CreateRevisions]);
+        assert!(result.value.starts_with("rtk_"));
+
+        assert_eq!(
+            server_state.api.api_tokens().get_api_tokens().await?,
+            vec![result.token]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_params(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(api_tokens_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/api_tokens")
+                .method(Method::POST)
+                .set_json(json!({ "name": "acme", "tags": [], "scopes": ["runTrackers"] }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"API token should be scoped to at least one tag.\"}""###);
+
+        Ok(())
+    }
+}
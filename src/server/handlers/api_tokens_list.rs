@@ -0,0 +1,84 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::api_tokens::ApiToken;
+use tracing::error;
+
+/// Gets a list of API tokens, token values aren't included.
+#[utoipa::path(
+    tags = ["api_tokens"],
+    responses(
+        (status = 200, description = "A list of API tokens ordered by name.", body = [ApiToken])
+    )
+)]
+#[get("/api/api_tokens")]
+pub async fn api_tokens_list(state: web::Data<ServerState>) -> Result<HttpResponse, RetrackError> {
+    match state.api.api_tokens().get_api_tokens().await {
+        Ok(api_tokens) => Ok(HttpResponse::Ok().json(api_tokens)),
+        Err(err) => {
+            error!("Failed to retrieve API tokens: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::api_tokens_list::api_tokens_list, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::api_tokens::{ApiTokenCreateParams, ApiTokenScope};
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_list_api_tokens(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(api_tokens_list),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/api_tokens").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        let result = server_state
+            .api
+            .api_tokens()
+            .create_api_token(ApiTokenCreateParams {
+                name: "acme".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::RunTrackers],
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/api_tokens").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(vec![result.token])?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{delete, web, HttpResponse};
+use tracing::error;
+use uuid::Uuid;
+
+/// Removes an API token with the specified ID, the token can no longer be used right away.
+#[utoipa::path(
+    tags = ["api_tokens"],
+    params(
+        ("api_token_id" = Uuid, Path, description = "A unique API token ID."),
+    ),
+    responses(
+        (status = NO_CONTENT, description = "API token with the specified ID was successfully removed.")
+    )
+)]
+#[delete("/api/api_tokens/{api_token_id}")]
+pub async fn api_tokens_remove(
+    state: web::Data<ServerState>,
+    api_token_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.api_tokens().remove_api_token(*api_token_id).await {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => {
+            error!("Failed to remove API token: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::api_tokens_remove::api_tokens_remove, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::api_tokens::{ApiTokenCreateParams, ApiTokenScope};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn can_remove_api_token(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(api_tokens_remove),
+        )
+        .await;
+
+        let result = server_state
+            .api
+            .api_tokens()
+            .create_api_token(ApiTokenCreateParams {
+                name: "acme".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::RunTrackers],
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/api_tokens/{}",
+                result.token.id
+            ))
+            .method(Method::DELETE)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 204);
+        assert!(server_state
+            .api
+            .api_tokens()
+            .get_api_tokens()
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::auth_profiles::{AuthProfileCreateParams, RedactedAuthProfile};
+use tracing::error;
+
+/// Creates a new auth profile with the specified parameters.
+#[utoipa::path(
+    tags = ["auth_profiles"],
+    request_body = AuthProfileCreateParams,
+    responses(
+        (status = 200, description = "Auth profile was successfully created.", body = RedactedAuthProfile),
+        (status = BAD_REQUEST, description = "Cannot create an auth profile with the specified properties.")
+    )
+)]
+#[post("/api/auth_profiles")]
+pub async fn auth_profiles_create(
+    state: web::Data<ServerState>,
+    params: web::Json<AuthProfileCreateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .auth_profiles()
+        .create_auth_profile(params.into_inner())
+        .await
+    {
+        Ok(auth_profile) => Ok(HttpResponse::Ok().json(RedactedAuthProfile::from(auth_profile))),
+        Err(err) => {
+            error!("Failed to create auth profile: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::auth_profiles_create::auth_profiles_create,
+        server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::auth_profiles::{AuthProfileKind, RedactedAuthProfile};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_create_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/auth_profiles")
+                .method(Method::POST)
+                .set_json(json!({
+                    "name": "github",
+                    "kind": { "type": "basic", "username": "user", "password": "pass" }
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let auth_profiles = server_state.api.auth_profiles().get_auth_profiles().await?;
+        assert_eq!(auth_profiles.len(), 1);
+        assert_eq!(auth_profiles[0].name, "github");
+        assert_eq!(
+            auth_profiles[0].kind,
+            AuthProfileKind::Basic {
+                username: "user".to_string(),
+                password: Some("pass".to_string())
+            }
+        );
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(RedactedAuthProfile::from(auth_profiles[0].clone()))?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_params(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/auth_profiles")
+                .method(Method::POST)
+                .set_json(json!({
+                    "name": "github",
+                    "kind": { "type": "bearer", "token": "" }
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Auth profile token cannot be empty.\"}""###
+        );
+
+        Ok(())
+    }
+}
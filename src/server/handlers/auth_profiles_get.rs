@@ -0,0 +1,109 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::auth_profiles::RedactedAuthProfile;
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets an auth profile with the specified ID.
+#[utoipa::path(
+    tags = ["auth_profiles"],
+    params(
+        ("auth_profile_id" = Uuid, Path, description = "A unique auth profile ID."),
+    ),
+    responses(
+        (status = 200, description = "Auth profile with the specified ID.", body = RedactedAuthProfile),
+        (status = NOT_FOUND, description = "Auth profile with the specified ID was not found or the ID is not a valid UUID.")
+    )
+)]
+#[get("/api/auth_profiles/{auth_profile_id}")]
+pub async fn auth_profiles_get(
+    state: web::Data<ServerState>,
+    auth_profile_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .auth_profiles()
+        .get_auth_profile(*auth_profile_id)
+        .await
+    {
+        Ok(Some(auth_profile)) => {
+            Ok(HttpResponse::Ok().json(RedactedAuthProfile::from(auth_profile)))
+        }
+        Ok(None) => Ok(HttpResponse::NotFound().finish()),
+        Err(err) => {
+            error!("Failed to retrieve auth profile: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::auth_profiles_get::auth_profiles_get, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::auth_profiles::{
+        AuthProfileCreateParams, AuthProfileKind, RedactedAuthProfile,
+    };
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_get_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_get),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/auth_profiles/{}",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 404);
+
+        let auth_profile = server_state
+            .api
+            .auth_profiles()
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: AuthProfileKind::ApiKey {
+                    header: "X-API-Key".to_string(),
+                    value: "some-key".to_string(),
+                },
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/auth_profiles/{}",
+                auth_profile.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        let response_body = response.into_body().try_into_bytes().unwrap();
+        assert!(!from_utf8(&response_body)?.contains("some-key"));
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&response_body)?,
+            serde_json::to_value(RedactedAuthProfile::from(auth_profile))?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::auth_profiles::RedactedAuthProfile;
+use tracing::error;
+
+/// Gets a list of auth profiles.
+#[utoipa::path(
+    tags = ["auth_profiles"],
+    responses(
+        (status = 200, description = "A list of auth profiles ordered by name.", body = [RedactedAuthProfile])
+    )
+)]
+#[get("/api/auth_profiles")]
+pub async fn auth_profiles_list(
+    state: web::Data<ServerState>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.auth_profiles().get_auth_profiles().await {
+        Ok(auth_profiles) => Ok(HttpResponse::Ok().json(
+            auth_profiles
+                .into_iter()
+                .map(RedactedAuthProfile::from)
+                .collect::<Vec<_>>(),
+        )),
+        Err(err) => {
+            error!("Failed to retrieve auth profiles: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::auth_profiles_list::auth_profiles_list, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::auth_profiles::{
+        AuthProfileCreateParams, AuthProfileKind, RedactedAuthProfile,
+    };
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_list_auth_profiles(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_list),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/auth_profiles").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        let auth_profile = server_state
+            .api
+            .auth_profiles()
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: AuthProfileKind::Bearer {
+                    token: "some-token".to_string(),
+                },
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/auth_profiles").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        let response_body = response.into_body().try_into_bytes().unwrap();
+        assert!(!from_utf8(&response_body)?.contains("some-token"));
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&response_body)?,
+            serde_json::to_value(vec![RedactedAuthProfile::from(auth_profile)])?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{delete, web, HttpResponse};
+use tracing::error;
+use uuid::Uuid;
+
+/// Removes an auth profile with the specified ID. Tracker target requests that reference the auth
+/// profile will fail until they are updated.
+#[utoipa::path(
+    tags = ["auth_profiles"],
+    params(
+        ("auth_profile_id" = Uuid, Path, description = "A unique auth profile ID."),
+    ),
+    responses(
+        (status = NO_CONTENT, description = "Auth profile with the specified ID was successfully removed.")
+    )
+)]
+#[delete("/api/auth_profiles/{auth_profile_id}")]
+pub async fn auth_profiles_remove(
+    state: web::Data<ServerState>,
+    auth_profile_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .auth_profiles()
+        .remove_auth_profile(*auth_profile_id)
+        .await
+    {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => {
+            error!("Failed to remove auth profile: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::auth_profiles_remove::auth_profiles_remove,
+        server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::auth_profiles::{AuthProfileCreateParams, AuthProfileKind};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn can_remove_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_remove),
+        )
+        .await;
+
+        let auth_profile = server_state
+            .api
+            .auth_profiles()
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: AuthProfileKind::Bearer {
+                    token: "some-token".to_string(),
+                },
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/auth_profiles/{}",
+                auth_profile.id
+            ))
+            .method(Method::DELETE)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 204);
+        assert!(server_state
+            .api
+            .auth_profiles()
+            .get_auth_profiles()
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{put, web, HttpResponse};
+use retrack_types::auth_profiles::{AuthProfileUpdateParams, RedactedAuthProfile};
+use tracing::error;
+use uuid::Uuid;
+
+/// Updates an existing auth profile with the specified parameters.
+#[utoipa::path(
+    tags = ["auth_profiles"],
+    params(
+        ("auth_profile_id" = Uuid, Path, description = "A unique auth profile ID."),
+    ),
+    request_body = AuthProfileUpdateParams,
+    responses(
+        (status = 200, description = "Auth profile was successfully updated.", body = RedactedAuthProfile),
+        (status = BAD_REQUEST, description = "Cannot update an auth profile with the specified properties.")
+    )
+)]
+#[put("/api/auth_profiles/{auth_profile_id}")]
+pub async fn auth_profiles_update(
+    state: web::Data<ServerState>,
+    auth_profile_id: web::Path<Uuid>,
+    params: web::Json<AuthProfileUpdateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .auth_profiles()
+        .update_auth_profile(*auth_profile_id, params.into_inner())
+        .await
+    {
+        Ok(auth_profile) => Ok(HttpResponse::Ok().json(RedactedAuthProfile::from(auth_profile))),
+        Err(err) => {
+            error!("Failed to update auth profile: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::auth_profiles_update::auth_profiles_update,
+        server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::auth_profiles::{
+        AuthProfileCreateParams, AuthProfileKind, RedactedAuthProfile,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_update_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_update),
+        )
+        .await;
+
+        let auth_profile = server_state
+            .api
+            .auth_profiles()
+            .create_auth_profile(AuthProfileCreateParams {
+                name: "github".to_string(),
+                kind: AuthProfileKind::Bearer {
+                    token: "some-token".to_string(),
+                },
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/auth_profiles/{}",
+                auth_profile.id
+            ))
+            .method(Method::PUT)
+            .set_json(json!({
+                "kind": { "type": "bearer", "token": "some-other-token" }
+            }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let updated_auth_profile = server_state
+            .api
+            .auth_profiles()
+            .get_auth_profile(auth_profile.id)
+            .await?
+            .unwrap();
+        assert_eq!(updated_auth_profile.name, "github");
+        assert_eq!(
+            updated_auth_profile.kind,
+            AuthProfileKind::Bearer {
+                token: "some-other-token".to_string()
+            }
+        );
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(RedactedAuthProfile::from(updated_auth_profile))?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_unknown_auth_profile(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(auth_profiles_update),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/auth_profiles/{}",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .method(Method::PUT)
+            .set_json(json!({ "name": "github" }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Auth profile ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
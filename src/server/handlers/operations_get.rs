@@ -0,0 +1,104 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::operations::Operation;
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets an operation with the specified ID, including its progress.
+#[utoipa::path(
+    tags = ["operations"],
+    params(
+        ("operation_id" = Uuid, Path, description = "A unique operation ID."),
+    ),
+    responses(
+        (status = 200, description = "Operation with the specified ID.", body = Operation),
+        (status = NOT_FOUND, description = "Operation with the specified ID was not found or the ID is not a valid UUID.")
+    )
+)]
+#[get("/api/operations/{operation_id}")]
+pub async fn operations_get(
+    state: web::Data<ServerState>,
+    operation_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.operations().get_operation(*operation_id).await {
+        Ok(Some(operation)) => Ok(HttpResponse::Ok().json(operation)),
+        Ok(None) => Ok(HttpResponse::NotFound().finish()),
+        Err(err) => {
+            error!("Failed to retrieve operation: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::operations_get::operations_get, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::operations::OperationKind;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn can_retrieve_operation(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(operations_get),
+        )
+        .await;
+
+        let operation = server_state
+            .api
+            .operations()
+            .create_operation(OperationKind::TrackersRun, 10)
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/operations/{}",
+                operation.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(operation)?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn returns_not_found_for_unknown_operation(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(operations_get),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(
+                "https://retrack.dev/api/operations/00000000-0000-0000-0000-000000000001",
+            )
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 404);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use actix_web_lab::extract::Query;
+use retrack_types::search::{SearchMatch, SearchParams};
+use tracing::error;
+
+/// Searches the data revisions of all trackers for the specified query (e.g., to find every
+/// tracker whose data mentions a specific CVE). Matching revisions are returned from the newest to
+/// the oldest.
+#[utoipa::path(
+    tags = ["search"],
+    params(SearchParams),
+    responses(
+        (status = 200, description = "A list of tracker data revisions that match the search query.", body = [SearchMatch]),
+        (status = BAD_REQUEST, description = "Cannot search tracker data with the specified parameters.")
+    )
+)]
+#[get("/api/search")]
+pub async fn search_get(
+    state: web::Data<ServerState>,
+    params: Query<SearchParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.search().search(params.into_inner()).await {
+        Ok(matches) => Ok(HttpResponse::Ok().json(matches)),
+        Err(err) => {
+            error!("Failed to search tracker data: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{handlers::search_get::search_get, server_state::tests::mock_server_state},
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::{
+        search::SearchMatch,
+        trackers::{TrackerDataRevision, TrackerDataValue},
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_search_trackers_data(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker_data_revision(&TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: tracker.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!("Update nginx to 1.27")),
+                provenance: None,
+                sequence: None,
+            })
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(search_get),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/search?query=nginx&tag=env:prod")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<Vec<SearchMatch>>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            vec![SearchMatch {
+                tracker_id: tracker.id,
+                tracker_name: "name_one".to_string(),
+                revision_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                snippet: "Update <b>nginx</b> to 1.27".to_string(),
+            }]
+        );
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/search?query=nginx&tag=env:staging")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""[]""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_query(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(search_get),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/search?query=%20").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Search query cannot be empty or longer than 200 characters.\"}""###
+        );
+
+        Ok(())
+    }
+}
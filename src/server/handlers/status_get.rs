@@ -37,21 +37,42 @@ pub async fn status_get(
 
 #[cfg(test)]
 mod tests {
-    use crate::server::{handlers::status_get::status_get, server_state::tests::mock_server_state};
+    use crate::{
+        server::{
+            handlers::status_get::status_get, server_state::tests::mock_server_state_with_config,
+        },
+        tests::mock_config,
+    };
     use actix_web::{
         body::MessageBody,
         test::{call_service, init_service, TestRequest},
         web, App,
     };
-    use insta::assert_snapshot;
+    use httpmock::MockServer;
+    use insta::assert_json_snapshot;
+    use serde_json::{json, Value as JsonValue};
     use sqlx::PgPool;
-    use std::str::from_utf8;
+    use url::Url;
 
     #[sqlx::test]
     async fn can_return_status(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let status_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/status");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body(json!({
+                    "version": "1.0.0",
+                    "capabilities": { "engines": ["chromium"] }
+                }));
+        });
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse("http://localhost:7272")?;
         let app = init_service(
             App::new()
-                .app_data(web::Data::new(mock_server_state(pool.clone()).await?))
+                .app_data(web::Data::new(
+                    mock_server_state_with_config(pool.clone(), config).await?,
+                ))
                 .service(status_get),
         )
         .await;
@@ -64,7 +85,76 @@ mod tests {
         assert_eq!(response.status(), 200);
 
         let body = response.into_body().try_into_bytes().unwrap();
-        assert_snapshot!(from_utf8(&body)?, @r###"{"version":"0.0.1","scheduler":{"operational":true}}"###);
+        assert_json_snapshot!(
+            serde_json::from_slice::<JsonValue>(&body)?,
+            { ".schemaVersion" => "[schema-version]", ".features" => "[features]" },
+            @r###"
+        {
+          "version": "0.0.1",
+          "scheduler": {
+            "operational": true
+          },
+          "schemaVersion": "[schema-version]",
+          "features": "[features]",
+          "components": {
+            "webScraper": {
+              "url": "http://localhost:7272/",
+              "reachable": false
+            },
+            "smtp": {
+              "configured": true
+            },
+            "translator": {
+              "configured": false
+            },
+            "summarizer": {
+              "configured": false
+            },
+            "embeddings": {
+              "configured": false
+            }
+          },
+          "jsRuntime": {
+            "maxHeapSize": 10485760,
+            "maxScriptExecutionTime": 10000
+          }
+        }
+        "###
+        );
+        status_mock.assert_hits(0);
+
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(
+                    mock_server_state_with_config(pool.clone(), config).await?,
+                ))
+                .service(status_get),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/status").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let body =
+            serde_json::from_slice::<JsonValue>(&response.into_body().try_into_bytes().unwrap())?;
+        assert_json_snapshot!(body["components"]["webScraper"], {
+            ".url" => "[web-scraper-url]"
+        }, @r###"
+        {
+          "url": "[web-scraper-url]",
+          "reachable": true,
+          "engines": [
+            "chromium"
+          ]
+        }
+        "###);
+        status_mock.assert_hits(1);
 
         Ok(())
     }
@@ -0,0 +1,117 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::templates::{Template, TemplateCreateParams};
+use tracing::error;
+
+/// Creates a new email template with the specified parameters.
+#[utoipa::path(
+    tags = ["templates"],
+    request_body = TemplateCreateParams,
+    responses(
+        (status = 200, description = "Email template was successfully created.", body = Template),
+        (status = BAD_REQUEST, description = "Cannot create an email template with the specified properties.")
+    )
+)]
+#[post("/api/templates")]
+pub async fn templates_create(
+    state: web::Data<ServerState>,
+    params: web::Json<TemplateCreateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .email_templates()
+        .create_template(params.into_inner())
+        .await
+    {
+        Ok(template) => Ok(HttpResponse::Ok().json(template)),
+        Err(err) => {
+            error!("Failed to create template: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::templates_create::templates_create, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_create_template(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/templates")
+                .method(Method::POST)
+                .set_json(json!({
+                    "name": "changes",
+                    "subject": "{{tracker.name}} changed",
+                    "text": "{{diff}}",
+                    "html": "<pre>{{diff}}</pre>"
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let templates = server_state.api.email_templates().get_templates().await?;
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "changes");
+        assert_eq!(templates[0].subject, "{{tracker.name}} changed");
+        assert_eq!(templates[0].text, "{{diff}}");
+        assert_eq!(templates[0].html, Some("<pre>{{diff}}</pre>".to_string()));
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(&templates[0])?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_params(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/templates")
+                .method(Method::POST)
+                .set_json(json!({ "name": "", "subject": "subject", "text": "text" }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Template name cannot be empty.\"}""###
+        );
+
+        Ok(())
+    }
+}
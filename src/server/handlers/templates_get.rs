@@ -0,0 +1,98 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::templates::Template;
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets an email template with the specified ID.
+#[utoipa::path(
+    tags = ["templates"],
+    params(
+        ("template_id" = Uuid, Path, description = "A unique email template ID."),
+    ),
+    responses(
+        (status = 200, description = "Email template with the specified ID.", body = Template),
+        (status = NOT_FOUND, description = "Email template with the specified ID was not found or the ID is not a valid UUID.")
+    )
+)]
+#[get("/api/templates/{template_id}")]
+pub async fn templates_get(
+    state: web::Data<ServerState>,
+    template_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.email_templates().get_template(*template_id).await {
+        Ok(Some(template)) => Ok(HttpResponse::Ok().json(template)),
+        Ok(None) => Ok(HttpResponse::NotFound().finish()),
+        Err(err) => {
+            error!("Failed to retrieve template: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::templates_get::templates_get, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::templates::TemplateCreateParams;
+    use sqlx::PgPool;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_get_template(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_get),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/templates/{}",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 404);
+
+        let template = server_state
+            .api
+            .email_templates()
+            .create_template(TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: None,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/templates/{}",
+                template.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(template)?
+        );
+
+        Ok(())
+    }
+}
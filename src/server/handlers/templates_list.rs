@@ -0,0 +1,85 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::templates::Template;
+use tracing::error;
+
+/// Gets a list of email templates.
+#[utoipa::path(
+    tags = ["templates"],
+    responses(
+        (status = 200, description = "A list of email templates ordered by name.", body = [Template])
+    )
+)]
+#[get("/api/templates")]
+pub async fn templates_list(state: web::Data<ServerState>) -> Result<HttpResponse, RetrackError> {
+    match state.api.email_templates().get_templates().await {
+        Ok(templates) => Ok(HttpResponse::Ok().json(templates)),
+        Err(err) => {
+            error!("Failed to retrieve templates: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::templates_list::templates_list, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::templates::TemplateCreateParams;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_list_views(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_list),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/templates").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        let template = server_state
+            .api
+            .email_templates()
+            .create_template(TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: None,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/templates").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(vec![template])?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{delete, web, HttpResponse};
+use tracing::error;
+use uuid::Uuid;
+
+/// Removes an email template with the specified ID. Email actions that use the template fail
+/// until it's recreated or the actions are updated.
+#[utoipa::path(
+    tags = ["templates"],
+    params(
+        ("template_id" = Uuid, Path, description = "A unique email template ID."),
+    ),
+    responses(
+        (status = NO_CONTENT, description = "Email template with the specified ID was successfully removed.")
+    )
+)]
+#[delete("/api/templates/{template_id}")]
+pub async fn templates_remove(
+    state: web::Data<ServerState>,
+    template_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .email_templates()
+        .remove_template(*template_id)
+        .await
+    {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => {
+            error!("Failed to remove template: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::templates_remove::templates_remove, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::templates::TemplateCreateParams;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn can_remove_template(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_remove),
+        )
+        .await;
+
+        let template = server_state
+            .api
+            .email_templates()
+            .create_template(TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: None,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/templates/{}",
+                template.id
+            ))
+            .method(Method::DELETE)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 204);
+        assert!(server_state
+            .api
+            .email_templates()
+            .get_templates()
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+}
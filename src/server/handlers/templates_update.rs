@@ -0,0 +1,140 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{put, web, HttpResponse};
+use retrack_types::templates::{Template, TemplateUpdateParams};
+use tracing::error;
+use uuid::Uuid;
+
+/// Updates an existing email template with the specified parameters.
+#[utoipa::path(
+    tags = ["templates"],
+    params(
+        ("template_id" = Uuid, Path, description = "A unique email template ID."),
+    ),
+    request_body = TemplateUpdateParams,
+    responses(
+        (status = 200, description = "Email template was successfully updated.", body = Template),
+        (status = BAD_REQUEST, description = "Cannot update an email template with the specified properties.")
+    )
+)]
+#[put("/api/templates/{template_id}")]
+pub async fn templates_update(
+    state: web::Data<ServerState>,
+    template_id: web::Path<Uuid>,
+    params: web::Json<TemplateUpdateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .email_templates()
+        .update_template(*template_id, params.into_inner())
+        .await
+    {
+        Ok(template) => Ok(HttpResponse::Ok().json(template)),
+        Err(err) => {
+            error!("Failed to update template: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::templates_update::templates_update, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::templates::TemplateCreateParams;
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_update_template(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_update),
+        )
+        .await;
+
+        let template = server_state
+            .api
+            .email_templates()
+            .create_template(TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: None,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/templates/{}",
+                template.id
+            ))
+            .method(Method::PUT)
+            .set_json(json!({ "subject": "{{tracker.name}} updated" }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let updated_template = server_state
+            .api
+            .email_templates()
+            .get_template(template.id)
+            .await?
+            .unwrap();
+        assert_eq!(updated_template.name, "changes");
+        assert_eq!(updated_template.subject, "{{tracker.name}} updated");
+        assert_eq!(updated_template.text, "{{diff}}");
+        assert_eq!(updated_template.html, None);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(updated_template)?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_unknown_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(templates_update),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/templates/{}",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .method(Method::PUT)
+            .set_json(json!({ "name": "changes" }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Template ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::TrackerDataRevision;
+use tracing::error;
+use uuid::Uuid;
+
+/// Accepts a quarantined data revision into the revisions history of a tracker with the specified
+/// ID. Tracker actions are executed for the accepted revision as if it has just been fetched.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID."),
+        ("revision_id" = Uuid, Path, description = "A unique quarantined data revision ID.")
+    ),
+    responses(
+        (status = OK, description = "Accepted data revision.", body = TrackerDataRevision),
+        (status = BAD_REQUEST, description = "Cannot accept quarantined data revision with the specified ID.")
+    )
+)]
+#[post("/api/trackers/{tracker_id}/quarantine/{revision_id}/accept")]
+pub async fn trackers_accept_quarantined_revision(
+    state: web::Data<ServerState>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> Result<HttpResponse, RetrackError> {
+    let (tracker_id, revision_id) = path.into_inner();
+    let trackers = state.api.trackers();
+    match trackers
+        .accept_tracker_quarantined_revision(tracker_id, revision_id)
+        .await
+    {
+        Ok(revision) => Ok(HttpResponse::Ok().json(revision)),
+        Err(err) => {
+            error!("Failed to accept quarantined tracker data revision: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_accept_quarantined_revision::trackers_accept_quarantined_revision,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        TrackerDataRevision, TrackerDataValue, TrackerQuarantinedRevision,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_accept_quarantined_revision(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+
+        // Create tracker and quarantine tracker data revision.
+        let trackers_api = server_state.api.trackers();
+        let tracker = trackers_api
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+        let quarantined_revision = TrackerQuarantinedRevision {
+            revision: TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: tracker.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!({ "releases": [] })),
+                provenance: None,
+                sequence: None,
+            },
+            reason: "Value at `$.releases` is empty.".to_string(),
+        };
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker_quarantined_revision(&quarantined_revision)
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_accept_quarantined_revision),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine/{}/accept",
+                tracker.id, quarantined_revision.revision.id
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let revision = serde_json::from_slice::<TrackerDataRevision>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(
+            revision,
+            TrackerDataRevision {
+                sequence: Some(1),
+                ..quarantined_revision.revision.clone()
+            }
+        );
+        assert_eq!(
+            trackers_api
+                .get_tracker_data(tracker.id, Default::default())
+                .await?,
+            vec![revision]
+        );
+        assert!(trackers_api
+            .get_tracker_quarantine(tracker.id)
+            .await?
+            .is_empty());
+
+        // Revision cannot be accepted twice.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine/{}/accept",
+                tracker.id, quarantined_revision.revision.id
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Quarantined data revision ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_accept_quarantined_revision_for_unknown_tracker(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_accept_quarantined_revision),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine/{}/accept",
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                uuid!("00000000-0000-0000-0000-000000000002")
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+}
@@ -85,6 +85,9 @@ mod tests {
                     .with_tags(vec!["tag_two".to_string()])
                     .with_actions(vec![TrackerAction::Email(EmailAction {
                         to: vec!["dev@retrack.dev".to_string()],
+                        timeout: None,
+                        template: None,
+                        attachment: None,
                     })])
                     .build(),
             )
@@ -0,0 +1,430 @@
+use crate::{
+    error::Error as RetrackError,
+    server::{BearerToken, ServerState},
+};
+use actix_web::{post, web, HttpResponse};
+use actix_web_lab::extract::Query;
+use retrack_types::{
+    api_tokens::ApiTokenScope,
+    operations::{Operation, OperationKind},
+    trackers::TrackersListParams,
+};
+use tracing::error;
+
+/// Triggers immediate run for all trackers that have all specified tags. Trackers are run in the
+/// background, and the response includes the operation that can be used to track the progress.
+/// If the request is authorized with an API token, only trackers with all token tags are run.
+/// Requests without an API token are rejected if `api.require_tokens` is enabled.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(TrackersListParams),
+    responses(
+        (status = ACCEPTED, description = "Trackers run operation was started.", body = Operation),
+        (status = UNAUTHORIZED, description = "API token is missing or not valid."),
+        (status = FORBIDDEN, description = "API token doesn't allow running trackers.")
+    )
+)]
+#[post("/api/trackers/run")]
+pub async fn trackers_bulk_run(
+    state: web::Data<ServerState>,
+    params: Query<TrackersListParams>,
+    bearer_token: BearerToken,
+) -> Result<HttpResponse, RetrackError> {
+    let mut params = params.into_inner();
+    if let Some(ref token) = bearer_token.into_token(&state.api.config.api)? {
+        match state
+            .api
+            .api_tokens()
+            .authorize_api_token(token, ApiTokenScope::RunTrackers)
+            .await
+        {
+            Ok(api_token) => params.tags.extend(api_token.tags),
+            Err(err) => {
+                error!("Failed to authorize trackers run: {err:?}");
+                return Err(err.into());
+            }
+        }
+    }
+
+    let trackers = match state.api.trackers().get_trackers(params).await {
+        Ok(trackers) => trackers,
+        Err(err) => {
+            error!("Failed to retrieve trackers to run: {err:?}");
+            return Err(err.into());
+        }
+    };
+
+    let operation = match state
+        .api
+        .operations()
+        .create_operation(OperationKind::TrackersRun, trackers.len())
+        .await
+    {
+        Ok(operation) => operation,
+        Err(err) => {
+            error!("Failed to create trackers run operation: {err:?}");
+            return Err(err.into());
+        }
+    };
+
+    let api = state.api.clone();
+    let background_operation = operation.clone();
+    tokio::spawn(async move {
+        if let Err(err) = api
+            .trackers()
+            .run_trackers(background_operation, trackers)
+            .await
+        {
+            error!("Failed to run trackers: {err:?}");
+        }
+    });
+
+    Ok(HttpResponse::Accepted().json(operation))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::{ApiConfig, Config},
+        server::{
+            handlers::trackers_bulk_run::trackers_bulk_run,
+            server_state::tests::{mock_server_state, mock_server_state_with_config},
+        },
+        tests::{mock_config, TrackerCreateParamsBuilder},
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::{header::AUTHORIZATION, Method},
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use httpmock::MockServer;
+    use insta::assert_debug_snapshot;
+    use retrack_types::{
+        api_tokens::{ApiTokenCreateParams, ApiTokenScope},
+        operations::{Operation, OperationKind},
+        trackers::{ApiTarget, TargetRequest, TrackerListRevisionsParams, TrackerTarget},
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::{str::from_utf8, time::Duration};
+
+    #[sqlx::test]
+    async fn can_run_trackers_by_tags(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/get-call");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!({ "one": 1 }));
+        });
+
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_bulk_run),
+        )
+        .await;
+
+        // Create trackers.
+        let trackers = server_state.api.trackers();
+        let api_target = TrackerTarget::Api(ApiTarget {
+            requests: vec![TargetRequest::new(server.url("/api/get-call").parse()?)],
+            configurator: None,
+            extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
+        });
+        let tracker_one = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_target(api_target.clone())
+                    .with_tags(vec!["vendor:acme".to_string()])
+                    .build(),
+            )
+            .await?;
+        let tracker_two = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_two")
+                    .with_target(api_target.clone())
+                    .with_tags(vec!["vendor:acme".to_string(), "other".to_string()])
+                    .build(),
+            )
+            .await?;
+        let tracker_three = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_three")
+                    .with_target(api_target)
+                    .with_tags(vec!["other".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/run?tag=vendor:acme")
+                .method(Method::POST)
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 202);
+
+        let operation =
+            serde_json::from_slice::<Operation>(&response.into_body().try_into_bytes().unwrap())?;
+        assert_eq!(operation.kind, OperationKind::TrackersRun);
+        assert_eq!(operation.total, 2);
+
+        // Wait until trackers are run in the background.
+        let params = || TrackerListRevisionsParams {
+            calculate_diff: false,
+            diff_format: None,
+            page_size: None,
+            after: None,
+            since: None,
+            until: None,
+        };
+        for tracker in [&tracker_one, &tracker_two] {
+            let mut revisions = vec![];
+            for _ in 0..50 {
+                revisions = trackers.get_tracker_data(tracker.id, params()).await?;
+                if !revisions.is_empty() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            assert_eq!(revisions.len(), 1);
+            assert_eq!(revisions[0].data.value(), &json!({ "one": 1 }));
+        }
+
+        content_mock.assert_hits(2);
+
+        // Wait until the operation progress is persisted.
+        let operations = server_state.api.operations();
+        let mut operation = operations.get_operation(operation.id).await?.unwrap();
+        for _ in 0..50 {
+            if operation.is_finished() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            operation = operations.get_operation(operation.id).await?.unwrap();
+        }
+        assert_eq!(operation.completed, 2);
+        assert!(operation.errors.is_empty());
+
+        assert!(trackers
+            .get_tracker_data(tracker_three.id, params())
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_tags(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_bulk_run),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/run?tag={}",
+                "a".repeat(51)
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Tracker tags cannot be empty or longer than 50 characters.\"}""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_run_trackers_with_api_token(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/get-call");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!({ "one": 1 }));
+        });
+
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_bulk_run),
+        )
+        .await;
+
+        let trackers = server_state.api.trackers();
+        let api_target = TrackerTarget::Api(ApiTarget {
+            requests: vec![TargetRequest::new(server.url("/api/get-call").parse()?)],
+            configurator: None,
+            extractor: None,
+            capture_tls: false,
+            cookie_jar: false,
+            selectors: None,
+        });
+        let tracker_one = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_target(api_target.clone())
+                    .with_tags(vec!["partner:acme".to_string()])
+                    .build(),
+            )
+            .await?;
+        trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_two")
+                    .with_target(api_target)
+                    .with_tags(vec!["other".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        let api_tokens = server_state.api.api_tokens();
+        let run_token = api_tokens
+            .create_api_token(ApiTokenCreateParams {
+                name: "run".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::RunTrackers],
+            })
+            .await?;
+        let revisions_token = api_tokens
+            .create_api_token(ApiTokenCreateParams {
+                name: "revisions".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::CreateRevisions],
+            })
+            .await?;
+
+        // Only trackers with the token tags are run, even if no tags are specified.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/run")
+                .method(Method::POST)
+                .insert_header((AUTHORIZATION, format!("Bearer {}", run_token.value)))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 202);
+
+        let operation =
+            serde_json::from_slice::<Operation>(&response.into_body().try_into_bytes().unwrap())?;
+        assert_eq!(operation.total, 1);
+
+        let params = || TrackerListRevisionsParams {
+            calculate_diff: false,
+            diff_format: None,
+            page_size: None,
+            after: None,
+            since: None,
+            until: None,
+        };
+        let mut revisions = vec![];
+        for _ in 0..50 {
+            revisions = trackers.get_tracker_data(tracker_one.id, params()).await?;
+            if !revisions.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(revisions.len(), 1);
+        content_mock.assert_hits(1);
+
+        // Token without the required scope.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/run")
+                .method(Method::POST)
+                .insert_header((AUTHORIZATION, format!("Bearer {}", revisions_token.value)))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 403);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"API token ('revisions') doesn't allow this operation.\"}""###
+        );
+
+        // Unknown token.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/run")
+                .method(Method::POST)
+                .insert_header((AUTHORIZATION, "Bearer rtk_unknown"))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 401);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"API token is not valid.\"}""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_without_api_token_if_required(pool: PgPool) -> anyhow::Result<()> {
+        let config = Config {
+            api: ApiConfig {
+                require_tokens: true,
+            },
+            ..mock_config()?
+        };
+        let server_state = web::Data::new(mock_server_state_with_config(pool, config).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_bulk_run),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/run")
+                .method(Method::POST)
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 401);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Request must be authorized with an API token.\"}""###
+        );
+
+        let run_token = server_state
+            .api
+            .api_tokens()
+            .create_api_token(ApiTokenCreateParams {
+                name: "run".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::RunTrackers],
+            })
+            .await?;
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/run")
+                .method(Method::POST)
+                .insert_header((AUTHORIZATION, format!("Bearer {}", run_token.value)))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 202);
+
+        Ok(())
+    }
+}
@@ -97,12 +97,16 @@ mod tests {
             tracker_id: tracker.id,
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
             data: TrackerDataValue::new(json!("\"some-data\"")),
+            provenance: None,
+            sequence: None,
         };
         let data_revision_two = TrackerDataRevision {
             id: uuid!("00000000-0000-0000-0000-000000000002"),
             tracker_id: tracker.id,
             created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
             data: TrackerDataValue::new(json!("\"some-data\"")),
+            provenance: None,
+            sequence: None,
         };
         trackers_db
             .insert_tracker_data_revision(&data_revision_one)
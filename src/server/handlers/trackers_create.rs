@@ -94,6 +94,10 @@ mod tests {
                 params: None,
                 user_agent: None,
                 ignore_https_errors: false,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             })
         );
 
@@ -174,6 +178,10 @@ mod tests {
                 params: Some(json!({ "param": "value" })),
                 user_agent: Some("Retrack/1.0.0".to_string()),
                 ignore_https_errors: true,
+                extract_article: false,
+                engine: None,
+                steps: None,
+                selectors: None,
             })
         );
         assert_eq!(
@@ -1,28 +1,67 @@
-use crate::{error::Error as RetrackError, server::ServerState};
+use crate::{
+    error::Error as RetrackError,
+    server::{BearerToken, ServerState},
+};
 use actix_web::{post, web, HttpResponse};
-use retrack_types::trackers::TrackerDataRevision;
+use retrack_types::{api_tokens::ApiTokenScope, trackers::TrackerRunResult};
 use tracing::error;
 use uuid::Uuid;
 
 /// Tries to create a new revision for a tracker with the specified ID. If revision is equal to the
-/// previous one, it will not be created, and the previous revision will be returned.
+/// previous one, it will not be created, and the previous revision will be returned. The response
+/// describes the outcome of the data check, the status of the executed actions, and timings.
+/// If the request is authorized with an API token, the tracker must have all token tags.
+/// Requests without an API token are rejected if `api.require_tokens` is enabled.
 #[utoipa::path(
     tags = ["trackers"],
     params(
         ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
     ),
     responses(
-        (status = OK, description = "Newly created data revision.", body = TrackerDataRevision)
+        (status = OK, description = "Result of the tracker data check.", body = TrackerRunResult),
+        (status = UNAUTHORIZED, description = "API token is missing or not valid."),
+        (status = FORBIDDEN, description = "API token doesn't allow creating revisions for the tracker.")
     )
 )]
 #[post("/api/trackers/{tracker_id}/revisions")]
 pub async fn trackers_create_revision(
     state: web::Data<ServerState>,
     tracker_id: web::Path<Uuid>,
+    bearer_token: BearerToken,
 ) -> Result<HttpResponse, RetrackError> {
     let trackers = state.api.trackers();
-    match trackers.create_tracker_data_revision(*tracker_id).await {
-        Ok(revision) => Ok(HttpResponse::Ok().json(revision)),
+    if let Some(ref token) = bearer_token.into_token(&state.api.config.api)? {
+        let api_token = match state
+            .api
+            .api_tokens()
+            .authorize_api_token(token, ApiTokenScope::CreateRevisions)
+            .await
+        {
+            Ok(api_token) => api_token,
+            Err(err) => {
+                error!("Failed to authorize tracker data revision: {err:?}");
+                return Err(err.into());
+            }
+        };
+
+        // Don't disclose whether the tracker exists if it's not accessible with the token.
+        let tracker = match trackers.get_tracker(*tracker_id).await {
+            Ok(tracker) => tracker,
+            Err(err) => {
+                error!("Failed to retrieve tracker: {err:?}");
+                return Err(err.into());
+            }
+        };
+        if !tracker.is_some_and(|tracker| api_token.allows_tags(&tracker.tags)) {
+            return Err(RetrackError::access_forbidden(format!(
+                "API token ('{}') doesn't allow access to the tracker ('{tracker_id}').",
+                api_token.name
+            )));
+        }
+    }
+
+    match trackers.run_tracker(*tracker_id).await {
+        Ok(result) => Ok(HttpResponse::Ok().json(result)),
         Err(err) => {
             error!("Failed to create tracker data revision: {err:?}");
             Err(err.into())
@@ -41,20 +80,24 @@ mod tests {
     };
     use actix_web::{
         body::MessageBody,
-        http::Method,
+        http::{header::AUTHORIZATION, Method},
         test::{call_service, init_service, TestRequest},
         web, App,
     };
     use httpmock::MockServer;
     use insta::assert_debug_snapshot;
-    use retrack_types::trackers::{
-        TrackerDataRevision, TrackerDataValue, TrackerListRevisionsParams,
+    use retrack_types::{
+        api_tokens::{ApiTokenCreateParams, ApiTokenScope},
+        trackers::{
+            TrackerDataValue, TrackerListRevisionsParams, TrackerRunActionResult,
+            TrackerRunActionStatus, TrackerRunOutcome, TrackerRunResult,
+        },
     };
     use serde_json::json;
     use sqlx::PgPool;
     use std::str::from_utf8;
     use url::Url;
-    use uuid::uuid;
+    use uuid::{uuid, Uuid};
 
     #[sqlx::test]
     async fn can_create_tracker_data(pool: PgPool) -> anyhow::Result<()> {
@@ -102,21 +145,56 @@ mod tests {
         .await;
         assert_eq!(response.status(), 200);
 
-        let revision = serde_json::from_slice::<TrackerDataRevision>(
+        let result = serde_json::from_slice::<TrackerRunResult>(
             &response.into_body().try_into_bytes().unwrap(),
         )?;
+        assert_eq!(result.outcome, TrackerRunOutcome::Created);
+        assert_eq!(
+            result.actions,
+            vec![TrackerRunActionResult {
+                index: 0,
+                status: TrackerRunActionStatus::Succeeded,
+            }]
+        );
+        assert!(result.timings.total >= result.timings.fetch);
+
         let saved_revision = trackers_api
             .get_tracker_data(
                 tracker.id,
                 TrackerListRevisionsParams {
                     calculate_diff: false,
+                    diff_format: None,
+                    page_size: None,
+                    after: None,
+                    since: None,
+                    until: None,
                 },
             )
             .await?;
-        assert_eq!(saved_revision[0].id, revision.id);
-        assert_eq!(saved_revision[0].data, revision.data);
+        assert_eq!(saved_revision[0].id, result.revision.id);
+        assert_eq!(saved_revision[0].data, result.revision.data);
+
+        // Same data doesn't produce a new revision.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let result = serde_json::from_slice::<TrackerRunResult>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(result.outcome, TrackerRunOutcome::Unchanged);
+        assert!(result.actions.is_empty());
+        assert_eq!(result.revision.id, saved_revision[0].id);
 
-        content_mock.assert();
+        content_mock.assert_hits(2);
 
         Ok(())
     }
@@ -146,4 +224,167 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_create_tracker_data_with_api_token(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        let server_state = web::Data::new(mock_server_state_with_config(pool, config).await?);
+
+        let trackers_api = server_state.api.trackers();
+        let tracker = trackers_api
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_tags(vec!["partner:acme".to_string(), "prod".to_string()])
+                    .build(),
+            )
+            .await?;
+        let other_tracker = trackers_api
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_two")
+                    .with_tags(vec!["prod".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        let api_tokens = server_state.api.api_tokens();
+        let revisions_token = api_tokens
+            .create_api_token(ApiTokenCreateParams {
+                name: "revisions".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::CreateRevisions],
+            })
+            .await?;
+        let run_token = api_tokens
+            .create_api_token(ApiTokenCreateParams {
+                name: "run".to_string(),
+                tags: vec!["partner:acme".to_string()],
+                scopes: vec![ApiTokenScope::RunTrackers],
+            })
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_create_revision),
+        )
+        .await;
+
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/execute");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!("\"rev_1\""));
+        });
+
+        let create_revision = |tracker_id: Uuid, token: &str| {
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{tracker_id}/revisions"
+            ))
+            .method(Method::POST)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request()
+        };
+
+        // Tracker with all token tags.
+        let response =
+            call_service(&app, create_revision(tracker.id, &revisions_token.value)).await;
+        assert_eq!(response.status(), 200);
+
+        let result = serde_json::from_slice::<TrackerRunResult>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(result.outcome, TrackerRunOutcome::Created);
+
+        // Tracker without token tags.
+        let response = call_service(
+            &app,
+            create_revision(other_tracker.id, &revisions_token.value),
+        )
+        .await;
+        assert_eq!(response.status(), 403);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?.replace(&other_tracker.id.to_string(), "[ID]"),
+            @r###""{\"message\":\"API token ('revisions') doesn't allow access to the tracker ('[ID]').\"}""###
+        );
+
+        // Unknown tracker is indistinguishable from the inaccessible one.
+        let response = call_service(
+            &app,
+            create_revision(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                &revisions_token.value,
+            ),
+        )
+        .await;
+        assert_eq!(response.status(), 403);
+
+        // Token without the required scope.
+        let response = call_service(&app, create_revision(tracker.id, &run_token.value)).await;
+        assert_eq!(response.status(), 403);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"API token ('run') doesn't allow this operation.\"}""###
+        );
+
+        // Unknown token.
+        let response = call_service(&app, create_revision(tracker.id, "rtk_unknown")).await;
+        assert_eq!(response.status(), 401);
+
+        content_mock.assert_hits(1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_without_api_token_if_required(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.api.require_tokens = true;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        let server_state = web::Data::new(mock_server_state_with_config(pool, config).await?);
+
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_create_revision),
+        )
+        .await;
+
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/execute");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!("\"rev_1\""));
+        });
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 401);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Request must be authorized with an API token.\"}""###
+        );
+
+        content_mock.assert_hits(0);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,133 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{delete, web, HttpResponse};
+use tracing::error;
+use uuid::Uuid;
+
+/// Discards a quarantined data revision of a tracker with the specified ID.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID."),
+        ("revision_id" = Uuid, Path, description = "A unique quarantined data revision ID.")
+    ),
+    responses(
+        (status = NO_CONTENT, description = "Quarantined data revision was successfully discarded."),
+        (status = BAD_REQUEST, description = "Cannot discard quarantined data revision with the specified ID.")
+    )
+)]
+#[delete("/api/trackers/{tracker_id}/quarantine/{revision_id}")]
+pub async fn trackers_discard_quarantined_revision(
+    state: web::Data<ServerState>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> Result<HttpResponse, RetrackError> {
+    let (tracker_id, revision_id) = path.into_inner();
+    let trackers = state.api.trackers();
+    match trackers
+        .discard_tracker_quarantined_revision(tracker_id, revision_id)
+        .await
+    {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => {
+            error!("Failed to discard quarantined tracker data revision: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_discard_quarantined_revision::trackers_discard_quarantined_revision,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        TrackerDataRevision, TrackerDataValue, TrackerQuarantinedRevision,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_discard_quarantined_revision(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+
+        // Create tracker and quarantine tracker data revision.
+        let trackers_api = server_state.api.trackers();
+        let tracker = trackers_api
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+        let quarantined_revision = TrackerQuarantinedRevision {
+            revision: TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: tracker.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!({ "releases": [] })),
+                provenance: None,
+                sequence: None,
+            },
+            reason: "Value at `$.releases` is empty.".to_string(),
+        };
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker_quarantined_revision(&quarantined_revision)
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_discard_quarantined_revision),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine/{}",
+                tracker.id, quarantined_revision.revision.id
+            ))
+            .method(Method::DELETE)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 204);
+        assert!(trackers_api
+            .get_tracker_quarantine(tracker.id)
+            .await?
+            .is_empty());
+        // Discarded revision doesn't make it into the revisions history.
+        assert!(trackers_api
+            .get_tracker_data(tracker.id, Default::default())
+            .await?
+            .is_empty());
+
+        // Revision cannot be discarded twice.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine/{}",
+                tracker.id, quarantined_revision.revision.id
+            ))
+            .method(Method::DELETE)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Quarantined data revision ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::trackers::TrackerBundle;
+use tracing::error;
+use uuid::Uuid;
+
+/// Exports a tracker with the specified ID along with all its data revisions as a single bundle
+/// that can be imported into another Retrack instance.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
+    ),
+    responses(
+        (status = OK, description = "Tracker bundle.", body = TrackerBundle),
+        (status = BAD_REQUEST, description = "Tracker with the specified ID is not found.")
+    )
+)]
+#[get("/api/trackers/{tracker_id}/bundle")]
+pub async fn trackers_export_bundle(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers.export_tracker_bundle(*tracker_id).await {
+        Ok(bundle) => Ok(HttpResponse::Ok().json(bundle)),
+        Err(err) => {
+            error!("Failed to export tracker bundle: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_export_bundle::trackers_export_bundle,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerBundle, TrackerDataRevision, TrackerDataValue};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_export_tracker_bundle(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+
+        let trackers_db = server_state.api.db.trackers();
+        let data_revision_one = TrackerDataRevision {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_id: tracker.id,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            data: TrackerDataValue::new(json!("some-data")),
+            provenance: None,
+            sequence: None,
+        };
+        let data_revision_two = TrackerDataRevision {
+            id: uuid!("00000000-0000-0000-0000-000000000002"),
+            tracker_id: tracker.id,
+            created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+            data: TrackerDataValue::new(json!("other-data")),
+            provenance: None,
+            sequence: None,
+        };
+        trackers_db
+            .insert_tracker_data_revision(&data_revision_one)
+            .await?;
+        trackers_db
+            .insert_tracker_data_revision(&data_revision_two)
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_export_bundle),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/bundle",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let bundle = serde_json::from_slice::<TrackerBundle>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(bundle.tracker.id, tracker.id);
+        assert_eq!(bundle.tracker.name, tracker.name);
+        assert_eq!(
+            bundle.revisions,
+            vec![
+                TrackerDataRevision {
+                    sequence: Some(1),
+                    ..data_revision_one
+                },
+                TrackerDataRevision {
+                    sequence: Some(2),
+                    ..data_revision_two
+                }
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_unknown_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_export_bundle),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/bundle",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, http::header::ContentDisposition, web, HttpResponse};
+use futures::stream;
+use retrack_types::trackers::{TrackerDataRevision, TrackerRevisionsExportParams};
+use tracing::error;
+use uuid::Uuid;
+
+/// Exports all stored revisions of a tracker with the specified ID in the requested format, so
+/// that the history of the tracker data can be analyzed with the external tools.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID."),
+        TrackerRevisionsExportParams
+    ),
+    responses(
+        (status = OK, description = "Exported tracker revisions.", content(
+            ([TrackerDataRevision] = "application/json"),
+            (String = "text/csv"),
+            (String = "application/x-ndjson")
+        )),
+        (status = BAD_REQUEST, description = "Cannot export revisions for a tracker with the specified parameters.")
+    )
+)]
+#[get("/api/trackers/{tracker_id}/revisions/export")]
+pub async fn trackers_export_revisions(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+    params: web::Query<TrackerRevisionsExportParams>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers
+        .export_tracker_data(*tracker_id, params.into_inner())
+        .await
+    {
+        Ok(export) => Ok(HttpResponse::Ok()
+            .content_type(export.content_type())
+            .insert_header(ContentDisposition::attachment(export.file_name()))
+            .streaming(stream::iter(export))),
+        Err(err) => {
+            error!("Failed to export tracker data revisions: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_export_revisions::trackers_export_revisions,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        test::{call_service, init_service, read_body, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_export_tracker_data(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+
+        let trackers_db = server_state.api.db.trackers();
+        for (id, data, created_at) in [
+            (
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                json!("one"),
+                946720800,
+            ),
+            (
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                json!("two"),
+                946720900,
+            ),
+        ] {
+            trackers_db
+                .insert_tracker_data_revision(&TrackerDataRevision {
+                    id,
+                    tracker_id: tracker.id,
+                    created_at: OffsetDateTime::from_unix_timestamp(created_at)?,
+                    data: TrackerDataValue::new(data),
+                    provenance: None,
+                    sequence: None,
+                })
+                .await?;
+        }
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_export_revisions),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions/export",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            response.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"revisions.json\""
+        );
+        assert_debug_snapshot!(from_utf8(&read_body(response).await)?, @r###""[{\"id\":\"00000000-0000-0000-0000-000000000001\",\"createdAt\":\"2000-01-01T10:00:00Z\",\"data\":\"one\"},{\"id\":\"00000000-0000-0000-0000-000000000002\",\"createdAt\":\"2000-01-01T10:01:40Z\",\"data\":\"two\"}]""###);
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions/export?format=csv&calculateDiff=true",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers().get(CONTENT_TYPE).unwrap(), "text/csv");
+        assert_eq!(
+            response.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"revisions.csv\""
+        );
+        assert_debug_snapshot!(from_utf8(&read_body(response).await)?, @r###""id,createdAt,data,diff\n00000000-0000-0000-0000-000000000001,2000-01-01T10:00:00Z,one,\n00000000-0000-0000-0000-000000000002,2000-01-01T10:01:40Z,two,\"@@ -1 +1 @@\n-one\n+two\n\"\n""###);
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions/export?format=ndjson",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/x-ndjson"
+        );
+        assert_debug_snapshot!(from_utf8(&read_body(response).await)?, @r###""{\"id\":\"00000000-0000-0000-0000-000000000001\",\"createdAt\":\"2000-01-01T10:00:00Z\",\"data\":\"one\"}\n{\"id\":\"00000000-0000-0000-0000-000000000002\",\"createdAt\":\"2000-01-01T10:01:40Z\",\"data\":\"two\"}\n""###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_if_tracker_does_not_exist(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_export_revisions),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(
+                "https://retrack.dev/api/trackers/00000000-0000-0000-0000-000000000001/revisions/export",
+            )
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
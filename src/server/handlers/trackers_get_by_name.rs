@@ -0,0 +1,159 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use actix_web_lab::extract::Query;
+use retrack_types::trackers::{Tracker, TrackersListParams};
+use tracing::error;
+
+/// Gets a tracker with the specified name (case-insensitive). If tracker names aren't globally
+/// unique, tags can be used to narrow down the search.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("name" = String, Path, description = "A tracker name."),
+        TrackersListParams
+    ),
+    responses(
+        (status = 200, description = "Tracker with the specified name.", body = Tracker),
+        (status = BAD_REQUEST, description = "Multiple trackers with the specified name were found."),
+        (status = NOT_FOUND, description = "Tracker with the specified name was not found.")
+    )
+)]
+#[get("/api/trackers/by-name/{name}")]
+pub async fn trackers_get_by_name(
+    state: web::Data<ServerState>,
+    name: web::Path<String>,
+    params: Query<TrackersListParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .trackers()
+        .get_tracker_by_name(&name, params.into_inner())
+        .await
+    {
+        Ok(Some(tracker)) => Ok(HttpResponse::Ok().json(tracker)),
+        Ok(None) => Ok(HttpResponse::NotFound().finish()),
+        Err(err) => {
+            error!("Failed to retrieve tracker by name: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::TrackerNameUniqueness,
+        server::{
+            handlers::trackers_get_by_name::trackers_get_by_name,
+            server_state::tests::{mock_server_state, mock_server_state_with_config},
+        },
+        tests::{mock_config, TrackerCreateParamsBuilder},
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_get_tracker_by_name(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+
+        // Create trackers.
+        let trackers = server_state.api.trackers();
+        let tracker = trackers
+            .create_tracker(TrackerCreateParamsBuilder::new("Name One").build())
+            .await?;
+        trackers
+            .create_tracker(TrackerCreateParamsBuilder::new("name_two").build())
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_get_by_name),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/by-name/name%20one")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::to_string(&tracker)?,
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?
+        );
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/by-name/name_three")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 404);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_get_tracker_by_name_and_tags(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.trackers.name_uniqueness = TrackerNameUniqueness::Tags;
+        let server_state = web::Data::new(mock_server_state_with_config(pool, config).await?);
+
+        // Create trackers.
+        let trackers = server_state.api.trackers();
+        trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name")
+                    .with_tags(vec!["vendor:acme".to_string()])
+                    .build(),
+            )
+            .await?;
+        let tracker = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name")
+                    .with_tags(vec!["vendor:other".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_get_by_name),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/by-name/name?tag=vendor:other")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::to_string(&tracker)?,
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?
+        );
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/by-name/name").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Found multiple trackers with such name ('name'), use tags to narrow down the search.\"}""###
+        );
+
+        Ok(())
+    }
+}
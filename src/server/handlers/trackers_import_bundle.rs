@@ -0,0 +1,175 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::{Tracker, TrackerBundle};
+use tracing::error;
+
+/// Imports a tracker along with its data revisions from the bundle exported by this or another
+/// Retrack instance. The imported tracker gets a new ID.
+#[utoipa::path(
+    tags = ["trackers"],
+    request_body = TrackerBundle,
+    responses(
+        (status = OK, description = "Tracker was successfully imported.", body = Tracker),
+        (status = BAD_REQUEST, description = "Cannot import a tracker from the specified bundle.")
+    )
+)]
+#[post("/api/trackers/bundle")]
+pub async fn trackers_import_bundle(
+    state: web::Data<ServerState>,
+    bundle: web::Json<TrackerBundle>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers.import_tracker_bundle(bundle.into_inner()).await {
+        Ok(tracker) => Ok(HttpResponse::Ok().json(tracker)),
+        Err(err) => {
+            error!("Failed to import tracker bundle: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_import_bundle::trackers_import_bundle,
+            server_state::tests::mock_server_state,
+        },
+        tests::MockTrackerBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{Tracker, TrackerBundle, TrackerDataRevision, TrackerDataValue};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_import_tracker_bundle(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_import_bundle),
+        )
+        .await;
+
+        let bundled_tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            3,
+        )?
+        .with_tags(vec!["App:Retrack".to_string()])
+        .build();
+        let bundled_revisions = vec![
+            TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                tracker_id: bundled_tracker.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!("some-data")),
+                provenance: None,
+                sequence: Some(5),
+            },
+            TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000003"),
+                tracker_id: bundled_tracker.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                data: TrackerDataValue::new(json!("other-data")),
+                provenance: None,
+                sequence: Some(6),
+            },
+        ];
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/bundle")
+                .method(Method::POST)
+                .set_json(TrackerBundle {
+                    tracker: bundled_tracker.clone(),
+                    revisions: bundled_revisions.clone(),
+                })
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let tracker =
+            serde_json::from_slice::<Tracker>(&response.into_body().try_into_bytes().unwrap())?;
+        assert_ne!(tracker.id, bundled_tracker.id);
+        assert_eq!(tracker.name, bundled_tracker.name);
+        assert_eq!(tracker.target, bundled_tracker.target);
+        assert_eq!(tracker.config, bundled_tracker.config);
+        assert_eq!(tracker.tags, vec!["app:retrack".to_string()]);
+        assert!(server_state
+            .api
+            .trackers()
+            .get_tracker(tracker.id)
+            .await?
+            .is_some());
+
+        let revisions = server_state
+            .api
+            .trackers()
+            .get_tracker_data(tracker.id, Default::default())
+            .await?;
+        assert_eq!(revisions.len(), 2);
+        for (index, (revision, bundled_revision)) in
+            revisions.into_iter().zip(bundled_revisions).enumerate()
+        {
+            assert_ne!(revision.id, bundled_revision.id);
+            assert_eq!(revision.tracker_id, tracker.id);
+            assert_eq!(revision.data, bundled_revision.data);
+            assert_eq!(revision.created_at, bundled_revision.created_at);
+            assert_eq!(revision.sequence, Some(index as u64 + 1));
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_bundle(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_import_bundle),
+        )
+        .await;
+
+        let bundled_tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            3,
+        )?
+        .build();
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers/bundle")
+                .method(Method::POST)
+                .set_json(TrackerBundle {
+                    tracker: bundled_tracker,
+                    revisions: vec![TrackerDataRevision {
+                        id: uuid!("00000000-0000-0000-0000-000000000002"),
+                        tracker_id: uuid!("00000000-0000-0000-0000-000000000003"),
+                        created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                        data: TrackerDataValue::new(json!("some-data")),
+                        provenance: None,
+                        sequence: None,
+                    }],
+                })
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker bundle revision ('00000000-0000-0000-0000-000000000002') doesn't belong to the bundled tracker ('00000000-0000-0000-0000-000000000001').\"}""###);
+
+        Ok(())
+    }
+}
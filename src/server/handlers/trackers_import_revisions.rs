@@ -0,0 +1,159 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::{TrackerDataRevision, TrackerDataRevisionImport};
+use tracing::error;
+use uuid::Uuid;
+
+/// Imports historical data revisions for a tracker with the specified ID, so that the history
+/// collected with another tool isn't lost. Actions aren't executed for the imported revisions.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
+    ),
+    request_body = [TrackerDataRevisionImport],
+    responses(
+        (status = OK, description = "Imported tracker data revisions that were kept.", body = [TrackerDataRevision]),
+        (status = BAD_REQUEST, description = "Cannot import the specified revisions for a tracker.")
+    )
+)]
+#[post("/api/trackers/{tracker_id}/revisions/import")]
+pub async fn trackers_import_revisions(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+    revisions: web::Json<Vec<TrackerDataRevisionImport>>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers
+        .import_tracker_data(*tracker_id, revisions.into_inner())
+        .await
+    {
+        Ok(revisions) => Ok(HttpResponse::Ok().json(revisions)),
+        Err(err) => {
+            error!("Failed to import tracker data revisions: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_import_revisions::trackers_import_revisions,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::TrackerDataRevision;
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+
+    #[sqlx::test]
+    async fn can_import_tracker_data(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_import_revisions),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions/import",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .set_json(json!([
+                { "data": "two", "createdAt": 946720900 },
+                { "data": "one", "createdAt": 946720800 }
+            ]))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let imported_revisions = serde_json::from_slice::<Vec<TrackerDataRevision>>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        let revisions = server_state
+            .api
+            .trackers()
+            .get_tracker_data(tracker.id, Default::default())
+            .await?;
+        assert_eq!(imported_revisions, revisions);
+        assert_eq!(
+            revisions
+                .into_iter()
+                .map(|revision| (
+                    revision.tracker_id,
+                    revision.data.value().clone(),
+                    revision.created_at,
+                    revision.sequence
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    tracker.id,
+                    json!("one"),
+                    OffsetDateTime::from_unix_timestamp(946720800)?,
+                    Some(3)
+                ),
+                (
+                    tracker.id,
+                    json!("two"),
+                    OffsetDateTime::from_unix_timestamp(946720900)?,
+                    Some(4)
+                )
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_if_tracker_does_not_exist(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_import_revisions),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(
+                "https://retrack.dev/api/trackers/00000000-0000-0000-0000-000000000001/revisions/import",
+            )
+            .method(Method::POST)
+            .set_json(json!([{ "data": "one", "createdAt": 946720800 }]))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use actix_web_lab::extract::Query;
+use retrack_types::trackers::{TrackerInsight, TrackersInsightsParams};
+use tracing::error;
+
+/// Gets maintenance insights about trackers that likely need attention (stale, failing, or
+/// inactive), with suggested cleanup actions.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(TrackersInsightsParams),
+    responses(
+        (status = 200, description = "A list of trackers that likely need attention, optionally filtered by the specified tags.", body = [TrackerInsight]),
+        (status = BAD_REQUEST, description = "Cannot retrieve insights due to invalid parameters.")
+    )
+)]
+#[get("/api/admin/insights")]
+pub async fn trackers_insights(
+    state: web::Data<ServerState>,
+    params: Query<TrackersInsightsParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .trackers()
+        .get_trackers_insights(params.into_inner())
+        .await
+    {
+        Ok(insights) => Ok(HttpResponse::Ok().json(insights)),
+        Err(err) => {
+            error!("Failed to retrieve trackers insights: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_insights::trackers_insights, server_state::tests::mock_server_state,
+        },
+        tests::MockTrackerBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_return_trackers_insights(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_insights),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/insights").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker(
+                &MockTrackerBuilder::create(
+                    uuid!("00000000-0000-0000-0000-000000000001"),
+                    "some-name",
+                    3,
+                )?
+                .with_tags(vec!["vendor".to_string()])
+                .build(),
+            )
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/insights?tag=vendor&staleDays=30")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""[{\"trackerId\":\"00000000-0000-0000-0000-000000000001\",\"trackerName\":\"some-name\",\"kind\":\"inactive\",\"suggestedAction\":\"remove\"}]""###
+        );
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/insights?tag=other").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_invalid_parameters(pool: PgPool) -> anyhow::Result<()> {
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(mock_server_state(pool).await?))
+                .service(trackers_insights),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/insights?staleDays=0")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Trackers insights stale period must be between 1 and 3650 days.\"}""###
+        );
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use tracing::error;
     tags = ["trackers"],
     params(TrackersListParams),
     responses(
-        (status = 200, description = "A list of currently active trackers, optionally filtered by the specified tags.", body = [Tracker])
+        (status = 200, description = "A list of currently active trackers, optionally filtered by the specified tags and search query.", body = [Tracker])
     )
 )]
 #[get("/api/trackers")]
@@ -37,9 +37,14 @@ mod tests {
         test::{call_service, init_service, TestRequest},
         web, App,
     };
-    use retrack_types::trackers::{EmailAction, TrackerAction};
+    use retrack_types::trackers::{
+        EmailAction, Tracker, TrackerAction, TrackerDataRevision, TrackerDataValue, TrackerStats,
+    };
+    use serde_json::json;
     use sqlx::PgPool;
     use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
 
     #[sqlx::test]
     async fn can_list_trackers(pool: PgPool) -> anyhow::Result<()> {
@@ -89,6 +94,9 @@ mod tests {
                     .with_tags(vec!["tag_two".to_string()])
                     .with_actions(vec![TrackerAction::Email(EmailAction {
                         to: vec!["dev@retrack.dev".to_string()],
+                        timeout: None,
+                        template: None,
+                        attachment: None,
                     })])
                     .build(),
             )
@@ -232,4 +240,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_list_trackers_with_stats(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_list),
+        )
+        .await;
+
+        let tracker_1 = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+        let tracker_2 = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_two").build())
+            .await?;
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker_data_revision(&TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: tracker_1.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!("some-data")),
+                provenance: None,
+                sequence: None,
+            })
+            .await?;
+
+        // Stats aren't included by default.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::to_string(&[&tracker_1, &tracker_2])?,
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?
+        );
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/trackers?includeStats=true")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::to_string(&[
+                Tracker {
+                    stats: Some(TrackerStats {
+                        revisions: 1,
+                        last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                        last_change_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                    }),
+                    ..tracker_1
+                },
+                Tracker {
+                    stats: Some(TrackerStats::default()),
+                    ..tracker_2
+                }
+            ])?,
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?
+        );
+
+        Ok(())
+    }
 }
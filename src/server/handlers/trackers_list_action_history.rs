@@ -0,0 +1,144 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::trackers::TrackerActionHistoryEntry;
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets the history of the action executions for a tracker with the specified ID.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
+    ),
+    responses(
+        (status = 200, description = "A list of the tracker action executions, most recent first.", body = [TrackerActionHistoryEntry]),
+        (status = BAD_REQUEST, description = "Cannot list action history for a tracker with the specified ID.")
+    )
+)]
+#[get("/api/trackers/{tracker_id}/actions/history")]
+pub async fn trackers_list_action_history(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .trackers()
+        .get_tracker_action_history(*tracker_id)
+        .await
+    {
+        Ok(history) => Ok(HttpResponse::Ok().json(history)),
+        Err(err) => {
+            error!("Failed to retrieve tracker action history: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_list_action_history::trackers_list_action_history,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        TrackerAction, TrackerActionHistoryEntry, TrackerActionHistoryStatus,
+    };
+    use sqlx::PgPool;
+    use std::{str::from_utf8, time::Duration};
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_list_tracker_action_history(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_actions(vec![TrackerAction::ServerLog])
+                    .build(),
+            )
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_list_action_history),
+        )
+        .await;
+
+        let uri = format!(
+            "https://retrack.dev/api/trackers/{}/actions/history",
+            tracker.id
+        );
+        let response = call_service(&app, TestRequest::with_uri(&uri).to_request()).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        let entry = TrackerActionHistoryEntry {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_id: tracker.id,
+            action: 0,
+            action_type: "log".to_string(),
+            task_id: None,
+            status: TrackerActionHistoryStatus::Succeeded,
+            error: None,
+            duration: Duration::from_millis(3),
+            executed_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker_action_history_entry(&entry)
+            .await?;
+
+        let response = call_service(&app, TestRequest::with_uri(&uri).to_request()).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<Vec<TrackerActionHistoryEntry>>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            vec![entry]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_list_action_history_of_unknown_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(mock_server_state(pool).await?))
+                .service(trackers_list_action_history),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/actions/history",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+}
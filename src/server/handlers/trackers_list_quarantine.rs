@@ -0,0 +1,153 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::trackers::TrackerQuarantinedRevision;
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets a list of quarantined data revisions for a tracker with the specified ID.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
+    ),
+    responses(
+        (status = 200, description = "A list of data revisions that are pending review in quarantine.", body = [TrackerQuarantinedRevision]),
+        (status = BAD_REQUEST, description = "Cannot list quarantined revisions for a tracker with the specified ID.")
+    )
+)]
+#[get("/api/trackers/{tracker_id}/quarantine")]
+pub async fn trackers_list_quarantine(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers.get_tracker_quarantine(*tracker_id).await {
+        Ok(quarantine) => Ok(HttpResponse::Ok().json(quarantine)),
+        Err(err) => {
+            error!("Failed to retrieve tracker quarantine: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_list_quarantine::trackers_list_quarantine,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        TrackerDataRevision, TrackerDataValue, TrackerQuarantinedRevision,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_list_tracker_quarantine(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+
+        // Create tracker.
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_list_quarantine),
+        )
+        .await;
+
+        // Nothing is quarantined yet.
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        // Quarantine tracker data revision.
+        let quarantined_revision = TrackerQuarantinedRevision {
+            revision: TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: tracker.id,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                data: TrackerDataValue::new(json!({ "releases": [] })),
+                provenance: None,
+                sequence: None,
+            },
+            reason: "Value at `$.releases` is empty.".to_string(),
+        };
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_tracker_quarantined_revision(&quarantined_revision)
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let quarantine = serde_json::from_slice::<Vec<TrackerQuarantinedRevision>>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(quarantine, vec![quarantined_revision]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_list_quarantine_for_unknown_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_list_quarantine),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/quarantine",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+}
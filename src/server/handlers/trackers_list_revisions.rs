@@ -50,7 +50,7 @@ mod tests {
         web, App,
     };
     use insta::assert_debug_snapshot;
-    use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+    use retrack_types::trackers::{TrackerDataDiffFormat, TrackerDataRevision, TrackerDataValue};
     use serde_json::json;
     use sqlx::PgPool;
     use std::str::from_utf8;
@@ -92,15 +92,19 @@ mod tests {
 
         // Add tracker data revision.
         let trackers_db = server_state.api.db.trackers();
-        let data_revision_one = TrackerDataRevision {
+        let mut data_revision_one = TrackerDataRevision {
             id: uuid!("00000000-0000-0000-0000-000000000001"),
             tracker_id: tracker.id,
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
             data: TrackerDataValue::new(json!("\"some-data\"")),
+            provenance: None,
+            sequence: None,
         };
-        trackers_db
-            .insert_tracker_data_revision(&data_revision_one)
-            .await?;
+        data_revision_one.sequence = Some(
+            trackers_db
+                .insert_tracker_data_revision(&data_revision_one)
+                .await?,
+        );
         let response = call_service(
             &app,
             TestRequest::with_uri(&format!(
@@ -120,15 +124,19 @@ mod tests {
         // Add another revision
         let mut data = TrackerDataValue::new(json!("\"some-new-data\""));
         data.add_mod(json!("\"some-other-data\""));
-        let data_revision_two = TrackerDataRevision {
+        let mut data_revision_two = TrackerDataRevision {
             id: uuid!("00000000-0000-0000-0000-000000000002"),
             tracker_id: tracker.id,
             created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
             data,
+            provenance: None,
+            sequence: None,
         };
-        trackers_db
-            .insert_tracker_data_revision(&data_revision_two)
-            .await?;
+        data_revision_two.sequence = Some(
+            trackers_db
+                .insert_tracker_data_revision(&data_revision_two)
+                .await?,
+        );
         let response = call_service(
             &app,
             TestRequest::with_uri(&format!(
@@ -183,7 +191,33 @@ mod tests {
         )?;
         assert_eq!(
             revisions,
-            tracker_data_revisions_diff(vec![data_revision_one, data_revision_two])?
+            tracker_data_revisions_diff(
+                vec![data_revision_one.clone(), data_revision_two.clone()],
+                TrackerDataDiffFormat::Text
+            )?
+        );
+
+        // Calculate the difference between the two revisions as JSON Patch
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/revisions?calculateDiff=true&diffFormat=jsonPatch",
+                tracker.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let revisions = serde_json::from_slice::<Vec<TrackerDataRevision>>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(
+            revisions,
+            tracker_data_revisions_diff(
+                vec![data_revision_one, data_revision_two],
+                TrackerDataDiffFormat::JsonPatch
+            )?
         );
 
         Ok(())
@@ -0,0 +1,95 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::trackers::TrackersScrub;
+use tracing::error;
+
+/// Gets the audit log of the trackers data scrubs.
+#[utoipa::path(
+    tags = ["trackers"],
+    responses(
+        (status = 200, description = "A list of the performed trackers data scrubs, oldest first.", body = [TrackersScrub])
+    )
+)]
+#[get("/api/admin/scrubs")]
+pub async fn trackers_list_scrubs(
+    state: web::Data<ServerState>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.trackers().get_trackers_scrubs().await {
+        Ok(scrubs) => Ok(HttpResponse::Ok().json(scrubs)),
+        Err(err) => {
+            error!("Failed to retrieve trackers scrubs: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::trackers_list_scrubs::trackers_list_scrubs,
+        server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::TrackersScrub;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_list_trackers_scrubs(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_list_scrubs),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/scrubs").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        server_state
+            .api
+            .db
+            .trackers()
+            .insert_trackers_scrub(&TrackersScrub {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                reason: Some("DSR-42".to_string()),
+                pattern_digest: Some("sha256:ab".to_string()),
+                trackers: vec![uuid!("00000000-0000-0000-0000-000000000002")],
+                revisions: 3,
+                pending_data: 0,
+                alerts: 1,
+                tasks: 2,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/scrubs").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""[{\"id\":\"00000000-0000-0000-0000-000000000001\",\"reason\":\"DSR-42\",\"patternDigest\":\"sha256:ab\",\"trackers\":[\"00000000-0000-0000-0000-000000000002\"],\"revisions\":3,\"pendingData\":0,\"alerts\":1,\"tasks\":2,\"createdAt\":946720800}]""###
+        );
+
+        Ok(())
+    }
+}
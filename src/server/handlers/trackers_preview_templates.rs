@@ -0,0 +1,139 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::{TrackerTemplatesPreview, TrackerTemplatesPreviewParams};
+use tracing::error;
+
+/// Renders notification templates (email and webhook payload) for the specified tracker using
+/// either the supplied data, error message, or the latest tracker data revision, without sending
+/// any notifications.
+#[utoipa::path(
+    tags = ["trackers"],
+    request_body = TrackerTemplatesPreviewParams,
+    responses(
+        (status = 200, description = "Notification templates were successfully rendered.", body = TrackerTemplatesPreview),
+        (status = BAD_REQUEST, description = "Cannot render notification templates with the specified parameters.")
+    )
+)]
+#[post("/api/templates/preview")]
+pub async fn trackers_preview_templates(
+    state: web::Data<ServerState>,
+    params: web::Json<TrackerTemplatesPreviewParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .trackers()
+        .preview_tracker_templates(params.into_inner())
+        .await
+    {
+        Ok(preview) => Ok(HttpResponse::Ok().json(preview)),
+        Err(err) => {
+            error!("Failed to render notification templates preview: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_preview_templates::trackers_preview_templates,
+            server_state::tests::mock_server_state,
+        },
+        tests::MockTrackerBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_preview_templates(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_preview_templates),
+        )
+        .await;
+
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .build();
+        let trackers_db = server_state.api.db.trackers();
+        trackers_db.insert_tracker(&tracker).await?;
+        trackers_db
+            .insert_tracker_data_revision(&TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000011"),
+                tracker_id: tracker.id,
+                data: TrackerDataValue::new(json!({ "price": 10 })),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: None,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/templates/preview")
+                .method(Method::POST)
+                .set_json(json!({ "tracker": tracker.id }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let preview = serde_json::from_slice::<serde_json::Value>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(
+            preview["email"]["subject"],
+            json!("[Retrack] Change detected: \"some-name\"")
+        );
+        assert!(preview["email"]["html"]
+            .as_str()
+            .unwrap()
+            .contains("some-name"));
+        assert_eq!(preview["webhook"], json!({ "price": 10 }));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_invalid_parameters(pool: PgPool) -> anyhow::Result<()> {
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(mock_server_state(pool).await?))
+                .service(trackers_preview_templates),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/templates/preview")
+                .method(Method::POST)
+                .set_json(json!({ "tracker": "00000000-0000-0000-0000-000000000001" }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
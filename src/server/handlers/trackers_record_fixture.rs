@@ -0,0 +1,191 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::TrackerFixture;
+use tracing::error;
+use uuid::Uuid;
+
+/// Fetches data for a tracker with the specified ID and records raw upstream responses as a
+/// fixture that can be replayed later. Neither data revision is created, nor actions are executed.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
+    ),
+    responses(
+        (status = OK, description = "Recorded tracker fixture.", body = TrackerFixture)
+    )
+)]
+#[post("/api/trackers/{tracker_id}/fixtures")]
+pub async fn trackers_record_fixture(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers.record_tracker_fixture(*tracker_id).await {
+        Ok(fixture) => Ok(HttpResponse::Ok().json(fixture)),
+        Err(err) => {
+            error!("Failed to record tracker fixture: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_record_fixture::trackers_record_fixture,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use httpmock::MockServer;
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        ApiTarget, TargetRequest, TrackerFixture, TrackerFixtureResponse,
+        TrackerListRevisionsParams, TrackerTarget,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_record_tracker_fixture(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+
+        // Create tracker.
+        let trackers_api = server_state.api.trackers();
+        let tracker = trackers_api
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_target(TrackerTarget::Api(ApiTarget {
+                        requests: vec![TargetRequest::new(server.url("/api/get-call").parse()?)],
+                        configurator: None,
+                        extractor: None,
+                        capture_tls: false,
+                        cookie_jar: false,
+                        selectors: None,
+                    }))
+                    .build(),
+            )
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_record_fixture),
+        )
+        .await;
+
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/get-call");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body("{\"key\":\"value\"}");
+        });
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/fixtures",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        content_mock.assert();
+
+        let fixture = serde_json::from_slice::<TrackerFixture>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(fixture.tracker_id, tracker.id);
+        assert_eq!(
+            fixture.responses,
+            vec![TrackerFixtureResponse {
+                content_type: Some("application/json".to_string()),
+                body: b"{\"key\":\"value\"}".to_vec(),
+            }]
+        );
+        assert_eq!(fixture.previous_content, None);
+        assert_eq!(fixture.data, json!({ "key": "value" }));
+
+        // Recording doesn't create data revisions.
+        assert!(trackers_api
+            .get_tracker_data(
+                tracker.id,
+                TrackerListRevisionsParams {
+                    calculate_diff: false,
+                    diff_format: None,
+                    page_size: None,
+                    after: None,
+                    since: None,
+                    until: None,
+                },
+            )
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_unsupported_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(TrackerCreateParamsBuilder::new("name_one").build())
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_record_fixture),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/fixtures",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/fixtures",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            format!(
+                "{{\"message\":\"Tracker ('{}') target is not `Api`.\"}}",
+                tracker.id
+            )
+        );
+
+        Ok(())
+    }
+}
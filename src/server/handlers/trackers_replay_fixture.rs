@@ -0,0 +1,209 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::{TrackerFixture, TrackerFixtureReplay};
+use tracing::error;
+use uuid::Uuid;
+
+/// Re-runs data extraction for a tracker with the specified ID against the previously recorded
+/// fixture and reports whether the extracted data still matches the recorded one.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID.")
+    ),
+    request_body = TrackerFixture,
+    responses(
+        (status = OK, description = "Result of the fixture replay.", body = TrackerFixtureReplay),
+        (status = BAD_REQUEST, description = "Cannot replay the specified fixture.")
+    )
+)]
+#[post("/api/trackers/{tracker_id}/fixtures/replay")]
+pub async fn trackers_replay_fixture(
+    state: web::Data<ServerState>,
+    tracker_id: web::Path<Uuid>,
+    fixture: web::Json<TrackerFixture>,
+) -> Result<HttpResponse, RetrackError> {
+    let trackers = state.api.trackers();
+    match trackers
+        .replay_tracker_fixture(*tracker_id, fixture.into_inner())
+        .await
+    {
+        Ok(replay) => Ok(HttpResponse::Ok().json(replay)),
+        Err(err) => {
+            error!("Failed to replay tracker fixture: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_replay_fixture::trackers_replay_fixture,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        ApiTarget, TargetRequest, TrackerFixtureReplay, TrackerListRevisionsParams, TrackerTarget,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_replay_tracker_fixture(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+
+        // Create tracker.
+        let trackers_api = server_state.api.trackers();
+        let tracker = trackers_api
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_target(TrackerTarget::Api(ApiTarget {
+                        requests: vec![
+                            TargetRequest::new("https://retrack.dev/api/one".parse()?),
+                            TargetRequest {
+                                media_type: Some("text/csv".parse()?),
+                                ..TargetRequest::new("https://retrack.dev/api/two".parse()?)
+                            },
+                        ],
+                        configurator: None,
+                        extractor: None,
+                        capture_tls: false,
+                        cookie_jar: false,
+                        selectors: None,
+                    }))
+                    .build(),
+            )
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_replay_fixture),
+        )
+        .await;
+
+        let replay_fixture = |data: serde_json::Value| {
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/fixtures/replay",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .set_json(json!({
+                "trackerId": tracker.id,
+                "responses": [
+                    { "contentType": "application/json; charset=iso-8859-1", "body": b"\"Caf\xE9\"" },
+                    { "body": b"key,value\nname,Retrack\n" }
+                ],
+                "data": data,
+                "createdAt": 946720800
+            }))
+            .to_request()
+        };
+
+        let expected_data = json!([
+            serde_json::to_vec(&json!("Café"))?,
+            serde_json::to_vec(&json!([["key", "value"], ["name", "Retrack"]]))?
+        ]);
+        let response = call_service(&app, replay_fixture(expected_data.clone())).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<TrackerFixtureReplay>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            TrackerFixtureReplay {
+                data: expected_data.clone(),
+                matches: true
+            }
+        );
+
+        let response = call_service(&app, replay_fixture(json!("outdated"))).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<TrackerFixtureReplay>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            TrackerFixtureReplay {
+                data: expected_data,
+                matches: false
+            }
+        );
+
+        // Replaying doesn't create data revisions.
+        assert!(trackers_api
+            .get_tracker_data(
+                tracker.id,
+                TrackerListRevisionsParams {
+                    calculate_diff: false,
+                    diff_format: None,
+                    page_size: None,
+                    after: None,
+                    since: None,
+                    until: None,
+                },
+            )
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_empty_fixtures(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_target(TrackerTarget::Api(ApiTarget {
+                        requests: vec![TargetRequest::new("https://retrack.dev/api/one".parse()?)],
+                        configurator: None,
+                        extractor: None,
+                        capture_tls: false,
+                        cookie_jar: false,
+                        selectors: None,
+                    }))
+                    .build(),
+            )
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_replay_fixture),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/fixtures/replay",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .set_json(json!({
+                "trackerId": tracker.id,
+                "responses": [],
+                "data": null,
+                "createdAt": 946720800
+            }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker fixture should have at least one response.\"}""###);
+
+        Ok(())
+    }
+}
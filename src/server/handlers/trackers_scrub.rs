@@ -0,0 +1,153 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::{TrackersScrub, TrackersScrubParams};
+use tracing::error;
+
+/// Scrubs stored trackers data (data revisions, pending data changes, alerts, and pending tasks)
+/// that matches the specified pattern or belongs to the specified trackers, e.g. to satisfy a
+/// personal data deletion request. The scrub is recorded in the audit log.
+#[utoipa::path(
+    tags = ["trackers"],
+    request_body = TrackersScrubParams,
+    responses(
+        (status = 200, description = "Trackers data was successfully scrubbed.", body = TrackersScrub),
+        (status = BAD_REQUEST, description = "Cannot scrub trackers data with the specified parameters.")
+    )
+)]
+#[post("/api/admin/scrub")]
+pub async fn trackers_scrub(
+    state: web::Data<ServerState>,
+    params: web::Json<TrackersScrubParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .trackers()
+        .scrub_trackers_data(params.into_inner())
+        .await
+    {
+        Ok(scrub) => Ok(HttpResponse::Ok().json(scrub)),
+        Err(err) => {
+            error!("Failed to scrub trackers data: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_scrub::trackers_scrub, server_state::tests::mock_server_state,
+        },
+        tests::MockTrackerBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_scrub_trackers_data(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_scrub),
+        )
+        .await;
+
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .build();
+        let trackers_db = server_state.api.db.trackers();
+        trackers_db.insert_tracker(&tracker).await?;
+        for (id, data, time_shift) in [
+            (
+                uuid!("00000000-0000-0000-0000-000000000011"),
+                "Author: John Doe",
+                10,
+            ),
+            (
+                uuid!("00000000-0000-0000-0000-000000000012"),
+                "Author: Jane Doe",
+                20,
+            ),
+        ] {
+            trackers_db
+                .insert_tracker_data_revision(&TrackerDataRevision {
+                    id,
+                    tracker_id: tracker.id,
+                    data: TrackerDataValue::new(json!(data)),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800 + time_shift)?,
+                    provenance: None,
+                    sequence: None,
+                })
+                .await?;
+        }
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/scrub")
+                .method(Method::POST)
+                .set_json(json!({ "pattern": "John Doe", "reason": "DSR-42" }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let scrub = serde_json::from_slice::<serde_json::Value>(
+            &response.into_body().try_into_bytes().unwrap(),
+        )?;
+        assert_eq!(scrub["reason"], json!("DSR-42"));
+        assert_eq!(scrub["trackers"], json!([tracker.id]));
+        assert_eq!(scrub["revisions"], json!(1));
+        assert_eq!(
+            serde_json::to_value(server_state.api.trackers().get_trackers_scrubs().await?)?,
+            json!([scrub])
+        );
+
+        let revisions = trackers_db.get_tracker_data(tracker.id).await?;
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].data.value(), &json!("Author: Jane Doe"));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_invalid_parameters(pool: PgPool) -> anyhow::Result<()> {
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(mock_server_state(pool).await?))
+                .service(trackers_scrub),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/admin/scrub")
+                .method(Method::POST)
+                .set_json(json!({ "pattern": "(" }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"Scrub pattern is not a valid regular expression.\"}""###
+        );
+
+        Ok(())
+    }
+}
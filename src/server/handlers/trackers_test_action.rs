@@ -0,0 +1,137 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::trackers::{TrackerActionTestParams, TrackerActionTestResult};
+use tracing::error;
+use uuid::Uuid;
+
+/// Executes a single action of a tracker with the specified ID using either the supplied data, the
+/// data of the latest tracker data revision, or synthetic test data, without creating a new data
+/// revision. Notifications are delivered right away and aren't retried if they fail.
+#[utoipa::path(
+    tags = ["trackers"],
+    params(
+        ("tracker_id" = Uuid, Path, description = "A unique tracker ID."),
+        ("action_index" = usize, Path, description = "Index of the action within the tracker actions.")
+    ),
+    request_body = TrackerActionTestParams,
+    responses(
+        (status = OK, description = "Result of the tracker action test.", body = TrackerActionTestResult),
+        (status = BAD_REQUEST, description = "Cannot test tracker action with the specified parameters.")
+    )
+)]
+#[post("/api/trackers/{tracker_id}/actions/{action_index}/test")]
+pub async fn trackers_test_action(
+    state: web::Data<ServerState>,
+    path: web::Path<(Uuid, usize)>,
+    params: web::Json<TrackerActionTestParams>,
+) -> Result<HttpResponse, RetrackError> {
+    let (tracker_id, action_index) = path.into_inner();
+    match state
+        .api
+        .trackers()
+        .test_tracker_action(tracker_id, action_index, params.into_inner())
+        .await
+    {
+        Ok(result) => Ok(HttpResponse::Ok().json(result)),
+        Err(err) => {
+            error!("Failed to test tracker action: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::trackers_test_action::trackers_test_action,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerAction, TrackerActionTestResult, TrackerRunActionStatus};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_test_action(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let tracker = server_state
+            .api
+            .trackers()
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("name_one")
+                    .with_actions(vec![TrackerAction::ServerLog])
+                    .build(),
+            )
+            .await?;
+
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(trackers_test_action),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/actions/0/test",
+                tracker.id
+            ))
+            .method(Method::POST)
+            .set_json(json!({ "data": { "price": 10 } }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<TrackerActionTestResult>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            TrackerActionTestResult {
+                status: TrackerRunActionStatus::Succeeded,
+                data: json!({ "price": 10 }),
+                output: None,
+                error: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_test_action_of_unknown_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(mock_server_state(pool).await?))
+                .service(trackers_test_action),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/trackers/{}/actions/0/test",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .method(Method::POST)
+            .set_json(json!({}))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(from_utf8(&response.into_body().try_into_bytes().unwrap())?, @r###""{\"message\":\"Tracker ('00000000-0000-0000-0000-000000000001') is not found.\"}""###);
+
+        Ok(())
+    }
+}
@@ -135,6 +135,25 @@ mod tests {
                     ),
                 },
             ),
+            fail_on_shape_deviation: false,
+            change_filter: None,
+            semantic_filter: None,
+            skip_first_revision_actions: false,
+            confirmations: None,
+            active_hours: None,
+            active_days: None,
+            max_content_size: None,
+            action_concurrency: None,
+            action_dependencies: None,
+            action_rules: None,
+            severity_routing: None,
+            action_throttles: None,
+            action_digests: None,
+            workflow: None,
+            escalation: None,
+            quarantine: None,
+            runbook_url: None,
+            max_revision_age: None,
         }
         "###);
         assert_debug_snapshot!(tracker.actions, @r###"
@@ -159,6 +178,11 @@ mod tests {
                     },
                     method: None,
                     headers: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
+                    diff_format: None,
                 },
             ),
         ]
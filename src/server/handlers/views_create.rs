@@ -0,0 +1,116 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{post, web, HttpResponse};
+use retrack_types::views::{View, ViewCreateParams};
+use tracing::error;
+
+/// Creates a new view with the specified parameters.
+#[utoipa::path(
+    tags = ["views"],
+    request_body = ViewCreateParams,
+    responses(
+        (status = 200, description = "View was successfully created.", body = View),
+        (status = BAD_REQUEST, description = "Cannot create a view with the specified properties.")
+    )
+)]
+#[post("/api/views")]
+pub async fn views_create(
+    state: web::Data<ServerState>,
+    params: web::Json<ViewCreateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.views().create_view(params.into_inner()).await {
+        Ok(view) => Ok(HttpResponse::Ok().json(view)),
+        Err(err) => {
+            error!("Failed to create view: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::views_create::views_create, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::TrackersSort;
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_create_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/views")
+                .method(Method::POST)
+                .set_json(json!({
+                    "name": "failing prod",
+                    "tags": ["env:prod", "failing"],
+                    "sort": "-updatedAt"
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let views = server_state.api.views().get_views().await?;
+        assert_eq!(views.len(), 1);
+        assert_eq!(views[0].name, "failing prod");
+        assert_eq!(
+            views[0].tags,
+            vec!["env:prod".to_string(), "failing".to_string()]
+        );
+        assert_eq!(views[0].query, None);
+        assert_eq!(views[0].sort, Some(TrackersSort::UpdatedAtDesc));
+        assert!(!views[0].include_archived);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(&views[0])?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_invalid_params(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_create),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/views")
+                .method(Method::POST)
+                .set_json(json!({ "name": "" }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"View name cannot be empty.\"}""###
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::views::View;
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets a view with the specified ID.
+#[utoipa::path(
+    tags = ["views"],
+    params(
+        ("view_id" = Uuid, Path, description = "A unique view ID."),
+    ),
+    responses(
+        (status = 200, description = "View with the specified ID.", body = View),
+        (status = NOT_FOUND, description = "View with the specified ID was not found or the ID is not a valid UUID.")
+    )
+)]
+#[get("/api/views/{view_id}")]
+pub async fn views_get(
+    state: web::Data<ServerState>,
+    view_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.views().get_view(*view_id).await {
+        Ok(Some(view)) => Ok(HttpResponse::Ok().json(view)),
+        Ok(None) => Ok(HttpResponse::NotFound().finish()),
+        Err(err) => {
+            error!("Failed to retrieve view: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{handlers::views_get::views_get, server_state::tests::mock_server_state};
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::{trackers::TrackersSort, views::ViewCreateParams};
+    use sqlx::PgPool;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_get_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(App::new().app_data(server_state.clone()).service(views_get)).await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/views/{}",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 404);
+
+        let view = server_state
+            .api
+            .views()
+            .create_view(ViewCreateParams {
+                name: "prod".to_string(),
+                tags: vec!["env:prod".to_string()],
+                query: Some("api".to_string()),
+                sort: Some(TrackersSort::Name),
+                include_archived: false,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!("https://retrack.dev/api/views/{}", view.id))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(view)?
+        );
+
+        Ok(())
+    }
+}
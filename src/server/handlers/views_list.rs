@@ -0,0 +1,84 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use retrack_types::views::View;
+use tracing::error;
+
+/// Gets a list of views.
+#[utoipa::path(
+    tags = ["views"],
+    responses(
+        (status = 200, description = "A list of views ordered by name.", body = [View])
+    )
+)]
+#[get("/api/views")]
+pub async fn views_list(state: web::Data<ServerState>) -> Result<HttpResponse, RetrackError> {
+    match state.api.views().get_views().await {
+        Ok(views) => Ok(HttpResponse::Ok().json(views)),
+        Err(err) => {
+            error!("Failed to retrieve views: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{handlers::views_list::views_list, server_state::tests::mock_server_state};
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::views::ViewCreateParams;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+
+    #[sqlx::test]
+    async fn can_list_views(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_list),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/views").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            "[]"
+        );
+
+        let view = server_state
+            .api
+            .views()
+            .create_view(ViewCreateParams {
+                name: "prod".to_string(),
+                tags: vec!["env:prod".to_string()],
+                query: None,
+                sort: None,
+                include_archived: false,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri("https://retrack.dev/api/views").to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(vec![view])?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{get, web, HttpResponse};
+use actix_web_lab::extract::Query;
+use retrack_types::{trackers::Tracker, views::ViewTrackersParams};
+use tracing::error;
+use uuid::Uuid;
+
+/// Gets a list of trackers that match the filters of the view with the specified ID, in the order
+/// defined by the view.
+#[utoipa::path(
+    tags = ["views"],
+    params(
+        ("view_id" = Uuid, Path, description = "A unique view ID."),
+        ViewTrackersParams
+    ),
+    responses(
+        (status = 200, description = "A list of trackers that match the view.", body = [Tracker]),
+        (status = BAD_REQUEST, description = "View with the specified ID was not found.")
+    )
+)]
+#[get("/api/views/{view_id}/trackers")]
+pub async fn views_list_trackers(
+    state: web::Data<ServerState>,
+    view_id: web::Path<Uuid>,
+    params: Query<ViewTrackersParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .views()
+        .get_view_trackers(*view_id, params.into_inner().include_stats)
+        .await
+    {
+        Ok(trackers) => Ok(HttpResponse::Ok().json(trackers)),
+        Err(err) => {
+            error!("Failed to retrieve view trackers: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::{
+            handlers::views_list_trackers::views_list_trackers,
+            server_state::tests::mock_server_state,
+        },
+        tests::TrackerCreateParamsBuilder,
+    };
+    use actix_web::{
+        body::MessageBody,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::{trackers::TrackersSort, views::ViewCreateParams};
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_list_view_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_list_trackers),
+        )
+        .await;
+
+        let trackers = server_state.api.trackers();
+        let tracker_one = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("prod_one")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+        trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("staging_one")
+                    .with_tags(vec!["env:staging".to_string()])
+                    .build(),
+            )
+            .await?;
+        let tracker_three = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("prod_two")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        let view = server_state
+            .api
+            .views()
+            .create_view(ViewCreateParams {
+                name: "prod".to_string(),
+                tags: vec!["env:prod".to_string()],
+                query: None,
+                sort: Some(TrackersSort::NameDesc),
+                include_archived: false,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/views/{}/trackers",
+                view.id
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(vec![tracker_three, tracker_one])?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_unknown_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_list_trackers),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/views/{}/trackers",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"View ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
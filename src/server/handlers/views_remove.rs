@@ -0,0 +1,77 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{delete, web, HttpResponse};
+use tracing::error;
+use uuid::Uuid;
+
+/// Removes a view with the specified ID. Trackers that match the view aren't affected.
+#[utoipa::path(
+    tags = ["views"],
+    params(
+        ("view_id" = Uuid, Path, description = "A unique view ID."),
+    ),
+    responses(
+        (status = NO_CONTENT, description = "View with the specified ID was successfully removed.")
+    )
+)]
+#[delete("/api/views/{view_id}")]
+pub async fn views_remove(
+    state: web::Data<ServerState>,
+    view_id: web::Path<Uuid>,
+) -> Result<HttpResponse, RetrackError> {
+    match state.api.views().remove_view(*view_id).await {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(err) => {
+            error!("Failed to remove view: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::views_remove::views_remove, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use retrack_types::views::ViewCreateParams;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn can_remove_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_remove),
+        )
+        .await;
+
+        let view = server_state
+            .api
+            .views()
+            .create_view(ViewCreateParams {
+                name: "prod".to_string(),
+                tags: vec!["env:prod".to_string()],
+                query: None,
+                sort: None,
+                include_archived: false,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!("https://retrack.dev/api/views/{}", view.id))
+                .method(Method::DELETE)
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 204);
+        assert!(server_state.api.views().get_views().await?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{error::Error as RetrackError, server::ServerState};
+use actix_web::{put, web, HttpResponse};
+use retrack_types::views::{View, ViewUpdateParams};
+use tracing::error;
+use uuid::Uuid;
+
+/// Updates an existing view with the specified parameters.
+#[utoipa::path(
+    tags = ["views"],
+    params(
+        ("view_id" = Uuid, Path, description = "A unique view ID."),
+    ),
+    request_body = ViewUpdateParams,
+    responses(
+        (status = 200, description = "View was successfully updated.", body = View),
+        (status = BAD_REQUEST, description = "Cannot update a view with the specified properties.")
+    )
+)]
+#[put("/api/views/{view_id}")]
+pub async fn views_update(
+    state: web::Data<ServerState>,
+    view_id: web::Path<Uuid>,
+    params: web::Json<ViewUpdateParams>,
+) -> Result<HttpResponse, RetrackError> {
+    match state
+        .api
+        .views()
+        .update_view(*view_id, params.into_inner())
+        .await
+    {
+        Ok(view) => Ok(HttpResponse::Ok().json(view)),
+        Err(err) => {
+            error!("Failed to update view: {err:?}");
+            Err(err.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::{
+        handlers::views_update::views_update, server_state::tests::mock_server_state,
+    };
+    use actix_web::{
+        body::MessageBody,
+        http::Method,
+        test::{call_service, init_service, TestRequest},
+        web, App,
+    };
+    use insta::assert_debug_snapshot;
+    use retrack_types::{trackers::TrackersSort, views::ViewCreateParams};
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::str::from_utf8;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_update_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_update),
+        )
+        .await;
+
+        let view = server_state
+            .api
+            .views()
+            .create_view(ViewCreateParams {
+                name: "prod".to_string(),
+                tags: vec!["env:prod".to_string()],
+                query: None,
+                sort: None,
+                include_archived: false,
+            })
+            .await?;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!("https://retrack.dev/api/views/{}", view.id))
+                .method(Method::PUT)
+                .set_json(json!({ "query": "api", "sort": "name" }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+
+        let updated_view = server_state.api.views().get_view(view.id).await?.unwrap();
+        assert_eq!(updated_view.name, "prod");
+        assert_eq!(updated_view.tags, vec!["env:prod".to_string()]);
+        assert_eq!(updated_view.query, Some("api".to_string()));
+        assert_eq!(updated_view.sort, Some(TrackersSort::Name));
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &response.into_body().try_into_bytes().unwrap()
+            )?,
+            serde_json::to_value(updated_view)?
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_with_bad_request_for_unknown_view(pool: PgPool) -> anyhow::Result<()> {
+        let server_state = web::Data::new(mock_server_state(pool).await?);
+        let app = init_service(
+            App::new()
+                .app_data(server_state.clone())
+                .service(views_update),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::with_uri(&format!(
+                "https://retrack.dev/api/views/{}",
+                uuid!("00000000-0000-0000-0000-000000000001")
+            ))
+            .method(Method::PUT)
+            .set_json(json!({ "name": "prod" }))
+            .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        assert_debug_snapshot!(
+            from_utf8(&response.into_body().try_into_bytes().unwrap())?,
+            @r###""{\"message\":\"View ('00000000-0000-0000-0000-000000000001') is not found.\"}""###
+        );
+
+        Ok(())
+    }
+}
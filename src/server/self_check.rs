@@ -0,0 +1,262 @@
+use crate::{
+    api::Api,
+    database::MIGRATOR,
+    js_runtime::ScriptConfig,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+    trackers::HttpCacheManager,
+};
+use anyhow::{anyhow, bail, Context};
+use serde_bytes::ByteBuf;
+use tracing::{error, info, warn};
+
+/// Result of the single self-check.
+#[derive(Debug)]
+pub enum SelfCheckOutcome {
+    /// Check passed.
+    Passed,
+    /// Check wasn't performed, e.g. because the corresponding component isn't configured.
+    Skipped(&'static str),
+    /// Check failed with the specified error.
+    Failed(anyhow::Error),
+}
+
+/// Consolidated report of the server self-check.
+#[derive(Debug)]
+pub struct SelfCheckReport {
+    /// Outcomes of the individual checks, in the order they were performed.
+    pub checks: Vec<(&'static str, SelfCheckOutcome)>,
+}
+
+impl SelfCheckReport {
+    /// Checks if none of the self-checks failed.
+    pub fn is_passed(&self) -> bool {
+        !self
+            .checks
+            .iter()
+            .any(|(_, outcome)| matches!(outcome, SelfCheckOutcome::Failed(_)))
+    }
+
+    /// Returns the number of failed self-checks.
+    pub fn failed_count(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|(_, outcome)| matches!(outcome, SelfCheckOutcome::Failed(_)))
+            .count()
+    }
+
+    /// Logs outcomes of the individual checks followed by the consolidated result.
+    pub fn log(&self) {
+        for (check, outcome) in &self.checks {
+            match outcome {
+                SelfCheckOutcome::Passed => info!(check, "Self-check passed."),
+                SelfCheckOutcome::Skipped(reason) => {
+                    info!(check, "Self-check skipped: {reason}")
+                }
+                SelfCheckOutcome::Failed(err) => error!(check, "Self-check failed: {err:?}"),
+            }
+        }
+
+        if self.is_passed() {
+            info!("All self-checks passed ({}).", self.checks.len());
+        } else {
+            warn!(
+                "Self-check failed: {} of {} checks didn't pass.",
+                self.failed_count(),
+                self.checks.len()
+            );
+        }
+    }
+}
+
+/// Verifies that all components Retrack relies on are properly configured and reachable: database
+/// schema is up to date, web scraper is reachable, SMTP server accepts credentials, HTTP cache is
+/// writable, and JS runtime can execute scripts.
+pub async fn self_check<DR: DnsResolver, ET: EmailTransport>(api: &Api<DR, ET>) -> SelfCheckReport
+where
+    ET::Error: EmailTransportError,
+{
+    let into_outcome = |result: anyhow::Result<()>| match result {
+        Ok(_) => SelfCheckOutcome::Passed,
+        Err(err) => SelfCheckOutcome::Failed(err),
+    };
+
+    let mut checks = vec![
+        ("database", into_outcome(check_database(api).await)),
+        ("web_scraper", into_outcome(check_web_scraper(api).await)),
+    ];
+
+    checks.push((
+        "smtp",
+        if api.config.smtp.is_some() {
+            into_outcome(api.network.email_transport.verify_connection().await)
+        } else {
+            SelfCheckOutcome::Skipped("SMTP isn't configured.")
+        },
+    ));
+
+    checks.push((
+        "http_cache",
+        into_outcome(async { HttpCacheManager::create(&api.config.cache)?.verify().await }.await),
+    ));
+    checks.push(("js_runtime", into_outcome(check_js_runtime(api).await)));
+
+    SelfCheckReport { checks }
+}
+
+/// Checks that all database migrations known to this server version have been applied.
+async fn check_database<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+) -> anyhow::Result<()> {
+    let expected_version = MIGRATOR.iter().map(|migration| migration.version).max();
+    let actual_version = api.db.get_schema_version().await?;
+    if actual_version < expected_version {
+        bail!(
+            "Database schema version ({}) is older than the expected one ({}).",
+            actual_version.unwrap_or_default(),
+            expected_version.unwrap_or_default()
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks that the web scraper is reachable and detects its capabilities.
+async fn check_web_scraper<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+) -> anyhow::Result<()>
+where
+    ET::Error: EmailTransportError,
+{
+    let capabilities = api.trackers().get_web_scraper_capabilities().await?;
+    info!(
+        engines = ?capabilities.engines,
+        "Detected web scraper capabilities."
+    );
+
+    Ok(())
+}
+
+/// Checks that the JS runtime can execute a trivial script within the configured limits.
+async fn check_js_runtime<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+) -> anyhow::Result<()> {
+    let result = api
+        .js_runtime
+        .execute_script::<ByteBuf, ByteBuf>(
+            r#"(() => Deno.core.encode("ok"))();"#,
+            None,
+            ScriptConfig {
+                max_heap_size: api.config.js_runtime.max_heap_size,
+                max_execution_time: api.config.js_runtime.max_script_execution_time,
+            },
+        )
+        .await
+        .context("Failed to execute JS runtime probe script.")?;
+    match result {
+        Some(result) if result.as_ref() == b"ok" => Ok(()),
+        result => Err(anyhow!(
+            "JS runtime returned unexpected probe script result: {result:?}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{self_check, SelfCheckOutcome, SelfCheckReport};
+    use crate::tests::{mock_api_with_config, mock_config};
+    use anyhow::anyhow;
+    use httpmock::MockServer;
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+    use sqlx::PgPool;
+    use url::Url;
+
+    fn find_outcome<'r>(report: &'r SelfCheckReport, check: &str) -> &'r SelfCheckOutcome {
+        report
+            .checks
+            .iter()
+            .find_map(|(name, outcome)| (*name == check).then_some(outcome))
+            .unwrap()
+    }
+
+    #[test]
+    fn properly_consolidates_outcomes() {
+        let report = SelfCheckReport {
+            checks: vec![
+                ("database", SelfCheckOutcome::Passed),
+                ("smtp", SelfCheckOutcome::Skipped("SMTP isn't configured.")),
+            ],
+        };
+        assert!(report.is_passed());
+        assert_eq!(report.failed_count(), 0);
+
+        let report = SelfCheckReport {
+            checks: vec![
+                ("database", SelfCheckOutcome::Passed),
+                ("web_scraper", SelfCheckOutcome::Failed(anyhow!("Uh oh."))),
+                ("smtp", SelfCheckOutcome::Skipped("SMTP isn't configured.")),
+            ],
+        };
+        assert!(!report.is_passed());
+        assert_eq!(report.failed_count(), 1);
+    }
+
+    #[sqlx::test]
+    async fn properly_checks_components(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let status_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/api/status");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body(json!({ "version": "1.0.0" }));
+        });
+
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.smtp = None;
+        let api = mock_api_with_config(pool.clone(), config).await?;
+
+        let report = self_check(&api).await;
+        status_mock.assert_hits(1);
+
+        assert!(matches!(
+            find_outcome(&report, "database"),
+            SelfCheckOutcome::Passed
+        ));
+        assert!(matches!(
+            find_outcome(&report, "web_scraper"),
+            SelfCheckOutcome::Passed
+        ));
+        assert!(matches!(
+            find_outcome(&report, "http_cache"),
+            SelfCheckOutcome::Passed
+        ));
+        assert_debug_snapshot!(find_outcome(&report, "smtp"), @r###"
+        Skipped(
+            "SMTP isn't configured.",
+        )
+        "###);
+
+        // Web scraper capabilities are cached after the self-check.
+        api.trackers().get_web_scraper_capabilities().await?;
+        status_mock.assert_hits(1);
+
+        // Web scraper isn't reachable.
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse("http://localhost:1")?;
+        let api = mock_api_with_config(pool, config).await?;
+
+        let report = self_check(&api).await;
+        assert!(!report.is_passed());
+        assert!(matches!(
+            find_outcome(&report, "web_scraper"),
+            SelfCheckOutcome::Failed(_)
+        ));
+        assert!(matches!(
+            find_outcome(&report, "smtp"),
+            SelfCheckOutcome::Passed
+        ));
+
+        Ok(())
+    }
+}
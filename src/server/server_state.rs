@@ -1,9 +1,15 @@
+mod components_status;
+mod js_runtime_status;
 mod scheduler_status;
 mod status;
 mod status_get_params;
 
 pub use self::{
-    scheduler_status::SchedulerStatus, status::Status, status_get_params::GetStatusParams,
+    components_status::{ComponentStatus, ComponentsStatus, WebScraperStatus},
+    js_runtime_status::JsRuntimeStatus,
+    scheduler_status::SchedulerStatus,
+    status::Status,
+    status_get_params::GetStatusParams,
 };
 use crate::{
     api::Api,
@@ -13,6 +19,7 @@ use crate::{
 use lettre::{AsyncSmtpTransport, Tokio1Executor};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::warn;
 
 pub struct ServerState<
     DR: DnsResolver = TokioDnsResolver,
@@ -38,11 +45,59 @@ where
 
     /// Gets the status of the server.
     pub async fn status(&self) -> anyhow::Result<Status<'_>> {
+        let config = &self.api.config;
+        let web_scraper_status = self.api.trackers().get_web_scraper_status().await;
+        if let Err(ref err) = web_scraper_status {
+            warn!("Web scraper isn't reachable: {err:?}");
+        }
+
         Ok(Status {
             version: self.version.as_str(),
             scheduler: self.scheduler.write().await.status().await?,
+            schema_version: self.api.db.get_schema_version().await?,
+            features: Self::features(),
+            components: ComponentsStatus {
+                web_scraper: WebScraperStatus {
+                    url: &config.components.web_scraper_url,
+                    reachable: web_scraper_status.is_ok(),
+                    engines: web_scraper_status
+                        .ok()
+                        .map(|status| status.capabilities.engines),
+                },
+                smtp: ComponentStatus {
+                    configured: config.smtp.is_some(),
+                },
+                translator: ComponentStatus {
+                    configured: config.components.translator.is_some(),
+                },
+                summarizer: ComponentStatus {
+                    configured: config.components.summarizer.is_some(),
+                },
+                embeddings: ComponentStatus {
+                    configured: config.components.embeddings.is_some(),
+                },
+            },
+            js_runtime: JsRuntimeStatus {
+                max_heap_size: config.js_runtime.max_heap_size,
+                max_script_execution_time: config.js_runtime.max_script_execution_time,
+            },
         })
     }
+
+    /// Returns the list of the optional features the server has been built with.
+    fn features() -> Vec<&'static str> {
+        [
+            ("http-cache-redis", cfg!(feature = "http-cache-redis")),
+            ("http-cache-s3", cfg!(feature = "http-cache-s3")),
+            (
+                "scheduler-redis-locks",
+                cfg!(feature = "scheduler-redis-locks"),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(feature, enabled)| enabled.then_some(feature))
+        .collect()
+    }
 }
 
 #[cfg(test)]
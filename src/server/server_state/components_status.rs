@@ -0,0 +1,101 @@
+use retrack_types::trackers::PageTargetEngine;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use url::Url;
+use utoipa::ToSchema;
+
+/// Status of the components Retrack relies on.
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentsStatus<'s> {
+    /// Status of the Web Scraper component.
+    pub web_scraper: WebScraperStatus<'s>,
+    /// Status of the SMTP server used to send emails.
+    pub smtp: ComponentStatus,
+    /// Status of the translation API used by the tracker translate actions.
+    pub translator: ComponentStatus,
+    /// Status of the LLM API used by the tracker summarize actions.
+    pub summarizer: ComponentStatus,
+    /// Status of the text embeddings API used by the tracker semantic filters.
+    pub embeddings: ComponentStatus,
+}
+
+/// Status of the optional component.
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentStatus {
+    /// Indicates if the component is configured.
+    pub configured: bool,
+}
+
+/// Status of the Web Scraper component.
+#[skip_serializing_none]
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperStatus<'s> {
+    /// The URL Retrack accesses the Web Scraper at.
+    pub url: &'s Url,
+    /// Indicates if the Web Scraper is reachable at the configured URL.
+    pub reachable: bool,
+    /// List of the browser engines supported by the Web Scraper, if it's reachable.
+    pub engines: Option<Vec<PageTargetEngine>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentStatus, ComponentsStatus, WebScraperStatus};
+    use insta::assert_json_snapshot;
+    use retrack_types::trackers::PageTargetEngine;
+    use url::Url;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let web_scraper_url = Url::parse("http://localhost:7272")?;
+        assert_json_snapshot!(ComponentsStatus {
+            web_scraper: WebScraperStatus {
+                url: &web_scraper_url,
+                reachable: true,
+                engines: Some(vec![PageTargetEngine::Chromium]),
+            },
+            smtp: ComponentStatus { configured: true },
+            translator: ComponentStatus { configured: false },
+            summarizer: ComponentStatus { configured: false },
+            embeddings: ComponentStatus { configured: true },
+        }, @r###"
+        {
+          "webScraper": {
+            "url": "http://localhost:7272/",
+            "reachable": true,
+            "engines": [
+              "chromium"
+            ]
+          },
+          "smtp": {
+            "configured": true
+          },
+          "translator": {
+            "configured": false
+          },
+          "summarizer": {
+            "configured": false
+          },
+          "embeddings": {
+            "configured": true
+          }
+        }
+        "###);
+
+        assert_json_snapshot!(WebScraperStatus {
+            url: &web_scraper_url,
+            reachable: false,
+            engines: None,
+        }, @r###"
+        {
+          "url": "http://localhost:7272/",
+          "reachable": false
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Limits of the embedded JS runtime that executes tracker scripts.
+#[serde_as]
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsRuntimeStatus {
+    /// The hard limit for the JS runtime heap size in bytes.
+    pub max_heap_size: usize,
+    /// The maximum duration for a single JS script execution, in milliseconds.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub max_script_execution_time: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server::JsRuntimeStatus;
+    use insta::assert_json_snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(JsRuntimeStatus {
+            max_heap_size: 10485760,
+            max_script_execution_time: Duration::from_secs(10),
+        }, @r###"
+        {
+          "maxHeapSize": 10485760,
+          "maxScriptExecutionTime": 10000
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -1,14 +1,25 @@
-use crate::server::SchedulerStatus;
+use crate::server::{ComponentsStatus, JsRuntimeStatus, SchedulerStatus};
 use serde::Serialize;
+use serde_with::skip_serializing_none;
 use utoipa::ToSchema;
 
 /// Server status.
+#[skip_serializing_none]
 #[derive(Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Status<'s> {
     /// Version of the server.
     pub version: &'s str,
     /// Status of the scheduler.
     pub scheduler: SchedulerStatus,
+    /// Version of the latest applied database schema migration.
+    pub schema_version: Option<i64>,
+    /// List of the optional features the server has been built with.
+    pub features: Vec<&'static str>,
+    /// Status of the components Retrack relies on.
+    pub components: ComponentsStatus<'s>,
+    /// Limits of the embedded JS runtime.
+    pub js_runtime: JsRuntimeStatus,
 }
 
 impl Status<'_> {
@@ -20,25 +31,81 @@ impl Status<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::server::{SchedulerStatus, Status};
+    use crate::server::{
+        server_state::{ComponentStatus, WebScraperStatus},
+        ComponentsStatus, JsRuntimeStatus, SchedulerStatus, Status,
+    };
     use insta::assert_json_snapshot;
+    use retrack_types::trackers::PageTargetEngine;
     use std::time::Duration;
+    use url::Url;
+
+    fn mock_status(scheduler: SchedulerStatus, web_scraper_url: &Url) -> Status<'_> {
+        Status {
+            version: "1.0.0-alpha.4",
+            scheduler,
+            schema_version: Some(20250220100000),
+            features: vec!["http-cache-redis"],
+            components: ComponentsStatus {
+                web_scraper: WebScraperStatus {
+                    url: web_scraper_url,
+                    reachable: true,
+                    engines: Some(vec![PageTargetEngine::Chromium]),
+                },
+                smtp: ComponentStatus { configured: true },
+                translator: ComponentStatus { configured: false },
+                summarizer: ComponentStatus { configured: false },
+                embeddings: ComponentStatus { configured: false },
+            },
+            js_runtime: JsRuntimeStatus {
+                max_heap_size: 10485760,
+                max_script_execution_time: Duration::from_secs(10),
+            },
+        }
+    }
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
+        let web_scraper_url = Url::parse("http://localhost:7272")?;
         let scheduler = SchedulerStatus {
             operational: true,
             time_till_next_job: Some(Duration::from_secs(10)),
         };
-        assert_json_snapshot!(Status {
-            version: "1.0.0-alpha.4",
-            scheduler
-        }, @r###"
+        assert_json_snapshot!(mock_status(scheduler, &web_scraper_url), @r###"
         {
           "version": "1.0.0-alpha.4",
           "scheduler": {
             "operational": true,
             "timeTillNextJob": 10000
+          },
+          "schemaVersion": 20250220100000,
+          "features": [
+            "http-cache-redis"
+          ],
+          "components": {
+            "webScraper": {
+              "url": "http://localhost:7272/",
+              "reachable": true,
+              "engines": [
+                "chromium"
+              ]
+            },
+            "smtp": {
+              "configured": true
+            },
+            "translator": {
+              "configured": false
+            },
+            "summarizer": {
+              "configured": false
+            },
+            "embeddings": {
+              "configured": false
+            }
+          },
+          "jsRuntime": {
+            "maxHeapSize": 10485760,
+            "maxScriptExecutionTime": 10000
           }
         }
         "###);
@@ -47,23 +114,26 @@ mod tests {
     }
 
     #[test]
-    fn is_operational() {
-        let status = Status {
-            version: "1.0.0-alpha.4",
-            scheduler: SchedulerStatus {
+    fn is_operational() -> anyhow::Result<()> {
+        let web_scraper_url = Url::parse("http://localhost:7272")?;
+        let status = mock_status(
+            SchedulerStatus {
                 operational: true,
                 time_till_next_job: Some(Duration::from_secs(10)),
             },
-        };
+            &web_scraper_url,
+        );
         assert!(status.is_operational());
 
-        let status = Status {
-            version: "1.0.0-alpha.4",
-            scheduler: SchedulerStatus {
+        let status = mock_status(
+            SchedulerStatus {
                 operational: false,
                 time_till_next_job: None,
             },
-        };
+            &web_scraper_url,
+        );
         assert!(!status.is_operational());
+
+        Ok(())
     }
 }
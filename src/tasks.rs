@@ -1,17 +1,29 @@
 mod api_ext;
 mod database_ext;
+mod digest_composer;
 mod task;
+mod task_delivery;
 mod task_type;
 
 mod email_task_type;
 mod http_task_type;
+mod issue_task_type;
+mod mqtt_client;
+mod mqtt_task_type;
+mod nats_client;
+mod nats_task_type;
 
 pub use self::{
+    digest_composer::compose_digest,
     email_task_type::{
         Email, EmailAttachment, EmailAttachmentDisposition, EmailContent, EmailTaskType,
         EmailTemplate,
     },
-    http_task_type::HttpTaskType,
+    http_task_type::{HttpTaskExpectedResponse, HttpTaskRetryStrategy, HttpTaskType},
+    issue_task_type::{IssueTaskProvider, IssueTaskType},
+    mqtt_task_type::MqttTaskType,
+    nats_task_type::NatsTaskType,
     task::Task,
+    task_delivery::TaskDelivery,
     task_type::TaskType,
 };
@@ -1,24 +1,65 @@
 use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport, EmailTransportError},
-    tasks::{EmailAttachmentDisposition, EmailTaskType, HttpTaskType, Task, TaskType},
+    tasks::{
+        mqtt_client::MqttClient, nats_client::NatsClient, EmailAttachmentDisposition,
+        EmailTaskType, HttpTaskType, IssueTaskProvider, IssueTaskType, MqttTaskType, NatsTaskType,
+        Task, TaskDelivery, TaskType,
+    },
 };
-use anyhow::{bail, Context};
+use anyhow::{anyhow, bail, Context};
 use futures::{pin_mut, StreamExt};
+use http::{
+    header::{ACCEPT, AUTHORIZATION, USER_AGENT},
+    HeaderMap, HeaderName, HeaderValue,
+};
 use lettre::{
     message::{header::ContentType, Attachment, MultiPart, SinglePart},
     Message,
 };
-use reqwest_middleware::ClientBuilder;
+use regex::Regex;
+use reqwest_middleware::{ClientBuilder, RequestBuilder};
 use reqwest_tracing::{SpanBackendWithUrl, TracingMiddleware};
-use std::cmp;
+use retrack_types::{scheduler::SchedulerJobRetryStrategy, trackers::TrackerActionHistoryStatus};
+use ring::hmac;
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Deserialize,
+};
+use serde_json::json;
+use std::{cmp, fmt::Write};
 use time::OffsetDateTime;
-use tracing::{debug, error};
+use tracing::{debug, error, info, warn};
+use url::Url;
 use uuid::Uuid;
 
 /// Defines a maximum number of tasks that can be retrieved from the database at once.
 const MAX_TASKS_PAGE_SIZE: usize = 100;
 
+/// Defines a maximum number of characters of the HTTP response to include into logs and errors.
+const MAX_HTTP_RESPONSE_SNIPPET_LENGTH: usize = 500;
+
+/// Defines the name of the header with the HMAC-SHA256 signature of the HTTP request.
+const SIGNATURE_HEADER: &str = "X-Retrack-Signature";
+
+/// Defines a maximum number of open issues to look through when searching for the issue to update.
+const MAX_ISSUES_PAGE_SIZE: usize = 100;
+
+/// Issue returned by the GitHub REST API (pull requests are returned as issues as well).
+#[derive(Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    title: String,
+    pull_request: Option<IgnoredAny>,
+}
+
+/// Issue returned by the GitLab REST API.
+#[derive(Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    title: String,
+}
+
 /// Describes the API to work with tasks.
 pub struct TasksApi<'a, DR: DnsResolver, ET: EmailTransport> {
     api: &'a Api<DR, ET>,
@@ -43,6 +84,7 @@ where
             id: Uuid::now_v7(),
             task_type,
             scheduled_at,
+            delivery: None,
         };
 
         self.api.db.insert_task(&task).await?;
@@ -50,6 +92,38 @@ where
         Ok(task)
     }
 
+    /// Executes a new task right away, without waiting for the pending tasks to be processed. If
+    /// the task fails, it's scheduled to be retried along with the other pending tasks, and the
+    /// execution error is returned.
+    pub async fn execute_task_now(&self, task_type: TaskType) -> anyhow::Result<()> {
+        let task = Task {
+            id: Uuid::now_v7(),
+            task_type,
+            scheduled_at: OffsetDateTime::now_utc(),
+            delivery: None,
+        };
+
+        if let Err(err) = self.execute_task(task.clone()).await {
+            self.api.db.insert_task(&task).await?;
+            self.record_task_failure(task, &err).await?;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Executes a new task right away, without scheduling it to be retried if it fails (e.g., to
+    /// test the tracker action).
+    pub async fn execute_task_once(&self, task_type: TaskType) -> anyhow::Result<()> {
+        self.execute_task(Task {
+            id: Uuid::now_v7(),
+            task_type,
+            scheduled_at: OffsetDateTime::now_utc(),
+            delivery: None,
+        })
+        .await
+    }
+
     /// Executes pending tasks. The max number to send is limited by `limit`.
     pub async fn execute_pending_tasks(&self, limit: usize) -> anyhow::Result<usize> {
         let pending_tasks_ids = self.api.db.get_tasks_ids(
@@ -62,12 +136,22 @@ where
         while let Some(task_id) = pending_tasks_ids.next().await {
             if let Some(task) = self.api.db.get_task(task_id?).await? {
                 let task_id = task.id;
-                if let Err(err) = self.execute_task(task).await {
+                if let Err(err) = self.execute_task(task.clone()).await {
                     error!(taask.id = %task_id, "Failed to execute task: {err:?}");
+                    self.record_task_failure(task, &err).await?;
                 } else {
                     debug!(taask.id = %task_id, "Successfully executed task.");
                     executed_tasks += 1;
                     self.api.db.remove_task(task_id).await?;
+                    self.api
+                        .db
+                        .trackers()
+                        .update_tracker_action_history_task_status(
+                            task_id,
+                            TrackerActionHistoryStatus::Succeeded,
+                            None,
+                        )
+                        .await?;
                 }
             }
 
@@ -79,6 +163,101 @@ where
         Ok(executed_tasks)
     }
 
+    /// Removes all pending tasks that match the pattern (see [`TaskType::matches`]), and clears
+    /// the matching last delivery errors of the remaining tasks, or only counts them if `dry_run`
+    /// is set. Returns the number of matched tasks.
+    pub async fn remove_matching_tasks(
+        &self,
+        pattern: &Regex,
+        dry_run: bool,
+    ) -> anyhow::Result<usize> {
+        let tasks = self.api.db.get_tasks(MAX_TASKS_PAGE_SIZE);
+        pin_mut!(tasks);
+
+        let mut matched_tasks = 0;
+        while let Some(task) = tasks.next().await {
+            let task = task?;
+            if task.task_type.matches(pattern) {
+                if !dry_run {
+                    self.api.db.remove_task(task.id).await?;
+                }
+                matched_tasks += 1;
+            } else if task
+                .delivery
+                .as_ref()
+                .is_some_and(|delivery| pattern.is_match(&delivery.last_error))
+            {
+                if !dry_run {
+                    self.api.db.clear_task_last_error(task.id).await?;
+                }
+                matched_tasks += 1;
+            }
+        }
+
+        Ok(matched_tasks)
+    }
+
+    /// Records the failed attempt to execute the task and schedules the next attempt according to
+    /// the task retry strategy, if any. Once the retry attempts are exhausted, the task is marked
+    /// as failed and isn't retried anymore. The error is also recorded in the history of the
+    /// tracker action the task delivers the notification of, if any.
+    async fn record_task_failure(&self, mut task: Task, err: &anyhow::Error) -> anyhow::Result<()> {
+        let attempts = task
+            .delivery
+            .as_ref()
+            .map(|delivery| delivery.attempts)
+            .unwrap_or_default()
+            + 1;
+        let retry_strategy = match task.task_type {
+            TaskType::Http(ref http_task) => http_task
+                .retry_strategy
+                .map(SchedulerJobRetryStrategy::from),
+            _ => None,
+        };
+
+        let failed = if let Some(retry_strategy) = retry_strategy {
+            // The first attempt isn't a retry.
+            let retry_attempts = attempts - 1;
+            if retry_attempts >= retry_strategy.max_attempts() {
+                warn!(task.id = %task.id, "Retry limit reached ('{retry_attempts}') for a task.");
+                true
+            } else {
+                let retry_interval = retry_strategy.interval(retry_attempts);
+                debug!(
+                    task.id = %task.id,
+                    "Scheduling a retry for task in {}.",
+                    humantime::format_duration(retry_interval),
+                );
+                task.scheduled_at = OffsetDateTime::now_utc() + retry_interval;
+                false
+            }
+        } else {
+            false
+        };
+
+        let last_error = format!("{err:#}");
+        self.api
+            .db
+            .trackers()
+            .update_tracker_action_history_task_status(
+                task.id,
+                if failed {
+                    TrackerActionHistoryStatus::Failed
+                } else {
+                    TrackerActionHistoryStatus::Scheduled
+                },
+                Some(&last_error),
+            )
+            .await?;
+
+        task.delivery = Some(TaskDelivery {
+            attempts,
+            last_error,
+            failed,
+        });
+        self.api.db.update_task_delivery(&task).await
+    }
+
     /// Executes task and removes it from the database, if it was executed successfully.
     async fn execute_task(&self, task: Task) -> anyhow::Result<()> {
         match task.task_type {
@@ -90,6 +269,18 @@ where
                 debug!(task.id = %task.id, "Executing HTTP task.");
                 self.send_http_request(http_task, task.scheduled_at).await?;
             }
+            TaskType::Nats(nats_task) => {
+                debug!(task.id = %task.id, "Executing NATS task.");
+                self.publish_nats_message(nats_task).await?;
+            }
+            TaskType::Mqtt(mqtt_task) => {
+                debug!(task.id = %task.id, "Executing MQTT task.");
+                self.publish_mqtt_message(mqtt_task).await?;
+            }
+            TaskType::Issue(issue_task) => {
+                debug!(task.id = %task.id, "Executing issue task.");
+                self.create_or_update_issue(issue_task).await?;
+            }
         }
 
         Ok(())
@@ -129,49 +320,78 @@ where
                     format!("Cannot parse catch-all TO address: {}", catch_all_recipient)
                 })?)
         } else {
-            for to in task.to {
+            // Apply routing rules, and skip email entirely if all recipients have been dropped.
+            let recipients = smtp_config.route_recipients(&task.to);
+            if recipients.to.is_empty() {
+                info!(
+                    "Email isn't sent since all recipients have been dropped by the routing rules."
+                );
+                return Ok(());
+            }
+
+            for to in recipients.to {
                 message_builder = message_builder.to(to
                     .parse()
                     .with_context(|| format!("Cannot parse TO address: {to}"))?);
             }
+
+            for bcc in recipients.bcc {
+                message_builder = message_builder.bcc(
+                    bcc.parse()
+                        .with_context(|| format!("Cannot parse BCC address: {bcc}"))?,
+                );
+            }
         };
 
+        let mut attachments = email.attachments.unwrap_or_default();
+        attachments.extend(task.attachments.unwrap_or_default());
+
         let message = match email.html {
-            Some(html) => {
-                let alternative_builder = MultiPart::alternative()
-                    .singlepart(
-                        SinglePart::builder()
-                            .header(ContentType::TEXT_PLAIN)
-                            .body(email.text),
-                    )
-                    .singlepart(
-                        SinglePart::builder()
-                            .header(ContentType::TEXT_HTML)
-                            .body(html),
-                    );
-                message_builder.multipart(match email.attachments {
-                    Some(attachments) if !attachments.is_empty() => {
-                        let mut message_builder = MultiPart::mixed().multipart(alternative_builder);
-                        for attachment in attachments {
-                            let attachment_builder = match attachment.disposition {
-                                EmailAttachmentDisposition::Inline(id) => {
-                                    Attachment::new_inline(id)
-                                }
-                            };
-                            message_builder = message_builder.singlepart(attachment_builder.body(
-                                attachment.content,
-                                ContentType::parse(&attachment.content_type)?,
-                            ));
+            None if attachments.is_empty() => message_builder.body(email.text)?,
+            html => {
+                let text_part = SinglePart::builder()
+                    .header(ContentType::TEXT_PLAIN)
+                    .body(email.text);
+                let mut body_builder = match html {
+                    Some(html) => {
+                        let alternative_builder =
+                            MultiPart::alternative().singlepart(text_part).singlepart(
+                                SinglePart::builder()
+                                    .header(ContentType::TEXT_HTML)
+                                    .body(html),
+                            );
+                        if attachments.is_empty() {
+                            alternative_builder
+                        } else {
+                            MultiPart::mixed().multipart(alternative_builder)
                         }
-                        message_builder
                     }
-                    _ => alternative_builder,
-                })?
+                    None => MultiPart::mixed().singlepart(text_part),
+                };
+
+                for attachment in attachments {
+                    let attachment_builder = match attachment.disposition {
+                        EmailAttachmentDisposition::Inline(id) => Attachment::new_inline(id),
+                        EmailAttachmentDisposition::Attachment(file_name) => {
+                            Attachment::new(file_name)
+                        }
+                    };
+                    body_builder = body_builder.singlepart(attachment_builder.body(
+                        attachment.content,
+                        ContentType::parse(&attachment.content_type)?,
+                    ));
+                }
+
+                message_builder.multipart(body_builder)?
             }
-            None => message_builder.body(email.text)?,
         };
 
-        self.api.network.email_transport.send(message).await?;
+        let timeout = task
+            .timeout
+            .unwrap_or(self.api.config.trackers.action_timeouts.email);
+        tokio::time::timeout(timeout, self.api.network.email_transport.send(message))
+            .await
+            .map_err(|_| anyhow!("Email wasn't sent within {}ms.", timeout.as_millis()))??;
 
         Ok(())
     }
@@ -191,6 +411,31 @@ where
             request_builder
         };
 
+        // Sign the request, if requested, right before it's sent so that the signature timestamp
+        // can be used by the receiver to reject replayed requests.
+        let request_builder = if let Some(signing_secret) = task.signing_secret {
+            let Some(secret) = self.api.config.secrets.get(&signing_secret) else {
+                bail!("HTTP request signing secret ('{signing_secret}') isn't configured.");
+            };
+
+            let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+            let mut context =
+                hmac::Context::with_key(&hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes()));
+            context.update(format!("{timestamp}.").as_bytes());
+            context.update(task.body.as_deref().unwrap_or_default());
+            let signature = context
+                .sign()
+                .as_ref()
+                .iter()
+                .fold(String::new(), |mut hex, byte| {
+                    let _ = write!(hex, "{byte:02x}");
+                    hex
+                });
+            request_builder.header(SIGNATURE_HEADER, format!("t={timestamp},v1={signature}"))
+        } else {
+            request_builder
+        };
+
         // Add body, if any.
         let request_builder = if let Some(body) = task.body {
             request_builder.body(body)
@@ -198,21 +443,322 @@ where
             request_builder
         };
 
+        // Limit the time to wait for the response, including its body.
+        let timeout = task
+            .timeout
+            .unwrap_or(self.api.config.trackers.action_timeouts.webhook);
+        let request_builder = request_builder.timeout(timeout);
+
         let response = client
             .execute(request_builder.build()?)
-            .await?
-            .error_for_status()?;
+            .await
+            .map_err(|err| {
+                if err.is_timeout() {
+                    anyhow!(
+                        "HTTP response wasn't received within {}ms.",
+                        timeout.as_millis()
+                    )
+                } else {
+                    err.into()
+                }
+            })?;
+        let Some(expected_response) = task.expected_response else {
+            let response = response.error_for_status()?;
+            let response_status = response.status().as_u16();
+            let response_text = response.text().await?;
+            debug!(
+                http.status_code = response_status,
+                http.response = response_text,
+                "Successfully sent HTTP request."
+            );
+
+            return Ok(());
+        };
 
         let response_status = response.status().as_u16();
         let response_text = response.text().await?;
-        debug!(
+        let response_snippet = truncate_response_text(&response_text);
+
+        let status_matches = if let Some((min, max)) = expected_response.status {
+            (min..=max).contains(&response_status)
+        } else {
+            (200..300).contains(&response_status)
+        };
+        if !status_matches {
+            bail!(
+                "HTTP response status {response_status} doesn't match the expected one, response: {response_snippet}"
+            );
+        }
+
+        if let Some(body_pattern) = expected_response.body_pattern {
+            if !Regex::new(&body_pattern)?.is_match(&response_text) {
+                bail!(
+                    "HTTP response body doesn't match the expected pattern `{body_pattern}`, response: {response_snippet}"
+                );
+            }
+        }
+
+        info!(
             http.status_code = response_status,
-            http.response = response_text,
-            "Successfully sent HTTP request."
+            http.response = response_snippet,
+            "Successfully sent HTTP request, response matches expectations."
+        );
+
+        Ok(())
+    }
+
+    /// Publish message to the NATS subject.
+    async fn publish_nats_message(&self, task: NatsTaskType) -> anyhow::Result<()> {
+        let timeout = task
+            .timeout
+            .unwrap_or(self.api.config.trackers.action_timeouts.webhook);
+        tokio::time::timeout(
+            timeout,
+            NatsClient::connect_and_publish(&task.url, &task.subject, &task.payload),
+        )
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "NATS message wasn't acknowledged within {}ms.",
+                timeout.as_millis()
+            )
+        })??;
+
+        debug!(
+            nats.subject = task.subject,
+            "Successfully published NATS message."
+        );
+
+        Ok(())
+    }
+
+    /// Publish message to the MQTT topic.
+    async fn publish_mqtt_message(&self, task: MqttTaskType) -> anyhow::Result<()> {
+        let timeout = task
+            .timeout
+            .unwrap_or(self.api.config.trackers.action_timeouts.webhook);
+        tokio::time::timeout(
+            timeout,
+            MqttClient::connect_and_publish(
+                &task.url,
+                &task.topic,
+                &task.payload,
+                task.qos,
+                task.retain,
+            ),
+        )
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "MQTT message wasn't acknowledged within {}ms.",
+                timeout.as_millis()
+            )
+        })??;
+
+        debug!(
+            mqtt.topic = task.topic,
+            "Successfully published MQTT message."
         );
 
         Ok(())
     }
+
+    /// Creates a new issue in the GitHub or GitLab repository, or comments on the already open
+    /// issue with the same title.
+    async fn create_or_update_issue(&self, task: IssueTaskType) -> anyhow::Result<()> {
+        let timeout = task
+            .timeout
+            .unwrap_or(self.api.config.trackers.action_timeouts.webhook);
+        tokio::time::timeout(timeout, async {
+            match task.provider {
+                IssueTaskProvider::GitHub => Self::create_or_update_github_issue(&task).await,
+                IssueTaskProvider::GitLab => Self::create_or_update_gitlab_issue(&task).await,
+            }
+        })
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "Issue tracker didn't respond within {}ms.",
+                timeout.as_millis()
+            )
+        })?
+    }
+
+    /// Creates or comments on the GitHub issue, see https://docs.github.com/en/rest/issues.
+    async fn create_or_update_github_issue(task: &IssueTaskType) -> anyhow::Result<()> {
+        let mut authorization = HeaderValue::from_str(&format!("Bearer {}", task.token))?;
+        authorization.set_sensitive(true);
+        let headers = HeaderMap::from_iter([
+            (AUTHORIZATION, authorization),
+            (
+                ACCEPT,
+                HeaderValue::from_static("application/vnd.github+json"),
+            ),
+            (USER_AGENT, HeaderValue::from_static("retrack")),
+            (
+                HeaderName::from_static("x-github-api-version"),
+                HeaderValue::from_static("2022-11-28"),
+            ),
+        ]);
+
+        let client = ClientBuilder::new(reqwest::Client::new())
+            .with(TracingMiddleware::<SpanBackendWithUrl>::new())
+            .build();
+        let issues_url = issue_api_url(
+            &task.api_url,
+            ["repos"]
+                .into_iter()
+                .chain(task.repository.split('/'))
+                .chain(["issues"]),
+        )?;
+        let open_issues = send_issue_request::<Vec<GitHubIssue>>(
+            client
+                .get(issues_url.clone())
+                .headers(headers.clone())
+                .query(&[("state", "open")])
+                .query(&[("per_page", MAX_ISSUES_PAGE_SIZE)]),
+        )
+        .await?;
+
+        let existing_issue = open_issues
+            .into_iter()
+            .find(|issue| issue.pull_request.is_none() && issue.title == task.title);
+        if let Some(issue) = existing_issue {
+            let comments_url =
+                issue_api_url(&issues_url, [issue.number.to_string().as_str(), "comments"])?;
+            send_issue_request::<IgnoredAny>(
+                client
+                    .post(comments_url)
+                    .headers(headers)
+                    .json(&json!({ "body": task.body })),
+            )
+            .await?;
+            debug!(
+                issue.number = issue.number,
+                "Successfully commented on GitHub issue."
+            );
+        } else {
+            let issue = send_issue_request::<GitHubIssue>(
+                client.post(issues_url).headers(headers).json(&json!({
+                    "title": task.title,
+                    "body": task.body,
+                    "labels": task.labels
+                })),
+            )
+            .await?;
+            debug!(
+                issue.number = issue.number,
+                "Successfully created GitHub issue."
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Creates or comments on the GitLab issue, see https://docs.gitlab.com/ee/api/issues.html.
+    async fn create_or_update_gitlab_issue(task: &IssueTaskType) -> anyhow::Result<()> {
+        let mut token = HeaderValue::from_str(&task.token)?;
+        token.set_sensitive(true);
+        let headers = HeaderMap::from_iter([
+            (HeaderName::from_static("private-token"), token),
+            (USER_AGENT, HeaderValue::from_static("retrack")),
+        ]);
+
+        let client = ClientBuilder::new(reqwest::Client::new())
+            .with(TracingMiddleware::<SpanBackendWithUrl>::new())
+            .build();
+        // The project can be referred to by its full path, and it's encoded as a single segment.
+        let issues_url = issue_api_url(
+            &task.api_url,
+            ["projects", task.repository.as_str(), "issues"],
+        )?;
+        let open_issues = send_issue_request::<Vec<GitLabIssue>>(
+            client
+                .get(issues_url.clone())
+                .headers(headers.clone())
+                .query(&[
+                    ("state", "opened"),
+                    ("in", "title"),
+                    ("search", task.title.as_str()),
+                ])
+                .query(&[("per_page", MAX_ISSUES_PAGE_SIZE)]),
+        )
+        .await?;
+
+        let existing_issue = open_issues
+            .into_iter()
+            .find(|issue| issue.title == task.title);
+        if let Some(issue) = existing_issue {
+            let notes_url = issue_api_url(&issues_url, [issue.iid.to_string().as_str(), "notes"])?;
+            send_issue_request::<IgnoredAny>(
+                client
+                    .post(notes_url)
+                    .headers(headers)
+                    .json(&json!({ "body": task.body })),
+            )
+            .await?;
+            debug!(
+                issue.iid = issue.iid,
+                "Successfully commented on GitLab issue."
+            );
+        } else {
+            let issue = send_issue_request::<GitLabIssue>(
+                client.post(issues_url).headers(headers).json(&json!({
+                    "title": task.title,
+                    "description": task.body,
+                    "labels": task.labels.join(",")
+                })),
+            )
+            .await?;
+            debug!(issue.iid = issue.iid, "Successfully created GitLab issue.");
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends path segments to the issue tracker API URL, percent-encoding every segment.
+fn issue_api_url<'s>(
+    base_url: &Url,
+    segments: impl IntoIterator<Item = &'s str>,
+) -> anyhow::Result<Url> {
+    let mut url = base_url.clone();
+    url.set_query(None);
+    url.path_segments_mut()
+        .map_err(|_| anyhow!("Issue tracker API URL ({base_url}) cannot be a base."))?
+        .pop_if_empty()
+        .extend(segments);
+    Ok(url)
+}
+
+/// Sends the issue tracker API request and deserializes its JSON response.
+async fn send_issue_request<T: DeserializeOwned>(request: RequestBuilder) -> anyhow::Result<T> {
+    let response = request.send().await?;
+    let response_status = response.status();
+    if !response_status.is_success() {
+        let response_text = response.text().await?;
+        bail!(
+            "Issue tracker responded with an unexpected status {}, response: {}",
+            response_status.as_u16(),
+            truncate_response_text(&response_text)
+        );
+    }
+
+    Ok(response.json::<T>().await?)
+}
+
+/// Truncates HTTP response text to make it suitable for logs and errors.
+fn truncate_response_text(text: &str) -> String {
+    if text.chars().count() > MAX_HTTP_RESPONSE_SNIPPET_LENGTH {
+        format!(
+            "{}…",
+            text.chars()
+                .take(MAX_HTTP_RESPONSE_SNIPPET_LENGTH)
+                .collect::<String>()
+        )
+    } else {
+        text.to_string()
+    }
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
@@ -228,18 +774,29 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        config::SmtpConfig,
+        config::{SecretsConfig, SmtpConfig},
         tasks::{
-            Email, EmailAttachment, EmailContent, EmailTaskType, HttpTaskType, Task, TaskType,
+            Email, EmailAttachment, EmailContent, EmailTaskType, HttpTaskExpectedResponse,
+            HttpTaskRetryStrategy, HttpTaskType, IssueTaskProvider, IssueTaskType, MqttTaskType,
+            NatsTaskType, Task, TaskDelivery, TaskType,
+        },
+        tests::{
+            mock_api, mock_api_with_config, mock_config, SmtpCatchAllConfig, SmtpRoutingAction,
+            SmtpRoutingRule,
         },
-        tests::{mock_api, mock_api_with_config, mock_config, SmtpCatchAllConfig},
     };
+    use futures::StreamExt;
     use http::{header::CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, Method};
     use httpmock::MockServer;
     use insta::assert_debug_snapshot;
+    use regex::Regex;
+    use ring::hmac;
     use serde_json::json;
     use sqlx::PgPool;
+    use std::{net::Ipv4Addr, time::Duration};
     use time::OffsetDateTime;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+    use url::Url;
     use uuid::uuid;
 
     #[sqlx::test]
@@ -261,8 +818,11 @@ mod tests {
                         "subj".to_string(),
                         "email text".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
             Task {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
@@ -272,8 +832,11 @@ mod tests {
                         "subj #2".to_string(),
                         "email text #2".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
         ];
 
@@ -313,8 +876,11 @@ mod tests {
                         "subj".to_string(),
                         "email text".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720700)?,
+                delivery: None,
             },
             Task {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
@@ -325,8 +891,11 @@ mod tests {
                         "email text #2".to_string(),
                         "html #2",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
         ];
 
@@ -425,8 +994,11 @@ mod tests {
                     "<img src='cid:logo' />",
                     vec![EmailAttachment::inline("logo", "image/png", vec![1, 2, 3])],
                 )),
+                timeout: None,
+                attachments: None,
             }),
             scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            delivery: None,
         }];
 
         let tasks_api = api.tasks();
@@ -502,6 +1074,8 @@ mod tests {
                             format!("subj {n}"),
                             format!("email text {n}"),
                         )),
+                        timeout: None,
+                        attachments: None,
                     }),
                     OffsetDateTime::from_unix_timestamp(946720800 + n)?,
                 )
@@ -555,6 +1129,8 @@ mod tests {
                         "some one text message".to_string(),
                         "html",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             ),
@@ -566,6 +1142,8 @@ mod tests {
                         "some two text message".to_string(),
                         "html",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             ),
@@ -577,6 +1155,8 @@ mod tests {
                         "some three text message".to_string(),
                         "html",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             ),
@@ -671,6 +1251,92 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn sends_emails_respecting_routing_rules(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        let routes = vec![
+            SmtpRoutingRule {
+                recipient_matcher: regex::Regex::new(r"@customer\.com$")?,
+                action: SmtpRoutingAction::Redirect {
+                    recipient: "qa@retrack.dev".to_string(),
+                },
+            },
+            SmtpRoutingRule {
+                recipient_matcher: regex::Regex::new(r"@retrack\.dev$")?,
+                action: SmtpRoutingAction::Bcc {
+                    recipient: "audit@retrack.dev".to_string(),
+                },
+            },
+            SmtpRoutingRule {
+                recipient_matcher: regex::Regex::new(r".*")?,
+                action: SmtpRoutingAction::Drop,
+            },
+        ];
+        config.smtp = config.smtp.map(|smtp| SmtpConfig { routes, ..smtp });
+
+        let api = mock_api_with_config(pool, config).await?;
+        for to in [
+            vec!["one@customer.com".to_string()],
+            vec![
+                "dev@retrack.dev".to_string(),
+                "two@customer.com".to_string(),
+            ],
+            vec!["someone@unknown.com".to_string()],
+        ] {
+            api.tasks()
+                .schedule_task(
+                    TaskType::Email(EmailTaskType {
+                        to,
+                        content: EmailContent::Custom(Email::text(
+                            "subject".to_string(),
+                            "some text message".to_string(),
+                        )),
+                        timeout: None,
+                        attachments: None,
+                    }),
+                    OffsetDateTime::from_unix_timestamp(946720800)?,
+                )
+                .await?;
+        }
+
+        // The email to the dropped recipient is considered executed, but isn't sent.
+        assert_eq!(api.tasks().execute_pending_tasks(4).await?, 3);
+
+        let messages = api.network.email_transport.messages().await;
+        assert_eq!(
+            messages
+                .iter()
+                .map(|(envelope, content)| (
+                    envelope
+                        .to()
+                        .iter()
+                        .map(|address| address.to_string())
+                        .collect::<Vec<_>>(),
+                    content
+                        .lines()
+                        .find(|line| line.starts_with("To: "))
+                        .map(|line| line.to_string())
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    vec!["qa@retrack.dev".to_string()],
+                    Some("To: qa@retrack.dev".to_string())
+                ),
+                (
+                    vec![
+                        "dev@retrack.dev".to_string(),
+                        "qa@retrack.dev".to_string(),
+                        "audit@retrack.dev".to_string()
+                    ],
+                    Some("To: dev@retrack.dev, qa@retrack.dev".to_string())
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn sends_emails_respecting_wide_open_catch_all_filter(
         pool: PgPool,
@@ -694,6 +1360,8 @@ mod tests {
                         "some one text message".to_string(),
                         "html",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             ),
@@ -705,6 +1373,8 @@ mod tests {
                         "some two text message".to_string(),
                         "html",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             ),
@@ -716,6 +1386,8 @@ mod tests {
                         "some three text message".to_string(),
                         "html",
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             ),
@@ -831,6 +1503,10 @@ mod tests {
                     method: Method::POST,
                     headers: None,
                     body: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             )
@@ -858,6 +1534,10 @@ mod tests {
                     method: Method::POST,
                     headers: None,
                     body: Some(serde_json::to_vec(&vec![1, 2, 3])?),
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             )
@@ -893,6 +1573,10 @@ mod tests {
                         ),
                     ])),
                     body: Some(serde_json::to_vec(&vec![1, 2, 3])?),
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             )
@@ -927,16 +1611,801 @@ mod tests {
                     method: Method::POST,
                     headers: None,
                     body: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
                 }),
                 OffsetDateTime::from_unix_timestamp(946720800)?,
             )
             .await?;
 
         assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
-        assert!(api.db.get_task(task.id).await?.is_some());
+        // Task without retry strategy is retried until it succeeds, but its attempts are recorded.
+        let delivery = api
+            .db
+            .get_task(task.id)
+            .await?
+            .and_then(|task| task.delivery);
+        assert_eq!(
+            delivery.map(|delivery| (delivery.attempts, delivery.failed)),
+            Some((1, false))
+        );
 
         server_handler_mock.assert();
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn keep_http_task_if_response_times_out(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.trackers.action_timeouts.webhook = Duration::from_millis(100);
+        let api = mock_api_with_config(pool, config).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let slow_server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/api/some/slow");
+            then.status(200).delay(Duration::from_secs(2));
+        });
+        let fast_server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/api/some/fast");
+            then.status(200);
+        });
+
+        // The first task defines its own timeout, and the second one relies on the default one.
+        let mut slow_tasks = vec![];
+        for timeout in [Some(Duration::from_millis(100)), None] {
+            slow_tasks.push(
+                tasks_api
+                    .schedule_task(
+                        TaskType::Http(HttpTaskType {
+                            url: format!("{}/api/some/slow", server.base_url()).parse()?,
+                            method: Method::POST,
+                            headers: None,
+                            body: None,
+                            expected_response: None,
+                            timeout,
+                            signing_secret: None,
+                            retry_strategy: None,
+                        }),
+                        OffsetDateTime::from_unix_timestamp(946720800)?,
+                    )
+                    .await?,
+            );
+        }
+        let fast_task = tasks_api
+            .schedule_task(
+                TaskType::Http(HttpTaskType {
+                    url: format!("{}/api/some/fast", server.base_url()).parse()?,
+                    method: Method::POST,
+                    headers: None,
+                    body: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: None,
+                }),
+                OffsetDateTime::from_unix_timestamp(946720801)?,
+            )
+            .await?;
+
+        // Slow receiver shouldn't prevent the remaining tasks from being executed.
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        for slow_task in slow_tasks {
+            assert!(api.db.get_task(slow_task.id).await?.is_some());
+        }
+        assert!(api.db.get_task(fast_task.id).await?.is_none());
+
+        slow_server_handler_mock.assert_hits(2);
+        fast_server_handler_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn retries_http_tasks_according_to_retry_strategy(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/some/execute");
+            then.status(500);
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Http(HttpTaskType {
+                    url: format!("{}/api/some/execute", server.base_url()).parse()?,
+                    method: Method::POST,
+                    headers: None,
+                    body: None,
+                    expected_response: None,
+                    timeout: None,
+                    signing_secret: None,
+                    retry_strategy: Some(HttpTaskRetryStrategy::Constant(
+                        Duration::from_secs(3600),
+                        2,
+                    )),
+                }),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        // Failed task should be rescheduled according to the retry strategy.
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        let failed_task = api.db.get_task(task.id).await?.unwrap();
+        let delivery = failed_task.delivery.clone().unwrap();
+        assert_eq!(delivery.attempts, 1);
+        assert!(delivery.last_error.contains("500"));
+        assert!(!delivery.failed);
+        assert!(failed_task.scheduled_at > OffsetDateTime::now_utc() + Duration::from_secs(3500));
+
+        // Task isn't retried before the scheduled time.
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        server_handler_mock.assert_hits(1);
+
+        // Task should be marked as failed once the retry attempts are exhausted.
+        for attempts in 2..=3 {
+            let task = api.db.get_task(task.id).await?.unwrap();
+            api.db
+                .update_task_delivery(&Task {
+                    scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    ..task
+                })
+                .await?;
+            assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+
+            let delivery = api.db.get_task(task.id).await?.unwrap().delivery.unwrap();
+            assert_eq!(delivery.attempts, attempts);
+            assert_eq!(delivery.failed, attempts == 3);
+        }
+        server_handler_mock.assert_hits(3);
+
+        // Failed task isn't retried anymore, but is kept for inspection.
+        let task = api.db.get_task(task.id).await?.unwrap();
+        api.db
+            .update_task_delivery(&Task {
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                ..task
+            })
+            .await?;
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        server_handler_mock.assert_hits(3);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn validates_http_task_response(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let mut server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/some/execute");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!({ "ok": false, "error": "Quota exceeded" }));
+        });
+
+        let task_type = TaskType::Http(HttpTaskType {
+            url: format!("{}/api/some/execute", server.base_url()).parse()?,
+            method: Method::POST,
+            headers: None,
+            body: None,
+            expected_response: Some(HttpTaskExpectedResponse {
+                status: Some((200, 204)),
+                body_pattern: Some(r#""ok":\s*true"#.to_string()),
+            }),
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+        });
+        let task = tasks_api
+            .schedule_task(
+                task_type.clone(),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        // Successful status with the error body shouldn't be considered delivered.
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        server_handler_mock.assert();
+        server_handler_mock.delete();
+
+        let mut server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/some/execute");
+            then.status(202)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!({ "ok": true }));
+        });
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        server_handler_mock.assert();
+        server_handler_mock.delete();
+
+        // Status outside of the expected range should fail the task even if the body matches.
+        let server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/some/execute");
+            then.status(500)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&json!({ "ok": true }));
+        });
+
+        let task = tasks_api
+            .schedule_task(task_type, OffsetDateTime::from_unix_timestamp(946720800)?)
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        server_handler_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_signs_http_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.secrets = SecretsConfig::from_iter([("webhook-secret", "secret")]);
+        let mut api = mock_api_with_config(pool, config).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let server_handler_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/some/execute")
+                .body(r#"{"version":"1.0.0"}"#)
+                .matches(|request| {
+                    let Some((_, signature)) = request
+                        .headers
+                        .iter()
+                        .flatten()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("x-retrack-signature"))
+                    else {
+                        return false;
+                    };
+                    let Some((timestamp, signature)) = signature
+                        .strip_prefix("t=")
+                        .and_then(|signature| signature.split_once(",v1="))
+                    else {
+                        return false;
+                    };
+                    let expected_signature = hmac::sign(
+                        &hmac::Key::new(hmac::HMAC_SHA256, b"secret"),
+                        format!(r#"{timestamp}.{{"version":"1.0.0"}}"#).as_bytes(),
+                    );
+                    let expected_signature = expected_signature
+                        .as_ref()
+                        .iter()
+                        .map(|byte| format!("{byte:02x}"))
+                        .collect::<String>();
+                    signature == expected_signature
+                        && (OffsetDateTime::now_utc().unix_timestamp()
+                            - timestamp.parse::<i64>().unwrap_or_default())
+                        .abs()
+                            < 60
+                });
+            then.status(200);
+        });
+
+        let task_type = TaskType::Http(HttpTaskType {
+            url: format!("{}/api/some/execute", server.base_url()).parse()?,
+            method: Method::POST,
+            headers: None,
+            body: Some(br#"{"version":"1.0.0"}"#.to_vec()),
+            expected_response: None,
+            timeout: None,
+            signing_secret: Some("webhook-secret".to_string()),
+            retry_strategy: None,
+        });
+        let task = tasks_api
+            .schedule_task(
+                task_type.clone(),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        server_handler_mock.assert();
+
+        // Task shouldn't be sent if the signing secret isn't configured anymore.
+        api.config.secrets = SecretsConfig::default();
+        let tasks_api = api.tasks();
+        let task = tasks_api
+            .schedule_task(task_type, OffsetDateTime::from_unix_timestamp(946720800)?)
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        server_handler_mock.assert_hits(1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_executes_nats_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let url = Url::parse(&format!("nats://retrack:pass@{}", listener.local_addr()?))?;
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = tokio::io::BufReader::new(stream);
+            stream
+                .get_mut()
+                .write_all(b"INFO {\"server_id\":\"retrack\",\"max_payload\":1048576}\r\n")
+                .await
+                .unwrap();
+
+            let mut lines = vec![];
+            while lines.len() < 4 {
+                let mut line = String::new();
+                stream.read_line(&mut line).await.unwrap();
+                if line.starts_with("PING") {
+                    stream.get_mut().write_all(b"PONG\r\n").await.unwrap();
+                } else if line.starts_with("PUB ") {
+                    let mut payload = vec![0; 21];
+                    stream.read_exact(&mut payload).await.unwrap();
+                    line.push_str(&String::from_utf8(payload).unwrap());
+                }
+                lines.push(line);
+            }
+            lines
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Nats(NatsTaskType {
+                    url,
+                    subject: "retrack.releases".to_string(),
+                    payload: br#"{"version":"1.0.0"}"#.to_vec(),
+                    timeout: None,
+                }),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        let lines = server.await?;
+        assert!(lines[0].starts_with("CONNECT {"));
+        assert!(lines[0].contains(r#""user":"retrack","pass":"pass""#));
+        assert_eq!(lines[1], "PING\r\n");
+        assert_eq!(
+            lines[2],
+            "PUB retrack.releases 19\r\n{\"version\":\"1.0.0\"}\r\n"
+        );
+        assert_eq!(lines[3], "PING\r\n");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn keep_nats_task_if_server_is_unavailable(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        // Bind and immediately release the port to get an address nobody listens on.
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let url = Url::parse(&format!("nats://{}", listener.local_addr()?))?;
+        drop(listener);
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Nats(NatsTaskType {
+                    url,
+                    subject: "retrack.releases".to_string(),
+                    payload: br#"{"version":"1.0.0"}"#.to_vec(),
+                    timeout: None,
+                }),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_executes_mqtt_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let url = Url::parse(&format!("mqtt://retrack:pass@{}", listener.local_addr()?))?;
+        let broker = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut packets = vec![];
+            loop {
+                // All packets sent by the client in this test are shorter than 128 bytes, so the
+                // remaining length always fits into a single byte.
+                let mut header = [0; 2];
+                stream.read_exact(&mut header).await.unwrap();
+                let mut body = vec![0; usize::from(header[1])];
+                stream.read_exact(&mut body).await.unwrap();
+                match header[0] & 0xF0 {
+                    0x10 => stream.write_all(b"\x20\x02\x00\x00").await.unwrap(),
+                    0x30 => stream.write_all(b"\x40\x02\x00\x01").await.unwrap(),
+                    _ => {}
+                }
+                packets.push([header.as_slice(), &body].concat());
+                if header[0] == 0xE0 {
+                    break;
+                }
+            }
+            packets
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Mqtt(MqttTaskType {
+                    url,
+                    topic: "retrack/releases".to_string(),
+                    payload: br#"{"version":"1.0.0"}"#.to_vec(),
+                    qos: 1,
+                    retain: true,
+                    timeout: None,
+                }),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        let packets = broker.await?;
+        assert_eq!(packets.len(), 3);
+        assert_eq!(
+            packets[0],
+            b"\x10\x1b\x00\x04MQTT\x04\xc2\x00\x3c\x00\x00\x00\x07retrack\x00\x04pass"
+        );
+        assert_eq!(
+            packets[1],
+            b"\x33\x27\x00\x10retrack/releases\x00\x01{\"version\":\"1.0.0\"}"
+        );
+        assert_eq!(packets[2], b"\xe0\x00");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn keep_mqtt_task_if_broker_is_unavailable(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        // Bind and immediately release the port to get an address nobody listens on.
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let url = Url::parse(&format!("mqtt://{}", listener.local_addr()?))?;
+        drop(listener);
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Mqtt(MqttTaskType {
+                    url,
+                    topic: "retrack/releases".to_string(),
+                    payload: br#"{"version":"1.0.0"}"#.to_vec(),
+                    qos: 0,
+                    retain: false,
+                    timeout: None,
+                }),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_executes_github_issue_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let issue_task = IssueTaskType {
+            provider: IssueTaskProvider::GitHub,
+            api_url: server.base_url().parse()?,
+            repository: "secutils-dev/retrack".to_string(),
+            token: "ghp_token".to_string(),
+            title: "Retrack: Releases".to_string(),
+            body: "New release: 1.0.0".to_string(),
+            labels: vec!["retrack".to_string()],
+            timeout: None,
+        };
+
+        // There are no open issues with the same title, so a new issue should be created.
+        let mut list_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/secutils-dev/retrack/issues")
+                .query_param("state", "open")
+                .header("authorization", "Bearer ghp_token")
+                .header("x-github-api-version", "2022-11-28");
+            then.status(200).json_body(json!([
+                { "number": 1, "title": "Retrack: Releases", "pull_request": {} },
+                { "number": 2, "title": "Unrelated" }
+            ]));
+        });
+        let mut create_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/repos/secutils-dev/retrack/issues")
+                .header("authorization", "Bearer ghp_token")
+                .json_body(json!({
+                    "title": "Retrack: Releases",
+                    "body": "New release: 1.0.0",
+                    "labels": ["retrack"]
+                }));
+            then.status(201)
+                .json_body(json!({ "number": 3, "title": "Retrack: Releases" }));
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Issue(issue_task.clone()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        list_mock.assert();
+        create_mock.assert();
+        list_mock.delete();
+        create_mock.delete();
+
+        // The issue with the same title is open, so it should be commented on instead.
+        let list_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/secutils-dev/retrack/issues");
+            then.status(200)
+                .json_body(json!([{ "number": 3, "title": "Retrack: Releases" }]));
+        });
+        let comment_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/repos/secutils-dev/retrack/issues/3/comments")
+                .header("authorization", "Bearer ghp_token")
+                .json_body(json!({ "body": "New release: 1.0.0" }));
+            then.status(201).json_body(json!({ "id": 10 }));
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Issue(issue_task),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        list_mock.assert();
+        comment_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_executes_gitlab_issue_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let issue_task = IssueTaskType {
+            provider: IssueTaskProvider::GitLab,
+            api_url: format!("{}/api/v4", server.base_url()).parse()?,
+            repository: "42".to_string(),
+            token: "glpat-token".to_string(),
+            title: "Retrack: Releases".to_string(),
+            body: "New release: 1.0.0".to_string(),
+            labels: vec!["retrack".to_string(), "triage".to_string()],
+            timeout: None,
+        };
+
+        // There are no open issues with the same title, so a new issue should be created.
+        let mut list_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/api/v4/projects/42/issues")
+                .query_param("state", "opened")
+                .query_param("search", "Retrack: Releases")
+                .header("private-token", "glpat-token");
+            then.status(200)
+                .json_body(json!([{ "iid": 1, "title": "Retrack: Releases (old)" }]));
+        });
+        let mut create_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/v4/projects/42/issues")
+                .header("private-token", "glpat-token")
+                .json_body(json!({
+                    "title": "Retrack: Releases",
+                    "description": "New release: 1.0.0",
+                    "labels": "retrack,triage"
+                }));
+            then.status(201)
+                .json_body(json!({ "iid": 2, "title": "Retrack: Releases" }));
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Issue(issue_task.clone()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        list_mock.assert();
+        create_mock.assert();
+        list_mock.delete();
+        create_mock.delete();
+
+        // The issue with the same title is open, so a note should be added instead.
+        let list_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/api/v4/projects/42/issues");
+            then.status(200)
+                .json_body(json!([{ "iid": 2, "title": "Retrack: Releases" }]));
+        });
+        let note_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/v4/projects/42/issues/2/notes")
+                .header("private-token", "glpat-token")
+                .json_body(json!({ "body": "New release: 1.0.0" }));
+            then.status(201).json_body(json!({ "id": 10 }));
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Issue(issue_task),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 1);
+        assert!(api.db.get_task(task.id).await?.is_none());
+
+        list_mock.assert();
+        note_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn keep_issue_task_if_issue_tracker_rejects_request(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let tasks_api = api.tasks();
+
+        let server = MockServer::start();
+        let list_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/repos/secutils-dev/retrack/issues");
+            then.status(401)
+                .json_body(json!({ "message": "Bad credentials" }));
+        });
+
+        let task = tasks_api
+            .schedule_task(
+                TaskType::Issue(IssueTaskType {
+                    provider: IssueTaskProvider::GitHub,
+                    api_url: server.base_url().parse()?,
+                    repository: "secutils-dev/retrack".to_string(),
+                    token: "ghp_token".to_string(),
+                    title: "Retrack: Releases".to_string(),
+                    body: "New release: 1.0.0".to_string(),
+                    labels: vec![],
+                    timeout: None,
+                }),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(tasks_api.execute_pending_tasks(3).await?, 0);
+        assert!(api.db.get_task(task.id).await?.is_some());
+
+        list_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_removes_matching_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let tasks = [
+            ("john@retrack.dev", "Hello, John!"),
+            ("dev@retrack.dev", "Hello, World!"),
+            ("dev@retrack.dev", "Contact: john@retrack.dev"),
+        ];
+        for (to, text) in tasks {
+            api.tasks()
+                .schedule_task(
+                    TaskType::Email(EmailTaskType {
+                        to: vec![to.to_string()],
+                        content: EmailContent::Custom(Email::text("subj", text)),
+                        timeout: None,
+                        attachments: None,
+                    }),
+                    OffsetDateTime::from_unix_timestamp(946720800)?,
+                )
+                .await?;
+        }
+
+        // Task that doesn't match the pattern itself, but its last delivery error does.
+        let failed_task = Task {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            task_type: TaskType::Email(EmailTaskType {
+                to: vec!["ops@retrack.dev".to_string()],
+                content: EmailContent::Custom(Email::text("subj", "Hello, Ops!")),
+                timeout: None,
+                attachments: None,
+            }),
+            scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            delivery: Some(TaskDelivery {
+                attempts: 1,
+                last_error: "Recipient john@retrack.dev is rejected.".to_string(),
+                failed: false,
+            }),
+        };
+        api.db.insert_task(&failed_task).await?;
+
+        let pattern = Regex::new(r"john@retrack\.dev")?;
+        assert_eq!(api.tasks().remove_matching_tasks(&pattern, true).await?, 3);
+        assert_eq!(api.db.get_tasks(10).collect::<Vec<_>>().await.len(), 4);
+        assert_eq!(
+            api.db.get_task(failed_task.id).await?,
+            Some(failed_task.clone())
+        );
+
+        assert_eq!(api.tasks().remove_matching_tasks(&pattern, false).await?, 3);
+        let remaining_tasks = api
+            .db
+            .get_tasks(10)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(remaining_tasks.len(), 2);
+        assert_eq!(
+            remaining_tasks[1].task_type,
+            TaskType::Email(EmailTaskType {
+                to: vec!["dev@retrack.dev".to_string()],
+                content: EmailContent::Custom(Email::text("subj", "Hello, World!")),
+                timeout: None,
+                attachments: None,
+            })
+        );
+        assert_eq!(
+            api.db.get_task(failed_task.id).await?,
+            Some(Task {
+                delivery: Some(TaskDelivery {
+                    attempts: 1,
+                    last_error: String::new(),
+                    failed: false,
+                }),
+                ..failed_task
+            })
+        );
+
+        assert_eq!(api.tasks().remove_matching_tasks(&pattern, false).await?, 0);
+
+        Ok(())
+    }
 }
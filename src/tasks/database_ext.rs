@@ -14,38 +14,90 @@ use uuid::Uuid;
 impl Database {
     /// Retrieves task from the database using ID.
     pub async fn get_task(&self, id: Uuid) -> anyhow::Result<Option<Task>> {
-        query_as!(RawTask, r#"SELECT * FROM tasks WHERE id = $1"#, id)
-            .fetch_optional(&self.pool)
-            .await?
-            .map(Task::try_from)
-            .transpose()
+        self.with_retries(|| async {
+            Ok(
+                query_as!(RawTask, r#"SELECT * FROM tasks WHERE id = $1"#, id)
+                    .fetch_optional(&self.pool)
+                    .await?,
+            )
+        })
+        .await?
+        .map(Task::try_from)
+        .transpose()
     }
 
     /// Inserts a new task to the database.
     pub async fn insert_task(&self, task: &Task) -> anyhow::Result<()> {
         let raw_task = RawTask::try_from(task)?;
-        query!(
-            r#"INSERT INTO tasks (id, task_type, scheduled_at) VALUES ($1, $2, $3)"#,
-            raw_task.id,
-            raw_task.task_type,
-            raw_task.scheduled_at
-        )
-        .execute(&self.pool)
+        self.with_retries(|| async {
+            query!(
+                r#"INSERT INTO tasks (id, task_type, scheduled_at, attempts, last_error, failed) VALUES ($1, $2, $3, $4, $5, $6)"#,
+                raw_task.id,
+                &raw_task.task_type,
+                raw_task.scheduled_at,
+                raw_task.attempts,
+                raw_task.last_error,
+                raw_task.failed
+            )
+            .execute(&self.pool)
+            .await?;
+            Ok(())
+        })
         .await?;
 
         Ok(())
     }
 
-    /// Removes task from the database using ID.
-    pub async fn remove_task(&self, id: Uuid) -> anyhow::Result<()> {
-        query!(r#"DELETE FROM tasks WHERE id = $1"#, id)
+    /// Updates the time the task is scheduled at and its delivery state.
+    pub async fn update_task_delivery(&self, task: &Task) -> anyhow::Result<()> {
+        let raw_task = RawTask::try_from(task)?;
+        self.with_retries(|| async {
+            query!(
+                r#"UPDATE tasks SET scheduled_at = $2, attempts = $3, last_error = $4, failed = $5 WHERE id = $1"#,
+                raw_task.id,
+                raw_task.scheduled_at,
+                raw_task.attempts,
+                raw_task.last_error,
+                raw_task.failed
+            )
             .execute(&self.pool)
             .await?;
+            Ok(())
+        })
+        .await?;
 
         Ok(())
     }
 
-    /// Retrieves a list of tasks IDs that are scheduled at or before specified date.
+    /// Clears the error of the last failed attempt to execute the task, keeping the rest of the
+    /// delivery state intact.
+    pub async fn clear_task_last_error(&self, id: Uuid) -> anyhow::Result<()> {
+        self.with_retries(|| async {
+            query!(r#"UPDATE tasks SET last_error = NULL WHERE id = $1"#, id)
+                .execute(&self.pool)
+                .await?;
+            Ok(())
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes task from the database using ID.
+    pub async fn remove_task(&self, id: Uuid) -> anyhow::Result<()> {
+        self.with_retries(|| async {
+            query!(r#"DELETE FROM tasks WHERE id = $1"#, id)
+                .execute(&self.pool)
+                .await?;
+            Ok(())
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves a list of tasks IDs that are scheduled at or before specified date, excluding the
+    /// tasks that have failed and won't be retried anymore.
     pub fn get_tasks_ids(
         &self,
         scheduled_before_or_at: OffsetDateTime,
@@ -57,7 +109,7 @@ impl Database {
             let mut conn = self.pool.acquire().await?;
             loop {
                  let raw_tasks_ids = query!(
-                    r#"SELECT id FROM tasks WHERE scheduled_at <= $1 AND id > $2 ORDER BY scheduled_at, id LIMIT $3;"#,
+                    r#"SELECT id FROM tasks WHERE scheduled_at <= $1 AND id > $2 AND failed = FALSE ORDER BY scheduled_at, id LIMIT $3;"#,
                     scheduled_before_or_at,
                     last_id,
                     page_limit
@@ -75,6 +127,33 @@ impl Database {
             }
         }
     }
+
+    /// Retrieves all tasks regardless of the time they are scheduled at, ordered by ID.
+    pub fn get_tasks(&self, page_size: usize) -> impl Stream<Item = anyhow::Result<Task>> + '_ {
+        let page_limit = page_size as i64;
+        try_stream! {
+            let mut last_id = Uuid::nil();
+            let mut conn = self.pool.acquire().await?;
+            loop {
+                 let raw_tasks = query_as!(
+                    RawTask,
+                    r#"SELECT * FROM tasks WHERE id > $1 ORDER BY id LIMIT $2;"#,
+                    last_id,
+                    page_limit
+                ).fetch_all(&mut *conn).await?;
+
+                let is_last_page = raw_tasks.len() < page_size;
+                for raw_task in raw_tasks {
+                    last_id = raw_task.id;
+                    yield Task::try_from(raw_task)?;
+                }
+
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -106,8 +185,11 @@ mod tests {
                         "subj".to_string(),
                         "email text".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
             Task {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
@@ -117,8 +199,11 @@ mod tests {
                         "subj #2".to_string(),
                         "email text #2".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
         ];
 
@@ -143,9 +228,11 @@ mod tests {
                                 attachments: None,
                             },
                         ),
+                        timeout: None,
                     },
                 ),
                 scheduled_at: 2000-01-01 10:00:00.0 +00:00:00,
+                delivery: None,
             },
         )
         "###);
@@ -166,9 +253,11 @@ mod tests {
                                 attachments: None,
                             },
                         ),
+                        timeout: None,
                     },
                 ),
                 scheduled_at: 2000-01-01 10:00:00.0 +00:00:00,
+                delivery: None,
             },
         )
         "###);
@@ -190,8 +279,11 @@ mod tests {
                         "subj".to_string(),
                         "email text".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
             Task {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
@@ -201,8 +293,11 @@ mod tests {
                         "subj #2".to_string(),
                         "email text #2".to_string(),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             },
         ];
 
@@ -269,8 +364,11 @@ mod tests {
                         format!("subj {n}"),
                         format!("email text {n}"),
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720700 + n)?,
+                delivery: None,
             })
             .await?;
         }
@@ -301,4 +399,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_get_all_tasks(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let tasks = db.get_tasks(2);
+        assert_eq!(tasks.collect::<Vec<_>>().await.len(), 0);
+
+        for n in 0..5 {
+            db.insert_task(&Task {
+                id: Uuid::parse_str(&format!("00000000-0000-0000-0000-0000000000{:02X}", 5 - n))?,
+                task_type: TaskType::Email(EmailTaskType {
+                    to: vec!["dev@retrack.dev".to_string()],
+                    content: EmailContent::Custom(Email::text(
+                        format!("subj {n}"),
+                        format!("email text {n}"),
+                    )),
+                    timeout: None,
+                    attachments: None,
+                }),
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720700 + n)?,
+                delivery: None,
+            })
+            .await?;
+        }
+
+        let tasks = db
+            .get_tasks(2)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            tasks.iter().map(|task| task.id).collect::<Vec<_>>(),
+            vec![
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                uuid!("00000000-0000-0000-0000-000000000004"),
+                uuid!("00000000-0000-0000-0000-000000000005"),
+            ]
+        );
+        assert_eq!(
+            tasks[0].scheduled_at,
+            OffsetDateTime::from_unix_timestamp(946720704)?
+        );
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use crate::tasks::Task;
+use crate::tasks::{Task, TaskDelivery};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -7,6 +7,9 @@ pub(super) struct RawTask {
     pub id: Uuid,
     pub task_type: Vec<u8>,
     pub scheduled_at: OffsetDateTime,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub failed: bool,
 }
 
 impl TryFrom<RawTask> for Task {
@@ -17,6 +20,15 @@ impl TryFrom<RawTask> for Task {
             id: raw_task.id,
             task_type: postcard::from_bytes(&raw_task.task_type)?,
             scheduled_at: raw_task.scheduled_at,
+            delivery: if raw_task.attempts > 0 {
+                Some(TaskDelivery {
+                    attempts: raw_task.attempts as u32,
+                    last_error: raw_task.last_error.unwrap_or_default(),
+                    failed: raw_task.failed,
+                })
+            } else {
+                None
+            },
         })
     }
 }
@@ -29,6 +41,19 @@ impl TryFrom<&Task> for RawTask {
             id: task.id,
             task_type: postcard::to_stdvec(&task.task_type)?,
             scheduled_at: task.scheduled_at,
+            attempts: task
+                .delivery
+                .as_ref()
+                .map(|delivery| delivery.attempts as i32)
+                .unwrap_or_default(),
+            last_error: task
+                .delivery
+                .as_ref()
+                .map(|delivery| delivery.last_error.clone()),
+            failed: task
+                .delivery
+                .as_ref()
+                .is_some_and(|delivery| delivery.failed),
         })
     }
 }
@@ -36,7 +61,7 @@ impl TryFrom<&Task> for RawTask {
 #[cfg(test)]
 mod tests {
     use super::RawTask;
-    use crate::tasks::{Email, EmailContent, EmailTaskType, Task, TaskType};
+    use crate::tasks::{Email, EmailContent, EmailTaskType, Task, TaskDelivery, TaskType};
     use time::OffsetDateTime;
     use uuid::uuid;
 
@@ -48,9 +73,12 @@ mod tests {
                 task_type: vec![
                     0, 1, 15, 100, 101, 118, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101,
                     118, 0, 4, 115, 117, 98, 106, 10, 101, 109, 97, 105, 108, 32, 116, 101, 120,
-                    116, 0, 0
+                    116, 0, 0, 0, 0
                 ],
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                attempts: 0,
+                last_error: None,
+                failed: false,
             })?,
             Task {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
@@ -60,8 +88,11 @@ mod tests {
                         "subj".to_string(),
                         "email text".to_string()
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             }
         );
 
@@ -79,20 +110,56 @@ mod tests {
                         "subj".to_string(),
                         "email text".to_string()
                     )),
+                    timeout: None,
+                    attachments: None,
                 }),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                delivery: None,
             })?,
             RawTask {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
                 task_type: vec![
                     0, 1, 15, 100, 101, 118, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101,
                     118, 0, 4, 115, 117, 98, 106, 10, 101, 109, 97, 105, 108, 32, 116, 101, 120,
-                    116, 0, 0
+                    116, 0, 0, 0, 0
                 ],
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                attempts: 0,
+                last_error: None,
+                failed: false,
             }
         );
 
         Ok(())
     }
+
+    #[test]
+    fn can_convert_task_delivery() -> anyhow::Result<()> {
+        let task = Task {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            task_type: TaskType::Email(EmailTaskType {
+                to: vec!["dev@retrack.dev".to_string()],
+                content: EmailContent::Custom(Email::text(
+                    "subj".to_string(),
+                    "email text".to_string(),
+                )),
+                timeout: None,
+                attachments: None,
+            }),
+            scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            delivery: Some(TaskDelivery {
+                attempts: 3,
+                last_error: "Connection refused.".to_string(),
+                failed: true,
+            }),
+        };
+
+        let raw_task = RawTask::try_from(&task)?;
+        assert_eq!(raw_task.attempts, 3);
+        assert_eq!(raw_task.last_error.as_deref(), Some("Connection refused."));
+        assert!(raw_task.failed);
+        assert_eq!(Task::try_from(raw_task)?, task);
+
+        Ok(())
+    }
 }
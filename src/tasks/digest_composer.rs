@@ -0,0 +1,242 @@
+use crate::tasks::{EmailContent, EmailTaskType, EmailTemplate, HttpTaskType, TaskType};
+use serde_json::{json, Value as JsonValue};
+use std::time::Duration;
+
+/// Digest email that accumulates changes of the trackers with the same recipients.
+struct EmailDigest {
+    /// Recipients of the digest email.
+    to: Vec<String>,
+    /// Optional timeout for the email delivery.
+    timeout: Option<Duration>,
+    /// Pairs of tracker names and their changes.
+    changes: Vec<(String, String)>,
+}
+
+/// Composes tasks of the notifications accumulated for a single digest into as few tasks as
+/// possible. Notifications are merged only if they are delivered to the same recipients: emails
+/// are merged into a single digest email, and HTTP requests into a single request with the JSON
+/// array of changes as the body. Notifications that cannot be merged, including emails with
+/// attachments, are delivered as is.
+pub fn compose_digest(
+    tag: Option<&str>,
+    notifications: Vec<(String, TaskType)>,
+) -> anyhow::Result<Vec<TaskType>> {
+    let mut emails: Vec<EmailDigest> = vec![];
+    let mut requests: Vec<(HttpTaskType, Vec<JsonValue>)> = vec![];
+    let mut other_task_types = vec![];
+    for (tracker_name, task_type) in notifications {
+        match task_type {
+            TaskType::Email(EmailTaskType {
+                to,
+                content:
+                    EmailContent::Template(EmailTemplate::TrackerChanges {
+                        content: Ok(content),
+                        ..
+                    }),
+                timeout,
+                attachments: None,
+            }) => {
+                match emails
+                    .iter_mut()
+                    .find(|email| email.to == to && email.timeout == timeout)
+                {
+                    Some(email) => email.changes.push((tracker_name, content)),
+                    None => emails.push(EmailDigest {
+                        to,
+                        timeout,
+                        changes: vec![(tracker_name, content)],
+                    }),
+                }
+            }
+            TaskType::Http(mut request) => {
+                let data = request
+                    .body
+                    .take()
+                    .map(|body| serde_json::from_slice::<JsonValue>(&body))
+                    .transpose()?
+                    .unwrap_or_default();
+                let change = json!({ "tracker": tracker_name, "data": data });
+                match requests
+                    .iter_mut()
+                    .find(|(digest_request, _)| *digest_request == request)
+                {
+                    Some((_, changes)) => changes.push(change),
+                    None => requests.push((request, vec![change])),
+                }
+            }
+            task_type => other_task_types.push(task_type),
+        }
+    }
+
+    let mut task_types = Vec::with_capacity(emails.len() + requests.len() + other_task_types.len());
+    for EmailDigest {
+        to,
+        timeout,
+        changes,
+    } in emails
+    {
+        task_types.push(TaskType::Email(EmailTaskType {
+            to,
+            content: EmailContent::Template(EmailTemplate::TrackersDigest {
+                tag: tag.map(str::to_string),
+                changes,
+            }),
+            timeout,
+            attachments: None,
+        }));
+    }
+
+    for (mut request, changes) in requests {
+        request.body = Some(serde_json::to_vec(&changes)?);
+        task_types.push(TaskType::Http(request));
+    }
+
+    task_types.extend(other_task_types);
+
+    Ok(task_types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compose_digest;
+    use crate::tasks::{Email, EmailContent, EmailTaskType, EmailTemplate, HttpTaskType, TaskType};
+    use http::Method;
+    use serde_json::{json, Value as JsonValue};
+
+    fn email_task(to: &str, content: &str) -> TaskType {
+        TaskType::Email(EmailTaskType {
+            to: vec![to.to_string()],
+            content: EmailContent::Template(EmailTemplate::TrackerChanges {
+                tracker_name: "tracker".to_string(),
+                content: Ok(content.to_string()),
+            }),
+            timeout: None,
+            attachments: None,
+        })
+    }
+
+    fn http_task(url: &str, body: JsonValue) -> anyhow::Result<TaskType> {
+        Ok(TaskType::Http(HttpTaskType {
+            url: url.parse()?,
+            method: Method::POST,
+            headers: None,
+            body: Some(serde_json::to_vec(&body)?),
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+        }))
+    }
+
+    #[test]
+    fn merges_notifications_with_the_same_recipients() -> anyhow::Result<()> {
+        let custom_email = TaskType::Email(EmailTaskType {
+            to: vec!["dev@retrack.dev".to_string()],
+            content: EmailContent::Custom(Email::text("subject", "text")),
+            timeout: None,
+            attachments: None,
+        });
+        let task_types = compose_digest(
+            Some("vendor"),
+            vec![
+                (
+                    "tracker-one".to_string(),
+                    email_task("dev@retrack.dev", "one"),
+                ),
+                (
+                    "tracker-one".to_string(),
+                    http_task("https://retrack.dev/hook", json!({ "version": "1.0.0" }))?,
+                ),
+                (
+                    "tracker-two".to_string(),
+                    email_task("ops@retrack.dev", "two"),
+                ),
+                ("tracker-two".to_string(), custom_email.clone()),
+                (
+                    "tracker-two".to_string(),
+                    email_task("dev@retrack.dev", "three"),
+                ),
+                (
+                    "tracker-two".to_string(),
+                    http_task("https://retrack.dev/hook", json!({ "version": "2.0.0" }))?,
+                ),
+            ],
+        )?;
+        assert_eq!(task_types.len(), 4);
+        assert_eq!(
+            task_types[0],
+            TaskType::Email(EmailTaskType {
+                to: vec!["dev@retrack.dev".to_string()],
+                content: EmailContent::Template(EmailTemplate::TrackersDigest {
+                    tag: Some("vendor".to_string()),
+                    changes: vec![
+                        ("tracker-one".to_string(), "one".to_string()),
+                        ("tracker-two".to_string(), "three".to_string()),
+                    ],
+                }),
+                timeout: None,
+                attachments: None,
+            })
+        );
+        assert_eq!(
+            task_types[1],
+            TaskType::Email(EmailTaskType {
+                to: vec!["ops@retrack.dev".to_string()],
+                content: EmailContent::Template(EmailTemplate::TrackersDigest {
+                    tag: Some("vendor".to_string()),
+                    changes: vec![("tracker-two".to_string(), "two".to_string())],
+                }),
+                timeout: None,
+                attachments: None,
+            })
+        );
+
+        let TaskType::Http(ref request) = task_types[2] else {
+            panic!("Expected HTTP task, got {:?}", task_types[2]);
+        };
+        assert_eq!(request.url.as_str(), "https://retrack.dev/hook");
+        assert_eq!(
+            serde_json::from_slice::<JsonValue>(request.body.as_deref().unwrap_or_default())?,
+            json!([
+                { "tracker": "tracker-one", "data": { "version": "1.0.0" } },
+                { "tracker": "tracker-two", "data": { "version": "2.0.0" } }
+            ])
+        );
+
+        assert_eq!(task_types[3], custom_email);
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_notifications_with_different_recipients_apart() -> anyhow::Result<()> {
+        let task_types = compose_digest(
+            None,
+            vec![
+                (
+                    "tracker".to_string(),
+                    http_task("https://retrack.dev/hook", json!("one"))?,
+                ),
+                (
+                    "tracker".to_string(),
+                    http_task("https://retrack.dev/other-hook", json!("two"))?,
+                ),
+            ],
+        )?;
+        assert_eq!(
+            task_types,
+            vec![
+                http_task(
+                    "https://retrack.dev/hook",
+                    json!([{ "tracker": "tracker", "data": "one" }])
+                )?,
+                http_task(
+                    "https://retrack.dev/other-hook",
+                    json!([{ "tracker": "tracker", "data": "two" }])
+                )?,
+            ]
+        );
+
+        Ok(())
+    }
+}
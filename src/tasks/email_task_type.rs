@@ -5,6 +5,7 @@ mod email_content;
 mod email_template;
 
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 pub use self::{
     email::Email, email_attachment::EmailAttachment,
@@ -19,12 +20,19 @@ pub struct EmailTaskType {
     pub to: Vec<String>,
     /// The content of the email.
     pub content: EmailContent,
+    /// Optional time to wait for the email to be sent, the default email task timeout is used if
+    /// not set.
+    pub timeout: Option<Duration>,
+    /// Optional list of attachments to add to the email, in addition to the attachments of the
+    /// email content.
+    pub attachments: Option<Vec<EmailAttachment>>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::EmailContent;
     use crate::tasks::{Email, EmailTaskType};
+    use std::time::Duration;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
@@ -32,11 +40,13 @@ mod tests {
             postcard::to_stdvec(&EmailTaskType {
                 to: vec!["one@retrack.dev".to_string(), "two@retrack.dev".to_string()],
                 content: EmailContent::Custom(Email::text("subject", "text")),
+                timeout: Some(Duration::from_secs(30)),
+                attachments: None,
             })?,
             vec![
                 2, 15, 111, 110, 101, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118, 15,
                 116, 119, 111, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118, 0, 7, 115,
-                117, 98, 106, 101, 99, 116, 4, 116, 101, 120, 116, 0, 0
+                117, 98, 106, 101, 99, 116, 4, 116, 101, 120, 116, 0, 0, 1, 30, 0, 0
             ]
         );
 
@@ -49,11 +59,13 @@ mod tests {
             postcard::from_bytes::<EmailTaskType>(&[
                 2, 15, 111, 110, 101, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118, 15,
                 116, 119, 111, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118, 0, 7, 115,
-                117, 98, 106, 101, 99, 116, 4, 116, 101, 120, 116, 0, 0
+                117, 98, 106, 101, 99, 116, 4, 116, 101, 120, 116, 0, 0, 1, 30, 0, 0
             ])?,
             EmailTaskType {
                 to: vec!["one@retrack.dev".to_string(), "two@retrack.dev".to_string()],
                 content: EmailContent::Custom(Email::text("subject", "text")),
+                timeout: Some(Duration::from_secs(30)),
+                attachments: None,
             }
         );
 
@@ -16,7 +16,6 @@ pub struct Email {
 
 impl Email {
     /// Creates a new plain-text email.
-    #[allow(dead_code)]
     pub fn text<S: Into<String>, T: Into<String>>(subject: S, text: T) -> Self {
         Self {
             subject: subject.into(),
@@ -27,7 +26,6 @@ impl Email {
     }
 
     /// Create new HTML email with a plain-text fallback.
-    #[allow(dead_code)]
     pub fn html<S: Into<String>, T: Into<String>, H: Into<String>>(
         subject: S,
         text: T,
@@ -25,6 +25,19 @@ impl EmailAttachment {
             content: content.into(),
         }
     }
+
+    /// Create a file email attachment.
+    pub fn attachment<F: Into<String>, T: Into<String>, C: Into<Vec<u8>>>(
+        file_name: F,
+        content_type: T,
+        content: C,
+    ) -> Self {
+        Self {
+            disposition: EmailAttachmentDisposition::Attachment(file_name.into()),
+            content_type: content_type.into(),
+            content: content.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -75,4 +88,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn create_attachment() -> anyhow::Result<()> {
+        assert_eq!(
+            EmailAttachment::attachment("data.json", "application/json", vec![1, 2, 3]),
+            EmailAttachment {
+                disposition: EmailAttachmentDisposition::Attachment("data.json".to_string()),
+                content_type: "application/json".to_string(),
+                content: vec![1, 2, 3],
+            }
+        );
+
+        Ok(())
+    }
 }
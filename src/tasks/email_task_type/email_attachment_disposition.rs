@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub enum EmailAttachmentDisposition {
     /// Email attachment should be inlined.
     Inline(String),
+    /// Email attachment should be attached as a file with the specified name.
+    Attachment(String),
 }
 
 #[cfg(test)]
@@ -17,6 +19,10 @@ mod tests {
             postcard::to_stdvec(&EmailAttachmentDisposition::Inline("abc".to_string()))?,
             vec![0, 3, 97, 98, 99]
         );
+        assert_eq!(
+            postcard::to_stdvec(&EmailAttachmentDisposition::Attachment("abc".to_string()))?,
+            vec![1, 3, 97, 98, 99]
+        );
 
         Ok(())
     }
@@ -27,6 +33,10 @@ mod tests {
             postcard::from_bytes::<EmailAttachmentDisposition>(&[0, 3, 97, 98, 99])?,
             EmailAttachmentDisposition::Inline("abc".to_string())
         );
+        assert_eq!(
+            postcard::from_bytes::<EmailAttachmentDisposition>(&[1, 3, 97, 98, 99])?,
+            EmailAttachmentDisposition::Attachment("abc".to_string())
+        );
 
         Ok(())
     }
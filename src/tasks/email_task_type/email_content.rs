@@ -48,7 +48,7 @@ mod tests {
         assert_eq!(
             postcard::to_stdvec(&EmailContent::Template(EmailTemplate::TrackerChanges {
                 tracker_name: "tracker_name_1".to_string(),
-                content: Ok("email content".to_string())
+                content: Ok("email content".to_string()),
             }))?,
             vec![
                 1, 0, 14, 116, 114, 97, 99, 107, 101, 114, 95, 110, 97, 109, 101, 95, 49, 0, 13,
@@ -72,7 +72,19 @@ mod tests {
             ])?,
             EmailContent::Template(EmailTemplate::TrackerChanges {
                 tracker_name: "tracker_name_1".to_string(),
-                content: Ok("email content".to_string())
+                content: Ok("email content".to_string()),
+            })
+        );
+
+        assert_eq!(
+            postcard::from_bytes::<EmailContent>(&[
+                1, 3, 7, 116, 114, 97, 99, 107, 101, 114, 1, 5, 101, 114, 114, 111, 114, 19, 104,
+                116, 116, 112, 115, 58, 47, 47, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118
+            ])?,
+            EmailContent::Template(EmailTemplate::TrackerChangesWithRunbook {
+                tracker_name: "tracker".to_string(),
+                content: Err("error".to_string()),
+                runbook_url: "https://retrack.dev".to_string(),
             })
         );
 
@@ -15,9 +15,55 @@ pub enum EmailTemplate {
         tracker_name: String,
         content: Result<String, String>,
     },
+    TrackersIncident {
+        tag: String,
+        trackers: Vec<(String, String)>,
+    },
+    TrackersDigest {
+        tag: Option<String>,
+        changes: Vec<(String, String)>,
+    },
+    /// Same as `TrackerChanges`, but with the link to the runbook. Templates are persisted with
+    /// the scheduled tasks, so variants are only ever appended to keep the stored tasks readable.
+    TrackerChangesWithRunbook {
+        tracker_name: String,
+        content: Result<String, String>,
+        runbook_url: String,
+    },
+    /// Same as `TrackersIncident`, but with the link to the runbook.
+    TrackersIncidentWithRunbook {
+        tag: String,
+        trackers: Vec<(String, String)>,
+        runbook_url: String,
+    },
 }
 
 impl EmailTemplate {
+    /// Attaches the link to the runbook to the tracker failure templates, if it's specified.
+    pub fn with_runbook_url(self, runbook_url: Option<String>) -> Self {
+        match (self, runbook_url) {
+            (
+                Self::TrackerChanges {
+                    tracker_name,
+                    content,
+                },
+                Some(runbook_url),
+            ) => Self::TrackerChangesWithRunbook {
+                tracker_name,
+                content,
+                runbook_url,
+            },
+            (Self::TrackersIncident { tag, trackers }, Some(runbook_url)) => {
+                Self::TrackersIncidentWithRunbook {
+                    tag,
+                    trackers,
+                    runbook_url,
+                }
+            }
+            (template, _) => template,
+        }
+    }
+
     /// Compiles tracker content changes template as an email.
     pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
         &self,
@@ -27,7 +73,23 @@ impl EmailTemplate {
             Self::TrackerChanges {
                 tracker_name,
                 content,
-            } => Self::tracker_changes(api, tracker_name, content).await,
+            } => Self::tracker_changes(api, tracker_name, content, None).await,
+            Self::TrackersIncident { tag, trackers } => {
+                Self::trackers_incident(api, tag, trackers, None).await
+            }
+            Self::TrackersDigest { tag, changes } => {
+                Self::trackers_digest(api, tag.as_deref(), changes).await
+            }
+            Self::TrackerChangesWithRunbook {
+                tracker_name,
+                content,
+                runbook_url,
+            } => Self::tracker_changes(api, tracker_name, content, Some(runbook_url)).await,
+            Self::TrackersIncidentWithRunbook {
+                tag,
+                trackers,
+                runbook_url,
+            } => Self::trackers_incident(api, tag, trackers, Some(runbook_url)).await,
         }
     }
     /// Compiles tracker content changes template as an email.
@@ -35,6 +97,7 @@ impl EmailTemplate {
         api: &Api<DR, ET>,
         tracker_name: &str,
         content: &Result<String, String>,
+        runbook_url: Option<&str>,
     ) -> anyhow::Result<Email> {
         let back_link = format!("{}ws/web_scraping__content", api.config.public_url);
         let (subject, text, html) = match content {
@@ -57,14 +120,15 @@ impl EmailTemplate {
             Err(error_message) => (
                 format!("[Retrack] Check failed: \"{}\"", tracker_name),
                 format!(
-                    "\"{}\" tracker failed to check for content changes due to the following error: {error_message}. Visit {} to learn more.",
-                    tracker_name, back_link
+                    "\"{}\" tracker failed to check for content changes due to the following error: {error_message}. Visit {} to learn more.{}",
+                    tracker_name, back_link, Self::runbook_text(runbook_url)
                 ),
                 api.templates.render(
                     "tracker_changes_error_email",
                     &json!({
                 "tracker_name": tracker_name,
                 "error_message": error_message,
+                "runbook_url": runbook_url,
                 "back_link": back_link,
                 "home_link": api.config.public_url.as_str(),
             }),
@@ -83,6 +147,127 @@ impl EmailTemplate {
             )],
         ))
     }
+
+    /// Compiles incident template, when multiple trackers sharing the same tag fail, as an email.
+    async fn trackers_incident<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tag: &str,
+        trackers: &[(String, String)],
+        runbook_url: Option<&str>,
+    ) -> anyhow::Result<Email> {
+        let back_link = format!("{}ws/web_scraping__content", api.config.public_url);
+        let subject = format!(
+            "[Retrack] Incident: {} trackers tagged \"{tag}\" failed",
+            trackers.len()
+        );
+        let text = format!(
+            "{} trackers tagged \"{tag}\" failed to check for content changes: {}. Visit {back_link} to learn more.{}",
+            trackers.len(),
+            trackers
+                .iter()
+                .map(|(tracker_name, error_message)| format!("\"{tracker_name}\" ({error_message})"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Self::runbook_text(runbook_url)
+        );
+        let html = api.templates.render(
+            "trackers_incident_email",
+            &json!({
+                "tag": tag,
+                "trackers": trackers
+                    .iter()
+                    .map(|(tracker_name, error_message)| json!({
+                        "tracker_name": tracker_name,
+                        "error_message": error_message,
+                    }))
+                    .collect::<Vec<_>>(),
+                "trackers_count": trackers.len(),
+                "runbook_url": runbook_url,
+                "back_link": back_link,
+                "home_link": api.config.public_url.as_str(),
+            }),
+        )?;
+
+        Ok(Email::html_with_attachments(
+            subject,
+            text,
+            html,
+            vec![EmailAttachment::inline(
+                "retrack-logo",
+                "image/png",
+                RETRACK_LOGO_BYTES.to_vec(),
+            )],
+        ))
+    }
+
+    /// Compiles digest template, when content changes of the tracker or of the trackers sharing the
+    /// same tag are accumulated over a period of time, as an email.
+    async fn trackers_digest<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tag: Option<&str>,
+        changes: &[(String, String)],
+    ) -> anyhow::Result<Email> {
+        let back_link = format!("{}ws/web_scraping__content", api.config.public_url);
+        // Digest that isn't aggregated by tag only includes changes of a single tracker.
+        let tracker_name = changes
+            .first()
+            .map(|(tracker_name, _)| tracker_name.as_str())
+            .unwrap_or_default();
+        let source = match tag {
+            Some(tag) => format!("trackers tagged \"{tag}\""),
+            None => format!("\"{tracker_name}\" tracker"),
+        };
+        let subject = format!(
+            "[Retrack] Digest: {} changes detected by {source}",
+            changes.len()
+        );
+        let text = format!(
+            "{} changes detected by {source}: {}. Visit {back_link} to learn more.",
+            changes.len(),
+            changes
+                .iter()
+                .map(|(tracker_name, content)| format!("\"{tracker_name}\" ({content})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let html = api.templates.render(
+            "trackers_digest_email",
+            &json!({
+                "tag": tag,
+                "tracker_name": tracker_name,
+                "changes": changes
+                    .iter()
+                    .map(|(tracker_name, content)| json!({
+                        "tracker_name": tracker_name,
+                        "content": content,
+                    }))
+                    .collect::<Vec<_>>(),
+                "changes_count": changes.len(),
+                "back_link": back_link,
+                "home_link": api.config.public_url.as_str(),
+            }),
+        )?;
+
+        Ok(Email::html_with_attachments(
+            subject,
+            text,
+            html,
+            vec![EmailAttachment::inline(
+                "retrack-logo",
+                "image/png",
+                RETRACK_LOGO_BYTES.to_vec(),
+            )],
+        ))
+    }
+
+    /// Returns the sentence that refers to the runbook in the text version of the failure emails.
+    fn runbook_text(runbook_url: Option<&str>) -> String {
+        runbook_url
+            .map(|runbook_url| {
+                format!(" To handle the failure, follow the runbook: {runbook_url}.")
+            })
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -199,4 +384,127 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_compile_tracker_changes_error_template_with_runbook_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let template = EmailTemplate::TrackerChanges {
+            tracker_name: "tracker".to_string(),
+            content: Err("Something went wrong".to_string()),
+        }
+        .with_runbook_url(Some("https://retrack.dev/runbooks/tracker".to_string()))
+        .compile_to_email(&api)
+        .await?;
+        assert_debug_snapshot!(template.text, @r###""\"tracker\" tracker failed to check for content changes due to the following error: Something went wrong. Visit http://localhost:1234/ws/web_scraping__content to learn more. To handle the failure, follow the runbook: https://retrack.dev/runbooks/tracker.""###);
+        assert!(template.html.unwrap().contains(
+            "<p>To handle the failure, follow the runbook: <a href=\"https://retrack.dev/runbooks/tracker\">https://retrack.dev/runbooks/tracker</a></p>"
+        ));
+
+        let template = EmailTemplate::TrackersIncident {
+            tag: "vendor".to_string(),
+            trackers: vec![("tracker-one".to_string(), "some error".to_string())],
+        }
+        .with_runbook_url(Some("https://retrack.dev/runbooks/vendor".to_string()))
+        .compile_to_email(&api)
+        .await?;
+        assert_debug_snapshot!(template.text, @r###""1 trackers tagged \"vendor\" failed to check for content changes: \"tracker-one\" (some error). Visit http://localhost:1234/ws/web_scraping__content to learn more. To handle the failure, follow the runbook: https://retrack.dev/runbooks/vendor.""###);
+        assert!(template.html.unwrap().contains(
+            "<p>To handle the failure, follow the runbook: <a href=\"https://retrack.dev/runbooks/vendor\">https://retrack.dev/runbooks/vendor</a></p>"
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_trackers_incident_template_to_email(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = EmailTemplate::TrackersIncident {
+            tag: "vendor".to_string(),
+            trackers: vec![
+                ("tracker-one".to_string(), "some error".to_string()),
+                ("tracker-two".to_string(), "other error".to_string()),
+            ],
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        Email {
+            subject: "[Retrack] Incident: 2 trackers tagged \"vendor\" failed",
+            text: "2 trackers tagged \"vendor\" failed to check for content changes: \"tracker-one\" (some error), \"tracker-two\" (other error). Visit http://localhost:1234/ws/web_scraping__content to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n    <title>2 trackers tagged \"vendor\" failed to check for changes</title>\n    <meta charset=\"utf-8\">\n    <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n    <style>\n        body {\n            font-family: Arial, sans-serif;\n            background-color: #f1f1f1;\n            margin: 0;\n            padding: 0;\n        }\n    \n        .container {\n            max-width: 600px;\n            margin: 0 auto;\n            background-color: #fff;\n            padding: 20px;\n            border-radius: 5px;\n            box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n        }\n    \n        h1 {\n            font-size: 24px;\n            margin-top: 0;\n        }\n    \n        p {\n            font-size: 16px;\n            line-height: 1.5;\n            margin-bottom: 20px;\n        }\n    \n        .navigate-link {\n            display: block;\n            width: 250px;\n            margin: auto;\n            padding: 10px 20px;\n            text-align: center;\n            text-decoration: none;\n            color: #5e1d3f;\n            background-color: #fed047;\n            border-radius: 5px;\n            font-weight: bold;\n        }\n    </style>\n</head>\n<body>\n<div class=\"container\">\n    <h1>2 trackers tagged \"vendor\" failed to check for changes</h1>\n    <p>The following trackers failed at around the same time, which usually indicates an issue with the shared target:</p>\n    <ul>\n        <li>\"tracker-one\": <b>some error</b></li>\n        <li>\"tracker-two\": <b>other error</b></li>\n    </ul>\n    <p>To check the trackers configuration and re-try, visit the <b>Content trackers</b> page:</p>\n    <a class=\"navigate-link\" href=\"http://localhost:1234/ws/web_scraping__content\">Web Scraping → Content trackers</a>\n    <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n    <p>http://localhost:1234/ws/web_scraping__content</p>\n    <a href=\"http://localhost:1234/\"><img src=\"cid:retrack-logo\" alt=\"Retrack logo\" width=\"64\" height=\"16\"/></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailAttachment {
+                        disposition: Inline(
+                            "retrack-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            20,
+                            157,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_trackers_digest_template_to_email(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let template = EmailTemplate::TrackersDigest {
+            tag: Some("vendor".to_string()),
+            changes: vec![
+                ("tracker-one".to_string(), "content-one".to_string()),
+                ("tracker-two".to_string(), "content-two".to_string()),
+            ],
+        }
+        .compile_to_email(&api)
+        .await?;
+        assert_debug_snapshot!(template.subject, @r###""[Retrack] Digest: 2 changes detected by trackers tagged \"vendor\"""###);
+        assert_debug_snapshot!(template.text, @r###""2 changes detected by trackers tagged \"vendor\": \"tracker-one\" (content-one), \"tracker-two\" (content-two). Visit http://localhost:1234/ws/web_scraping__content to learn more.""###);
+        let html = template.html.unwrap();
+        assert!(html.contains("<h1>2 changes detected by trackers tagged \"vendor\"</h1>"));
+        assert!(html.contains("<li>\"tracker-one\": content-one</li>"));
+        assert!(html.contains("<li>\"tracker-two\": content-two</li>"));
+
+        let template = EmailTemplate::TrackersDigest {
+            tag: None,
+            changes: vec![("tracker".to_string(), "content".to_string())],
+        }
+        .compile_to_email(&api)
+        .await?;
+        assert_debug_snapshot!(template.subject, @r###""[Retrack] Digest: 1 changes detected by \"tracker\" tracker""###);
+        assert!(template
+            .html
+            .unwrap()
+            .contains("<h1>1 changes detected by \"tracker\" tracker</h1>"));
+
+        Ok(())
+    }
 }
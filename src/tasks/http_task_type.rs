@@ -1,5 +1,7 @@
 use http::{HeaderMap, Method};
+use retrack_types::scheduler::SchedulerJobRetryStrategy;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use url::Url;
 
 /// Describes the HTTP task type.
@@ -15,12 +17,97 @@ pub struct HttpTaskType {
     pub headers: Option<HeaderMap>,
     /// Optional body to include in the request.
     pub body: Option<Vec<u8>>,
+    /// Optional expectations for the response, the task is considered failed if they aren't met.
+    pub expected_response: Option<HttpTaskExpectedResponse>,
+    /// Optional time to wait for the response, the default HTTP task timeout is used if not set.
+    pub timeout: Option<Duration>,
+    /// Optional name of the server secret to sign the request with, the request is signed right
+    /// before it's sent and includes the signature in the `X-Retrack-Signature` header.
+    pub signing_secret: Option<String>,
+    /// Optional strategy to retry the request if it fails, the request is retried until it
+    /// succeeds if not set.
+    pub retry_strategy: Option<HttpTaskRetryStrategy>,
+}
+
+/// Describes the response expected from the HTTP task receiver.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct HttpTaskExpectedResponse {
+    /// Inclusive range of the expected response status codes. If not specified, any successful
+    /// status code is expected.
+    pub status: Option<(u16, u16)>,
+    /// Regular expression that the response body is expected to match.
+    pub body_pattern: Option<String>,
+}
+
+/// Describes the strategy to retry the HTTP task if it fails (see [`SchedulerJobRetryStrategy`]).
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HttpTaskRetryStrategy {
+    Constant(Duration, u32),
+    Exponential(Duration, u32, Duration, u32),
+    Linear(Duration, Duration, Duration, u32),
+}
+
+impl From<SchedulerJobRetryStrategy> for HttpTaskRetryStrategy {
+    fn from(retry_strategy: SchedulerJobRetryStrategy) -> Self {
+        match retry_strategy {
+            SchedulerJobRetryStrategy::Constant {
+                interval,
+                max_attempts,
+            } => Self::Constant(interval, max_attempts),
+            SchedulerJobRetryStrategy::Exponential {
+                initial_interval,
+                multiplier,
+                max_interval,
+                max_attempts,
+            } => Self::Exponential(initial_interval, multiplier, max_interval, max_attempts),
+            SchedulerJobRetryStrategy::Linear {
+                initial_interval,
+                increment,
+                max_interval,
+                max_attempts,
+            } => Self::Linear(initial_interval, increment, max_interval, max_attempts),
+        }
+    }
+}
+
+impl From<HttpTaskRetryStrategy> for SchedulerJobRetryStrategy {
+    fn from(retry_strategy: HttpTaskRetryStrategy) -> Self {
+        match retry_strategy {
+            HttpTaskRetryStrategy::Constant(interval, max_attempts) => Self::Constant {
+                interval,
+                max_attempts,
+            },
+            HttpTaskRetryStrategy::Exponential(
+                initial_interval,
+                multiplier,
+                max_interval,
+                max_attempts,
+            ) => Self::Exponential {
+                initial_interval,
+                multiplier,
+                max_interval,
+                max_attempts,
+            },
+            HttpTaskRetryStrategy::Linear(
+                initial_interval,
+                increment,
+                max_interval,
+                max_attempts,
+            ) => Self::Linear {
+                initial_interval,
+                increment,
+                max_interval,
+                max_attempts,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tasks::HttpTaskType;
+    use crate::tasks::{HttpTaskExpectedResponse, HttpTaskRetryStrategy, HttpTaskType};
     use http::{header, HeaderMap, HeaderValue, Method};
+    use std::time::Duration;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
@@ -33,12 +120,20 @@ mod tests {
                     HeaderValue::from_static("text/plain")
                 )])),
                 body: Some(vec![1, 2, 3]),
+                expected_response: Some(HttpTaskExpectedResponse {
+                    status: Some((200, 204)),
+                    body_pattern: Some("ok".to_string()),
+                }),
+                timeout: Some(Duration::from_secs(5)),
+                signing_secret: Some("secret".to_string()),
+                retry_strategy: Some(HttpTaskRetryStrategy::Constant(Duration::from_secs(60), 3)),
             })?,
             vec![
                 29, 104, 116, 116, 112, 115, 58, 47, 47, 114, 101, 116, 114, 97, 99, 107, 46, 100,
                 101, 118, 47, 115, 111, 109, 101, 45, 112, 97, 116, 104, 3, 80, 85, 84, 1, 1, 12,
                 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 1, 10, 116, 101, 120,
-                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3
+                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3, 1, 1, 200, 1, 204, 1, 1, 2, 111,
+                107, 1, 5, 0, 1, 6, 115, 101, 99, 114, 101, 116, 1, 0, 60, 0, 3
             ]
         );
 
@@ -52,7 +147,8 @@ mod tests {
                 29, 104, 116, 116, 112, 115, 58, 47, 47, 114, 101, 116, 114, 97, 99, 107, 46, 100,
                 101, 118, 47, 115, 111, 109, 101, 45, 112, 97, 116, 104, 3, 80, 85, 84, 1, 1, 12,
                 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 1, 10, 116, 101, 120,
-                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3
+                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3, 1, 1, 200, 1, 204, 1, 1, 2, 111,
+                107, 1, 5, 0, 1, 6, 115, 101, 99, 114, 101, 116, 1, 0, 60, 0, 3
             ])?,
             HttpTaskType {
                 method: Method::PUT,
@@ -62,6 +158,13 @@ mod tests {
                     HeaderValue::from_static("text/plain")
                 )])),
                 body: Some(vec![1, 2, 3]),
+                expected_response: Some(HttpTaskExpectedResponse {
+                    status: Some((200, 204)),
+                    body_pattern: Some("ok".to_string()),
+                }),
+                timeout: Some(Duration::from_secs(5)),
+                signing_secret: Some("secret".to_string()),
+                retry_strategy: Some(HttpTaskRetryStrategy::Constant(Duration::from_secs(60), 3)),
             }
         );
 
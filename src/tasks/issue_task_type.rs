@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use url::Url;
+
+/// Describes the issue task type.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct IssueTaskType {
+    /// The issue tracker to create or update the issue in.
+    pub provider: IssueTaskProvider,
+    /// The base URL of the issue tracker REST API.
+    pub api_url: Url,
+    /// The repository (GitHub) or project (GitLab) to create or update the issue in.
+    pub repository: String,
+    /// The access token to authenticate with.
+    pub token: String,
+    /// The title of the issue, used to find the already open issue to update.
+    pub title: String,
+    /// The body of the new issue or the comment to add to the existing one.
+    pub body: String,
+    /// The labels to assign to the new issue.
+    pub labels: Vec<String>,
+    /// Optional time to wait for the issue tracker to respond, the default webhook task timeout is
+    /// used if not set.
+    pub timeout: Option<Duration>,
+}
+
+/// Describes the issue tracker the issue task is executed against.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IssueTaskProvider {
+    /// GitHub or GitHub Enterprise Server REST API.
+    GitHub,
+    /// GitLab REST API (v4).
+    GitLab,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::{IssueTaskProvider, IssueTaskType};
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_eq!(
+            postcard::to_stdvec(&IssueTaskType {
+                provider: IssueTaskProvider::GitLab,
+                api_url: "https://gitlab.com/api/v4/".parse()?,
+                repository: "a/b".to_string(),
+                token: "tk".to_string(),
+                title: "Title".to_string(),
+                body: "Body".to_string(),
+                labels: vec!["bug".to_string()],
+                timeout: Some(Duration::from_secs(5)),
+            })?,
+            vec![
+                1, 26, 104, 116, 116, 112, 115, 58, 47, 47, 103, 105, 116, 108, 97, 98, 46, 99,
+                111, 109, 47, 97, 112, 105, 47, 118, 52, 47, 3, 97, 47, 98, 2, 116, 107, 5, 84,
+                105, 116, 108, 101, 4, 66, 111, 100, 121, 1, 3, 98, 117, 103, 1, 5, 0
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            postcard::from_bytes::<IssueTaskType>(&[
+                1, 26, 104, 116, 116, 112, 115, 58, 47, 47, 103, 105, 116, 108, 97, 98, 46, 99,
+                111, 109, 47, 97, 112, 105, 47, 118, 52, 47, 3, 97, 47, 98, 2, 116, 107, 5, 84,
+                105, 116, 108, 101, 4, 66, 111, 100, 121, 1, 3, 98, 117, 103, 1, 5, 0
+            ])?,
+            IssueTaskType {
+                provider: IssueTaskProvider::GitLab,
+                api_url: "https://gitlab.com/api/v4/".parse()?,
+                repository: "a/b".to_string(),
+                token: "tk".to_string(),
+                title: "Title".to_string(),
+                body: "Body".to_string(),
+                labels: vec!["bug".to_string()],
+                timeout: Some(Duration::from_secs(5)),
+            }
+        );
+
+        Ok(())
+    }
+}
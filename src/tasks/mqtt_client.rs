@@ -0,0 +1,446 @@
+use anyhow::{bail, Context};
+use rustls::{crypto::ring, pki_types::ServerName, ClientConfig, RootCertStore};
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+};
+use tokio_rustls::TlsConnector;
+use tracing::warn;
+use url::Url;
+
+/// Ports the MQTT broker listens on if the URL doesn't specify one, for plain-text (`mqtt://`) and
+/// TLS (`mqtts://`) connections.
+const DEFAULT_MQTT_PORT: u16 = 1883;
+const DEFAULT_MQTTS_PORT: u16 = 8883;
+
+/// Keep alive interval (in seconds) sent to the broker. The connection is only used to publish a
+/// single message, so the interval just needs to exceed the time it takes.
+const KEEP_ALIVE_INTERVAL: u16 = 60;
+
+/// Identifier of the packet used to publish messages with QoS 1 and 2. The connection is only used
+/// to publish a single message, so the identifier is never reused.
+const PACKET_ID: u16 = 1;
+
+/// Maximum size of a single MQTT broker packet the client is willing to read, the client only
+/// expects small acknowledgement packets.
+const MAX_BROKER_PACKET_SIZE: usize = 64 * 1024;
+
+/// Maximum value of the packet remaining length that can be encoded (4 bytes).
+const MAX_REMAINING_LENGTH: usize = 268_435_455;
+
+/// Control packet types (upper 4 bits of the first byte of the fixed header).
+const CONNECT: u8 = 0x10;
+const CONNACK: u8 = 0x20;
+const PUBLISH: u8 = 0x30;
+const PUBACK: u8 = 0x40;
+const PUBREC: u8 = 0x50;
+const PUBREL: u8 = 0x60;
+const PUBCOMP: u8 = 0x70;
+const DISCONNECT: u8 = 0xE0;
+
+/// Lightweight MQTT 3.1.1 (https://docs.oasis-open.org/mqtt/mqtt/v3.1.1/mqtt-v3.1.1.html) client
+/// that supports only publishing messages to topics.
+pub struct MqttClient<S> {
+    stream: S,
+}
+
+impl MqttClient<TcpStream> {
+    /// Connects to the MQTT broker specified by the URL (`mqtt://host:port` or
+    /// `mqtts://host:port`), authenticates with the URL credentials, if any, publishes the payload
+    /// to the topic with the specified QoS level and retain flag, and disconnects.
+    pub async fn connect_and_publish(
+        url: &Url,
+        topic: &str,
+        payload: &[u8],
+        qos: u8,
+        retain: bool,
+    ) -> anyhow::Result<()> {
+        let Some(host) = url.host_str() else {
+            bail!("MQTT broker URL doesn't have a host ({url}).");
+        };
+        let is_tls = url.scheme() == "mqtts";
+        let port = url.port().unwrap_or(if is_tls {
+            DEFAULT_MQTTS_PORT
+        } else {
+            DEFAULT_MQTT_PORT
+        });
+
+        let tcp_stream = TcpStream::connect((host, port))
+            .await
+            .with_context(|| format!("Cannot connect to MQTT broker ({host}:{port})."))?;
+        if !is_tls {
+            return Self::new(tcp_stream)
+                .authenticate_and_publish(url, topic, payload, qos, retain)
+                .await;
+        }
+
+        let mut root_store = RootCertStore::empty();
+        let native_certs = rustls_native_certs::load_native_certs();
+        for err in native_certs.errors {
+            warn!("Failed to load native root certificate: {err}");
+        }
+        root_store.add_parsable_certificates(native_certs.certs);
+
+        let tls_config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_root_certificates(root_store)
+            .with_no_client_auth();
+        let server_name = ServerName::try_from(host.to_string())
+            .with_context(|| format!("Invalid MQTT broker host name ({host})."))?;
+
+        let tls_stream = TlsConnector::from(Arc::new(tls_config))
+            .connect(server_name, tcp_stream)
+            .await
+            .with_context(|| format!("Cannot establish TLS session with MQTT broker ({host})."))?;
+        MqttClient::new(tls_stream)
+            .authenticate_and_publish(url, topic, payload, qos, retain)
+            .await
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> MqttClient<S> {
+    /// Creates MQTT client for the already established connection.
+    pub fn new(stream: S) -> Self {
+        Self { stream }
+    }
+
+    /// Starts a new clean session with the credentials, if any, and waits for the broker to accept
+    /// it. The broker assigns the client identifier.
+    pub async fn connect(
+        &mut self,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let mut body = vec![];
+        encode_string(&mut body, "MQTT")?;
+        // Protocol level of MQTT 3.1.1.
+        body.push(4);
+
+        // Clean session flag, followed by the password and username flags, if any.
+        let mut flags = 0x02;
+        if username.is_some() {
+            flags |= 0x80;
+            if password.is_some() {
+                flags |= 0x40;
+            }
+        }
+        body.push(flags);
+        body.extend_from_slice(&KEEP_ALIVE_INTERVAL.to_be_bytes());
+
+        // Empty client identifier.
+        encode_string(&mut body, "")?;
+        if let Some(username) = username {
+            encode_string(&mut body, username)?;
+            if let Some(password) = password {
+                encode_string(&mut body, password)?;
+            }
+        }
+        self.write_packet(CONNECT, &body).await?;
+
+        let (packet_type, body) = self.read_packet().await?;
+        if packet_type != CONNACK || body.len() != 2 {
+            bail!("Unexpected MQTT broker response to the connection request.");
+        }
+
+        let reason = match body[1] {
+            0 => return Ok(()),
+            1 => "unacceptable protocol version",
+            2 => "client identifier rejected",
+            3 => "server unavailable",
+            4 => "bad username or password",
+            5 => "not authorized",
+            _ => "unknown reason",
+        };
+        bail!("MQTT broker refused the connection: {reason}.");
+    }
+
+    /// Publishes the payload to the topic with the specified QoS level and retain flag, and waits
+    /// for the broker to acknowledge it, if required by the QoS level.
+    pub async fn publish(
+        &mut self,
+        topic: &str,
+        payload: &[u8],
+        qos: u8,
+        retain: bool,
+    ) -> anyhow::Result<()> {
+        if topic.is_empty() || topic.contains(['+', '#', '\0']) {
+            bail!("MQTT topic cannot be empty or contain wildcards or NUL characters.");
+        }
+
+        if qos > 2 {
+            bail!("MQTT QoS level must be 0, 1, or 2, but received {qos}.");
+        }
+
+        let mut body = vec![];
+        encode_string(&mut body, topic)?;
+        if qos > 0 {
+            body.extend_from_slice(&PACKET_ID.to_be_bytes());
+        }
+        body.extend_from_slice(payload);
+        self.write_packet(PUBLISH | (qos << 1) | u8::from(retain), &body)
+            .await?;
+
+        match qos {
+            1 => self.read_ack(PUBACK, "PUBACK").await,
+            2 => {
+                self.read_ack(PUBREC, "PUBREC").await?;
+                // PUBREL packet has the reserved flags set to `0010`.
+                self.write_packet(PUBREL | 0x02, &PACKET_ID.to_be_bytes())
+                    .await?;
+                self.read_ack(PUBCOMP, "PUBCOMP").await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Gracefully closes the session.
+    pub async fn disconnect(&mut self) -> anyhow::Result<()> {
+        self.write_packet(DISCONNECT, &[]).await
+    }
+
+    /// Authenticates with the URL credentials, publishes the payload to the topic, and
+    /// disconnects.
+    async fn authenticate_and_publish(
+        mut self,
+        url: &Url,
+        topic: &str,
+        payload: &[u8],
+        qos: u8,
+        retain: bool,
+    ) -> anyhow::Result<()> {
+        let (username, password) = if url.username().is_empty() {
+            (None, None)
+        } else {
+            (
+                Some(urlencoding::decode(url.username())?),
+                url.password().map(urlencoding::decode).transpose()?,
+            )
+        };
+        self.connect(username.as_deref(), password.as_deref())
+            .await?;
+        self.publish(topic, payload, qos, retain)
+            .await
+            .with_context(|| format!("Failed to publish MQTT message to topic ({topic})."))?;
+        self.disconnect().await
+    }
+
+    /// Reads the acknowledgement packet of the specified type for the published message.
+    async fn read_ack(&mut self, expected_packet_type: u8, name: &str) -> anyhow::Result<()> {
+        let (packet_type, body) = self.read_packet().await?;
+        if packet_type & 0xF0 != expected_packet_type || body != PACKET_ID.to_be_bytes() {
+            bail!("Unexpected MQTT broker response, expected {name}.");
+        }
+
+        Ok(())
+    }
+
+    /// Writes the packet with the specified first byte of the fixed header and the body (variable
+    /// header and payload) to the broker.
+    async fn write_packet(&mut self, header: u8, body: &[u8]) -> anyhow::Result<()> {
+        if body.len() > MAX_REMAINING_LENGTH {
+            bail!(
+                "MQTT packet is too large ({} bytes), the maximum allowed size is {MAX_REMAINING_LENGTH} bytes.",
+                body.len()
+            );
+        }
+
+        let mut packet = Vec::with_capacity(body.len() + 5);
+        packet.push(header);
+
+        // Remaining length is encoded with 7 bits per byte, the highest bit indicates that more
+        // bytes follow.
+        let mut remaining_length = body.len();
+        loop {
+            let mut byte = (remaining_length % 128) as u8;
+            remaining_length /= 128;
+            if remaining_length > 0 {
+                byte |= 0x80;
+            }
+            packet.push(byte);
+            if remaining_length == 0 {
+                break;
+            }
+        }
+        packet.extend_from_slice(body);
+
+        self.stream.write_all(&packet).await?;
+        self.stream.flush().await?;
+        Ok(())
+    }
+
+    /// Reads a single packet from the broker and returns its type (the first byte of the fixed
+    /// header) and body.
+    async fn read_packet(&mut self) -> anyhow::Result<(u8, Vec<u8>)> {
+        let packet_type = self
+            .stream
+            .read_u8()
+            .await
+            .context("MQTT broker closed the connection unexpectedly.")?;
+
+        let mut remaining_length = 0;
+        for index in 0..4 {
+            let byte = self
+                .stream
+                .read_u8()
+                .await
+                .context("Failed to read MQTT broker response.")?;
+            remaining_length |= usize::from(byte & 0x7F) << (7 * index);
+            if byte & 0x80 == 0 {
+                break;
+            } else if index == 3 {
+                bail!("MQTT broker response has malformed remaining length.");
+            }
+        }
+
+        if remaining_length > MAX_BROKER_PACKET_SIZE {
+            bail!(
+                "MQTT broker response is too large ({remaining_length} bytes), the maximum allowed size is {MAX_BROKER_PACKET_SIZE} bytes."
+            );
+        }
+
+        let mut body = vec![0; remaining_length];
+        self.stream
+            .read_exact(&mut body)
+            .await
+            .context("Failed to read MQTT broker response.")?;
+
+        Ok((packet_type, body))
+    }
+}
+
+/// Encodes the value as MQTT UTF-8 string prefixed with its length.
+fn encode_string(buffer: &mut Vec<u8>, value: &str) -> anyhow::Result<()> {
+    let Ok(length) = u16::try_from(value.len()) else {
+        bail!("MQTT string is too long ({} bytes).", value.len());
+    };
+
+    buffer.extend_from_slice(&length.to_be_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MqttClient;
+    use insta::assert_debug_snapshot;
+    use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+    /// Spawns a mock MQTT broker that replies to the expected packets with the specified
+    /// responses, in order.
+    fn mock_broker(
+        mut stream: DuplexStream,
+        conversation: Vec<(&'static [u8], &'static [u8])>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            for (expected_packet, response) in conversation {
+                let mut packet = vec![0; expected_packet.len()];
+                stream.read_exact(&mut packet).await.unwrap();
+                assert_eq!(packet, expected_packet);
+                stream.write_all(response).await.unwrap();
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn can_publish_messages() -> anyhow::Result<()> {
+        let (client_stream, broker_stream) = duplex(4096);
+        let broker = mock_broker(
+            broker_stream,
+            vec![
+                (
+                    b"\x10\x1b\x00\x04MQTT\x04\xc2\x00\x3c\x00\x00\x00\x07retrack\x00\x04pass",
+                    b"\x20\x02\x00\x00",
+                ),
+                // QoS 0, not retained.
+                (b"\x30\x14\x00\x10retrack/releases{}", b""),
+                // QoS 1, retained.
+                (
+                    b"\x33\x16\x00\x10retrack/releases\x00\x01{}",
+                    b"\x40\x02\x00\x01",
+                ),
+                // QoS 2, not retained.
+                (
+                    b"\x34\x16\x00\x10retrack/releases\x00\x01{}",
+                    b"\x50\x02\x00\x01",
+                ),
+                (b"\x62\x02\x00\x01", b"\x70\x02\x00\x01"),
+                (b"\xe0\x00", b""),
+            ],
+        );
+
+        let mut client = MqttClient::new(client_stream);
+        client.connect(Some("retrack"), Some("pass")).await?;
+        client.publish("retrack/releases", b"{}", 0, false).await?;
+        client.publish("retrack/releases", b"{}", 1, true).await?;
+        client.publish("retrack/releases", b"{}", 2, false).await?;
+        client.disconnect().await?;
+
+        broker.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_connection_is_refused() -> anyhow::Result<()> {
+        let (client_stream, broker_stream) = duplex(4096);
+        let broker = mock_broker(
+            broker_stream,
+            vec![(
+                b"\x10\x0c\x00\x04MQTT\x04\x02\x00\x3c\x00\x00",
+                b"\x20\x02\x00\x05",
+            )],
+        );
+
+        let mut client = MqttClient::new(client_stream);
+        assert_debug_snapshot!(
+            client.connect(None, None).await.unwrap_err(),
+            @r###""MQTT broker refused the connection: not authorized.""###
+        );
+
+        broker.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_message_is_not_acknowledged() -> anyhow::Result<()> {
+        let (client_stream, broker_stream) = duplex(4096);
+        let broker = mock_broker(
+            broker_stream,
+            vec![(
+                b"\x32\x16\x00\x10retrack/releases\x00\x01{}",
+                b"\x50\x02\x00\x01",
+            )],
+        );
+
+        let mut client = MqttClient::new(client_stream);
+        assert_debug_snapshot!(
+            client.publish("retrack/releases", b"{}", 1, false).await.unwrap_err(),
+            @r###""Unexpected MQTT broker response, expected PUBACK.""###
+        );
+
+        // Invalid topic and QoS level are rejected before they are sent.
+        assert_debug_snapshot!(
+            client.publish("retrack/+", b"{}", 0, false).await.unwrap_err(),
+            @r###""MQTT topic cannot be empty or contain wildcards or NUL characters.""###
+        );
+        assert_debug_snapshot!(
+            client.publish("retrack/releases", b"{}", 3, false).await.unwrap_err(),
+            @r###""MQTT QoS level must be 0, 1, or 2, but received 3.""###
+        );
+
+        broker.await?;
+
+        // Connection closed before the acknowledgement.
+        let (client_stream, broker_stream) = duplex(4096);
+        drop(broker_stream);
+        let mut client = MqttClient::new(client_stream);
+        assert!(client
+            .publish("retrack/releases", b"{}", 1, false)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+}
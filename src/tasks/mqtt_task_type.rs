@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use url::Url;
+
+/// Describes the MQTT task type.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MqttTaskType {
+    /// The URL of the MQTT broker to connect to (`mqtt://` or `mqtts://`), including credentials,
+    /// if any.
+    pub url: Url,
+    /// The topic to publish the message to.
+    pub topic: String,
+    /// The payload of the message.
+    pub payload: Vec<u8>,
+    /// The quality of service level to publish the message with.
+    pub qos: u8,
+    /// Indicates whether the broker should retain the message.
+    pub retain: bool,
+    /// Optional time to wait for the broker to acknowledge the message, the default webhook task
+    /// timeout is used if not set.
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::MqttTaskType;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_eq!(
+            postcard::to_stdvec(&MqttTaskType {
+                url: "mqtt://mqtt.retrack.dev".parse()?,
+                topic: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                qos: 1,
+                retain: true,
+                timeout: Some(Duration::from_secs(5)),
+            })?,
+            vec![
+                23, 109, 113, 116, 116, 58, 47, 47, 109, 113, 116, 116, 46, 114, 101, 116, 114, 97,
+                99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2, 3,
+                1, 1, 1, 5, 0
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            postcard::from_bytes::<MqttTaskType>(&[
+                23, 109, 113, 116, 116, 58, 47, 47, 109, 113, 116, 116, 46, 114, 101, 116, 114, 97,
+                99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2, 3,
+                1, 1, 1, 5, 0
+            ])?,
+            MqttTaskType {
+                url: "mqtt://mqtt.retrack.dev".parse()?,
+                topic: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                qos: 1,
+                retain: true,
+                timeout: Some(Duration::from_secs(5)),
+            }
+        );
+
+        Ok(())
+    }
+}
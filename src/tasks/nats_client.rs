@@ -0,0 +1,374 @@
+use anyhow::{bail, Context};
+use rustls::{crypto::ring, pki_types::ServerName, ClientConfig, RootCertStore};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use tokio_rustls::TlsConnector;
+use tracing::warn;
+use url::Url;
+
+/// Port the NATS server listens on if the URL doesn't specify one.
+const DEFAULT_NATS_PORT: u16 = 4222;
+
+/// Maximum size of a single NATS server protocol message line the client is willing to read,
+/// protects from the unbounded memory usage.
+const MAX_SERVER_LINE_SIZE: usize = 64 * 1024;
+
+/// Subset of the server information sent by the NATS server right after the connection is
+/// established (`INFO {...}`).
+#[derive(Deserialize, Debug, Default)]
+struct NatsServerInfo {
+    /// Indicates whether the server requires the connection to be upgraded to TLS.
+    #[serde(default)]
+    tls_required: bool,
+    /// Maximum payload size the server accepts, in bytes.
+    max_payload: Option<usize>,
+}
+
+/// Lightweight NATS (https://docs.nats.io/reference/reference-protocols/nats-protocol) client that
+/// supports only publishing messages to subjects.
+pub struct NatsClient<S> {
+    stream: BufReader<S>,
+    server_info: NatsServerInfo,
+}
+
+impl NatsClient<TcpStream> {
+    /// Connects to the NATS server specified by the URL (`nats://host:port` or `tls://host:port`),
+    /// authenticates with the URL credentials (`user:password@` or `token@`), if any, and publishes
+    /// the payload to the subject. The connection is upgraded to TLS if either the URL or the
+    /// server requires it.
+    pub async fn connect_and_publish(
+        url: &Url,
+        subject: &str,
+        payload: &[u8],
+    ) -> anyhow::Result<()> {
+        let Some(host) = url.host_str() else {
+            bail!("NATS server URL doesn't have a host ({url}).");
+        };
+        let port = url.port().unwrap_or(DEFAULT_NATS_PORT);
+
+        let tcp_stream = TcpStream::connect((host, port))
+            .await
+            .with_context(|| format!("Cannot connect to NATS server ({host}:{port})."))?;
+        let client = Self::new(tcp_stream).await?;
+        if url.scheme() != "tls" && !client.server_info.tls_required {
+            return client.authenticate_and_publish(url, subject, payload).await;
+        }
+
+        let mut root_store = RootCertStore::empty();
+        let native_certs = rustls_native_certs::load_native_certs();
+        for err in native_certs.errors {
+            warn!("Failed to load native root certificate: {err}");
+        }
+        root_store.add_parsable_certificates(native_certs.certs);
+
+        let tls_config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_root_certificates(root_store)
+            .with_no_client_auth();
+        let server_name = ServerName::try_from(host.to_string())
+            .with_context(|| format!("Invalid NATS server host name ({host})."))?;
+
+        // The server information is always sent in plain text, before the TLS handshake.
+        let tls_stream = TlsConnector::from(Arc::new(tls_config))
+            .connect(server_name, client.stream.into_inner())
+            .await
+            .with_context(|| format!("Cannot establish TLS session with NATS server ({host})."))?;
+        NatsClient {
+            stream: BufReader::new(tls_stream),
+            server_info: client.server_info,
+        }
+        .authenticate_and_publish(url, subject, payload)
+        .await
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> NatsClient<S> {
+    /// Creates NATS client for the already established connection and reads the server
+    /// information.
+    pub async fn new(stream: S) -> anyhow::Result<Self> {
+        let mut client = Self {
+            stream: BufReader::new(stream),
+            server_info: NatsServerInfo::default(),
+        };
+
+        let info = client.read_line().await?;
+        let Some(info) = info.strip_prefix("INFO ") else {
+            bail!("Unexpected NATS server greeting: {info}");
+        };
+        client.server_info = serde_json::from_str(info)
+            .with_context(|| format!("Cannot parse NATS server information: {info}"))?;
+
+        Ok(client)
+    }
+
+    /// Sends the connection options, including the credentials, if any, and waits for the server
+    /// to accept them.
+    pub async fn connect(
+        &mut self,
+        user: Option<&str>,
+        password: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let mut options = json!({
+            "verbose": false,
+            "pedantic": false,
+            "name": "retrack",
+            "lang": "rust",
+            "version": env!("CARGO_PKG_VERSION"),
+        });
+        match (user, password) {
+            (Some(user), Some(password)) => {
+                options["user"] = json!(user);
+                options["pass"] = json!(password);
+            }
+            (Some(token), None) => {
+                options["auth_token"] = json!(token);
+            }
+            _ => {}
+        }
+
+        self.write(format!("CONNECT {options}\r\n").as_bytes())
+            .await?;
+        self.flush()
+            .await
+            .context("NATS server rejected the connection.")
+    }
+
+    /// Publishes the payload to the subject and waits for the server to process it.
+    pub async fn publish(&mut self, subject: &str, payload: &[u8]) -> anyhow::Result<()> {
+        if subject.is_empty() || subject.contains(|char: char| char.is_ascii_whitespace()) {
+            bail!("NATS subject cannot be empty or contain whitespace characters.");
+        }
+
+        if let Some(max_payload) = self.server_info.max_payload {
+            if payload.len() > max_payload {
+                bail!(
+                    "NATS message payload is too large ({} bytes), the maximum allowed size is {max_payload} bytes.",
+                    payload.len()
+                );
+            }
+        }
+
+        self.write(format!("PUB {subject} {}\r\n", payload.len()).as_bytes())
+            .await?;
+        self.write(payload).await?;
+        self.write(b"\r\n").await?;
+        self.flush()
+            .await
+            .with_context(|| format!("Failed to publish NATS message to subject ({subject})."))
+    }
+
+    /// Authenticates with the URL credentials and publishes the payload to the subject.
+    async fn authenticate_and_publish(
+        mut self,
+        url: &Url,
+        subject: &str,
+        payload: &[u8],
+    ) -> anyhow::Result<()> {
+        let user = if url.username().is_empty() {
+            None
+        } else {
+            Some(urlencoding::decode(url.username())?)
+        };
+        let password = url.password().map(urlencoding::decode).transpose()?;
+        self.connect(user.as_deref(), password.as_deref()).await?;
+        self.publish(subject, payload).await
+    }
+
+    /// Sends `PING` and reads the server messages until `PONG` is received. Since the server
+    /// processes messages in order, any error caused by the previously sent messages is reported
+    /// before `PONG`.
+    async fn flush(&mut self) -> anyhow::Result<()> {
+        self.write(b"PING\r\n").await?;
+        loop {
+            let line = self.read_line().await?;
+            if line == "PONG" {
+                return Ok(());
+            }
+
+            if line == "PING" {
+                self.write(b"PONG\r\n").await?;
+            } else if let Some(error) = line.strip_prefix("-ERR") {
+                bail!(
+                    "NATS server responded with an error: {}",
+                    error.trim().trim_matches('\'')
+                );
+            } else if line != "+OK" && !line.starts_with("INFO ") {
+                bail!("Unexpected NATS server response: {line}");
+            }
+        }
+    }
+
+    /// Writes the raw protocol data to the server.
+    async fn write(&mut self, data: &[u8]) -> anyhow::Result<()> {
+        let stream = self.stream.get_mut();
+        stream.write_all(data).await?;
+        stream.flush().await?;
+        Ok(())
+    }
+
+    /// Reads a single CRLF-terminated protocol message line without the line terminator.
+    async fn read_line(&mut self) -> anyhow::Result<String> {
+        let mut line = vec![];
+        let read_bytes = (&mut self.stream)
+            .take(MAX_SERVER_LINE_SIZE as u64)
+            .read_until(b'\n', &mut line)
+            .await
+            .context("Failed to read NATS server response.")?;
+        if read_bytes == 0 {
+            bail!("NATS server closed the connection unexpectedly.");
+        }
+
+        if !line.ends_with(b"\n") {
+            bail!("NATS server response line is too long or truncated.");
+        }
+
+        let line = line.trim_ascii_end();
+        Ok(String::from_utf8_lossy(line).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NatsClient;
+    use insta::assert_debug_snapshot;
+    use tokio::io::{
+        duplex, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, DuplexStream,
+    };
+
+    const SERVER_INFO: &[u8] =
+        b"INFO {\"server_id\":\"retrack\",\"version\":\"2.10.0\",\"max_payload\":16}\r\n";
+
+    /// Spawns a mock NATS server that sends the server information and then replies to the
+    /// expected protocol messages with the specified responses, in order. Message payloads are
+    /// read along with the `PUB` messages.
+    fn mock_server(
+        stream: DuplexStream,
+        conversation: Vec<(String, &'static [u8])>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut stream = BufReader::new(stream);
+            stream.get_mut().write_all(SERVER_INFO).await.unwrap();
+            for (expected_message, response) in conversation {
+                let mut message = String::new();
+                stream.read_line(&mut message).await.unwrap();
+                if let Some(size) = message
+                    .strip_prefix("PUB ")
+                    .and_then(|args| args.trim_end().rsplit_once(' '))
+                    .and_then(|(_, size)| size.parse::<usize>().ok())
+                {
+                    let mut payload = vec![0; size + 2];
+                    stream.read_exact(&mut payload).await.unwrap();
+                    message.push_str(&String::from_utf8(payload).unwrap());
+                }
+                assert_eq!(message, expected_message);
+                stream.get_mut().write_all(response).await.unwrap();
+            }
+        })
+    }
+
+    fn connect_message(credentials: &str) -> String {
+        format!(
+            "CONNECT {{\"verbose\":false,\"pedantic\":false,\"name\":\"retrack\",\"lang\":\"rust\",\"version\":\"{}\"{credentials}}}\r\n",
+            env!("CARGO_PKG_VERSION")
+        )
+    }
+
+    #[tokio::test]
+    async fn can_publish_messages() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = mock_server(
+            server_stream,
+            vec![
+                (connect_message(r#","user":"retrack","pass":"pa ss""#), b""),
+                ("PING\r\n".to_string(), b"+OK\r\nPONG\r\n"),
+                (
+                    "PUB retrack.releases 15\r\n{\"version\":\"1\"}\r\n".to_string(),
+                    b"",
+                ),
+                ("PING\r\n".to_string(), b"PING\r\n"),
+                ("PONG\r\n".to_string(), b"PONG\r\n"),
+            ],
+        );
+
+        let mut client = NatsClient::new(client_stream).await?;
+        client.connect(Some("retrack"), Some("pa ss")).await?;
+        client
+            .publish("retrack.releases", br#"{"version":"1"}"#)
+            .await?;
+
+        server.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_server_rejects_messages() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = mock_server(
+            server_stream,
+            vec![
+                (connect_message(r#","auth_token":"s3cr3t""#), b""),
+                ("PING\r\n".to_string(), b"PONG\r\n"),
+                ("PUB retrack 2\r\n{}\r\n".to_string(), b""),
+                (
+                    "PING\r\n".to_string(),
+                    b"-ERR 'Permissions Violation for Publish to \"retrack\"'\r\n",
+                ),
+            ],
+        );
+
+        let mut client = NatsClient::new(client_stream).await?;
+        client.connect(Some("s3cr3t"), None).await?;
+        assert_debug_snapshot!(
+            client.publish("retrack", b"{}").await.unwrap_err(),
+            @r###"
+        Error {
+            context: "Failed to publish NATS message to subject (retrack).",
+            source: "NATS server responded with an error: Permissions Violation for Publish to \"retrack\"",
+        }
+        "###
+        );
+
+        // Invalid subject and too large payload are rejected before they are sent.
+        assert_debug_snapshot!(
+            client.publish("retrack releases", b"{}").await.unwrap_err(),
+            @r###""NATS subject cannot be empty or contain whitespace characters.""###
+        );
+        assert_debug_snapshot!(
+            client.publish("retrack", &[b'a'; 17]).await.unwrap_err(),
+            @r###""NATS message payload is too large (17 bytes), the maximum allowed size is 16 bytes.""###
+        );
+
+        server.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_greeting_is_unexpected() -> anyhow::Result<()> {
+        let (client_stream, mut server_stream) = duplex(4096);
+        server_stream
+            .write_all(b"-ERR 'Unknown Protocol'\r\n")
+            .await?;
+
+        assert_debug_snapshot!(
+            NatsClient::new(client_stream).await.err().unwrap(),
+            @r###""Unexpected NATS server greeting: -ERR 'Unknown Protocol'""###
+        );
+
+        // Connection closed before the server information.
+        let (client_stream, server_stream) = duplex(4096);
+        drop(server_stream);
+        assert_debug_snapshot!(
+            NatsClient::new(client_stream).await.err().unwrap(),
+            @r###""NATS server closed the connection unexpectedly.""###
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use url::Url;
+
+/// Describes the NATS task type.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NatsTaskType {
+    /// The URL of the NATS server to connect to (`nats://` or `tls://`), including credentials, if
+    /// any.
+    pub url: Url,
+    /// The subject to publish the message to.
+    pub subject: String,
+    /// The payload of the message.
+    pub payload: Vec<u8>,
+    /// Optional time to wait for the server to acknowledge the message, the default webhook task
+    /// timeout is used if not set.
+    pub timeout: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::NatsTaskType;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_eq!(
+            postcard::to_stdvec(&NatsTaskType {
+                url: "nats://nats.retrack.dev".parse()?,
+                subject: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                timeout: Some(Duration::from_secs(5)),
+            })?,
+            vec![
+                23, 110, 97, 116, 115, 58, 47, 47, 110, 97, 116, 115, 46, 114, 101, 116, 114, 97,
+                99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2, 3,
+                1, 5, 0
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            postcard::from_bytes::<NatsTaskType>(&[
+                23, 110, 97, 116, 115, 58, 47, 47, 110, 97, 116, 115, 46, 114, 101, 116, 114, 97,
+                99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2, 3,
+                1, 5, 0
+            ])?,
+            NatsTaskType {
+                url: "nats://nats.retrack.dev".parse()?,
+                subject: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                timeout: Some(Duration::from_secs(5)),
+            }
+        );
+
+        Ok(())
+    }
+}
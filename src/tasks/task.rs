@@ -1,4 +1,4 @@
-use crate::tasks::TaskType;
+use crate::tasks::{TaskDelivery, TaskType};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -11,4 +11,6 @@ pub struct Task {
     pub task_type: TaskType,
     /// The time at which the task is scheduled to be executed, in UTC.
     pub scheduled_at: OffsetDateTime,
+    /// The delivery state of the task, if it has failed to be executed at least once.
+    pub delivery: Option<TaskDelivery>,
 }
@@ -0,0 +1,10 @@
+/// Describes the delivery state of the task that has failed to be executed at least once.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TaskDelivery {
+    /// How many times the task has failed to be executed.
+    pub attempts: u32,
+    /// The error of the last failed attempt to execute the task.
+    pub last_error: String,
+    /// Indicates whether the retry attempts are exhausted and the task won't be retried anymore.
+    pub failed: bool,
+}
@@ -1,4 +1,8 @@
-use crate::tasks::{EmailTaskType, HttpTaskType};
+use crate::tasks::{
+    EmailContent, EmailTaskType, EmailTemplate, HttpTaskType, IssueTaskType, MqttTaskType,
+    NatsTaskType,
+};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 /// Defines a task type.
@@ -8,13 +12,104 @@ pub enum TaskType {
     Email(EmailTaskType),
     /// Task for sending HTTP request.
     Http(HttpTaskType),
+    /// Task for publishing message to NATS subject.
+    Nats(NatsTaskType),
+    /// Task for publishing message to MQTT topic.
+    Mqtt(MqttTaskType),
+    /// Task for creating or updating GitHub or GitLab issue.
+    Issue(IssueTaskType),
+}
+
+impl TaskType {
+    /// Checks if any of the task texts (email recipients and content, HTTP request URL, headers, and
+    /// body, NATS server and MQTT broker URL, subject or topic, and payload, issue repository,
+    /// title, and body) match the pattern.
+    pub fn matches(&self, pattern: &Regex) -> bool {
+        match self {
+            TaskType::Email(task) => {
+                task.to.iter().any(|recipient| pattern.is_match(recipient))
+                    || match &task.content {
+                        EmailContent::Custom(email) => {
+                            pattern.is_match(&email.subject)
+                                || pattern.is_match(&email.text)
+                                || email
+                                    .html
+                                    .as_ref()
+                                    .is_some_and(|html| pattern.is_match(html))
+                        }
+                        EmailContent::Template(
+                            EmailTemplate::TrackerChanges {
+                                tracker_name,
+                                content,
+                            }
+                            | EmailTemplate::TrackerChangesWithRunbook {
+                                tracker_name,
+                                content,
+                                ..
+                            },
+                        ) => {
+                            pattern.is_match(tracker_name)
+                                || pattern.is_match(match content {
+                                    Ok(content) | Err(content) => content,
+                                })
+                        }
+                        EmailContent::Template(
+                            EmailTemplate::TrackersIncident { tag, trackers }
+                            | EmailTemplate::TrackersIncidentWithRunbook { tag, trackers, .. },
+                        ) => {
+                            pattern.is_match(tag)
+                                || trackers.iter().any(|(name, error)| {
+                                    pattern.is_match(name) || pattern.is_match(error)
+                                })
+                        }
+                        EmailContent::Template(EmailTemplate::TrackersDigest { tag, changes }) => {
+                            tag.as_ref().is_some_and(|tag| pattern.is_match(tag))
+                                || changes.iter().any(|(name, content)| {
+                                    pattern.is_match(name) || pattern.is_match(content)
+                                })
+                        }
+                    }
+            }
+            TaskType::Http(task) => {
+                pattern.is_match(task.url.as_str())
+                    || task.headers.as_ref().is_some_and(|headers| {
+                        headers.values().any(|value| {
+                            pattern.is_match(&String::from_utf8_lossy(value.as_bytes()))
+                        })
+                    })
+                    || task
+                        .body
+                        .as_ref()
+                        .is_some_and(|body| pattern.is_match(&String::from_utf8_lossy(body)))
+            }
+            TaskType::Nats(task) => {
+                pattern.is_match(task.url.as_str())
+                    || pattern.is_match(&task.subject)
+                    || pattern.is_match(&String::from_utf8_lossy(&task.payload))
+            }
+            TaskType::Mqtt(task) => {
+                pattern.is_match(task.url.as_str())
+                    || pattern.is_match(&task.topic)
+                    || pattern.is_match(&String::from_utf8_lossy(&task.payload))
+            }
+            TaskType::Issue(task) => {
+                pattern.is_match(&task.repository)
+                    || pattern.is_match(&task.title)
+                    || pattern.is_match(&task.body)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::TaskType;
-    use crate::tasks::{Email, EmailContent, EmailTaskType, HttpTaskType};
+    use crate::tasks::{
+        Email, EmailContent, EmailTaskType, EmailTemplate, HttpTaskType, IssueTaskProvider,
+        IssueTaskType, MqttTaskType, NatsTaskType,
+    };
     use http::{header, HeaderMap, HeaderValue, Method};
+    use regex::Regex;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
@@ -25,11 +120,13 @@ mod tests {
                     "subject".to_string(),
                     "some text message".to_string()
                 )),
+                timeout: None,
+                attachments: None,
             }))?,
             vec![
                 0, 1, 15, 116, 119, 111, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118, 0,
                 7, 115, 117, 98, 106, 101, 99, 116, 17, 115, 111, 109, 101, 32, 116, 101, 120, 116,
-                32, 109, 101, 115, 115, 97, 103, 101, 0, 0
+                32, 109, 101, 115, 115, 97, 103, 101, 0, 0, 0, 0
             ]
         );
 
@@ -42,12 +139,64 @@ mod tests {
                     HeaderValue::from_static("text/plain")
                 )])),
                 body: Some(vec![1, 2, 3]),
+                expected_response: None,
+                timeout: None,
+                signing_secret: None,
+                retry_strategy: None,
             }))?,
             vec![
                 1, 29, 104, 116, 116, 112, 115, 58, 47, 47, 114, 101, 116, 114, 97, 99, 107, 46,
                 100, 101, 118, 47, 115, 111, 109, 101, 45, 112, 97, 116, 104, 3, 80, 85, 84, 1, 1,
                 12, 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 1, 10, 116, 101, 120,
-                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3
+                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3, 0, 0, 0, 0
+            ]
+        );
+
+        assert_eq!(
+            postcard::to_stdvec(&TaskType::Nats(NatsTaskType {
+                url: "nats://nats.retrack.dev".parse()?,
+                subject: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                timeout: None,
+            }))?,
+            vec![
+                2, 23, 110, 97, 116, 115, 58, 47, 47, 110, 97, 116, 115, 46, 114, 101, 116, 114,
+                97, 99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2,
+                3, 0
+            ]
+        );
+
+        assert_eq!(
+            postcard::to_stdvec(&TaskType::Mqtt(MqttTaskType {
+                url: "mqtt://mqtt.retrack.dev".parse()?,
+                topic: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                qos: 1,
+                retain: false,
+                timeout: None,
+            }))?,
+            vec![
+                3, 23, 109, 113, 116, 116, 58, 47, 47, 109, 113, 116, 116, 46, 114, 101, 116, 114,
+                97, 99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2,
+                3, 1, 0, 0
+            ]
+        );
+
+        assert_eq!(
+            postcard::to_stdvec(&TaskType::Issue(IssueTaskType {
+                provider: IssueTaskProvider::GitHub,
+                api_url: "https://api.github.com/".parse()?,
+                repository: "a/b".to_string(),
+                token: "tk".to_string(),
+                title: "Title".to_string(),
+                body: "Body".to_string(),
+                labels: vec![],
+                timeout: None,
+            }))?,
+            vec![
+                4, 0, 23, 104, 116, 116, 112, 115, 58, 47, 47, 97, 112, 105, 46, 103, 105, 116,
+                104, 117, 98, 46, 99, 111, 109, 47, 3, 97, 47, 98, 2, 116, 107, 5, 84, 105, 116,
+                108, 101, 4, 66, 111, 100, 121, 0, 0
             ]
         );
 
@@ -60,7 +209,7 @@ mod tests {
             postcard::from_bytes::<TaskType>(&[
                 0, 1, 15, 116, 119, 111, 64, 114, 101, 116, 114, 97, 99, 107, 46, 100, 101, 118, 0,
                 7, 115, 117, 98, 106, 101, 99, 116, 17, 115, 111, 109, 101, 32, 116, 101, 120, 116,
-                32, 109, 101, 115, 115, 97, 103, 101, 0, 0
+                32, 109, 101, 115, 115, 97, 103, 101, 0, 0, 0, 0
             ])?,
             TaskType::Email(EmailTaskType {
                 to: vec!["two@retrack.dev".to_string()],
@@ -68,6 +217,8 @@ mod tests {
                     "subject".to_string(),
                     "some text message".to_string()
                 )),
+                timeout: None,
+                attachments: None,
             })
         );
 
@@ -76,7 +227,7 @@ mod tests {
                 1, 29, 104, 116, 116, 112, 115, 58, 47, 47, 114, 101, 116, 114, 97, 99, 107, 46,
                 100, 101, 118, 47, 115, 111, 109, 101, 45, 112, 97, 116, 104, 3, 80, 85, 84, 1, 1,
                 12, 99, 111, 110, 116, 101, 110, 116, 45, 116, 121, 112, 101, 1, 10, 116, 101, 120,
-                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3
+                116, 47, 112, 108, 97, 105, 110, 1, 3, 1, 2, 3, 0, 0, 0, 0
             ])?,
             TaskType::Http(HttpTaskType {
                 method: Method::PUT,
@@ -86,9 +237,215 @@ mod tests {
                     HeaderValue::from_static("text/plain")
                 )])),
                 body: Some(vec![1, 2, 3]),
+                expected_response: None,
+                timeout: None,
+                signing_secret: None,
+                retry_strategy: None,
             })
         );
 
+        assert_eq!(
+            postcard::from_bytes::<TaskType>(&[
+                2, 23, 110, 97, 116, 115, 58, 47, 47, 110, 97, 116, 115, 46, 114, 101, 116, 114,
+                97, 99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2,
+                3, 0
+            ])?,
+            TaskType::Nats(NatsTaskType {
+                url: "nats://nats.retrack.dev".parse()?,
+                subject: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                timeout: None,
+            })
+        );
+
+        assert_eq!(
+            postcard::from_bytes::<TaskType>(&[
+                3, 23, 109, 113, 116, 116, 58, 47, 47, 109, 113, 116, 116, 46, 114, 101, 116, 114,
+                97, 99, 107, 46, 100, 101, 118, 8, 114, 101, 108, 101, 97, 115, 101, 115, 3, 1, 2,
+                3, 1, 0, 0
+            ])?,
+            TaskType::Mqtt(MqttTaskType {
+                url: "mqtt://mqtt.retrack.dev".parse()?,
+                topic: "releases".to_string(),
+                payload: vec![1, 2, 3],
+                qos: 1,
+                retain: false,
+                timeout: None,
+            })
+        );
+
+        assert_eq!(
+            postcard::from_bytes::<TaskType>(&[
+                4, 0, 23, 104, 116, 116, 112, 115, 58, 47, 47, 97, 112, 105, 46, 103, 105, 116,
+                104, 117, 98, 46, 99, 111, 109, 47, 3, 97, 47, 98, 2, 116, 107, 5, 84, 105, 116,
+                108, 101, 4, 66, 111, 100, 121, 0, 0
+            ])?,
+            TaskType::Issue(IssueTaskType {
+                provider: IssueTaskProvider::GitHub,
+                api_url: "https://api.github.com/".parse()?,
+                repository: "a/b".to_string(),
+                token: "tk".to_string(),
+                title: "Title".to_string(),
+                body: "Body".to_string(),
+                labels: vec![],
+                timeout: None,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_pattern() -> anyhow::Result<()> {
+        let pattern = Regex::new(r"john@retrack\.dev")?;
+
+        let email_task = |to: &str, content: EmailContent| {
+            TaskType::Email(EmailTaskType {
+                to: vec![to.to_string()],
+                content,
+                timeout: None,
+                attachments: None,
+            })
+        };
+        assert!(email_task(
+            "john@retrack.dev",
+            EmailContent::Custom(Email::text("subject", "text"))
+        )
+        .matches(&pattern));
+        assert!(email_task(
+            "dev@retrack.dev",
+            EmailContent::Custom(Email::html("subject", "text", "<b>john@retrack.dev</b>"))
+        )
+        .matches(&pattern));
+        assert!(email_task(
+            "dev@retrack.dev",
+            EmailContent::Template(EmailTemplate::TrackerChanges {
+                tracker_name: "tracker".to_string(),
+                content: Ok(r#"{"email":"john@retrack.dev"}"#.to_string()),
+            })
+        )
+        .matches(&pattern));
+        assert!(email_task(
+            "dev@retrack.dev",
+            EmailContent::Template(EmailTemplate::TrackersIncident {
+                tag: "app:retrack".to_string(),
+                trackers: vec![(
+                    "tracker".to_string(),
+                    "Cannot find john@retrack.dev".to_string()
+                )],
+            })
+        )
+        .matches(&pattern));
+        assert!(email_task(
+            "dev@retrack.dev",
+            EmailContent::Template(EmailTemplate::TrackersDigest {
+                tag: None,
+                changes: vec![(
+                    "tracker".to_string(),
+                    r#"{"email":"john@retrack.dev"}"#.to_string()
+                )],
+            })
+        )
+        .matches(&pattern));
+        assert!(!email_task(
+            "dev@retrack.dev",
+            EmailContent::Template(EmailTemplate::TrackerChanges {
+                tracker_name: "tracker".to_string(),
+                content: Err("Uh oh.".to_string()),
+            })
+        )
+        .matches(&pattern));
+
+        let http_task = HttpTaskType {
+            method: Method::POST,
+            url: "https://retrack.dev/hook".parse()?,
+            headers: None,
+            body: None,
+            expected_response: None,
+            timeout: None,
+            signing_secret: None,
+            retry_strategy: None,
+        };
+        assert!(!TaskType::Http(http_task.clone()).matches(&pattern));
+        assert!(TaskType::Http(HttpTaskType {
+            body: Some(br#"{"email":"john@retrack.dev"}"#.to_vec()),
+            ..http_task.clone()
+        })
+        .matches(&pattern));
+        assert!(TaskType::Http(HttpTaskType {
+            headers: Some(HeaderMap::from_iter([(
+                header::FROM,
+                HeaderValue::from_static("john@retrack.dev")
+            )])),
+            ..http_task.clone()
+        })
+        .matches(&pattern));
+        assert!(TaskType::Http(HttpTaskType {
+            url: "https://retrack.dev/hook?email=john@retrack.dev".parse()?,
+            ..http_task
+        })
+        .matches(&pattern));
+
+        let nats_task = NatsTaskType {
+            url: "nats://nats.retrack.dev".parse()?,
+            subject: "releases".to_string(),
+            payload: br#"{"version":"1.0.0"}"#.to_vec(),
+            timeout: None,
+        };
+        assert!(!TaskType::Nats(nats_task.clone()).matches(&pattern));
+        assert!(TaskType::Nats(NatsTaskType {
+            payload: br#"{"email":"john@retrack.dev"}"#.to_vec(),
+            ..nats_task.clone()
+        })
+        .matches(&pattern));
+        assert!(TaskType::Nats(NatsTaskType {
+            subject: "users.john@retrack.dev".to_string(),
+            ..nats_task
+        })
+        .matches(&pattern));
+
+        let mqtt_task = MqttTaskType {
+            url: "mqtt://mqtt.retrack.dev".parse()?,
+            topic: "releases".to_string(),
+            payload: br#"{"version":"1.0.0"}"#.to_vec(),
+            qos: 0,
+            retain: false,
+            timeout: None,
+        };
+        assert!(!TaskType::Mqtt(mqtt_task.clone()).matches(&pattern));
+        assert!(TaskType::Mqtt(MqttTaskType {
+            payload: br#"{"email":"john@retrack.dev"}"#.to_vec(),
+            ..mqtt_task.clone()
+        })
+        .matches(&pattern));
+        assert!(TaskType::Mqtt(MqttTaskType {
+            topic: "users/john@retrack.dev".to_string(),
+            ..mqtt_task
+        })
+        .matches(&pattern));
+
+        let issue_task = IssueTaskType {
+            provider: IssueTaskProvider::GitHub,
+            api_url: "https://api.github.com/".parse()?,
+            repository: "secutils-dev/retrack".to_string(),
+            token: "ghp_token".to_string(),
+            title: "Retrack: Releases".to_string(),
+            body: "New release".to_string(),
+            labels: vec![],
+            timeout: None,
+        };
+        assert!(!TaskType::Issue(issue_task.clone()).matches(&pattern));
+        assert!(TaskType::Issue(IssueTaskType {
+            body: "Reported by john@retrack.dev".to_string(),
+            ..issue_task.clone()
+        })
+        .matches(&pattern));
+        assert!(TaskType::Issue(IssueTaskType {
+            title: "Retrack: john@retrack.dev".to_string(),
+            ..issue_task
+        })
+        .matches(&pattern));
+
         Ok(())
     }
 }
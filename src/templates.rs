@@ -1,3 +1,6 @@
+mod api_ext;
+mod database_ext;
+
 use handlebars::Handlebars;
 use rust_embed::RustEmbed;
 
@@ -0,0 +1,315 @@
+use crate::{
+    api::Api,
+    database::Database,
+    error::Error as RetrackError,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+};
+use anyhow::{anyhow, bail};
+use retrack_types::templates::{Template, TemplateCreateParams, TemplateUpdateParams};
+use uuid::Uuid;
+
+/// Defines the maximum length of the template name.
+pub const MAX_TEMPLATE_NAME_LENGTH: usize = 100;
+
+/// Defines the maximum length of the email subject template.
+pub const MAX_TEMPLATE_SUBJECT_LENGTH: usize = 1000;
+
+/// Defines the maximum length of the email body (text or HTML) template.
+pub const MAX_TEMPLATE_BODY_LENGTH: usize = 65536;
+
+/// Describes the API to work with email templates.
+pub struct EmailTemplatesApi<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> EmailTemplatesApi<'a, DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Creates Email Templates API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Returns all email templates.
+    pub async fn get_templates(&self) -> anyhow::Result<Vec<Template>> {
+        self.api.db.get_templates().await
+    }
+
+    /// Returns email template by its ID.
+    pub async fn get_template(&self, id: Uuid) -> anyhow::Result<Option<Template>> {
+        self.api.db.get_template(id).await
+    }
+
+    /// Returns email template by its name.
+    pub async fn get_template_by_name(&self, name: &str) -> anyhow::Result<Option<Template>> {
+        self.api.db.get_template_by_name(name).await
+    }
+
+    /// Creates a new email template.
+    pub async fn create_template(&self, params: TemplateCreateParams) -> anyhow::Result<Template> {
+        let created_at = Database::utc_now()?;
+        let template = Self::normalize_template(Template {
+            id: Uuid::now_v7(),
+            name: params.name,
+            subject: params.subject,
+            text: params.text,
+            html: params.html,
+            created_at,
+            updated_at: created_at,
+        })?;
+
+        self.api.db.insert_template(&template).await?;
+
+        Ok(template)
+    }
+
+    /// Updates existing email template.
+    pub async fn update_template(
+        &self,
+        id: Uuid,
+        params: TemplateUpdateParams,
+    ) -> anyhow::Result<Template> {
+        if params == TemplateUpdateParams::default() {
+            bail!(RetrackError::client(format!(
+                "At least one template property should be provided ({id})."
+            )));
+        }
+
+        let Some(existing_template) = self.api.db.get_template(id).await? else {
+            bail!(RetrackError::client(format!(
+                "Template ('{id}') is not found."
+            )));
+        };
+
+        let template = Self::normalize_template(Template {
+            name: params.name.unwrap_or(existing_template.name),
+            subject: params.subject.unwrap_or(existing_template.subject),
+            text: params.text.unwrap_or(existing_template.text),
+            html: params.html.or(existing_template.html),
+            updated_at: Database::utc_now()?,
+            ..existing_template
+        })?;
+
+        self.api.db.update_template(&template).await?;
+
+        Ok(template)
+    }
+
+    /// Removes existing email template.
+    pub async fn remove_template(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api.db.remove_template(id).await
+    }
+
+    /// Validates email template and removes empty HTML body template.
+    fn normalize_template(template: Template) -> anyhow::Result<Template> {
+        if template.name.is_empty() {
+            bail!(RetrackError::client("Template name cannot be empty."));
+        }
+
+        if template.name.len() > MAX_TEMPLATE_NAME_LENGTH {
+            bail!(RetrackError::client(format!(
+                "Template name cannot be longer than {MAX_TEMPLATE_NAME_LENGTH} characters."
+            )));
+        }
+
+        if template.subject.is_empty() || template.subject.len() > MAX_TEMPLATE_SUBJECT_LENGTH {
+            bail!(RetrackError::client(format!(
+                "Template subject cannot be empty or longer than {MAX_TEMPLATE_SUBJECT_LENGTH} characters."
+            )));
+        }
+
+        if template.text.is_empty() || template.text.len() > MAX_TEMPLATE_BODY_LENGTH {
+            bail!(RetrackError::client(format!(
+                "Template text cannot be empty or longer than {MAX_TEMPLATE_BODY_LENGTH} characters."
+            )));
+        }
+
+        let html = template.html.filter(|html| !html.is_empty());
+        if let Some(ref html) = html {
+            if html.len() > MAX_TEMPLATE_BODY_LENGTH {
+                bail!(RetrackError::client(format!(
+                    "Template HTML cannot be longer than {MAX_TEMPLATE_BODY_LENGTH} characters."
+                )));
+            }
+        }
+
+        for (name, source) in [
+            ("subject", Some(&template.subject)),
+            ("text", Some(&template.text)),
+            ("HTML", html.as_ref()),
+        ] {
+            let Some(source) = source else {
+                continue;
+            };
+
+            if let Err(err) = handlebars::Template::compile(source) {
+                bail!(RetrackError::client_with_root_cause(anyhow!(err).context(
+                    format!("Template {name} is not a valid Handlebars template.")
+                )));
+            }
+        }
+
+        Ok(Template { html, ..template })
+    }
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Returns an API to work with email templates.
+    pub fn email_templates(&self) -> EmailTemplatesApi<'_, DR, ET> {
+        EmailTemplatesApi::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::Error as RetrackError, tests::mock_api};
+    use insta::assert_debug_snapshot;
+    use retrack_types::templates::{TemplateCreateParams, TemplateUpdateParams};
+    use sqlx::PgPool;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn properly_creates_updates_and_removes_template(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let templates = api.email_templates();
+
+        let template = templates
+            .create_template(TemplateCreateParams {
+                name: "changes".to_string(),
+                subject: "{{tracker.name}} changed".to_string(),
+                text: "{{diff}}".to_string(),
+                html: Some("".to_string()),
+            })
+            .await?;
+        assert_eq!(template.html, None);
+        assert_eq!(templates.get_templates().await?, vec![template.clone()]);
+        assert_eq!(
+            templates.get_template(template.id).await?,
+            Some(template.clone())
+        );
+        assert_eq!(
+            templates.get_template_by_name("changes").await?,
+            Some(template.clone())
+        );
+
+        let updated_template = templates
+            .update_template(
+                template.id,
+                TemplateUpdateParams {
+                    html: Some("<pre>{{diff}}</pre>".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(updated_template.name, "changes");
+        assert_eq!(updated_template.subject, "{{tracker.name}} changed");
+        assert_eq!(updated_template.text, "{{diff}}");
+        assert_eq!(
+            updated_template.html,
+            Some("<pre>{{diff}}</pre>".to_string())
+        );
+        assert_eq!(
+            templates.get_template(template.id).await?,
+            Some(updated_template.clone())
+        );
+
+        let updated_template = templates
+            .update_template(
+                template.id,
+                TemplateUpdateParams {
+                    html: Some("".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(updated_template.html, None);
+
+        templates.remove_template(template.id).await?;
+        assert!(templates.get_template(template.id).await?.is_none());
+        assert!(templates.get_templates().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_template(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let templates = &api.email_templates();
+
+        let create_and_fail = move |params: TemplateCreateParams| async move {
+            templates
+                .create_template(params)
+                .await
+                .unwrap_err()
+                .downcast::<RetrackError>()
+                .unwrap()
+                .root_cause
+                .to_string()
+        };
+
+        let params = TemplateCreateParams {
+            name: "changes".to_string(),
+            subject: "{{tracker.name}} changed".to_string(),
+            text: "{{diff}}".to_string(),
+            html: None,
+        };
+
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            name: "".to_string(),
+            ..params.clone()
+        }).await, @r###""Template name cannot be empty.""###);
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            name: "a".repeat(101),
+            ..params.clone()
+        }).await, @r###""Template name cannot be longer than 100 characters.""###);
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            subject: "".to_string(),
+            ..params.clone()
+        }).await, @r###""Template subject cannot be empty or longer than 1000 characters.""###);
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            text: "a".repeat(65537),
+            ..params.clone()
+        }).await, @r###""Template text cannot be empty or longer than 65536 characters.""###);
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            html: Some("a".repeat(65537)),
+            ..params.clone()
+        }).await, @r###""Template HTML cannot be longer than 65536 characters.""###);
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            subject: "{{tracker.name".to_string(),
+            ..params.clone()
+        }).await, @r###""Template subject is not a valid Handlebars template.""###);
+        assert_debug_snapshot!(create_and_fail(TemplateCreateParams {
+            html: Some("{{#each diffLines}}{{line}}".to_string()),
+            ..params.clone()
+        }).await, @r###""Template HTML is not a valid Handlebars template.""###);
+
+        templates.create_template(params.clone()).await?;
+        assert_debug_snapshot!(create_and_fail(params).await, @r###""Template with such name ('changes') already exists.""###);
+
+        let update_and_fail = |result: anyhow::Result<_>| -> String {
+            result
+                .unwrap_err()
+                .downcast::<RetrackError>()
+                .unwrap()
+                .root_cause
+                .to_string()
+        };
+        assert_debug_snapshot!(
+            update_and_fail(templates.update_template(uuid!("00000000-0000-0000-0000-000000000001"), Default::default()).await),
+            @r###""At least one template property should be provided (00000000-0000-0000-0000-000000000001).""###
+        );
+        assert_debug_snapshot!(
+            update_and_fail(templates.update_template(uuid!("00000000-0000-0000-0000-000000000001"), TemplateUpdateParams {
+                name: Some("other".to_string()),
+                ..Default::default()
+            }).await),
+            @r###""Template ('00000000-0000-0000-0000-000000000001') is not found.""###
+        );
+
+        Ok(())
+    }
+}
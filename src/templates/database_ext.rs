@@ -0,0 +1,234 @@
+use crate::{database::Database, error::Error as RetrackError};
+use anyhow::{anyhow, bail};
+use retrack_types::templates::Template;
+use sqlx::{query, query_as};
+use uuid::Uuid;
+
+/// Name of the database constraint that enforces template name uniqueness.
+const TEMPLATES_NAME_CONSTRAINT: &str = "templates_name_key";
+
+/// Extends primary database with the email templates-related methods.
+impl Database {
+    /// Retrieves all email templates from the database, ordered by name.
+    pub async fn get_templates(&self) -> anyhow::Result<Vec<Template>> {
+        Ok(query_as!(
+            Template,
+            r#"SELECT id, name, subject, text, html, created_at, updated_at FROM templates ORDER BY name"#
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Retrieves email template from the database using ID.
+    pub async fn get_template(&self, id: Uuid) -> anyhow::Result<Option<Template>> {
+        Ok(query_as!(
+            Template,
+            r#"SELECT id, name, subject, text, html, created_at, updated_at FROM templates WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    /// Retrieves email template from the database using its name.
+    pub async fn get_template_by_name(&self, name: &str) -> anyhow::Result<Option<Template>> {
+        Ok(query_as!(
+            Template,
+            r#"SELECT id, name, subject, text, html, created_at, updated_at FROM templates WHERE name = $1"#,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    /// Inserts a new email template to the database.
+    pub async fn insert_template(&self, template: &Template) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+INSERT INTO templates (id, name, subject, text, html, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            template.id,
+            template.name,
+            template.subject,
+            template.text,
+            template.html,
+            template.created_at,
+            template.updated_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        if let Err(err) = result {
+            bail!(match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    let message = if database_error.constraint() == Some(TEMPLATES_NAME_CONSTRAINT)
+                    {
+                        format!(
+                            "Template with such name ('{}') already exists.",
+                            template.name
+                        )
+                    } else {
+                        format!("Template with such id ('{}') already exists.", template.id)
+                    };
+                    RetrackError::client_with_root_cause(anyhow!(err).context(message))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't create template ('{}') due to unknown reason.",
+                    template.name
+                ))),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Updates email template in the database.
+    pub async fn update_template(&self, template: &Template) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+UPDATE templates
+SET name = $2, subject = $3, text = $4, html = $5, updated_at = $6
+WHERE id = $1
+            "#,
+            template.id,
+            template.name,
+            template.subject,
+            template.text,
+            template.html,
+            template.updated_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => {
+                bail!(RetrackError::client(format!(
+                    "Template ('{}') doesn't exist.",
+                    template.id
+                )));
+            }
+            Ok(_) => Ok(()),
+            Err(err) => bail!(match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    RetrackError::client_with_root_cause(anyhow!(err).context(format!(
+                        "Template with such name ('{}') already exists.",
+                        template.name
+                    )))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't update template ('{}') due to unknown reason.",
+                    template.name
+                ))),
+            }),
+        }
+    }
+
+    /// Removes email template from the database.
+    pub async fn remove_template(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(r#"DELETE FROM templates WHERE id = $1"#, id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{database::Database, error::Error as RetrackError};
+    use insta::assert_debug_snapshot;
+    use retrack_types::templates::Template;
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    fn mock_template(id: u128, name: &str) -> anyhow::Result<Template> {
+        Ok(Template {
+            id: Uuid::from_u128(id),
+            name: name.to_string(),
+            subject: "{{tracker.name}} changed".to_string(),
+            text: "{{diff}}".to_string(),
+            html: None,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    #[sqlx::test]
+    async fn can_add_update_and_remove_template(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let template_one = mock_template(1, "changes")?;
+        let template_two = mock_template(2, "alerts")?;
+        assert!(db.get_template(template_one.id).await?.is_none());
+        assert!(db.get_templates().await?.is_empty());
+
+        db.insert_template(&template_one).await?;
+        db.insert_template(&template_two).await?;
+        assert_eq!(
+            db.get_template(template_one.id).await?,
+            Some(template_one.clone())
+        );
+        assert_eq!(
+            db.get_template_by_name("changes").await?,
+            Some(template_one.clone())
+        );
+        assert_eq!(
+            db.get_templates().await?,
+            vec![template_two.clone(), template_one.clone()]
+        );
+
+        let template_one = Template {
+            name: "detailed changes".to_string(),
+            html: Some("<pre>{{diff}}</pre>".to_string()),
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            ..template_one
+        };
+        db.update_template(&template_one).await?;
+        assert_eq!(
+            db.get_template(template_one.id).await?,
+            Some(template_one.clone())
+        );
+        assert!(db.get_template_by_name("changes").await?.is_none());
+
+        db.remove_template(template_one.id).await?;
+        assert!(db.get_template(template_one.id).await?.is_none());
+        assert_eq!(db.get_templates().await?, vec![template_two]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_add_or_update_template_with_duplicate_name(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        db.insert_template(&mock_template(1, "changes")?).await?;
+        db.insert_template(&mock_template(2, "alerts")?).await?;
+
+        let insert_error = db
+            .insert_template(&mock_template(3, "changes")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(insert_error.root_cause.to_string(), @r###""Template with such name ('changes') already exists.""###);
+
+        let update_error = db
+            .update_template(&mock_template(2, "changes")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(update_error.root_cause.to_string(), @r###""Template with such name ('changes') already exists.""###);
+
+        let update_error = db
+            .update_template(&mock_template(4, "other")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(update_error.root_cause.to_string(), @r###""Template ('00000000-0000-0000-0000-000000000004') doesn't exist.""###);
+
+        Ok(())
+    }
+}
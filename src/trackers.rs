@@ -1,9 +1,62 @@
 mod api_ext;
+mod command_runner;
 mod database_ext;
-
+mod embeddings;
+mod file_scanner;
+mod file_writer;
+mod git_client;
+mod grpc;
+mod http_cache;
+mod imap_client;
 mod parsers;
+mod registry_client;
+mod s3_client;
+mod sql_client;
+mod summarizer;
+mod target_request_body;
+mod target_request_pages;
+mod target_selectors;
+mod tls_session;
+mod tracker_action_dispatch;
+mod tracker_action_executions;
+mod tracker_action_rule;
+mod tracker_activity;
+mod tracker_alert;
+mod tracker_change_filter;
+mod tracker_content_size;
+mod tracker_cookie_jar;
+mod tracker_data_assertion;
+mod tracker_data_export;
+mod tracker_data_path;
 mod tracker_data_revisions_diff;
+mod tracker_data_scrub;
+mod tracker_data_severity;
+mod tracker_data_shape;
+mod tracker_data_similarity;
+mod tracker_data_summary;
+mod tracker_data_translation;
+mod tracker_data_version;
+mod tracker_digest_notification;
+mod tracker_email;
+mod tracker_google_chat_message;
+mod tracker_issue;
+mod tracker_pending_data;
+mod tracker_push_notification;
+mod tracker_slack_message;
+mod tracker_teams_message;
+mod tracker_telegram_messages;
+mod transforms;
+mod translator;
 mod web_scraper;
+mod websocket_client;
+
+pub use self::{
+    http_cache::HttpCacheManager,
+    tracker_alert::{TrackerAlert, TrackerAlertsGroup},
+    tracker_data_export::TrackerDataExport,
+    tracker_digest_notification::TrackerDigestNotification,
+    web_scraper::WebScraperCapabilities,
+};
 
 #[cfg(test)]
 pub mod tests {
@@ -11,77 +64,10 @@ pub mod tests {
         tracker_data_revisions_diff::tracker_data_revisions_diff,
         web_scraper::{WebScraperContentRequest, WebScraperErrorResponse},
     };
-    use anyhow::bail;
-    use retrack_types::{
-        scheduler::SchedulerJobConfig,
-        trackers::{
-            PageTarget, Tracker, TrackerAction, TrackerConfig, TrackerCreateParams,
-            TrackerDataValue, TrackerTarget,
-        },
-    };
-    use std::time::Duration;
-    use time::OffsetDateTime;
-    use uuid::Uuid;
-
-    pub struct TrackerCreateParamsBuilder {
-        params: TrackerCreateParams,
-    }
-    impl TrackerCreateParamsBuilder {
-        pub fn new(name: impl Into<String>) -> Self {
-            Self {
-                params: TrackerCreateParams {
-                    name: name.into(),
-                    enabled: true,
-                    target: TrackerTarget::Page(PageTarget {
-                        extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
-                        params: None,
-                        user_agent: Some("Retrack/1.0.0".to_string()),
-                        ignore_https_errors: true,
-                    }),
-                    config: Default::default(),
-                    tags: vec!["tag".to_string()],
-                    actions: vec![TrackerAction::ServerLog],
-                }
-            }
-        }
-
-        pub fn with_config(mut self, config: TrackerConfig) -> Self {
-            self.params.config = config;
-            self
-        }
+    pub use retrack_types::test_utils::{MockTrackerBuilder, TrackerCreateParamsBuilder};
 
-        pub fn with_target(mut self, target: TrackerTarget) -> Self {
-            self.params.target = target;
-            self
-        }
-
-        pub fn with_tags(mut self, tags: Vec<String>) -> Self {
-            self.params.tags = tags;
-            self
-        }
-
-        pub fn with_actions(mut self, actions: Vec<TrackerAction>) -> Self {
-            self.params.actions = actions;
-            self
-        }
-
-        pub fn with_schedule<S: Into<String>>(mut self, schedule: S) -> Self {
-            self.params.config.job = Some(SchedulerJobConfig {
-                schedule: schedule.into(),
-                retry_strategy: None,
-            });
-            self
-        }
-
-        pub fn disable(mut self) -> Self {
-            self.params.enabled = false;
-            self
-        }
-
-        pub fn build(self) -> TrackerCreateParams {
-            self.params
-        }
-    }
+    use anyhow::bail;
+    use retrack_types::trackers::{Tracker, TrackerDataValue, TrackerTarget};
 
     impl<'a> WebScraperContentRequest<'a> {
         /// Sets the content that has been extracted from the page previously.
@@ -107,11 +93,13 @@ pub mod tests {
 
             Ok(Self {
                 // Target properties.
-                extractor: target.extractor.as_str(),
+                extractor: target.scenario()?,
                 extractor_params: target.params.as_ref(),
                 tags: &tracker.tags,
                 user_agent: target.user_agent.as_deref(),
                 ignore_https_errors: target.ignore_https_errors,
+                extract_article: target.extract_article,
+                engine: target.engine,
                 // Config properties.
                 timeout: tracker.config.timeout,
                 // Non-tracker properties.
@@ -119,72 +107,4 @@ pub mod tests {
             })
         }
     }
-
-    pub struct MockTrackerBuilder {
-        tracker: Tracker,
-    }
-
-    impl MockTrackerBuilder {
-        pub fn create<N: Into<String>>(
-            id: Uuid,
-            name: N,
-            revisions: usize,
-        ) -> anyhow::Result<Self> {
-            Ok(Self {
-                tracker: Tracker {
-                    id,
-                    name: name.into(),
-                    enabled: true,
-                    job_id: None,
-                    target: TrackerTarget::Page(PageTarget {
-                        extractor: "export async function execute(p) { await p.goto('https://retrack.dev/'); return await p.content(); }".to_string(),
-                        params: None,
-                        user_agent: Some("Retrack/1.0.0".to_string()),
-                        ignore_https_errors: false,
-                    }),
-                    config: TrackerConfig {
-                        revisions,
-                        timeout: Some(Duration::from_millis(2000)),
-                        job: None,
-                    },
-                    tags: vec![],
-                    actions: vec![TrackerAction::ServerLog],
-                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
-                    updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
-                },
-            })
-        }
-
-        pub fn with_schedule<S: Into<String>>(mut self, schedule: S) -> Self {
-            self.tracker.config.job = Some(SchedulerJobConfig {
-                schedule: schedule.into(),
-                retry_strategy: None,
-            });
-            self
-        }
-
-        pub fn with_job_id(mut self, job_id: Uuid) -> Self {
-            self.tracker.job_id = Some(job_id);
-            self
-        }
-
-        pub fn with_target(mut self, target: TrackerTarget) -> Self {
-            self.tracker.target = target;
-            self
-        }
-
-        pub fn with_timeout(mut self, timeout: Duration) -> Self {
-            self.tracker.config.timeout = Some(timeout);
-            self
-        }
-
-        pub fn with_tags(mut self, tags: Vec<String>) -> Self {
-            self.tracker.tags = tags;
-            self
-        }
-
-        pub fn build(self) -> Tracker {
-            self.tracker
-        }
-    }
 }
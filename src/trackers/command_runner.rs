@@ -0,0 +1,209 @@
+use anyhow::{anyhow, bail, Context};
+use std::{
+    env,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::Duration,
+};
+use tokio::{io::AsyncWriteExt, process::Command};
+
+/// Defines a maximum number of characters of the command output to include into logs and errors.
+const MAX_COMMAND_OUTPUT_SNIPPET_LENGTH: usize = 500;
+
+/// Runs local commands, restricted to the list of allowed executables. Commands are run directly,
+/// without a shell, and with the cleared environment (except for `PATH`), so that the server
+/// configuration and secrets don't leak to them.
+pub struct CommandRunner<'p> {
+    programs: &'p [PathBuf],
+}
+
+impl<'p> CommandRunner<'p> {
+    /// Creates a new runner restricted to the specified executables.
+    pub fn new(programs: &'p [PathBuf]) -> Self {
+        Self { programs }
+    }
+
+    /// Checks if the command is an absolute path to one of the allowed executables.
+    pub fn is_allowed_command(&self, command: &str) -> bool {
+        let command = Path::new(command);
+        command.is_absolute() && self.programs.iter().any(|program| program == command)
+    }
+
+    /// Runs the command with the specified arguments and environment variables, writes the input
+    /// to its standard input, and waits for it to exit. The command is killed if it doesn't exit
+    /// within the timeout. Returns the standard output of the command, if it exits successfully.
+    pub async fn run(
+        &self,
+        command: &str,
+        args: &[String],
+        envs: &[(&str, &str)],
+        input: &[u8],
+        timeout: Duration,
+    ) -> anyhow::Result<String> {
+        if !self.is_allowed_command(command) {
+            bail!("Command ({command}) is not one of the allowed executables.");
+        }
+
+        let mut child = Command::new(command)
+            .args(args)
+            .env_clear()
+            .envs(env::var_os("PATH").map(|path| ("PATH", path)))
+            .envs(envs.iter().copied())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("Cannot run command ({command})."))?;
+
+        // Write the input while reading the output, so that the command that produces a lot of
+        // output before reading its input doesn't block. Commands can exit without reading their
+        // input at all.
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("Cannot open standard input of command ({command})."))?;
+        let write_input = async move {
+            match stdin.write_all(input).await {
+                Err(err) if err.kind() != ErrorKind::BrokenPipe => Err(err),
+                _ => Ok(()),
+            }
+        };
+
+        let (write_result, output) = tokio::time::timeout(timeout, async {
+            tokio::join!(write_input, child.wait_with_output())
+        })
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "Command ({command}) didn't exit within {}ms.",
+                timeout.as_millis()
+            )
+        })?;
+        write_result
+            .with_context(|| format!("Cannot write to standard input of command ({command})."))?;
+
+        let output = output?;
+        if !output.status.success() {
+            bail!(
+                "Command ({command}) failed ({}): {}",
+                output.status,
+                truncate_output(&String::from_utf8_lossy(&output.stderr))
+            );
+        }
+
+        Ok(truncate_output(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+/// Truncates command output to make it suitable for logs and errors.
+fn truncate_output(output: &str) -> String {
+    let output = output.trim();
+    if output.chars().count() > MAX_COMMAND_OUTPUT_SNIPPET_LENGTH {
+        format!(
+            "{}…",
+            output
+                .chars()
+                .take(MAX_COMMAND_OUTPUT_SNIPPET_LENGTH)
+                .collect::<String>()
+        )
+    } else {
+        output.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandRunner;
+    use std::{path::PathBuf, time::Duration};
+
+    #[test]
+    fn checks_allowed_commands() {
+        let programs = vec![PathBuf::from("/usr/local/bin/notify")];
+        let runner = CommandRunner::new(&programs);
+
+        assert!(runner.is_allowed_command("/usr/local/bin/notify"));
+        assert!(!runner.is_allowed_command("notify"));
+        assert!(!runner.is_allowed_command("/usr/local/bin/notify-all"));
+        assert!(!runner.is_allowed_command("/usr/local/bin"));
+        assert!(!CommandRunner::new(&[]).is_allowed_command("/usr/local/bin/notify"));
+    }
+
+    #[tokio::test]
+    async fn runs_commands() -> anyhow::Result<()> {
+        let programs = vec![PathBuf::from("/bin/sh")];
+        let runner = CommandRunner::new(&programs);
+
+        let args = vec![
+            "-c".to_string(),
+            r#"printf '%s:%s:' "$RETRACK_TRACKER_NAME" "$HOME"; cat"#.to_string(),
+        ];
+        assert_eq!(
+            runner
+                .run(
+                    "/bin/sh",
+                    &args,
+                    &[("RETRACK_TRACKER_NAME", "tracker")],
+                    br#"{"version":"1.0.0"}"#,
+                    Duration::from_secs(5)
+                )
+                .await?,
+            r#"tracker::{"version":"1.0.0"}"#
+        );
+
+        // Commands don't have to read the input.
+        let args = vec!["-c".to_string(), "echo done".to_string()];
+        assert_eq!(
+            runner
+                .run(
+                    "/bin/sh",
+                    &args,
+                    &[],
+                    &[0; 1024 * 1024],
+                    Duration::from_secs(5)
+                )
+                .await?,
+            "done"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_command_fails() -> anyhow::Result<()> {
+        let programs = vec![PathBuf::from("/bin/sh")];
+        let runner = CommandRunner::new(&programs);
+
+        let args = vec!["-c".to_string(), "echo 'Uh oh!' >&2; exit 3".to_string()];
+        assert_eq!(
+            runner
+                .run("/bin/sh", &args, &[], b"{}", Duration::from_secs(5))
+                .await
+                .unwrap_err()
+                .to_string(),
+            "Command (/bin/sh) failed (exit status: 3): Uh oh!"
+        );
+
+        let args = vec!["-c".to_string(), "sleep 5".to_string()];
+        assert_eq!(
+            runner
+                .run("/bin/sh", &args, &[], b"{}", Duration::from_millis(100))
+                .await
+                .unwrap_err()
+                .to_string(),
+            "Command (/bin/sh) didn't exit within 100ms."
+        );
+
+        assert_eq!(
+            runner
+                .run("/bin/bash", &args, &[], b"{}", Duration::from_secs(5))
+                .await
+                .unwrap_err()
+                .to_string(),
+            "Command (/bin/bash) is not one of the allowed executables."
+        );
+
+        Ok(())
+    }
+}
@@ -2,15 +2,27 @@ mod raw_tracker;
 mod raw_tracker_data_revision;
 
 use crate::{
-    database::Database, error::Error as RetrackError, scheduler::SchedulerJobMetadata,
-    trackers::database_ext::raw_tracker_data_revision::RawTrackerDataRevision,
+    database::Database,
+    error::Error as RetrackError,
+    scheduler::SchedulerJobMetadata,
+    search::search_content,
+    trackers::{
+        database_ext::raw_tracker_data_revision::RawTrackerDataRevision,
+        tracker_action_executions::TrackerActionExecutions, tracker_activity::TrackerActivity,
+        tracker_cookie_jar::TrackerCookieJar, tracker_data_shape::TrackerDataShape,
+        tracker_pending_data::TrackerPendingData, TrackerAlert, TrackerDigestNotification,
+    },
 };
 use anyhow::{anyhow, bail};
 use async_stream::try_stream;
 use futures::Stream;
 use raw_tracker::RawTracker;
-use retrack_types::trackers::{Tracker, TrackerDataRevision};
+use retrack_types::trackers::{
+    Tracker, TrackerActionHistoryEntry, TrackerActionHistoryStatus, TrackerDataRevision,
+    TrackerQuarantinedRevision, TrackerStats, TrackersScrub,
+};
 use sqlx::{error::ErrorKind as SqlxErrorKind, query, query_as, Pool, Postgres};
+use std::{collections::HashMap, time::Duration};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -30,7 +42,7 @@ impl<'pool> TrackersDatabaseExt<'pool> {
             query_as!(
                 RawTracker,
                 r#"
-SELECT id, name, enabled, config, tags, created_at, updated_at, job_id, job_needed
+SELECT id, name, enabled, archived, config, tags, created_at, updated_at, job_id, job_needed, shape_deviation, workflow_state
 FROM trackers
 ORDER BY updated_at
                 "#
@@ -41,7 +53,7 @@ ORDER BY updated_at
             query_as!(
                 RawTracker,
                 r#"
-SELECT id, name, enabled, config, tags, created_at, updated_at, job_id, job_needed
+SELECT id, name, enabled, archived, config, tags, created_at, updated_at, job_id, job_needed, shape_deviation, workflow_state
 FROM trackers
 WHERE tags @> $1
 ORDER BY updated_at
@@ -60,12 +72,41 @@ ORDER BY updated_at
         Ok(trackers)
     }
 
+    /// Retrieves all trackers with the specified name (case-insensitive) that have all specified
+    /// tags. If `tags` is empty, all trackers with the specified name are returned.
+    pub async fn get_trackers_by_name(
+        &self,
+        name: &str,
+        tags: &[String],
+    ) -> anyhow::Result<Vec<Tracker>> {
+        let raw_trackers = query_as!(
+            RawTracker,
+            r#"
+SELECT id, name, enabled, archived, config, tags, created_at, updated_at, job_id, job_needed, shape_deviation, workflow_state
+FROM trackers
+WHERE name = $1 AND tags @> $2
+ORDER BY updated_at
+                "#,
+            name,
+            tags
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut trackers = vec![];
+        for raw_tracker in raw_trackers {
+            trackers.push(Tracker::try_from(raw_tracker)?);
+        }
+
+        Ok(trackers)
+    }
+
     /// Retrieves tracker with the specified ID.
     pub async fn get_tracker(&self, id: Uuid) -> anyhow::Result<Option<Tracker>> {
         query_as!(
             RawTracker,
             r#"
-    SELECT id, name, enabled, config, tags, created_at, updated_at, job_id, job_needed
+    SELECT id, name, enabled, archived, config, tags, created_at, updated_at, job_id, job_needed, shape_deviation, workflow_state
     FROM trackers
     WHERE id = $1
                     "#,
@@ -82,12 +123,13 @@ ORDER BY updated_at
         let raw_tracker = RawTracker::try_from(tracker)?;
         let result = query!(
             r#"
-    INSERT INTO trackers (id, name, enabled, config, tags, created_at, updated_at, job_needed, job_id)
-    VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 )
+    INSERT INTO trackers (id, name, enabled, archived, config, tags, created_at, updated_at, job_needed, job_id)
+    VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10 )
             "#,
             raw_tracker.id,
             raw_tracker.name,
             raw_tracker.enabled,
+            raw_tracker.archived,
             raw_tracker.config,
             &raw_tracker.tags,
             raw_tracker.created_at,
@@ -102,8 +144,8 @@ ORDER BY updated_at
             bail!(match err.as_database_error() {
                 Some(database_error) if database_error.is_unique_violation() => {
                     RetrackError::client_with_root_cause(anyhow!(err).context(format!(
-                        "Tracker with such name ('{}') or id ('{}') already exists.",
-                        tracker.name, tracker.id
+                        "Tracker with such id ('{}') already exists.",
+                        tracker.id
                     )))
                 }
                 _ => RetrackError::from(anyhow!(err).context(format!(
@@ -122,12 +164,13 @@ ORDER BY updated_at
         let result = query!(
             r#"
 UPDATE trackers
-SET name = $2, enabled = $3, config = $4, tags = $5, updated_at = $6, job_needed = $7, job_id = $8
+SET name = $2, enabled = $3, archived = $4, config = $5, tags = $6, updated_at = $7, job_needed = $8, job_id = $9
 WHERE id = $1
         "#,
             raw_tracker.id,
             raw_tracker.name,
             raw_tracker.enabled,
+            raw_tracker.archived,
             raw_tracker.config,
             &raw_tracker.tags,
             raw_tracker.updated_at,
@@ -193,12 +236,12 @@ WHERE id = $1
         let raw_revisions = query_as!(
             RawTrackerDataRevision,
             r#"
-SELECT data.id, data.tracker_id, data.data, data.created_at
+SELECT data.id, data.tracker_id, data.data, data.created_at, data.provenance, data.sequence
 FROM trackers_data as data
 INNER JOIN trackers
 ON data.tracker_id = trackers.id
 WHERE data.tracker_id = $1
-ORDER BY data.created_at
+ORDER BY data.sequence
                 "#,
             tracker_id
         )
@@ -213,6 +256,122 @@ ORDER BY data.created_at
         Ok(revisions)
     }
 
+    /// Retrieves a page of tracked data for the specified tracker, ordered by sequence. If
+    /// specified, only revisions persisted after the revision with the `after` ID and created
+    /// within the `since` (inclusive) and `until` (exclusive) time range are returned.
+    pub async fn get_tracker_data_page(
+        &self,
+        tracker_id: Uuid,
+        after: Option<Uuid>,
+        since: Option<OffsetDateTime>,
+        until: Option<OffsetDateTime>,
+        limit: Option<usize>,
+    ) -> anyhow::Result<Vec<TrackerDataRevision>> {
+        let raw_revisions = query_as!(
+            RawTrackerDataRevision,
+            r#"
+SELECT data.id, data.tracker_id, data.data, data.created_at, data.provenance, data.sequence
+FROM trackers_data as data
+INNER JOIN trackers
+ON data.tracker_id = trackers.id
+WHERE data.tracker_id = $1
+  AND ($2::uuid IS NULL OR data.sequence > (SELECT sequence FROM trackers_data WHERE tracker_id = $1 AND id = $2))
+  AND ($3::timestamptz IS NULL OR data.created_at >= $3)
+  AND ($4::timestamptz IS NULL OR data.created_at < $4)
+ORDER BY data.sequence
+LIMIT $5
+                "#,
+            tracker_id,
+            after,
+            since,
+            until,
+            limit.map(i64::try_from).transpose()?
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut revisions = vec![];
+        for raw_revision in raw_revisions {
+            revisions.push(TrackerDataRevision::try_from(raw_revision)?);
+        }
+
+        Ok(revisions)
+    }
+
+    /// Retrieves up to `limit` tracked data revisions of all trackers that don't have the text
+    /// content for the full-text search yet (e.g., persisted before the search was introduced).
+    pub async fn get_tracker_data_without_content(
+        &self,
+        limit: usize,
+    ) -> anyhow::Result<Vec<TrackerDataRevision>> {
+        let raw_revisions = query_as!(
+            RawTrackerDataRevision,
+            r#"
+SELECT id, tracker_id, data, created_at, provenance, sequence
+FROM trackers_data
+WHERE content IS NULL
+LIMIT $1
+                "#,
+            i64::try_from(limit)?
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut revisions = vec![];
+        for raw_revision in raw_revisions {
+            revisions.push(TrackerDataRevision::try_from(raw_revision)?);
+        }
+
+        Ok(revisions)
+    }
+
+    /// Retrieves tracked data revision with the specified ID for the specified tracker.
+    pub async fn get_tracker_data_revision(
+        &self,
+        tracker_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<TrackerDataRevision>> {
+        query_as!(
+            RawTrackerDataRevision,
+            r#"
+SELECT id, tracker_id, data, created_at, provenance, sequence
+FROM trackers_data
+WHERE tracker_id = $1 AND id = $2
+                "#,
+            tracker_id,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(TrackerDataRevision::try_from)
+        .transpose()
+    }
+
+    /// Retrieves tracked data revision persisted right before the revision with the specified ID
+    /// for the specified tracker, if any.
+    pub async fn get_previous_tracker_data_revision(
+        &self,
+        tracker_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<Option<TrackerDataRevision>> {
+        query_as!(
+            RawTrackerDataRevision,
+            r#"
+SELECT id, tracker_id, data, created_at, provenance, sequence
+FROM trackers_data
+WHERE tracker_id = $1 AND sequence < (SELECT sequence FROM trackers_data WHERE tracker_id = $1 AND id = $2)
+ORDER BY sequence DESC
+LIMIT 1
+                "#,
+            tracker_id,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(TrackerDataRevision::try_from)
+        .transpose()
+    }
+
     /// Removes tracker data.
     pub async fn clear_tracker_data(&self, tracker_id: Uuid) -> anyhow::Result<()> {
         query!(
@@ -228,38 +387,264 @@ ORDER BY data.created_at
         Ok(())
     }
 
-    // Inserts tracker revision.
+    /// Inserts tracker revision and returns the sequence number assigned to it.
     pub async fn insert_tracker_data_revision(
         &self,
         revision: &TrackerDataRevision,
+    ) -> anyhow::Result<u64> {
+        let raw_revision = RawTrackerDataRevision::try_from(revision)?;
+        // Updating the tracker row locks it, so concurrent inserts get consecutive sequence numbers.
+        let result = query!(
+            r#"
+    WITH tracker AS (
+        UPDATE trackers
+        SET revisions_sequence = revisions_sequence + 1
+        WHERE id = $2
+        RETURNING revisions_sequence
+    )
+    INSERT INTO trackers_data (id, tracker_id, data, created_at, provenance, sequence, content)
+    SELECT $1, $2, $3, $4, $5, tracker.revisions_sequence, $6
+    FROM tracker
+    RETURNING sequence
+            "#,
+            raw_revision.id,
+            raw_revision.tracker_id,
+            raw_revision.data,
+            raw_revision.created_at,
+            raw_revision.provenance,
+            search_content(revision.data.value())
+        )
+        .fetch_optional(self.pool)
+        .await;
+
+        match result {
+            Ok(Some(record)) => Ok(u64::try_from(record.sequence)?),
+            Ok(None) => bail!(RetrackError::client(format!(
+                "Tracker ('{}') is not found.",
+                revision.tracker_id
+            ))),
+            Err(err) => {
+                let is_conflict_error = err
+                    .as_database_error()
+                    .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::UniqueViolation))
+                    .unwrap_or_default();
+                bail!(if is_conflict_error {
+                    RetrackError::client_with_root_cause(anyhow!(err).context(format!(
+                        "Tracker revision ('{}') already exists.",
+                        revision.id
+                    )))
+                } else {
+                    RetrackError::from(anyhow!(err).context(format!(
+                        "Couldn't create tracker revision ('{}') due to unknown reason.",
+                        revision.id
+                    )))
+                });
+            }
+        }
+    }
+
+    /// Inserts historical tracker revisions and renumbers all revisions of the tracker, so that
+    /// sequence numbers follow the order in which revisions were created. Renumbered revisions
+    /// get sequence numbers that have never been assigned before.
+    pub async fn import_tracker_data_revisions(
+        &self,
+        tracker_id: Uuid,
+        revisions: &[TrackerDataRevision],
+    ) -> anyhow::Result<()> {
+        let mut transaction = self.pool.begin().await?;
+
+        // Locking the tracker row prevents concurrent inserts while revisions are renumbered.
+        let Some(tracker) = query!(
+            r#"
+    SELECT revisions_sequence
+    FROM trackers
+    WHERE id = $1
+    FOR UPDATE
+            "#,
+            tracker_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?
+        else {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{tracker_id}') is not found."
+            )));
+        };
+
+        let mut sequence = tracker.revisions_sequence;
+        for revision in revisions {
+            let raw_revision = RawTrackerDataRevision::try_from(revision)?;
+            sequence += 1;
+            query!(
+                r#"
+    INSERT INTO trackers_data (id, tracker_id, data, created_at, provenance, sequence, content)
+    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                raw_revision.id,
+                tracker_id,
+                raw_revision.data,
+                raw_revision.created_at,
+                raw_revision.provenance,
+                sequence,
+                search_content(revision.data.value())
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        // New sequence numbers start after the last assigned one, so they never collide with the
+        // current ones.
+        let result = query!(
+            r#"
+    UPDATE trackers_data
+    SET sequence = numbered.sequence
+    FROM (
+        SELECT id, $2::BIGINT + ROW_NUMBER() OVER (ORDER BY created_at, sequence) AS sequence
+        FROM trackers_data
+        WHERE tracker_id = $1
+    ) AS numbered
+    WHERE trackers_data.id = numbered.id
+            "#,
+            tracker_id,
+            sequence
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        query!(
+            r#"
+    UPDATE trackers
+    SET revisions_sequence = $2
+    WHERE id = $1
+            "#,
+            tracker_id,
+            sequence + i64::try_from(result.rows_affected())?
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    /// Removes tracker data revision.
+    pub async fn remove_tracker_data_revision(
+        &self,
+        tracker_id: Uuid,
+        id: Uuid,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM trackers_data
+    WHERE tracker_id = $1 AND id = $2
+                    "#,
+            tracker_id,
+            id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes all data revisions of the specified tracker created before the specified time.
+    /// Returns the number of removed revisions.
+    pub async fn remove_tracker_data_revisions_before(
+        &self,
+        tracker_id: Uuid,
+        before: OffsetDateTime,
+    ) -> anyhow::Result<u64> {
+        let result = query!(
+            r#"
+    DELETE FROM trackers_data
+    WHERE tracker_id = $1 AND created_at < $2
+                    "#,
+            tracker_id,
+            before
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Retrieves all quarantined data revisions for the specified tracker, ordered by creation
+    /// time.
+    pub async fn get_tracker_quarantine(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Vec<TrackerQuarantinedRevision>> {
+        let records = query!(
+            r#"
+SELECT id, tracker_id, data, created_at, provenance, reason
+FROM trackers_quarantine
+WHERE tracker_id = $1
+ORDER BY created_at, id
+                "#,
+            tracker_id
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut quarantine = vec![];
+        for record in records {
+            let revision = TrackerDataRevision::try_from(RawTrackerDataRevision {
+                id: record.id,
+                tracker_id: record.tracker_id,
+                data: record.data,
+                created_at: record.created_at,
+                provenance: record.provenance,
+                sequence: 0,
+            })?;
+            quarantine.push(TrackerQuarantinedRevision {
+                // Quarantined revisions aren't part of the revisions history yet.
+                revision: TrackerDataRevision {
+                    sequence: None,
+                    ..revision
+                },
+                reason: record.reason,
+            });
+        }
+
+        Ok(quarantine)
+    }
+
+    /// Inserts tracker data revision into quarantine.
+    pub async fn insert_tracker_quarantined_revision(
+        &self,
+        quarantined_revision: &TrackerQuarantinedRevision,
     ) -> anyhow::Result<()> {
+        let revision = &quarantined_revision.revision;
         let raw_revision = RawTrackerDataRevision::try_from(revision)?;
         let result = query!(
             r#"
-    INSERT INTO trackers_data (id, tracker_id, data, created_at)
-    VALUES ( $1, $2, $3, $4 )
+    INSERT INTO trackers_quarantine (id, tracker_id, data, created_at, provenance, reason)
+    VALUES ($1, $2, $3, $4, $5, $6)
             "#,
             raw_revision.id,
             raw_revision.tracker_id,
             raw_revision.data,
-            raw_revision.created_at
+            raw_revision.created_at,
+            raw_revision.provenance,
+            quarantined_revision.reason
         )
         .execute(self.pool)
         .await;
 
         if let Err(err) = result {
-            let is_conflict_error = err
+            let is_foreign_key_error = err
                 .as_database_error()
-                .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::UniqueViolation))
+                .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::ForeignKeyViolation))
                 .unwrap_or_default();
-            bail!(if is_conflict_error {
-                RetrackError::client_with_root_cause(anyhow!(err).context(format!(
-                    "Tracker revision ('{}') already exists.",
-                    revision.id
-                )))
+            bail!(if is_foreign_key_error {
+                RetrackError::client_with_root_cause(
+                    anyhow!(err)
+                        .context(format!("Tracker ('{}') is not found.", revision.tracker_id)),
+                )
             } else {
                 RetrackError::from(anyhow!(err).context(format!(
-                    "Couldn't create tracker revision ('{}') due to unknown reason.",
+                    "Couldn't quarantine tracker revision ('{}') due to unknown reason.",
                     revision.id
                 )))
             });
@@ -268,15 +653,16 @@ ORDER BY data.created_at
         Ok(())
     }
 
-    /// Removes tracker data revision.
-    pub async fn remove_tracker_data_revision(
+    /// Removes tracker data revision from quarantine, returns `false` if the revision isn't
+    /// quarantined.
+    pub async fn remove_tracker_quarantined_revision(
         &self,
         tracker_id: Uuid,
         id: Uuid,
-    ) -> anyhow::Result<()> {
-        query!(
+    ) -> anyhow::Result<bool> {
+        let result = query!(
             r#"
-    DELETE FROM trackers_data
+    DELETE FROM trackers_quarantine
     WHERE tracker_id = $1 AND id = $2
                     "#,
             tracker_id,
@@ -285,7 +671,7 @@ ORDER BY data.created_at
         .execute(self.pool)
         .await?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 
     /// Retrieves all trackers that need to be scheduled.
@@ -293,9 +679,9 @@ ORDER BY data.created_at
         let raw_trackers = query_as!(
             RawTracker,
             r#"
-SELECT id, name, enabled, config, tags, created_at, updated_at, job_needed, job_id
+SELECT id, name, enabled, archived, config, tags, created_at, updated_at, job_needed, job_id, shape_deviation, workflow_state
 FROM trackers
-WHERE job_needed = TRUE AND enabled = TRUE AND job_id IS NULL
+WHERE job_needed = TRUE AND enabled = TRUE AND archived = FALSE AND job_id IS NULL
 ORDER BY updated_at
                 "#
         )
@@ -320,8 +706,9 @@ ORDER BY updated_at
             loop {
                  let records = query!(
 r#"
-SELECT trackers.id, trackers.name, trackers.enabled, trackers.config, trackers.tags,
-       trackers.created_at, trackers.updated_at, trackers.job_needed, trackers.job_id, jobs.extra
+SELECT trackers.id, trackers.name, trackers.enabled, trackers.archived, trackers.config, trackers.tags,
+       trackers.created_at, trackers.updated_at, trackers.job_needed, trackers.job_id,
+       trackers.shape_deviation, trackers.workflow_state, jobs.extra
 FROM trackers
 INNER JOIN scheduler_jobs as jobs
 ON trackers.job_id = jobs.id
@@ -351,12 +738,15 @@ LIMIT $2;
                         id: record.id,
                         name: record.name,
                         enabled: record.enabled,
+                        archived: record.archived,
                         config: record.config,
                         tags: record.tags,
                         created_at: record.created_at,
                         updated_at: record.updated_at,
                         job_needed: record.job_needed,
                         job_id: record.job_id,
+                        shape_deviation: record.shape_deviation,
+                        workflow_state: record.workflow_state,
                     })?;
                 }
 
@@ -372,7 +762,7 @@ LIMIT $2;
         query_as!(
             RawTracker,
             r#"
-    SELECT id, name, enabled, config, tags, created_at, updated_at, job_needed, job_id
+    SELECT id, name, enabled, archived, config, tags, created_at, updated_at, job_needed, job_id, shape_deviation, workflow_state
     FROM trackers
     WHERE job_id = $1
                     "#,
@@ -406,41 +796,732 @@ LIMIT $2;
 
         Ok(())
     }
-}
 
-impl Database {
-    /// Returns a database extension for the trackers operations performed on.
-    pub fn trackers(&self) -> TrackersDatabaseExt {
-        TrackersDatabaseExt::new(&self.pool)
-    }
-}
+    /// Retrieves the shape of the last healthy data revision of the tracker with the specified ID.
+    pub async fn get_tracker_data_shape(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<TrackerDataShape>> {
+        let data_shape = query!(
+            r#"
+    SELECT data_shape
+    FROM trackers
+    WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .and_then(|record| record.data_shape);
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        database::Database,
-        error::Error as RetrackError,
-        scheduler::{SchedulerJob, SchedulerJobMetadata, SchedulerJobRetryState},
-        tests::{
-            mock_scheduler_job, mock_upsert_scheduler_job, to_database_error, MockTrackerBuilder,
-            RawSchedulerJobStoredData,
-        },
-    };
-    use futures::StreamExt;
-    use insta::assert_debug_snapshot;
-    use retrack_types::trackers::{Tracker, TrackerDataRevision, TrackerDataValue};
-    use serde_json::json;
-    use sqlx::PgPool;
-    use std::{
-        ops::{Add, Sub},
-        time::Duration,
-    };
-    use time::OffsetDateTime;
-    use uuid::{uuid, Uuid};
+        Ok(data_shape
+            .map(|data_shape| postcard::from_bytes(&data_shape))
+            .transpose()?)
+    }
 
-    fn create_data_revision(
+    /// Updates the shape of the last healthy data revision of the tracker and the description of
+    /// the detected shape deviation, if any.
+    pub async fn update_tracker_data_shape(
+        &self,
         id: Uuid,
-        tracker_id: Uuid,
+        data_shape: Option<&TrackerDataShape>,
+        shape_deviation: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    UPDATE trackers
+    SET data_shape = $2, shape_deviation = $3
+    WHERE id = $1
+            "#,
+            id,
+            data_shape.map(postcard::to_stdvec).transpose()?,
+            shape_deviation
+        )
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{id}') doesn't exist.",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Updates the current state of the tracker workflow. Tracker without a state is considered to
+    /// be in the initial state of its workflow.
+    pub async fn update_tracker_workflow_state(
+        &self,
+        id: Uuid,
+        workflow_state: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    UPDATE trackers
+    SET workflow_state = $2
+    WHERE id = $1
+            "#,
+            id,
+            workflow_state
+        )
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{id}') doesn't exist.",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the changed data of the tracker with the specified ID that is waiting for
+    /// confirmation, if any.
+    pub async fn get_tracker_pending_data(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<TrackerPendingData>> {
+        let pending_data = query!(
+            r#"
+    SELECT pending_data
+    FROM trackers
+    WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .and_then(|record| record.pending_data);
+
+        Ok(pending_data
+            .map(|pending_data| postcard::from_bytes(&pending_data))
+            .transpose()?)
+    }
+
+    /// Updates the changed data of the tracker that is waiting for confirmation, `None` clears it.
+    pub async fn update_tracker_pending_data(
+        &self,
+        id: Uuid,
+        pending_data: Option<&TrackerPendingData>,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    UPDATE trackers
+    SET pending_data = $2
+    WHERE id = $1
+            "#,
+            id,
+            pending_data.map(postcard::to_stdvec).transpose()?
+        )
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{id}') doesn't exist.",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the cookie jar of the tracker with the specified ID, if any.
+    pub async fn get_tracker_cookie_jar(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<TrackerCookieJar>> {
+        let cookie_jar = query!(
+            r#"
+    SELECT cookie_jar
+    FROM trackers
+    WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .and_then(|record| record.cookie_jar);
+
+        Ok(cookie_jar
+            .map(|cookie_jar| postcard::from_bytes(&cookie_jar))
+            .transpose()?)
+    }
+
+    /// Updates the cookie jar of the tracker, `None` clears it.
+    pub async fn update_tracker_cookie_jar(
+        &self,
+        id: Uuid,
+        cookie_jar: Option<&TrackerCookieJar>,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    UPDATE trackers
+    SET cookie_jar = $2
+    WHERE id = $1
+            "#,
+            id,
+            cookie_jar.map(postcard::to_stdvec).transpose()?
+        )
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{id}') doesn't exist.",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the last executions of the throttled actions of the tracker with the specified ID,
+    /// if any.
+    pub async fn get_tracker_action_executions(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<TrackerActionExecutions>> {
+        let action_executions = query!(
+            r#"
+    SELECT action_executions
+    FROM trackers
+    WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .and_then(|record| record.action_executions);
+
+        Ok(action_executions
+            .map(|action_executions| postcard::from_bytes(&action_executions))
+            .transpose()?)
+    }
+
+    /// Updates the last executions of the throttled actions of the tracker, `None` clears them.
+    pub async fn update_tracker_action_executions(
+        &self,
+        id: Uuid,
+        action_executions: Option<&TrackerActionExecutions>,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    UPDATE trackers
+    SET action_executions = $2
+    WHERE id = $1
+            "#,
+            id,
+            action_executions.map(postcard::to_stdvec).transpose()?
+        )
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{id}') doesn't exist.",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Increments the number of consecutive failed data checks of the tracker and returns it.
+    pub async fn increment_tracker_consecutive_failures(&self, id: Uuid) -> anyhow::Result<u32> {
+        let Some(record) = query!(
+            r#"
+    UPDATE trackers
+    SET consecutive_failures = consecutive_failures + 1
+    WHERE id = $1
+    RETURNING consecutive_failures
+            "#,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        else {
+            bail!(RetrackError::client(format!(
+                "Tracker ('{id}') doesn't exist.",
+            )));
+        };
+
+        Ok(u32::try_from(record.consecutive_failures)?)
+    }
+
+    /// Resets the number of consecutive failed data checks of the tracker, if there are any.
+    pub async fn reset_tracker_consecutive_failures(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(
+            r#"
+    UPDATE trackers
+    SET consecutive_failures = 0
+    WHERE id = $1 AND consecutive_failures > 0
+            "#,
+            id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the activity summary of all trackers: when the last data revision was created and
+    /// when the tracker job was last triggered.
+    pub async fn get_trackers_activity(&self) -> anyhow::Result<Vec<TrackerActivity>> {
+        let records = query!(
+            r#"
+SELECT trackers.id, MAX(data.created_at) as last_revision_at, MAX(jobs.last_tick) as last_tick
+FROM trackers
+LEFT JOIN trackers_data as data
+ON data.tracker_id = trackers.id
+LEFT JOIN scheduler_jobs as jobs
+ON jobs.id = trackers.job_id
+GROUP BY trackers.id
+ORDER BY trackers.id
+                "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut activity = vec![];
+        for record in records {
+            activity.push(TrackerActivity {
+                tracker_id: record.id,
+                last_revision_at: record.last_revision_at,
+                last_run_at: record
+                    .last_tick
+                    .map(OffsetDateTime::from_unix_timestamp)
+                    .transpose()?,
+            });
+        }
+
+        Ok(activity)
+    }
+
+    /// Retrieves the aggregated statistics of the data revisions of the trackers with the specified
+    /// IDs. Trackers without revisions aren't included.
+    pub async fn get_trackers_stats(
+        &self,
+        ids: &[Uuid],
+    ) -> anyhow::Result<HashMap<Uuid, TrackerStats>> {
+        let records = query!(
+            r#"
+SELECT tracker_id, COUNT(*) as "revisions!", MAX(created_at) as last_revision_at,
+       MAX(created_at) FILTER (WHERE changed) as last_change_at
+FROM (
+    SELECT tracker_id, created_at,
+           data IS DISTINCT FROM LAG(data) OVER (PARTITION BY tracker_id ORDER BY sequence) as changed
+    FROM trackers_data
+    WHERE tracker_id = ANY($1)
+) as data
+GROUP BY tracker_id
+                "#,
+            ids
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                (
+                    record.tracker_id,
+                    TrackerStats {
+                        revisions: record.revisions as usize,
+                        last_revision_at: record.last_revision_at,
+                        last_change_at: record.last_change_at,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Inserts tracker alert that is pending correlation and reporting.
+    pub async fn insert_tracker_alert(&self, alert: &TrackerAlert) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    INSERT INTO trackers_alerts (id, tracker_id, error, created_at)
+    VALUES ($1, $2, $3, $4)
+            "#,
+            alert.id,
+            alert.tracker_id,
+            alert.error,
+            alert.created_at
+        )
+        .execute(self.pool)
+        .await;
+
+        if let Err(err) = result {
+            let is_foreign_key_error = err
+                .as_database_error()
+                .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::ForeignKeyViolation))
+                .unwrap_or_default();
+            bail!(if is_foreign_key_error {
+                RetrackError::client_with_root_cause(
+                    anyhow!(err).context(format!("Tracker ('{}') is not found.", alert.tracker_id)),
+                )
+            } else {
+                RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't create tracker alert ('{}') due to unknown reason.",
+                    alert.id
+                )))
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves all tracker alerts that are pending reporting, ordered by creation time.
+    pub async fn get_tracker_alerts(&self) -> anyhow::Result<Vec<TrackerAlert>> {
+        let records = query!(
+            r#"
+SELECT alerts.id, alerts.tracker_id, alerts.error, alerts.created_at, trackers.name, trackers.tags
+FROM trackers_alerts as alerts
+INNER JOIN trackers
+ON alerts.tracker_id = trackers.id
+ORDER BY alerts.created_at, alerts.id
+                "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| TrackerAlert {
+                id: record.id,
+                tracker_id: record.tracker_id,
+                tracker_name: record.name,
+                tracker_tags: record.tags,
+                error: record.error,
+                created_at: record.created_at,
+            })
+            .collect())
+    }
+
+    /// Removes tracker alerts with the specified IDs.
+    pub async fn remove_tracker_alerts(&self, ids: &[Uuid]) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM trackers_alerts
+    WHERE id = ANY($1)
+                    "#,
+            ids
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Inserts tracker action notification that is pending delivery as a part of the digest.
+    pub async fn insert_tracker_digest_notification(
+        &self,
+        notification: &TrackerDigestNotification,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    INSERT INTO trackers_digest_notifications (id, tracker_id, tag, task_type, send_at, created_at)
+    VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            notification.id,
+            notification.tracker_id,
+            notification.tag,
+            postcard::to_stdvec(&notification.task_type)?,
+            notification.send_at,
+            notification.created_at
+        )
+        .execute(self.pool)
+        .await;
+
+        if let Err(err) = result {
+            let is_foreign_key_error = err
+                .as_database_error()
+                .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::ForeignKeyViolation))
+                .unwrap_or_default();
+            bail!(if is_foreign_key_error {
+                RetrackError::client_with_root_cause(anyhow!(err).context(format!(
+                    "Tracker ('{}') is not found.",
+                    notification.tracker_id
+                )))
+            } else {
+                RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't create tracker digest notification ('{}') due to unknown reason.",
+                    notification.id
+                )))
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves all tracker action notifications that are pending delivery as a part of the
+    /// digest, ordered by creation time.
+    pub async fn get_tracker_digest_notifications(
+        &self,
+    ) -> anyhow::Result<Vec<TrackerDigestNotification>> {
+        let records = query!(
+            r#"
+SELECT notifications.id, notifications.tracker_id, notifications.tag, notifications.task_type,
+       notifications.send_at, notifications.created_at, trackers.name
+FROM trackers_digest_notifications as notifications
+INNER JOIN trackers
+ON notifications.tracker_id = trackers.id
+ORDER BY notifications.created_at, notifications.id
+                "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                Ok(TrackerDigestNotification {
+                    id: record.id,
+                    tracker_id: record.tracker_id,
+                    tracker_name: record.name,
+                    tag: record.tag,
+                    task_type: postcard::from_bytes(&record.task_type)?,
+                    send_at: record.send_at,
+                    created_at: record.created_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Removes tracker digest notifications with the specified IDs.
+    pub async fn remove_tracker_digest_notifications(&self, ids: &[Uuid]) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM trackers_digest_notifications
+    WHERE id = ANY($1)
+                    "#,
+            ids
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Inserts the tracker action history entry.
+    pub async fn insert_tracker_action_history_entry(
+        &self,
+        entry: &TrackerActionHistoryEntry,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    INSERT INTO trackers_action_history (id, tracker_id, action, action_type, task_id, status, error, duration, executed_at)
+    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+            entry.id,
+            entry.tracker_id,
+            i32::try_from(entry.action)?,
+            entry.action_type,
+            entry.task_id,
+            postcard::to_stdvec(&entry.status)?,
+            entry.error,
+            i64::try_from(entry.duration.as_millis())?,
+            entry.executed_at
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the history of the tracker action executions, most recent first.
+    pub async fn get_tracker_action_history(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Vec<TrackerActionHistoryEntry>> {
+        let records = query!(
+            r#"
+SELECT id, tracker_id, action, action_type, task_id, status, error, duration, executed_at
+FROM trackers_action_history
+WHERE tracker_id = $1
+ORDER BY executed_at DESC, id DESC
+                "#,
+            tracker_id
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                Ok(TrackerActionHistoryEntry {
+                    id: record.id,
+                    tracker_id: record.tracker_id,
+                    action: usize::try_from(record.action)?,
+                    action_type: record.action_type,
+                    task_id: record.task_id,
+                    status: postcard::from_bytes(&record.status)?,
+                    error: record.error,
+                    duration: Duration::from_millis(u64::try_from(record.duration)?),
+                    executed_at: record.executed_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Removes the oldest tracker action history entries, keeping at most the specified number of
+    /// the most recent ones.
+    pub async fn trim_tracker_action_history(
+        &self,
+        tracker_id: Uuid,
+        max_size: usize,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM trackers_action_history
+    WHERE tracker_id = $1 AND id NOT IN (
+        SELECT id FROM trackers_action_history
+        WHERE tracker_id = $1
+        ORDER BY executed_at DESC, id DESC
+        LIMIT $2
+    )
+            "#,
+            tracker_id,
+            i64::try_from(max_size)?
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Updates the status of the tracker action history entries whose notification is delivered
+    /// by the task with the specified ID.
+    pub async fn update_tracker_action_history_task_status(
+        &self,
+        task_id: Uuid,
+        status: TrackerActionHistoryStatus,
+        error: Option<&str>,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    UPDATE trackers_action_history
+    SET status = $2, error = $3
+    WHERE task_id = $1
+            "#,
+            task_id,
+            postcard::to_stdvec(&status)?,
+            error
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Clears errors of the tracker action history entries with the specified IDs.
+    pub async fn clear_tracker_action_history_errors(&self, ids: &[Uuid]) -> anyhow::Result<()> {
+        query!(
+            r#"
+    UPDATE trackers_action_history
+    SET error = NULL
+    WHERE id = ANY($1)
+            "#,
+            ids
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Inserts audit record of the trackers data scrub.
+    pub async fn insert_trackers_scrub(&self, scrub: &TrackersScrub) -> anyhow::Result<()> {
+        query!(
+            r#"
+    INSERT INTO trackers_scrubs (id, reason, pattern_digest, trackers, revisions, pending_data, alerts, tasks, created_at)
+    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+            scrub.id,
+            scrub.reason,
+            scrub.pattern_digest,
+            &scrub.trackers,
+            i64::try_from(scrub.revisions)?,
+            i64::try_from(scrub.pending_data)?,
+            i64::try_from(scrub.alerts)?,
+            i64::try_from(scrub.tasks)?,
+            scrub.created_at
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves audit records of all trackers data scrubs, ordered by creation time.
+    pub async fn get_trackers_scrubs(&self) -> anyhow::Result<Vec<TrackersScrub>> {
+        let records = query!(
+            r#"
+SELECT id, reason, pattern_digest, trackers, revisions, pending_data, alerts, tasks, created_at
+FROM trackers_scrubs
+ORDER BY created_at, id
+                "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut scrubs = vec![];
+        for record in records {
+            scrubs.push(TrackersScrub {
+                id: record.id,
+                reason: record.reason,
+                pattern_digest: record.pattern_digest,
+                trackers: record.trackers,
+                revisions: usize::try_from(record.revisions)?,
+                pending_data: usize::try_from(record.pending_data)?,
+                alerts: usize::try_from(record.alerts)?,
+                tasks: usize::try_from(record.tasks)?,
+                created_at: record.created_at,
+            });
+        }
+
+        Ok(scrubs)
+    }
+}
+
+impl Database {
+    /// Returns a database extension for the trackers operations performed on.
+    pub fn trackers(&self) -> TrackersDatabaseExt {
+        TrackersDatabaseExt::new(&self.pool)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        database::Database,
+        error::Error as RetrackError,
+        scheduler::{SchedulerJob, SchedulerJobMetadata, SchedulerJobRetryState},
+        tasks::{Email, EmailContent, EmailTaskType, TaskType},
+        tests::{
+            mock_scheduler_job, mock_upsert_scheduler_job, to_database_error, MockTrackerBuilder,
+            RawSchedulerJobStoredData,
+        },
+        trackers::{tracker_activity::TrackerActivity, TrackerAlert, TrackerDigestNotification},
+    };
+    use futures::StreamExt;
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        Tracker, TrackerActionHistoryEntry, TrackerActionHistoryStatus, TrackerDataRevision,
+        TrackerDataValue, TrackerQuarantinedRevision, TrackerStats, TrackersScrub,
+    };
+    use serde_json::json;
+    use sqlx::PgPool;
+    use std::{
+        collections::HashMap,
+        ops::{Add, Sub},
+        time::Duration,
+    };
+    use time::OffsetDateTime;
+    use uuid::{uuid, Uuid};
+
+    fn create_data_revision(
+        id: Uuid,
+        tracker_id: Uuid,
         time_shift: i64,
     ) -> anyhow::Result<TrackerDataRevision> {
         Ok(TrackerDataRevision {
@@ -448,6 +1529,8 @@ mod tests {
             tracker_id,
             created_at: OffsetDateTime::from_unix_timestamp(946720800 + time_shift)?,
             data: TrackerDataValue::new(json!("some-data")),
+            provenance: None,
+            sequence: None,
         })
     }
 
@@ -517,15 +1600,16 @@ mod tests {
             .unwrap();
         assert_debug_snapshot!(
             insert_error.root_cause.to_string(),
-            @r###""Tracker with such name ('some-other-name') or id ('00000000-0000-0000-0000-000000000001') already exists.""###
+            @r###""Tracker with such id ('00000000-0000-0000-0000-000000000001') already exists.""###
         );
         assert_debug_snapshot!(
             to_database_error(insert_error.root_cause)?.message(),
             @r###""duplicate key value violates unique constraint \"trackers_pkey\"""###
         );
 
-        // Tracker with the same name, but different ID should not be allowed.
-        let insert_error = trackers
+        // Tracker with the same name, but different ID is allowed (name uniqueness is enforced
+        // by the API according to the configuration).
+        let insert_result = trackers
             .insert_tracker(
                 &MockTrackerBuilder::create(
                     uuid!("00000000-0000-0000-0000-000000000002"),
@@ -534,18 +1618,8 @@ mod tests {
                 )?
                 .build(),
             )
-            .await
-            .unwrap_err()
-            .downcast::<RetrackError>()
-            .unwrap();
-        assert_debug_snapshot!(
-            insert_error.root_cause.to_string(),
-            @r###""Tracker with such name ('some-name') or id ('00000000-0000-0000-0000-000000000002') already exists.""###
-        );
-        assert_debug_snapshot!(
-            to_database_error(insert_error.root_cause)?.message(),
-            @r###""duplicate key value violates unique constraint \"trackers_name_key\"""###
-        );
+            .await;
+        assert!(insert_result.is_ok());
 
         // Tracker with different name should be allowed.
         let insert_result = trackers
@@ -610,77 +1684,339 @@ mod tests {
             )
             .await?;
 
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?
-            .unwrap();
-        assert_eq!(
-            tracker,
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?
+            .unwrap();
+        assert_eq!(
+            tracker,
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                "some-name-2",
+                5,
+            )?
+            .build()
+        );
+
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
+            .await?
+            .unwrap();
+        assert_eq!(
+            tracker,
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                "some-other-name-2",
+                5,
+            )?
+            .build()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn correctly_handles_non_existent_trackers_on_update(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+        let update_error = db
+            .trackers()
+            .update_tracker(
+                &MockTrackerBuilder::create(
+                    uuid!("00000000-0000-0000-0000-000000000001"),
+                    "some-name-2",
+                    5,
+                )?
+                .build(),
+            )
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(
+            update_error,
+            @r###""Tracker ('some-name-2') doesn't exist.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_remove_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let mut trackers_list = vec![
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                "some-name",
+                3,
+            )?
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                "some-name-2",
+                3,
+            )?
+            .build(),
+        ];
+
+        let trackers = db.trackers();
+        for tracker in trackers_list.iter() {
+            trackers.insert_tracker(tracker).await?;
+        }
+
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?
+            .unwrap();
+        assert_eq!(tracker, trackers_list.remove(0));
+
+        let tracker_2 = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
+            .await?
+            .unwrap();
+        assert_eq!(tracker_2, trackers_list[0].clone());
+
+        trackers
+            .remove_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?;
+
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?;
+        assert!(tracker.is_none());
+
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
+            .await?
+            .unwrap();
+        assert_eq!(tracker, trackers_list.remove(0));
+
+        trackers
+            .remove_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
+            .await?;
+
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?;
+        assert!(tracker.is_none());
+
+        let tracker = trackers
+            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
+            .await?;
+        assert!(tracker.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_retrieve_all_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let trackers_list = vec![
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                "some-name",
+                3,
+            )?
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000004"),
+                "some-name-2",
+                3,
+            )?
+            .build(),
+        ];
+
+        let trackers = db.trackers();
+        for tracker in trackers_list.iter() {
+            trackers.insert_tracker(tracker).await?;
+        }
+
+        assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_bulk_remove_all_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let trackers_list = vec![
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                "some-name",
+                3,
+            )?
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000004"),
+                "some-name-2",
+                3,
+            )?
+            .build(),
+        ];
+
+        let trackers = db.trackers();
+        for tracker in trackers_list.iter() {
+            trackers.insert_tracker(tracker).await?;
+        }
+
+        assert_eq!(trackers.remove_trackers(&[]).await?, 2);
+        assert!(trackers.get_trackers(&[]).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_retrieve_trackers_by_tags(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let trackers_list = vec![
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                "some-name",
+                3,
+            )?
+            .with_tags(vec!["tag:1".to_string()])
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                "some-name-2",
+                3,
+            )?
+            .with_tags(vec!["tag:1".to_string(), "tag:2".to_string()])
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                "some-name-3",
+                3,
+            )?
+            .build(),
+        ];
+
+        let trackers = db.trackers();
+        for tracker in trackers_list.iter() {
+            trackers.insert_tracker(tracker).await?;
+        }
+
+        assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
+        assert_eq!(
+            trackers.get_trackers(&["tag:1".to_string()]).await?,
+            vec![trackers_list[0].clone(), trackers_list[1].clone()]
+        );
+        assert_eq!(
+            trackers.get_trackers(&["tag:2".to_string()]).await?,
+            vec![trackers_list[1].clone()]
+        );
+        assert_eq!(
+            trackers
+                .get_trackers(&["tag:1".to_string(), "tag:2".to_string()])
+                .await?,
+            vec![trackers_list[1].clone()]
+        );
+        assert!(trackers
+            .get_trackers(&[
+                "tag:1".to_string(),
+                "tag:2".to_string(),
+                "tag:3".to_string()
+            ])
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_retrieve_trackers_by_name(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let trackers_list = vec![
             MockTrackerBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
-                "some-name-2",
-                5,
+                "some-name",
+                3,
             )?
-            .build()
-        );
-
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
-            .await?
-            .unwrap();
-        assert_eq!(
-            tracker,
+            .with_tags(vec!["tag:1".to_string()])
+            .build(),
             MockTrackerBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000002"),
-                "some-other-name-2",
-                5,
+                "Some-Name",
+                3,
             )?
-            .build()
-        );
+            .with_tags(vec!["tag:1".to_string(), "tag:2".to_string()])
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                "some-name-3",
+                3,
+            )?
+            .with_tags(vec!["tag:1".to_string()])
+            .build(),
+        ];
 
-        Ok(())
-    }
+        let trackers = db.trackers();
+        for tracker in trackers_list.iter() {
+            trackers.insert_tracker(tracker).await?;
+        }
 
-    #[sqlx::test]
-    async fn correctly_handles_non_existent_trackers_on_update(pool: PgPool) -> anyhow::Result<()> {
-        let db = Database::create(pool).await?;
-        let update_error = db
-            .trackers()
-            .update_tracker(
-                &MockTrackerBuilder::create(
-                    uuid!("00000000-0000-0000-0000-000000000001"),
-                    "some-name-2",
-                    5,
-                )?
-                .build(),
-            )
-            .await
-            .unwrap_err()
-            .downcast::<RetrackError>()?;
-        assert_debug_snapshot!(
-            update_error,
-            @r###""Tracker ('some-name-2') doesn't exist.""###
+        assert_eq!(
+            trackers.get_trackers_by_name("SOME-NAME", &[]).await?,
+            vec![trackers_list[0].clone(), trackers_list[1].clone()]
+        );
+        assert_eq!(
+            trackers
+                .get_trackers_by_name("some-name", &["tag:2".to_string()])
+                .await?,
+            vec![trackers_list[1].clone()]
+        );
+        assert_eq!(
+            trackers
+                .get_trackers_by_name("some-name-3", &["tag:1".to_string()])
+                .await?,
+            vec![trackers_list[2].clone()]
         );
+        assert!(trackers
+            .get_trackers_by_name("some-name-3", &["tag:2".to_string()])
+            .await?
+            .is_empty());
+        assert!(trackers
+            .get_trackers_by_name("unknown-name", &[])
+            .await?
+            .is_empty());
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_remove_trackers(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_remove_trackers_by_tags(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let mut trackers_list = vec![
+        let trackers_list = vec![
             MockTrackerBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
                 3,
             )?
+            .with_tags(vec!["tag:1".to_string()])
             .build(),
             MockTrackerBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000002"),
                 "some-name-2",
                 3,
             )?
+            .with_tags(vec!["tag:1".to_string(), "tag:2".to_string()])
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                "some-name-3",
+                3,
+            )?
+            .with_tags(vec!["tag:3".to_string()])
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000004"),
+                "some-name-4",
+                3,
+            )?
             .build(),
         ];
 
@@ -689,63 +2025,44 @@ mod tests {
             trackers.insert_tracker(tracker).await?;
         }
 
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?
-            .unwrap();
-        assert_eq!(tracker, trackers_list.remove(0));
-
-        let tracker_2 = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
-            .await?
-            .unwrap();
-        assert_eq!(tracker_2, trackers_list[0].clone());
-
-        trackers
-            .remove_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?;
-
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?;
-        assert!(tracker.is_none());
-
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
-            .await?
-            .unwrap();
-        assert_eq!(tracker, trackers_list.remove(0));
+        assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
+        assert_eq!(trackers.remove_trackers(&["tag:1".to_string()]).await?, 2);
+        assert_eq!(
+            trackers.get_trackers(&[]).await?,
+            vec![trackers_list[2].clone(), trackers_list[3].clone()]
+        );
 
-        trackers
-            .remove_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
-            .await?;
+        assert_eq!(trackers.remove_trackers(&["tag:2".to_string()]).await?, 0);
+        assert_eq!(
+            trackers.get_trackers(&[]).await?,
+            vec![trackers_list[2].clone(), trackers_list[3].clone()]
+        );
 
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?;
-        assert!(tracker.is_none());
+        assert_eq!(trackers.remove_trackers(&["tag:3".to_string()]).await?, 1);
+        assert_eq!(
+            trackers.get_trackers(&[]).await?,
+            vec![trackers_list[3].clone()]
+        );
 
-        let tracker = trackers
-            .get_tracker(uuid!("00000000-0000-0000-0000-000000000002"))
-            .await?;
-        assert!(tracker.is_none());
+        assert_eq!(trackers.remove_trackers(&[]).await?, 1);
+        assert!(trackers.get_trackers(&[]).await?.is_empty());
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_retrieve_all_trackers(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_add_and_retrieve_tracker_data(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
         let trackers_list = vec![
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000003"),
+                uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
                 3,
             )?
             .build(),
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000004"),
+                uuid!("00000000-0000-0000-0000-000000000002"),
                 "some-name-2",
                 3,
             )?
@@ -757,24 +2074,70 @@ mod tests {
             trackers.insert_tracker(tracker).await?;
         }
 
-        assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
+        // No data yet.
+        for tracker in trackers_list.iter() {
+            assert!(trackers.get_tracker_data(tracker.id).await?.is_empty());
+        }
+
+        let mut revisions = vec![
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                trackers_list[0].id,
+                0,
+            )?,
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                trackers_list[0].id,
+                1,
+            )?,
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                trackers_list[1].id,
+                0,
+            )?,
+        ];
+        for revision in revisions.iter_mut() {
+            revision.sequence = Some(trackers.insert_tracker_data_revision(revision).await?);
+        }
+
+        assert_eq!(
+            revisions
+                .iter()
+                .map(|revision| revision.sequence)
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(1)]
+        );
+
+        let tracker_one_data = trackers.get_tracker_data(trackers_list[0].id).await?;
+        assert_eq!(
+            tracker_one_data,
+            vec![revisions.remove(0), revisions.remove(0)]
+        );
+
+        let tracker_two_data = trackers.get_tracker_data(trackers_list[1].id).await?;
+        assert_eq!(tracker_two_data, vec![revisions.remove(0)]);
+
+        assert!(trackers
+            .get_tracker_data(uuid!("00000000-0000-0000-0000-000000000004"))
+            .await?
+            .is_empty());
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_bulk_remove_all_trackers(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_remove_tracker_data(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
         let trackers_list = vec![
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000003"),
+                uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
                 3,
             )?
             .build(),
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000004"),
+                uuid!("00000000-0000-0000-0000-000000000002"),
                 "some-name-2",
                 3,
             )?
@@ -786,14 +2149,69 @@ mod tests {
             trackers.insert_tracker(tracker).await?;
         }
 
-        assert_eq!(trackers.remove_trackers(&[]).await?, 2);
-        assert!(trackers.get_trackers(&[]).await?.is_empty());
+        let mut revisions = vec![
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                trackers_list[0].id,
+                0,
+            )?,
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                trackers_list[0].id,
+                1,
+            )?,
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                trackers_list[1].id,
+                0,
+            )?,
+        ];
+        for revision in revisions.iter_mut() {
+            revision.sequence = Some(trackers.insert_tracker_data_revision(revision).await?);
+        }
+
+        let tracker_data = trackers.get_tracker_data(trackers_list[0].id).await?;
+        assert_eq!(
+            tracker_data,
+            vec![revisions[0].clone(), revisions[1].clone()]
+        );
+
+        let tracker_data = trackers.get_tracker_data(trackers_list[1].id).await?;
+        assert_eq!(tracker_data, vec![revisions[2].clone()]);
+
+        // Remove one revision.
+        trackers
+            .remove_tracker_data_revision(trackers_list[0].id, revisions[0].id)
+            .await?;
+
+        let tracker_data = trackers.get_tracker_data(trackers_list[0].id).await?;
+        assert_eq!(tracker_data, vec![revisions[1].clone()]);
+
+        let tracker_data = trackers.get_tracker_data(trackers_list[1].id).await?;
+        assert_eq!(tracker_data, vec![revisions[2].clone()]);
+
+        // Remove the rest of revisions.
+        trackers
+            .remove_tracker_data_revision(trackers_list[0].id, revisions[1].id)
+            .await?;
+        trackers
+            .remove_tracker_data_revision(trackers_list[1].id, revisions[2].id)
+            .await?;
+
+        assert!(trackers
+            .get_tracker_data(trackers_list[0].id)
+            .await?
+            .is_empty());
+        assert!(trackers
+            .get_tracker_data(trackers_list[1].id)
+            .await?
+            .is_empty());
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_retrieve_trackers_by_tags(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_clear_all_data_revisions_at_once(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
         let trackers_list = vec![
@@ -802,20 +2220,12 @@ mod tests {
                 "some-name",
                 3,
             )?
-            .with_tags(vec!["tag:1".to_string()])
             .build(),
             MockTrackerBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000002"),
                 "some-name-2",
                 3,
             )?
-            .with_tags(vec!["tag:1".to_string(), "tag:2".to_string()])
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000003"),
-                "some-name-3",
-                3,
-            )?
             .build(),
         ];
 
@@ -824,99 +2234,151 @@ mod tests {
             trackers.insert_tracker(tracker).await?;
         }
 
-        assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
-        assert_eq!(
-            trackers.get_trackers(&["tag:1".to_string()]).await?,
-            vec![trackers_list[0].clone(), trackers_list[1].clone()]
-        );
-        assert_eq!(
-            trackers.get_trackers(&["tag:2".to_string()]).await?,
-            vec![trackers_list[1].clone()]
-        );
+        let mut revisions = vec![
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000001"),
+                trackers_list[0].id,
+                0,
+            )?,
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                trackers_list[0].id,
+                1,
+            )?,
+            create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                trackers_list[1].id,
+                0,
+            )?,
+        ];
+        for revision in revisions.iter_mut() {
+            revision.sequence = Some(trackers.insert_tracker_data_revision(revision).await?);
+        }
+
+        let tracker_data = trackers.get_tracker_data(trackers_list[0].id).await?;
         assert_eq!(
-            trackers
-                .get_trackers(&["tag:1".to_string(), "tag:2".to_string()])
-                .await?,
-            vec![trackers_list[1].clone()]
+            tracker_data,
+            vec![revisions[0].clone(), revisions[1].clone()]
         );
+
+        let tracker_data = trackers.get_tracker_data(trackers_list[1].id).await?;
+        assert_eq!(tracker_data, vec![revisions[2].clone()]);
+
+        // Clear all revisions.
+        trackers.clear_tracker_data(trackers_list[0].id).await?;
+        trackers.clear_tracker_data(trackers_list[1].id).await?;
+
         assert!(trackers
-            .get_trackers(&[
-                "tag:1".to_string(),
-                "tag:2".to_string(),
-                "tag:3".to_string()
-            ])
+            .get_tracker_data(trackers_list[0].id)
+            .await?
+            .is_empty());
+        assert!(trackers
+            .get_tracker_data(trackers_list[1].id)
             .await?
             .is_empty());
 
+        // Sequence numbers aren't reused even if revisions are cleared.
+        let revision = create_data_revision(
+            uuid!("00000000-0000-0000-0000-000000000004"),
+            trackers_list[0].id,
+            2,
+        )?;
+        assert_eq!(trackers.insert_tracker_data_revision(&revision).await?, 3);
+
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_remove_trackers_by_tags(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_retrieve_all_unscheduled_trackers(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let trackers_list = vec![
+        let mut trackers_list: Vec<Tracker> = vec![
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000001"),
+                uuid!("00000000-0000-0000-0000-000000000006"),
                 "some-name",
                 3,
             )?
-            .with_tags(vec!["tag:1".to_string()])
+            .with_schedule("* * * * *")
             .build(),
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000002"),
+                uuid!("00000000-0000-0000-0000-000000000007"),
                 "some-name-2",
                 3,
             )?
-            .with_tags(vec!["tag:1".to_string(), "tag:2".to_string()])
+            .with_schedule("* * * * *")
             .build(),
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000003"),
+                uuid!("00000000-0000-0000-0000-000000000008"),
                 "some-name-3",
                 3,
             )?
-            .with_tags(vec!["tag:3".to_string()])
+            .with_schedule("* * * * *")
             .build(),
             MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000004"),
+                uuid!("00000000-0000-0000-0000-000000000009"),
                 "some-name-4",
                 3,
             )?
             .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000010"),
+                "some-name-5",
+                0,
+            )?
+            .with_schedule("* * * * *")
+            .build(),
         ];
 
         let trackers = db.trackers();
-        for tracker in trackers_list.iter() {
+        for (index, tracker) in trackers_list.iter_mut().enumerate() {
+            tracker.updated_at = tracker
+                .updated_at
+                .checked_add(Duration::from_secs(index as u64).try_into()?)
+                .unwrap();
             trackers.insert_tracker(tracker).await?;
         }
 
         assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
-        assert_eq!(trackers.remove_trackers(&["tag:1".to_string()]).await?, 2);
+
+        let trackers = db.trackers();
         assert_eq!(
-            trackers.get_trackers(&[]).await?,
-            vec![trackers_list[2].clone(), trackers_list[3].clone()]
+            trackers.get_trackers_to_schedule().await?,
+            vec![
+                trackers_list[0].clone(),
+                trackers_list[1].clone(),
+                trackers_list[2].clone()
+            ]
         );
 
-        assert_eq!(trackers.remove_trackers(&["tag:2".to_string()]).await?, 0);
+        trackers
+            .update_tracker_job(
+                trackers_list[1].id,
+                Some(uuid!("00000000-0000-0000-0000-000000000002")),
+            )
+            .await?;
         assert_eq!(
             trackers.get_trackers(&[]).await?,
-            vec![trackers_list[2].clone(), trackers_list[3].clone()]
+            vec![
+                trackers_list[0].clone(),
+                Tracker {
+                    job_id: Some(uuid!("00000000-0000-0000-0000-000000000002")),
+                    ..trackers_list[1].clone()
+                },
+                trackers_list[2].clone(),
+                trackers_list[3].clone(),
+                trackers_list[4].clone(),
+            ]
         );
-
-        assert_eq!(trackers.remove_trackers(&["tag:3".to_string()]).await?, 1);
         assert_eq!(
-            trackers.get_trackers(&[]).await?,
-            vec![trackers_list[3].clone()]
+            trackers.get_trackers_to_schedule().await?,
+            vec![trackers_list[0].clone(), trackers_list[2].clone()]
         );
 
-        assert_eq!(trackers.remove_trackers(&[]).await?, 1);
-        assert!(trackers.get_trackers(&[]).await?.is_empty());
-
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_add_and_retrieve_tracker_data(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_retrieve_tracker_by_job_id(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
         let trackers_list = vec![
@@ -925,12 +2387,23 @@ mod tests {
                 "some-name",
                 3,
             )?
+            .with_schedule("* * * * *")
+            .with_job_id(uuid!("00000000-0000-0000-0000-000000000011"))
             .build(),
             MockTrackerBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000002"),
                 "some-name-2",
                 3,
             )?
+            .with_schedule("* * * * *")
+            .with_job_id(uuid!("00000000-0000-0000-0000-000000000022"))
+            .build(),
+            MockTrackerBuilder::create(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                "some-name-3",
+                3,
+            )?
+            .with_schedule("* * * * *")
             .build(),
         ];
 
@@ -939,586 +2412,892 @@ mod tests {
             trackers.insert_tracker(tracker).await?;
         }
 
-        // No data yet.
-        for tracker in trackers_list.iter() {
-            assert!(trackers.get_tracker_data(tracker.id).await?.is_empty());
-        }
+        let trackers = db.trackers();
+        assert_eq!(
+            trackers
+                .get_tracker_by_job_id(uuid!("00000000-0000-0000-0000-000000000011"))
+                .await?,
+            Some(trackers_list[0].clone())
+        );
+        assert_eq!(
+            trackers
+                .get_tracker_by_job_id(uuid!("00000000-0000-0000-0000-000000000022"))
+                .await?,
+            Some(trackers_list[1].clone())
+        );
 
-        let mut revisions = vec![
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000001"),
-                trackers_list[0].id,
-                0,
-            )?,
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                trackers_list[0].id,
-                1,
-            )?,
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000003"),
-                trackers_list[1].id,
-                0,
-            )?,
-        ];
-        for revision in revisions.iter() {
-            trackers.insert_tracker_data_revision(revision).await?;
-        }
+        Ok(())
+    }
 
-        let tracker_one_data = trackers.get_tracker_data(trackers_list[0].id).await?;
+    #[sqlx::test]
+    async fn can_update_trackers_job_id(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .with_schedule("* * * * *")
+        .build();
+
+        let trackers = db.trackers();
+        trackers.insert_tracker(&tracker).await?;
+
+        assert_eq!(
+            trackers.get_tracker(tracker.id).await?.unwrap().job_id,
+            None
+        );
+
+        let trackers = db.trackers();
+        trackers
+            .update_tracker_job(
+                tracker.id,
+                Some(uuid!("00000000-0000-0000-0000-000000000011")),
+            )
+            .await?;
+        assert_eq!(
+            trackers.get_tracker(tracker.id).await?.unwrap().job_id,
+            Some(uuid!("00000000-0000-0000-0000-000000000011"))
+        );
+
+        trackers
+            .update_tracker_job(
+                tracker.id,
+                Some(uuid!("00000000-0000-0000-0000-000000000022")),
+            )
+            .await?;
+        assert_eq!(
+            trackers.get_tracker(tracker.id).await?.unwrap().job_id,
+            Some(uuid!("00000000-0000-0000-0000-000000000022"))
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_update_trackers_job_id_if_needed(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .build();
+
+        let update_and_fail = |result: anyhow::Result<_>| -> RetrackError {
+            result.unwrap_err().downcast::<RetrackError>().unwrap()
+        };
+
+        // Non-existent tracker
+        let update_result = update_and_fail(
+            db.trackers()
+                .update_tracker_job(
+                    tracker.id,
+                    Some(uuid!("00000000-0000-0000-0000-000000000011")),
+                )
+                .await,
+        );
         assert_eq!(
-            tracker_one_data,
-            vec![revisions.remove(0), revisions.remove(0)]
+            update_result.to_string(),
+            format!("Tracker ('{}') doesn't exist.", tracker.id)
         );
 
-        let tracker_two_data = trackers.get_tracker_data(trackers_list[1].id).await?;
-        assert_eq!(tracker_two_data, vec![revisions.remove(0)]);
-
-        assert!(trackers
-            .get_tracker_data(uuid!("00000000-0000-0000-0000-000000000004"))
-            .await?
-            .is_empty());
-
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_remove_tracker_data(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_return_tracker_with_pending_jobs(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let trackers_list = vec![
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000001"),
-                "some-name",
-                3,
-            )?
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                "some-name-2",
-                3,
-            )?
-            .build(),
-        ];
+        let pending_trackers = db
+            .trackers()
+            .get_trackers_to_run(10)
+            .collect::<Vec<_>>()
+            .await;
+        assert!(pending_trackers.is_empty());
 
-        let trackers = db.trackers();
-        for tracker in trackers_list.iter() {
-            trackers.insert_tracker(tracker).await?;
-        }
+        for n in 0..=2 {
+            let job = RawSchedulerJobStoredData {
+                last_updated: Some(946720800 + n),
+                last_tick: Some(946720700),
+                next_tick: Some(946720900),
+                ran: Some(true),
+                count: Some(n as i32),
+                stopped: Some(n != 1),
+                ..mock_scheduler_job(
+                    Uuid::parse_str(&format!("68e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
+                    SchedulerJob::TrackersTrigger,
+                    format!("{} 0 0 1 1 * *", n),
+                )
+            };
 
-        let revisions = vec![
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000001"),
-                trackers_list[0].id,
-                0,
-            )?,
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                trackers_list[0].id,
-                1,
-            )?,
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000003"),
-                trackers_list[1].id,
-                0,
-            )?,
-        ];
-        for revision in revisions.iter() {
-            trackers.insert_tracker_data_revision(revision).await?;
+            mock_upsert_scheduler_job(&db, &job).await?;
         }
 
-        let tracker_data = trackers.get_tracker_data(trackers_list[0].id).await?;
-        assert_eq!(
-            tracker_data,
-            vec![revisions[0].clone(), revisions[1].clone()]
-        );
-
-        let tracker_data = trackers.get_tracker_data(trackers_list[1].id).await?;
-        assert_eq!(tracker_data, vec![revisions[2].clone()]);
-
-        // Remove one revision.
-        trackers
-            .remove_tracker_data_revision(trackers_list[0].id, revisions[0].id)
-            .await?;
-
-        let tracker_data = trackers.get_tracker_data(trackers_list[0].id).await?;
-        assert_eq!(tracker_data, vec![revisions[1].clone()]);
-
-        let tracker_data = trackers.get_tracker_data(trackers_list[1].id).await?;
-        assert_eq!(tracker_data, vec![revisions[2].clone()]);
-
-        // Remove the rest of revisions.
-        trackers
-            .remove_tracker_data_revision(trackers_list[0].id, revisions[1].id)
-            .await?;
-        trackers
-            .remove_tracker_data_revision(trackers_list[1].id, revisions[2].id)
-            .await?;
+        for n in 0..=2 {
+            db.trackers()
+                .insert_tracker(
+                    &MockTrackerBuilder::create(
+                        Uuid::parse_str(&format!("78e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
+                        format!("name_{}", n),
+                        3,
+                    )?
+                    .with_schedule("0 0 * * * *")
+                    .with_job_id(Uuid::parse_str(&format!(
+                        "68e55044-10b1-426f-9247-bb680e5fe0c{}",
+                        n
+                    ))?)
+                    .build(),
+                )
+                .await?;
+        }
 
-        assert!(trackers
-            .get_tracker_data(trackers_list[0].id)
-            .await?
-            .is_empty());
-        assert!(trackers
-            .get_tracker_data(trackers_list[1].id)
-            .await?
-            .is_empty());
+        let pending_trackers = db
+            .trackers()
+            .get_trackers_to_run(10)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(pending_trackers.len(), 2);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_clear_all_data_revisions_at_once(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_return_tracker_with_pending_jobs_with_retry(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let trackers_list = vec![
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000001"),
-                "some-name",
-                3,
-            )?
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                "some-name-2",
-                3,
-            )?
-            .build(),
-        ];
+        let pending_trackers = db
+            .trackers()
+            .get_trackers_to_run(10)
+            .collect::<Vec<_>>()
+            .await;
+        assert!(pending_trackers.is_empty());
 
-        let trackers = db.trackers();
-        for tracker in trackers_list.iter() {
-            trackers.insert_tracker(tracker).await?;
+        for n in 0..=2 {
+            let job = RawSchedulerJobStoredData {
+                last_updated: Some(946720800 + n),
+                last_tick: Some(946720700),
+                next_tick: Some(946720900),
+                ran: Some(true),
+                count: Some(n as i32),
+                stopped: Some(n != 1),
+                extra: Some(
+                    if n == 2 {
+                        SchedulerJobMetadata {
+                            job_type: SchedulerJob::TrackersTrigger,
+                            retry: Some(SchedulerJobRetryState {
+                                attempts: 1,
+                                next_at: OffsetDateTime::now_utc().add(Duration::from_secs(3600)),
+                            }),
+                        }
+                    } else {
+                        SchedulerJobMetadata::new(SchedulerJob::TrackersTrigger)
+                    }
+                    .try_into()?,
+                ),
+                ..mock_scheduler_job(
+                    Uuid::parse_str(&format!("67e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
+                    SchedulerJob::TrackersTrigger,
+                    format!("{} 0 0 1 1 * *", n),
+                )
+            };
+
+            mock_upsert_scheduler_job(&db, &job).await?;
         }
 
-        let revisions = vec![
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000001"),
-                trackers_list[0].id,
-                0,
-            )?,
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                trackers_list[0].id,
-                1,
-            )?,
-            create_data_revision(
-                uuid!("00000000-0000-0000-0000-000000000003"),
-                trackers_list[1].id,
-                0,
-            )?,
-        ];
-        for revision in revisions.iter() {
-            trackers.insert_tracker_data_revision(revision).await?;
+        for n in 0..=2 {
+            db.trackers()
+                .insert_tracker(
+                    &MockTrackerBuilder::create(
+                        Uuid::parse_str(&format!("77e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
+                        format!("name_{}", n),
+                        3,
+                    )?
+                    .with_schedule("0 0 * * * *")
+                    .with_job_id(Uuid::parse_str(&format!(
+                        "67e55044-10b1-426f-9247-bb680e5fe0c{}",
+                        n
+                    ))?)
+                    .build(),
+                )
+                .await?;
         }
 
-        let tracker_data = trackers.get_tracker_data(trackers_list[0].id).await?;
+        let mut pending_trackers = db
+            .trackers()
+            .get_trackers_to_run(10)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(pending_trackers.len(), 1);
+
+        let tracker = pending_trackers.remove(0)?;
+        assert_eq!(tracker.id, uuid!("77e55044-10b1-426f-9247-bb680e5fe0c0"));
+        assert_eq!(
+            tracker.job_id,
+            Some(uuid!("67e55044-10b1-426f-9247-bb680e5fe0c0"))
+        );
+
+        db.update_scheduler_job_meta(
+            uuid!("67e55044-10b1-426f-9247-bb680e5fe0c2"),
+            SchedulerJobMetadata {
+                job_type: SchedulerJob::TrackersTrigger,
+                retry: Some(SchedulerJobRetryState {
+                    attempts: 1,
+                    next_at: OffsetDateTime::now_utc().sub(Duration::from_secs(3600)),
+                }),
+            },
+        )
+        .await?;
+
+        let mut pending_trackers = db
+            .trackers()
+            .get_trackers_to_run(10)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(pending_trackers.len(), 2);
+
+        let tracker = pending_trackers.remove(0)?;
+        assert_eq!(tracker.id, uuid!("77e55044-10b1-426f-9247-bb680e5fe0c0"));
         assert_eq!(
-            tracker_data,
-            vec![revisions[0].clone(), revisions[1].clone()]
+            tracker.job_id,
+            Some(uuid!("67e55044-10b1-426f-9247-bb680e5fe0c0"))
         );
 
-        let tracker_data = trackers.get_tracker_data(trackers_list[1].id).await?;
-        assert_eq!(tracker_data, vec![revisions[2].clone()]);
-
-        // Clear all revisions.
-        trackers.clear_tracker_data(trackers_list[0].id).await?;
-        trackers.clear_tracker_data(trackers_list[1].id).await?;
-
-        assert!(trackers
-            .get_tracker_data(trackers_list[0].id)
-            .await?
-            .is_empty());
-        assert!(trackers
-            .get_tracker_data(trackers_list[1].id)
-            .await?
-            .is_empty());
+        let tracker = pending_trackers.remove(0)?;
+        assert_eq!(tracker.id, uuid!("77e55044-10b1-426f-9247-bb680e5fe0c2"));
+        assert_eq!(
+            tracker.job_id,
+            Some(uuid!("67e55044-10b1-426f-9247-bb680e5fe0c2"))
+        );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_retrieve_all_unscheduled_trackers(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_retrieve_trackers_activity(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let mut trackers_list: Vec<Tracker> = vec![
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000006"),
-                "some-name",
-                3,
-            )?
-            .with_schedule("* * * * *")
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000007"),
-                "some-name-2",
-                3,
-            )?
-            .with_schedule("* * * * *")
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000008"),
-                "some-name-3",
-                3,
-            )?
-            .with_schedule("* * * * *")
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000009"),
-                "some-name-4",
-                3,
-            )?
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000010"),
-                "some-name-5",
-                0,
-            )?
-            .with_schedule("* * * * *")
-            .build(),
-        ];
-
         let trackers = db.trackers();
-        for (index, tracker) in trackers_list.iter_mut().enumerate() {
-            tracker.updated_at = tracker
-                .updated_at
-                .checked_add(Duration::from_secs(index as u64).try_into()?)
-                .unwrap();
-            trackers.insert_tracker(tracker).await?;
+        assert!(trackers.get_trackers_activity().await?.is_empty());
+
+        let tracker_one = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .with_schedule("0 0 * * * *")
+        .with_job_id(uuid!("00000000-0000-0000-0000-000000000011"))
+        .build();
+        let tracker_two = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-other-name",
+            3,
+        )?
+        .build();
+        trackers.insert_tracker(&tracker_one).await?;
+        trackers.insert_tracker(&tracker_two).await?;
+
+        for (id, time_shift) in [
+            (uuid!("00000000-0000-0000-0000-000000000021"), 10),
+            (uuid!("00000000-0000-0000-0000-000000000022"), 20),
+        ] {
+            trackers
+                .insert_tracker_data_revision(&create_data_revision(
+                    id,
+                    tracker_one.id,
+                    time_shift,
+                )?)
+                .await?;
         }
 
-        assert_eq!(trackers.get_trackers(&[]).await?, trackers_list);
+        mock_upsert_scheduler_job(
+            &db,
+            &RawSchedulerJobStoredData {
+                last_tick: Some(946720700),
+                ..mock_scheduler_job(
+                    uuid!("00000000-0000-0000-0000-000000000011"),
+                    SchedulerJob::TrackersTrigger,
+                    "0 0 * * * *",
+                )
+            },
+        )
+        .await?;
 
-        let trackers = db.trackers();
         assert_eq!(
-            trackers.get_trackers_to_schedule().await?,
+            trackers.get_trackers_activity().await?,
             vec![
-                trackers_list[0].clone(),
-                trackers_list[1].clone(),
-                trackers_list[2].clone()
+                TrackerActivity {
+                    tracker_id: tracker_one.id,
+                    last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720820)?),
+                    last_run_at: Some(OffsetDateTime::from_unix_timestamp(946720700)?),
+                },
+                TrackerActivity {
+                    tracker_id: tracker_two.id,
+                    last_revision_at: None,
+                    last_run_at: None,
+                },
             ]
         );
 
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_retrieve_trackers_stats(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let trackers = db.trackers();
+        let tracker_one = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .build();
+        let tracker_two = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-other-name",
+            3,
+        )?
+        .build();
+        trackers.insert_tracker(&tracker_one).await?;
+        trackers.insert_tracker(&tracker_two).await?;
+        assert!(trackers
+            .get_trackers_stats(&[tracker_one.id, tracker_two.id])
+            .await?
+            .is_empty());
+
+        // The last revision has the same data as the previous one (e.g., only provenance changed).
+        for (id, time_shift, data) in [
+            (uuid!("00000000-0000-0000-0000-000000000021"), 10, "one"),
+            (uuid!("00000000-0000-0000-0000-000000000022"), 20, "two"),
+            (uuid!("00000000-0000-0000-0000-000000000023"), 30, "two"),
+        ] {
+            trackers
+                .insert_tracker_data_revision(&TrackerDataRevision {
+                    data: TrackerDataValue::new(json!(data)),
+                    ..create_data_revision(id, tracker_one.id, time_shift)?
+                })
+                .await?;
+        }
         trackers
-            .update_tracker_job(
-                trackers_list[1].id,
-                Some(uuid!("00000000-0000-0000-0000-000000000002")),
-            )
+            .insert_tracker_data_revision(&create_data_revision(
+                uuid!("00000000-0000-0000-0000-000000000024"),
+                tracker_two.id,
+                40,
+            )?)
             .await?;
+
         assert_eq!(
-            trackers.get_trackers(&[]).await?,
-            vec![
-                trackers_list[0].clone(),
-                Tracker {
-                    job_id: Some(uuid!("00000000-0000-0000-0000-000000000002")),
-                    ..trackers_list[1].clone()
-                },
-                trackers_list[2].clone(),
-                trackers_list[3].clone(),
-                trackers_list[4].clone(),
-            ]
+            trackers
+                .get_trackers_stats(&[tracker_one.id, tracker_two.id])
+                .await?,
+            HashMap::from_iter([
+                (
+                    tracker_one.id,
+                    TrackerStats {
+                        revisions: 3,
+                        last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720830)?),
+                        last_change_at: Some(OffsetDateTime::from_unix_timestamp(946720820)?),
+                    }
+                ),
+                (
+                    tracker_two.id,
+                    TrackerStats {
+                        revisions: 1,
+                        last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720840)?),
+                        last_change_at: Some(OffsetDateTime::from_unix_timestamp(946720840)?),
+                    }
+                )
+            ])
         );
+
         assert_eq!(
-            trackers.get_trackers_to_schedule().await?,
-            vec![trackers_list[0].clone(), trackers_list[2].clone()]
+            trackers.get_trackers_stats(&[tracker_two.id]).await?,
+            HashMap::from_iter([(
+                tracker_two.id,
+                TrackerStats {
+                    revisions: 1,
+                    last_revision_at: Some(OffsetDateTime::from_unix_timestamp(946720840)?),
+                    last_change_at: Some(OffsetDateTime::from_unix_timestamp(946720840)?),
+                }
+            )])
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_retrieve_tracker_by_job_id(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_add_retrieve_and_remove_tracker_alerts(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let trackers_list = vec![
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000001"),
-                "some-name",
-                3,
-            )?
-            .with_schedule("* * * * *")
-            .with_job_id(uuid!("00000000-0000-0000-0000-000000000011"))
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                "some-name-2",
-                3,
-            )?
-            .with_schedule("* * * * *")
-            .with_job_id(uuid!("00000000-0000-0000-0000-000000000022"))
-            .build(),
-            MockTrackerBuilder::create(
-                uuid!("00000000-0000-0000-0000-000000000003"),
-                "some-name-3",
-                3,
-            )?
-            .with_schedule("* * * * *")
-            .build(),
-        ];
-
         let trackers = db.trackers();
-        for tracker in trackers_list.iter() {
-            trackers.insert_tracker(tracker).await?;
+        let tracker_one = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .with_tags(vec!["vendor".to_string()])
+        .build();
+        let tracker_two = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-other-name",
+            3,
+        )?
+        .build();
+        trackers.insert_tracker(&tracker_one).await?;
+        trackers.insert_tracker(&tracker_two).await?;
+
+        let alert =
+            |id: Uuid, tracker: &Tracker, time_shift: i64| -> anyhow::Result<TrackerAlert> {
+                Ok(TrackerAlert {
+                    id,
+                    tracker_id: tracker.id,
+                    tracker_name: tracker.name.clone(),
+                    tracker_tags: tracker.tags.clone(),
+                    error: format!("Error {time_shift}"),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800 + time_shift)?,
+                })
+            };
+
+        let alerts = vec![
+            alert(
+                uuid!("00000000-0000-0000-0000-000000000011"),
+                &tracker_one,
+                10,
+            )?,
+            alert(
+                uuid!("00000000-0000-0000-0000-000000000012"),
+                &tracker_two,
+                5,
+            )?,
+            alert(
+                uuid!("00000000-0000-0000-0000-000000000013"),
+                &tracker_one,
+                20,
+            )?,
+        ];
+        for alert in &alerts {
+            trackers.insert_tracker_alert(alert).await?;
         }
 
-        let trackers = db.trackers();
         assert_eq!(
-            trackers
-                .get_tracker_by_job_id(uuid!("00000000-0000-0000-0000-000000000011"))
-                .await?,
-            Some(trackers_list[0].clone())
+            trackers.get_tracker_alerts().await?,
+            vec![alerts[1].clone(), alerts[0].clone(), alerts[2].clone()]
+        );
+
+        let insert_error = trackers
+            .insert_tracker_alert(&alert(
+                uuid!("00000000-0000-0000-0000-000000000014"),
+                &MockTrackerBuilder::create(
+                    uuid!("00000000-0000-0000-0000-000000000003"),
+                    "unknown",
+                    3,
+                )?
+                .build(),
+                30,
+            )?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(
+            insert_error.root_cause.to_string(),
+            @r###""Tracker ('00000000-0000-0000-0000-000000000003') is not found.""###
         );
+        assert_debug_snapshot!(
+            to_database_error(insert_error.root_cause)?.message(),
+            @r###""insert or update on table \"trackers_alerts\" violates foreign key constraint \"trackers_alerts_tracker_id_fkey\"""###
+        );
+
+        trackers
+            .remove_tracker_alerts(&[alerts[0].id, alerts[1].id])
+            .await?;
         assert_eq!(
-            trackers
-                .get_tracker_by_job_id(uuid!("00000000-0000-0000-0000-000000000022"))
-                .await?,
-            Some(trackers_list[1].clone())
+            trackers.get_tracker_alerts().await?,
+            vec![alerts[2].clone()]
         );
 
+        // Alerts are removed together with the tracker.
+        trackers.insert_tracker_alert(&alerts[0]).await?;
+        trackers.remove_tracker(tracker_one.id).await?;
+        assert!(trackers.get_tracker_alerts().await?.is_empty());
+
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_update_trackers_job_id(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_add_retrieve_and_remove_tracker_digest_notifications(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let tracker = MockTrackerBuilder::create(
+        let trackers = db.trackers();
+        let tracker_one = MockTrackerBuilder::create(
             uuid!("00000000-0000-0000-0000-000000000001"),
             "some-name",
             3,
         )?
-        .with_schedule("* * * * *")
+        .with_tags(vec!["vendor".to_string()])
         .build();
+        let tracker_two = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-other-name",
+            3,
+        )?
+        .build();
+        trackers.insert_tracker(&tracker_one).await?;
+        trackers.insert_tracker(&tracker_two).await?;
+
+        let notification = |id: Uuid,
+                            tracker: &Tracker,
+                            time_shift: i64|
+         -> anyhow::Result<TrackerDigestNotification> {
+            Ok(TrackerDigestNotification {
+                id,
+                tracker_id: tracker.id,
+                tracker_name: tracker.name.clone(),
+                tag: tracker.tags.first().cloned(),
+                task_type: TaskType::Email(EmailTaskType {
+                    to: vec!["dev@retrack.dev".to_string()],
+                    content: EmailContent::Custom(Email::text(
+                        "subj".to_string(),
+                        format!("Change {time_shift}"),
+                    )),
+                    timeout: None,
+                    attachments: None,
+                }),
+                send_at: OffsetDateTime::from_unix_timestamp(946724400)?,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800 + time_shift)?,
+            })
+        };
 
-        let trackers = db.trackers();
-        trackers.insert_tracker(&tracker).await?;
+        let notifications = vec![
+            notification(
+                uuid!("00000000-0000-0000-0000-000000000011"),
+                &tracker_one,
+                10,
+            )?,
+            notification(
+                uuid!("00000000-0000-0000-0000-000000000012"),
+                &tracker_two,
+                5,
+            )?,
+            notification(
+                uuid!("00000000-0000-0000-0000-000000000013"),
+                &tracker_one,
+                20,
+            )?,
+        ];
+        for notification in &notifications {
+            trackers
+                .insert_tracker_digest_notification(notification)
+                .await?;
+        }
 
         assert_eq!(
-            trackers.get_tracker(tracker.id).await?.unwrap().job_id,
-            None
+            trackers.get_tracker_digest_notifications().await?,
+            vec![
+                notifications[1].clone(),
+                notifications[0].clone(),
+                notifications[2].clone()
+            ]
+        );
+
+        let insert_error = trackers
+            .insert_tracker_digest_notification(&notification(
+                uuid!("00000000-0000-0000-0000-000000000014"),
+                &MockTrackerBuilder::create(
+                    uuid!("00000000-0000-0000-0000-000000000003"),
+                    "unknown",
+                    3,
+                )?
+                .build(),
+                30,
+            )?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(
+            insert_error.root_cause.to_string(),
+            @r###""Tracker ('00000000-0000-0000-0000-000000000003') is not found.""###
         );
 
-        let trackers = db.trackers();
         trackers
-            .update_tracker_job(
-                tracker.id,
-                Some(uuid!("00000000-0000-0000-0000-000000000011")),
-            )
+            .remove_tracker_digest_notifications(&[notifications[0].id, notifications[1].id])
             .await?;
         assert_eq!(
-            trackers.get_tracker(tracker.id).await?.unwrap().job_id,
-            Some(uuid!("00000000-0000-0000-0000-000000000011"))
+            trackers.get_tracker_digest_notifications().await?,
+            vec![notifications[2].clone()]
         );
 
+        // Notifications are removed together with the tracker.
         trackers
-            .update_tracker_job(
-                tracker.id,
-                Some(uuid!("00000000-0000-0000-0000-000000000022")),
-            )
+            .insert_tracker_digest_notification(&notifications[0])
             .await?;
-        assert_eq!(
-            trackers.get_tracker(tracker.id).await?.unwrap().job_id,
-            Some(uuid!("00000000-0000-0000-0000-000000000022"))
-        );
+        trackers.remove_tracker(tracker_one.id).await?;
+        assert!(trackers
+            .get_tracker_digest_notifications()
+            .await?
+            .is_empty());
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn fails_to_update_trackers_job_id_if_needed(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_add_retrieve_and_remove_tracker_quarantined_revisions(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let tracker = MockTrackerBuilder::create(
+        let trackers = db.trackers();
+        let tracker_one = MockTrackerBuilder::create(
             uuid!("00000000-0000-0000-0000-000000000001"),
             "some-name",
             3,
         )?
         .build();
-
-        let update_and_fail = |result: anyhow::Result<_>| -> RetrackError {
-            result.unwrap_err().downcast::<RetrackError>().unwrap()
+        let tracker_two = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-other-name",
+            3,
+        )?
+        .build();
+        trackers.insert_tracker(&tracker_one).await?;
+        trackers.insert_tracker(&tracker_two).await?;
+
+        let quarantined_revision = |id: Uuid,
+                                    tracker: &Tracker,
+                                    time_shift: i64|
+         -> anyhow::Result<TrackerQuarantinedRevision> {
+            Ok(TrackerQuarantinedRevision {
+                revision: create_data_revision(id, tracker.id, time_shift)?,
+                reason: format!("Reason {time_shift}"),
+            })
         };
 
-        // Non-existent tracker
-        let update_result = update_and_fail(
-            db.trackers()
-                .update_tracker_job(
-                    tracker.id,
-                    Some(uuid!("00000000-0000-0000-0000-000000000011")),
-                )
-                .await,
+        let quarantine = vec![
+            quarantined_revision(
+                uuid!("00000000-0000-0000-0000-000000000011"),
+                &tracker_one,
+                10,
+            )?,
+            quarantined_revision(
+                uuid!("00000000-0000-0000-0000-000000000012"),
+                &tracker_two,
+                5,
+            )?,
+            quarantined_revision(
+                uuid!("00000000-0000-0000-0000-000000000013"),
+                &tracker_one,
+                1,
+            )?,
+        ];
+        for quarantined_revision in &quarantine {
+            trackers
+                .insert_tracker_quarantined_revision(quarantined_revision)
+                .await?;
+        }
+
+        assert_eq!(
+            trackers.get_tracker_quarantine(tracker_one.id).await?,
+            vec![quarantine[2].clone(), quarantine[0].clone()]
         );
         assert_eq!(
-            update_result.to_string(),
-            format!("Tracker ('{}') doesn't exist.", tracker.id)
+            trackers.get_tracker_quarantine(tracker_two.id).await?,
+            vec![quarantine[1].clone()]
         );
+        // Quarantined revisions aren't part of the revisions history.
+        assert!(trackers.get_tracker_data(tracker_one.id).await?.is_empty());
 
-        Ok(())
-    }
-
-    #[sqlx::test]
-    async fn can_return_tracker_with_pending_jobs(pool: PgPool) -> anyhow::Result<()> {
-        let db = Database::create(pool).await?;
-
-        let pending_trackers = db
-            .trackers()
-            .get_trackers_to_run(10)
-            .collect::<Vec<_>>()
-            .await;
-        assert!(pending_trackers.is_empty());
-
-        for n in 0..=2 {
-            let job = RawSchedulerJobStoredData {
-                last_updated: Some(946720800 + n),
-                last_tick: Some(946720700),
-                next_tick: Some(946720900),
-                ran: Some(true),
-                count: Some(n as i32),
-                stopped: Some(n != 1),
-                ..mock_scheduler_job(
-                    Uuid::parse_str(&format!("68e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
-                    SchedulerJob::TrackersTrigger,
-                    format!("{} 0 0 1 1 * *", n),
-                )
-            };
-
-            mock_upsert_scheduler_job(&db, &job).await?;
-        }
+        let insert_error = trackers
+            .insert_tracker_quarantined_revision(&quarantined_revision(
+                uuid!("00000000-0000-0000-0000-000000000014"),
+                &MockTrackerBuilder::create(
+                    uuid!("00000000-0000-0000-0000-000000000003"),
+                    "unknown",
+                    3,
+                )?
+                .build(),
+                30,
+            )?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(
+            insert_error.root_cause.to_string(),
+            @r###""Tracker ('00000000-0000-0000-0000-000000000003') is not found.""###
+        );
 
-        for n in 0..=2 {
-            db.trackers()
-                .insert_tracker(
-                    &MockTrackerBuilder::create(
-                        Uuid::parse_str(&format!("78e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
-                        format!("name_{}", n),
-                        3,
-                    )?
-                    .with_schedule("0 0 * * * *")
-                    .with_job_id(Uuid::parse_str(&format!(
-                        "68e55044-10b1-426f-9247-bb680e5fe0c{}",
-                        n
-                    ))?)
-                    .build(),
-                )
-                .await?;
-        }
+        assert!(
+            trackers
+                .remove_tracker_quarantined_revision(tracker_one.id, quarantine[0].revision.id)
+                .await?
+        );
+        // Revision of another tracker cannot be removed.
+        assert!(
+            !trackers
+                .remove_tracker_quarantined_revision(tracker_one.id, quarantine[1].revision.id)
+                .await?
+        );
+        assert_eq!(
+            trackers.get_tracker_quarantine(tracker_one.id).await?,
+            vec![quarantine[2].clone()]
+        );
 
-        let pending_trackers = db
-            .trackers()
-            .get_trackers_to_run(10)
-            .collect::<Vec<_>>()
-            .await;
-        assert_eq!(pending_trackers.len(), 2);
+        // Quarantined revisions are removed together with the tracker.
+        trackers.remove_tracker(tracker_one.id).await?;
+        assert!(trackers
+            .get_tracker_quarantine(tracker_one.id)
+            .await?
+            .is_empty());
+        assert_eq!(
+            trackers.get_tracker_quarantine(tracker_two.id).await?,
+            vec![quarantine[1].clone()]
+        );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn can_return_tracker_with_pending_jobs_with_retry(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_add_retrieve_update_and_trim_tracker_action_history(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
 
-        let pending_trackers = db
-            .trackers()
-            .get_trackers_to_run(10)
-            .collect::<Vec<_>>()
-            .await;
-        assert!(pending_trackers.is_empty());
+        let trackers = db.trackers();
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .build();
+        trackers.insert_tracker(&tracker).await?;
+        assert!(trackers
+            .get_tracker_action_history(tracker.id)
+            .await?
+            .is_empty());
 
-        for n in 0..=2 {
-            let job = RawSchedulerJobStoredData {
-                last_updated: Some(946720800 + n),
-                last_tick: Some(946720700),
-                next_tick: Some(946720900),
-                ran: Some(true),
-                count: Some(n as i32),
-                stopped: Some(n != 1),
-                extra: Some(
-                    if n == 2 {
-                        SchedulerJobMetadata {
-                            job_type: SchedulerJob::TrackersTrigger,
-                            retry: Some(SchedulerJobRetryState {
-                                attempts: 1,
-                                next_at: OffsetDateTime::now_utc().add(Duration::from_secs(3600)),
-                            }),
-                        }
-                    } else {
-                        SchedulerJobMetadata::new(SchedulerJob::TrackersTrigger)
-                    }
-                    .try_into()?,
-                ),
-                ..mock_scheduler_job(
-                    Uuid::parse_str(&format!("67e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
-                    SchedulerJob::TrackersTrigger,
-                    format!("{} 0 0 1 1 * *", n),
-                )
-            };
+        let entry = |id: Uuid,
+                     task_id: Option<Uuid>,
+                     time_shift: i64|
+         -> anyhow::Result<TrackerActionHistoryEntry> {
+            Ok(TrackerActionHistoryEntry {
+                id,
+                tracker_id: tracker.id,
+                action: 1,
+                action_type: "webhook".to_string(),
+                task_id,
+                status: if task_id.is_some() {
+                    TrackerActionHistoryStatus::Scheduled
+                } else {
+                    TrackerActionHistoryStatus::Succeeded
+                },
+                error: None,
+                duration: Duration::from_millis(25),
+                executed_at: OffsetDateTime::from_unix_timestamp(946720800 + time_shift)?,
+            })
+        };
 
-            mock_upsert_scheduler_job(&db, &job).await?;
+        let task_id = uuid!("00000000-0000-0000-0000-000000000100");
+        let entries = vec![
+            entry(uuid!("00000000-0000-0000-0000-000000000010"), None, 0)?,
+            entry(
+                uuid!("00000000-0000-0000-0000-000000000011"),
+                Some(task_id),
+                10,
+            )?,
+            entry(uuid!("00000000-0000-0000-0000-000000000012"), None, 20)?,
+        ];
+        for entry in &entries {
+            trackers.insert_tracker_action_history_entry(entry).await?;
         }
 
-        for n in 0..=2 {
-            db.trackers()
-                .insert_tracker(
-                    &MockTrackerBuilder::create(
-                        Uuid::parse_str(&format!("77e55044-10b1-426f-9247-bb680e5fe0c{}", n))?,
-                        format!("name_{}", n),
-                        3,
-                    )?
-                    .with_schedule("0 0 * * * *")
-                    .with_job_id(Uuid::parse_str(&format!(
-                        "67e55044-10b1-426f-9247-bb680e5fe0c{}",
-                        n
-                    ))?)
-                    .build(),
-                )
-                .await?;
-        }
+        assert_eq!(
+            trackers.get_tracker_action_history(tracker.id).await?,
+            vec![entries[2].clone(), entries[1].clone(), entries[0].clone()]
+        );
 
-        let mut pending_trackers = db
-            .trackers()
-            .get_trackers_to_run(10)
-            .collect::<Vec<_>>()
-            .await;
-        assert_eq!(pending_trackers.len(), 1);
+        // Update entry delivered by the task.
+        trackers
+            .update_tracker_action_history_task_status(
+                task_id,
+                TrackerActionHistoryStatus::Failed,
+                Some("Connection refused."),
+            )
+            .await?;
+        let updated_entry = TrackerActionHistoryEntry {
+            status: TrackerActionHistoryStatus::Failed,
+            error: Some("Connection refused.".to_string()),
+            ..entries[1].clone()
+        };
+        assert_eq!(
+            trackers.get_tracker_action_history(tracker.id).await?,
+            vec![
+                entries[2].clone(),
+                updated_entry.clone(),
+                entries[0].clone()
+            ]
+        );
 
-        let tracker = pending_trackers.remove(0)?;
-        assert_eq!(tracker.id, uuid!("77e55044-10b1-426f-9247-bb680e5fe0c0"));
+        // Only the most recent entries are kept.
+        trackers.trim_tracker_action_history(tracker.id, 2).await?;
         assert_eq!(
-            tracker.job_id,
-            Some(uuid!("67e55044-10b1-426f-9247-bb680e5fe0c0"))
+            trackers.get_tracker_action_history(tracker.id).await?,
+            vec![entries[2].clone(), updated_entry]
         );
 
-        db.update_scheduler_job_meta(
-            uuid!("67e55044-10b1-426f-9247-bb680e5fe0c2"),
-            SchedulerJobMetadata {
-                job_type: SchedulerJob::TrackersTrigger,
-                retry: Some(SchedulerJobRetryState {
-                    attempts: 1,
-                    next_at: OffsetDateTime::now_utc().sub(Duration::from_secs(3600)),
-                }),
-            },
-        )
-        .await?;
+        // History is removed along with the tracker.
+        trackers.remove_tracker(tracker.id).await?;
+        assert!(trackers
+            .get_tracker_action_history(tracker.id)
+            .await?
+            .is_empty());
 
-        let mut pending_trackers = db
-            .trackers()
-            .get_trackers_to_run(10)
-            .collect::<Vec<_>>()
-            .await;
-        assert_eq!(pending_trackers.len(), 2);
+        Ok(())
+    }
 
-        let tracker = pending_trackers.remove(0)?;
-        assert_eq!(tracker.id, uuid!("77e55044-10b1-426f-9247-bb680e5fe0c0"));
-        assert_eq!(
-            tracker.job_id,
-            Some(uuid!("67e55044-10b1-426f-9247-bb680e5fe0c0"))
-        );
+    #[sqlx::test]
+    async fn can_add_and_retrieve_trackers_scrubs(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let trackers = db.trackers();
+        assert!(trackers.get_trackers_scrubs().await?.is_empty());
+
+        let scrubs = vec![
+            TrackersScrub {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                reason: None,
+                pattern_digest: Some("sha256:ab".to_string()),
+                trackers: vec![],
+                revisions: 0,
+                pending_data: 0,
+                alerts: 0,
+                tasks: 2,
+                created_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            },
+            TrackersScrub {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                reason: Some("DSR-42".to_string()),
+                pattern_digest: None,
+                trackers: vec![
+                    uuid!("00000000-0000-0000-0000-000000000010"),
+                    uuid!("00000000-0000-0000-0000-000000000011"),
+                ],
+                revisions: 10,
+                pending_data: 1,
+                alerts: 3,
+                tasks: 0,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            },
+        ];
+        for scrub in &scrubs {
+            trackers.insert_trackers_scrub(scrub).await?;
+        }
 
-        let tracker = pending_trackers.remove(0)?;
-        assert_eq!(tracker.id, uuid!("77e55044-10b1-426f-9247-bb680e5fe0c2"));
         assert_eq!(
-            tracker.job_id,
-            Some(uuid!("67e55044-10b1-426f-9247-bb680e5fe0c2"))
+            trackers.get_trackers_scrubs().await?,
+            vec![scrubs[1].clone(), scrubs[0].clone()]
         );
 
         Ok(())
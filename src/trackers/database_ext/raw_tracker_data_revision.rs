@@ -1,6 +1,9 @@
-use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+use retrack_types::trackers::{
+    TlsSessionInfo, TrackerDataProvenance, TrackerDataRevision, TrackerDataValue,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use std::collections::VecDeque;
+use std::{borrow::Cow, collections::VecDeque};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -10,8 +13,21 @@ pub(super) struct RawTrackerDataRevision {
     pub tracker_id: Uuid,
     pub data: Vec<u8>,
     pub created_at: OffsetDateTime,
+    pub provenance: Option<Vec<u8>>,
+    pub sequence: i64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+struct RawTrackerDataProvenance<'s> {
+    #[serde(borrow)]
+    tls: Option<Vec<Option<RawTlsSessionInfo<'s>>>>,
+    #[serde(borrow)]
+    charsets: Option<Vec<Option<Cow<'s, str>>>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+struct RawTlsSessionInfo<'s>(Cow<'s, str>, Cow<'s, str>, i64);
+
 impl TryFrom<RawTrackerDataRevision> for TrackerDataRevision {
     type Error = anyhow::Error;
 
@@ -28,11 +44,45 @@ impl TryFrom<RawTrackerDataRevision> for TrackerDataRevision {
             .into_iter()
             .for_each(|value| data.add_mod(value));
 
+        let provenance = if let Some(ref raw_provenance) = raw.provenance {
+            let raw_provenance = postcard::from_bytes::<RawTrackerDataProvenance>(raw_provenance)?;
+            Some(TrackerDataProvenance {
+                tls: raw_provenance
+                    .tls
+                    .map(|tls| {
+                        tls.into_iter()
+                            .map(|info| {
+                                info.map(|RawTlsSessionInfo(protocol, fingerprint, expires_at)| {
+                                    Ok::<_, anyhow::Error>(TlsSessionInfo {
+                                        protocol: protocol.into_owned(),
+                                        certificate_fingerprint: fingerprint.into_owned(),
+                                        certificate_expires_at:
+                                            OffsetDateTime::from_unix_timestamp(expires_at)?,
+                                    })
+                                })
+                                .transpose()
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()
+                    })
+                    .transpose()?,
+                charsets: raw_provenance.charsets.map(|charsets| {
+                    charsets
+                        .into_iter()
+                        .map(|charset| charset.map(Cow::into_owned))
+                        .collect()
+                }),
+            })
+        } else {
+            None
+        };
+
         Ok(Self {
             id: raw.id,
             tracker_id: raw.tracker_id,
             data,
             created_at: raw.created_at,
+            provenance,
+            sequence: Some(u64::try_from(raw.sequence)?),
         })
     }
 }
@@ -51,6 +101,39 @@ impl TryFrom<&TrackerDataRevision> for RawTrackerDataRevision {
                     .collect::<Vec<_>>(),
             )?,
             created_at: item.created_at,
+            provenance: item
+                .provenance
+                .as_ref()
+                .map(|provenance| {
+                    postcard::to_stdvec(&RawTrackerDataProvenance {
+                        tls: provenance.tls.as_ref().map(|tls| {
+                            tls.iter()
+                                .map(|info| {
+                                    info.as_ref().map(|info| {
+                                        RawTlsSessionInfo(
+                                            Cow::Borrowed(info.protocol.as_ref()),
+                                            Cow::Borrowed(info.certificate_fingerprint.as_ref()),
+                                            info.certificate_expires_at.unix_timestamp(),
+                                        )
+                                    })
+                                })
+                                .collect()
+                        }),
+                        charsets: provenance.charsets.as_ref().map(|charsets| {
+                            charsets
+                                .iter()
+                                .map(|charset| charset.as_deref().map(Cow::Borrowed))
+                                .collect()
+                        }),
+                    })
+                })
+                .transpose()?,
+            // Sequence number is assigned by the database when revision is inserted.
+            sequence: item
+                .sequence
+                .map(i64::try_from)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -58,7 +141,9 @@ impl TryFrom<&TrackerDataRevision> for RawTrackerDataRevision {
 #[cfg(test)]
 mod tests {
     use super::RawTrackerDataRevision;
-    use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+    use retrack_types::trackers::{
+        TlsSessionInfo, TrackerDataProvenance, TrackerDataRevision, TrackerDataValue,
+    };
     use serde_json::json;
     use time::OffsetDateTime;
     use uuid::uuid;
@@ -70,6 +155,8 @@ mod tests {
             tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
             data: TrackerDataValue::new(json!("some-data")),
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            provenance: None,
+            sequence: Some(1),
         };
         assert_eq!(
             TrackerDataRevision::try_from(RawTrackerDataRevision::try_from(&data_revision)?)?,
@@ -88,6 +175,25 @@ mod tests {
             data_revision
         );
 
+        let data_revision = TrackerDataRevision {
+            provenance: Some(TrackerDataProvenance {
+                tls: Some(vec![
+                    Some(TlsSessionInfo {
+                        protocol: "HTTP/2.0".to_string(),
+                        certificate_fingerprint: "ab01".to_string(),
+                        certificate_expires_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                    }),
+                    None,
+                ]),
+                charsets: Some(vec![None, Some("windows-1252".to_string())]),
+            }),
+            ..data_revision
+        };
+        assert_eq!(
+            TrackerDataRevision::try_from(RawTrackerDataRevision::try_from(&data_revision)?)?,
+            data_revision
+        );
+
         Ok(())
     }
 }
@@ -0,0 +1,4 @@
+mod embeddings_request;
+mod embeddings_response;
+
+pub use self::{embeddings_request::EmbeddingsRequest, embeddings_response::EmbeddingsResponse};
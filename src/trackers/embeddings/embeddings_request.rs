@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+/// Represents request to the text embeddings API (OpenAI-compatible embeddings API).
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct EmbeddingsRequest<'a> {
+    /// The name of the model to use.
+    pub model: &'a str,
+
+    /// Texts to calculate embeddings for.
+    pub input: Vec<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmbeddingsRequest;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(EmbeddingsRequest {
+            model: "text-embedding-3-small",
+            input: vec!["Hello world!", "Hello, world!"],
+        }, @r###"
+        {
+          "model": "text-embedding-3-small",
+          "input": [
+            "Hello world!",
+            "Hello, world!"
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a response of the text embeddings API (OpenAI-compatible embeddings API).
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct EmbeddingsResponse {
+    /// Calculated embeddings.
+    pub data: Vec<EmbeddingsResponseItem>,
+}
+
+/// Represents embedding of a single text.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct EmbeddingsResponseItem {
+    /// Index of the text in the request.
+    pub index: usize,
+    /// Embedding vector.
+    pub embedding: Vec<f64>,
+}
+
+impl EmbeddingsResponse {
+    /// Returns embeddings in the same order as texts in the request.
+    pub fn into_embeddings(mut self) -> Vec<Vec<f64>> {
+        self.data.sort_by_key(|item| item.index);
+        self.data.into_iter().map(|item| item.embedding).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmbeddingsResponse, EmbeddingsResponseItem};
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let response = serde_json::from_str::<EmbeddingsResponse>(
+            r#"
+{
+    "object": "list",
+    "data": [
+        { "object": "embedding", "index": 1, "embedding": [0.5, -0.25] },
+        { "object": "embedding", "index": 0, "embedding": [0.1, 0.2] }
+    ],
+    "model": "text-embedding-3-small"
+}
+          "#,
+        )?;
+        assert_eq!(
+            response,
+            EmbeddingsResponse {
+                data: vec![
+                    EmbeddingsResponseItem {
+                        index: 1,
+                        embedding: vec![0.5, -0.25],
+                    },
+                    EmbeddingsResponseItem {
+                        index: 0,
+                        embedding: vec![0.1, 0.2],
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            response.into_embeddings(),
+            vec![vec![0.1, 0.2], vec![0.5, -0.25]]
+        );
+
+        Ok(())
+    }
+}
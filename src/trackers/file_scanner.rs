@@ -0,0 +1,421 @@
+use anyhow::{bail, Context};
+use globset::{GlobBuilder, GlobMatcher};
+use ring::digest::{Context as DigestContext, SHA256};
+use serde::Serialize;
+use std::{
+    fmt::Write,
+    fs::{self, File},
+    io::Read,
+    path::{Component, Path, PathBuf},
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+use walkdir::WalkDir;
+
+/// Maximum number of the file system entries the scanner is willing to visit while matching files
+/// in the directory tree, protects from the unbounded scans of huge directory trees.
+const MAX_VISITED_ENTRIES_COUNT: usize = 10_000;
+
+/// Maximum size of the file the scanner is willing to read the content of.
+const MAX_FILE_CONTENT_SIZE: u64 = 1024 * 1024;
+
+/// Characters that turn a path component into a glob pattern.
+const GLOB_META_CHARS: [char; 4] = ['*', '?', '[', '{'];
+
+/// Metadata and optional content of the local file matched by the file target.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScannedFile {
+    /// Path of the file as it was matched.
+    pub path: String,
+    /// Size of the file in bytes.
+    pub size: u64,
+    /// Last modification time of the file in RFC 3339 format, if supported by the file system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<String>,
+    /// Digest of the file content (e.g., `sha256:...`).
+    pub checksum: String,
+    /// Text content of the file, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+/// Scans local files matching the path or glob pattern, restricted to the list of allowed root
+/// directories. Symbolic links are resolved, and the files they point to should be within the
+/// allowed root directories as well.
+pub struct FileScanner<'r> {
+    roots: &'r [PathBuf],
+}
+
+impl<'r> FileScanner<'r> {
+    /// Creates a new scanner restricted to the specified root directories.
+    pub fn new(roots: &'r [PathBuf]) -> Self {
+        Self { roots }
+    }
+
+    /// Checks if the path or glob pattern is absolute, doesn't contain parent directory references,
+    /// and its non-glob part is within one of the allowed root directories. The file system isn't
+    /// accessed, symbolic links are checked only when files are scanned.
+    pub fn is_allowed_path(&self, path: &str) -> bool {
+        let path = Path::new(path);
+        if !path.is_absolute()
+            || path
+                .components()
+                .any(|component| matches!(component, Component::ParentDir))
+        {
+            return false;
+        }
+
+        let (base, _) = split_glob(path);
+        self.roots.iter().any(|root| base.starts_with(root))
+    }
+
+    /// Scans files matching the path or glob pattern and returns up to `max_files` files sorted by
+    /// path. If the path points to a directory, all files within the directory tree are matched.
+    /// The method performs blocking file system operations.
+    pub fn scan(
+        &self,
+        path: &str,
+        capture_content: bool,
+        max_files: usize,
+    ) -> anyhow::Result<Vec<ScannedFile>> {
+        if !self.is_allowed_path(path) {
+            bail!("Path ({path}) is not within the allowed directories.");
+        }
+
+        let canonical_roots = self
+            .roots
+            .iter()
+            .filter_map(|root| fs::canonicalize(root).ok())
+            .collect::<Vec<_>>();
+
+        let (base, glob) = split_glob(Path::new(path));
+        let mut paths = match glob {
+            Some(glob) => {
+                let matcher = GlobBuilder::new(glob)
+                    .literal_separator(true)
+                    .build()
+                    .with_context(|| format!("Invalid glob pattern ({glob})."))?
+                    .compile_matcher();
+                walk_files(&base, Some(&matcher))?
+            }
+            None if base.is_dir() => walk_files(&base, None)?,
+            None if base.is_file() => vec![base],
+            None => bail!("File ({}) doesn't exist.", base.display()),
+        };
+        paths.sort();
+        paths.truncate(max_files);
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let canonical_path = fs::canonicalize(&path)
+                    .with_context(|| format!("Cannot resolve file ({}).", path.display()))?;
+                if !canonical_roots
+                    .iter()
+                    .any(|root| canonical_path.starts_with(root))
+                {
+                    bail!(
+                        "File ({}) resolves to a path outside of the allowed directories.",
+                        path.display()
+                    );
+                }
+
+                scan_file(&path, capture_content)
+                    .with_context(|| format!("Cannot read file ({}).", path.display()))
+            })
+            .collect()
+    }
+}
+
+/// Splits path into the base directory path that doesn't contain glob meta characters, and the
+/// full glob pattern, if any of the path components contain glob meta characters.
+fn split_glob(path: &Path) -> (PathBuf, Option<&str>) {
+    let mut base = PathBuf::new();
+    for component in path.components() {
+        if component
+            .as_os_str()
+            .to_str()
+            .is_some_and(|component| component.contains(GLOB_META_CHARS))
+        {
+            return (base, path.to_str());
+        }
+        base.push(component);
+    }
+
+    (base, None)
+}
+
+/// Recursively collects paths of the files within the directory, optionally matched against the
+/// glob. Symbolic links to directories aren't followed to avoid cycles.
+fn walk_files(base: &Path, glob: Option<&GlobMatcher>) -> anyhow::Result<Vec<PathBuf>> {
+    let mut paths = vec![];
+    for (index, entry) in WalkDir::new(base)
+        .follow_links(false)
+        .into_iter()
+        .enumerate()
+    {
+        if index >= MAX_VISITED_ENTRIES_COUNT {
+            bail!(
+                "Directory ({}) contains more than {MAX_VISITED_ENTRIES_COUNT} entries.",
+                base.display()
+            );
+        }
+
+        let entry = match entry {
+            Ok(entry) => entry,
+            // The base directory might not exist yet, e.g. when no report has been exported.
+            Err(err) if err.depth() == 0 && err.io_error().is_some_and(is_not_found) => break,
+            Err(err) => return Err(err.into()),
+        };
+
+        let is_file =
+            entry.file_type().is_file() || (entry.path_is_symlink() && entry.path().is_file());
+        if is_file && glob.is_none_or(|glob| glob.is_match(entry.path())) {
+            paths.push(entry.into_path());
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Reads file metadata and calculates the file content digest, optionally capturing the content.
+fn scan_file(path: &Path, capture_content: bool) -> anyhow::Result<ScannedFile> {
+    let mut file = File::open(path)?;
+    let metadata = file.metadata()?;
+    if capture_content && metadata.len() > MAX_FILE_CONTENT_SIZE {
+        bail!("File content is larger than {MAX_FILE_CONTENT_SIZE} bytes and cannot be captured.");
+    }
+
+    let mut digest = DigestContext::new(&SHA256);
+    let mut content = capture_content.then(Vec::new);
+    let mut buffer = [0; 8192];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        digest.update(&buffer[..read]);
+        if let Some(ref mut content) = content {
+            content.extend_from_slice(&buffer[..read]);
+        }
+    }
+
+    let checksum =
+        digest
+            .finish()
+            .as_ref()
+            .iter()
+            .fold(String::from("sha256:"), |mut checksum, byte| {
+                let _ = write!(checksum, "{byte:02x}");
+                checksum
+            });
+
+    Ok(ScannedFile {
+        path: path.display().to_string(),
+        size: metadata.len(),
+        modified_at: metadata
+            .modified()
+            .ok()
+            .and_then(|modified| OffsetDateTime::from(modified).format(&Rfc3339).ok()),
+        checksum,
+        content: content
+            .map(String::from_utf8)
+            .transpose()
+            .context("File content is not a valid UTF-8 text.")?,
+    })
+}
+
+fn is_not_found(err: &std::io::Error) -> bool {
+    err.kind() == std::io::ErrorKind::NotFound
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_glob, FileScanner};
+    use insta::assert_json_snapshot;
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    #[test]
+    fn properly_splits_glob() {
+        assert_eq!(
+            split_glob(Path::new("/var/reports/daily.csv")),
+            (PathBuf::from("/var/reports/daily.csv"), None)
+        );
+        assert_eq!(
+            split_glob(Path::new("/var/reports/*.csv")),
+            (PathBuf::from("/var/reports"), Some("/var/reports/*.csv"))
+        );
+        assert_eq!(
+            split_glob(Path::new("/var/{reports,exports}/**/*.csv")),
+            (
+                PathBuf::from("/var"),
+                Some("/var/{reports,exports}/**/*.csv")
+            )
+        );
+    }
+
+    #[test]
+    fn properly_checks_allowed_paths() {
+        let roots = [PathBuf::from("/var/reports"), PathBuf::from("/etc/retrack")];
+        let scanner = FileScanner::new(&roots);
+
+        assert!(scanner.is_allowed_path("/var/reports"));
+        assert!(scanner.is_allowed_path("/var/reports/daily.csv"));
+        assert!(scanner.is_allowed_path("/var/reports/**/*.csv"));
+        assert!(scanner.is_allowed_path("/etc/retrack/config.toml"));
+
+        assert!(!scanner.is_allowed_path("reports/daily.csv"));
+        assert!(!scanner.is_allowed_path("/var/reports/../../etc/shadow"));
+        assert!(!scanner.is_allowed_path("/var/reports-backup/daily.csv"));
+        assert!(!scanner.is_allowed_path("/var/*/daily.csv"));
+        assert!(!scanner.is_allowed_path("/etc/passwd"));
+        assert!(!FileScanner::new(&[]).is_allowed_path("/var/reports/daily.csv"));
+    }
+
+    #[test]
+    fn properly_scans_files() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let root_path = root.path().canonicalize()?;
+        fs::create_dir_all(root_path.join("daily/2025"))?;
+        fs::write(root_path.join("daily/2025/01.csv"), "id,price\n1,10\n")?;
+        fs::write(root_path.join("daily/2025/02.csv"), "id,price\n1,11\n")?;
+        fs::write(root_path.join("daily/summary.txt"), "Summary")?;
+        fs::write(root_path.join("binary.bin"), [0xff, 0xfe, 0x00])?;
+
+        let roots = [root_path.clone()];
+        let scanner = FileScanner::new(&roots);
+        let scan = |path: PathBuf, capture_content: bool, max_files: usize| {
+            scanner
+                .scan(path.to_str().unwrap(), capture_content, max_files)
+                .map(|files| {
+                    files
+                        .into_iter()
+                        .map(|file| {
+                            assert!(file.modified_at.is_some());
+                            (
+                                file.path.replace(root_path.to_str().unwrap(), "<root>"),
+                                file.size,
+                                file.checksum,
+                                file.content,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        // Single file.
+        assert_json_snapshot!(scan(root_path.join("daily/summary.txt"), true, 10)?, @r###"
+        [
+          [
+            "<root>/daily/summary.txt",
+            7,
+            "sha256:8e76a94ac8320d515375e625bef1829238b097ecbd33611b59eeefd4ffefebfb",
+            "Summary"
+          ]
+        ]
+        "###);
+
+        // Glob.
+        assert_json_snapshot!(scan(root_path.join("daily/**/*.csv"), false, 10)?, @r###"
+        [
+          [
+            "<root>/daily/2025/01.csv",
+            14,
+            "sha256:48891dbd1c19f38a173db0b9d1ee25f8715ec9f0675f4131914cc981128b842a",
+            null
+          ],
+          [
+            "<root>/daily/2025/02.csv",
+            14,
+            "sha256:cdcb1f99ef75b8c17d603e5508fcb3ae985e26209006ea6ab36cb7215437830f",
+            null
+          ]
+        ]
+        "###);
+
+        // Directory, limited number of files.
+        assert_json_snapshot!(scan(root_path.join("daily"), false, 2)?, @r###"
+        [
+          [
+            "<root>/daily/2025/01.csv",
+            14,
+            "sha256:48891dbd1c19f38a173db0b9d1ee25f8715ec9f0675f4131914cc981128b842a",
+            null
+          ],
+          [
+            "<root>/daily/2025/02.csv",
+            14,
+            "sha256:cdcb1f99ef75b8c17d603e5508fcb3ae985e26209006ea6ab36cb7215437830f",
+            null
+          ]
+        ]
+        "###);
+
+        // Non-existent directory and glob base.
+        assert!(scan(root_path.join("weekly/*.csv"), false, 10)?.is_empty());
+        assert_eq!(
+            scan(root_path.join("weekly"), false, 10)
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "File ({}) doesn't exist.",
+                root_path.join("weekly").display()
+            )
+        );
+
+        // Binary content cannot be captured, but can be tracked by the checksum.
+        assert_eq!(scan(root_path.join("binary.bin"), false, 10)?.len(), 1);
+        assert_eq!(
+            format!(
+                "{:#}",
+                scan(root_path.join("binary.bin"), true, 10).unwrap_err()
+            )
+            .replace(root_path.to_str().unwrap(), "<root>"),
+            "Cannot read file (<root>/binary.bin).: File content is not a valid UTF-8 text.: invalid utf-8 sequence of 1 bytes from index 0"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_follow_symlinks_outside_of_roots() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let outside = tempfile::tempdir()?;
+        let root_path = root.path().canonicalize()?;
+        fs::write(outside.path().join("secret.txt"), "secret")?;
+        fs::write(root_path.join("report.txt"), "report")?;
+        std::os::unix::fs::symlink(
+            outside.path().join("secret.txt"),
+            root_path.join("link.txt"),
+        )?;
+
+        let roots = [root_path.clone()];
+        let scanner = FileScanner::new(&roots);
+        let err = scanner
+            .scan(root_path.join("*.txt").to_str().unwrap(), true, 10)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "File ({}) resolves to a path outside of the allowed directories.",
+                root_path.join("link.txt").display()
+            )
+        );
+
+        // Symbolic links within the roots are allowed.
+        fs::remove_file(root_path.join("link.txt"))?;
+        std::os::unix::fs::symlink(root_path.join("report.txt"), root_path.join("link.txt"))?;
+        assert_eq!(
+            scanner
+                .scan(root_path.join("*.txt").to_str().unwrap(), true, 10)?
+                .len(),
+            2
+        );
+
+        Ok(())
+    }
+}
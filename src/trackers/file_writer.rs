@@ -0,0 +1,184 @@
+use anyhow::{bail, Context};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Component, Path, PathBuf},
+};
+
+/// Writes local files, restricted to the list of allowed root directories. Parent directories of
+/// the files are resolved, and should be within the allowed root directories as well. Files are
+/// never written through symbolic links.
+pub struct FileWriter<'r> {
+    roots: &'r [PathBuf],
+}
+
+impl<'r> FileWriter<'r> {
+    /// Creates a new writer restricted to the specified root directories.
+    pub fn new(roots: &'r [PathBuf]) -> Self {
+        Self { roots }
+    }
+
+    /// Checks if the path is absolute, doesn't contain parent directory references, and points to
+    /// a file within one of the allowed root directories. The file system isn't accessed, symbolic
+    /// links are checked only when files are written.
+    pub fn is_allowed_path(&self, path: &str) -> bool {
+        let path = Path::new(path);
+        if !path.is_absolute()
+            || path
+                .components()
+                .any(|component| matches!(component, Component::ParentDir))
+        {
+            return false;
+        }
+
+        self.roots
+            .iter()
+            .any(|root| path.starts_with(root) && path != root)
+    }
+
+    /// Appends the content to the file or replaces the file content, creating the file if it
+    /// doesn't exist. The parent directory of the file should exist. The method performs blocking
+    /// file system operations.
+    pub fn write(&self, path: &str, content: &[u8], append: bool) -> anyhow::Result<()> {
+        if !self.is_allowed_path(path) {
+            bail!("Path ({path}) is not within the allowed directories.");
+        }
+
+        let path = Path::new(path);
+        let (Some(parent), Some(file_name)) = (path.parent(), path.file_name()) else {
+            bail!("Path ({}) doesn't point to a file.", path.display());
+        };
+
+        let canonical_parent = fs::canonicalize(parent)
+            .with_context(|| format!("Directory ({}) doesn't exist.", parent.display()))?;
+        if !self
+            .roots
+            .iter()
+            .filter_map(|root| fs::canonicalize(root).ok())
+            .any(|root| canonical_parent.starts_with(root))
+        {
+            bail!(
+                "Directory ({}) resolves to a path outside of the allowed directories.",
+                parent.display()
+            );
+        }
+
+        let path = canonical_parent.join(file_name);
+        if fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.file_type().is_symlink()) {
+            bail!(
+                "File ({}) is a symbolic link and cannot be written to.",
+                path.display()
+            );
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(&path)
+            .with_context(|| format!("Cannot open file ({}).", path.display()))?;
+        file.write_all(content)
+            .with_context(|| format!("Cannot write to file ({}).", path.display()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileWriter;
+    use std::{fs, path::PathBuf};
+
+    #[test]
+    fn properly_checks_allowed_paths() {
+        let roots = [PathBuf::from("/var/retrack"), PathBuf::from("/srv/exports")];
+        let writer = FileWriter::new(&roots);
+
+        assert!(writer.is_allowed_path("/var/retrack/releases.jsonl"));
+        assert!(writer.is_allowed_path("/srv/exports/daily/version.txt"));
+
+        assert!(!writer.is_allowed_path("/var/retrack"));
+        assert!(!writer.is_allowed_path("retrack/releases.jsonl"));
+        assert!(!writer.is_allowed_path("/var/retrack/../../etc/passwd"));
+        assert!(!writer.is_allowed_path("/var/retrack-backup/releases.jsonl"));
+        assert!(!writer.is_allowed_path("/etc/passwd"));
+        assert!(!FileWriter::new(&[]).is_allowed_path("/var/retrack/releases.jsonl"));
+    }
+
+    #[test]
+    fn properly_writes_files() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let root_path = root.path().canonicalize()?;
+        let roots = [root_path.clone()];
+        let writer = FileWriter::new(&roots);
+
+        let path = root_path.join("releases.jsonl");
+        let path_str = path.to_str().unwrap();
+        writer.write(path_str, b"{\"version\":\"1.0.0\"}\n", true)?;
+        writer.write(path_str, b"{\"version\":\"1.0.1\"}\n", true)?;
+        assert_eq!(
+            fs::read_to_string(&path)?,
+            "{\"version\":\"1.0.0\"}\n{\"version\":\"1.0.1\"}\n"
+        );
+
+        writer.write(path_str, b"1.0.2", false)?;
+        assert_eq!(fs::read_to_string(&path)?, "1.0.2");
+
+        // Parent directory should exist.
+        let path = root_path.join("daily/releases.jsonl");
+        assert_eq!(
+            writer
+                .write(path.to_str().unwrap(), b"{}", true)
+                .unwrap_err()
+                .to_string()
+                .replace(root_path.to_str().unwrap(), "<root>"),
+            "Directory (<root>/daily) doesn't exist."
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_write_through_symlinks() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let outside = tempfile::tempdir()?;
+        let root_path = root.path().canonicalize()?;
+        fs::write(outside.path().join("secret.txt"), "secret")?;
+        std::os::unix::fs::symlink(
+            outside.path().join("secret.txt"),
+            root_path.join("link.txt"),
+        )?;
+        std::os::unix::fs::symlink(outside.path(), root_path.join("outside"))?;
+
+        let roots = [root_path.clone()];
+        let writer = FileWriter::new(&roots);
+        assert_eq!(
+            writer
+                .write(root_path.join("link.txt").to_str().unwrap(), b"{}", false)
+                .unwrap_err()
+                .to_string()
+                .replace(root_path.to_str().unwrap(), "<root>"),
+            "File (<root>/link.txt) is a symbolic link and cannot be written to."
+        );
+        assert_eq!(
+            writer
+                .write(
+                    root_path.join("outside/secret.txt").to_str().unwrap(),
+                    b"{}",
+                    false
+                )
+                .unwrap_err()
+                .to_string()
+                .replace(root_path.to_str().unwrap(), "<root>"),
+            "Directory (<root>/outside) resolves to a path outside of the allowed directories."
+        );
+        assert_eq!(
+            fs::read_to_string(outside.path().join("secret.txt"))?,
+            "secret"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1180 @@
+use crate::trackers::tracker_data_version::parse_tracker_data_version;
+use anyhow::{anyhow, bail, Context};
+use flate2::{Decompress, FlushDecompress, Status};
+use globset::GlobMatcher;
+use reqwest_middleware::ClientWithMiddleware;
+use ring::digest::{digest, SHA1_FOR_LEGACY_USE_ONLY};
+use serde::Serialize;
+use std::{cmp::Reverse, collections::HashMap, fmt::Write};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+use url::Url;
+
+/// Maximum size of the git server response the client is willing to process, protects from the
+/// unbounded packs of huge repositories.
+const MAX_RESPONSE_SIZE: usize = 32 * 1024 * 1024;
+
+/// Maximum size of the file the client is willing to fetch the content of.
+const MAX_FILE_CONTENT_SIZE: usize = 1024 * 1024;
+
+/// Length of the SHA-1 object ID in bytes.
+const OID_LENGTH: usize = 20;
+
+/// Git reference advertised by the remote repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRef {
+    /// Full name of the reference (e.g., `refs/heads/main`).
+    pub name: String,
+    /// ID of the object the reference points to.
+    pub oid: String,
+    /// ID of the commit the reference points to, if the reference is an annotated tag.
+    pub peeled_oid: Option<String>,
+    /// Full name of the reference this reference points to, if it's a symbolic reference.
+    pub symref_target: Option<String>,
+}
+
+impl GitRef {
+    /// Returns the hash of the commit the reference eventually points to.
+    pub fn commit(&self) -> &str {
+        self.peeled_oid.as_deref().unwrap_or(&self.oid)
+    }
+}
+
+/// Commit recorded by the git target.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommit {
+    /// Hash of the commit.
+    pub hash: String,
+    /// Name and email of the commit author (e.g., `Dev <dev@retrack.dev>`).
+    pub author: String,
+    /// Time the commit was authored at in RFC 3339 format.
+    pub authored_at: String,
+    /// Commit message.
+    pub message: String,
+}
+
+/// Tag recorded by the git target.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GitTag {
+    /// Short name of the tag (e.g., `v1.0.0`).
+    pub name: String,
+    /// Hash of the commit the tag points to.
+    pub commit: String,
+}
+
+/// Type of the object stored in the git pack.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GitObjectKind {
+    Commit,
+    Tree,
+    Blob,
+    Tag,
+}
+
+impl GitObjectKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Commit => "commit",
+            Self::Tree => "tree",
+            Self::Blob => "blob",
+            Self::Tag => "tag",
+        }
+    }
+}
+
+/// Object stored in the git pack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GitObject {
+    kind: GitObjectKind,
+    data: Vec<u8>,
+}
+
+/// Single packet of the git wire protocol (`pkt-line`).
+#[derive(Debug, PartialEq, Eq)]
+enum PktLine<'p> {
+    Flush,
+    Delimiter,
+    ResponseEnd,
+    Data(&'p [u8]),
+}
+
+/// Client for the remote git repositories served over HTTP(S) using the git protocol version 2.
+/// The client only fetches the objects it needs (shallow and, if supported by the server, partial
+/// fetches), and never clones the repository.
+pub struct GitClient<'c> {
+    http_client: &'c ClientWithMiddleware,
+    url: &'c Url,
+    supports_shallow: bool,
+    supports_filter: bool,
+}
+
+impl<'c> GitClient<'c> {
+    /// Connects to the repository and negotiates the protocol capabilities.
+    pub async fn connect(
+        http_client: &'c ClientWithMiddleware,
+        url: &'c Url,
+    ) -> anyhow::Result<Self> {
+        let mut capabilities_url = service_url(url, "info/refs");
+        capabilities_url.set_query(Some("service=git-upload-pack"));
+        let response = http_client
+            .get(capabilities_url)
+            .header("git-protocol", "version=2")
+            .send()
+            .await?;
+        let content = read_response(response).await?;
+
+        let mut is_version_2 = false;
+        let mut supports_shallow = false;
+        let mut supports_filter = false;
+        for line in parse_pkt_lines(&content)? {
+            let PktLine::Data(line) = line else {
+                continue;
+            };
+
+            let line = String::from_utf8_lossy(line);
+            let line = line.trim_end();
+            if line == "version 2" {
+                is_version_2 = true;
+            } else if let Some(features) = line.strip_prefix("fetch=") {
+                supports_shallow = features.split(' ').any(|feature| feature == "shallow");
+                supports_filter = features.split(' ').any(|feature| feature == "filter");
+            } else if let Some(object_format) = line.strip_prefix("object-format=") {
+                if object_format != "sha1" {
+                    bail!("Git repository object format ({object_format}) isn't supported.");
+                }
+            }
+        }
+
+        if !is_version_2 {
+            bail!("Git server doesn't support protocol version 2.");
+        }
+
+        Ok(Self {
+            http_client,
+            url,
+            supports_shallow,
+            supports_filter,
+        })
+    }
+
+    /// Lists references with the names starting with any of the prefixes (e.g., `HEAD` or
+    /// `refs/tags/`).
+    pub async fn list_refs(&self, prefixes: &[&str]) -> anyhow::Result<Vec<GitRef>> {
+        let mut arguments = vec!["symrefs".to_string(), "peel".to_string()];
+        arguments.extend(prefixes.iter().map(|prefix| format!("ref-prefix {prefix}")));
+
+        let content = self.send_command("ls-refs", &arguments).await?;
+        let mut refs = vec![];
+        for line in parse_pkt_lines(&content)? {
+            let PktLine::Data(line) = line else {
+                break;
+            };
+
+            refs.push(parse_ref(&String::from_utf8_lossy(line))?);
+        }
+
+        Ok(refs)
+    }
+
+    /// Lists tags with the names matching the pattern, the latest versions listed first. Returns up
+    /// to `max_tags` tags.
+    pub async fn list_tags(
+        &self,
+        pattern: &GlobMatcher,
+        max_tags: usize,
+    ) -> anyhow::Result<Vec<GitTag>> {
+        let mut tags = self
+            .list_refs(&["refs/tags/"])
+            .await?
+            .into_iter()
+            .filter_map(|git_ref| {
+                let name = git_ref.name.strip_prefix("refs/tags/")?;
+                pattern.is_match(name).then(|| GitTag {
+                    name: name.to_string(),
+                    commit: git_ref.commit().to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Tags that look like versions are sorted by version, and the rest by name.
+        tags.sort_by_cached_key(|tag| {
+            Reverse((parse_tracker_data_version(&tag.name).ok(), tag.name.clone()))
+        });
+        tags.truncate(max_tags);
+
+        Ok(tags)
+    }
+
+    /// Fetches the commit with the specified hash.
+    pub async fn get_commit(&self, hash: &str) -> anyhow::Result<GitCommit> {
+        // Commit is the only object needed, skip trees and blobs if possible.
+        let objects = self
+            .fetch(hash, self.supports_filter.then_some("tree:0"))
+            .await?;
+        let Some(object) = objects.get(hash) else {
+            bail!("Git server didn't return commit ({hash}).");
+        };
+
+        parse_commit(hash, object)
+    }
+
+    /// Fetches the text content of the file at the specified path within the commit.
+    pub async fn get_file_content(&self, hash: &str, path: &str) -> anyhow::Result<String> {
+        // Blobs aren't needed to resolve the path, skip them if possible.
+        let objects = self
+            .fetch(hash, self.supports_filter.then_some("blob:none"))
+            .await?;
+        let blob_oid = resolve_path(&objects, hash, path)?;
+
+        let blob = match objects.get(&blob_oid) {
+            Some(blob) => blob.clone(),
+            None => {
+                let mut objects = self.fetch(&blob_oid, None).await?;
+                objects
+                    .remove(&blob_oid)
+                    .ok_or_else(|| anyhow!("Git server didn't return file ({path}) content."))?
+            }
+        };
+        if blob.kind != GitObjectKind::Blob {
+            bail!("Path ({path}) doesn't point to a file.");
+        }
+
+        if blob.data.len() > MAX_FILE_CONTENT_SIZE {
+            bail!(
+                "File ({path}) content is larger than {MAX_FILE_CONTENT_SIZE} bytes and cannot be captured."
+            );
+        }
+
+        String::from_utf8(blob.data)
+            .with_context(|| format!("File ({path}) content is not a valid UTF-8 text."))
+    }
+
+    /// Fetches the object with the specified ID (along with the objects it references, unless
+    /// they are filtered out) without the history.
+    async fn fetch(
+        &self,
+        oid: &str,
+        filter: Option<&str>,
+    ) -> anyhow::Result<HashMap<String, GitObject>> {
+        let mut arguments = vec!["no-progress".to_string(), "ofs-delta".to_string()];
+        if self.supports_shallow {
+            arguments.push("deepen 1".to_string());
+        }
+        if let Some(filter) = filter {
+            arguments.push(format!("filter {filter}"));
+        }
+        arguments.push(format!("want {oid}"));
+        arguments.push("done".to_string());
+
+        let content = self.send_command("fetch", &arguments).await?;
+        parse_pack(&parse_fetch_response(&content)?)
+    }
+
+    /// Sends the protocol version 2 command with the specified arguments and returns the response.
+    async fn send_command(&self, command: &str, arguments: &[String]) -> anyhow::Result<Vec<u8>> {
+        let mut body = pkt_line(&format!("command={command}\n"));
+        body.extend_from_slice(b"0001");
+        for argument in arguments {
+            body.extend(pkt_line(&format!("{argument}\n")));
+        }
+        body.extend_from_slice(b"0000");
+
+        let response = self
+            .http_client
+            .post(service_url(self.url, "git-upload-pack"))
+            .header("content-type", "application/x-git-upload-pack-request")
+            .header("accept", "application/x-git-upload-pack-result")
+            .header("git-protocol", "version=2")
+            .body(body)
+            .send()
+            .await?;
+
+        read_response(response).await
+    }
+}
+
+/// Constructs the URL of the git service endpoint relative to the repository URL.
+fn service_url(url: &Url, service: &str) -> Url {
+    let mut service_url = url.clone();
+    service_url.set_path(&format!("{}/{service}", url.path().trim_end_matches('/')));
+    service_url.set_query(None);
+    service_url
+}
+
+/// Reads the successful response of the git server, up to the maximum response size.
+async fn read_response(mut response: reqwest::Response) -> anyhow::Result<Vec<u8>> {
+    let status = response.status();
+    if !status.is_success() {
+        let response_text = response.text().await.unwrap_or_default();
+        let response_text = response_text.trim();
+        if response_text.is_empty() {
+            bail!("Git request failed with status {status}.");
+        }
+        bail!("Git request failed with status {status}: {response_text}");
+    }
+
+    let mut content = vec![];
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .context("Failed to read git server response.")?
+    {
+        content.extend_from_slice(&chunk);
+        if content.len() > MAX_RESPONSE_SIZE {
+            bail!("Git server response is larger than {MAX_RESPONSE_SIZE} bytes.");
+        }
+    }
+
+    Ok(content)
+}
+
+/// Encodes the data as a single `pkt-line`.
+fn pkt_line(data: &str) -> Vec<u8> {
+    format!("{:04x}{data}", data.len() + 4).into_bytes()
+}
+
+/// Splits the content into `pkt-line`s. Fails if the server reported an error.
+fn parse_pkt_lines(content: &[u8]) -> anyhow::Result<Vec<PktLine<'_>>> {
+    let mut lines = vec![];
+    let mut offset = 0;
+    while offset < content.len() {
+        let length = content
+            .get(offset..offset + 4)
+            .and_then(|length| std::str::from_utf8(length).ok())
+            .and_then(|length| usize::from_str_radix(length, 16).ok())
+            .ok_or_else(|| anyhow!("Git server response is malformed."))?;
+        let line = match length {
+            0 => PktLine::Flush,
+            1 => PktLine::Delimiter,
+            2 => PktLine::ResponseEnd,
+            3 => bail!("Git server response is malformed."),
+            _ => PktLine::Data(
+                content
+                    .get(offset + 4..offset + length)
+                    .ok_or_else(|| anyhow!("Git server response is truncated."))?,
+            ),
+        };
+
+        if let PktLine::Data(data) = line {
+            if let Some(message) = data.strip_prefix(b"ERR ") {
+                bail!(
+                    "Git server returned an error: {}",
+                    String::from_utf8_lossy(message).trim_end()
+                );
+            }
+        }
+
+        lines.push(line);
+        offset += length.max(4);
+    }
+
+    Ok(lines)
+}
+
+/// Parses the reference advertised in the `ls-refs` command response, e.g.
+/// `<oid> refs/tags/v1.0.0 peeled:<oid>`.
+fn parse_ref(line: &str) -> anyhow::Result<GitRef> {
+    let mut parts = line.trim_end().split(' ');
+    let (Some(oid), Some(name)) = (parts.next(), parts.next()) else {
+        bail!("Git reference ({line}) is malformed.");
+    };
+
+    let mut git_ref = GitRef {
+        name: name.to_string(),
+        oid: oid.to_string(),
+        peeled_oid: None,
+        symref_target: None,
+    };
+    for attribute in parts {
+        if let Some(peeled_oid) = attribute.strip_prefix("peeled:") {
+            git_ref.peeled_oid = Some(peeled_oid.to_string());
+        } else if let Some(symref_target) = attribute.strip_prefix("symref-target:") {
+            git_ref.symref_target = Some(symref_target.to_string());
+        }
+    }
+
+    Ok(git_ref)
+}
+
+/// Extracts the pack from the `fetch` command response, skipping all other sections.
+fn parse_fetch_response(content: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut pack = vec![];
+    let mut in_pack_section = false;
+    for line in parse_pkt_lines(content)? {
+        match line {
+            PktLine::Data(data) if in_pack_section => match data.split_first() {
+                // Pack data is multiplexed: 1 - data, 2 - progress, 3 - fatal error.
+                Some((&1, data)) => pack.extend_from_slice(data),
+                Some((&2, _)) => {}
+                Some((&3, message)) => bail!(
+                    "Git server returned an error: {}",
+                    String::from_utf8_lossy(message).trim_end()
+                ),
+                _ => bail!("Git server response is malformed."),
+            },
+            PktLine::Data(data) => {
+                in_pack_section = data == b"packfile\n";
+            }
+            PktLine::Delimiter => in_pack_section = false,
+            PktLine::Flush | PktLine::ResponseEnd => break,
+        }
+    }
+
+    if pack.is_empty() {
+        bail!("Git server response doesn't contain a pack.");
+    }
+
+    Ok(pack)
+}
+
+/// Parses the pack and returns all objects it contains indexed by their IDs. Deltified objects
+/// are resolved against the base objects from the same pack.
+fn parse_pack(pack: &[u8]) -> anyhow::Result<HashMap<String, GitObject>> {
+    if pack.len() < 12 || &pack[..4] != b"PACK" {
+        bail!("Git pack is malformed.");
+    }
+
+    let version = u32::from_be_bytes([pack[4], pack[5], pack[6], pack[7]]);
+    if version != 2 && version != 3 {
+        bail!("Git pack version ({version}) isn't supported.");
+    }
+
+    let objects_count = u32::from_be_bytes([pack[8], pack[9], pack[10], pack[11]]) as usize;
+
+    // Objects indexed by their offset within the pack (for `ofs-delta`) and by their IDs.
+    let mut objects_by_offset = HashMap::new();
+    let mut objects = HashMap::new();
+    // Deltas that reference base objects by ID that might not have been seen yet.
+    let mut pending_ref_deltas = vec![];
+
+    let mut offset = 12;
+    for _ in 0..objects_count {
+        let object_offset = offset;
+
+        let mut byte = read_byte(pack, &mut offset)?;
+        let kind = (byte >> 4) & 0x7;
+        let mut size = (byte & 0x0f) as usize;
+        let mut shift = 4;
+        while byte & 0x80 != 0 {
+            byte = read_byte(pack, &mut offset)?;
+            size |= ((byte & 0x7f) as usize)
+                .checked_shl(shift)
+                .ok_or_else(|| anyhow!("Git pack object size is too large."))?;
+            shift += 7;
+        }
+
+        if size > MAX_RESPONSE_SIZE {
+            bail!("Git pack object size is too large.");
+        }
+
+        let object = match kind {
+            1..=4 => {
+                let data = inflate(pack, &mut offset, size)?;
+                GitObject {
+                    kind: object_kind(kind)?,
+                    data,
+                }
+            }
+            // OFS_DELTA: base is referenced by the negative offset relative to the delta.
+            6 => {
+                let mut byte = read_byte(pack, &mut offset)?;
+                let mut base_distance = (byte & 0x7f) as usize;
+                while byte & 0x80 != 0 {
+                    byte = read_byte(pack, &mut offset)?;
+                    base_distance = ((base_distance + 1) << 7) | (byte & 0x7f) as usize;
+                }
+
+                let delta = inflate(pack, &mut offset, size)?;
+                let base: &GitObject = object_offset
+                    .checked_sub(base_distance)
+                    .and_then(|base_offset| objects_by_offset.get(&base_offset))
+                    .ok_or_else(|| anyhow!("Git pack delta base object is missing."))?;
+                GitObject {
+                    kind: base.kind,
+                    data: apply_delta(&base.data, &delta)?,
+                }
+            }
+            // REF_DELTA: base is referenced by the object ID.
+            7 => {
+                let base_oid = pack
+                    .get(offset..offset + OID_LENGTH)
+                    .map(to_hex)
+                    .ok_or_else(|| anyhow!("Git pack is truncated."))?;
+                offset += OID_LENGTH;
+
+                let delta = inflate(pack, &mut offset, size)?;
+                pending_ref_deltas.push((object_offset, base_oid, delta));
+                continue;
+            }
+            _ => bail!("Git pack object type ({kind}) isn't supported."),
+        };
+
+        objects.insert(object_id(&object), object.clone());
+        objects_by_offset.insert(object_offset, object);
+    }
+
+    // Resolve deltas referencing base objects by ID, bases can be deltas themselves.
+    while !pending_ref_deltas.is_empty() {
+        let pending_count = pending_ref_deltas.len();
+        for (object_offset, base_oid, delta) in std::mem::take(&mut pending_ref_deltas) {
+            match objects.get(&base_oid) {
+                Some(base) => {
+                    let object = GitObject {
+                        kind: base.kind,
+                        data: apply_delta(&base.data, &delta)?,
+                    };
+                    objects.insert(object_id(&object), object.clone());
+                    objects_by_offset.insert(object_offset, object);
+                }
+                None => pending_ref_deltas.push((object_offset, base_oid, delta)),
+            }
+        }
+
+        if pending_ref_deltas.len() == pending_count {
+            bail!("Git pack delta base object is missing.");
+        }
+    }
+
+    Ok(objects)
+}
+
+fn read_byte(data: &[u8], offset: &mut usize) -> anyhow::Result<u8> {
+    let byte = data
+        .get(*offset)
+        .copied()
+        .ok_or_else(|| anyhow!("Git pack is truncated."))?;
+    *offset += 1;
+    Ok(byte)
+}
+
+fn object_kind(kind: u8) -> anyhow::Result<GitObjectKind> {
+    Ok(match kind {
+        1 => GitObjectKind::Commit,
+        2 => GitObjectKind::Tree,
+        3 => GitObjectKind::Blob,
+        4 => GitObjectKind::Tag,
+        _ => bail!("Git pack object type ({kind}) isn't supported."),
+    })
+}
+
+/// Inflates the zlib-compressed data starting at the offset, and advances the offset past it.
+fn inflate(pack: &[u8], offset: &mut usize, size: usize) -> anyhow::Result<Vec<u8>> {
+    let input = pack
+        .get(*offset..)
+        .ok_or_else(|| anyhow!("Git pack is truncated."))?;
+
+    let mut decompress = Decompress::new(true);
+    // Reserve an extra byte, so that the stream end is always reached before the buffer is full.
+    let mut data = Vec::with_capacity(size + 1);
+    loop {
+        let consumed = decompress.total_in() as usize;
+        let status = decompress
+            .decompress_vec(&input[consumed..], &mut data, FlushDecompress::Finish)
+            .context("Git pack object cannot be decompressed.")?;
+        if status == Status::StreamEnd {
+            break;
+        }
+
+        if decompress.total_in() as usize == consumed || data.len() > size {
+            bail!("Git pack object is truncated or malformed.");
+        }
+    }
+
+    if data.len() != size {
+        bail!("Git pack object size doesn't match the expected one.");
+    }
+
+    *offset += decompress.total_in() as usize;
+    Ok(data)
+}
+
+/// Reconstructs the object from the base object and the delta.
+fn apply_delta(base: &[u8], delta: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut offset = 0;
+    let base_size = read_delta_size(delta, &mut offset)?;
+    let result_size = read_delta_size(delta, &mut offset)?;
+    if base_size != base.len() || result_size > MAX_RESPONSE_SIZE {
+        bail!("Git pack delta is malformed.");
+    }
+
+    let mut result = Vec::with_capacity(result_size);
+    while offset < delta.len() {
+        let instruction = read_byte(delta, &mut offset)?;
+        if instruction & 0x80 != 0 {
+            // Copy instruction, the lower bits indicate which offset and size bytes are present.
+            let mut copy_offset = 0usize;
+            for index in 0..4 {
+                if instruction & (1 << index) != 0 {
+                    copy_offset |= (read_byte(delta, &mut offset)? as usize) << (8 * index);
+                }
+            }
+
+            let mut copy_size = 0usize;
+            for index in 0..3 {
+                if instruction & (0x10 << index) != 0 {
+                    copy_size |= (read_byte(delta, &mut offset)? as usize) << (8 * index);
+                }
+            }
+            if copy_size == 0 {
+                copy_size = 0x10000;
+            }
+
+            result.extend_from_slice(
+                base.get(copy_offset..copy_offset + copy_size)
+                    .ok_or_else(|| anyhow!("Git pack delta is malformed."))?,
+            );
+        } else if instruction != 0 {
+            // Insert instruction, the instruction itself is the size of the data to insert.
+            let insert_size = instruction as usize;
+            result.extend_from_slice(
+                delta
+                    .get(offset..offset + insert_size)
+                    .ok_or_else(|| anyhow!("Git pack delta is malformed."))?,
+            );
+            offset += insert_size;
+        } else {
+            bail!("Git pack delta is malformed.");
+        }
+    }
+
+    if result.len() != result_size {
+        bail!("Git pack delta is malformed.");
+    }
+
+    Ok(result)
+}
+
+/// Reads the variable-length size from the delta header.
+fn read_delta_size(delta: &[u8], offset: &mut usize) -> anyhow::Result<usize> {
+    let mut size = 0usize;
+    let mut shift = 0;
+    loop {
+        let byte = read_byte(delta, offset)?;
+        size |= ((byte & 0x7f) as usize)
+            .checked_shl(shift)
+            .ok_or_else(|| anyhow!("Git pack delta is malformed."))?;
+        if byte & 0x80 == 0 {
+            return Ok(size);
+        }
+        shift += 7;
+    }
+}
+
+/// Calculates the ID of the object (SHA-1 digest of the object header and data).
+fn object_id(object: &GitObject) -> String {
+    let mut content = format!("{} {}\0", object.kind.as_str(), object.data.len()).into_bytes();
+    content.extend_from_slice(&object.data);
+    to_hex(digest(&SHA1_FOR_LEGACY_USE_ONLY, &content).as_ref())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// Splits the commit object into the headers and the message.
+fn split_commit(object: &GitObject) -> anyhow::Result<(String, String)> {
+    if object.kind != GitObjectKind::Commit {
+        bail!("Git object isn't a commit.");
+    }
+
+    let content = String::from_utf8_lossy(&object.data);
+    let (headers, message) = content.split_once("\n\n").unwrap_or((&content, ""));
+    Ok((headers.to_string(), message.to_string()))
+}
+
+/// Parses the commit object.
+fn parse_commit(hash: &str, object: &GitObject) -> anyhow::Result<GitCommit> {
+    let (headers, message) = split_commit(object)?;
+    let author = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("author "))
+        .ok_or_else(|| anyhow!("Git commit ({hash}) doesn't have an author."))?;
+
+    // Author is formatted as `Name <email> <unix timestamp> <timezone offset>`.
+    let mut author_parts = author.rsplitn(3, ' ');
+    let (Some(offset), Some(timestamp), Some(author)) = (
+        author_parts.next(),
+        author_parts.next(),
+        author_parts.next(),
+    ) else {
+        bail!("Git commit ({hash}) author is malformed.");
+    };
+
+    let offset_sign = if offset.starts_with('-') { -1 } else { 1 };
+    let offset = offset.trim_start_matches(['+', '-']);
+    let (offset_hours, offset_minutes) = (
+        offset.get(..2).and_then(|hours| hours.parse::<i8>().ok()),
+        offset
+            .get(2..4)
+            .and_then(|minutes| minutes.parse::<i8>().ok()),
+    );
+    let utc_offset = match (offset_hours, offset_minutes) {
+        (Some(hours), Some(minutes)) => {
+            UtcOffset::from_hms(offset_sign * hours, offset_sign * minutes, 0)
+                .unwrap_or(UtcOffset::UTC)
+        }
+        _ => UtcOffset::UTC,
+    };
+    let authored_at = timestamp
+        .parse::<i64>()
+        .ok()
+        .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        .ok_or_else(|| anyhow!("Git commit ({hash}) author timestamp is malformed."))?
+        .to_offset(utc_offset)
+        .format(&Rfc3339)?;
+
+    Ok(GitCommit {
+        hash: hash.to_string(),
+        author: author.to_string(),
+        authored_at,
+        message: message.trim_end().to_string(),
+    })
+}
+
+/// Resolves the path within the commit tree to the ID of the object it points to.
+fn resolve_path(
+    objects: &HashMap<String, GitObject>,
+    hash: &str,
+    path: &str,
+) -> anyhow::Result<String> {
+    let commit = objects
+        .get(hash)
+        .ok_or_else(|| anyhow!("Git server didn't return commit ({hash})."))?;
+    let (headers, _) = split_commit(commit)?;
+    let mut oid = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("tree "))
+        .ok_or_else(|| anyhow!("Git commit ({hash}) doesn't have a tree."))?
+        .to_string();
+
+    for segment in path.trim_matches('/').split('/') {
+        let tree = objects
+            .get(&oid)
+            .filter(|object| object.kind == GitObjectKind::Tree)
+            .ok_or_else(|| anyhow!("Path ({path}) doesn't exist in commit ({hash})."))?;
+        oid = find_tree_entry(&tree.data, segment)?
+            .ok_or_else(|| anyhow!("Path ({path}) doesn't exist in commit ({hash})."))?;
+    }
+
+    Ok(oid)
+}
+
+/// Finds the entry with the specified name in the tree object, and returns its object ID. Each
+/// entry is formatted as `<mode> <name>\0<20-byte object ID>`.
+fn find_tree_entry(tree: &[u8], name: &str) -> anyhow::Result<Option<String>> {
+    let mut offset = 0;
+    while offset < tree.len() {
+        let name_end = tree[offset..]
+            .iter()
+            .position(|byte| *byte == 0)
+            .map(|position| offset + position)
+            .ok_or_else(|| anyhow!("Git tree is malformed."))?;
+        let (_, entry_name) = std::str::from_utf8(&tree[offset..name_end])
+            .ok()
+            .and_then(|entry| entry.split_once(' '))
+            .ok_or_else(|| anyhow!("Git tree is malformed."))?;
+        let entry_oid = tree
+            .get(name_end + 1..name_end + 1 + OID_LENGTH)
+            .ok_or_else(|| anyhow!("Git tree is malformed."))?;
+        if entry_name == name {
+            return Ok(Some(to_hex(entry_oid)));
+        }
+
+        offset = name_end + 1 + OID_LENGTH;
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_delta, object_id, parse_commit, parse_pack, parse_pkt_lines, parse_ref, pkt_line,
+        GitClient, GitObject, GitObjectKind, GitRef, PktLine,
+    };
+    use flate2::{write::ZlibEncoder, Compression};
+    use globset::Glob;
+    use httpmock::MockServer;
+    use insta::assert_json_snapshot;
+    use reqwest_middleware::ClientBuilder;
+    use serde_json::json;
+    use std::io::Write;
+    use url::Url;
+
+    fn object(kind: GitObjectKind, data: &[u8]) -> GitObject {
+        GitObject {
+            kind,
+            data: data.to_vec(),
+        }
+    }
+
+    fn tree(entries: &[(&str, &str, &str)]) -> anyhow::Result<GitObject> {
+        let mut data = vec![];
+        for (mode, name, oid) in entries {
+            data.extend(format!("{mode} {name}\0").into_bytes());
+            data.extend(
+                (0..oid.len())
+                    .step_by(2)
+                    .map(|index| u8::from_str_radix(&oid[index..index + 2], 16))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+        Ok(object(GitObjectKind::Tree, &data))
+    }
+
+    fn commit(tree_oid: &str, message: &str) -> GitObject {
+        object(
+            GitObjectKind::Commit,
+            format!("tree {tree_oid}\nauthor Dev <dev@retrack.dev> 946720800 +0100\ncommitter Dev <dev@retrack.dev> 946720800 +0100\n\n{message}\n").as_bytes(),
+        )
+    }
+
+    fn encode_object_header(kind: u8, size: usize) -> Vec<u8> {
+        let mut header = vec![];
+        let mut byte = (kind << 4) | (size & 0x0f) as u8;
+        let mut size = size >> 4;
+        while size > 0 {
+            header.push(byte | 0x80);
+            byte = (size & 0x7f) as u8;
+            size >>= 7;
+        }
+        header.push(byte);
+        header
+    }
+
+    fn deflate(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(data)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Builds the pack with the objects, and an optional delta (base object index, delta data).
+    fn pack(objects: &[GitObject], delta: Option<(usize, &[u8])>) -> anyhow::Result<Vec<u8>> {
+        let mut pack = b"PACK".to_vec();
+        pack.extend(2u32.to_be_bytes());
+        pack.extend((objects.len() as u32 + delta.map_or(0, |_| 1)).to_be_bytes());
+
+        let mut offsets = vec![];
+        for object in objects {
+            offsets.push(pack.len());
+            let kind = match object.kind {
+                GitObjectKind::Commit => 1,
+                GitObjectKind::Tree => 2,
+                GitObjectKind::Blob => 3,
+                GitObjectKind::Tag => 4,
+            };
+            pack.extend(encode_object_header(kind, object.data.len()));
+            pack.extend(deflate(&object.data)?);
+        }
+
+        if let Some((base_index, delta)) = delta {
+            let distance = pack.len() - offsets[base_index];
+            pack.extend(encode_object_header(6, delta.len()));
+            // Distance is encoded with the big-endian base-128 encoding with an offset.
+            let mut encoded_distance = vec![(distance & 0x7f) as u8];
+            let mut distance = distance >> 7;
+            while distance > 0 {
+                distance -= 1;
+                encoded_distance.insert(0, 0x80 | (distance & 0x7f) as u8);
+                distance >>= 7;
+            }
+            pack.extend(encoded_distance);
+            pack.extend(deflate(delta)?);
+        }
+
+        // Checksum isn't verified by the client.
+        pack.extend([0; 20]);
+        Ok(pack)
+    }
+
+    fn fetch_response(pack: &[u8]) -> Vec<u8> {
+        let mut response = pkt_line("shallow-info\n");
+        response.extend(pkt_line(
+            "shallow 0000000000000000000000000000000000000000\n",
+        ));
+        response.extend_from_slice(b"0001");
+        response.extend(pkt_line("packfile\n"));
+        for chunk in pack.chunks(1000) {
+            response.extend(format!("{:04x}", chunk.len() + 5).into_bytes());
+            response.push(1);
+            response.extend_from_slice(chunk);
+        }
+        response.extend_from_slice(b"0000");
+        response
+    }
+
+    #[test]
+    fn properly_parses_pkt_lines() -> anyhow::Result<()> {
+        let mut content = pkt_line("version 2\n");
+        content.extend_from_slice(b"000100020000");
+        assert_eq!(
+            parse_pkt_lines(&content)?,
+            vec![
+                PktLine::Data(b"version 2\n"),
+                PktLine::Delimiter,
+                PktLine::ResponseEnd,
+                PktLine::Flush
+            ]
+        );
+
+        assert_eq!(
+            parse_pkt_lines(&pkt_line("ERR access denied\n"))
+                .unwrap_err()
+                .to_string(),
+            "Git server returned an error: access denied"
+        );
+        assert_eq!(
+            parse_pkt_lines(b"00ffversion").unwrap_err().to_string(),
+            "Git server response is truncated."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_parses_refs() -> anyhow::Result<()> {
+        assert_eq!(
+            parse_ref(
+                "5a8b1c2d3e4f5a8b1c2d3e4f5a8b1c2d3e4f5a8b HEAD symref-target:refs/heads/main\n"
+            )?,
+            GitRef {
+                name: "HEAD".to_string(),
+                oid: "5a8b1c2d3e4f5a8b1c2d3e4f5a8b1c2d3e4f5a8b".to_string(),
+                peeled_oid: None,
+                symref_target: Some("refs/heads/main".to_string()),
+            }
+        );
+
+        let git_ref = parse_ref("1111111111111111111111111111111111111111 refs/tags/v1.0.0 peeled:2222222222222222222222222222222222222222")?;
+        assert_eq!(git_ref.commit(), "2222222222222222222222222222222222222222");
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_applies_delta() -> anyhow::Result<()> {
+        let base = b"Hello, world!";
+        // Base size 13, result size 15, copy 7 bytes from offset 0, insert "Retrack!".
+        let delta = [
+            13, 15, 0x90, 7, 8, b'R', b'e', b't', b'r', b'a', b'c', b'k', b'!',
+        ];
+        assert_eq!(apply_delta(base, &delta)?, b"Hello, Retrack!");
+
+        assert_eq!(
+            apply_delta(b"Hello", &delta).unwrap_err().to_string(),
+            "Git pack delta is malformed."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_parses_pack() -> anyhow::Result<()> {
+        let blob = object(GitObjectKind::Blob, b"Hello, world!");
+        let delta = [
+            13, 15, 0x90, 7, 8, b'R', b'e', b't', b'r', b'a', b'c', b'k', b'!',
+        ];
+        let objects = parse_pack(&pack(std::slice::from_ref(&blob), Some((0, &delta)))?)?;
+
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects.get(&object_id(&blob)), Some(&blob));
+        assert_eq!(
+            objects.get(&object_id(&object(GitObjectKind::Blob, b"Hello, Retrack!"))),
+            Some(&object(GitObjectKind::Blob, b"Hello, Retrack!"))
+        );
+
+        // Well-known ID of the empty blob.
+        assert_eq!(
+            object_id(&object(GitObjectKind::Blob, b"")),
+            "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"
+        );
+
+        assert_eq!(
+            parse_pack(b"PACK").unwrap_err().to_string(),
+            "Git pack is malformed."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_parses_commit() -> anyhow::Result<()> {
+        let commit = object(
+            GitObjectKind::Commit,
+            b"tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\nparent 1111111111111111111111111111111111111111\nauthor Dev Retrack <dev@retrack.dev> 946720800 -0130\ncommitter Dev <dev@retrack.dev> 946720800 +0000\ngpgsig -----BEGIN PGP SIGNATURE-----\n \n -----END PGP SIGNATURE-----\n\nRelease v1.0.0\n\nSome details.\n",
+        );
+        assert_json_snapshot!(parse_commit("2222222222222222222222222222222222222222", &commit)?, @r###"
+        {
+          "hash": "2222222222222222222222222222222222222222",
+          "author": "Dev Retrack <dev@retrack.dev>",
+          "authoredAt": "2000-01-01T08:30:00-01:30",
+          "message": "Release v1.0.0\n\nSome details."
+        }
+        "###);
+
+        assert_eq!(
+            parse_commit(
+                "2222222222222222222222222222222222222222",
+                &object(GitObjectKind::Blob, b"")
+            )
+            .unwrap_err()
+            .to_string(),
+            "Git object isn't a commit."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetches_refs_commits_and_files() -> anyhow::Result<()> {
+        let readme = object(GitObjectKind::Blob, b"# Retrack\n");
+        let changelog = object(GitObjectKind::Blob, b"## 1.0.0\n");
+        let docs = tree(&[("100644", "CHANGELOG.md", &object_id(&changelog))])?;
+        let root = tree(&[
+            ("100644", "README.md", &object_id(&readme)),
+            ("40000", "docs", &object_id(&docs)),
+        ])?;
+        let head = commit(&object_id(&root), "Release v1.0.0");
+        let head_oid = object_id(&head);
+
+        let server = MockServer::start();
+        let capabilities_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/retrack.git/info/refs")
+                .query_param("service", "git-upload-pack")
+                .header("git-protocol", "version=2");
+            let mut body = pkt_line("# service=git-upload-pack\n");
+            body.extend_from_slice(b"0000");
+            body.extend(pkt_line("version 2\n"));
+            body.extend(pkt_line("ls-refs=unborn\n"));
+            body.extend(pkt_line("fetch=shallow wait-for-done filter\n"));
+            body.extend(pkt_line("object-format=sha1\n"));
+            body.extend_from_slice(b"0000");
+            then.status(200).body(body);
+        });
+        let ls_refs_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/retrack.git/git-upload-pack")
+                .body_contains("command=ls-refs")
+                .body_contains("ref-prefix refs/tags/");
+            let mut body = pkt_line(&format!("{head_oid} refs/tags/v1.0.0\n"));
+            body.extend(pkt_line(&format!(
+                "1111111111111111111111111111111111111111 refs/tags/v1.10.0 peeled:{head_oid}\n"
+            )));
+            body.extend(pkt_line(&format!("{head_oid} refs/tags/v1.2.0\n")));
+            body.extend(pkt_line(&format!("{head_oid} refs/tags/nightly\n")));
+            body.extend_from_slice(b"0000");
+            then.status(200).body(body);
+        });
+        let fetch_commit_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/retrack.git/git-upload-pack")
+                .body_contains("command=fetch")
+                .body_contains("filter tree:0")
+                .body_contains(format!("want {head_oid}"));
+            then.status(200).body(fetch_response(
+                &pack(std::slice::from_ref(&head), None).unwrap(),
+            ));
+        });
+        let fetch_trees_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/retrack.git/git-upload-pack")
+                .body_contains("command=fetch")
+                .body_contains("filter blob:none")
+                .body_contains(format!("want {head_oid}"));
+            then.status(200).body(fetch_response(
+                &pack(&[head.clone(), root.clone(), docs.clone()], None).unwrap(),
+            ));
+        });
+        let fetch_blob_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/retrack.git/git-upload-pack")
+                .body_contains("command=fetch")
+                .body_contains(format!("want {}", object_id(&changelog)));
+            then.status(200).body(fetch_response(
+                &pack(std::slice::from_ref(&changelog), None).unwrap(),
+            ));
+        });
+
+        let http_client = ClientBuilder::new(reqwest::Client::new()).build();
+        let url = Url::parse(&format!("{}/retrack.git", server.base_url()))?;
+        let client = GitClient::connect(&http_client, &url).await?;
+        capabilities_mock.assert();
+
+        let tags = client
+            .list_tags(&Glob::new("v*")?.compile_matcher(), 2)
+            .await?;
+        ls_refs_mock.assert();
+        assert_eq!(
+            serde_json::to_value(tags)?,
+            json!([
+                { "name": "v1.10.0", "commit": head_oid },
+                { "name": "v1.2.0", "commit": head_oid }
+            ])
+        );
+
+        let commit = client.get_commit(&head_oid).await?;
+        fetch_commit_mock.assert();
+        assert_eq!(commit.message, "Release v1.0.0");
+        assert_eq!(commit.author, "Dev <dev@retrack.dev>");
+
+        let content = client
+            .get_file_content(&head_oid, "docs/CHANGELOG.md")
+            .await?;
+        fetch_trees_mock.assert();
+        fetch_blob_mock.assert();
+        assert_eq!(content, "## 1.0.0\n");
+
+        assert_eq!(
+            client
+                .get_file_content(&head_oid, "docs/README.md")
+                .await
+                .unwrap_err()
+                .to_string(),
+            format!("Path (docs/README.md) doesn't exist in commit ({head_oid}).")
+        );
+        assert_eq!(
+            client
+                .get_file_content(&head_oid, "docs")
+                .await
+                .unwrap_err()
+                .to_string(),
+            "Path (docs) doesn't point to a file."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_protocol_version_2_is_not_supported() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let capabilities_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/retrack.git/info/refs");
+            let mut body = pkt_line("# service=git-upload-pack\n");
+            body.extend_from_slice(b"0000");
+            body.extend(pkt_line(
+                "5a8b1c2d3e4f5a8b1c2d3e4f5a8b1c2d3e4f5a8b HEAD\0multi_ack\n",
+            ));
+            body.extend_from_slice(b"0000");
+            then.status(200).body(body);
+        });
+
+        let http_client = ClientBuilder::new(reqwest::Client::new()).build();
+        let url = Url::parse(&format!("{}/retrack.git", server.base_url()))?;
+        let err = GitClient::connect(&http_client, &url).await.err().unwrap();
+        capabilities_mock.assert();
+        assert_eq!(
+            err.to_string(),
+            "Git server doesn't support protocol version 2."
+        );
+
+        Ok(())
+    }
+}
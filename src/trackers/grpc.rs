@@ -0,0 +1,13 @@
+mod descriptor_pool;
+mod grpc_frame;
+mod json_codec;
+mod wire;
+
+pub use self::{
+    descriptor_pool::{DescriptorPool, MethodDescriptor},
+    grpc_frame::{decode_grpc_frame, encode_grpc_frame},
+    json_codec::{decode_json_message, encode_json_message},
+};
+
+#[cfg(test)]
+pub use self::descriptor_pool::tests::mock_descriptor_set;
@@ -0,0 +1,736 @@
+use crate::trackers::grpc::wire::{
+    WireReader, WIRE_TYPE_I32, WIRE_TYPE_I64, WIRE_TYPE_LEN, WIRE_TYPE_VARINT,
+};
+use anyhow::bail;
+use std::collections::HashMap;
+
+/// Type of the protobuf message field (`google.protobuf.FieldDescriptorProto.Type`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FieldKind {
+    Double,
+    Float,
+    Int64,
+    UInt64,
+    Int32,
+    Fixed64,
+    Fixed32,
+    Bool,
+    String,
+    Group,
+    Message,
+    Bytes,
+    UInt32,
+    Enum,
+    SFixed32,
+    SFixed64,
+    SInt32,
+    SInt64,
+}
+
+impl FieldKind {
+    /// Returns the wire type of the field values (values of the packed repeated fields are
+    /// encoded as a single length-delimited value).
+    pub fn wire_type(&self) -> u8 {
+        match self {
+            FieldKind::Double | FieldKind::Fixed64 | FieldKind::SFixed64 => WIRE_TYPE_I64,
+            FieldKind::Float | FieldKind::Fixed32 | FieldKind::SFixed32 => WIRE_TYPE_I32,
+            FieldKind::Int64
+            | FieldKind::UInt64
+            | FieldKind::Int32
+            | FieldKind::Bool
+            | FieldKind::UInt32
+            | FieldKind::Enum
+            | FieldKind::SInt32
+            | FieldKind::SInt64 => WIRE_TYPE_VARINT,
+            FieldKind::String | FieldKind::Group | FieldKind::Message | FieldKind::Bytes => {
+                WIRE_TYPE_LEN
+            }
+        }
+    }
+
+    /// Checks if repeated values of this kind can be packed.
+    pub fn is_packable(&self) -> bool {
+        self.wire_type() != WIRE_TYPE_LEN
+    }
+
+    fn from_number(number: u64) -> anyhow::Result<Self> {
+        Ok(match number {
+            1 => FieldKind::Double,
+            2 => FieldKind::Float,
+            3 => FieldKind::Int64,
+            4 => FieldKind::UInt64,
+            5 => FieldKind::Int32,
+            6 => FieldKind::Fixed64,
+            7 => FieldKind::Fixed32,
+            8 => FieldKind::Bool,
+            9 => FieldKind::String,
+            10 => FieldKind::Group,
+            11 => FieldKind::Message,
+            12 => FieldKind::Bytes,
+            13 => FieldKind::UInt32,
+            14 => FieldKind::Enum,
+            15 => FieldKind::SFixed32,
+            16 => FieldKind::SFixed64,
+            17 => FieldKind::SInt32,
+            18 => FieldKind::SInt64,
+            _ => bail!("Protobuf field type ({number}) is not valid."),
+        })
+    }
+}
+
+/// Describes a field of the protobuf message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    /// Name of the field as defined in the `.proto` file.
+    pub name: String,
+    /// Name of the field in the JSON representation of the message.
+    pub json_name: String,
+    /// Field number.
+    pub number: u32,
+    /// Type of the field.
+    pub kind: FieldKind,
+    /// Indicates whether the field is repeated.
+    pub is_repeated: bool,
+    /// Fully-qualified name of the message or enum type for the `Message` and `Enum` fields.
+    pub type_name: Option<String>,
+}
+
+/// Describes a protobuf message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageDescriptor {
+    /// Fully-qualified name of the message.
+    pub name: String,
+    /// Fields of the message.
+    pub fields: Vec<FieldDescriptor>,
+    /// Indicates whether the message is a synthetic entry of the map field.
+    pub is_map_entry: bool,
+}
+
+impl MessageDescriptor {
+    /// Returns field by its number.
+    pub fn field(&self, number: u32) -> Option<&FieldDescriptor> {
+        self.fields.iter().find(|field| field.number == number)
+    }
+
+    /// Returns field by its JSON or original name.
+    pub fn field_by_name(&self, name: &str) -> Option<&FieldDescriptor> {
+        self.fields
+            .iter()
+            .find(|field| field.json_name == name || field.name == name)
+    }
+}
+
+/// Describes a protobuf enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumDescriptor {
+    /// Names and numbers of the enum values.
+    pub values: Vec<(String, i32)>,
+}
+
+/// Describes a gRPC service method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodDescriptor {
+    /// Path of the method in the gRPC request (e.g., `/retrack.v1.Status/GetStatus`).
+    pub path: String,
+    /// Fully-qualified name of the request message.
+    pub input_type: String,
+    /// Fully-qualified name of the response message.
+    pub output_type: String,
+    /// Indicates whether the client sends a stream of request messages.
+    pub client_streaming: bool,
+    /// Indicates whether the server sends a stream of response messages.
+    pub server_streaming: bool,
+}
+
+/// Collection of messages, enums, and service methods from the protobuf descriptor set
+/// (`google.protobuf.FileDescriptorSet`). Type names are stored without the leading dot.
+#[derive(Debug, Default)]
+pub struct DescriptorPool {
+    messages: HashMap<String, MessageDescriptor>,
+    enums: HashMap<String, EnumDescriptor>,
+    methods: HashMap<String, MethodDescriptor>,
+}
+
+impl DescriptorPool {
+    /// Decodes the binary protobuf descriptor set.
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut pool = Self::default();
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            match reader.read_tag()? {
+                // FileDescriptorSet.file
+                (1, WIRE_TYPE_LEN) => pool.decode_file(reader.read_bytes()?)?,
+                (number, wire_type) => reader.skip(number, wire_type)?,
+            }
+        }
+
+        Ok(pool)
+    }
+
+    /// Returns message by its fully-qualified name.
+    pub fn message(&self, name: &str) -> Option<&MessageDescriptor> {
+        self.messages.get(name.trim_start_matches('.'))
+    }
+
+    /// Returns enum by its fully-qualified name.
+    pub fn enumeration(&self, name: &str) -> Option<&EnumDescriptor> {
+        self.enums.get(name.trim_start_matches('.'))
+    }
+
+    /// Returns method by its fully-qualified name (e.g., `retrack.v1.Status/GetStatus`).
+    pub fn method(&self, name: &str) -> Option<&MethodDescriptor> {
+        self.methods.get(name.trim_start_matches('/'))
+    }
+
+    /// Decodes `google.protobuf.FileDescriptorProto`.
+    fn decode_file(&mut self, bytes: &[u8]) -> anyhow::Result<()> {
+        let mut package = String::new();
+        let mut messages = vec![];
+        let mut enums = vec![];
+        let mut services = vec![];
+
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            match reader.read_tag()? {
+                (2, WIRE_TYPE_LEN) => package = reader.read_string()?.to_string(),
+                (4, WIRE_TYPE_LEN) => messages.push(reader.read_bytes()?),
+                (5, WIRE_TYPE_LEN) => enums.push(reader.read_bytes()?),
+                (6, WIRE_TYPE_LEN) => services.push(reader.read_bytes()?),
+                (number, wire_type) => reader.skip(number, wire_type)?,
+            }
+        }
+
+        for message in messages {
+            self.decode_message(&package, message)?;
+        }
+        for enumeration in enums {
+            self.decode_enum(&package, enumeration)?;
+        }
+        for service in services {
+            self.decode_service(&package, service)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decodes `google.protobuf.DescriptorProto` along with the nested messages and enums.
+    fn decode_message(&mut self, scope: &str, bytes: &[u8]) -> anyhow::Result<()> {
+        let mut name = String::new();
+        let mut fields = vec![];
+        let mut nested_messages = vec![];
+        let mut nested_enums = vec![];
+        let mut is_map_entry = false;
+
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            match reader.read_tag()? {
+                (1, WIRE_TYPE_LEN) => name = reader.read_string()?.to_string(),
+                (2, WIRE_TYPE_LEN) => fields.push(Self::decode_field(reader.read_bytes()?)?),
+                (3, WIRE_TYPE_LEN) => nested_messages.push(reader.read_bytes()?),
+                (4, WIRE_TYPE_LEN) => nested_enums.push(reader.read_bytes()?),
+                // MessageOptions.map_entry
+                (7, WIRE_TYPE_LEN) => {
+                    let mut options_reader = WireReader::new(reader.read_bytes()?);
+                    while !options_reader.is_empty() {
+                        match options_reader.read_tag()? {
+                            (7, WIRE_TYPE_VARINT) => {
+                                is_map_entry = options_reader.read_varint()? != 0
+                            }
+                            (number, wire_type) => options_reader.skip(number, wire_type)?,
+                        }
+                    }
+                }
+                (number, wire_type) => reader.skip(number, wire_type)?,
+            }
+        }
+
+        let full_name = Self::full_name(scope, &name);
+        for nested_message in nested_messages {
+            self.decode_message(&full_name, nested_message)?;
+        }
+        for nested_enum in nested_enums {
+            self.decode_enum(&full_name, nested_enum)?;
+        }
+
+        self.messages.insert(
+            full_name.clone(),
+            MessageDescriptor {
+                name: full_name,
+                fields,
+                is_map_entry,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Decodes `google.protobuf.FieldDescriptorProto`.
+    fn decode_field(bytes: &[u8]) -> anyhow::Result<FieldDescriptor> {
+        let mut name = String::new();
+        let mut json_name = None;
+        let mut number = 0;
+        let mut kind = None;
+        let mut is_repeated = false;
+        let mut type_name = None;
+
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            match reader.read_tag()? {
+                (1, WIRE_TYPE_LEN) => name = reader.read_string()?.to_string(),
+                (3, WIRE_TYPE_VARINT) => number = u32::try_from(reader.read_varint()?)?,
+                // Label: `LABEL_REPEATED` is 3.
+                (4, WIRE_TYPE_VARINT) => is_repeated = reader.read_varint()? == 3,
+                (5, WIRE_TYPE_VARINT) => {
+                    kind = Some(FieldKind::from_number(reader.read_varint()?)?)
+                }
+                (6, WIRE_TYPE_LEN) => {
+                    type_name = Some(reader.read_string()?.trim_start_matches('.').to_string())
+                }
+                (10, WIRE_TYPE_LEN) => json_name = Some(reader.read_string()?.to_string()),
+                (field_number, wire_type) => reader.skip(field_number, wire_type)?,
+            }
+        }
+
+        let Some(kind) = kind else {
+            bail!("Protobuf field ('{name}') doesn't have a type.");
+        };
+
+        if number == 0 {
+            bail!("Protobuf field ('{name}') doesn't have a number.");
+        }
+
+        Ok(FieldDescriptor {
+            json_name: json_name.unwrap_or_else(|| Self::json_name(&name)),
+            name,
+            number,
+            kind,
+            is_repeated,
+            type_name,
+        })
+    }
+
+    /// Decodes `google.protobuf.EnumDescriptorProto`.
+    fn decode_enum(&mut self, scope: &str, bytes: &[u8]) -> anyhow::Result<()> {
+        let mut name = String::new();
+        let mut values = vec![];
+
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            match reader.read_tag()? {
+                (1, WIRE_TYPE_LEN) => name = reader.read_string()?.to_string(),
+                (2, WIRE_TYPE_LEN) => {
+                    let mut value_name = String::new();
+                    let mut value_number = 0;
+                    let mut value_reader = WireReader::new(reader.read_bytes()?);
+                    while !value_reader.is_empty() {
+                        match value_reader.read_tag()? {
+                            (1, WIRE_TYPE_LEN) => {
+                                value_name = value_reader.read_string()?.to_string()
+                            }
+                            (2, WIRE_TYPE_VARINT) => {
+                                value_number = value_reader.read_varint()? as i32
+                            }
+                            (number, wire_type) => value_reader.skip(number, wire_type)?,
+                        }
+                    }
+                    values.push((value_name, value_number));
+                }
+                (number, wire_type) => reader.skip(number, wire_type)?,
+            }
+        }
+
+        self.enums
+            .insert(Self::full_name(scope, &name), EnumDescriptor { values });
+
+        Ok(())
+    }
+
+    /// Decodes `google.protobuf.ServiceDescriptorProto`.
+    fn decode_service(&mut self, package: &str, bytes: &[u8]) -> anyhow::Result<()> {
+        let mut name = String::new();
+        let mut methods = vec![];
+
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            match reader.read_tag()? {
+                (1, WIRE_TYPE_LEN) => name = reader.read_string()?.to_string(),
+                (2, WIRE_TYPE_LEN) => {
+                    let mut method_name = String::new();
+                    let mut method = MethodDescriptor {
+                        path: String::new(),
+                        input_type: String::new(),
+                        output_type: String::new(),
+                        client_streaming: false,
+                        server_streaming: false,
+                    };
+                    let mut method_reader = WireReader::new(reader.read_bytes()?);
+                    while !method_reader.is_empty() {
+                        match method_reader.read_tag()? {
+                            (1, WIRE_TYPE_LEN) => {
+                                method_name = method_reader.read_string()?.to_string()
+                            }
+                            (2, WIRE_TYPE_LEN) => {
+                                method.input_type = method_reader
+                                    .read_string()?
+                                    .trim_start_matches('.')
+                                    .to_string()
+                            }
+                            (3, WIRE_TYPE_LEN) => {
+                                method.output_type = method_reader
+                                    .read_string()?
+                                    .trim_start_matches('.')
+                                    .to_string()
+                            }
+                            (5, WIRE_TYPE_VARINT) => {
+                                method.client_streaming = method_reader.read_varint()? != 0
+                            }
+                            (6, WIRE_TYPE_VARINT) => {
+                                method.server_streaming = method_reader.read_varint()? != 0
+                            }
+                            (number, wire_type) => method_reader.skip(number, wire_type)?,
+                        }
+                    }
+                    methods.push((method_name, method));
+                }
+                (number, wire_type) => reader.skip(number, wire_type)?,
+            }
+        }
+
+        let service_name = Self::full_name(package, &name);
+        for (method_name, method) in methods {
+            let method_full_name = format!("{service_name}/{method_name}");
+            self.methods.insert(
+                method_full_name.clone(),
+                MethodDescriptor {
+                    path: format!("/{method_full_name}"),
+                    ..method
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns fully-qualified name of the type defined in the specified scope (package or
+    /// message).
+    fn full_name(scope: &str, name: &str) -> String {
+        if scope.is_empty() {
+            name.to_string()
+        } else {
+            format!("{scope}.{name}")
+        }
+    }
+
+    /// Converts field name to the lowerCamelCase JSON name the same way `protoc` does.
+    fn json_name(name: &str) -> String {
+        let mut json_name = String::with_capacity(name.len());
+        let mut capitalize_next = false;
+        for char in name.chars() {
+            if char == '_' {
+                capitalize_next = true;
+            } else if capitalize_next {
+                json_name.push(char.to_ascii_uppercase());
+                capitalize_next = false;
+            } else {
+                json_name.push(char);
+            }
+        }
+        json_name
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{DescriptorPool, FieldDescriptor, FieldKind, MethodDescriptor};
+    use crate::trackers::grpc::wire::{write_bytes, write_tag, write_varint, WIRE_TYPE_VARINT};
+    use insta::assert_debug_snapshot;
+
+    fn varint_field(buf: &mut Vec<u8>, number: u32, value: u64) {
+        write_tag(buf, number, WIRE_TYPE_VARINT);
+        write_varint(buf, value);
+    }
+
+    fn field(name: &str, number: u64, kind: u64, label: u64, type_name: Option<&str>) -> Vec<u8> {
+        let mut buf = vec![];
+        write_bytes(&mut buf, 1, name.as_bytes());
+        varint_field(&mut buf, 3, number);
+        varint_field(&mut buf, 4, label);
+        varint_field(&mut buf, 5, kind);
+        if let Some(type_name) = type_name {
+            write_bytes(&mut buf, 6, type_name.as_bytes());
+        }
+        buf
+    }
+
+    /// Builds the descriptor set of the following file:
+    /// ```proto
+    /// syntax = "proto3";
+    /// package retrack.v1;
+    ///
+    /// service Status {
+    ///   rpc GetStatus(StatusRequest) returns (StatusResponse);
+    /// }
+    ///
+    /// message StatusRequest {
+    ///   string service_name = 1;
+    /// }
+    ///
+    /// message StatusResponse {
+    ///   enum State { STATE_UNKNOWN = 0; STATE_UP = 1; STATE_DOWN = 2; }
+    ///   message Check { string name = 1; bool passed = 2; }
+    ///   State state = 1;
+    ///   int64 uptime_seconds = 2;
+    ///   repeated Check checks = 3;
+    ///   map<string, int32> counters = 4;
+    ///   repeated uint32 ports = 5;
+    ///   bytes digest = 6;
+    ///   double load = 7;
+    ///   sint32 delta = 8;
+    ///   google.protobuf.Timestamp updated_at = 9;
+    ///   google.protobuf.Duration latency = 10;
+    ///   google.protobuf.StringValue region = 11;
+    ///   google.protobuf.Struct labels = 12;
+    /// }
+    /// ```
+    pub fn mock_descriptor_set() -> Vec<u8> {
+        let mut status_request = vec![];
+        write_bytes(&mut status_request, 1, b"StatusRequest");
+        write_bytes(
+            &mut status_request,
+            2,
+            &field("service_name", 1, 9, 1, None),
+        );
+
+        let mut state_enum = vec![];
+        write_bytes(&mut state_enum, 1, b"State");
+        for (name, number) in [("STATE_UNKNOWN", 0), ("STATE_UP", 1), ("STATE_DOWN", 2)] {
+            let mut value = vec![];
+            write_bytes(&mut value, 1, name.as_bytes());
+            varint_field(&mut value, 2, number);
+            write_bytes(&mut state_enum, 2, &value);
+        }
+
+        let mut check_message = vec![];
+        write_bytes(&mut check_message, 1, b"Check");
+        write_bytes(&mut check_message, 2, &field("name", 1, 9, 1, None));
+        write_bytes(&mut check_message, 2, &field("passed", 2, 8, 1, None));
+
+        let mut counters_entry = vec![];
+        write_bytes(&mut counters_entry, 1, b"CountersEntry");
+        write_bytes(&mut counters_entry, 2, &field("key", 1, 9, 1, None));
+        write_bytes(&mut counters_entry, 2, &field("value", 2, 5, 1, None));
+        let mut map_entry_options = vec![];
+        varint_field(&mut map_entry_options, 7, 1);
+        write_bytes(&mut counters_entry, 7, &map_entry_options);
+
+        let mut status_response = vec![];
+        write_bytes(&mut status_response, 1, b"StatusResponse");
+        for field_bytes in [
+            field("state", 1, 14, 1, Some(".retrack.v1.StatusResponse.State")),
+            field("uptime_seconds", 2, 3, 1, None),
+            field("checks", 3, 11, 3, Some(".retrack.v1.StatusResponse.Check")),
+            field(
+                "counters",
+                4,
+                11,
+                3,
+                Some(".retrack.v1.StatusResponse.CountersEntry"),
+            ),
+            field("ports", 5, 13, 3, None),
+            field("digest", 6, 12, 1, None),
+            field("load", 7, 1, 1, None),
+            field("delta", 8, 17, 1, None),
+            field("updated_at", 9, 11, 1, Some(".google.protobuf.Timestamp")),
+            field("latency", 10, 11, 1, Some(".google.protobuf.Duration")),
+            field("region", 11, 11, 1, Some(".google.protobuf.StringValue")),
+            field("labels", 12, 11, 1, Some(".google.protobuf.Struct")),
+        ] {
+            write_bytes(&mut status_response, 2, &field_bytes);
+        }
+        write_bytes(&mut status_response, 3, &check_message);
+        write_bytes(&mut status_response, 3, &counters_entry);
+        write_bytes(&mut status_response, 4, &state_enum);
+
+        let mut method = vec![];
+        write_bytes(&mut method, 1, b"GetStatus");
+        write_bytes(&mut method, 2, b".retrack.v1.StatusRequest");
+        write_bytes(&mut method, 3, b".retrack.v1.StatusResponse");
+        let mut service = vec![];
+        write_bytes(&mut service, 1, b"Status");
+        write_bytes(&mut service, 2, &method);
+
+        let mut file = vec![];
+        write_bytes(&mut file, 1, b"retrack/v1/status.proto");
+        write_bytes(&mut file, 2, b"retrack.v1");
+        write_bytes(&mut file, 4, &status_request);
+        write_bytes(&mut file, 4, &status_response);
+        write_bytes(&mut file, 6, &service);
+        write_bytes(&mut file, 12, b"proto3");
+
+        let mut descriptor_set = vec![];
+        write_bytes(&mut descriptor_set, 1, &file);
+        write_bytes(&mut descriptor_set, 1, &well_known_types_file());
+        descriptor_set
+    }
+
+    /// Builds the descriptor of the well-known types used by the mock descriptor set.
+    fn well_known_types_file() -> Vec<u8> {
+        let message = |name: &str, fields: Vec<Vec<u8>>| {
+            let mut buf = vec![];
+            write_bytes(&mut buf, 1, name.as_bytes());
+            for field_bytes in fields {
+                write_bytes(&mut buf, 2, &field_bytes);
+            }
+            buf
+        };
+
+        let mut fields_entry = message(
+            "FieldsEntry",
+            vec![
+                field("key", 1, 9, 1, None),
+                field("value", 2, 11, 1, Some(".google.protobuf.Value")),
+            ],
+        );
+        let mut map_entry_options = vec![];
+        varint_field(&mut map_entry_options, 7, 1);
+        write_bytes(&mut fields_entry, 7, &map_entry_options);
+        let mut struct_message = message(
+            "Struct",
+            vec![field(
+                "fields",
+                1,
+                11,
+                3,
+                Some(".google.protobuf.Struct.FieldsEntry"),
+            )],
+        );
+        write_bytes(&mut struct_message, 3, &fields_entry);
+
+        let mut null_value = vec![];
+        write_bytes(&mut null_value, 1, b"NullValue");
+        let mut null_value_value = vec![];
+        write_bytes(&mut null_value_value, 1, b"NULL_VALUE");
+        varint_field(&mut null_value_value, 2, 0);
+        write_bytes(&mut null_value, 2, &null_value_value);
+
+        let mut file = vec![];
+        write_bytes(&mut file, 2, b"google.protobuf");
+        for message_bytes in [
+            message(
+                "Timestamp",
+                vec![
+                    field("seconds", 1, 3, 1, None),
+                    field("nanos", 2, 5, 1, None),
+                ],
+            ),
+            message(
+                "Duration",
+                vec![
+                    field("seconds", 1, 3, 1, None),
+                    field("nanos", 2, 5, 1, None),
+                ],
+            ),
+            message("StringValue", vec![field("value", 1, 9, 1, None)]),
+            struct_message,
+            message(
+                "Value",
+                vec![
+                    field("null_value", 1, 14, 1, Some(".google.protobuf.NullValue")),
+                    field("number_value", 2, 1, 1, None),
+                    field("string_value", 3, 9, 1, None),
+                    field("bool_value", 4, 8, 1, None),
+                    field("struct_value", 5, 11, 1, Some(".google.protobuf.Struct")),
+                    field("list_value", 6, 11, 1, Some(".google.protobuf.ListValue")),
+                ],
+            ),
+            message(
+                "ListValue",
+                vec![field("values", 1, 11, 3, Some(".google.protobuf.Value"))],
+            ),
+        ] {
+            write_bytes(&mut file, 4, &message_bytes);
+        }
+        write_bytes(&mut file, 5, &null_value);
+        file
+    }
+
+    #[test]
+    fn can_decode_descriptor_set() -> anyhow::Result<()> {
+        let pool = DescriptorPool::decode(&mock_descriptor_set())?;
+
+        assert_eq!(
+            pool.method("retrack.v1.Status/GetStatus"),
+            Some(&MethodDescriptor {
+                path: "/retrack.v1.Status/GetStatus".to_string(),
+                input_type: "retrack.v1.StatusRequest".to_string(),
+                output_type: "retrack.v1.StatusResponse".to_string(),
+                client_streaming: false,
+                server_streaming: false,
+            })
+        );
+        assert_eq!(
+            pool.method("/retrack.v1.Status/GetStatus"),
+            pool.method("retrack.v1.Status/GetStatus")
+        );
+        assert!(pool.method("retrack.v1.Status/Unknown").is_none());
+
+        let request = pool.message(".retrack.v1.StatusRequest").unwrap();
+        assert_eq!(
+            request.fields,
+            vec![FieldDescriptor {
+                name: "service_name".to_string(),
+                json_name: "serviceName".to_string(),
+                number: 1,
+                kind: FieldKind::String,
+                is_repeated: false,
+                type_name: None,
+            }]
+        );
+        assert_eq!(request.field_by_name("serviceName"), request.field(1));
+        assert_eq!(request.field_by_name("service_name"), request.field(1));
+
+        let response = pool.message("retrack.v1.StatusResponse").unwrap();
+        assert!(!response.is_map_entry);
+        assert_eq!(response.fields.len(), 12);
+        assert!(
+            pool.message("retrack.v1.StatusResponse.CountersEntry")
+                .unwrap()
+                .is_map_entry
+        );
+        assert!(pool.message("retrack.v1.StatusResponse.Check").is_some());
+        assert_debug_snapshot!(pool.enumeration("retrack.v1.StatusResponse.State").unwrap().values, @r###"
+        [
+            (
+                "STATE_UNKNOWN",
+                0,
+            ),
+            (
+                "STATE_UP",
+                1,
+            ),
+            (
+                "STATE_DOWN",
+                2,
+            ),
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_decode_malformed_descriptor_set() -> anyhow::Result<()> {
+        assert_debug_snapshot!(DescriptorPool::decode(b"not a descriptor").unwrap_err().to_string(), @r###""Protobuf wire type (6) is not valid.""###);
+
+        let mut file = vec![];
+        let mut message = vec![];
+        write_bytes(&mut message, 1, b"Broken");
+        write_bytes(&mut message, 2, &field("value", 1, 42, 1, None));
+        write_bytes(&mut file, 4, &message);
+        let mut descriptor_set = vec![];
+        write_bytes(&mut descriptor_set, 1, &file);
+        assert_debug_snapshot!(DescriptorPool::decode(&descriptor_set).unwrap_err().to_string(), @r###""Protobuf field type (42) is not valid.""###);
+
+        Ok(())
+    }
+}
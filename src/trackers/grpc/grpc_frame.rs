@@ -0,0 +1,86 @@
+use anyhow::bail;
+
+/// Size of the gRPC message prefix: compressed flag and 4-byte message length.
+const GRPC_FRAME_PREFIX_SIZE: usize = 5;
+
+/// Wraps protobuf message into the gRPC length-prefixed message (uncompressed).
+pub fn encode_grpc_frame(message: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut frame = Vec::with_capacity(GRPC_FRAME_PREFIX_SIZE + message.len());
+    frame.push(0);
+    frame.extend_from_slice(&u32::try_from(message.len())?.to_be_bytes());
+    frame.extend_from_slice(message);
+    Ok(frame)
+}
+
+/// Extracts protobuf message from the gRPC length-prefixed message. Only responses with a single
+/// uncompressed message (unary calls) are supported.
+pub fn decode_grpc_frame(frame: &[u8], max_message_size: usize) -> anyhow::Result<&[u8]> {
+    if frame.len() < GRPC_FRAME_PREFIX_SIZE {
+        bail!("gRPC response doesn't contain a message.");
+    }
+
+    if frame[0] != 0 {
+        bail!("Compressed gRPC messages are not supported.");
+    }
+
+    let message_size = u32::from_be_bytes([frame[1], frame[2], frame[3], frame[4]]) as usize;
+    if message_size > max_message_size {
+        bail!("gRPC response message exceeds {max_message_size} bytes.");
+    }
+
+    let Some(message) = frame.get(GRPC_FRAME_PREFIX_SIZE..GRPC_FRAME_PREFIX_SIZE + message_size)
+    else {
+        bail!("gRPC response message is truncated.");
+    };
+
+    if frame.len() > GRPC_FRAME_PREFIX_SIZE + message_size {
+        bail!("gRPC response contains more than one message.");
+    }
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_grpc_frame, encode_grpc_frame};
+
+    #[test]
+    fn can_encode_and_decode_frames() -> anyhow::Result<()> {
+        let frame = encode_grpc_frame(b"\x0a\x03api")?;
+        assert_eq!(frame, b"\x00\x00\x00\x00\x05\x0a\x03api");
+        assert_eq!(decode_grpc_frame(&frame, 100)?, b"\x0a\x03api");
+
+        // Empty message.
+        assert_eq!(decode_grpc_frame(&encode_grpc_frame(&[])?, 100)?, b"");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_decode_invalid_frames() -> anyhow::Result<()> {
+        let decode_error = |frame: &[u8]| decode_grpc_frame(frame, 5).unwrap_err().to_string();
+
+        assert_eq!(
+            decode_error(b""),
+            "gRPC response doesn't contain a message."
+        );
+        assert_eq!(
+            decode_error(b"\x01\x00\x00\x00\x01\x00"),
+            "Compressed gRPC messages are not supported."
+        );
+        assert_eq!(
+            decode_error(b"\x00\x00\x00\x00\x06abcdef"),
+            "gRPC response message exceeds 5 bytes."
+        );
+        assert_eq!(
+            decode_error(b"\x00\x00\x00\x00\x03ab"),
+            "gRPC response message is truncated."
+        );
+        assert_eq!(
+            decode_error(b"\x00\x00\x00\x00\x01a\x00\x00\x00\x00\x01b"),
+            "gRPC response contains more than one message."
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,850 @@
+use crate::trackers::grpc::{
+    descriptor_pool::{DescriptorPool, FieldDescriptor, FieldKind, MessageDescriptor},
+    wire::{write_bytes, write_tag, write_varint, WireReader, WIRE_TYPE_LEN},
+};
+use anyhow::{anyhow, bail, Context};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE},
+    Engine,
+};
+use serde_json::{json, Map, Number, Value as JsonValue};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// Maximum depth of the nested messages, protects from the stack overflow on recursive messages.
+const MAX_MESSAGE_DEPTH: usize = 64;
+
+/// Encodes JSON value as a protobuf message of the specified type, following the proto3 JSON
+/// mapping (https://protobuf.dev/programming-guides/json).
+pub fn encode_json_message(
+    pool: &DescriptorPool,
+    type_name: &str,
+    value: &JsonValue,
+) -> anyhow::Result<Vec<u8>> {
+    let mut buf = vec![];
+    JsonEncoder { pool }.encode_message(
+        message_descriptor(pool, type_name)?,
+        value,
+        0,
+        &mut buf,
+    )?;
+    Ok(buf)
+}
+
+/// Decodes protobuf message of the specified type to JSON value, following the proto3 JSON
+/// mapping (https://protobuf.dev/programming-guides/json). Fields that aren't present in the
+/// message are omitted.
+pub fn decode_json_message(
+    pool: &DescriptorPool,
+    type_name: &str,
+    bytes: &[u8],
+) -> anyhow::Result<JsonValue> {
+    JsonDecoder { pool }.decode_message(message_descriptor(pool, type_name)?, bytes, 0)
+}
+
+/// Returns descriptor of the message with the specified name.
+fn message_descriptor<'p>(
+    pool: &'p DescriptorPool,
+    type_name: &str,
+) -> anyhow::Result<&'p MessageDescriptor> {
+    pool.message(type_name)
+        .ok_or_else(|| anyhow!("Protobuf message (`{type_name}`) is not found in descriptor set."))
+}
+
+/// Returns the name of the message or enum type referenced by the field.
+fn field_type_name(field: &FieldDescriptor) -> anyhow::Result<&str> {
+    field.type_name.as_deref().ok_or_else(|| {
+        anyhow!(
+            "Protobuf field (`{}`) doesn't have a type name.",
+            field.name
+        )
+    })
+}
+
+/// Checks if the message is a well-known wrapper message (e.g., `google.protobuf.StringValue`)
+/// that is represented in JSON as the wrapped value.
+fn is_wrapper_message(name: &str) -> bool {
+    matches!(
+        name,
+        "google.protobuf.DoubleValue"
+            | "google.protobuf.FloatValue"
+            | "google.protobuf.Int64Value"
+            | "google.protobuf.UInt64Value"
+            | "google.protobuf.Int32Value"
+            | "google.protobuf.UInt32Value"
+            | "google.protobuf.BoolValue"
+            | "google.protobuf.StringValue"
+            | "google.protobuf.BytesValue"
+    )
+}
+
+/// Converts JSON values to protobuf messages.
+struct JsonEncoder<'p> {
+    pool: &'p DescriptorPool,
+}
+
+impl JsonEncoder<'_> {
+    fn encode_message(
+        &self,
+        message: &MessageDescriptor,
+        value: &JsonValue,
+        depth: usize,
+        buf: &mut Vec<u8>,
+    ) -> anyhow::Result<()> {
+        if depth > MAX_MESSAGE_DEPTH {
+            bail!("Protobuf message nesting exceeds {MAX_MESSAGE_DEPTH} levels.");
+        }
+
+        // Well-known types have special JSON representation, they are converted to the regular
+        // JSON representation of the corresponding message first.
+        let value = match message.name.as_str() {
+            "google.protobuf.Timestamp" => Self::timestamp_to_message(value)?,
+            "google.protobuf.Duration" => Self::duration_to_message(value)?,
+            "google.protobuf.Struct" => json!({ "fields": value }),
+            "google.protobuf.ListValue" => json!({ "values": value }),
+            "google.protobuf.Value" => match value {
+                JsonValue::Null => json!({ "nullValue": 0 }),
+                JsonValue::Bool(_) => json!({ "boolValue": value }),
+                JsonValue::Number(_) => json!({ "numberValue": value }),
+                JsonValue::String(_) => json!({ "stringValue": value }),
+                JsonValue::Array(_) => json!({ "listValue": value }),
+                JsonValue::Object(_) => json!({ "structValue": value }),
+            },
+            name if is_wrapper_message(name) => json!({ "value": value }),
+            _ => value.clone(),
+        };
+
+        let JsonValue::Object(fields) = value else {
+            bail!(
+                "Protobuf message (`{}`) should be represented as JSON object, but received: {value}",
+                message.name
+            );
+        };
+
+        for (name, value) in fields.iter() {
+            let Some(field) = message.field_by_name(name) else {
+                bail!(
+                    "Protobuf message (`{}`) doesn't have field `{name}`.",
+                    message.name
+                );
+            };
+
+            // Null means the default value for all fields except for `google.protobuf.Value`.
+            if value.is_null() && field.type_name.as_deref() != Some("google.protobuf.Value") {
+                continue;
+            }
+
+            self.encode_field(field, value, depth, buf)
+                .with_context(|| format!("Failed to encode protobuf field `{name}`."))?;
+        }
+
+        Ok(())
+    }
+
+    fn encode_field(
+        &self,
+        field: &FieldDescriptor,
+        value: &JsonValue,
+        depth: usize,
+        buf: &mut Vec<u8>,
+    ) -> anyhow::Result<()> {
+        if !field.is_repeated {
+            return self.encode_value(field, value, depth, buf);
+        }
+
+        let map_entry = if field.kind == FieldKind::Message {
+            self.pool
+                .message(field_type_name(field)?)
+                .filter(|message| message.is_map_entry)
+        } else {
+            None
+        };
+
+        if let Some(map_entry) = map_entry {
+            let JsonValue::Object(entries) = value else {
+                bail!("Map field should be represented as JSON object, but received: {value}");
+            };
+
+            let (Some(key_field), Some(value_field)) = (map_entry.field(1), map_entry.field(2))
+            else {
+                bail!("Map entry (`{}`) is not valid.", map_entry.name);
+            };
+
+            for (key, value) in entries {
+                // Map keys are always strings in JSON, even for the numeric and boolean keys.
+                let key = match key_field.kind {
+                    FieldKind::String => JsonValue::String(key.clone()),
+                    FieldKind::Bool => JsonValue::Bool(key.parse()?),
+                    _ => JsonValue::Number(key.parse()?),
+                };
+
+                let mut entry_buf = vec![];
+                self.encode_value(key_field, &key, depth, &mut entry_buf)?;
+                self.encode_value(value_field, value, depth, &mut entry_buf)?;
+                write_bytes(buf, field.number, &entry_buf);
+            }
+
+            return Ok(());
+        }
+
+        let JsonValue::Array(values) = value else {
+            bail!("Repeated field should be represented as JSON array, but received: {value}");
+        };
+
+        if field.kind.is_packable() {
+            let mut packed_buf = vec![];
+            for value in values {
+                self.encode_scalar(field, value, &mut packed_buf)?;
+            }
+            write_bytes(buf, field.number, &packed_buf);
+        } else {
+            for value in values {
+                self.encode_value(field, value, depth, buf)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn encode_value(
+        &self,
+        field: &FieldDescriptor,
+        value: &JsonValue,
+        depth: usize,
+        buf: &mut Vec<u8>,
+    ) -> anyhow::Result<()> {
+        match field.kind {
+            FieldKind::Message => {
+                let mut message_buf = vec![];
+                self.encode_message(
+                    message_descriptor(self.pool, field_type_name(field)?)?,
+                    value,
+                    depth + 1,
+                    &mut message_buf,
+                )?;
+                write_bytes(buf, field.number, &message_buf);
+            }
+            FieldKind::String => {
+                let JsonValue::String(value) = value else {
+                    bail!(
+                        "String field should be represented as JSON string, but received: {value}"
+                    );
+                };
+                write_bytes(buf, field.number, value.as_bytes());
+            }
+            FieldKind::Bytes => {
+                let JsonValue::String(value) = value else {
+                    bail!("Bytes field should be represented as base64 JSON string, but received: {value}");
+                };
+                let padded_value = format!("{value}{}", "=".repeat((4 - value.len() % 4) % 4));
+                let bytes = STANDARD
+                    .decode(&padded_value)
+                    .or_else(|_| URL_SAFE.decode(&padded_value))
+                    .context("Bytes field value is not valid base64.")?;
+                write_bytes(buf, field.number, &bytes);
+            }
+            FieldKind::Group => bail!("Protobuf groups are not supported."),
+            _ => {
+                write_tag(buf, field.number, field.kind.wire_type());
+                self.encode_scalar(field, value, buf)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn encode_scalar(
+        &self,
+        field: &FieldDescriptor,
+        value: &JsonValue,
+        buf: &mut Vec<u8>,
+    ) -> anyhow::Result<()> {
+        match field.kind {
+            FieldKind::Double => {
+                buf.extend_from_slice(&Self::parse_float(value)?.to_le_bytes());
+            }
+            FieldKind::Float => {
+                buf.extend_from_slice(&(Self::parse_float(value)? as f32).to_le_bytes());
+            }
+            FieldKind::Int64 => write_varint(buf, Self::parse_integer::<i64>(value)? as u64),
+            FieldKind::UInt64 => write_varint(buf, Self::parse_integer::<u64>(value)?),
+            // Negative 32-bit integers are sign-extended to 64 bits.
+            FieldKind::Int32 => {
+                write_varint(buf, i64::from(Self::parse_integer::<i32>(value)?) as u64)
+            }
+            FieldKind::Fixed64 => {
+                buf.extend_from_slice(&Self::parse_integer::<u64>(value)?.to_le_bytes())
+            }
+            FieldKind::Fixed32 => {
+                buf.extend_from_slice(&Self::parse_integer::<u32>(value)?.to_le_bytes())
+            }
+            FieldKind::Bool => {
+                let JsonValue::Bool(value) = value else {
+                    bail!(
+                        "Bool field should be represented as JSON boolean, but received: {value}"
+                    );
+                };
+                write_varint(buf, u64::from(*value));
+            }
+            FieldKind::UInt32 => write_varint(buf, u64::from(Self::parse_integer::<u32>(value)?)),
+            FieldKind::Enum => {
+                let number = match value {
+                    JsonValue::String(name) => {
+                        let enum_name = field_type_name(field)?;
+                        self.pool
+                            .enumeration(enum_name)
+                            .and_then(|enumeration| {
+                                enumeration
+                                    .values
+                                    .iter()
+                                    .find(|(value_name, _)| value_name == name)
+                            })
+                            .map(|(_, number)| *number)
+                            .ok_or_else(|| {
+                                anyhow!("Enum (`{enum_name}`) doesn't have value `{name}`.")
+                            })?
+                    }
+                    _ => Self::parse_integer::<i32>(value)?,
+                };
+                write_varint(buf, i64::from(number) as u64);
+            }
+            FieldKind::SFixed32 => {
+                buf.extend_from_slice(&Self::parse_integer::<i32>(value)?.to_le_bytes())
+            }
+            FieldKind::SFixed64 => {
+                buf.extend_from_slice(&Self::parse_integer::<i64>(value)?.to_le_bytes())
+            }
+            FieldKind::SInt32 => {
+                let value = Self::parse_integer::<i32>(value)?;
+                write_varint(buf, u64::from(((value << 1) ^ (value >> 31)) as u32));
+            }
+            FieldKind::SInt64 => {
+                let value = Self::parse_integer::<i64>(value)?;
+                write_varint(buf, ((value << 1) ^ (value >> 63)) as u64);
+            }
+            FieldKind::String | FieldKind::Bytes | FieldKind::Message | FieldKind::Group => {
+                bail!("Field (`{}`) is not a scalar field.", field.name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses integer represented either as JSON number or JSON string.
+    fn parse_integer<T: TryFrom<i128>>(value: &JsonValue) -> anyhow::Result<T> {
+        let integer = match value {
+            JsonValue::Number(number) => number
+                .as_i64()
+                .map(i128::from)
+                .or_else(|| number.as_u64().map(i128::from))
+                .or_else(|| {
+                    number
+                        .as_f64()
+                        .filter(|float| float.fract() == 0.0)
+                        .map(|float| float as i128)
+                }),
+            JsonValue::String(string) => string.parse::<i128>().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            anyhow!("Integer field should be represented as JSON number or string, but received: {value}")
+        })?;
+
+        T::try_from(integer).map_err(|_| anyhow!("Integer field value is out of range: {value}"))
+    }
+
+    /// Parses floating point number represented either as JSON number or JSON string.
+    fn parse_float(value: &JsonValue) -> anyhow::Result<f64> {
+        match value {
+            JsonValue::Number(number) => number.as_f64(),
+            JsonValue::String(string) => match string.as_str() {
+                "NaN" => Some(f64::NAN),
+                "Infinity" => Some(f64::INFINITY),
+                "-Infinity" => Some(f64::NEG_INFINITY),
+                string => string.parse().ok(),
+            },
+            _ => None,
+        }
+        .ok_or_else(|| {
+            anyhow!(
+                "Float field should be represented as JSON number or string, but received: {value}"
+            )
+        })
+    }
+
+    /// Converts RFC 3339 timestamp to the JSON representation of `google.protobuf.Timestamp`.
+    fn timestamp_to_message(value: &JsonValue) -> anyhow::Result<JsonValue> {
+        let JsonValue::String(timestamp) = value else {
+            bail!("Timestamp should be represented as RFC 3339 JSON string, but received: {value}");
+        };
+
+        let timestamp = OffsetDateTime::parse(timestamp, &Rfc3339)
+            .with_context(|| format!("Timestamp is not valid RFC 3339 string: {timestamp}"))?;
+        Ok(json!({
+            "seconds": timestamp.unix_timestamp(),
+            "nanos": timestamp.nanosecond()
+        }))
+    }
+
+    /// Converts duration string (e.g., `1.5s`) to the JSON representation of
+    /// `google.protobuf.Duration`.
+    fn duration_to_message(value: &JsonValue) -> anyhow::Result<JsonValue> {
+        let parsed_duration = value
+            .as_str()
+            .and_then(|duration| duration.strip_suffix('s'))
+            .and_then(|duration| {
+                let (is_negative, duration) = match duration.strip_prefix('-') {
+                    Some(duration) => (true, duration),
+                    None => (false, duration),
+                };
+                let (seconds, fraction) = duration.split_once('.').unwrap_or((duration, ""));
+                if fraction.len() > 9 || !fraction.chars().all(|char| char.is_ascii_digit()) {
+                    return None;
+                }
+
+                let seconds = seconds.parse::<i64>().ok()?;
+                let nanos = if fraction.is_empty() {
+                    0
+                } else {
+                    format!("{fraction:0<9}").parse::<i32>().ok()?
+                };
+                Some(if is_negative {
+                    (-seconds, -nanos)
+                } else {
+                    (seconds, nanos)
+                })
+            });
+        let Some((seconds, nanos)) = parsed_duration else {
+            bail!("Duration should be represented as JSON string with `s` suffix (e.g., `1.5s`), but received: {value}");
+        };
+
+        Ok(json!({ "seconds": seconds, "nanos": nanos }))
+    }
+}
+
+/// Converts protobuf messages to JSON values.
+struct JsonDecoder<'p> {
+    pool: &'p DescriptorPool,
+}
+
+impl JsonDecoder<'_> {
+    fn decode_message(
+        &self,
+        message: &MessageDescriptor,
+        bytes: &[u8],
+        depth: usize,
+    ) -> anyhow::Result<JsonValue> {
+        if depth > MAX_MESSAGE_DEPTH {
+            bail!("Protobuf message nesting exceeds {MAX_MESSAGE_DEPTH} levels.");
+        }
+
+        let mut fields = Map::new();
+        let mut reader = WireReader::new(bytes);
+        while !reader.is_empty() {
+            let (number, wire_type) = reader.read_tag()?;
+            // Unknown fields are skipped.
+            let Some(field) = message.field(number) else {
+                reader.skip(number, wire_type)?;
+                continue;
+            };
+
+            self.decode_field(field, &mut reader, wire_type, depth, &mut fields)
+                .with_context(|| format!("Failed to decode protobuf field `{}`.", field.name))?;
+        }
+
+        // Well-known types have special JSON representation.
+        Ok(match message.name.as_str() {
+            "google.protobuf.Timestamp" => Self::message_to_timestamp(&fields)?,
+            "google.protobuf.Duration" => Self::message_to_duration(&fields)?,
+            "google.protobuf.Struct" => fields
+                .remove("fields")
+                .unwrap_or_else(|| JsonValue::Object(Map::new())),
+            "google.protobuf.ListValue" => fields
+                .remove("values")
+                .unwrap_or_else(|| JsonValue::Array(vec![])),
+            "google.protobuf.Value" => fields
+                .into_iter()
+                .find(|(name, _)| name != "nullValue")
+                .map(|(_, value)| value)
+                .unwrap_or(JsonValue::Null),
+            name if is_wrapper_message(name) => match fields.remove("value") {
+                Some(value) => value,
+                None => Self::default_value(message.field(1).ok_or_else(|| {
+                    anyhow!("Wrapper message (`{name}`) doesn't have a value field.")
+                })?),
+            },
+            _ => JsonValue::Object(fields),
+        })
+    }
+
+    fn decode_field(
+        &self,
+        field: &FieldDescriptor,
+        reader: &mut WireReader,
+        wire_type: u8,
+        depth: usize,
+        fields: &mut Map<String, JsonValue>,
+    ) -> anyhow::Result<()> {
+        if !field.is_repeated {
+            let value = self.decode_value(field, reader, wire_type, depth)?;
+            fields.insert(field.json_name.clone(), value);
+            return Ok(());
+        }
+
+        let map_entry = if field.kind == FieldKind::Message {
+            self.pool
+                .message(field_type_name(field)?)
+                .filter(|message| message.is_map_entry)
+        } else {
+            None
+        };
+
+        if let Some(map_entry) = map_entry {
+            let (Some(key_field), Some(value_field)) = (map_entry.field(1), map_entry.field(2))
+            else {
+                bail!("Map entry (`{}`) is not valid.", map_entry.name);
+            };
+
+            let mut key = Self::default_value(key_field);
+            let mut value = Self::default_value(value_field);
+            let mut entry_reader = WireReader::new(reader.read_bytes()?);
+            while !entry_reader.is_empty() {
+                match entry_reader.read_tag()? {
+                    (1, entry_wire_type) => {
+                        key =
+                            self.decode_value(key_field, &mut entry_reader, entry_wire_type, depth)?
+                    }
+                    (2, entry_wire_type) => {
+                        value = self.decode_value(
+                            value_field,
+                            &mut entry_reader,
+                            entry_wire_type,
+                            depth,
+                        )?
+                    }
+                    (number, entry_wire_type) => entry_reader.skip(number, entry_wire_type)?,
+                }
+            }
+
+            let key = match key {
+                JsonValue::String(key) => key,
+                key => key.to_string(),
+            };
+            if let JsonValue::Object(entries) = fields
+                .entry(field.json_name.clone())
+                .or_insert_with(|| JsonValue::Object(Map::new()))
+            {
+                entries.insert(key, value);
+            }
+
+            return Ok(());
+        }
+
+        let mut values = vec![];
+        // Packable fields can be encoded either packed or not.
+        if wire_type == WIRE_TYPE_LEN && field.kind.is_packable() {
+            let mut packed_reader = WireReader::new(reader.read_bytes()?);
+            while !packed_reader.is_empty() {
+                values.push(self.decode_value(
+                    field,
+                    &mut packed_reader,
+                    field.kind.wire_type(),
+                    depth,
+                )?);
+            }
+        } else {
+            values.push(self.decode_value(field, reader, wire_type, depth)?);
+        }
+
+        if let JsonValue::Array(existing_values) = fields
+            .entry(field.json_name.clone())
+            .or_insert_with(|| JsonValue::Array(vec![]))
+        {
+            existing_values.extend(values);
+        }
+
+        Ok(())
+    }
+
+    fn decode_value(
+        &self,
+        field: &FieldDescriptor,
+        reader: &mut WireReader,
+        wire_type: u8,
+        depth: usize,
+    ) -> anyhow::Result<JsonValue> {
+        if field.kind == FieldKind::Group {
+            bail!("Protobuf groups are not supported.");
+        }
+
+        if wire_type != field.kind.wire_type() {
+            bail!(
+                "Protobuf field wire type ({wire_type}) doesn't match the field type ({:?}).",
+                field.kind
+            );
+        }
+
+        Ok(match field.kind {
+            FieldKind::Double => Self::float_value(f64::from_bits(reader.read_fixed64()?)),
+            FieldKind::Float => {
+                Self::float_value(f64::from(f32::from_bits(reader.read_fixed32()?)))
+            }
+            // 64-bit integers are represented as strings to not lose precision.
+            FieldKind::Int64 => JsonValue::String((reader.read_varint()? as i64).to_string()),
+            FieldKind::UInt64 => JsonValue::String(reader.read_varint()?.to_string()),
+            FieldKind::Int32 => json!(reader.read_varint()? as i32),
+            FieldKind::Fixed64 => JsonValue::String(reader.read_fixed64()?.to_string()),
+            FieldKind::Fixed32 => json!(reader.read_fixed32()?),
+            FieldKind::Bool => JsonValue::Bool(reader.read_varint()? != 0),
+            FieldKind::String => JsonValue::String(reader.read_string()?.to_string()),
+            FieldKind::Bytes => JsonValue::String(STANDARD.encode(reader.read_bytes()?)),
+            FieldKind::UInt32 => json!(reader.read_varint()? as u32),
+            FieldKind::Enum => {
+                let number = reader.read_varint()? as i32;
+                // Unknown enum values are represented as numbers.
+                self.pool
+                    .enumeration(field_type_name(field)?)
+                    .and_then(|enumeration| {
+                        enumeration
+                            .values
+                            .iter()
+                            .find(|(_, value_number)| *value_number == number)
+                    })
+                    .map(|(name, _)| JsonValue::String(name.clone()))
+                    .unwrap_or_else(|| json!(number))
+            }
+            FieldKind::SFixed32 => json!(reader.read_fixed32()? as i32),
+            FieldKind::SFixed64 => JsonValue::String((reader.read_fixed64()? as i64).to_string()),
+            FieldKind::SInt32 => {
+                let value = reader.read_varint()? as u32;
+                json!(((value >> 1) as i32) ^ -((value & 1) as i32))
+            }
+            FieldKind::SInt64 => {
+                let value = reader.read_varint()?;
+                JsonValue::String((((value >> 1) as i64) ^ -((value & 1) as i64)).to_string())
+            }
+            FieldKind::Message => self.decode_message(
+                message_descriptor(self.pool, field_type_name(field)?)?,
+                reader.read_bytes()?,
+                depth + 1,
+            )?,
+            FieldKind::Group => unreachable!(),
+        })
+    }
+
+    /// Returns JSON representation of the default value of the field.
+    fn default_value(field: &FieldDescriptor) -> JsonValue {
+        match field.kind {
+            FieldKind::Double | FieldKind::Float => json!(0.0),
+            FieldKind::Int64
+            | FieldKind::UInt64
+            | FieldKind::Fixed64
+            | FieldKind::SFixed64
+            | FieldKind::SInt64 => JsonValue::String("0".to_string()),
+            FieldKind::Int32
+            | FieldKind::Fixed32
+            | FieldKind::UInt32
+            | FieldKind::SFixed32
+            | FieldKind::SInt32
+            | FieldKind::Enum => json!(0),
+            FieldKind::Bool => JsonValue::Bool(false),
+            FieldKind::String | FieldKind::Bytes => JsonValue::String(String::new()),
+            FieldKind::Message | FieldKind::Group => JsonValue::Object(Map::new()),
+        }
+    }
+
+    /// Returns JSON representation of the floating point number, non-finite numbers are
+    /// represented as strings.
+    fn float_value(value: f64) -> JsonValue {
+        match Number::from_f64(value) {
+            Some(number) => JsonValue::Number(number),
+            None if value.is_nan() => JsonValue::String("NaN".to_string()),
+            None if value.is_sign_positive() => JsonValue::String("Infinity".to_string()),
+            None => JsonValue::String("-Infinity".to_string()),
+        }
+    }
+
+    /// Returns seconds and nanoseconds of the `google.protobuf.Timestamp` and
+    /// `google.protobuf.Duration` messages.
+    fn seconds_and_nanos(fields: &Map<String, JsonValue>) -> anyhow::Result<(i64, i32)> {
+        let seconds = match fields.get("seconds") {
+            Some(JsonValue::String(seconds)) => seconds.parse()?,
+            _ => 0,
+        };
+        let nanos = match fields.get("nanos") {
+            Some(nanos) => nanos
+                .as_i64()
+                .and_then(|nanos| i32::try_from(nanos).ok())
+                .ok_or_else(|| anyhow!("Nanos value is not valid: {nanos}"))?,
+            None => 0,
+        };
+
+        Ok((seconds, nanos))
+    }
+
+    /// Converts `google.protobuf.Timestamp` message to RFC 3339 string.
+    fn message_to_timestamp(fields: &Map<String, JsonValue>) -> anyhow::Result<JsonValue> {
+        let (seconds, nanos) = Self::seconds_and_nanos(fields)?;
+        let timestamp = OffsetDateTime::from_unix_timestamp_nanos(
+            i128::from(seconds) * 1_000_000_000 + i128::from(nanos),
+        )?;
+        Ok(JsonValue::String(timestamp.format(&Rfc3339)?))
+    }
+
+    /// Converts `google.protobuf.Duration` message to string with `s` suffix (e.g., `1.5s`).
+    fn message_to_duration(fields: &Map<String, JsonValue>) -> anyhow::Result<JsonValue> {
+        let (seconds, nanos) = Self::seconds_and_nanos(fields)?;
+        let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+        let (seconds, nanos) = (seconds.unsigned_abs(), nanos.unsigned_abs());
+        Ok(JsonValue::String(if nanos == 0 {
+            format!("{sign}{seconds}s")
+        } else {
+            format!(
+                "{sign}{seconds}.{}s",
+                format!("{nanos:09}").trim_end_matches('0')
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_json_message, encode_json_message};
+    use crate::trackers::grpc::{
+        descriptor_pool::{tests::mock_descriptor_set, DescriptorPool},
+        wire::{write_bytes, write_tag, write_varint, WIRE_TYPE_VARINT},
+    };
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn can_encode_and_decode_messages() -> anyhow::Result<()> {
+        let pool = DescriptorPool::decode(&mock_descriptor_set())?;
+
+        let request = encode_json_message(
+            &pool,
+            "retrack.v1.StatusRequest",
+            &json!({ "serviceName": "api" }),
+        )?;
+        assert_eq!(request, b"\x0a\x03api");
+        assert_eq!(
+            encode_json_message(
+                &pool,
+                "retrack.v1.StatusRequest",
+                &json!({ "service_name": "api" })
+            )?,
+            request
+        );
+        assert_eq!(
+            decode_json_message(&pool, "retrack.v1.StatusRequest", &request)?,
+            json!({ "serviceName": "api" })
+        );
+
+        let response_json = json!({
+            "state": "STATE_UP",
+            "uptimeSeconds": "-9007199254740993",
+            "checks": [{ "name": "db", "passed": true }, { "name": "cache" }],
+            "counters": { "requests": 10, "errors": -1 },
+            "ports": [80, 443],
+            "digest": "AQID",
+            "load": 0.5,
+            "delta": -3,
+            "updatedAt": "2000-01-01T10:00:00.5Z",
+            "latency": "-1.050s",
+            "region": "eu-west-1",
+            "labels": { "team": "core", "tier": 1.0, "flags": [true, null], "meta": {} }
+        });
+        let response = encode_json_message(&pool, "retrack.v1.StatusResponse", &response_json)?;
+        assert_debug_snapshot!(
+            decode_json_message(&pool, "retrack.v1.StatusResponse", &response)?,
+            @r###"
+        Object {
+            "state": String("STATE_UP"),
+            "uptimeSeconds": String("-9007199254740993"),
+            "checks": Array [
+                Object {
+                    "name": String("db"),
+                    "passed": Bool(true),
+                },
+                Object {
+                    "name": String("cache"),
+                },
+            ],
+            "counters": Object {
+                "requests": Number(10),
+                "errors": Number(-1),
+            },
+            "ports": Array [
+                Number(80),
+                Number(443),
+            ],
+            "digest": String("AQID"),
+            "load": Number(0.5),
+            "delta": Number(-3),
+            "updatedAt": String("2000-01-01T10:00:00.5Z"),
+            "latency": String("-1.05s"),
+            "region": String("eu-west-1"),
+            "labels": Object {
+                "team": String("core"),
+                "tier": Number(1.0),
+                "flags": Array [
+                    Bool(true),
+                    Null,
+                ],
+                "meta": Object {},
+            },
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_decode_unpacked_and_unknown_fields() -> anyhow::Result<()> {
+        let pool = DescriptorPool::decode(&mock_descriptor_set())?;
+
+        let mut response = vec![];
+        // Unpacked repeated field.
+        for port in [80, 443] {
+            write_tag(&mut response, 5, WIRE_TYPE_VARINT);
+            write_varint(&mut response, port);
+        }
+        // Unknown fields.
+        write_tag(&mut response, 100, WIRE_TYPE_VARINT);
+        write_varint(&mut response, 1);
+        write_bytes(&mut response, 101, b"unknown");
+        // Unknown enum value.
+        write_tag(&mut response, 1, WIRE_TYPE_VARINT);
+        write_varint(&mut response, 42);
+
+        assert_eq!(
+            decode_json_message(&pool, "retrack.v1.StatusResponse", &response)?,
+            json!({ "ports": [80, 443], "state": 42 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_encode_invalid_messages() -> anyhow::Result<()> {
+        let pool = DescriptorPool::decode(&mock_descriptor_set())?;
+        let encode = |value| {
+            format!(
+                "{:#}",
+                encode_json_message(&pool, "retrack.v1.StatusResponse", &value).unwrap_err()
+            )
+        };
+
+        assert_debug_snapshot!(encode(json!([])), @r###""Protobuf message (`retrack.v1.StatusResponse`) should be represented as JSON object, but received: []""###);
+        assert_debug_snapshot!(encode(json!({ "unknown": 1 })), @r###""Protobuf message (`retrack.v1.StatusResponse`) doesn't have field `unknown`.""###);
+        assert_debug_snapshot!(encode(json!({ "state": "STATE_SIDEWAYS" })), @r###""Failed to encode protobuf field `state`.: Enum (`retrack.v1.StatusResponse.State`) doesn't have value `STATE_SIDEWAYS`.""###);
+        assert_debug_snapshot!(encode(json!({ "ports": [-1] })), @r###""Failed to encode protobuf field `ports`.: Integer field value is out of range: -1""###);
+        assert_debug_snapshot!(encode(json!({ "latency": "1m" })), @r###""Failed to encode protobuf field `latency`.: Duration should be represented as JSON string with `s` suffix (e.g., `1.5s`), but received: \"1m\"""###);
+        assert_debug_snapshot!(
+            encode_json_message(&pool, "retrack.v1.Unknown", &json!({})).unwrap_err().to_string(),
+            @r###""Protobuf message (`retrack.v1.Unknown`) is not found in descriptor set.""###
+        );
+
+        Ok(())
+    }
+}
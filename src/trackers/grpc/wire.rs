@@ -0,0 +1,231 @@
+use anyhow::{anyhow, bail};
+
+/// Wire type of the `int32`, `int64`, `uint32`, `uint64`, `sint32`, `sint64`, `bool`, and `enum`
+/// fields.
+pub const WIRE_TYPE_VARINT: u8 = 0;
+/// Wire type of the `fixed64`, `sfixed64`, and `double` fields.
+pub const WIRE_TYPE_I64: u8 = 1;
+/// Wire type of the `string`, `bytes`, embedded messages, and packed repeated fields.
+pub const WIRE_TYPE_LEN: u8 = 2;
+/// Wire type of the group start (deprecated).
+pub const WIRE_TYPE_SGROUP: u8 = 3;
+/// Wire type of the group end (deprecated).
+pub const WIRE_TYPE_EGROUP: u8 = 4;
+/// Wire type of the `fixed32`, `sfixed32`, and `float` fields.
+pub const WIRE_TYPE_I32: u8 = 5;
+
+/// Reads values encoded with the protobuf binary wire format
+/// (https://protobuf.dev/programming-guides/encoding).
+pub struct WireReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WireReader<'a> {
+    /// Creates a new reader for the specified buffer.
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Checks if all bytes of the buffer have been read.
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+
+    /// Reads the field tag, and returns the field number and the wire type.
+    pub fn read_tag(&mut self) -> anyhow::Result<(u32, u8)> {
+        let tag = self.read_varint()?;
+        let number = u32::try_from(tag >> 3)?;
+        if number == 0 {
+            bail!("Protobuf field number cannot be zero.");
+        }
+
+        Ok((number, (tag & 0x7) as u8))
+    }
+
+    /// Reads base 128 varint.
+    pub fn read_varint(&mut self) -> anyhow::Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .buf
+                .get(self.pos)
+                .ok_or_else(|| anyhow!("Protobuf varint is truncated."))?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        bail!("Protobuf varint is too long.")
+    }
+
+    /// Reads little-endian 32-bit value.
+    pub fn read_fixed32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_le_bytes(self.read_exact(4)?.try_into()?))
+    }
+
+    /// Reads little-endian 64-bit value.
+    pub fn read_fixed64(&mut self) -> anyhow::Result<u64> {
+        Ok(u64::from_le_bytes(self.read_exact(8)?.try_into()?))
+    }
+
+    /// Reads length-delimited value.
+    pub fn read_bytes(&mut self) -> anyhow::Result<&'a [u8]> {
+        let len = usize::try_from(self.read_varint()?)?;
+        self.read_exact(len)
+    }
+
+    /// Reads length-delimited value as UTF-8 string.
+    pub fn read_string(&mut self) -> anyhow::Result<&'a str> {
+        Ok(std::str::from_utf8(self.read_bytes()?)?)
+    }
+
+    /// Skips the value of the field with the specified number and wire type.
+    pub fn skip(&mut self, number: u32, wire_type: u8) -> anyhow::Result<()> {
+        match wire_type {
+            WIRE_TYPE_VARINT => {
+                self.read_varint()?;
+            }
+            WIRE_TYPE_I64 => {
+                self.read_exact(8)?;
+            }
+            WIRE_TYPE_LEN => {
+                self.read_bytes()?;
+            }
+            WIRE_TYPE_I32 => {
+                self.read_exact(4)?;
+            }
+            WIRE_TYPE_SGROUP => loop {
+                let (group_number, group_wire_type) = self.read_tag()?;
+                if group_wire_type == WIRE_TYPE_EGROUP {
+                    if group_number != number {
+                        bail!("Protobuf group ({number}) end doesn't match its start.");
+                    }
+                    break;
+                }
+                self.skip(group_number, group_wire_type)?;
+            },
+            _ => bail!("Protobuf wire type ({wire_type}) is not valid."),
+        }
+
+        Ok(())
+    }
+
+    /// Reads the specified number of bytes.
+    fn read_exact(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| anyhow!("Protobuf value is truncated."))?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+}
+
+/// Writes base 128 varint.
+pub fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Writes the field tag.
+pub fn write_tag(buf: &mut Vec<u8>, number: u32, wire_type: u8) {
+    write_varint(buf, (u64::from(number) << 3) | u64::from(wire_type));
+}
+
+/// Writes length-delimited field.
+pub fn write_bytes(buf: &mut Vec<u8>, number: u32, bytes: &[u8]) {
+    write_tag(buf, number, WIRE_TYPE_LEN);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        write_bytes, write_tag, write_varint, WireReader, WIRE_TYPE_EGROUP, WIRE_TYPE_I32,
+        WIRE_TYPE_LEN, WIRE_TYPE_SGROUP, WIRE_TYPE_VARINT,
+    };
+
+    #[test]
+    fn can_write_and_read_values() -> anyhow::Result<()> {
+        let mut buf = vec![];
+        write_tag(&mut buf, 1, WIRE_TYPE_VARINT);
+        write_varint(&mut buf, 150);
+        write_bytes(&mut buf, 2, b"testing");
+        write_tag(&mut buf, 3, WIRE_TYPE_I32);
+        buf.extend_from_slice(&7u32.to_le_bytes());
+        write_tag(&mut buf, 4, WIRE_TYPE_VARINT);
+        write_varint(&mut buf, u64::MAX);
+        assert_eq!(&buf[..3], &[0x08, 0x96, 0x01]);
+
+        let mut reader = WireReader::new(&buf);
+        assert_eq!(reader.read_tag()?, (1, WIRE_TYPE_VARINT));
+        assert_eq!(reader.read_varint()?, 150);
+        assert_eq!(reader.read_tag()?, (2, WIRE_TYPE_LEN));
+        assert_eq!(reader.read_string()?, "testing");
+        assert_eq!(reader.read_tag()?, (3, WIRE_TYPE_I32));
+        assert_eq!(reader.read_fixed32()?, 7);
+        assert_eq!(reader.read_tag()?, (4, WIRE_TYPE_VARINT));
+        assert_eq!(reader.read_varint()?, u64::MAX);
+        assert!(reader.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_skip_values() -> anyhow::Result<()> {
+        let mut buf = vec![];
+        write_tag(&mut buf, 1, WIRE_TYPE_SGROUP);
+        write_bytes(&mut buf, 2, b"nested");
+        write_tag(&mut buf, 1, WIRE_TYPE_EGROUP);
+        write_bytes(&mut buf, 3, b"skipped");
+        write_tag(&mut buf, 4, WIRE_TYPE_VARINT);
+        write_varint(&mut buf, 1);
+
+        let mut reader = WireReader::new(&buf);
+        for _ in 0..2 {
+            let (number, wire_type) = reader.read_tag()?;
+            reader.skip(number, wire_type)?;
+        }
+        assert_eq!(reader.read_tag()?, (4, WIRE_TYPE_VARINT));
+        assert_eq!(reader.read_varint()?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_read_malformed_values() -> anyhow::Result<()> {
+        assert_eq!(
+            WireReader::new(&[0x96])
+                .read_varint()
+                .unwrap_err()
+                .to_string(),
+            "Protobuf varint is truncated."
+        );
+        assert_eq!(
+            WireReader::new(&[0x03, b'a'])
+                .read_bytes()
+                .unwrap_err()
+                .to_string(),
+            "Protobuf value is truncated."
+        );
+        assert_eq!(
+            WireReader::new(&[0x00]).read_tag().unwrap_err().to_string(),
+            "Protobuf field number cannot be zero."
+        );
+        assert_eq!(
+            WireReader::new(&[0x0F]).skip(1, 7).unwrap_err().to_string(),
+            "Protobuf wire type (7) is not valid."
+        );
+
+        Ok(())
+    }
+}
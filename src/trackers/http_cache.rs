@@ -0,0 +1,288 @@
+#[cfg(feature = "http-cache-redis")]
+mod redis_cache_manager;
+#[cfg(feature = "http-cache-s3")]
+mod s3_cache_manager;
+
+use crate::config::{CacheConfig, HttpCacheStorageConfig};
+#[cfg(any(feature = "http-cache-redis", feature = "http-cache-s3"))]
+use anyhow::anyhow;
+#[cfg(not(all(feature = "http-cache-redis", feature = "http-cache-s3")))]
+use anyhow::bail;
+use anyhow::Context;
+use http_cache_reqwest::{CACacheManager, CacheManager, HttpResponse};
+use http_cache_semantics::CachePolicy;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+#[cfg(feature = "http-cache-redis")]
+use self::redis_cache_manager::RedisCacheManager;
+#[cfg(feature = "http-cache-s3")]
+use self::s3_cache_manager::S3CacheManager;
+
+/// Name of the entry used to verify that the cache storage is usable.
+const HTTP_CACHE_PROBE_KEY: &str = ".retrack-probe";
+
+/// Result type expected by the HTTP cache middleware from the cache managers.
+type CacheResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Cached HTTP response along with its cache policy, as it's stored in the shared storages.
+#[derive(Serialize, Deserialize)]
+struct HttpCacheEntry {
+    response: HttpResponse,
+    policy: CachePolicy,
+}
+
+impl HttpCacheEntry {
+    /// Deserializes cache entry from the binary representation.
+    #[cfg_attr(
+        not(any(feature = "http-cache-redis", feature = "http-cache-s3")),
+        allow(dead_code)
+    )]
+    fn from_bytes(bytes: &[u8]) -> anyhow::Result<(HttpResponse, CachePolicy)> {
+        let entry = postcard::from_bytes::<Self>(bytes)?;
+        Ok((entry.response, entry.policy))
+    }
+
+    /// Serializes cache entry to the binary representation.
+    #[cfg_attr(
+        not(any(feature = "http-cache-redis", feature = "http-cache-s3")),
+        allow(dead_code)
+    )]
+    fn to_bytes(response: HttpResponse, policy: CachePolicy) -> anyhow::Result<Vec<u8>> {
+        Ok(postcard::to_stdvec(&Self { response, policy })?)
+    }
+}
+
+/// HTTP cache manager that stores cached responses in the storage configured for the instance.
+#[derive(Clone)]
+pub enum HttpCacheManager {
+    /// Cache is stored in the local directory.
+    Local(CACacheManager),
+    /// Cache is stored in Redis and shared between all instances.
+    #[cfg(feature = "http-cache-redis")]
+    Redis(RedisCacheManager),
+    /// Cache is stored in S3-compatible object storage and shared between all instances.
+    #[cfg(feature = "http-cache-s3")]
+    S3(S3CacheManager),
+}
+
+impl HttpCacheManager {
+    /// Creates HTTP cache manager based on the cache configuration.
+    pub fn create(config: &CacheConfig) -> anyhow::Result<Self> {
+        match config.http_cache_storage {
+            None => Ok(Self::Local(
+                config
+                    .http_cache_path
+                    .as_ref()
+                    .map(|path| CACacheManager {
+                        path: path.to_path_buf(),
+                    })
+                    .unwrap_or_default(),
+            )),
+            #[cfg(feature = "http-cache-redis")]
+            Some(HttpCacheStorageConfig::Redis {
+                ref url,
+                ref key_prefix,
+            }) => Ok(Self::Redis(RedisCacheManager::new(
+                url,
+                key_prefix.as_deref(),
+            )?)),
+            #[cfg(not(feature = "http-cache-redis"))]
+            Some(HttpCacheStorageConfig::Redis { .. }) => {
+                bail!("Redis HTTP cache storage requires `http-cache-redis` feature to be enabled.")
+            }
+            #[cfg(feature = "http-cache-s3")]
+            Some(HttpCacheStorageConfig::S3 {
+                ref bucket,
+                ref region,
+                ref endpoint,
+                ref key_prefix,
+            }) => Ok(Self::S3(S3CacheManager::new(
+                bucket,
+                region.as_deref(),
+                endpoint.as_ref(),
+                key_prefix.as_deref(),
+            )?)),
+            #[cfg(not(feature = "http-cache-s3"))]
+            Some(HttpCacheStorageConfig::S3 { .. }) => {
+                bail!("S3 HTTP cache storage requires `http-cache-s3` feature to be enabled.")
+            }
+        }
+    }
+
+    /// Verifies that the cache storage is usable: local cache directory should be writable, and
+    /// shared storages should be reachable.
+    pub async fn verify(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Local(manager) => {
+                fs::create_dir_all(&manager.path).with_context(|| {
+                    format!(
+                        "Cannot create HTTP cache directory ({}).",
+                        manager.path.display()
+                    )
+                })?;
+
+                let probe_path = manager.path.join(HTTP_CACHE_PROBE_KEY);
+                fs::write(&probe_path, [])
+                    .and_then(|_| fs::remove_file(&probe_path))
+                    .with_context(|| {
+                        format!(
+                            "HTTP cache directory ({}) isn't writable.",
+                            manager.path.display()
+                        )
+                    })
+            }
+            #[cfg(any(feature = "http-cache-redis", feature = "http-cache-s3"))]
+            _ => self
+                .get(HTTP_CACHE_PROBE_KEY)
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err).context("HTTP cache storage isn't reachable.")),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheManager for HttpCacheManager {
+    async fn get(&self, cache_key: &str) -> CacheResult<Option<(HttpResponse, CachePolicy)>> {
+        match self {
+            Self::Local(manager) => manager.get(cache_key).await,
+            #[cfg(feature = "http-cache-redis")]
+            Self::Redis(manager) => manager.get(cache_key).await,
+            #[cfg(feature = "http-cache-s3")]
+            Self::S3(manager) => manager.get(cache_key).await,
+        }
+    }
+
+    async fn put(
+        &self,
+        cache_key: String,
+        response: HttpResponse,
+        policy: CachePolicy,
+    ) -> CacheResult<HttpResponse> {
+        match self {
+            Self::Local(manager) => manager.put(cache_key, response, policy).await,
+            #[cfg(feature = "http-cache-redis")]
+            Self::Redis(manager) => manager.put(cache_key, response, policy).await,
+            #[cfg(feature = "http-cache-s3")]
+            Self::S3(manager) => manager.put(cache_key, response, policy).await,
+        }
+    }
+
+    async fn delete(&self, cache_key: &str) -> CacheResult<()> {
+        match self {
+            Self::Local(manager) => manager.delete(cache_key).await,
+            #[cfg(feature = "http-cache-redis")]
+            Self::Redis(manager) => manager.delete(cache_key).await,
+            #[cfg(feature = "http-cache-s3")]
+            Self::S3(manager) => manager.delete(cache_key).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HttpCacheEntry, HttpCacheManager};
+    use crate::config::CacheConfig;
+    #[cfg(not(feature = "http-cache-redis"))]
+    use crate::config::HttpCacheStorageConfig;
+    use http_cache_reqwest::HttpResponse;
+    use http_cache_semantics::CachePolicy;
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+    use std::{fs, time::SystemTime};
+    #[cfg(not(feature = "http-cache-redis"))]
+    use url::Url;
+
+    #[test]
+    fn creates_local_manager_by_default() -> anyhow::Result<()> {
+        let manager = HttpCacheManager::create(&CacheConfig {
+            http_cache_path: Some("./http-cache".into()),
+            http_cache_storage: None,
+        })?;
+        assert!(matches!(
+            manager,
+            HttpCacheManager::Local(ref manager) if manager.path.to_str() == Some("./http-cache")
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verifies_local_manager() -> anyhow::Result<()> {
+        let manager = HttpCacheManager::create(&CacheConfig {
+            http_cache_path: Some("./target/http-cache-verify".into()),
+            http_cache_storage: None,
+        })?;
+        manager.verify().await?;
+        assert!(fs::read_dir("./target/http-cache-verify")?.next().is_none());
+
+        // Cache path points to a file.
+        fs::write("./target/http-cache-verify-file", [])?;
+        let manager = HttpCacheManager::create(&CacheConfig {
+            http_cache_path: Some("./target/http-cache-verify-file".into()),
+            http_cache_storage: None,
+        })?;
+        assert_debug_snapshot!(
+            manager.verify().await.unwrap_err().to_string(),
+            @r###""Cannot create HTTP cache directory (./target/http-cache-verify-file).""###
+        );
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "http-cache-redis"))]
+    #[test]
+    fn fails_to_create_redis_manager_without_feature() {
+        let result = HttpCacheManager::create(&CacheConfig {
+            http_cache_path: None,
+            http_cache_storage: Some(HttpCacheStorageConfig::Redis {
+                url: Url::parse("redis://localhost:6379").unwrap(),
+                key_prefix: None,
+            }),
+        });
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some(
+                "Redis HTTP cache storage requires `http-cache-redis` feature to be enabled."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn serializes_cache_entry() -> anyhow::Result<()> {
+        let request = http::Request::get("https://retrack.dev/api").body(())?;
+        let response = http::Response::builder()
+            .status(200)
+            .header("Cache-Control", "max-age=3600")
+            .header("ETag", "\"etag\"")
+            .body(())?;
+        let policy = CachePolicy::new(&request, &response);
+
+        let cached_response = serde_json::from_value::<HttpResponse>(json!({
+            "body": [1, 2, 3],
+            "headers": { "etag": "\"etag\"" },
+            "status": 200,
+            "url": "https://retrack.dev/api",
+            "version": "HTTP/1.1"
+        }))?;
+
+        let bytes = HttpCacheEntry::to_bytes(cached_response.clone(), policy.clone())?;
+        let (deserialized_response, deserialized_policy) = HttpCacheEntry::from_bytes(&bytes)?;
+        assert_eq!(deserialized_response.status, cached_response.status);
+        assert_eq!(deserialized_response.body, cached_response.body);
+        assert_eq!(deserialized_response.headers, cached_response.headers);
+        assert_eq!(deserialized_response.url, cached_response.url);
+        assert_eq!(deserialized_response.version, cached_response.version);
+
+        let now = SystemTime::now();
+        assert_eq!(
+            deserialized_policy.time_to_live(now),
+            policy.time_to_live(now)
+        );
+        assert!(!deserialized_policy.is_stale(now));
+
+        Ok(())
+    }
+}
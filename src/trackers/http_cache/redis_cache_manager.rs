@@ -0,0 +1,62 @@
+use super::{CacheResult, HttpCacheEntry};
+use http_cache_reqwest::{CacheManager, HttpResponse};
+use http_cache_semantics::CachePolicy;
+use redis::{AsyncCommands, Client};
+use url::Url;
+
+/// Default prefix for the keys of the cached HTTP responses stored in Redis.
+const DEFAULT_KEY_PREFIX: &str = "retrack:http-cache:";
+
+/// Implements HTTP cache manager with Redis as the backend. Entries don't expire on their own since
+/// stale responses are still used for revalidation, so Redis should be configured with the
+/// appropriate eviction policy (e.g., `allkeys-lru`).
+#[derive(Clone)]
+pub struct RedisCacheManager {
+    client: Client,
+    key_prefix: String,
+}
+
+impl RedisCacheManager {
+    /// Creates a new Redis cache manager. Connection is established lazily.
+    pub fn new(url: &Url, key_prefix: Option<&str>) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: Client::open(url.as_str())?,
+            key_prefix: key_prefix.unwrap_or(DEFAULT_KEY_PREFIX).to_string(),
+        })
+    }
+
+    fn key(&self, cache_key: &str) -> String {
+        format!("{}{cache_key}", self.key_prefix)
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheManager for RedisCacheManager {
+    async fn get(&self, cache_key: &str) -> CacheResult<Option<(HttpResponse, CachePolicy)>> {
+        let mut connection = self.client.get_multiplexed_async_connection().await?;
+        let entry: Option<Vec<u8>> = connection.get(self.key(cache_key)).await?;
+        Ok(entry
+            .map(|entry| HttpCacheEntry::from_bytes(&entry))
+            .transpose()?)
+    }
+
+    async fn put(
+        &self,
+        cache_key: String,
+        response: HttpResponse,
+        policy: CachePolicy,
+    ) -> CacheResult<HttpResponse> {
+        let entry = HttpCacheEntry::to_bytes(response.clone(), policy)?;
+        let mut connection = self.client.get_multiplexed_async_connection().await?;
+        connection
+            .set::<_, _, ()>(self.key(&cache_key), entry)
+            .await?;
+        Ok(response)
+    }
+
+    async fn delete(&self, cache_key: &str) -> CacheResult<()> {
+        let mut connection = self.client.get_multiplexed_async_connection().await?;
+        connection.del::<_, ()>(self.key(cache_key)).await?;
+        Ok(())
+    }
+}
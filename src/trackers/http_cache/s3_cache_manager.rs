@@ -0,0 +1,89 @@
+use super::{CacheResult, HttpCacheEntry};
+use http_cache_reqwest::{CacheManager, HttpResponse};
+use http_cache_semantics::CachePolicy;
+use object_store::{
+    aws::{AmazonS3, AmazonS3Builder},
+    path::Path,
+    ObjectStore, PutPayload,
+};
+use ring::digest;
+use std::sync::Arc;
+use url::Url;
+
+/// Default prefix for the keys of the cached HTTP responses stored in S3.
+const DEFAULT_KEY_PREFIX: &str = "http-cache/";
+
+/// Implements HTTP cache manager with S3-compatible object storage as the backend. Cache keys
+/// include full URLs, so they are hashed to get predictable object names.
+#[derive(Clone)]
+pub struct S3CacheManager {
+    store: Arc<AmazonS3>,
+    key_prefix: String,
+}
+
+impl S3CacheManager {
+    /// Creates a new S3 cache manager. Credentials are read from the `AWS_*` environment variables.
+    pub fn new(
+        bucket: &str,
+        region: Option<&str>,
+        endpoint: Option<&Url>,
+        key_prefix: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let mut builder = AmazonS3Builder::from_env().with_bucket_name(bucket);
+        if let Some(region) = region {
+            builder = builder.with_region(region);
+        }
+        if let Some(endpoint) = endpoint {
+            builder = builder
+                .with_endpoint(endpoint.as_str())
+                .with_allow_http(endpoint.scheme() == "http");
+        }
+
+        Ok(Self {
+            store: Arc::new(builder.build()?),
+            key_prefix: key_prefix.unwrap_or(DEFAULT_KEY_PREFIX).to_string(),
+        })
+    }
+
+    fn path(&self, cache_key: &str) -> Path {
+        let key_hash = digest::digest(&digest::SHA256, cache_key.as_bytes())
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        Path::from(format!("{}{key_hash}", self.key_prefix))
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheManager for S3CacheManager {
+    async fn get(&self, cache_key: &str) -> CacheResult<Option<(HttpResponse, CachePolicy)>> {
+        let entry = match self.store.get(&self.path(cache_key)).await {
+            Ok(entry) => entry.bytes().await?,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Some(HttpCacheEntry::from_bytes(&entry)?))
+    }
+
+    async fn put(
+        &self,
+        cache_key: String,
+        response: HttpResponse,
+        policy: CachePolicy,
+    ) -> CacheResult<HttpResponse> {
+        let entry = HttpCacheEntry::to_bytes(response.clone(), policy)?;
+        self.store
+            .put(&self.path(&cache_key), PutPayload::from(entry))
+            .await?;
+        Ok(response)
+    }
+
+    async fn delete(&self, cache_key: &str) -> CacheResult<()> {
+        match self.store.delete(&self.path(cache_key)).await {
+            Ok(_) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
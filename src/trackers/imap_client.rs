@@ -0,0 +1,468 @@
+use anyhow::{anyhow, bail, Context};
+use rustls::{crypto::ring, pki_types::ServerName, ClientConfig, RootCertStore};
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use tokio_rustls::{client::TlsStream, TlsConnector};
+use tracing::warn;
+
+/// Maximum size of a single IMAP response line or literal (e.g., message content) the client is
+/// willing to read, protects from the unbounded memory usage.
+const MAX_RESPONSE_PART_SIZE: usize = 10 * 1024 * 1024;
+
+/// Untagged IMAP server response (`* ...`) with the literals extracted from the response text.
+#[derive(Debug, Default)]
+struct ImapResponse {
+    /// Response text without the leading `* `, literals are represented by their size specifiers
+    /// (e.g., `{42}`).
+    text: String,
+    /// Raw content of the response literals, in the order they appear in the response.
+    literals: Vec<Vec<u8>>,
+}
+
+/// Lightweight IMAP4rev1 (RFC 3501) client that supports only the read-only subset of commands
+/// needed to search and fetch messages from a mailbox folder.
+pub struct ImapClient<S> {
+    stream: BufReader<S>,
+    tag: usize,
+}
+
+impl ImapClient<TlsStream<TcpStream>> {
+    /// Connects to the IMAP server over implicit TLS and reads the server greeting.
+    pub async fn connect(host: &str, port: u16) -> anyhow::Result<Self> {
+        let mut root_store = RootCertStore::empty();
+        let native_certs = rustls_native_certs::load_native_certs();
+        for err in native_certs.errors {
+            warn!("Failed to load native root certificate: {err}");
+        }
+        root_store.add_parsable_certificates(native_certs.certs);
+
+        let tls_config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_root_certificates(root_store)
+            .with_no_client_auth();
+        let server_name = ServerName::try_from(host.to_string())
+            .with_context(|| format!("Invalid IMAP server host name ({host})."))?;
+
+        let tcp_stream = TcpStream::connect((host, port))
+            .await
+            .with_context(|| format!("Cannot connect to IMAP server ({host}:{port})."))?;
+        let tls_stream = TlsConnector::from(Arc::new(tls_config))
+            .connect(server_name, tcp_stream)
+            .await
+            .with_context(|| format!("Cannot establish TLS session with IMAP server ({host})."))?;
+
+        Self::new(tls_stream).await
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> ImapClient<S> {
+    /// Creates IMAP client for the already established connection and reads the server greeting.
+    pub async fn new(stream: S) -> anyhow::Result<Self> {
+        let mut client = Self {
+            stream: BufReader::new(stream),
+            tag: 0,
+        };
+
+        let greeting = client.read_response().await?;
+        if !greeting.text.starts_with("* OK") {
+            bail!("Unexpected IMAP server greeting: {}", greeting.text);
+        }
+
+        Ok(client)
+    }
+
+    /// Authenticates the user with the plain-text `LOGIN` command. The connection is expected to be
+    /// protected with TLS.
+    pub async fn login(&mut self, username: &str, password: &str) -> anyhow::Result<()> {
+        let command = format!("LOGIN {} {}", quote(username)?, quote(password)?);
+        self.execute(&command)
+            .await
+            .context("Failed to log in to IMAP server.")?;
+        Ok(())
+    }
+
+    /// Opens the mailbox folder in read-only mode (`EXAMINE`), so that fetched messages aren't
+    /// marked as seen.
+    pub async fn examine(&mut self, folder: &str) -> anyhow::Result<()> {
+        self.execute(&format!("EXAMINE {}", quote(folder)?))
+            .await
+            .with_context(|| format!("Failed to open IMAP folder ({folder})."))?;
+        Ok(())
+    }
+
+    /// Searches messages in the opened folder with the specified search criteria and returns the
+    /// unique identifiers (UIDs) of the matched messages in ascending order.
+    pub async fn uid_search(&mut self, criteria: &str) -> anyhow::Result<Vec<u32>> {
+        let responses = self
+            .execute(&format!("UID SEARCH {criteria}"))
+            .await
+            .context("Failed to search IMAP messages.")?;
+
+        let mut uids = responses
+            .iter()
+            .filter_map(|response| response.text.strip_prefix("SEARCH"))
+            .flat_map(str::split_ascii_whitespace)
+            .map(|uid| {
+                uid.parse::<u32>()
+                    .with_context(|| format!("Invalid IMAP message UID ({uid})."))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        uids.sort_unstable();
+
+        Ok(uids)
+    }
+
+    /// Fetches raw content (RFC 5322) of the messages with the specified UIDs from the opened
+    /// folder without marking them as seen. Returns UIDs along with the content of the messages.
+    pub async fn uid_fetch(&mut self, uids: &[u32]) -> anyhow::Result<Vec<(u32, Vec<u8>)>> {
+        if uids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let sequence_set = uids
+            .iter()
+            .map(|uid| uid.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let responses = self
+            .execute(&format!("UID FETCH {sequence_set} (UID BODY.PEEK[])"))
+            .await
+            .context("Failed to fetch IMAP messages.")?;
+
+        let mut messages = vec![];
+        for mut response in responses {
+            let Some((_, attributes)) = response.text.split_once(" FETCH (") else {
+                continue;
+            };
+
+            let uid = attributes
+                .split_once("UID ")
+                .and_then(|(_, rest)| {
+                    rest.split(|c: char| !c.is_ascii_digit())
+                        .next()
+                        .and_then(|uid| uid.parse::<u32>().ok())
+                })
+                .ok_or_else(|| anyhow!("IMAP server didn't return message UID: {attributes}"))?;
+            if response.literals.is_empty() {
+                bail!("IMAP server didn't return content of the message ({uid}).");
+            }
+
+            messages.push((uid, response.literals.swap_remove(0)));
+        }
+
+        Ok(messages)
+    }
+
+    /// Opens the folder in read-only mode and fetches raw content of the most recent (by UID)
+    /// messages matching the search criteria, the most recent messages first.
+    pub async fn fetch_recent(
+        &mut self,
+        folder: &str,
+        criteria: &str,
+        limit: usize,
+    ) -> anyhow::Result<Vec<(u32, Vec<u8>)>> {
+        self.examine(folder).await?;
+
+        let uids = self.uid_search(criteria).await?;
+        let mut messages = self
+            .uid_fetch(&uids[uids.len().saturating_sub(limit)..])
+            .await?;
+        messages.sort_unstable_by(|(uid_a, _), (uid_b, _)| uid_b.cmp(uid_a));
+
+        Ok(messages)
+    }
+
+    /// Closes the IMAP session.
+    pub async fn logout(&mut self) -> anyhow::Result<()> {
+        self.execute("LOGOUT").await?;
+        Ok(())
+    }
+
+    /// Sends the command to the server and reads the untagged responses until the tagged command
+    /// completion response is received.
+    async fn execute(&mut self, command: &str) -> anyhow::Result<Vec<ImapResponse>> {
+        self.tag += 1;
+        let tag = format!("A{:04}", self.tag);
+
+        let stream = self.stream.get_mut();
+        stream
+            .write_all(format!("{tag} {command}\r\n").as_bytes())
+            .await?;
+        stream.flush().await?;
+
+        let mut responses = vec![];
+        loop {
+            let mut response = self.read_response().await?;
+            if let Some(untagged_text) = response.text.strip_prefix("* ") {
+                response.text = untagged_text.to_string();
+                responses.push(response);
+            } else if let Some(status) = response
+                .text
+                .strip_prefix(&tag)
+                .and_then(|text| text.strip_prefix(' '))
+            {
+                if status.starts_with("OK") {
+                    return Ok(responses);
+                }
+                bail!("IMAP server rejected the command: {status}");
+            } else {
+                bail!("Unexpected IMAP server response: {}", response.text);
+            }
+        }
+    }
+
+    /// Reads a single server response, including all literals it contains.
+    async fn read_response(&mut self) -> anyhow::Result<ImapResponse> {
+        let mut response = ImapResponse::default();
+        loop {
+            let line = self.read_line().await?;
+            let literal_size = literal_size(&line);
+            response.text.push_str(&line);
+
+            let Some(literal_size) = literal_size else {
+                return Ok(response);
+            };
+            if literal_size > MAX_RESPONSE_PART_SIZE {
+                bail!(
+                    "IMAP server response literal is too large ({literal_size} bytes), the \
+                     maximum allowed size is {MAX_RESPONSE_PART_SIZE} bytes."
+                );
+            }
+
+            let mut literal = vec![0; literal_size];
+            self.stream
+                .read_exact(&mut literal)
+                .await
+                .context("Failed to read IMAP server response literal.")?;
+            response.literals.push(literal);
+        }
+    }
+
+    /// Reads a single CRLF-terminated line of the server response without the line terminator.
+    async fn read_line(&mut self) -> anyhow::Result<String> {
+        let mut line = vec![];
+        let read_bytes = (&mut self.stream)
+            .take(MAX_RESPONSE_PART_SIZE as u64)
+            .read_until(b'\n', &mut line)
+            .await
+            .context("Failed to read IMAP server response.")?;
+        if read_bytes == 0 {
+            bail!("IMAP server closed the connection unexpectedly.");
+        }
+
+        if !line.ends_with(b"\n") {
+            bail!("IMAP server response line is too long or truncated.");
+        }
+
+        let line = line.trim_ascii_end();
+        Ok(String::from_utf8_lossy(line).into_owned())
+    }
+}
+
+/// Encodes the value as IMAP quoted string, escaping backslashes and double quotes.
+fn quote(value: &str) -> anyhow::Result<String> {
+    if value.contains(['\r', '\n', '\0']) {
+        bail!("IMAP command argument cannot contain line breaks or NUL characters.");
+    }
+
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+/// Returns the size of the literal (`{42}` or non-synchronizing `{42+}`) that ends the response
+/// line, if any.
+fn literal_size(line: &str) -> Option<usize> {
+    let (_, size) = line.strip_suffix('}')?.rsplit_once('{')?;
+    size.strip_suffix('+').unwrap_or(size).parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{literal_size, quote, ImapClient};
+    use insta::assert_debug_snapshot;
+    use tokio::io::{duplex, AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream};
+
+    /// Spawns a mock IMAP server that sends the greeting and then replies to the expected commands
+    /// with the specified responses, in order.
+    fn mock_server(
+        stream: DuplexStream,
+        conversation: Vec<(&'static str, &'static [u8])>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut stream = BufReader::new(stream);
+            stream
+                .get_mut()
+                .write_all(b"* OK IMAP4rev1 Service Ready\r\n")
+                .await
+                .unwrap();
+            for (expected_command, response) in conversation {
+                let mut command = String::new();
+                stream.read_line(&mut command).await.unwrap();
+                assert_eq!(command, format!("{expected_command}\r\n"));
+                stream.get_mut().write_all(response).await.unwrap();
+            }
+        })
+    }
+
+    #[test]
+    fn properly_quotes_values() -> anyhow::Result<()> {
+        assert_eq!(quote("INBOX")?, r#""INBOX""#);
+        assert_eq!(quote(r#"p@ss "w\rd""#)?, r#""p@ss \"w\\rd\"""#);
+        assert_debug_snapshot!(quote("INBOX\r\nA0002 LOGOUT").unwrap_err(), @r###""IMAP command argument cannot contain line breaks or NUL characters.""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_detects_literals() {
+        assert_eq!(literal_size("* 1 FETCH (UID 10 BODY[] {342}"), Some(342));
+        assert_eq!(literal_size("* 1 FETCH (UID 10 BODY[] {342+}"), Some(342));
+        assert_eq!(literal_size("* 1 FETCH (UID 10 BODY[] NIL)"), None);
+        assert_eq!(literal_size("* OK [UIDVALIDITY 1] {not a literal}"), None);
+    }
+
+    #[tokio::test]
+    async fn can_search_and_fetch_messages() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = mock_server(
+            server_stream,
+            vec![
+                (
+                    r#"A0001 LOGIN "user@retrack.dev" "pa\"ss""#,
+                    b"A0001 OK LOGIN completed\r\n",
+                ),
+                (
+                    r#"A0002 EXAMINE "INBOX""#,
+                    b"* 3 EXISTS\r\n* OK [UIDVALIDITY 1] UIDs valid\r\nA0002 OK [READ-ONLY] EXAMINE completed\r\n",
+                ),
+                (
+                    r#"A0003 UID SEARCH FROM "billing@retrack.dev""#,
+                    b"* SEARCH 12 3 7\r\nA0003 OK SEARCH completed\r\n",
+                ),
+                (
+                    "A0004 UID FETCH 7,12 (UID BODY.PEEK[])",
+                    b"* 2 FETCH (UID 7 BODY[] {18}\r\nSubject: One\r\n\r\nHi)\r\n* 3 FETCH (BODY[] {18}\r\nSubject: Two\r\n\r\nHo UID 12)\r\nA0004 OK FETCH completed\r\n",
+                ),
+                (
+                    "A0005 LOGOUT",
+                    b"* BYE IMAP4rev1 Server logging out\r\nA0005 OK LOGOUT completed\r\n",
+                ),
+            ],
+        );
+
+        let mut client = ImapClient::new(client_stream).await?;
+        client.login("user@retrack.dev", "pa\"ss").await?;
+        client.examine("INBOX").await?;
+        assert_eq!(
+            client.uid_search(r#"FROM "billing@retrack.dev""#).await?,
+            vec![3, 7, 12]
+        );
+        assert_eq!(
+            client.uid_fetch(&[7, 12]).await?,
+            vec![
+                (7, b"Subject: One\r\n\r\nHi".to_vec()),
+                (12, b"Subject: Two\r\n\r\nHo".to_vec())
+            ]
+        );
+        client.logout().await?;
+
+        server.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn can_fetch_recent_messages() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = mock_server(
+            server_stream,
+            vec![
+                (
+                    r#"A0001 EXAMINE "Vendors""#,
+                    b"A0001 OK [READ-ONLY] EXAMINE completed\r\n",
+                ),
+                (
+                    "A0002 UID SEARCH UNSEEN",
+                    b"* SEARCH 3 7 12\r\nA0002 OK SEARCH completed\r\n",
+                ),
+                (
+                    "A0003 UID FETCH 7,12 (UID BODY.PEEK[])",
+                    b"* 2 FETCH (UID 7 BODY[] {2}\r\nHi)\r\n* 3 FETCH (UID 12 BODY[] {2}\r\nHo)\r\nA0003 OK FETCH completed\r\n",
+                ),
+                (
+                    r#"A0004 EXAMINE "INBOX""#,
+                    b"A0004 OK [READ-ONLY] EXAMINE completed\r\n",
+                ),
+                ("A0005 UID SEARCH ALL", b"* SEARCH\r\nA0005 OK SEARCH completed\r\n"),
+            ],
+        );
+
+        let mut client = ImapClient::new(client_stream).await?;
+        assert_eq!(
+            client.fetch_recent("Vendors", "UNSEEN", 2).await?,
+            vec![(12, b"Ho".to_vec()), (7, b"Hi".to_vec())]
+        );
+
+        // Nothing to fetch.
+        assert!(client.fetch_recent("INBOX", "ALL", 2).await?.is_empty());
+
+        server.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_command_is_rejected() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = mock_server(
+            server_stream,
+            vec![(
+                r#"A0001 LOGIN "user@retrack.dev" "wrong""#,
+                b"A0001 NO [AUTHENTICATIONFAILED] Invalid credentials\r\n",
+            )],
+        );
+
+        let mut client = ImapClient::new(client_stream).await?;
+        assert_debug_snapshot!(
+            client.login("user@retrack.dev", "wrong").await.unwrap_err(),
+            @r###"
+        Error {
+            context: "Failed to log in to IMAP server.",
+            source: "IMAP server rejected the command: NO [AUTHENTICATIONFAILED] Invalid credentials",
+        }
+        "###
+        );
+
+        server.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_greeting_is_unexpected() -> anyhow::Result<()> {
+        let (client_stream, mut server_stream) = duplex(4096);
+        server_stream
+            .write_all(b"* BYE Too many connections\r\n")
+            .await?;
+
+        assert_debug_snapshot!(
+            ImapClient::new(client_stream).await.err().unwrap(),
+            @r###""Unexpected IMAP server greeting: * BYE Too many connections""###
+        );
+
+        // Connection closed before the greeting.
+        let (client_stream, server_stream) = duplex(4096);
+        drop(server_stream);
+        assert_debug_snapshot!(
+            ImapClient::new(client_stream).await.err().unwrap(),
+            @r###""IMAP server closed the connection unexpectedly.""###
+        );
+
+        Ok(())
+    }
+}
@@ -1,4 +1,19 @@
+mod charset_decoder;
 mod csv_parser;
+mod email_parser;
+mod feed_parser;
+mod prometheus_parser;
+mod sitemap_parser;
+mod sse_parser;
 mod xls_parser;
 
-pub use self::{csv_parser::CsvParser, xls_parser::XlsParser};
+pub use self::{
+    charset_decoder::CharsetDecoder,
+    csv_parser::CsvParser,
+    email_parser::{EmailMessage, EmailParser},
+    feed_parser::FeedParser,
+    prometheus_parser::PrometheusParser,
+    sitemap_parser::{Sitemap, SitemapParser},
+    sse_parser::SseParser,
+    xls_parser::XlsParser,
+};
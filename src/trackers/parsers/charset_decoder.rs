@@ -0,0 +1,142 @@
+use bytes::Bytes;
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+use mediatype::{names::CHARSET, MediaType, ReadParams};
+use tracing::warn;
+
+/// Decoder that transcodes text content in non-UTF-8 charsets to UTF-8.
+pub struct CharsetDecoder;
+impl CharsetDecoder {
+    /// Transcodes the content to UTF-8, if needed. The charset is determined by the byte order
+    /// mark (BOM), then by the `charset` parameter of the media type, if it's specified. If the
+    /// charset isn't specified and the content isn't a valid UTF-8, it's assumed to be in
+    /// `windows-1252` (a superset of `ISO-8859-1` used by the legacy web). Returns the transcoded
+    /// content along with the name of the charset the content was transcoded from, if any.
+    pub fn decode(
+        content: Bytes,
+        media_type: Option<&MediaType>,
+    ) -> (Bytes, Option<&'static Encoding>) {
+        let (encoding, content) = if let Some((encoding, bom_length)) = Encoding::for_bom(&content)
+        {
+            (encoding, content.slice(bom_length..))
+        } else {
+            let declared_encoding = media_type
+                .and_then(|media_type| media_type.get_param(CHARSET))
+                .and_then(|charset| {
+                    let encoding =
+                        Encoding::for_label_no_replacement(charset.unquoted_str().as_bytes());
+                    if encoding.is_none() {
+                        warn!("Unsupported charset `{charset}`, falling back to detection.");
+                    }
+                    encoding
+                });
+            let encoding = match declared_encoding {
+                Some(encoding) => encoding,
+                None if std::str::from_utf8(&content).is_ok() => UTF_8,
+                None => WINDOWS_1252,
+            };
+            (encoding, content)
+        };
+
+        if encoding == UTF_8 {
+            return (content, None);
+        }
+
+        let (text, had_errors) = encoding.decode_without_bom_handling(&content);
+        if had_errors {
+            warn!(
+                "Content contains sequences that are malformed in `{}` charset.",
+                encoding.name()
+            );
+        }
+
+        (Bytes::from(text.into_owned()), Some(encoding))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharsetDecoder;
+    use bytes::Bytes;
+    use encoding_rs::{UTF_16LE, WINDOWS_1251, WINDOWS_1252};
+    use mediatype::MediaTypeBuf;
+
+    #[test]
+    fn keeps_utf8_content() -> anyhow::Result<()> {
+        let content = Bytes::from("{\"name\":\"Café\"}");
+        assert_eq!(
+            CharsetDecoder::decode(content.clone(), None),
+            (content.clone(), None)
+        );
+
+        let media_type = MediaTypeBuf::from_string("application/json; charset=utf-8".to_string())?;
+        assert_eq!(
+            CharsetDecoder::decode(content.clone(), Some(&media_type.to_ref())),
+            (content.clone(), None)
+        );
+
+        // UTF-8 BOM is stripped.
+        assert_eq!(
+            CharsetDecoder::decode(
+                Bytes::from([b"\xEF\xBB\xBF".as_slice(), &content].concat()),
+                None
+            ),
+            (content, None)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn transcodes_declared_charset() -> anyhow::Result<()> {
+        let media_type =
+            MediaTypeBuf::from_string("application/json; charset=\"ISO-8859-1\"".to_string())?;
+        assert_eq!(
+            CharsetDecoder::decode(
+                Bytes::from_static(b"{\"name\":\"Caf\xE9\"}"),
+                Some(&media_type.to_ref())
+            ),
+            (Bytes::from("{\"name\":\"Café\"}"), Some(WINDOWS_1252))
+        );
+
+        let media_type = MediaTypeBuf::from_string("text/csv; charset=windows-1251".to_string())?;
+        assert_eq!(
+            CharsetDecoder::decode(
+                Bytes::from_static(b"\xCF\xF0\xE8\xE2\xE5\xF2"),
+                Some(&media_type.to_ref())
+            ),
+            (Bytes::from("Привет"), Some(WINDOWS_1251))
+        );
+
+        // BOM takes precedence over the declared charset.
+        assert_eq!(
+            CharsetDecoder::decode(
+                Bytes::from_static(b"\xFF\xFE[\x001\x00]\x00"),
+                Some(&media_type.to_ref())
+            ),
+            (Bytes::from("[1]"), Some(UTF_16LE))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn detects_charset() -> anyhow::Result<()> {
+        assert_eq!(
+            CharsetDecoder::decode(Bytes::from_static(b"{\"name\":\"Caf\xE9\"}"), None),
+            (Bytes::from("{\"name\":\"Café\"}"), Some(WINDOWS_1252))
+        );
+
+        // Unknown charset is ignored.
+        let media_type =
+            MediaTypeBuf::from_string("application/json; charset=unknown".to_string())?;
+        assert_eq!(
+            CharsetDecoder::decode(
+                Bytes::from_static(b"{\"name\":\"Caf\xE9\"}"),
+                Some(&media_type.to_ref())
+            ),
+            (Bytes::from("{\"name\":\"Café\"}"), Some(WINDOWS_1252))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,443 @@
+use crate::trackers::parsers::CharsetDecoder;
+use anyhow::bail;
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
+use bytes::Bytes;
+use encoding_rs::Encoding;
+use mediatype::{
+    names::{BOUNDARY, HTML, MULTIPART, PLAIN, TEXT},
+    MediaType, MediaTypeBuf, ReadParams,
+};
+use regex::Regex;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::sync::LazyLock;
+use time::{
+    format_description::well_known::{Rfc2822, Rfc3339},
+    OffsetDateTime, UtcOffset,
+};
+
+/// Maximum depth of the nested multipart bodies the parser descends into.
+const MAX_MULTIPART_DEPTH: usize = 10;
+
+/// Matches RFC 2047 encoded words, e.g. `=?UTF-8?B?Q2Fmw6k=?=`.
+static ENCODED_WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"=\?([^?\s]+)\?([BbQq])\?([^?\s]*)\?=").expect("Invalid encoded word regex")
+});
+
+/// Matches whitespace between adjacent encoded words that must be ignored.
+static ENCODED_WORDS_SEPARATOR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\?=)\s+(=\?)").expect("Invalid encoded words separator regex"));
+
+/// Matches HTML elements along with the content that shouldn't be rendered as text.
+static HTML_HIDDEN_CONTENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?is)<(head|style|script)\b.*?</(head|style|script)\s*>|<!--.*?-->")
+        .expect("Invalid HTML hidden content regex")
+});
+
+/// Matches HTML tags.
+static HTML_TAG_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").expect("Invalid HTML tag regex"));
+
+/// Parsed email message.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailMessage {
+    /// Unique identifier (UID) of the message in the mailbox folder, if known.
+    pub uid: Option<u32>,
+    /// Value of the `Message-ID` header.
+    pub message_id: Option<String>,
+    /// Value of the `From` header.
+    pub from: Option<String>,
+    /// Value of the `To` header.
+    pub to: Option<String>,
+    /// Value of the `Subject` header.
+    pub subject: Option<String>,
+    /// Value of the `Date` header, converted to RFC 3339 in UTC if possible.
+    pub date: Option<String>,
+    /// Text body of the message. If message doesn't have a plain text part, the text is extracted
+    /// from the HTML part.
+    pub text: Option<String>,
+}
+
+/// Parser of the email messages in Internet Message Format (RFC 5322) with MIME (RFC 2045-2047)
+/// bodies.
+pub struct EmailParser;
+impl EmailParser {
+    /// Parses the raw email message. Only the main headers and the text body are extracted, while
+    /// attachments and other non-text parts are ignored.
+    pub fn parse(content: &[u8]) -> anyhow::Result<EmailMessage> {
+        let (headers, body) = split_headers(content);
+        if headers.is_empty() {
+            bail!("Content is not an email message.");
+        }
+
+        let header = |name: &str| header_value(&headers, name).map(decode_encoded_words);
+        Ok(EmailMessage {
+            uid: None,
+            message_id: header("Message-ID"),
+            from: header("From"),
+            to: header("To"),
+            subject: header("Subject"),
+            date: header("Date").map(normalize_date),
+            text: extract_text(&headers, body, 0).map(|(text, is_html)| {
+                if is_html {
+                    html_to_text(&text)
+                } else {
+                    text.replace("\r\n", "\n").trim_end().to_string()
+                }
+            }),
+        })
+    }
+}
+
+/// Splits the message (or MIME part) into the unfolded headers and the body.
+fn split_headers(content: &[u8]) -> (Vec<(String, String)>, &[u8]) {
+    let mut headers: Vec<(String, String)> = vec![];
+    let mut position = 0;
+    while position < content.len() {
+        let line_end = content[position..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map_or(content.len(), |index| position + index + 1);
+        let line = String::from_utf8_lossy(&content[position..line_end]);
+        let line = line.trim_end_matches(['\r', '\n']);
+        position = line_end;
+
+        if line.is_empty() {
+            break;
+        }
+
+        if line.starts_with([' ', '\t']) {
+            // Folded header continuation.
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        } else if headers.is_empty() {
+            // The very first line isn't a header, the content isn't a message.
+            return (headers, content);
+        }
+    }
+
+    (headers, &content[position.min(content.len())..])
+}
+
+/// Returns the value of the first header with the specified name (case-insensitive), if any.
+fn header_value<'h>(headers: &'h [(String, String)], name: &str) -> Option<&'h str> {
+    headers
+        .iter()
+        .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Decodes RFC 2047 encoded words in the header value. Encoded words in unsupported charsets or
+/// encodings are kept as is.
+fn decode_encoded_words(value: &str) -> String {
+    let value = ENCODED_WORDS_SEPARATOR_REGEX.replace_all(value, "$1$2");
+    ENCODED_WORD_REGEX
+        .replace_all(&value, |captures: &regex::Captures| {
+            // Charset can include language specification (RFC 2231), e.g. `UTF-8*en`.
+            let charset = captures[1].split('*').next().unwrap_or_default();
+            let Some(encoding) = Encoding::for_label_no_replacement(charset.as_bytes()) else {
+                return captures[0].to_string();
+            };
+
+            let bytes = if captures[2].eq_ignore_ascii_case("b") {
+                BASE64_STANDARD.decode(&captures[3]).ok()
+            } else {
+                Some(decode_quoted_printable(captures[3].as_bytes(), true))
+            };
+
+            bytes
+                .map(|bytes| encoding.decode_without_bom_handling(&bytes).0.into_owned())
+                .unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+/// Decodes quoted-printable content (RFC 2045). In header mode, underscores represent spaces.
+fn decode_quoted_printable(content: &[u8], is_header: bool) -> Vec<u8> {
+    let mut decoded = Vec::with_capacity(content.len());
+    let mut index = 0;
+    while index < content.len() {
+        match content[index] {
+            b'=' => {
+                let rest = &content[index + 1..];
+                if rest.starts_with(b"\r\n") {
+                    // Soft line break.
+                    index += 3;
+                } else if rest.starts_with(b"\n") {
+                    index += 2;
+                } else if let Some(byte) = rest
+                    .get(..2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    decoded.push(byte);
+                    index += 3;
+                } else {
+                    decoded.push(b'=');
+                    index += 1;
+                }
+            }
+            b'_' if is_header => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    decoded
+}
+
+/// Finds the text body in the message or MIME part, and returns it along with the flag that
+/// indicates whether the text is HTML. Plain text parts are preferred over HTML ones.
+fn extract_text(headers: &[(String, String)], body: &[u8], depth: usize) -> Option<(String, bool)> {
+    let is_attachment = header_value(headers, "Content-Disposition").is_some_and(|disposition| {
+        disposition
+            .trim_start()
+            .to_ascii_lowercase()
+            .starts_with("attachment")
+    });
+    if is_attachment {
+        return None;
+    }
+
+    // Content type defaults to `text/plain` (RFC 2045).
+    let media_type = header_value(headers, "Content-Type")
+        .and_then(|content_type| MediaTypeBuf::from_string(content_type.to_string()).ok());
+    let media_type = media_type.as_ref().map(|media_type| media_type.to_ref());
+    let (ty, subty) = media_type.as_ref().map_or((TEXT, PLAIN), |media_type| {
+        (media_type.ty, media_type.subty)
+    });
+
+    if ty == MULTIPART {
+        if depth >= MAX_MULTIPART_DEPTH {
+            return None;
+        }
+
+        let boundary = media_type
+            .as_ref()
+            .and_then(|media_type| media_type.get_param(BOUNDARY))?;
+        let mut html_text = None;
+        for part in split_multipart(body, boundary.unquoted_str().as_bytes()) {
+            let (part_headers, part_body) = split_headers(part);
+            match extract_text(&part_headers, part_body, depth + 1) {
+                Some((text, false)) => return Some((text, false)),
+                Some((text, true)) if html_text.is_none() => html_text = Some(text),
+                _ => {}
+            }
+        }
+
+        return html_text.map(|text| (text, true));
+    }
+
+    if ty != TEXT || (subty != PLAIN && subty != HTML) {
+        return None;
+    }
+
+    let body = match header_value(headers, "Content-Transfer-Encoding")
+        .map(|encoding| encoding.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("base64") => {
+            let body = body
+                .iter()
+                .filter(|byte| !byte.is_ascii_whitespace())
+                .copied()
+                .collect::<Vec<_>>();
+            BASE64_STANDARD.decode(body).ok()?
+        }
+        Some("quoted-printable") => decode_quoted_printable(body, false),
+        _ => body.to_vec(),
+    };
+
+    Some((decode_text(body, media_type.as_ref()), subty == HTML))
+}
+
+/// Transcodes the text body to UTF-8 according to the `charset` parameter of the content type.
+fn decode_text(body: Vec<u8>, media_type: Option<&MediaType>) -> String {
+    let (body, _) = CharsetDecoder::decode(Bytes::from(body), media_type);
+    String::from_utf8_lossy(&body).into_owned()
+}
+
+/// Splits the multipart body (RFC 2046) into the individual parts, the preamble and the epilogue
+/// are ignored.
+fn split_multipart<'b>(body: &'b [u8], boundary: &[u8]) -> Vec<&'b [u8]> {
+    let delimiter = [b"--", boundary].concat();
+
+    let mut parts = vec![];
+    let mut part_start = None;
+    let mut position = 0;
+    while position < body.len() {
+        let line_end = body[position..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map_or(body.len(), |index| position + index + 1);
+        let line = body[position..line_end].trim_ascii_end();
+
+        if let Some(rest) = line.strip_prefix(delimiter.as_slice()) {
+            if let Some(start) = part_start {
+                // The line break before the delimiter belongs to the delimiter.
+                let part = &body[start..position];
+                let part = part
+                    .strip_suffix(b"\r\n")
+                    .or_else(|| part.strip_suffix(b"\n"))
+                    .unwrap_or(part);
+                parts.push(part);
+            }
+
+            if rest.starts_with(b"--") {
+                return parts;
+            }
+
+            part_start = Some(line_end);
+        }
+
+        position = line_end;
+    }
+
+    // Tolerate the missing closing delimiter.
+    if let Some(start) = part_start {
+        parts.push(&body[start.min(body.len())..]);
+    }
+
+    parts
+}
+
+/// Extracts human-readable text from the HTML content.
+fn html_to_text(html: &str) -> String {
+    let text = HTML_HIDDEN_CONTENT_REGEX.replace_all(html, "");
+    let text = HTML_TAG_REGEX.replace_all(&text, " ");
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Converts RFC 2822 date to RFC 3339 in UTC, so that the dates of the messages are comparable.
+/// Dates in other formats are kept as is.
+fn normalize_date(date: String) -> String {
+    // Trailing comments, e.g. `(UTC)`, aren't supported by the RFC 2822 parser.
+    let date_without_comment = match date.split_once('(') {
+        Some((date, _)) => date.trim_end(),
+        None => date.as_str(),
+    };
+
+    OffsetDateTime::parse(date_without_comment, &Rfc2822)
+        .ok()
+        .and_then(|parsed_date| parsed_date.to_offset(UtcOffset::UTC).format(&Rfc3339).ok())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_encoded_words, decode_quoted_printable, EmailParser};
+    use crate::tests::load_fixture;
+    use insta::{assert_debug_snapshot, assert_json_snapshot};
+
+    #[test]
+    fn decodes_encoded_words() {
+        assert_eq!(decode_encoded_words("Plain subject"), "Plain subject");
+        assert_eq!(
+            decode_encoded_words("=?UTF-8?B?Q2Fmw6k=?= =?utf-8?q?_cr=C3=A8me?= (brûlée)"),
+            "Café crème (brûlée)"
+        );
+        assert_eq!(
+            decode_encoded_words("=?ISO-8859-1?Q?Andr=E9?= <andre@retrack.dev>"),
+            "André <andre@retrack.dev>"
+        );
+        assert_eq!(
+            decode_encoded_words("=?x-unknown?Q?abc?= =?UTF-8?B?not-base64?="),
+            "=?x-unknown?Q?abc?==?UTF-8?B?not-base64?="
+        );
+    }
+
+    #[test]
+    fn decodes_quoted_printable() {
+        assert_eq!(
+            decode_quoted_printable(b"Caf=C3=A9 =\r\nlong line_=3D=", false),
+            "Café long line_==".as_bytes()
+        );
+        assert_eq!(
+            decode_quoted_printable(b"Hello_world", true),
+            b"Hello world"
+        );
+    }
+
+    #[test]
+    fn parse_multipart() -> anyhow::Result<()> {
+        let fixture = load_fixture("email_fixture.eml")?;
+        assert_json_snapshot!(EmailParser::parse(&fixture)?, @r###"
+        {
+          "messageId": "<invoice-42@retrack.dev>",
+          "from": "Retrack Billing <billing@retrack.dev>",
+          "to": "user@retrack.dev",
+          "subject": "Your invoice № 42",
+          "date": "2024-01-02T10:00:00Z",
+          "text": "Your invoice № 42 is ready.\nTotal: 10 €, due in 14 days. This line is long enough to be wrapped with a soft line break."
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_simple() -> anyhow::Result<()> {
+        assert_json_snapshot!(EmailParser::parse(
+            b"From: Retrack <noreply@retrack.dev>\r\nSubject: =?UTF-8?Q?Your_code?=\r\n\
+              Date: Tue, 2 Jan 2024 11:00:00 +0100 (CET)\r\n\r\nYour code is 1234.\r\n\r\n"
+        )?, @r###"
+        {
+          "from": "Retrack <noreply@retrack.dev>",
+          "subject": "Your code",
+          "date": "2024-01-02T10:00:00Z",
+          "text": "Your code is 1234."
+        }
+        "###);
+
+        // HTML-only message with base64-encoded body in non-UTF-8 charset.
+        assert_json_snapshot!(EmailParser::parse(
+            b"Subject: News\nContent-Type: text/html; charset=windows-1252\n\
+              Content-Transfer-Encoding: base64\n\nPGh0bWw+PGhlYWQ+PHRpdGxlPk5ld3M8L3RpdGxlPjwvaGVhZD48Ym9keT48cD5DcuhtZSAm\n\
+              YW1wOyBjYWbpPC9wPjwvYm9keT48L2h0bWw+\n"
+        )?, @r###"
+        {
+          "subject": "News",
+          "text": "Crème & café"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_non_email_content() -> anyhow::Result<()> {
+        assert_debug_snapshot!(
+            EmailParser::parse(b"Not an email").unwrap_err().to_string(),
+            @r###""Content is not an email message.""###
+        );
+        assert_debug_snapshot!(
+            EmailParser::parse(b"").unwrap_err().to_string(),
+            @r###""Content is not an email message.""###
+        );
+
+        Ok(())
+    }
+}
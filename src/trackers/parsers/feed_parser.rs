@@ -0,0 +1,402 @@
+use anyhow::bail;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+use time::{
+    format_description::well_known::{Rfc2822, Rfc3339},
+    OffsetDateTime, UtcOffset,
+};
+use tracing::debug;
+
+/// Parsed RSS or Atom feed.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Feed {
+    /// Title of the feed.
+    pub title: Option<String>,
+    /// Link to the website the feed belongs to.
+    pub link: Option<String>,
+    /// Description (RSS) or subtitle (Atom) of the feed.
+    pub description: Option<String>,
+    /// Feed items in the order they appear in the feed.
+    pub items: Vec<FeedItem>,
+}
+
+/// Parsed RSS item or Atom entry.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItem {
+    /// Unique identifier of the item (RSS `guid` or Atom `id`).
+    pub id: Option<String>,
+    /// Title of the item.
+    pub title: Option<String>,
+    /// Link to the item.
+    pub link: Option<String>,
+    /// Publication date of the item in RFC 3339 format, if it can be parsed, or as is otherwise.
+    pub pub_date: Option<String>,
+    /// Full content of the item, or its summary if the content isn't available.
+    pub content: Option<String>,
+}
+
+/// Feed or feed item field the text of the current element belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FeedField {
+    Id,
+    Title,
+    Link,
+    Description,
+    Published,
+    Updated,
+    Summary,
+    Content,
+}
+
+/// Feed item fields collected while parsing, before they are normalized.
+#[derive(Debug, Default)]
+struct RawFeedItem {
+    id: Option<String>,
+    title: Option<String>,
+    link: Option<String>,
+    published: Option<String>,
+    updated: Option<String>,
+    summary: Option<String>,
+    content: Option<String>,
+}
+
+impl From<RawFeedItem> for FeedItem {
+    fn from(item: RawFeedItem) -> Self {
+        Self {
+            id: item.id,
+            title: item.title,
+            link: item.link,
+            pub_date: item.published.or(item.updated).map(normalize_date),
+            content: item.content.or(item.summary),
+        }
+    }
+}
+
+/// Parser of the RSS (0.9x, 1.0, and 2.0) and Atom feeds.
+pub struct FeedParser;
+impl FeedParser {
+    /// Parse the RSS or Atom feed content. Unknown elements and extensions are ignored, except for
+    /// the widely used `content:encoded` and `dc:date` ones.
+    pub fn parse(content: &[u8]) -> anyhow::Result<Feed> {
+        let content = String::from_utf8_lossy(content);
+        let mut reader = Reader::from_str(&content);
+
+        let mut feed = Feed::default();
+        let mut feed_item: Option<RawFeedItem> = None;
+        let mut is_feed = false;
+
+        // Local names of the currently open elements, and the field the text of the current
+        // element should be collected into, along with the depth of the element.
+        let mut path: Vec<Vec<u8>> = vec![];
+        let mut field: Option<(FeedField, usize, String)> = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) => {
+                    let name = element.local_name().as_ref().to_vec();
+                    if path.is_empty() {
+                        if !matches!(name.as_slice(), b"rss" | b"RDF" | b"feed") {
+                            bail!("Content is not an RSS or Atom feed.");
+                        }
+                        is_feed = true;
+                    }
+
+                    let parent = path.last().map(|parent| parent.as_slice());
+                    if feed_item.is_none()
+                        && matches!(name.as_slice(), b"item" | b"entry")
+                        && matches!(parent, Some(b"channel" | b"RDF" | b"feed"))
+                    {
+                        feed_item = Some(RawFeedItem::default());
+                    } else if field.is_none() && is_field_parent(feed_item.is_some(), parent) {
+                        if let Some(link) = atom_link(&reader, &element)? {
+                            set_field(&mut feed, feed_item.as_mut(), FeedField::Link, link);
+                        } else {
+                            field = if feed_item.is_some() {
+                                item_field(&name)
+                            } else {
+                                feed_field(&name)
+                            }
+                            .map(|field| (field, path.len() + 1, String::new()));
+                        }
+                    }
+
+                    path.push(name);
+                }
+                Event::Empty(element) => {
+                    // Atom links are usually empty elements with the `href` attribute.
+                    let parent = path.last().map(|parent| parent.as_slice());
+                    if is_field_parent(feed_item.is_some(), parent) {
+                        if let Some(link) = atom_link(&reader, &element)? {
+                            set_field(&mut feed, feed_item.as_mut(), FeedField::Link, link);
+                        }
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some((_, _, ref mut value)) = field {
+                        match text.unescape() {
+                            Ok(text) => value.push_str(&text),
+                            // Feeds often use HTML entities that aren't defined in XML.
+                            Err(_) => value.push_str(&String::from_utf8_lossy(&text)),
+                        }
+                    }
+                }
+                Event::CData(text) => {
+                    if let Some((_, _, ref mut value)) = field {
+                        value.push_str(&String::from_utf8_lossy(&text));
+                    }
+                }
+                Event::End(_) => {
+                    if let Some((field_name, depth, value)) = field.take() {
+                        if depth == path.len() {
+                            let value = value.trim();
+                            if !value.is_empty() {
+                                set_field(
+                                    &mut feed,
+                                    feed_item.as_mut(),
+                                    field_name,
+                                    value.to_string(),
+                                );
+                            }
+                        } else {
+                            field = Some((field_name, depth, value));
+                        }
+                    }
+
+                    if let Some(name) = path.pop() {
+                        let parent = path.last().map(|parent| parent.as_slice());
+                        if matches!(name.as_slice(), b"item" | b"entry")
+                            && matches!(parent, Some(b"channel" | b"RDF" | b"feed"))
+                        {
+                            if let Some(item) = feed_item.take() {
+                                feed.items.push(item.into());
+                            }
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if !is_feed {
+            bail!("Content is not an RSS or Atom feed.");
+        }
+
+        debug!("Parsed feed with {} items.", feed.items.len());
+
+        Ok(feed)
+    }
+}
+
+/// Returns the feed item field the element with the specified local name represents, if any.
+fn item_field(name: &[u8]) -> Option<FeedField> {
+    match name {
+        b"guid" | b"id" => Some(FeedField::Id),
+        b"title" => Some(FeedField::Title),
+        b"link" => Some(FeedField::Link),
+        b"pubDate" | b"published" | b"issued" | b"date" => Some(FeedField::Published),
+        b"updated" | b"modified" => Some(FeedField::Updated),
+        b"description" | b"summary" => Some(FeedField::Summary),
+        b"encoded" | b"content" => Some(FeedField::Content),
+        _ => None,
+    }
+}
+
+/// Returns the feed field the element with the specified local name represents, if any.
+fn feed_field(name: &[u8]) -> Option<FeedField> {
+    match name {
+        b"title" => Some(FeedField::Title),
+        b"link" => Some(FeedField::Link),
+        b"description" | b"subtitle" => Some(FeedField::Description),
+        _ => None,
+    }
+}
+
+/// Checks whether the element with the specified parent can hold the feed item field (if the item
+/// is being parsed) or the feed field (otherwise).
+fn is_field_parent(is_item: bool, parent: Option<&[u8]>) -> bool {
+    if is_item {
+        matches!(parent, Some(b"item" | b"entry"))
+    } else {
+        matches!(parent, Some(b"channel" | b"feed"))
+    }
+}
+
+/// Sets the field of the feed item that is being parsed, if any, or of the feed itself. Only the
+/// first value of the field is kept.
+fn set_field(feed: &mut Feed, item: Option<&mut RawFeedItem>, field: FeedField, value: String) {
+    let field = match (item, field) {
+        (Some(item), FeedField::Id) => &mut item.id,
+        (Some(item), FeedField::Title) => &mut item.title,
+        (Some(item), FeedField::Link) => &mut item.link,
+        (Some(item), FeedField::Published) => &mut item.published,
+        (Some(item), FeedField::Updated) => &mut item.updated,
+        (Some(item), FeedField::Summary | FeedField::Description) => &mut item.summary,
+        (Some(item), FeedField::Content) => &mut item.content,
+        (None, FeedField::Title) => &mut feed.title,
+        (None, FeedField::Link) => &mut feed.link,
+        (None, FeedField::Description) => &mut feed.description,
+        (None, _) => return,
+    };
+    field.get_or_insert(value);
+}
+
+/// Returns the URL of the Atom `link` element, if the element is an alternate link. RSS links
+/// don't have the `href` attribute, and links to the feed itself (`rel="self"`) are ignored.
+fn atom_link<B>(reader: &Reader<B>, element: &BytesStart) -> anyhow::Result<Option<String>> {
+    if element.local_name().as_ref() != b"link" {
+        return Ok(None);
+    }
+
+    let mut href = None;
+    let mut rel = None;
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        match attribute.key.local_name().as_ref() {
+            b"href" => href = Some(attribute.decode_and_unescape_value(reader)?),
+            b"rel" => rel = Some(attribute.decode_and_unescape_value(reader)?),
+            _ => {}
+        }
+    }
+
+    Ok(href
+        .filter(|_| rel.as_deref().is_none_or(|rel| rel == "alternate"))
+        .map(Cow::into_owned))
+}
+
+/// Converts RFC 2822 (RSS) and RFC 3339 (Atom) dates to RFC 3339 in UTC, so that the dates of the
+/// items from different feeds are comparable. Dates in other formats are kept as is.
+fn normalize_date(date: String) -> String {
+    OffsetDateTime::parse(&date, &Rfc2822)
+        .or_else(|_| OffsetDateTime::parse(&date, &Rfc3339))
+        .ok()
+        .and_then(|parsed_date| parsed_date.to_offset(UtcOffset::UTC).format(&Rfc3339).ok())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FeedParser;
+    use crate::tests::load_fixture;
+    use insta::{assert_debug_snapshot, assert_json_snapshot};
+
+    #[test]
+    fn parse_rss() -> anyhow::Result<()> {
+        let fixture = load_fixture("rss_fixture.xml")?;
+        assert_json_snapshot!(FeedParser::parse(&fixture)?, @r###"
+        {
+          "title": "Retrack Blog",
+          "link": "https://retrack.dev/blog",
+          "description": "News & updates",
+          "items": [
+            {
+              "id": "post-2",
+              "title": "Feed targets",
+              "link": "https://retrack.dev/blog/feed-targets",
+              "pubDate": "2024-01-02T10:00:00Z",
+              "content": "<p>Track <b>RSS</b> and <b>Atom</b> feeds.</p>"
+            },
+            {
+              "id": "https://retrack.dev/blog/hello-world",
+              "title": "Hello world",
+              "link": "https://retrack.dev/blog/hello-world",
+              "pubDate": "2024-01-01T10:00:00Z",
+              "content": "The very first post."
+            },
+            {
+              "title": "Draft",
+              "pubDate": "Someday"
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_atom() -> anyhow::Result<()> {
+        let fixture = load_fixture("atom_fixture.xml")?;
+        assert_json_snapshot!(FeedParser::parse(&fixture)?, @r###"
+        {
+          "title": "Retrack Releases",
+          "link": "https://retrack.dev/releases",
+          "description": "Latest releases",
+          "items": [
+            {
+              "id": "urn:retrack:release:1.1.0",
+              "title": "Retrack 1.1.0",
+              "link": "https://retrack.dev/releases/1.1.0",
+              "pubDate": "2024-01-02T10:00:00Z",
+              "content": "Adds feed targets."
+            },
+            {
+              "id": "urn:retrack:release:1.0.0",
+              "title": "Retrack 1.0.0",
+              "link": "https://retrack.dev/releases/1.0.0",
+              "pubDate": "2024-01-01T10:00:00Z",
+              "content": "First release."
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rdf() -> anyhow::Result<()> {
+        let feed = FeedParser::parse(
+            br#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel rdf:about="https://retrack.dev/">
+    <title>Retrack</title>
+    <link>https://retrack.dev/</link>
+  </channel>
+  <item rdf:about="https://retrack.dev/one">
+    <title>One</title>
+    <link>https://retrack.dev/one</link>
+    <dc:date>2024-01-01T10:00:00+01:00</dc:date>
+  </item>
+</rdf:RDF>"#,
+        )?;
+        assert_json_snapshot!(feed, @r###"
+        {
+          "title": "Retrack",
+          "link": "https://retrack.dev/",
+          "items": [
+            {
+              "title": "One",
+              "link": "https://retrack.dev/one",
+              "pubDate": "2024-01-01T09:00:00Z"
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_non_feed_content() -> anyhow::Result<()> {
+        assert_debug_snapshot!(
+            FeedParser::parse(b"<html><body>Not a feed</body></html>").unwrap_err().to_string(),
+            @r###""Content is not an RSS or Atom feed.""###
+        );
+        assert_debug_snapshot!(
+            FeedParser::parse(b"").unwrap_err().to_string(),
+            @r###""Content is not an RSS or Atom feed.""###
+        );
+        assert!(FeedParser::parse(b"<rss><channel><title>Broken</channel></rss>").is_err());
+
+        Ok(())
+    }
+}
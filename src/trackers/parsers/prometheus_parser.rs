@@ -0,0 +1,359 @@
+use anyhow::bail;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+
+/// Suffixes of the sample names that belong to the metric family with the name without suffix
+/// (e.g., `http_request_duration_seconds_bucket` sample of the `http_request_duration_seconds`
+/// histogram).
+const SAMPLE_NAME_SUFFIXES: [&str; 8] = [
+    "_total", "_count", "_sum", "_bucket", "_created", "_info", "_gcount", "_gsum",
+];
+
+/// Metric sample parsed from the Prometheus text exposition or OpenMetrics format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrometheusSample {
+    /// Name of the metric family the sample belongs to, or the name of the sample itself if the
+    /// family isn't declared with the `TYPE` comment.
+    pub family: String,
+    /// Name of the sample.
+    pub name: String,
+    /// Labels of the sample (name to value).
+    pub labels: BTreeMap<String, String>,
+    /// Value of the sample.
+    pub value: f64,
+}
+
+impl PrometheusSample {
+    /// Returns the series key of the sample: the sample name followed by the labels sorted by name,
+    /// e.g., `http_requests_total{code="200",method="get"}`.
+    pub fn series(&self) -> String {
+        if self.labels.is_empty() {
+            return self.name.clone();
+        }
+
+        let labels = self
+            .labels
+            .iter()
+            .map(|(name, value)| {
+                let value = value
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n");
+                format!("{name}=\"{value}\"")
+            })
+            .collect::<Vec<_>>();
+        format!("{}{{{}}}", self.name, labels.join(","))
+    }
+
+    /// Returns the value of the sample as JSON: integers are returned without fractional part, and
+    /// values that cannot be represented as JSON numbers are returned as strings (`NaN`, `+Inf`,
+    /// or `-Inf`).
+    pub fn json_value(&self) -> JsonValue {
+        if self.value.is_nan() {
+            JsonValue::from("NaN")
+        } else if self.value.is_infinite() {
+            JsonValue::from(if self.value > 0.0 { "+Inf" } else { "-Inf" })
+        } else if self.value.fract() == 0.0 && self.value.abs() <= i64::MAX as f64 {
+            JsonValue::from(self.value as i64)
+        } else {
+            serde_json::Number::from_f64(self.value).map_or(JsonValue::Null, JsonValue::Number)
+        }
+    }
+}
+
+/// Parser of the metrics in the Prometheus text exposition and OpenMetrics text formats
+/// (https://prometheus.io/docs/instrumenting/exposition_formats/).
+pub struct PrometheusParser;
+impl PrometheusParser {
+    /// Parse the metrics content into the list of samples. Sample timestamps and exemplars are
+    /// ignored.
+    pub fn parse(content: &str) -> anyhow::Result<Vec<PrometheusSample>> {
+        let mut samples = vec![];
+        let mut family: Option<&str> = None;
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                let mut parts = comment.split_whitespace();
+                match parts.next() {
+                    Some("TYPE") => family = parts.next(),
+                    Some("EOF") => break,
+                    _ => {}
+                }
+                continue;
+            }
+
+            let Some((name, labels, value)) = parse_sample(line) else {
+                bail!(
+                    "Content is not in Prometheus text format: line {} is not a valid sample.",
+                    index + 1
+                );
+            };
+
+            let family = match family {
+                Some(family)
+                    if name == family
+                        || name
+                            .strip_prefix(family)
+                            .is_some_and(|suffix| SAMPLE_NAME_SUFFIXES.contains(&suffix)) =>
+                {
+                    family.to_string()
+                }
+                _ => name.to_string(),
+            };
+            samples.push(PrometheusSample {
+                family,
+                name: name.to_string(),
+                labels,
+                value,
+            });
+        }
+
+        Ok(samples)
+    }
+
+    /// Checks whether the name is a valid metric name.
+    pub fn is_valid_metric_name(name: &str) -> bool {
+        is_valid_name(name, true)
+    }
+
+    /// Checks whether the name is a valid label name.
+    pub fn is_valid_label_name(name: &str) -> bool {
+        is_valid_name(name, false)
+    }
+}
+
+/// Parses the sample line into the sample name, labels, and value.
+fn parse_sample(line: &str) -> Option<(&str, BTreeMap<String, String>, f64)> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let name = &line[..name_end];
+    if !PrometheusParser::is_valid_metric_name(name) {
+        return None;
+    }
+
+    let (labels, rest) = match line[name_end..].strip_prefix('{') {
+        Some(rest) => parse_labels(rest)?,
+        None => (BTreeMap::new(), &line[name_end..]),
+    };
+
+    // Value can be followed by the timestamp and exemplar, which are ignored.
+    let value = rest.split_whitespace().next()?.parse().ok()?;
+    Some((name, labels, value))
+}
+
+/// Parses the labels of the sample up to the closing brace, and returns them along with the rest
+/// of the line.
+fn parse_labels(mut rest: &str) -> Option<(BTreeMap<String, String>, &str)> {
+    let mut labels = BTreeMap::new();
+    loop {
+        rest = rest.trim_start();
+        if let Some(rest) = rest.strip_prefix('}') {
+            return Some((labels, rest));
+        }
+
+        let name_end = rest.find('=')?;
+        let name = rest[..name_end].trim();
+        if !PrometheusParser::is_valid_label_name(name) {
+            return None;
+        }
+
+        rest = rest[name_end + 1..].trim_start().strip_prefix('"')?;
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        let value_end = loop {
+            match chars.next()? {
+                (index, '"') => break index,
+                (_, '\\') => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    escaped => value.push(escaped),
+                },
+                (_, char) => value.push(char),
+            }
+        };
+        labels.insert(name.to_string(), value);
+
+        rest = rest[value_end + 1..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest);
+    }
+}
+
+/// Checks whether the name is a valid metric (colons are allowed) or label name.
+fn is_valid_name(name: &str, allow_colons: bool) -> bool {
+    let is_valid_char = |char: char| char == '_' || (allow_colons && char == ':');
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || is_valid_char(char))
+        && chars.all(|char| char.is_ascii_alphanumeric() || is_valid_char(char))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrometheusParser, PrometheusSample};
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn can_parse_text_format() -> anyhow::Result<()> {
+        let samples = PrometheusParser::parse(
+            r#"
+# HELP http_requests_total The total number of HTTP requests.
+# TYPE http_requests_total counter
+http_requests_total{method="post",code="200"} 1027 1395066363000
+http_requests_total{ method = "get", code="400", } 3
+# TYPE http_request_duration_seconds histogram
+http_request_duration_seconds_bucket{le="0.5"} 129389
+http_request_duration_seconds_bucket{le="+Inf"} 144320
+http_request_duration_seconds_sum 53423.5
+http_request_duration_seconds_count 144320
+msdos_file_access_time_seconds{path="C:\\DIR\\FILE.TXT",error="Cannot find file:\n\"FILE.TXT\""} 1.458255915e9
+process_open_fds 12
+temperature NaN
+"#,
+        )?;
+
+        assert_eq!(
+            samples
+                .iter()
+                .map(|sample| (
+                    sample.family.as_str(),
+                    sample.series(),
+                    sample.json_value()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "http_requests_total",
+                    r#"http_requests_total{code="200",method="post"}"#.to_string(),
+                    json!(1027)
+                ),
+                (
+                    "http_requests_total",
+                    r#"http_requests_total{code="400",method="get"}"#.to_string(),
+                    json!(3)
+                ),
+                (
+                    "http_request_duration_seconds",
+                    r#"http_request_duration_seconds_bucket{le="0.5"}"#.to_string(),
+                    json!(129389)
+                ),
+                (
+                    "http_request_duration_seconds",
+                    r#"http_request_duration_seconds_bucket{le="+Inf"}"#.to_string(),
+                    json!(144320)
+                ),
+                (
+                    "http_request_duration_seconds",
+                    "http_request_duration_seconds_sum".to_string(),
+                    json!(53423.5)
+                ),
+                (
+                    "http_request_duration_seconds",
+                    "http_request_duration_seconds_count".to_string(),
+                    json!(144320)
+                ),
+                (
+                    "msdos_file_access_time_seconds",
+                    r#"msdos_file_access_time_seconds{error="Cannot find file:\n\"FILE.TXT\"",path="C:\\DIR\\FILE.TXT"}"#.to_string(),
+                    json!(1458255915)
+                ),
+                ("process_open_fds", "process_open_fds".to_string(), json!(12)),
+                ("temperature", "temperature".to_string(), json!("NaN")),
+            ]
+        );
+        assert_eq!(
+            samples[6].labels,
+            BTreeMap::from_iter([
+                (
+                    "error".to_string(),
+                    "Cannot find file:\n\"FILE.TXT\"".to_string()
+                ),
+                ("path".to_string(), "C:\\DIR\\FILE.TXT".to_string())
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_openmetrics_format() -> anyhow::Result<()> {
+        let samples = PrometheusParser::parse(
+            r#"# TYPE acme_http_router_request_seconds summary
+# UNIT acme_http_router_request_seconds seconds
+acme_http_router_request_seconds_sum{path="/api/v1",method="GET"} 9036.32
+acme_http_router_request_seconds_count{path="/api/v1",method="GET"} 807283.0
+# TYPE go_goroutines gauge
+go_goroutines -Inf
+# TYPE foo counter
+foo_total 17.0 1520879607.789 # {trace_id="KOO5S4vxi0o"} 0.67
+# EOF
+ignored 1
+"#,
+        )?;
+
+        assert_eq!(
+            samples,
+            vec![
+                PrometheusSample {
+                    family: "acme_http_router_request_seconds".to_string(),
+                    name: "acme_http_router_request_seconds_sum".to_string(),
+                    labels: BTreeMap::from_iter([
+                        ("method".to_string(), "GET".to_string()),
+                        ("path".to_string(), "/api/v1".to_string())
+                    ]),
+                    value: 9036.32
+                },
+                PrometheusSample {
+                    family: "acme_http_router_request_seconds".to_string(),
+                    name: "acme_http_router_request_seconds_count".to_string(),
+                    labels: BTreeMap::from_iter([
+                        ("method".to_string(), "GET".to_string()),
+                        ("path".to_string(), "/api/v1".to_string())
+                    ]),
+                    value: 807283.0
+                },
+                PrometheusSample {
+                    family: "go_goroutines".to_string(),
+                    name: "go_goroutines".to_string(),
+                    labels: BTreeMap::new(),
+                    value: f64::NEG_INFINITY
+                },
+                PrometheusSample {
+                    family: "foo".to_string(),
+                    name: "foo_total".to_string(),
+                    labels: BTreeMap::new(),
+                    value: 17.0
+                }
+            ]
+        );
+        assert_eq!(samples[2].json_value(), json!("-Inf"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_content() {
+        assert_debug_snapshot!(
+            PrometheusParser::parse("<!DOCTYPE html>\n<html></html>").unwrap_err().to_string(),
+            @r###""Content is not in Prometheus text format: line 1 is not a valid sample.""###
+        );
+        assert_debug_snapshot!(
+            PrometheusParser::parse("# TYPE up gauge\nup 1\nup{job=\"api} 1").unwrap_err().to_string(),
+            @r###""Content is not in Prometheus text format: line 3 is not a valid sample.""###
+        );
+        assert_debug_snapshot!(
+            PrometheusParser::parse("up").unwrap_err().to_string(),
+            @r###""Content is not in Prometheus text format: line 1 is not a valid sample.""###
+        );
+        assert_debug_snapshot!(
+            PrometheusParser::parse("up one").unwrap_err().to_string(),
+            @r###""Content is not in Prometheus text format: line 1 is not a valid sample.""###
+        );
+    }
+}
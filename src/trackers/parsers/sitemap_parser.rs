@@ -0,0 +1,268 @@
+use anyhow::{bail, Context};
+use flate2::read::GzDecoder;
+use quick_xml::{events::Event, Reader};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::io::Read;
+use time::{
+    format_description::well_known::Rfc3339, macros::format_description, Date, OffsetDateTime,
+    UtcOffset,
+};
+use tracing::debug;
+use url::Url;
+
+/// Maximum size of the decompressed sitemap, as defined by the Sitemaps protocol (50 MiB).
+const MAX_SITEMAP_SIZE: u64 = 50 * 1024 * 1024;
+
+/// Parsed sitemap: either the list of page URLs (`urlset`), or the list of sitemap URLs
+/// (`sitemapindex`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sitemap {
+    /// Regular sitemap with the page URLs.
+    Urls(Vec<SitemapEntry>),
+    /// Sitemap index with the URLs of other sitemaps.
+    Index(Vec<SitemapEntry>),
+}
+
+/// Page or sitemap URL listed in the sitemap.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SitemapEntry {
+    /// Normalized URL of the page or sitemap.
+    pub loc: String,
+    /// Date of the last modification in RFC 3339 format, if it can be parsed, or as is otherwise.
+    pub lastmod: Option<String>,
+}
+
+/// Sitemap entry field the text of the current element belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SitemapField {
+    Loc,
+    Lastmod,
+}
+
+/// Parser of the XML sitemaps and sitemap indexes (https://www.sitemaps.org/protocol.html).
+pub struct SitemapParser;
+impl SitemapParser {
+    /// Parse the sitemap content, optionally compressed with gzip. Entries with invalid URLs are
+    /// skipped, and extensions (images, news, etc.) are ignored.
+    pub fn parse(content: &[u8]) -> anyhow::Result<Sitemap> {
+        let content = if content.starts_with(&[0x1f, 0x8b]) {
+            let mut decompressed = vec![];
+            GzDecoder::new(content)
+                .take(MAX_SITEMAP_SIZE + 1)
+                .read_to_end(&mut decompressed)
+                .context("Failed to decompress sitemap.")?;
+            if decompressed.len() as u64 > MAX_SITEMAP_SIZE {
+                bail!("Decompressed sitemap exceeds {MAX_SITEMAP_SIZE} bytes.");
+            }
+            String::from_utf8_lossy(&decompressed).into_owned()
+        } else {
+            String::from_utf8_lossy(content).into_owned()
+        };
+        let mut reader = Reader::from_str(&content);
+
+        let mut is_index = None;
+        let mut entries = vec![];
+        let mut entry: Option<(Option<String>, Option<String>)> = None;
+
+        // Depth of the currently open elements, and the field the text of the current element
+        // should be collected into.
+        let mut depth = 0;
+        let mut field: Option<(SitemapField, String)> = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) => {
+                    depth += 1;
+                    let name = element.local_name();
+                    match (depth, name.as_ref()) {
+                        (1, b"urlset") => is_index = Some(false),
+                        (1, b"sitemapindex") => is_index = Some(true),
+                        (1, _) => bail!("Content is not a sitemap or sitemap index."),
+                        (2, b"url" | b"sitemap") => entry = Some((None, None)),
+                        (3, b"loc") if entry.is_some() => {
+                            field = Some((SitemapField::Loc, String::new()))
+                        }
+                        (3, b"lastmod") if entry.is_some() => {
+                            field = Some((SitemapField::Lastmod, String::new()))
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some((_, ref mut value)) = field {
+                        value.push_str(&text.unescape()?);
+                    }
+                }
+                Event::CData(text) => {
+                    if let Some((_, ref mut value)) = field {
+                        value.push_str(&String::from_utf8_lossy(&text));
+                    }
+                }
+                Event::End(_) => {
+                    if let (Some((field, value)), Some((loc, lastmod))) =
+                        (field.take(), entry.as_mut())
+                    {
+                        let value = value.trim();
+                        let field_value = match field {
+                            SitemapField::Loc => loc,
+                            SitemapField::Lastmod => lastmod,
+                        };
+                        if !value.is_empty() && field_value.is_none() {
+                            *field_value = Some(value.to_string());
+                        }
+                    }
+
+                    if depth == 2 {
+                        if let Some((loc, lastmod)) = entry.take() {
+                            match loc.as_deref().map(Url::parse) {
+                                Some(Ok(url)) => entries.push(SitemapEntry {
+                                    loc: url.to_string(),
+                                    lastmod: lastmod.map(normalize_date),
+                                }),
+                                _ => debug!("Skipping sitemap entry with invalid URL: {loc:?}."),
+                            }
+                        }
+                    }
+                    depth -= 1;
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        debug!("Parsed sitemap with {} entries.", entries.len());
+
+        match is_index {
+            Some(true) => Ok(Sitemap::Index(entries)),
+            Some(false) => Ok(Sitemap::Urls(entries)),
+            None => bail!("Content is not a sitemap or sitemap index."),
+        }
+    }
+}
+
+/// Converts W3C datetime dates (full date or date with time) to RFC 3339 in UTC, so that the dates
+/// from different sitemaps are comparable. Dates in other formats are kept as is.
+fn normalize_date(date: String) -> String {
+    OffsetDateTime::parse(&date, &Rfc3339)
+        .or_else(|_| {
+            Date::parse(&date, format_description!("[year]-[month]-[day]"))
+                .map(|date| date.midnight().assume_utc())
+        })
+        .ok()
+        .and_then(|parsed_date| parsed_date.to_offset(UtcOffset::UTC).format(&Rfc3339).ok())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sitemap, SitemapEntry, SitemapParser};
+    use flate2::{write::GzEncoder, Compression};
+    use insta::assert_debug_snapshot;
+    use std::io::Write;
+
+    #[test]
+    fn parse_urlset() -> anyhow::Result<()> {
+        let sitemap = SitemapParser::parse(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:image="http://www.google.com/schemas/sitemap-image/1.1">
+  <url>
+    <loc> https://RETRACK.dev:443/docs?a=1&amp;b=2 </loc>
+    <lastmod>2024-01-02T10:00:00+01:00</lastmod>
+    <changefreq>weekly</changefreq>
+    <image:image><image:loc>https://retrack.dev/logo.png</image:loc></image:image>
+  </url>
+  <url>
+    <loc><![CDATA[https://retrack.dev/blog]]></loc>
+    <lastmod>2024-01-01</lastmod>
+  </url>
+  <url>
+    <loc>https://retrack.dev/about</loc>
+    <lastmod>Someday</lastmod>
+  </url>
+  <url>
+    <loc>/relative</loc>
+  </url>
+  <url>
+    <lastmod>2024-01-01</lastmod>
+  </url>
+</urlset>"#,
+        )?;
+        assert_debug_snapshot!(sitemap, @r###"
+        Urls(
+            [
+                SitemapEntry {
+                    loc: "https://retrack.dev/docs?a=1&b=2",
+                    lastmod: Some(
+                        "2024-01-02T09:00:00Z",
+                    ),
+                },
+                SitemapEntry {
+                    loc: "https://retrack.dev/blog",
+                    lastmod: Some(
+                        "2024-01-01T00:00:00Z",
+                    ),
+                },
+                SitemapEntry {
+                    loc: "https://retrack.dev/about",
+                    lastmod: Some(
+                        "Someday",
+                    ),
+                },
+            ],
+        )
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_sitemap_index() -> anyhow::Result<()> {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap>
+    <loc>https://retrack.dev/sitemap-docs.xml</loc>
+    <lastmod>2024-01-02T10:00:00Z</lastmod>
+  </sitemap>
+  <sitemap>
+    <loc>https://retrack.dev/sitemap-blog.xml.gz</loc>
+  </sitemap>
+</sitemapindex>"#,
+        )?;
+
+        assert_eq!(
+            SitemapParser::parse(&encoder.finish()?)?,
+            Sitemap::Index(vec![
+                SitemapEntry {
+                    loc: "https://retrack.dev/sitemap-docs.xml".to_string(),
+                    lastmod: Some("2024-01-02T10:00:00Z".to_string()),
+                },
+                SitemapEntry {
+                    loc: "https://retrack.dev/sitemap-blog.xml.gz".to_string(),
+                    lastmod: None,
+                },
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_non_sitemap_content() -> anyhow::Result<()> {
+        assert_debug_snapshot!(
+            SitemapParser::parse(b"<html><body>Not a sitemap</body></html>").unwrap_err().to_string(),
+            @r###""Content is not a sitemap or sitemap index.""###
+        );
+        assert_debug_snapshot!(
+            SitemapParser::parse(b"").unwrap_err().to_string(),
+            @r###""Content is not a sitemap or sitemap index.""###
+        );
+        assert!(SitemapParser::parse(b"<urlset><url><loc>Broken</url></urlset>").is_err());
+        assert!(SitemapParser::parse(&[0x1f, 0x8b, 0x00]).is_err());
+
+        Ok(())
+    }
+}
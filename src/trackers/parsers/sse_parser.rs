@@ -0,0 +1,128 @@
+use anyhow::bail;
+
+/// Maximum size of a single event, or of a single line, the parser is willing to buffer, protects
+/// from the unbounded memory usage.
+const MAX_SSE_EVENT_SIZE: usize = 10 * 1024 * 1024;
+
+/// Incremental parser of the Server-Sent Events stream
+/// (https://html.spec.whatwg.org/multipage/server-sent-events.html). Only the event data is
+/// extracted, the event type, ID, and retry fields are ignored.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    /// Bytes of the incomplete line received so far.
+    line: Vec<u8>,
+    /// Data of the event that hasn't been dispatched yet, every data line is followed by `\n`.
+    data: String,
+    /// Indicates that the last processed byte was `\r`, and the following `\n` (if any) belongs
+    /// to the same line break.
+    skip_line_feed: bool,
+}
+
+impl SseParser {
+    /// Feeds the next chunk of the stream to the parser and returns the data of the events that
+    /// have been completed by this chunk.
+    pub fn feed(&mut self, chunk: &[u8]) -> anyhow::Result<Vec<String>> {
+        let mut events = vec![];
+        for byte in chunk {
+            if self.skip_line_feed {
+                self.skip_line_feed = false;
+                if *byte == b'\n' {
+                    continue;
+                }
+            }
+
+            match byte {
+                b'\r' | b'\n' => {
+                    self.skip_line_feed = *byte == b'\r';
+                    let line = std::mem::take(&mut self.line);
+                    if let Some(event) = self.process_line(&String::from_utf8_lossy(&line))? {
+                        events.push(event);
+                    }
+                }
+                _ => {
+                    if self.line.len() >= MAX_SSE_EVENT_SIZE {
+                        bail!("Server-Sent Events line exceeds {MAX_SSE_EVENT_SIZE} bytes.");
+                    }
+                    self.line.push(*byte);
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Processes a single line of the stream, and returns the event data if the line dispatches
+    /// the event.
+    fn process_line(&mut self, line: &str) -> anyhow::Result<Option<String>> {
+        // Empty line dispatches the event, events without data are ignored.
+        if line.is_empty() {
+            if self.data.is_empty() {
+                return Ok(None);
+            }
+
+            let mut data = std::mem::take(&mut self.data);
+            data.pop();
+            return Ok(Some(data));
+        }
+
+        // Lines starting with a colon are comments (e.g., keep-alive messages).
+        if line.starts_with(':') {
+            return Ok(None);
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        if field == "data" {
+            if self.data.len() + value.len() >= MAX_SSE_EVENT_SIZE {
+                bail!("Server-Sent Events event exceeds {MAX_SSE_EVENT_SIZE} bytes.");
+            }
+
+            self.data.push_str(value);
+            self.data.push('\n');
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SseParser;
+
+    #[test]
+    fn parses_events() -> anyhow::Result<()> {
+        let mut parser = SseParser::default();
+        assert_eq!(
+            parser
+                .feed(b": keep-alive\n\ndata: one\n\nevent: update\nid: 1\ndata:{\"a\":1}\n\n")?,
+            vec!["one".to_string(), r#"{"a":1}"#.to_string()]
+        );
+
+        // Multi-line data is joined with line feeds, and all line break styles are supported.
+        assert_eq!(
+            parser.feed(b"data: line one\r\ndata: line two\rdata\n\r\n")?,
+            vec!["line one\nline two\n".to_string()]
+        );
+
+        // Events without data are ignored.
+        assert!(parser.feed(b"event: ping\n\nretry: 1000\n\n")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_events_split_across_chunks() -> anyhow::Result<()> {
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"da")?.is_empty());
+        assert!(parser.feed(b"ta: \xF0\x9F")?.is_empty());
+        assert!(parser.feed(b"\x98\x80\r")?.is_empty());
+        assert_eq!(parser.feed(b"\n\n")?, vec!["😀".to_string()]);
+
+        // Incomplete event isn't dispatched.
+        assert!(parser.feed(b"data: incomplete\n")?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+mod summarizer_request;
+mod summarizer_response;
+
+pub use self::{
+    summarizer_request::{SummarizerMessage, SummarizerRequest},
+    summarizer_response::SummarizerResponse,
+};
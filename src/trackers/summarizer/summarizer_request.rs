@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+/// Represents request to the LLM API to summarize tracker data changes (OpenAI-compatible chat
+/// completions API).
+#[derive(Serialize, Debug, PartialEq)]
+pub struct SummarizerRequest<'a> {
+    /// The name of the model to use.
+    pub model: &'a str,
+
+    /// Messages comprising the conversation (system prompt and the changes to summarize).
+    pub messages: Vec<SummarizerMessage<'a>>,
+
+    /// The maximum number of tokens to generate.
+    pub max_tokens: u32,
+
+    /// Sampling temperature, lower values make the output more deterministic.
+    pub temperature: f32,
+}
+
+/// Represents a single message in the conversation with the LLM API.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct SummarizerMessage<'a> {
+    /// The role of the message author (`system` or `user`).
+    pub role: &'a str,
+
+    /// The content of the message.
+    pub content: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SummarizerMessage, SummarizerRequest};
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(SummarizerRequest {
+            model: "gpt-4o-mini",
+            messages: vec![
+                SummarizerMessage {
+                    role: "system",
+                    content: "Summarize the changes.",
+                },
+                SummarizerMessage {
+                    role: "user",
+                    content: "-1\n+2\n",
+                },
+            ],
+            max_tokens: 100,
+            temperature: 0.0,
+        }, @r###"
+        {
+          "model": "gpt-4o-mini",
+          "messages": [
+            {
+              "role": "system",
+              "content": "Summarize the changes."
+            },
+            {
+              "role": "user",
+              "content": "-1\n+2\n"
+            }
+          ],
+          "max_tokens": 100,
+          "temperature": 0.0
+        }
+        "###);
+
+        Ok(())
+    }
+}
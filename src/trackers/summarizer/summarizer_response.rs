@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a response of the LLM API (OpenAI-compatible chat completions API).
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SummarizerResponse {
+    /// Generated completions, only the first one is used.
+    pub choices: Vec<SummarizerResponseChoice>,
+}
+
+/// Represents a single completion generated by the LLM API.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SummarizerResponseChoice {
+    /// The generated message.
+    pub message: SummarizerResponseMessage,
+}
+
+/// Represents a message generated by the LLM API.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SummarizerResponseMessage {
+    /// The content of the message, if any.
+    pub content: Option<String>,
+}
+
+impl SummarizerResponse {
+    /// Returns the content of the first generated message, if it's not empty.
+    pub fn content(&self) -> Option<&str> {
+        self.choices
+            .first()
+            .and_then(|choice| choice.message.content.as_deref())
+            .map(str::trim)
+            .filter(|content| !content.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SummarizerResponse, SummarizerResponseChoice, SummarizerResponseMessage};
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        let response = serde_json::from_str::<SummarizerResponse>(
+            r#"
+{
+    "id": "chatcmpl-123",
+    "object": "chat.completion",
+    "choices": [{
+        "index": 0,
+        "message": { "role": "assistant", "content": " Price dropped from €120 to €99. " },
+        "finish_reason": "stop"
+    }]
+}
+          "#,
+        )?;
+        assert_eq!(
+            response,
+            SummarizerResponse {
+                choices: vec![SummarizerResponseChoice {
+                    message: SummarizerResponseMessage {
+                        content: Some(" Price dropped from €120 to €99. ".to_string()),
+                    },
+                }],
+            }
+        );
+        assert_eq!(response.content(), Some("Price dropped from €120 to €99."));
+
+        let response = serde_json::from_str::<SummarizerResponse>(
+            r#"{ "choices": [{ "message": { "content": "  " } }] }"#,
+        )?;
+        assert_eq!(response.content(), None);
+
+        let response = serde_json::from_str::<SummarizerResponse>(r#"{ "choices": [] }"#)?;
+        assert_eq!(response.content(), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,418 @@
+use crate::config::SecretsConfig;
+use anyhow::{anyhow, bail};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use retrack_types::trackers::{TargetRequest, TargetRequestBodyFormat, TargetRequestFilePart};
+use serde_json::Value as JsonValue;
+use url::form_urlencoded;
+use uuid::Uuid;
+
+/// Encoded body of the API target request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetRequestBody {
+    /// Encoded body content.
+    pub content: Vec<u8>,
+    /// The value of the `Content-Type` header that should be sent with the body, if any.
+    pub content_type: Option<String>,
+}
+
+impl TargetRequestBody {
+    /// Encodes the body of the API target request according to its body format. File parts of the
+    /// multipart body are read from the secrets store. Returns `None` if there is nothing to send.
+    pub fn encode(
+        request: &TargetRequest,
+        secrets: &SecretsConfig,
+    ) -> anyhow::Result<Option<Self>> {
+        let body = request.body.as_ref();
+        let Some(format) = request.body_format.as_ref() else {
+            return Self::encode_json(body);
+        };
+
+        match format {
+            TargetRequestBodyFormat::Json => Self::encode_json(body),
+            TargetRequestBodyFormat::Form => {
+                let Some(body) = body else {
+                    return Ok(None);
+                };
+
+                let mut serializer = form_urlencoded::Serializer::new(String::new());
+                for (name, value) in form_fields(body)? {
+                    serializer.append_pair(name, &value);
+                }
+
+                Ok(Some(Self {
+                    content: serializer.finish().into_bytes(),
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                }))
+            }
+            TargetRequestBodyFormat::Multipart { files } => {
+                if body.is_none() && files.is_empty() {
+                    return Ok(None);
+                }
+
+                let boundary = format!("retrack-{}", Uuid::now_v7().simple());
+                let mut content = vec![];
+                if let Some(body) = body {
+                    for (name, value) in form_fields(body)? {
+                        content.extend_from_slice(
+                            format!(
+                                "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{value}\r\n",
+                                escape_multipart_name(name)
+                            )
+                            .as_bytes(),
+                        );
+                    }
+                }
+
+                for TargetRequestFilePart {
+                    name,
+                    file_name,
+                    content_type,
+                    secret,
+                } in files
+                {
+                    let Some(file_content) = secrets.get(secret) else {
+                        bail!("Secret ('{secret}') is not found.");
+                    };
+
+                    content.extend_from_slice(
+                        format!(
+                            "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                            escape_multipart_name(name),
+                            escape_multipart_name(file_name),
+                            content_type
+                                .as_ref()
+                                .map(|content_type| content_type.as_str())
+                                .unwrap_or("application/octet-stream")
+                        )
+                        .as_bytes(),
+                    );
+                    content.extend_from_slice(file_content.as_bytes());
+                    content.extend_from_slice(b"\r\n");
+                }
+                content.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+                Ok(Some(Self {
+                    content,
+                    content_type: Some(format!("multipart/form-data; boundary={boundary}")),
+                }))
+            }
+            TargetRequestBodyFormat::Raw { content_type } => {
+                let content = match body {
+                    None => vec![],
+                    Some(JsonValue::String(body)) => body.as_bytes().to_vec(),
+                    Some(JsonValue::Array(bytes)) => bytes
+                        .iter()
+                        .map(|byte| {
+                            byte.as_u64()
+                                .and_then(|byte| u8::try_from(byte).ok())
+                                .ok_or_else(|| anyhow!("Raw body array must only contain bytes."))
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?,
+                    Some(_) => bail!("Raw body must be either a string or an array of bytes."),
+                };
+
+                Ok(Some(Self {
+                    content,
+                    content_type: Some(content_type.to_string()),
+                }))
+            }
+            TargetRequestBodyFormat::Base64 { content_type } => {
+                let content = match body {
+                    None => vec![],
+                    Some(JsonValue::String(body)) => STANDARD.decode(body).map_err(|_| {
+                        anyhow!("Base64 body is not a valid Base64-encoded string.")
+                    })?,
+                    Some(_) => bail!("Base64 body must be a string."),
+                };
+
+                Ok(Some(Self {
+                    content,
+                    content_type: Some(
+                        content_type
+                            .as_ref()
+                            .map(|content_type| content_type.to_string())
+                            .unwrap_or_else(|| "application/octet-stream".to_string()),
+                    ),
+                }))
+            }
+        }
+    }
+
+    fn encode_json(body: Option<&JsonValue>) -> anyhow::Result<Option<Self>> {
+        Ok(body
+            .map(serde_json::to_vec)
+            .transpose()?
+            .map(|content| Self {
+                content,
+                content_type: None,
+            }))
+    }
+}
+
+/// Converts a JSON object into a list of form fields. Arrays are treated as repeated fields.
+fn form_fields(body: &JsonValue) -> anyhow::Result<Vec<(&str, String)>> {
+    let JsonValue::Object(body) = body else {
+        bail!("Form body must be a JSON object.");
+    };
+
+    let mut fields = vec![];
+    for (name, value) in body {
+        let values = match value {
+            JsonValue::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+
+        for value in values {
+            fields.push((
+                name.as_str(),
+                match value {
+                    JsonValue::String(value) => value.clone(),
+                    JsonValue::Number(value) => value.to_string(),
+                    JsonValue::Bool(value) => value.to_string(),
+                    JsonValue::Null => String::new(),
+                    _ => bail!("Form field ('{name}') must be a scalar value or an array of scalar values."),
+                },
+            ));
+        }
+    }
+
+    Ok(fields)
+}
+
+/// Escapes the field or file name in the `Content-Disposition` header the same way browsers do.
+fn escape_multipart_name(name: &str) -> String {
+    name.replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TargetRequestBody;
+    use crate::config::SecretsConfig;
+    use insta::assert_debug_snapshot;
+    use regex::Regex;
+    use retrack_types::trackers::{TargetRequest, TargetRequestBodyFormat, TargetRequestFilePart};
+    use serde_json::json;
+
+    fn request(
+        body: Option<serde_json::Value>,
+        body_format: Option<TargetRequestBodyFormat>,
+    ) -> anyhow::Result<TargetRequest> {
+        Ok(TargetRequest {
+            body,
+            body_format,
+            ..TargetRequest::new("https://retrack.dev".parse()?)
+        })
+    }
+
+    #[test]
+    fn encodes_json_body() -> anyhow::Result<()> {
+        let secrets = SecretsConfig::default();
+        for format in [None, Some(TargetRequestBodyFormat::Json)] {
+            assert_eq!(
+                TargetRequestBody::encode(&request(None, format.clone())?, &secrets)?,
+                None
+            );
+            assert_eq!(
+                TargetRequestBody::encode(
+                    &request(Some(json!({ "key": "value" })), format)?,
+                    &secrets
+                )?,
+                Some(TargetRequestBody {
+                    content: b"{\"key\":\"value\"}".to_vec(),
+                    content_type: None
+                })
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn encodes_form_body() -> anyhow::Result<()> {
+        let secrets = SecretsConfig::default();
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(None, Some(TargetRequestBodyFormat::Form))?,
+                &secrets
+            )?,
+            None
+        );
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(
+                    Some(json!({
+                        "name": "John Doe & Co",
+                        "age": 42,
+                        "subscribed": true,
+                        "tags": ["one", "two"],
+                        "empty": null
+                    })),
+                    Some(TargetRequestBodyFormat::Form)
+                )?,
+                &secrets
+            )?,
+            Some(TargetRequestBody {
+                content: b"name=John+Doe+%26+Co&age=42&subscribed=true&tags=one&tags=two&empty="
+                    .to_vec(),
+                content_type: Some("application/x-www-form-urlencoded".to_string())
+            })
+        );
+
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(Some(json!("name=value")), Some(TargetRequestBodyFormat::Form))?, &secrets).unwrap_err().to_string(),
+            @r###""Form body must be a JSON object.""###
+        );
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(Some(json!({ "nested": { "key": "value" } })), Some(TargetRequestBodyFormat::Form))?, &secrets).unwrap_err().to_string(),
+            @r###""Form field ('nested') must be a scalar value or an array of scalar values.""###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn encodes_multipart_body() -> anyhow::Result<()> {
+        let secrets = SecretsConfig::from_iter([("report", "id,price\n1,10\n")]);
+        let body = TargetRequestBody::encode(
+            &request(
+                Some(json!({ "name": "Report \"Q1\"", "ids": [1, 2] })),
+                Some(TargetRequestBodyFormat::Multipart {
+                    files: vec![
+                        TargetRequestFilePart {
+                            name: "file".to_string(),
+                            file_name: "report.csv".to_string(),
+                            content_type: Some("text/csv".parse()?),
+                            secret: "report".to_string(),
+                        },
+                        TargetRequestFilePart {
+                            name: "raw".to_string(),
+                            file_name: "report.bin".to_string(),
+                            content_type: None,
+                            secret: "report".to_string(),
+                        },
+                    ],
+                }),
+            )?,
+            &secrets,
+        )?
+        .unwrap();
+
+        // Replace random boundary with a static one.
+        let content_type = body.content_type.unwrap();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        assert!(Regex::new(r"^retrack-[0-9a-f]{32}$")?.is_match(boundary));
+        assert_debug_snapshot!(String::from_utf8(body.content)?.replace(boundary, "BOUNDARY"), @r###""--BOUNDARY\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\nReport \"Q1\"\r\n--BOUNDARY\r\nContent-Disposition: form-data; name=\"ids\"\r\n\r\n1\r\n--BOUNDARY\r\nContent-Disposition: form-data; name=\"ids\"\r\n\r\n2\r\n--BOUNDARY\r\nContent-Disposition: form-data; name=\"file\"; filename=\"report.csv\"\r\nContent-Type: text/csv\r\n\r\nid,price\n1,10\n\r\n--BOUNDARY\r\nContent-Disposition: form-data; name=\"raw\"; filename=\"report.bin\"\r\nContent-Type: application/octet-stream\r\n\r\nid,price\n1,10\n\r\n--BOUNDARY--\r\n""###);
+
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(
+                    None,
+                    Some(TargetRequestBodyFormat::Multipart { files: vec![] })
+                )?,
+                &secrets
+            )?,
+            None
+        );
+
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(None, Some(TargetRequestBodyFormat::Multipart {
+                files: vec![TargetRequestFilePart {
+                    name: "file".to_string(),
+                    file_name: "report.csv".to_string(),
+                    content_type: None,
+                    secret: "unknown".to_string(),
+                }]
+            }))?, &secrets).unwrap_err().to_string(),
+            @r###""Secret ('unknown') is not found.""###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn encodes_raw_body() -> anyhow::Result<()> {
+        let secrets = SecretsConfig::default();
+        let format = TargetRequestBodyFormat::Raw {
+            content_type: "text/xml; charset=utf-8".parse()?,
+        };
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(Some(json!("<key>value</key>")), Some(format.clone()))?,
+                &secrets
+            )?,
+            Some(TargetRequestBody {
+                content: b"<key>value</key>".to_vec(),
+                content_type: Some("text/xml; charset=utf-8".to_string())
+            })
+        );
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(Some(json!([0, 1, 255])), Some(format.clone()))?,
+                &secrets
+            )?,
+            Some(TargetRequestBody {
+                content: vec![0, 1, 255],
+                content_type: Some("text/xml; charset=utf-8".to_string())
+            })
+        );
+
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(Some(json!([256])), Some(format.clone()))?, &secrets).unwrap_err().to_string(),
+            @r###""Raw body array must only contain bytes.""###
+        );
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(Some(json!({ "key": "value" })), Some(format))?, &secrets).unwrap_err().to_string(),
+            @r###""Raw body must be either a string or an array of bytes.""###
+        );
+
+        Ok(())
+    }
+    #[test]
+    fn encodes_base64_body() -> anyhow::Result<()> {
+        let secrets = SecretsConfig::default();
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(
+                    Some(json!("AAH/")),
+                    Some(TargetRequestBodyFormat::Base64 {
+                        content_type: Some("image/png".parse()?)
+                    })
+                )?,
+                &secrets
+            )?,
+            Some(TargetRequestBody {
+                content: vec![0, 1, 255],
+                content_type: Some("image/png".to_string())
+            })
+        );
+        assert_eq!(
+            TargetRequestBody::encode(
+                &request(
+                    None,
+                    Some(TargetRequestBodyFormat::Base64 { content_type: None })
+                )?,
+                &secrets
+            )?,
+            Some(TargetRequestBody {
+                content: vec![],
+                content_type: Some("application/octet-stream".to_string())
+            })
+        );
+
+        let format = TargetRequestBodyFormat::Base64 { content_type: None };
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(Some(json!("not base64")), Some(format.clone()))?, &secrets).unwrap_err().to_string(),
+            @r###""Base64 body is not a valid Base64-encoded string.""###
+        );
+        assert_debug_snapshot!(
+            TargetRequestBody::encode(&request(Some(json!([0, 1, 255])), Some(format))?, &secrets).unwrap_err().to_string(),
+            @r###""Base64 body must be a string.""###
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+use crate::trackers::tracker_data_path::TrackerDataPath;
+use anyhow::{anyhow, bail};
+use retrack_types::trackers::{TargetRequestPagination, TargetRequestPaginationStrategy};
+use serde_json::Value as JsonValue;
+use url::Url;
+
+/// Collects items from the pages of the paginated API target request response.
+#[derive(Debug)]
+pub struct TargetRequestPages<'p> {
+    pagination: &'p TargetRequestPagination,
+    items_path: Option<TrackerDataPath>,
+    cursor_path: Option<TrackerDataPath>,
+    items: Vec<JsonValue>,
+    pages_count: usize,
+}
+
+impl<'p> TargetRequestPages<'p> {
+    /// Creates a new collector for the pages of the paginated API target request response.
+    pub fn new(pagination: &'p TargetRequestPagination) -> anyhow::Result<Self> {
+        let cursor_path = match pagination.strategy {
+            TargetRequestPaginationStrategy::Cursor { ref path, .. } => {
+                Some(TrackerDataPath::parse(path)?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            pagination,
+            items_path: pagination
+                .items_path
+                .as_deref()
+                .map(TrackerDataPath::parse)
+                .transpose()?,
+            cursor_path,
+            items: vec![],
+            pages_count: 0,
+        })
+    }
+
+    /// Returns the URL of the first page for the specified request URL.
+    pub fn first_page_url(&self, url: &Url) -> Url {
+        match self.pagination.strategy {
+            TargetRequestPaginationStrategy::PageNumber { ref param, start } => {
+                with_query_param(url, param, &start.unwrap_or(1).to_string())
+            }
+            _ => url.clone(),
+        }
+    }
+
+    /// Adds items from the page response, and returns the URL of the next page, if it should be
+    /// requested. The value of the `Link` header is only used with the `LinkHeader` strategy.
+    pub fn add_page(
+        &mut self,
+        page_url: &Url,
+        link_header: Option<&str>,
+        page: &[u8],
+    ) -> anyhow::Result<Option<Url>> {
+        let page = serde_json::from_slice::<JsonValue>(page)
+            .map_err(|err| anyhow!("Page response is not a valid JSON: {err}"))?;
+        let page_items = match self.items_path {
+            Some(ref items_path) => items_path.select(&page),
+            None => Some(&page),
+        };
+        let Some(JsonValue::Array(page_items)) = page_items else {
+            bail!(
+                "Page response doesn't have an array of items at `{}`.",
+                self.pagination.items_path.as_deref().unwrap_or("$")
+            );
+        };
+
+        let has_items = !page_items.is_empty();
+        self.items.extend(page_items.iter().cloned());
+        self.pages_count += 1;
+        if self.pages_count >= self.pagination.max_pages {
+            return Ok(None);
+        }
+
+        Ok(match self.pagination.strategy {
+            TargetRequestPaginationStrategy::LinkHeader => link_header
+                .and_then(next_link)
+                .map(|next_link| page_url.join(next_link))
+                .transpose()?,
+            TargetRequestPaginationStrategy::Cursor { ref param, .. } => {
+                let cursor = self
+                    .cursor_path
+                    .as_ref()
+                    .and_then(|cursor_path| cursor_path.select(&page));
+                match cursor {
+                    Some(JsonValue::String(cursor)) if !cursor.is_empty() => {
+                        Some(with_query_param(page_url, param, cursor))
+                    }
+                    Some(JsonValue::Number(cursor)) => {
+                        Some(with_query_param(page_url, param, &cursor.to_string()))
+                    }
+                    _ => None,
+                }
+            }
+            TargetRequestPaginationStrategy::PageNumber { ref param, start } if has_items => {
+                let page_number = start.unwrap_or(1) + self.pages_count;
+                Some(with_query_param(page_url, param, &page_number.to_string()))
+            }
+            TargetRequestPaginationStrategy::PageNumber { .. } => None,
+        })
+    }
+
+    /// Returns the number of pages added so far.
+    pub fn pages_count(&self) -> usize {
+        self.pages_count
+    }
+
+    /// Returns items from all pages added so far serialized as a single JSON array.
+    pub fn response(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&self.items)?)
+    }
+}
+
+/// Returns a copy of the URL with the query parameter set to the specified value, replacing the
+/// existing value, if any.
+fn with_query_param(url: &Url, name: &str, value: &str) -> Url {
+    let mut url = url.clone();
+    let query_pairs = url
+        .query_pairs()
+        .filter(|(pair_name, _)| pair_name != name)
+        .map(|(pair_name, pair_value)| (pair_name.into_owned(), pair_value.into_owned()))
+        .collect::<Vec<_>>();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(query_pairs)
+        .append_pair(name, value);
+    url
+}
+
+/// Extracts the target of the link with `rel="next"` from the value of the `Link` header.
+fn next_link(link_header: &str) -> Option<&str> {
+    link_header.split(',').find_map(|link| {
+        let mut link_parts = link.split(';');
+        let target = link_parts
+            .next()?
+            .trim()
+            .strip_prefix('<')?
+            .strip_suffix('>')?;
+        link_parts
+            .any(|param| {
+                param.split_once('=').is_some_and(|(name, value)| {
+                    name.trim().eq_ignore_ascii_case("rel")
+                        && value
+                            .trim()
+                            .trim_matches('"')
+                            .split_whitespace()
+                            .any(|rel| rel.eq_ignore_ascii_case("next"))
+                })
+            })
+            .then_some(target)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_link, TargetRequestPages};
+    use retrack_types::trackers::{TargetRequestPagination, TargetRequestPaginationStrategy};
+    use serde_json::{json, Value as JsonValue};
+    use url::Url;
+
+    #[test]
+    fn collects_pages_with_link_header() -> anyhow::Result<()> {
+        let pagination = TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::LinkHeader,
+            items_path: None,
+            max_pages: 3,
+        };
+        let mut pages = TargetRequestPages::new(&pagination)?;
+
+        let url = Url::parse("https://retrack.dev/api/items?per_page=2")?;
+        assert_eq!(pages.first_page_url(&url), url);
+
+        let next_url = pages.add_page(
+            &url,
+            Some(r#"<https://retrack.dev/api/items?page=1>; rel="prev", </api/items?page=3>; rel="next""#),
+            &serde_json::to_vec(&json!([1, 2]))?,
+        )?;
+        assert_eq!(
+            next_url,
+            Some(Url::parse("https://retrack.dev/api/items?page=3")?)
+        );
+
+        let next_url = pages.add_page(
+            &next_url.unwrap(),
+            Some(r#"<https://retrack.dev/api/items?page=1>; rel="first""#),
+            &serde_json::to_vec(&json!([3]))?,
+        )?;
+        assert_eq!(next_url, None);
+        assert_eq!(pages.pages_count(), 2);
+        assert_eq!(
+            serde_json::from_slice::<JsonValue>(&pages.response()?)?,
+            json!([1, 2, 3])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn collects_pages_with_cursor() -> anyhow::Result<()> {
+        let pagination = TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::Cursor {
+                path: "$.meta.next".to_string(),
+                param: "cursor".to_string(),
+            },
+            items_path: Some("$.data".to_string()),
+            max_pages: 3,
+        };
+        let mut pages = TargetRequestPages::new(&pagination)?;
+
+        let url = Url::parse("https://retrack.dev/api/items?cursor=initial")?;
+        assert_eq!(pages.first_page_url(&url), url);
+
+        let next_url = pages.add_page(
+            &url,
+            None,
+            &serde_json::to_vec(&json!({ "data": [{ "id": 1 }], "meta": { "next": "abc" } }))?,
+        )?;
+        assert_eq!(
+            next_url,
+            Some(Url::parse("https://retrack.dev/api/items?cursor=abc")?)
+        );
+
+        let next_url = pages.add_page(
+            &next_url.unwrap(),
+            None,
+            &serde_json::to_vec(&json!({ "data": [{ "id": 2 }], "meta": { "next": 100 } }))?,
+        )?;
+        assert_eq!(
+            next_url,
+            Some(Url::parse("https://retrack.dev/api/items?cursor=100")?)
+        );
+
+        // Pagination stops once max pages is reached.
+        let next_url = pages.add_page(
+            &next_url.unwrap(),
+            None,
+            &serde_json::to_vec(&json!({ "data": [{ "id": 3 }], "meta": { "next": "def" } }))?,
+        )?;
+        assert_eq!(next_url, None);
+        assert_eq!(
+            serde_json::from_slice::<JsonValue>(&pages.response()?)?,
+            json!([{ "id": 1 }, { "id": 2 }, { "id": 3 }])
+        );
+
+        // Pagination stops when cursor is missing.
+        let mut pages = TargetRequestPages::new(&pagination)?;
+        let next_url = pages.add_page(
+            &url,
+            None,
+            &serde_json::to_vec(&json!({ "data": [], "meta": { "next": null } }))?,
+        )?;
+        assert_eq!(next_url, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn collects_pages_with_page_number() -> anyhow::Result<()> {
+        let pagination = TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::PageNumber {
+                param: "page".to_string(),
+                start: None,
+            },
+            items_path: None,
+            max_pages: 10,
+        };
+        let mut pages = TargetRequestPages::new(&pagination)?;
+
+        let url = pages.first_page_url(&Url::parse("https://retrack.dev/api/items?page=5&q=a")?);
+        assert_eq!(url.as_str(), "https://retrack.dev/api/items?q=a&page=1");
+
+        let next_url = pages.add_page(&url, None, &serde_json::to_vec(&json!([1]))?)?;
+        assert_eq!(
+            next_url.as_ref().map(Url::as_str),
+            Some("https://retrack.dev/api/items?q=a&page=2")
+        );
+
+        // Pagination stops at the empty page.
+        let next_url =
+            pages.add_page(&next_url.unwrap(), None, &serde_json::to_vec(&json!([]))?)?;
+        assert_eq!(next_url, None);
+        assert_eq!(
+            serde_json::from_slice::<JsonValue>(&pages.response()?)?,
+            json!([1])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_if_page_has_no_items() -> anyhow::Result<()> {
+        let pagination = TargetRequestPagination {
+            strategy: TargetRequestPaginationStrategy::LinkHeader,
+            items_path: Some("$.data".to_string()),
+            max_pages: 10,
+        };
+        let url = Url::parse("https://retrack.dev/api/items")?;
+
+        let mut pages = TargetRequestPages::new(&pagination)?;
+        assert_eq!(
+            pages
+                .add_page(&url, None, &serde_json::to_vec(&json!({ "data": {} }))?)
+                .unwrap_err()
+                .to_string(),
+            "Page response doesn't have an array of items at `$.data`."
+        );
+        assert!(pages.add_page(&url, None, b"not-json").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_next_link() {
+        assert_eq!(
+            next_link(r#"<https://retrack.dev/?page=2>; rel="next""#),
+            Some("https://retrack.dev/?page=2")
+        );
+        assert_eq!(
+            next_link(
+                r#"<https://retrack.dev/?page=1>; rel=prev, </?page=3>; title="x"; rel="last next""#
+            ),
+            Some("/?page=3")
+        );
+        assert_eq!(
+            next_link(r#"<https://retrack.dev/?page=1>; rel="prev""#),
+            None
+        );
+        assert_eq!(next_link("https://retrack.dev/?page=2; rel=next"), None);
+    }
+}
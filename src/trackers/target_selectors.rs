@@ -0,0 +1,332 @@
+use anyhow::{anyhow, bail};
+use retrack_types::trackers::TargetSelector;
+use scraper::{ElementRef, Html, Node, Selector};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::{cell::OnceCell, collections::BTreeMap};
+use sxd_document::{dom, Package};
+use sxd_xpath::{
+    nodeset::Node as XPathNode, Context as XPathContext, Factory as XPathFactory,
+    Value as XPathValue, XPath,
+};
+
+/// Extracts data from the HTML content with the declarative target selectors.
+pub struct TargetSelectors<'s> {
+    selectors: Vec<(&'s str, &'s TargetSelector, CompiledSelector)>,
+}
+
+/// CSS selector or XPath expression parsed from the target selector.
+enum CompiledSelector {
+    Css(Selector),
+    XPath(XPath),
+}
+
+impl<'s> TargetSelectors<'s> {
+    /// Parses CSS selectors and compiles XPath expressions of the target selectors. Fails if any
+    /// of the selectors isn't valid.
+    pub fn new(selectors: &'s BTreeMap<String, TargetSelector>) -> anyhow::Result<Self> {
+        let mut compiled_selectors = Vec::with_capacity(selectors.len());
+        for (name, selector) in selectors {
+            let compiled_selector = match (&selector.css, &selector.xpath) {
+                (Some(css), None) => {
+                    CompiledSelector::Css(Selector::parse(css).map_err(|err| {
+                        anyhow!("Selector ('{name}') has invalid CSS selector: {err}.")
+                    })?)
+                }
+                (None, Some(xpath)) => CompiledSelector::XPath(
+                    XPathFactory::new()
+                        .build(xpath)
+                        .map_err(|err| {
+                            anyhow!("Selector ('{name}') has invalid XPath expression: {err}.")
+                        })?
+                        .ok_or_else(|| {
+                            anyhow!("Selector ('{name}') has empty XPath expression.")
+                        })?,
+                ),
+                _ => bail!(
+                    "Selector ('{name}') should have either CSS selector or XPath expression."
+                ),
+            };
+
+            compiled_selectors.push((name.as_str(), selector, compiled_selector));
+        }
+
+        Ok(Self {
+            selectors: compiled_selectors,
+        })
+    }
+
+    /// Extracts values with all selectors from the HTML content, and returns them as a JSON object
+    /// (selector name to value). Single values that cannot be found are extracted as `null`.
+    pub fn extract(&self, content: &str) -> anyhow::Result<JsonValue> {
+        let html = Html::parse_document(content);
+
+        // XPath expressions are evaluated against the XML document built from the parsed HTML, so
+        // that HTML that isn't well-formed XML is still supported. Document is built only once,
+        // and only if there are XPath expressions.
+        let xpath_package = OnceCell::new();
+        let xpath_context = XPathContext::new();
+
+        let mut values = JsonMap::with_capacity(self.selectors.len());
+        for (name, selector, compiled_selector) in &self.selectors {
+            let attribute = selector.attribute.as_deref();
+            let value = match compiled_selector {
+                CompiledSelector::Css(css) => {
+                    let mut values = html.select(css).map(|element| {
+                        match attribute {
+                            Some(attribute) => element.value().attr(attribute).map(str::to_string),
+                            None => Some(element.text().collect::<String>().trim().to_string()),
+                        }
+                        .map_or(JsonValue::Null, JsonValue::String)
+                    });
+                    if selector.all {
+                        JsonValue::Array(values.collect())
+                    } else {
+                        values.next().unwrap_or(JsonValue::Null)
+                    }
+                }
+                CompiledSelector::XPath(xpath) => {
+                    let document = xpath_package
+                        .get_or_init(|| build_xpath_package(&html))
+                        .as_document();
+                    match xpath
+                        .evaluate(&xpath_context, document.root())
+                        .map_err(|err| anyhow!("Selector ('{name}') cannot be evaluated: {err}."))?
+                    {
+                        XPathValue::Nodeset(nodes) => {
+                            let mut values = nodes
+                                .document_order()
+                                .into_iter()
+                                .map(|node| xpath_node_value(node, attribute));
+                            if selector.all {
+                                JsonValue::Array(values.collect())
+                            } else {
+                                values.next().unwrap_or(JsonValue::Null)
+                            }
+                        }
+                        XPathValue::String(value) => JsonValue::String(value),
+                        XPathValue::Boolean(value) => JsonValue::Bool(value),
+                        XPathValue::Number(value) => xpath_number_value(value),
+                    }
+                }
+            };
+
+            values.insert(name.to_string(), value);
+        }
+
+        Ok(JsonValue::Object(values))
+    }
+}
+
+/// Builds the XML document from the parsed HTML. Only elements, their attributes, and text nodes
+/// are preserved.
+fn build_xpath_package(html: &Html) -> Package {
+    let package = Package::new();
+    {
+        let document = package.as_document();
+        let root_element = html.root_element();
+        let element = document.create_element(root_element.value().name());
+        append_xpath_children(&document, element, root_element);
+        document.root().append_child(element);
+    }
+    package
+}
+
+/// Recursively appends children of the HTML element to the XML element.
+fn append_xpath_children<'d>(
+    document: &dom::Document<'d>,
+    parent: dom::Element<'d>,
+    html_element: ElementRef,
+) {
+    for (name, value) in html_element.value().attrs() {
+        parent.set_attribute_value(name, value);
+    }
+
+    for child in html_element.children() {
+        match child.value() {
+            Node::Text(text) => parent.append_child(document.create_text(text)),
+            Node::Element(element) => {
+                let Some(child_element) = ElementRef::wrap(child) else {
+                    continue;
+                };
+
+                let element = document.create_element(element.name());
+                append_xpath_children(document, element, child_element);
+                parent.append_child(element);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extracts the value of the node selected by the XPath expression: the attribute value or the
+/// text content of the element, or the text value of any other node.
+fn xpath_node_value(node: XPathNode, attribute: Option<&str>) -> JsonValue {
+    match (node, attribute) {
+        (XPathNode::Element(element), Some(attribute)) => element
+            .attribute_value(attribute)
+            .map_or(JsonValue::Null, |value| {
+                JsonValue::String(value.to_string())
+            }),
+        (node, _) => JsonValue::String(node.string_value().trim().to_string()),
+    }
+}
+
+/// Converts the number returned by the XPath expression to a JSON number, integers (e.g., the
+/// result of `count()`) are returned without fractional part.
+fn xpath_number_value(value: f64) -> JsonValue {
+    if value.fract() == 0.0 && value.abs() <= i64::MAX as f64 {
+        JsonValue::from(value as i64)
+    } else {
+        serde_json::Number::from_f64(value).map_or(JsonValue::Null, JsonValue::Number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TargetSelectors;
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::TargetSelector;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    const HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <head><title>Releases</title></head>
+  <body>
+    <h1 class="title"> Retrack <b>releases</b> </h1>
+    <ul class="releases">
+      <li><a href="/v2">v2.0.0</a></li>
+      <li><a href="/v1">v1.0.0</a><br></li>
+    </ul>
+    <img src="/logo.png">
+  </body>
+</html>"#;
+
+    fn selectors(
+        selectors: impl IntoIterator<Item = (&'static str, TargetSelector)>,
+    ) -> BTreeMap<String, TargetSelector> {
+        selectors
+            .into_iter()
+            .map(|(name, selector)| (name.to_string(), selector))
+            .collect()
+    }
+
+    #[test]
+    fn extracts_values_with_css_selectors() -> anyhow::Result<()> {
+        let selectors = selectors([
+            ("title", TargetSelector::css("h1.title")),
+            (
+                "versions",
+                TargetSelector {
+                    all: true,
+                    ..TargetSelector::css(".releases li")
+                },
+            ),
+            (
+                "latest",
+                TargetSelector {
+                    attribute: Some("href".to_string()),
+                    ..TargetSelector::css(".releases a")
+                },
+            ),
+            (
+                "targets",
+                TargetSelector {
+                    attribute: Some("target".to_string()),
+                    all: true,
+                    ..TargetSelector::css(".releases a")
+                },
+            ),
+            ("missing", TargetSelector::css(".missing")),
+        ]);
+        assert_eq!(
+            TargetSelectors::new(&selectors)?.extract(HTML)?,
+            json!({
+                "title": "Retrack releases",
+                "versions": ["v2.0.0", "v1.0.0"],
+                "latest": "/v2",
+                "targets": [null, null],
+                "missing": null
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn extracts_values_with_xpath_expressions() -> anyhow::Result<()> {
+        let selectors = selectors([
+            ("title", TargetSelector::xpath("//h1[@class='title']")),
+            (
+                "versions",
+                TargetSelector {
+                    all: true,
+                    ..TargetSelector::xpath("//ul[@class='releases']/li/a/text()")
+                },
+            ),
+            (
+                "links",
+                TargetSelector {
+                    attribute: Some("href".to_string()),
+                    all: true,
+                    ..TargetSelector::xpath("//a")
+                },
+            ),
+            ("logo", TargetSelector::xpath("//img/@src")),
+            ("count", TargetSelector::xpath("count(//li)")),
+            ("ratio", TargetSelector::xpath("count(//li) div 4")),
+            ("hasLogo", TargetSelector::xpath("boolean(//img)")),
+            ("heading", TargetSelector::xpath("string(//title)")),
+            ("missing", TargetSelector::xpath("//table")),
+        ]);
+        assert_eq!(
+            TargetSelectors::new(&selectors)?.extract(HTML)?,
+            json!({
+                "title": "Retrack releases",
+                "versions": ["v2.0.0", "v1.0.0"],
+                "links": ["/v2", "/v1"],
+                "logo": "/logo.png",
+                "count": 2,
+                "ratio": 0.5,
+                "hasLogo": true,
+                "heading": "Releases",
+                "missing": null
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_invalid_selectors() -> anyhow::Result<()> {
+        let new_and_fail = |selector: TargetSelector| {
+            TargetSelectors::new(&selectors([("value", selector)]))
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_debug_snapshot!(
+            new_and_fail(TargetSelector { css: None, xpath: None, attribute: None, all: false }),
+            @r###""Selector ('value') should have either CSS selector or XPath expression.""###
+        );
+        assert_debug_snapshot!(
+            new_and_fail(TargetSelector { css: Some("a".to_string()), ..TargetSelector::xpath("//a") }),
+            @r###""Selector ('value') should have either CSS selector or XPath expression.""###
+        );
+        assert_debug_snapshot!(
+            new_and_fail(TargetSelector::css("a[")),
+            @r###""Selector ('value') has invalid CSS selector: Unexpected EOL.""###
+        );
+        assert_debug_snapshot!(
+            new_and_fail(TargetSelector::xpath("//a[")),
+            @r###""Selector ('value') has invalid XPath expression: EmptyPredicate.""###
+        );
+        assert_debug_snapshot!(
+            new_and_fail(TargetSelector::xpath("")),
+            @r###""Selector ('value') has empty XPath expression.""###
+        );
+
+        Ok(())
+    }
+}
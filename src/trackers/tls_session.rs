@@ -0,0 +1,190 @@
+use anyhow::{bail, Context};
+use reqwest::{tls::TlsInfo, Response};
+use retrack_types::trackers::TlsSessionInfo;
+use ring::digest;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// DER tags that are relevant for extracting certificate validity.
+const DER_TAG_SEQUENCE: u8 = 0x30;
+const DER_TAG_VERSION: u8 = 0xa0;
+const DER_TAG_UTC_TIME: u8 = 0x17;
+const DER_TAG_GENERALIZED_TIME: u8 = 0x18;
+
+/// Extracts TLS session details from the HTTP response. Returns `None` if response wasn't received
+/// over TLS (e.g., plain HTTP) or TLS details aren't available.
+pub fn tls_session_info(response: &Response) -> anyhow::Result<Option<TlsSessionInfo>> {
+    let Some(certificate) = response
+        .extensions()
+        .get::<TlsInfo>()
+        .and_then(|tls_info| tls_info.peer_certificate())
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(TlsSessionInfo {
+        protocol: format!("{:?}", response.version()),
+        certificate_fingerprint: digest::digest(&digest::SHA256, certificate)
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect(),
+        certificate_expires_at: certificate_expires_at(certificate)
+            .context("Cannot parse TLS certificate validity.")?,
+    }))
+}
+
+/// Reads `notAfter` field of the DER-encoded X.509 certificate:
+/// Certificate ::= SEQUENCE { tbsCertificate SEQUENCE { [0] version OPTIONAL, serialNumber,
+/// signature, issuer, validity SEQUENCE { notBefore, notAfter }, ... }, ... }
+fn certificate_expires_at(certificate: &[u8]) -> anyhow::Result<OffsetDateTime> {
+    let (_, certificate, _) = read_der(certificate, Some(DER_TAG_SEQUENCE))?;
+    let (_, tbs_certificate, _) = read_der(certificate, Some(DER_TAG_SEQUENCE))?;
+
+    let (tag, _, mut rest) = read_der(tbs_certificate, None)?;
+    // Skip the optional version, the serial number, the signature algorithm, and the issuer.
+    let fields_to_skip = if tag == DER_TAG_VERSION { 3 } else { 2 };
+    for _ in 0..fields_to_skip {
+        (_, _, rest) = read_der(rest, None)?;
+    }
+
+    let (_, validity, _) = read_der(rest, Some(DER_TAG_SEQUENCE))?;
+    let (_, _, validity) = read_der(validity, None)?;
+    let (tag, not_after, _) = read_der(validity, None)?;
+    parse_der_time(tag, not_after)
+}
+
+/// Reads a single DER TLV (tag, value, and the rest of the input).
+fn read_der(input: &[u8], expected_tag: Option<u8>) -> anyhow::Result<(u8, &[u8], &[u8])> {
+    let [tag, length, rest @ ..] = input else {
+        bail!("Unexpected end of DER input.");
+    };
+
+    if let Some(expected_tag) = expected_tag {
+        if *tag != expected_tag {
+            bail!("Unexpected DER tag {tag:#04x}, expected {expected_tag:#04x}.");
+        }
+    }
+
+    let (length, rest) = if length & 0x80 == 0 {
+        (*length as usize, rest)
+    } else {
+        let length_bytes = (length & 0x7f) as usize;
+        if length_bytes == 0 || length_bytes > 4 || rest.len() < length_bytes {
+            bail!("Unsupported DER length encoding.");
+        }
+        let (length, rest) = rest.split_at(length_bytes);
+        (
+            length
+                .iter()
+                .fold(0usize, |acc, byte| (acc << 8) | *byte as usize),
+            rest,
+        )
+    };
+
+    if rest.len() < length {
+        bail!("Unexpected end of DER input.");
+    }
+
+    let (value, rest) = rest.split_at(length);
+    Ok((*tag, value, rest))
+}
+
+/// Parses DER `UTCTime` (YYMMDDHHMMSSZ) or `GeneralizedTime` (YYYYMMDDHHMMSSZ) values.
+fn parse_der_time(tag: u8, value: &[u8]) -> anyhow::Result<OffsetDateTime> {
+    let value = std::str::from_utf8(value)?;
+    let (year, rest) = match tag {
+        DER_TAG_UTC_TIME => {
+            let year = value.get(0..2).unwrap_or_default().parse::<i32>()?;
+            (
+                if year >= 50 { 1900 + year } else { 2000 + year },
+                &value[2..],
+            )
+        }
+        DER_TAG_GENERALIZED_TIME => (
+            value.get(0..4).unwrap_or_default().parse::<i32>()?,
+            &value[4..],
+        ),
+        _ => bail!("Unexpected DER time tag {tag:#04x}."),
+    };
+
+    if rest.len() != 11 || !rest.ends_with('Z') {
+        bail!("Unsupported DER time format: {value}.");
+    }
+
+    let component = |index: usize| rest[index..index + 2].parse::<u8>();
+    Ok(PrimitiveDateTime::new(
+        Date::from_calendar_date(year, Month::try_from(component(0)?)?, component(2)?)?,
+        Time::from_hms(component(4)?, component(6)?, component(8)?)?,
+    )
+    .assume_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::certificate_expires_at;
+    use insta::assert_debug_snapshot;
+    use time::OffsetDateTime;
+
+    fn der(tag: u8, value: &[u8]) -> Vec<u8> {
+        let mut encoded = vec![tag];
+        if value.len() < 0x80 {
+            encoded.push(value.len() as u8);
+        } else {
+            encoded.extend([0x82, (value.len() >> 8) as u8, value.len() as u8]);
+        }
+        encoded.extend(value);
+        encoded
+    }
+
+    fn mock_certificate(with_version: bool, not_after: Vec<u8>) -> Vec<u8> {
+        let mut tbs_certificate = vec![];
+        if with_version {
+            tbs_certificate.extend(der(0xa0, &der(0x02, &[2])));
+        }
+        tbs_certificate.extend(der(0x02, &[1]));
+        tbs_certificate.extend(der(0x30, &der(0x06, &[0x2a; 8])));
+        tbs_certificate.extend(der(0x30, &[0x31; 200]));
+        tbs_certificate.extend(der(
+            0x30,
+            &[der(0x17, b"000101100000Z"), not_after].concat(),
+        ));
+        tbs_certificate.extend(der(0x30, &[]));
+
+        der(
+            0x30,
+            &[der(0x30, &tbs_certificate), der(0x30, &[]), der(0x03, &[0])].concat(),
+        )
+    }
+
+    #[test]
+    fn parses_certificate_expiration() -> anyhow::Result<()> {
+        assert_eq!(
+            certificate_expires_at(&mock_certificate(true, der(0x17, b"300101100000Z")))?,
+            OffsetDateTime::from_unix_timestamp(1893492000)?
+        );
+        assert_eq!(
+            certificate_expires_at(&mock_certificate(false, der(0x17, b"991231235959Z")))?,
+            OffsetDateTime::from_unix_timestamp(946684799)?
+        );
+        assert_eq!(
+            certificate_expires_at(&mock_certificate(true, der(0x18, b"20500101100000Z")))?,
+            OffsetDateTime::from_unix_timestamp(2524644000)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_invalid_certificate() -> anyhow::Result<()> {
+        assert_debug_snapshot!(
+            certificate_expires_at(&[0x30, 0x05, 0x01]).unwrap_err(),
+            @r###""Unexpected end of DER input.""###
+        );
+        assert_debug_snapshot!(
+            certificate_expires_at(&mock_certificate(true, der(0x02, b"1"))).unwrap_err(),
+            @r###""Unexpected DER time tag 0x02.""###
+        );
+
+        Ok(())
+    }
+}
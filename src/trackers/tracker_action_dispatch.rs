@@ -0,0 +1,271 @@
+use retrack_types::trackers::{
+    TrackerAction, TrackerActionCondition, TrackerActionDependency, TrackerActionDigest,
+    TrackerConfig, TrackerEscalationStep, TrackerRunActionStatus, TrackerWorkflowTransition,
+};
+use std::ops::Range;
+
+/// Outcome of the tracker action execution that dependent actions are conditioned on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TrackerActionOutcome {
+    /// Action wasn't executed, e.g. because data hasn't changed or its dependencies weren't met.
+    Skipped,
+    /// Action was executed successfully.
+    Succeeded,
+    /// Action was executed, but failed.
+    Failed,
+}
+
+impl TrackerActionOutcome {
+    /// Checks if the outcome allows actions that depend on it with the specified condition to run.
+    pub fn satisfies(self, condition: TrackerActionCondition) -> bool {
+        matches!(
+            (self, condition),
+            (Self::Succeeded, TrackerActionCondition::Succeeded)
+                | (Self::Failed, TrackerActionCondition::Failed)
+        )
+    }
+}
+
+impl From<TrackerActionOutcome> for TrackerRunActionStatus {
+    fn from(outcome: TrackerActionOutcome) -> Self {
+        match outcome {
+            TrackerActionOutcome::Skipped => Self::Skipped,
+            TrackerActionOutcome::Succeeded => Self::Succeeded,
+            TrackerActionOutcome::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Defines how the notification tracker actions (e.g., email or webhook) are delivered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TrackerActionDelivery<'t> {
+    /// Notification is scheduled as a task and delivered in the background.
+    Scheduled,
+    /// Notification is delivered right away, since there are actions depending on its outcome.
+    Immediate,
+    /// Notification is delivered later as a part of the digest.
+    Digest(&'t TrackerActionDigest),
+    /// Notification is delivered right away and isn't retried if it fails, since the action is
+    /// being tested.
+    Test,
+}
+
+impl TrackerActionDelivery<'_> {
+    /// Checks if the notification should be delivered right away.
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, Self::Immediate | Self::Test)
+    }
+}
+
+/// Defines what the tracker actions are executed for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TrackerActionsTrigger<'t> {
+    /// New data revision, along with the workflow transition it has caused, if any.
+    Revision(Option<&'t TrackerWorkflowTransition>),
+    /// Workflow transition caused by a data check that hasn't produced a new data revision.
+    Transition(&'t TrackerWorkflowTransition),
+    /// Escalation step reached by the consecutive failed data checks.
+    Escalation(&'t TrackerEscalationStep),
+}
+
+impl TrackerActionsTrigger<'_> {
+    /// Checks if the tracker action with the specified index should be executed. Actions reserved
+    /// for the workflow or escalation are only executed by the transitions or escalation steps
+    /// that refer to them.
+    pub fn includes(&self, config: &TrackerConfig, action: usize) -> bool {
+        let is_transition_action = |transition: &TrackerWorkflowTransition| {
+            transition
+                .actions
+                .as_ref()
+                .is_some_and(|actions| actions.contains(&action))
+        };
+        match self {
+            Self::Revision(transition) => {
+                let is_reserved_action = config
+                    .workflow
+                    .as_ref()
+                    .is_some_and(|workflow| workflow.is_workflow_action(action))
+                    || config
+                        .escalation
+                        .as_ref()
+                        .is_some_and(|escalation| escalation.is_escalation_action(action));
+                transition.is_some_and(is_transition_action) || !is_reserved_action
+            }
+            Self::Transition(transition) => is_transition_action(transition),
+            Self::Escalation(step) => step.actions.contains(&action),
+        }
+    }
+}
+
+/// Returns the range of the tracker actions, starting from `start`, that can be executed
+/// concurrently. Actions that transform the tracker data affect all the following actions, so they
+/// are always executed on their own. The batch also ends before the action that depends on any
+/// action within the batch, so that the outcome of the latter is known by then.
+pub fn next_tracker_actions_batch(
+    actions: &[TrackerAction],
+    dependencies: &[TrackerActionDependency],
+    start: usize,
+    concurrency: usize,
+) -> Range<usize> {
+    let mut end = start + 1;
+    if is_data_transforming_action(&actions[start]) {
+        return start..end;
+    }
+
+    while end < actions.len()
+        && end - start < concurrency
+        && !is_data_transforming_action(&actions[end])
+        && !dependencies
+            .iter()
+            .any(|dependency| dependency.action == end && dependency.depends_on >= start)
+    {
+        end += 1;
+    }
+
+    start..end
+}
+
+/// Checks if the action modifies the tracker data instead of reporting it.
+fn is_data_transforming_action(action: &TrackerAction) -> bool {
+    matches!(
+        action,
+        TrackerAction::Semver(_) | TrackerAction::Translate(_) | TrackerAction::Summarize(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_tracker_actions_batch, TrackerActionOutcome, TrackerActionsTrigger};
+    use retrack_types::trackers::{
+        EmailAction, SemverAction, TrackerAction, TrackerActionCondition, TrackerActionDependency,
+        TrackerConfig, TrackerEscalation, TrackerEscalationStep, TrackerWorkflow,
+        TrackerWorkflowEvent, TrackerWorkflowTransition,
+    };
+
+    #[test]
+    fn properly_checks_conditions() {
+        assert!(TrackerActionOutcome::Succeeded.satisfies(TrackerActionCondition::Succeeded));
+        assert!(!TrackerActionOutcome::Succeeded.satisfies(TrackerActionCondition::Failed));
+        assert!(TrackerActionOutcome::Failed.satisfies(TrackerActionCondition::Failed));
+        assert!(!TrackerActionOutcome::Failed.satisfies(TrackerActionCondition::Succeeded));
+        assert!(!TrackerActionOutcome::Skipped.satisfies(TrackerActionCondition::Succeeded));
+        assert!(!TrackerActionOutcome::Skipped.satisfies(TrackerActionCondition::Failed));
+    }
+
+    #[test]
+    fn properly_splits_actions_into_batches() {
+        let email = TrackerAction::Email(EmailAction {
+            to: vec!["dev@retrack.dev".to_string()],
+            timeout: None,
+            template: None,
+            attachment: None,
+        });
+        let semver = TrackerAction::Semver(SemverAction {
+            path: "$.version".to_string(),
+            current_version: None,
+        });
+        let actions = vec![
+            TrackerAction::ServerLog,
+            email.clone(),
+            email.clone(),
+            semver,
+            email.clone(),
+            email.clone(),
+            email,
+        ];
+
+        // Sequential execution.
+        assert_eq!(next_tracker_actions_batch(&actions, &[], 0, 1), 0..1);
+        assert_eq!(next_tracker_actions_batch(&actions, &[], 1, 1), 1..2);
+
+        // Concurrent execution is bounded by the concurrency and data transforming actions.
+        assert_eq!(next_tracker_actions_batch(&actions, &[], 0, 2), 0..2);
+        assert_eq!(next_tracker_actions_batch(&actions, &[], 0, 5), 0..3);
+        assert_eq!(next_tracker_actions_batch(&actions, &[], 3, 5), 3..4);
+        assert_eq!(next_tracker_actions_batch(&actions, &[], 4, 5), 4..7);
+
+        // Dependent actions aren't batched together with the actions they depend on.
+        let dependencies = vec![
+            TrackerActionDependency {
+                action: 5,
+                depends_on: 4,
+                condition: TrackerActionCondition::Failed,
+            },
+            TrackerActionDependency {
+                action: 6,
+                depends_on: 0,
+                condition: TrackerActionCondition::Succeeded,
+            },
+        ];
+        assert_eq!(
+            next_tracker_actions_batch(&actions, &dependencies, 4, 5),
+            4..5
+        );
+        assert_eq!(
+            next_tracker_actions_batch(&actions, &dependencies, 5, 5),
+            5..7
+        );
+    }
+
+    #[test]
+    fn properly_selects_actions_to_trigger() {
+        let config = TrackerConfig {
+            workflow: Some(TrackerWorkflow {
+                initial_state: "resolved".to_string(),
+                transitions: vec![
+                    TrackerWorkflowTransition {
+                        on: TrackerWorkflowEvent::Failure,
+                        from: None,
+                        to: "open".to_string(),
+                        actions: Some(vec![1]),
+                    },
+                    TrackerWorkflowTransition {
+                        on: TrackerWorkflowEvent::Success,
+                        from: None,
+                        to: "resolved".to_string(),
+                        actions: Some(vec![2]),
+                    },
+                ],
+            }),
+            escalation: Some(TrackerEscalation {
+                steps: vec![TrackerEscalationStep {
+                    failures: 3,
+                    actions: vec![3],
+                }],
+            }),
+            ..Default::default()
+        };
+        let workflow = config.workflow.as_ref().unwrap();
+        let escalation = config.escalation.as_ref().unwrap();
+
+        // All actions are executed for new revisions if there is no workflow or escalation.
+        let trigger = TrackerActionsTrigger::Revision(None);
+        assert!(trigger.includes(&TrackerConfig::default(), 0));
+        assert!(trigger.includes(&TrackerConfig::default(), 1));
+
+        // Workflow and escalation actions are only executed by the transitions and escalation
+        // steps that refer to them.
+        assert!(trigger.includes(&config, 0));
+        assert!(!trigger.includes(&config, 1));
+        assert!(!trigger.includes(&config, 2));
+        assert!(!trigger.includes(&config, 3));
+
+        let trigger = TrackerActionsTrigger::Revision(Some(&workflow.transitions[1]));
+        assert!(trigger.includes(&config, 0));
+        assert!(!trigger.includes(&config, 1));
+        assert!(trigger.includes(&config, 2));
+        assert!(!trigger.includes(&config, 3));
+
+        let trigger = TrackerActionsTrigger::Transition(&workflow.transitions[0]);
+        assert!(!trigger.includes(&config, 0));
+        assert!(trigger.includes(&config, 1));
+        assert!(!trigger.includes(&config, 2));
+        assert!(!trigger.includes(&config, 3));
+
+        let trigger = TrackerActionsTrigger::Escalation(&escalation.steps[0]);
+        assert!(!trigger.includes(&config, 0));
+        assert!(!trigger.includes(&config, 1));
+        assert!(!trigger.includes(&config, 2));
+        assert!(trigger.includes(&config, 3));
+    }
+}
@@ -0,0 +1,131 @@
+use retrack_types::trackers::TrackerActionThrottle;
+use ring::digest::{digest, SHA256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use time::OffsetDateTime;
+
+/// Last executions of the throttled tracker actions, persisted between the tracker data checks.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrackerActionExecutions {
+    /// List of the last executions, at most one per action.
+    pub executions: Vec<TrackerActionExecution>,
+}
+
+/// Last execution of the throttled tracker action.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrackerActionExecution {
+    /// Index of the action within the tracker actions.
+    pub action: usize,
+    /// Unix timestamp (in seconds) of the last execution.
+    pub executed_at: i64,
+    /// SHA-256 digest of the tracker data the action was last executed with.
+    pub data_digest: Vec<u8>,
+}
+
+impl TrackerActionExecutions {
+    /// Checks if the action should be skipped, because it has been executed too recently, or has
+    /// already been executed with the same data.
+    pub fn is_throttled(
+        &self,
+        throttle: &TrackerActionThrottle,
+        data: &JsonValue,
+        now: OffsetDateTime,
+    ) -> bool {
+        let Some(execution) = self
+            .executions
+            .iter()
+            .find(|execution| execution.action == throttle.action)
+        else {
+            return false;
+        };
+
+        let too_recent = throttle.interval.is_some_and(|interval| {
+            now.unix_timestamp() - execution.executed_at < interval.as_secs() as i64
+        });
+        too_recent || (throttle.deduplicate && execution.data_digest == data_digest(data))
+    }
+
+    /// Records the execution of the action with the specified data, replacing the previous one.
+    pub fn record(&mut self, action: usize, data: &JsonValue, now: OffsetDateTime) {
+        self.executions
+            .retain(|execution| execution.action != action);
+        self.executions.push(TrackerActionExecution {
+            action,
+            executed_at: now.unix_timestamp(),
+            data_digest: data_digest(data),
+        });
+    }
+}
+
+fn data_digest(data: &JsonValue) -> Vec<u8> {
+    digest(&SHA256, data.to_string().as_bytes())
+        .as_ref()
+        .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerActionExecutions;
+    use retrack_types::trackers::TrackerActionThrottle;
+    use serde_json::json;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let mut executions = TrackerActionExecutions::default();
+        executions.record(
+            1,
+            &json!({ "version": "1.0.0" }),
+            OffsetDateTime::from_unix_timestamp(946720800)?,
+        );
+        assert_eq!(
+            postcard::from_bytes::<TrackerActionExecutions>(&postcard::to_stdvec(&executions)?)?,
+            executions
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_throttles_actions() -> anyhow::Result<()> {
+        let now = OffsetDateTime::from_unix_timestamp(946720800)?;
+        let data = json!({ "version": "1.0.0" });
+        let throttle = |interval, deduplicate| TrackerActionThrottle {
+            action: 0,
+            interval,
+            deduplicate,
+        };
+
+        let mut executions = TrackerActionExecutions::default();
+        assert!(!executions.is_throttled(
+            &throttle(Some(Duration::from_secs(60)), true),
+            &data,
+            now
+        ));
+
+        executions.record(0, &data, now);
+        executions.record(1, &json!({ "version": "0.9.0" }), now);
+        assert_eq!(executions.executions.len(), 2);
+
+        // Interval.
+        let interval = throttle(Some(Duration::from_secs(60)), false);
+        let other_data = json!({ "version": "2.0.0" });
+        assert!(executions.is_throttled(&interval, &other_data, now + Duration::from_secs(59)));
+        assert!(!executions.is_throttled(&interval, &other_data, now + Duration::from_secs(60)));
+
+        // Deduplication.
+        let deduplicate = throttle(None, true);
+        assert!(executions.is_throttled(&deduplicate, &data, now + Duration::from_secs(3600)));
+        assert!(!executions.is_throttled(&deduplicate, &other_data, now));
+
+        // The latest execution replaces the previous one.
+        executions.record(0, &other_data, now + Duration::from_secs(60));
+        assert_eq!(executions.executions.len(), 2);
+        assert!(executions.is_throttled(&deduplicate, &other_data, now));
+        assert!(!executions.is_throttled(&deduplicate, &data, now));
+        assert!(executions.is_throttled(&interval, &data, now + Duration::from_secs(119)));
+
+        Ok(())
+    }
+}
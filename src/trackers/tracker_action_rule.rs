@@ -0,0 +1,266 @@
+use crate::trackers::{tracker_change_filter::parse_number, tracker_data_path::TrackerDataPath};
+use regex::Regex;
+use retrack_types::trackers::TrackerActionRule;
+use serde_json::Value as JsonValue;
+
+/// Checks whether the tracker action rule is met for the specified tracker data. The `failure`
+/// flag indicates whether the actions are executed because the data check has failed, and the
+/// `first_revision` flag indicates whether the data is the very first tracker data revision.
+pub fn matches_tracker_action_rule(
+    rule: &TrackerActionRule,
+    data: &JsonValue,
+    failure: bool,
+    first_revision: bool,
+) -> anyhow::Result<bool> {
+    if (rule.on_failure && !failure) || (rule.on_first_revision && !first_revision) {
+        return Ok(false);
+    }
+
+    let Some(ref path) = rule.path else {
+        return Ok(true);
+    };
+
+    let Some(value) = TrackerDataPath::parse(path)?.select(data) else {
+        return Ok(false);
+    };
+
+    if let Some(ref expected_value) = rule.equals {
+        if value != expected_value {
+            return Ok(false);
+        }
+    }
+
+    if let Some(ref item) = rule.contains {
+        let contains = match (value, item) {
+            (JsonValue::String(value), JsonValue::String(item)) => value.contains(item.as_str()),
+            (JsonValue::Array(values), item) => values.contains(item),
+            _ => false,
+        };
+        if !contains {
+            return Ok(false);
+        }
+    }
+
+    if let Some(ref pattern) = rule.matches {
+        let Some(value) = value.as_str() else {
+            return Ok(false);
+        };
+        if !Regex::new(pattern)?.is_match(value) {
+            return Ok(false);
+        }
+    }
+
+    if rule.min.is_none() && rule.max.is_none() {
+        return Ok(true);
+    }
+
+    let Ok(number) = parse_number(value) else {
+        return Ok(false);
+    };
+
+    Ok(match (rule.min, rule.max) {
+        (Some(min), _) if number < min => false,
+        (_, Some(max)) if number > max => false,
+        _ => true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_tracker_action_rule;
+    use retrack_types::trackers::TrackerActionRule;
+    use serde_json::json;
+
+    fn rule(path: &str) -> TrackerActionRule {
+        TrackerActionRule {
+            path: Some(path.to_string()),
+            ..TrackerActionRule::new(0)
+        }
+    }
+
+    #[test]
+    fn properly_checks_event_criteria() -> anyhow::Result<()> {
+        let data = json!({ "version": "1.0.0" });
+
+        assert!(matches_tracker_action_rule(
+            &TrackerActionRule::new(0),
+            &data,
+            false,
+            false
+        )?);
+
+        let on_failure = TrackerActionRule {
+            on_failure: true,
+            ..TrackerActionRule::new(0)
+        };
+        assert!(matches_tracker_action_rule(
+            &on_failure,
+            &data,
+            true,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &on_failure,
+            &data,
+            false,
+            true
+        )?);
+
+        let on_first_revision = TrackerActionRule {
+            on_first_revision: true,
+            ..rule("$.version")
+        };
+        assert!(matches_tracker_action_rule(
+            &on_first_revision,
+            &data,
+            false,
+            true
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &on_first_revision,
+            &data,
+            false,
+            false
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_checks_value_criteria() -> anyhow::Result<()> {
+        let data = json!({
+            "version": "1.2.0",
+            "tags": ["stable", "lts"],
+            "price": "10.5",
+            "count": 3
+        });
+
+        assert!(matches_tracker_action_rule(
+            &rule("$.version"),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &rule("$.name"),
+            &data,
+            false,
+            false
+        )?);
+        assert!(matches_tracker_action_rule(&rule("version"), &data, false, false).is_err());
+
+        let equals = |path: &str, value| TrackerActionRule {
+            equals: Some(value),
+            ..rule(path)
+        };
+        assert!(matches_tracker_action_rule(
+            &equals("$.version", json!("1.2.0")),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &equals("$.count", json!("3")),
+            &data,
+            false,
+            false
+        )?);
+
+        let contains = |path: &str, value| TrackerActionRule {
+            contains: Some(value),
+            ..rule(path)
+        };
+        assert!(matches_tracker_action_rule(
+            &contains("$.version", json!("1.2")),
+            &data,
+            false,
+            false
+        )?);
+        assert!(matches_tracker_action_rule(
+            &contains("$.tags", json!("lts")),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &contains("$.tags", json!("beta")),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &contains("$.count", json!(3)),
+            &data,
+            false,
+            false
+        )?);
+
+        let matches = |path: &str, pattern: &str| TrackerActionRule {
+            matches: Some(pattern.to_string()),
+            ..rule(path)
+        };
+        assert!(matches_tracker_action_rule(
+            &matches("$.version", r"^1\.\d+\.\d+$"),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &matches("$.version", r"^2\."),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &matches("$.count", r"\d"),
+            &data,
+            false,
+            false
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_checks_range_criteria() -> anyhow::Result<()> {
+        let data = json!({ "price": "10.5", "count": 3, "name": "product" });
+
+        let ranged = |path: &str, min, max| TrackerActionRule {
+            min,
+            max,
+            ..rule(path)
+        };
+        assert!(matches_tracker_action_rule(
+            &ranged("$.price", Some(10.5), Some(11.0)),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &ranged("$.price", None, Some(10.0)),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &ranged("$.count", Some(5.0), None),
+            &data,
+            false,
+            false
+        )?);
+        assert!(matches_tracker_action_rule(
+            &ranged("$.count", Some(3.0), None),
+            &data,
+            false,
+            false
+        )?);
+        assert!(!matches_tracker_action_rule(
+            &ranged("$.name", Some(0.0), None),
+            &data,
+            false,
+            false
+        )?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Summary of the tracker activity used to detect trackers that likely need attention.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerActivity {
+    /// ID of the tracker.
+    pub tracker_id: Uuid,
+    /// Date and time of the last tracker data revision, if any.
+    pub last_revision_at: Option<OffsetDateTime>,
+    /// Date and time when the tracker job was last triggered, if ever.
+    pub last_run_at: Option<OffsetDateTime>,
+}
@@ -0,0 +1,230 @@
+use std::collections::{BTreeMap, HashSet};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Failure of the tracker that is pending correlation with failures of other trackers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerAlert {
+    /// Unique alert id (UUIDv7).
+    pub id: Uuid,
+    /// ID of the tracker that failed.
+    pub tracker_id: Uuid,
+    /// Name of the tracker that failed.
+    pub tracker_name: String,
+    /// Tags of the tracker that failed.
+    pub tracker_tags: Vec<String>,
+    /// Human-readable description of the failure.
+    pub error: String,
+    /// Date and time when the failure occurred.
+    pub created_at: OffsetDateTime,
+}
+
+/// Group of tracker alerts that should be reported with a single notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackerAlertsGroup {
+    /// Failure that isn't correlated with failures of any other tracker.
+    Single(TrackerAlert),
+    /// Failures of the trackers sharing the same tag that occurred within the same window.
+    Incident {
+        tag: String,
+        alerts: Vec<TrackerAlert>,
+    },
+}
+
+/// Groups alerts of the distinct trackers sharing a tag into incidents, as long as the number of
+/// such trackers is at least `min_trackers`. Tags shared by the most trackers are picked first, so
+/// that every alert ends up in at most one group.
+pub fn correlate_tracker_alerts(
+    mut alerts: Vec<TrackerAlert>,
+    min_trackers: usize,
+) -> Vec<TrackerAlertsGroup> {
+    // Incident of a single tracker isn't an incident.
+    let min_trackers = min_trackers.max(2);
+
+    let mut groups = vec![];
+    loop {
+        let mut tags_trackers = BTreeMap::<&str, HashSet<Uuid>>::new();
+        for alert in &alerts {
+            for tag in &alert.tracker_tags {
+                tags_trackers
+                    .entry(tag.as_str())
+                    .or_default()
+                    .insert(alert.tracker_id);
+            }
+        }
+
+        // `max_by_key` returns the last max element, reverse to prefer tags that go first.
+        let Some((tag, trackers)) = tags_trackers
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, trackers)| trackers.len())
+        else {
+            break;
+        };
+
+        if trackers.len() < min_trackers {
+            break;
+        }
+
+        let tag = tag.to_string();
+        let (incident_alerts, rest_alerts) = alerts
+            .into_iter()
+            .partition(|alert| alert.tracker_tags.contains(&tag));
+        alerts = rest_alerts;
+        groups.push(TrackerAlertsGroup::Incident {
+            tag,
+            alerts: incident_alerts,
+        });
+    }
+
+    groups.extend(alerts.into_iter().map(TrackerAlertsGroup::Single));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{correlate_tracker_alerts, TrackerAlert, TrackerAlertsGroup};
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    fn alert(tracker_id: u128, tags: &[&str]) -> anyhow::Result<TrackerAlert> {
+        Ok(TrackerAlert {
+            id: Uuid::now_v7(),
+            tracker_id: Uuid::from_u128(tracker_id),
+            tracker_name: format!("tracker-{tracker_id}"),
+            tracker_tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            error: "Some error".to_string(),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    fn summary(groups: Vec<TrackerAlertsGroup>) -> Vec<(Option<String>, Vec<String>)> {
+        groups
+            .into_iter()
+            .map(|group| match group {
+                TrackerAlertsGroup::Single(alert) => (None, vec![alert.tracker_name]),
+                TrackerAlertsGroup::Incident { tag, alerts } => (
+                    Some(tag),
+                    alerts.into_iter().map(|alert| alert.tracker_name).collect(),
+                ),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn correlates_alerts_sharing_tag() -> anyhow::Result<()> {
+        let groups = correlate_tracker_alerts(
+            vec![
+                alert(1, &["vendor-a", "prices"])?,
+                alert(2, &["vendor-b", "prices"])?,
+                alert(3, &["vendor-a"])?,
+                alert(4, &["vendor-a", "docs"])?,
+                alert(5, &[])?,
+            ],
+            2,
+        );
+        assert_eq!(
+            summary(groups),
+            vec![
+                (
+                    Some("vendor-a".to_string()),
+                    vec![
+                        "tracker-1".to_string(),
+                        "tracker-3".to_string(),
+                        "tracker-4".to_string()
+                    ]
+                ),
+                (None, vec!["tracker-2".to_string()]),
+                (None, vec!["tracker-5".to_string()]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn counts_distinct_trackers_only() -> anyhow::Result<()> {
+        // The same tracker failing several times within the window isn't an incident.
+        let groups =
+            correlate_tracker_alerts(vec![alert(1, &["vendor-a"])?, alert(1, &["vendor-a"])?], 2);
+        assert_eq!(
+            summary(groups),
+            vec![
+                (None, vec!["tracker-1".to_string()]),
+                (None, vec!["tracker-1".to_string()]),
+            ]
+        );
+
+        let groups = correlate_tracker_alerts(
+            vec![
+                alert(1, &["vendor-a"])?,
+                alert(2, &["vendor-a"])?,
+                alert(1, &["vendor-a"])?,
+            ],
+            2,
+        );
+        assert_eq!(
+            summary(groups),
+            vec![(
+                Some("vendor-a".to_string()),
+                vec![
+                    "tracker-1".to_string(),
+                    "tracker-2".to_string(),
+                    "tracker-1".to_string()
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn respects_min_trackers() -> anyhow::Result<()> {
+        let alerts = vec![
+            alert(1, &["vendor-a"])?,
+            alert(2, &["vendor-a"])?,
+            alert(3, &["vendor-b"])?,
+            alert(4, &["vendor-b"])?,
+            alert(5, &["vendor-b"])?,
+        ];
+
+        assert_eq!(
+            summary(correlate_tracker_alerts(alerts.clone(), 3)),
+            vec![
+                (
+                    Some("vendor-b".to_string()),
+                    vec![
+                        "tracker-3".to_string(),
+                        "tracker-4".to_string(),
+                        "tracker-5".to_string()
+                    ]
+                ),
+                (None, vec!["tracker-1".to_string()]),
+                (None, vec!["tracker-2".to_string()]),
+            ]
+        );
+
+        // Minimum is always at least two trackers.
+        assert_eq!(
+            summary(correlate_tracker_alerts(alerts[..1].to_vec(), 0)),
+            vec![(None, vec!["tracker-1".to_string()])]
+        );
+
+        // Tags with the same number of trackers are picked in alphabetical order.
+        assert_eq!(
+            summary(correlate_tracker_alerts(alerts[..4].to_vec(), 2)),
+            vec![
+                (
+                    Some("vendor-a".to_string()),
+                    vec!["tracker-1".to_string(), "tracker-2".to_string()]
+                ),
+                (
+                    Some("vendor-b".to_string()),
+                    vec!["tracker-3".to_string(), "tracker-4".to_string()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
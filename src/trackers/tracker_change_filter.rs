@@ -0,0 +1,240 @@
+use crate::trackers::{
+    tracker_data_path::TrackerDataPath, tracker_data_version::parse_tracker_data_version,
+};
+use anyhow::{anyhow, bail};
+use retrack_types::trackers::{
+    TrackerChangeComparator, TrackerChangeDirection, TrackerChangeFilter,
+};
+use semver::Version;
+use serde_json::Value as JsonValue;
+use std::cmp::Ordering;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// Checks if the change of the value specified by the filter path matches the filter direction.
+/// If the previous data doesn't have the value, any new value is considered a match.
+pub fn matches_change_filter(
+    filter: &TrackerChangeFilter,
+    previous_data: &JsonValue,
+    data: &JsonValue,
+) -> anyhow::Result<bool> {
+    let path = TrackerDataPath::parse(&filter.path)?;
+    let Some(value) = path.select(data) else {
+        bail!("Tracker data doesn't have a value at `{}`.", filter.path);
+    };
+    let Some(previous_value) = path.select(previous_data) else {
+        return Ok(true);
+    };
+
+    let ordering = match filter.comparator {
+        TrackerChangeComparator::Number => parse_number(value)?
+            .partial_cmp(&parse_number(previous_value)?)
+            .unwrap_or(Ordering::Equal),
+        TrackerChangeComparator::Semver => parse_semver(value)?.cmp(&parse_semver(previous_value)?),
+        TrackerChangeComparator::Date => parse_date(value)?.cmp(&parse_date(previous_value)?),
+    };
+
+    Ok(match filter.direction {
+        TrackerChangeDirection::Increased => ordering == Ordering::Greater,
+        TrackerChangeDirection::Decreased => ordering == Ordering::Less,
+    })
+}
+
+pub fn parse_number(value: &JsonValue) -> anyhow::Result<f64> {
+    let number = match value {
+        JsonValue::Number(number) => number.as_f64(),
+        JsonValue::String(number) => number.trim().parse::<f64>().ok(),
+        _ => None,
+    };
+
+    match number {
+        Some(number) if number.is_finite() => Ok(number),
+        _ => bail!("Value `{value}` is not a valid number."),
+    }
+}
+
+fn parse_semver(value: &JsonValue) -> anyhow::Result<Version> {
+    let Some(version) = value.as_str() else {
+        bail!("Value `{value}` is not a valid semantic version.");
+    };
+
+    parse_tracker_data_version(version)
+}
+
+fn parse_date(value: &JsonValue) -> anyhow::Result<OffsetDateTime> {
+    let date = match value {
+        JsonValue::Number(timestamp) => timestamp
+            .as_i64()
+            .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok()),
+        JsonValue::String(date) => OffsetDateTime::parse(date.trim(), &Rfc3339).ok(),
+        _ => None,
+    };
+
+    date.ok_or_else(|| anyhow!("Value `{value}` is not a valid date."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_change_filter;
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{
+        TrackerChangeComparator, TrackerChangeDirection, TrackerChangeFilter,
+    };
+    use serde_json::json;
+
+    fn filter(
+        path: &str,
+        direction: TrackerChangeDirection,
+        comparator: TrackerChangeComparator,
+    ) -> TrackerChangeFilter {
+        TrackerChangeFilter {
+            path: path.to_string(),
+            direction,
+            comparator,
+        }
+    }
+
+    #[test]
+    fn properly_compares_numbers() -> anyhow::Result<()> {
+        let decreased = filter(
+            "$.price",
+            TrackerChangeDirection::Decreased,
+            TrackerChangeComparator::Number,
+        );
+        assert!(matches_change_filter(
+            &decreased,
+            &json!({ "price": 10 }),
+            &json!({ "price": 9.5 })
+        )?);
+        assert!(matches_change_filter(
+            &decreased,
+            &json!({ "price": "10" }),
+            &json!({ "price": " 9 " })
+        )?);
+        assert!(!matches_change_filter(
+            &decreased,
+            &json!({ "price": 10 }),
+            &json!({ "price": 10 })
+        )?);
+        assert!(!matches_change_filter(
+            &decreased,
+            &json!({ "price": 10 }),
+            &json!({ "price": 11 })
+        )?);
+
+        let increased = filter(
+            "$[1]",
+            TrackerChangeDirection::Increased,
+            TrackerChangeComparator::Number,
+        );
+        assert!(matches_change_filter(
+            &increased,
+            &json!([1, 2]),
+            &json!([1, 3])
+        )?);
+        assert!(!matches_change_filter(
+            &increased,
+            &json!([1, 2]),
+            &json!([3, 1])
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_compares_semver() -> anyhow::Result<()> {
+        let increased = filter(
+            "$.version",
+            TrackerChangeDirection::Increased,
+            TrackerChangeComparator::Semver,
+        );
+        assert!(matches_change_filter(
+            &increased,
+            &json!({ "version": "1.9.0" }),
+            &json!({ "version": "1.10.0" })
+        )?);
+        assert!(matches_change_filter(
+            &increased,
+            &json!({ "version": "1.9" }),
+            &json!({ "version": "V1.10" })
+        )?);
+        assert!(matches_change_filter(
+            &increased,
+            &json!({ "version": "v2.0.0-beta.1" }),
+            &json!({ "version": "v2.0.0" })
+        )?);
+        assert!(!matches_change_filter(
+            &increased,
+            &json!({ "version": "2.0.0" }),
+            &json!({ "version": "2.0.0-rc.1" })
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_compares_dates() -> anyhow::Result<()> {
+        let increased = filter(
+            "$.updatedAt",
+            TrackerChangeDirection::Increased,
+            TrackerChangeComparator::Date,
+        );
+        assert!(matches_change_filter(
+            &increased,
+            &json!({ "updatedAt": "2025-01-01T10:00:00Z" }),
+            &json!({ "updatedAt": "2025-01-01T12:00:00+01:00" })
+        )?);
+        assert!(matches_change_filter(
+            &increased,
+            &json!({ "updatedAt": 946720800 }),
+            &json!({ "updatedAt": "2000-01-01T10:00:01Z" })
+        )?);
+        assert!(!matches_change_filter(
+            &increased,
+            &json!({ "updatedAt": "2025-01-01T10:00:00Z" }),
+            &json!({ "updatedAt": "2025-01-01T11:00:00+01:00" })
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_handles_missing_and_invalid_values() -> anyhow::Result<()> {
+        let increased = filter(
+            "$.version",
+            TrackerChangeDirection::Increased,
+            TrackerChangeComparator::Semver,
+        );
+        assert!(matches_change_filter(
+            &increased,
+            &json!({}),
+            &json!({ "version": "1.0.0" })
+        )?);
+
+        assert_debug_snapshot!(
+            matches_change_filter(&increased, &json!({ "version": "1.0.0" }), &json!({})).unwrap_err().to_string(),
+            @r###""Tracker data doesn't have a value at `$.version`.""###
+        );
+        assert_debug_snapshot!(
+            matches_change_filter(&increased, &json!({ "version": "1.0.0" }), &json!({ "version": "latest" })).unwrap_err().to_string(),
+            @r###""Value `latest` is not a valid semantic version.""###
+        );
+        assert_debug_snapshot!(
+            matches_change_filter(
+                &filter("$.price", TrackerChangeDirection::Increased, TrackerChangeComparator::Number),
+                &json!({ "price": 1 }),
+                &json!({ "price": "N/A" })
+            ).unwrap_err().to_string(),
+            @r###""Value `\"N/A\"` is not a valid number.""###
+        );
+        assert_debug_snapshot!(
+            matches_change_filter(
+                &filter("$.date", TrackerChangeDirection::Increased, TrackerChangeComparator::Date),
+                &json!({ "date": 1 }),
+                &json!({ "date": "yesterday" })
+            ).unwrap_err().to_string(),
+            @r###""Value `\"yesterday\"` is not a valid date.""###
+        );
+
+        Ok(())
+    }
+}
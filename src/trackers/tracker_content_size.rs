@@ -0,0 +1,134 @@
+use anyhow::bail;
+use retrack_types::trackers::{TrackerContentSizePolicy, TrackerMaxContentSize};
+use ring::digest::{digest, SHA256};
+use serde_json::Value as JsonValue;
+use std::fmt::Write;
+
+/// Applies the tracker content size policy to the content that exceeds the maximum size. Returns
+/// `None` if the content fits, or a JSON string that should replace the content otherwise: either
+/// the truncated content with the marker, or the SHA-256 hash of the content.
+pub fn limit_tracker_content(
+    content: &[u8],
+    max_content_size: &TrackerMaxContentSize,
+) -> anyhow::Result<Option<JsonValue>> {
+    if content.len() <= max_content_size.size {
+        return Ok(None);
+    }
+
+    match max_content_size.policy {
+        TrackerContentSizePolicy::Fail => bail!(
+            "Content size ({} bytes) exceeds the maximum allowed size ({} bytes).",
+            content.len(),
+            max_content_size.size
+        ),
+        TrackerContentSizePolicy::Truncate => {
+            let marker = format!("… [truncated, {} bytes in total]", content.len());
+            let prefix = &content[..max_content_size.size.saturating_sub(marker.len())];
+
+            // Don't cut multi-byte UTF-8 characters in the middle.
+            let prefix = match std::str::from_utf8(prefix) {
+                Ok(_) => prefix,
+                Err(err) if err.error_len().is_none() => &prefix[..err.valid_up_to()],
+                Err(_) => prefix,
+            };
+
+            Ok(Some(JsonValue::String(format!(
+                "{}{marker}",
+                String::from_utf8_lossy(prefix)
+            ))))
+        }
+        TrackerContentSizePolicy::HashOnly => {
+            let hash = digest(&SHA256, content).as_ref().iter().fold(
+                String::with_capacity(64),
+                |mut hash, byte| {
+                    let _ = write!(hash, "{byte:02x}");
+                    hash
+                },
+            );
+            Ok(Some(JsonValue::String(format!("sha256:{hash}"))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::limit_tracker_content;
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::{TrackerContentSizePolicy, TrackerMaxContentSize};
+    use serde_json::json;
+
+    #[test]
+    fn keeps_content_within_limit() -> anyhow::Result<()> {
+        for policy in [
+            TrackerContentSizePolicy::Fail,
+            TrackerContentSizePolicy::Truncate,
+            TrackerContentSizePolicy::HashOnly,
+        ] {
+            let max_content_size = TrackerMaxContentSize { size: 5, policy };
+            assert_eq!(limit_tracker_content(b"", &max_content_size)?, None);
+            assert_eq!(limit_tracker_content(b"12345", &max_content_size)?, None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_content_over_limit() -> anyhow::Result<()> {
+        let max_content_size = TrackerMaxContentSize {
+            size: 5,
+            policy: TrackerContentSizePolicy::Fail,
+        };
+        assert_debug_snapshot!(
+            limit_tracker_content(b"123456", &max_content_size).unwrap_err().to_string(),
+            @r###""Content size (6 bytes) exceeds the maximum allowed size (5 bytes).""###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncates_content_over_limit() -> anyhow::Result<()> {
+        let max_content_size = TrackerMaxContentSize {
+            size: 40,
+            policy: TrackerContentSizePolicy::Truncate,
+        };
+        assert_eq!(
+            limit_tracker_content(&[b'a'; 100], &max_content_size)?,
+            Some(json!("aaaaa… [truncated, 100 bytes in total]"))
+        );
+
+        // Multi-byte characters aren't split.
+        assert_eq!(
+            limit_tracker_content("ééééééééééééééééééééé".as_bytes(), &max_content_size)?,
+            Some(json!("ééé… [truncated, 42 bytes in total]"))
+        );
+
+        // Marker is kept even if it doesn't fit.
+        let max_content_size = TrackerMaxContentSize {
+            size: 5,
+            policy: TrackerContentSizePolicy::Truncate,
+        };
+        assert_eq!(
+            limit_tracker_content(&[b'a'; 100], &max_content_size)?,
+            Some(json!("… [truncated, 100 bytes in total]"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hashes_content_over_limit() -> anyhow::Result<()> {
+        let max_content_size = TrackerMaxContentSize {
+            size: 5,
+            policy: TrackerContentSizePolicy::HashOnly,
+        };
+        assert_eq!(
+            limit_tracker_content(b"hello world", &max_content_size)?,
+            Some(json!(
+                "sha256:b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+            ))
+        );
+
+        Ok(())
+    }
+}
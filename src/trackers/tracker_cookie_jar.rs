@@ -0,0 +1,278 @@
+use cookie::Cookie;
+use serde::{Deserialize, Serialize};
+use std::{cmp::Reverse, collections::BTreeMap};
+use time::OffsetDateTime;
+use url::Url;
+
+/// Cookies set by the responses to the API target requests that are sent with the subsequent
+/// requests and persisted between tracker checks, if the tracker cookie jar is enabled.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TrackerCookieJar {
+    cookies: Vec<TrackerCookie>,
+}
+
+/// Cookie stored in the tracker cookie jar.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+struct TrackerCookie {
+    name: String,
+    value: String,
+    /// Domain of the hosts the cookie is sent to.
+    domain: String,
+    /// Indicates whether the cookie is sent only to the host that set it, and not to its
+    /// subdomains (cookie doesn't have `Domain` attribute).
+    host_only: bool,
+    /// Path prefix of the URLs the cookie is sent to.
+    path: String,
+    /// Indicates whether the cookie is sent only over secure connections.
+    secure: bool,
+    /// Unix timestamp (in seconds) of the cookie expiration, session cookies never expire since
+    /// the tracker checks are not bound to any session.
+    expires_at: Option<i64>,
+}
+
+impl TrackerCookieJar {
+    /// Stores cookies from the `Set-Cookie` headers of the response to the request with the
+    /// specified URL. Cookies that cannot be parsed or are set for a domain the request URL
+    /// doesn't belong to are ignored, and cookies that have already expired are removed.
+    pub fn store<'h>(
+        &mut self,
+        url: &Url,
+        set_cookie_headers: impl IntoIterator<Item = &'h str>,
+        now: OffsetDateTime,
+    ) {
+        let Some(host) = url.host_str() else {
+            return;
+        };
+
+        for set_cookie_header in set_cookie_headers {
+            let Ok(cookie) = Cookie::parse(set_cookie_header) else {
+                continue;
+            };
+
+            let (domain, host_only) = match cookie.domain() {
+                Some(domain) => {
+                    let domain = domain.to_lowercase();
+                    if !domain_matches(host, &domain) {
+                        continue;
+                    }
+                    (domain, false)
+                }
+                None => (host.to_string(), true),
+            };
+
+            let path = match cookie.path() {
+                Some(path) if path.starts_with('/') => path.to_string(),
+                _ => default_path(url),
+            };
+
+            // `Max-Age` attribute takes precedence over the `Expires` one.
+            let expires_at = match cookie.max_age() {
+                Some(max_age) => Some((now + max_age).unix_timestamp()),
+                None => cookie
+                    .expires_datetime()
+                    .map(|expires| expires.unix_timestamp()),
+            };
+
+            self.cookies.retain(|stored_cookie| {
+                stored_cookie.name != cookie.name()
+                    || stored_cookie.domain != domain
+                    || stored_cookie.path != path
+            });
+
+            if expires_at.is_some_and(|expires_at| expires_at <= now.unix_timestamp()) {
+                continue;
+            }
+
+            self.cookies.push(TrackerCookie {
+                name: cookie.name().to_string(),
+                value: cookie.value().to_string(),
+                domain,
+                host_only,
+                path,
+                secure: cookie.secure().unwrap_or_default(),
+                expires_at,
+            });
+        }
+    }
+
+    /// Returns the value of the `Cookie` header for the request with the specified URL, if there
+    /// are any cookies to send. Explicit request cookies take precedence over the stored cookies
+    /// with the same name.
+    pub fn cookie_header(
+        &self,
+        url: &Url,
+        explicit_cookies: Option<&BTreeMap<String, String>>,
+        now: OffsetDateTime,
+    ) -> Option<String> {
+        let host = url.host_str().unwrap_or_default();
+        let is_secure = matches!(url.scheme(), "https" | "wss");
+
+        // Cookies with longer paths are listed first.
+        let mut stored_cookies = self
+            .cookies
+            .iter()
+            .filter(|cookie| {
+                !explicit_cookies.is_some_and(|explicit| explicit.contains_key(&cookie.name))
+                    && (is_secure || !cookie.secure)
+                    && cookie
+                        .expires_at
+                        .is_none_or(|expires_at| expires_at > now.unix_timestamp())
+                    && if cookie.host_only {
+                        host == cookie.domain
+                    } else {
+                        domain_matches(host, &cookie.domain)
+                    }
+                    && path_matches(url.path(), &cookie.path)
+            })
+            .collect::<Vec<_>>();
+        stored_cookies.sort_by_key(|cookie| Reverse(cookie.path.len()));
+
+        let cookies = stored_cookies
+            .into_iter()
+            .map(|cookie| (cookie.name.as_str(), cookie.value.as_str()))
+            .chain(
+                explicit_cookies
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, value)| (name.as_str(), value.as_str())),
+            )
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        if cookies.is_empty() {
+            None
+        } else {
+            Some(cookies.join("; "))
+        }
+    }
+
+    /// Removes cookies that have already expired.
+    pub fn remove_expired(&mut self, now: OffsetDateTime) {
+        self.cookies.retain(|cookie| {
+            cookie
+                .expires_at
+                .is_none_or(|expires_at| expires_at > now.unix_timestamp())
+        });
+    }
+
+    /// Indicates whether the cookie jar has any cookies.
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty()
+    }
+}
+
+/// Checks whether the host belongs to the specified cookie domain.
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || host
+            .strip_suffix(domain)
+            .is_some_and(|subdomain| subdomain.ends_with('.'))
+}
+
+/// Checks whether the request path belongs to the specified cookie path.
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || request_path
+            .strip_prefix(cookie_path)
+            .is_some_and(|rest| cookie_path.ends_with('/') || rest.starts_with('/'))
+}
+
+/// Returns the default cookie path for the URL: the path up to, but not including, its last slash.
+fn default_path(url: &Url) -> String {
+    match url.path().rfind('/') {
+        Some(index) if index > 0 => url.path()[..index].to_string(),
+        _ => "/".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerCookieJar;
+    use time::OffsetDateTime;
+    use url::Url;
+
+    #[test]
+    fn can_store_and_send_cookies() -> anyhow::Result<()> {
+        let now = OffsetDateTime::from_unix_timestamp(946720800)?;
+        let login_url = Url::parse("https://retrack.dev/auth/login")?;
+
+        let mut jar = TrackerCookieJar::default();
+        jar.store(
+            &login_url,
+            [
+                "session=abc; Path=/; Secure; HttpOnly",
+                "theme=dark; Domain=.retrack.dev; Path=/",
+                "csrf=xyz",
+                "expired=1; Max-Age=0",
+                "foreign=1; Domain=example.com",
+                "invalid",
+            ],
+            now,
+        );
+
+        assert_eq!(
+            jar.cookie_header(&Url::parse("https://retrack.dev/api")?, None, now),
+            Some("session=abc; theme=dark".to_string())
+        );
+        assert_eq!(
+            jar.cookie_header(&Url::parse("https://retrack.dev/auth/me")?, None, now),
+            Some("csrf=xyz; session=abc; theme=dark".to_string())
+        );
+        assert_eq!(
+            jar.cookie_header(&Url::parse("http://retrack.dev/api")?, None, now),
+            Some("theme=dark".to_string())
+        );
+        assert_eq!(
+            jar.cookie_header(&Url::parse("https://api.retrack.dev/")?, None, now),
+            Some("theme=dark".to_string())
+        );
+        assert_eq!(
+            jar.cookie_header(&Url::parse("https://example.com/")?, None, now),
+            None
+        );
+
+        // Explicit cookies take precedence.
+        assert_eq!(
+            jar.cookie_header(
+                &Url::parse("https://retrack.dev/api")?,
+                Some(
+                    &[("theme".to_string(), "light".to_string())]
+                        .into_iter()
+                        .collect()
+                ),
+                now
+            ),
+            Some("session=abc; theme=light".to_string())
+        );
+
+        // Cookies are replaced and removed.
+        jar.store(
+            &login_url,
+            [
+                "session=def; Path=/; Max-Age=60",
+                "theme=; Domain=retrack.dev; Path=/; Max-Age=0",
+            ],
+            now,
+        );
+        assert_eq!(
+            jar.cookie_header(&Url::parse("https://retrack.dev/api")?, None, now),
+            Some("session=def".to_string())
+        );
+
+        // Expired cookies aren't sent and can be removed.
+        let later = now + time::Duration::seconds(61);
+        assert_eq!(
+            jar.cookie_header(&Url::parse("https://retrack.dev/auth/me")?, None, later),
+            Some("csrf=xyz".to_string())
+        );
+        jar.remove_expired(later);
+        assert_eq!(
+            postcard::from_bytes::<TrackerCookieJar>(&postcard::to_stdvec(&jar)?)?,
+            jar
+        );
+
+        jar.store(&login_url, ["csrf=; Max-Age=0"], later);
+        assert!(jar.is_empty());
+
+        Ok(())
+    }
+}
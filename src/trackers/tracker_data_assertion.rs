@@ -0,0 +1,210 @@
+use crate::trackers::tracker_data_path::TrackerDataPath;
+use retrack_types::trackers::{TrackerDataAssertion, TrackerDataAssertionValueType};
+use serde_json::Value as JsonValue;
+
+/// Checks whether the tracker data satisfies the assertion, and returns the description of the
+/// violation if it doesn't.
+pub fn check_tracker_data_assertion(
+    assertion: &TrackerDataAssertion,
+    data: &JsonValue,
+) -> anyhow::Result<Option<String>> {
+    let path = &assertion.path;
+    let Some(value) = TrackerDataPath::parse(path)?.select(data) else {
+        return Ok(Some(format!("Value at `{path}` doesn't exist.")));
+    };
+
+    if let Some(value_type) = assertion.value_type {
+        let actual_value_type = get_value_type(value);
+        if actual_value_type != value_type {
+            return Ok(Some(format!(
+                "Value at `{path}` is expected to be {}, but it's {}.",
+                describe_value_type(value_type),
+                describe_value_type(actual_value_type)
+            )));
+        }
+    }
+
+    if assertion.not_empty && is_empty_value(value) {
+        return Ok(Some(format!("Value at `{path}` is empty.")));
+    }
+
+    if assertion.min.is_none() && assertion.max.is_none() {
+        return Ok(None);
+    }
+
+    let Some(number) = value.as_f64() else {
+        return Ok(Some(format!(
+            "Value at `{path}` is expected to be a number, but it's {}.",
+            describe_value_type(get_value_type(value))
+        )));
+    };
+
+    Ok(match (assertion.min, assertion.max) {
+        (Some(min), _) if number < min => {
+            Some(format!("Value at `{path}` ({number}) is less than {min}."))
+        }
+        (_, Some(max)) if number > max => Some(format!(
+            "Value at `{path}` ({number}) is greater than {max}."
+        )),
+        _ => None,
+    })
+}
+
+fn get_value_type(value: &JsonValue) -> TrackerDataAssertionValueType {
+    match value {
+        JsonValue::Null => TrackerDataAssertionValueType::Null,
+        JsonValue::Bool(_) => TrackerDataAssertionValueType::Boolean,
+        JsonValue::Number(_) => TrackerDataAssertionValueType::Number,
+        JsonValue::String(_) => TrackerDataAssertionValueType::String,
+        JsonValue::Array(_) => TrackerDataAssertionValueType::Array,
+        JsonValue::Object(_) => TrackerDataAssertionValueType::Object,
+    }
+}
+
+fn describe_value_type(value_type: TrackerDataAssertionValueType) -> &'static str {
+    match value_type {
+        TrackerDataAssertionValueType::Null => "null",
+        TrackerDataAssertionValueType::Boolean => "a boolean",
+        TrackerDataAssertionValueType::Number => "a number",
+        TrackerDataAssertionValueType::String => "a string",
+        TrackerDataAssertionValueType::Array => "an array",
+        TrackerDataAssertionValueType::Object => "an object",
+    }
+}
+
+fn is_empty_value(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Null => true,
+        JsonValue::String(value) => value.trim().is_empty(),
+        JsonValue::Array(value) => value.is_empty(),
+        JsonValue::Object(value) => value.is_empty(),
+        JsonValue::Bool(_) | JsonValue::Number(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_tracker_data_assertion;
+    use retrack_types::trackers::{TrackerDataAssertion, TrackerDataAssertionValueType};
+    use serde_json::json;
+
+    fn assertion(path: &str) -> TrackerDataAssertion {
+        TrackerDataAssertion {
+            path: path.to_string(),
+            value_type: None,
+            not_empty: false,
+            min: None,
+            max: None,
+        }
+    }
+
+    #[test]
+    fn properly_checks_value_existence() -> anyhow::Result<()> {
+        let data = json!({ "releases": [{ "version": "1.0.0" }], "empty": null });
+
+        assert_eq!(
+            check_tracker_data_assertion(&assertion("$.releases[0].version"), &data)?,
+            None
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&assertion("$.empty"), &data)?,
+            None
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&assertion("$.releases[1].version"), &data)?,
+            Some("Value at `$.releases[1].version` doesn't exist.".to_string())
+        );
+        assert!(check_tracker_data_assertion(&assertion("releases"), &data).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_checks_value_type_and_emptiness() -> anyhow::Result<()> {
+        let data = json!({ "releases": [], "name": " ", "count": 0, "flag": false });
+
+        let typed = |path: &str, value_type| TrackerDataAssertion {
+            value_type: Some(value_type),
+            ..assertion(path)
+        };
+        assert_eq!(
+            check_tracker_data_assertion(
+                &typed("$.releases", TrackerDataAssertionValueType::Array),
+                &data
+            )?,
+            None
+        );
+        assert_eq!(
+            check_tracker_data_assertion(
+                &typed("$.releases", TrackerDataAssertionValueType::Object),
+                &data
+            )?,
+            Some(
+                "Value at `$.releases` is expected to be an object, but it's an array.".to_string()
+            )
+        );
+        assert_eq!(
+            check_tracker_data_assertion(
+                &typed("$.count", TrackerDataAssertionValueType::String),
+                &data
+            )?,
+            Some("Value at `$.count` is expected to be a string, but it's a number.".to_string())
+        );
+
+        let not_empty = |path: &str| TrackerDataAssertion {
+            not_empty: true,
+            ..assertion(path)
+        };
+        assert_eq!(
+            check_tracker_data_assertion(&not_empty("$.releases"), &data)?,
+            Some("Value at `$.releases` is empty.".to_string())
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&not_empty("$.name"), &data)?,
+            Some("Value at `$.name` is empty.".to_string())
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&not_empty("$.count"), &data)?,
+            None
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&not_empty("$.flag"), &data)?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_checks_value_range() -> anyhow::Result<()> {
+        let data = json!({ "price": 10.5, "name": "product" });
+
+        let ranged = |path: &str, min, max| TrackerDataAssertion {
+            min,
+            max,
+            ..assertion(path)
+        };
+        assert_eq!(
+            check_tracker_data_assertion(&ranged("$.price", Some(10.5), Some(11.0)), &data)?,
+            None
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&ranged("$.price", None, Some(10.5)), &data)?,
+            None
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&ranged("$.price", Some(11.0), None), &data)?,
+            Some("Value at `$.price` (10.5) is less than 11.".to_string())
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&ranged("$.price", None, Some(10.0)), &data)?,
+            Some("Value at `$.price` (10.5) is greater than 10.".to_string())
+        );
+        assert_eq!(
+            check_tracker_data_assertion(&ranged("$.name", Some(1.0), None), &data)?,
+            Some("Value at `$.name` is expected to be a number, but it's a string.".to_string())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use crate::trackers::tracker_data_revisions_diff::tracker_data_diff;
+use bytes::Bytes;
+use retrack_types::trackers::{TrackerDataRevision, TrackerRevisionsExportFormat};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::vec;
+use time::format_description::well_known::Rfc3339;
+use uuid::Uuid;
+
+/// Export of the tracker data revisions in the specified format. Export is produced chunk by chunk,
+/// a single revision per chunk, so that it can be streamed to the client.
+pub struct TrackerDataExport {
+    format: TrackerRevisionsExportFormat,
+    calculate_diff: bool,
+    revisions: vec::IntoIter<TrackerDataRevision>,
+    previous_data: Option<JsonValue>,
+    is_started: bool,
+    is_finished: bool,
+}
+
+/// Exported tracker data revision.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TrackerDataExportRow<'r> {
+    id: Uuid,
+    created_at: String,
+    data: &'r JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<String>,
+}
+
+impl TrackerDataExport {
+    /// Creates a new export of the specified revisions, ordered from the oldest to the newest.
+    pub fn new(
+        revisions: Vec<TrackerDataRevision>,
+        format: TrackerRevisionsExportFormat,
+        calculate_diff: bool,
+    ) -> Self {
+        Self {
+            format,
+            calculate_diff,
+            revisions: revisions.into_iter(),
+            previous_data: None,
+            is_started: false,
+            is_finished: false,
+        }
+    }
+
+    /// Returns the media type of the export.
+    pub fn content_type(&self) -> &'static str {
+        match self.format {
+            TrackerRevisionsExportFormat::Json => "application/json",
+            TrackerRevisionsExportFormat::Csv => "text/csv",
+            TrackerRevisionsExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    /// Returns the name of the file the export is saved to by default.
+    pub fn file_name(&self) -> &'static str {
+        match self.format {
+            TrackerRevisionsExportFormat::Json => "revisions.json",
+            TrackerRevisionsExportFormat::Csv => "revisions.csv",
+            TrackerRevisionsExportFormat::Ndjson => "revisions.ndjson",
+        }
+    }
+
+    /// Exports the revision, prefixed with the header of the export if it's the first revision.
+    /// The first revision doesn't have the diff, since there is nothing to compare it with.
+    fn export_revision(&mut self, revision: TrackerDataRevision) -> anyhow::Result<Bytes> {
+        let diff = match self.previous_data {
+            Some(ref previous_data) if self.calculate_diff => Some(tracker_data_diff(
+                Some(previous_data),
+                revision.data.value(),
+            )?),
+            _ => None,
+        };
+        let row = TrackerDataExportRow {
+            id: revision.id,
+            created_at: revision.created_at.format(&Rfc3339)?,
+            data: revision.data.value(),
+            diff,
+        };
+
+        let chunk = match self.format {
+            TrackerRevisionsExportFormat::Json => {
+                let mut chunk = if self.is_started {
+                    b",".to_vec()
+                } else {
+                    b"[".to_vec()
+                };
+                serde_json::to_writer(&mut chunk, &row)?;
+                chunk
+            }
+            TrackerRevisionsExportFormat::Ndjson => {
+                let mut chunk = serde_json::to_vec(&row)?;
+                chunk.push(b'\n');
+                chunk
+            }
+            TrackerRevisionsExportFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                if !self.is_started {
+                    writer.write_record(self.csv_header())?;
+                }
+
+                let mut record = vec![
+                    row.id.to_string(),
+                    row.created_at,
+                    match row.data {
+                        JsonValue::Null => String::new(),
+                        JsonValue::String(value) => value.clone(),
+                        value => value.to_string(),
+                    },
+                ];
+                if self.calculate_diff {
+                    record.push(row.diff.unwrap_or_default());
+                }
+                writer.write_record(record)?;
+                writer.into_inner()?
+            }
+        };
+
+        self.previous_data = Some(revision.data.value().clone());
+        self.is_started = true;
+
+        Ok(Bytes::from(chunk))
+    }
+
+    /// Returns the header row of the CSV export.
+    fn csv_header(&self) -> &'static [&'static str] {
+        if self.calculate_diff {
+            &["id", "createdAt", "data", "diff"]
+        } else {
+            &["id", "createdAt", "data"]
+        }
+    }
+
+    /// Returns the trailing chunk of the export, if the export format needs one.
+    fn export_end(&self) -> anyhow::Result<Option<Bytes>> {
+        Ok(match self.format {
+            TrackerRevisionsExportFormat::Json if self.is_started => Some(Bytes::from_static(b"]")),
+            TrackerRevisionsExportFormat::Json => Some(Bytes::from_static(b"[]")),
+            TrackerRevisionsExportFormat::Csv if !self.is_started => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                writer.write_record(self.csv_header())?;
+                Some(Bytes::from(writer.into_inner()?))
+            }
+            TrackerRevisionsExportFormat::Csv | TrackerRevisionsExportFormat::Ndjson => None,
+        })
+    }
+}
+
+impl Iterator for TrackerDataExport {
+    type Item = anyhow::Result<Bytes>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+
+        match self.revisions.next() {
+            Some(revision) => Some(self.export_revision(revision)),
+            None => {
+                self.is_finished = true;
+                self.export_end().transpose()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerDataExport;
+    use retrack_types::trackers::{
+        TrackerDataRevision, TrackerDataValue, TrackerRevisionsExportFormat,
+    };
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    fn export(
+        format: TrackerRevisionsExportFormat,
+        calculate_diff: bool,
+        with_revisions: bool,
+    ) -> anyhow::Result<String> {
+        let revisions = if with_revisions {
+            vec![
+                TrackerDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000001"),
+                    tracker_id: uuid!("00000000-0000-0000-0000-000000000010"),
+                    data: TrackerDataValue::new(json!("some, data")),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    provenance: None,
+                    sequence: Some(1),
+                },
+                TrackerDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000002"),
+                    tracker_id: uuid!("00000000-0000-0000-0000-000000000010"),
+                    data: TrackerDataValue::new(json!({ "version": "1.0.0" })),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                    provenance: None,
+                    sequence: Some(2),
+                },
+            ]
+        } else {
+            vec![]
+        };
+
+        let mut content = vec![];
+        for chunk in TrackerDataExport::new(revisions, format, calculate_diff) {
+            content.extend_from_slice(&chunk?);
+        }
+
+        Ok(String::from_utf8(content)?)
+    }
+
+    #[test]
+    fn exports_json() -> anyhow::Result<()> {
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Json, false, true)?,
+            "[{\"id\":\"00000000-0000-0000-0000-000000000001\",\"createdAt\":\"2000-01-01T10:00:00Z\",\"data\":\"some, data\"},\
+             {\"id\":\"00000000-0000-0000-0000-000000000002\",\"createdAt\":\"2000-01-01T10:01:40Z\",\"data\":{\"version\":\"1.0.0\"}}]"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&export(
+                TrackerRevisionsExportFormat::Json,
+                true,
+                true
+            )?)?[1]["diff"],
+            json!("@@ -1 +1,3 @@\n-some, data\n+{\n+  \"version\": \"1.0.0\"\n+}\n")
+        );
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Json, true, false)?,
+            "[]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn exports_ndjson() -> anyhow::Result<()> {
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Ndjson, true, true)?,
+            "{\"id\":\"00000000-0000-0000-0000-000000000001\",\"createdAt\":\"2000-01-01T10:00:00Z\",\"data\":\"some, data\"}\n\
+             {\"id\":\"00000000-0000-0000-0000-000000000002\",\"createdAt\":\"2000-01-01T10:01:40Z\",\"data\":{\"version\":\"1.0.0\"},\"diff\":\"@@ -1 +1,3 @@\\n-some, data\\n+{\\n+  \\\"version\\\": \\\"1.0.0\\\"\\n+}\\n\"}\n"
+        );
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Ndjson, true, false)?,
+            ""
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn exports_csv() -> anyhow::Result<()> {
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Csv, false, true)?,
+            "id,createdAt,data\n\
+             00000000-0000-0000-0000-000000000001,2000-01-01T10:00:00Z,\"some, data\"\n\
+             00000000-0000-0000-0000-000000000002,2000-01-01T10:01:40Z,\"{\"\"version\"\":\"\"1.0.0\"\"}\"\n"
+        );
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Csv, true, true)?,
+            "id,createdAt,data,diff\n\
+             00000000-0000-0000-0000-000000000001,2000-01-01T10:00:00Z,\"some, data\",\n\
+             00000000-0000-0000-0000-000000000002,2000-01-01T10:01:40Z,\"{\"\"version\"\":\"\"1.0.0\"\"}\",\"@@ -1 +1,3 @@\n-some, data\n+{\n+  \"\"version\"\": \"\"1.0.0\"\"\n+}\n\"\n"
+        );
+        assert_eq!(
+            export(TrackerRevisionsExportFormat::Csv, true, false)?,
+            "id,createdAt,data,diff\n"
+        );
+
+        Ok(())
+    }
+}
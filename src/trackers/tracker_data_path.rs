@@ -0,0 +1,218 @@
+use anyhow::{anyhow, bail, Context};
+use serde_json::Value as JsonValue;
+
+/// Segment of the path to the value within the tracker data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackerDataPathSegment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Path to the value within the tracker data. Supported syntax is a subset of JSONPath: `$` root
+/// followed by `.key`, `['key']`, `[index]`, or `[*]` (`.*`) segments, e.g.
+/// `$.releases[0]['version']` or `$.releases[*].version`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerDataPath {
+    segments: Vec<TrackerDataPathSegment>,
+}
+
+impl TrackerDataPath {
+    /// Parses the path to the value within the tracker data.
+    pub fn parse(path: &str) -> anyhow::Result<Self> {
+        let Some(mut path) = path.strip_prefix('$') else {
+            bail!("Path must start with `$`.");
+        };
+
+        let mut segments = vec![];
+        while !path.is_empty() {
+            if let Some(rest) = path.strip_prefix('.') {
+                let key_end = rest.find(['.', '[']).unwrap_or(rest.len());
+                segments.push(match &rest[..key_end] {
+                    "" => bail!("Path cannot have empty keys."),
+                    "*" => TrackerDataPathSegment::Wildcard,
+                    key => TrackerDataPathSegment::Key(key.to_string()),
+                });
+                path = &rest[key_end..];
+            } else if let Some(rest) = path.strip_prefix('[') {
+                let segment_end = rest
+                    .find(']')
+                    .ok_or_else(|| anyhow!("Path has unclosed `[`."))?;
+                let segment = &rest[..segment_end];
+                let quoted_key = segment
+                    .strip_prefix('\'')
+                    .and_then(|key| key.strip_suffix('\''))
+                    .or_else(|| {
+                        segment
+                            .strip_prefix('"')
+                            .and_then(|key| key.strip_suffix('"'))
+                    });
+                segments.push(if let Some(key) = quoted_key {
+                    TrackerDataPathSegment::Key(key.to_string())
+                } else if segment == "*" {
+                    TrackerDataPathSegment::Wildcard
+                } else {
+                    TrackerDataPathSegment::Index(
+                        segment
+                            .parse()
+                            .with_context(|| format!("Path has invalid index `{segment}`."))?,
+                    )
+                });
+                path = &rest[segment_end + 1..];
+            } else {
+                bail!("Path has unexpected segment `{path}`.");
+            }
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Selects the value at the path within the specified data, if it exists. Paths with wildcards
+    /// don't select any value, use [`TrackerDataPath::select_all`] instead.
+    pub fn select<'a>(&self, data: &'a JsonValue) -> Option<&'a JsonValue> {
+        self.segments
+            .iter()
+            .try_fold(data, |value, segment| match segment {
+                TrackerDataPathSegment::Key(key) => value.get(key),
+                TrackerDataPathSegment::Index(index) => value.get(index),
+                TrackerDataPathSegment::Wildcard => None,
+            })
+    }
+
+    /// Selects all values at the path within the specified data. Wildcard segments select all
+    /// items of the arrays and all values of the objects.
+    pub fn select_all<'a>(&self, data: &'a JsonValue) -> Vec<&'a JsonValue> {
+        self.segments
+            .iter()
+            .fold(vec![data], |values, segment| match segment {
+                TrackerDataPathSegment::Key(key) => values
+                    .into_iter()
+                    .filter_map(|value| value.get(key))
+                    .collect(),
+                TrackerDataPathSegment::Index(index) => values
+                    .into_iter()
+                    .filter_map(|value| value.get(index))
+                    .collect(),
+                TrackerDataPathSegment::Wildcard => values
+                    .into_iter()
+                    .flat_map(|value| match value {
+                        JsonValue::Array(items) => items.iter().collect(),
+                        JsonValue::Object(map) => map.values().collect(),
+                        _ => vec![],
+                    })
+                    .collect(),
+            })
+    }
+
+    /// Indicates whether the path has wildcard segments and can select multiple values.
+    pub fn has_wildcards(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, TrackerDataPathSegment::Wildcard))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrackerDataPath, TrackerDataPathSegment};
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn can_parse_path() -> anyhow::Result<()> {
+        assert_eq!(
+            TrackerDataPath::parse("$")?,
+            TrackerDataPath { segments: vec![] }
+        );
+        assert_eq!(
+            TrackerDataPath::parse("$.releases[0]['latest version'].number[\"a.b\"]")?,
+            TrackerDataPath {
+                segments: vec![
+                    TrackerDataPathSegment::Key("releases".to_string()),
+                    TrackerDataPathSegment::Index(0),
+                    TrackerDataPathSegment::Key("latest version".to_string()),
+                    TrackerDataPathSegment::Key("number".to_string()),
+                    TrackerDataPathSegment::Key("a.b".to_string()),
+                ]
+            }
+        );
+        assert_eq!(
+            TrackerDataPath::parse("$.data.items[*].price.*")?,
+            TrackerDataPath {
+                segments: vec![
+                    TrackerDataPathSegment::Key("data".to_string()),
+                    TrackerDataPathSegment::Key("items".to_string()),
+                    TrackerDataPathSegment::Wildcard,
+                    TrackerDataPathSegment::Key("price".to_string()),
+                    TrackerDataPathSegment::Wildcard,
+                ]
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_path() {
+        assert_debug_snapshot!(TrackerDataPath::parse("price").unwrap_err().to_string(), @r###""Path must start with `$`.""###);
+        assert_debug_snapshot!(TrackerDataPath::parse("$..price").unwrap_err().to_string(), @r###""Path cannot have empty keys.""###);
+        assert_debug_snapshot!(TrackerDataPath::parse("$.prices[0").unwrap_err().to_string(), @r###""Path has unclosed `[`.""###);
+        assert_debug_snapshot!(TrackerDataPath::parse("$.prices[first]").unwrap_err().to_string(), @r###""Path has invalid index `first`.""###);
+        assert_debug_snapshot!(TrackerDataPath::parse("$price").unwrap_err().to_string(), @r###""Path has unexpected segment `price`.""###);
+    }
+
+    #[test]
+    fn can_select_value() -> anyhow::Result<()> {
+        let data = json!({ "releases": [{ "version": "1.0.0" }, { "version": "2.0.0" }] });
+        assert_eq!(TrackerDataPath::parse("$")?.select(&data), Some(&data));
+        assert_eq!(
+            TrackerDataPath::parse("$.releases[1].version")?.select(&data),
+            Some(&json!("2.0.0"))
+        );
+        assert_eq!(
+            TrackerDataPath::parse("$.releases[2].version")?.select(&data),
+            None
+        );
+        assert_eq!(TrackerDataPath::parse("$.version")?.select(&data), None);
+        assert_eq!(
+            TrackerDataPath::parse("$.releases[*].version")?.select(&data),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_select_all_values() -> anyhow::Result<()> {
+        let data = json!({
+            "data": {
+                "items": [
+                    { "price": 10, "tags": { "a": "new", "b": "sale" } },
+                    { "name": "no price" },
+                    { "price": 20.5, "tags": [] }
+                ]
+            }
+        });
+        assert_eq!(TrackerDataPath::parse("$")?.select_all(&data), vec![&data]);
+        assert_eq!(
+            TrackerDataPath::parse("$.data.items[*].price")?.select_all(&data),
+            vec![&json!(10), &json!(20.5)]
+        );
+        assert_eq!(
+            TrackerDataPath::parse("$.data.items[*].tags.*")?.select_all(&data),
+            vec![&json!("new"), &json!("sale")]
+        );
+        assert_eq!(
+            TrackerDataPath::parse("$.data.items[0].price")?.select_all(&data),
+            vec![&json!(10)]
+        );
+        assert!(TrackerDataPath::parse("$.data.items[3].price")?
+            .select_all(&data)
+            .is_empty());
+
+        assert!(!TrackerDataPath::parse("$.data.items[0].price")?.has_wildcards());
+        assert!(TrackerDataPath::parse("$.data.items[*].price")?.has_wildcards());
+
+        Ok(())
+    }
+}
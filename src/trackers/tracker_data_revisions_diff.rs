@@ -1,5 +1,5 @@
 use handlebars::JsonRender;
-use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+use retrack_types::trackers::{TrackerDataDiffFormat, TrackerDataRevision, TrackerDataValue};
 use serde_json::{json, Value as JSONValue};
 use similar::TextDiff;
 
@@ -15,9 +15,127 @@ fn tracker_data_revision_pretty_print(data: &str) -> anyhow::Result<String> {
     )
 }
 
-/// Takes multiple web page content revisions and calculates the diff.
+/// Calculates the unified diff between two tracker data values. If there is no previous value,
+/// the whole current value is treated as added.
+pub fn tracker_data_diff(
+    previous: Option<&JSONValue>,
+    current: &JSONValue,
+) -> anyhow::Result<String> {
+    let current_value = tracker_data_revision_pretty_print(&current.to_string())?;
+    let previous_value = if let Some(previous) = previous {
+        tracker_data_revision_pretty_print(&previous.to_string())?
+    } else {
+        String::new()
+    };
+
+    Ok(TextDiff::from_lines(&previous_value, &current_value)
+        .unified_diff()
+        .context_radius(10000)
+        .missing_newline_hint(false)
+        .to_string())
+}
+
+/// Calculates the JSON Patch (RFC 6902) that transforms the previous tracker data value into the
+/// current one. If there is no previous value, the whole current value is treated as added.
+pub fn tracker_data_patch(previous: Option<&JSONValue>, current: &JSONValue) -> JSONValue {
+    let mut operations = vec![];
+    match previous {
+        Some(previous) => append_patch_operations(previous, current, "", &mut operations),
+        None => operations.push(json!({ "op": "add", "path": "", "value": current })),
+    }
+
+    JSONValue::Array(operations)
+}
+
+/// Calculates the diff between two tracker data values in the specified format: either the
+/// unified diff as a JSON string, or the JSON Patch as a JSON array.
+pub fn tracker_data_diff_value(
+    previous: Option<&JSONValue>,
+    current: &JSONValue,
+    format: TrackerDataDiffFormat,
+) -> anyhow::Result<JSONValue> {
+    Ok(match format {
+        TrackerDataDiffFormat::Text => json!(tracker_data_diff(previous, current)?),
+        TrackerDataDiffFormat::JsonPatch => tracker_data_patch(previous, current),
+    })
+}
+
+/// Recursively compares two JSON values and appends the operations needed to transform the
+/// previous value into the current one at the specified JSON Pointer. Object properties are
+/// compared by name, and array items - by index. Array items are removed starting from the end,
+/// so that the indexes of the remaining items don't shift while the patch is applied.
+fn append_patch_operations(
+    previous: &JSONValue,
+    current: &JSONValue,
+    path: &str,
+    operations: &mut Vec<JSONValue>,
+) {
+    match (previous, current) {
+        (JSONValue::Object(previous), JSONValue::Object(current)) => {
+            for (key, previous_value) in previous {
+                let key_path = format!("{path}/{}", escape_json_pointer_token(key));
+                match current.get(key) {
+                    Some(current_value) => append_patch_operations(
+                        previous_value,
+                        current_value,
+                        &key_path,
+                        operations,
+                    ),
+                    None => operations.push(json!({ "op": "remove", "path": key_path })),
+                }
+            }
+
+            for (key, current_value) in current {
+                if !previous.contains_key(key) {
+                    operations.push(json!({
+                        "op": "add",
+                        "path": format!("{path}/{}", escape_json_pointer_token(key)),
+                        "value": current_value
+                    }));
+                }
+            }
+        }
+        (JSONValue::Array(previous), JSONValue::Array(current)) => {
+            let common_len = previous.len().min(current.len());
+            for (index, (previous_value, current_value)) in
+                previous.iter().zip(current.iter()).enumerate()
+            {
+                append_patch_operations(
+                    previous_value,
+                    current_value,
+                    &format!("{path}/{index}"),
+                    operations,
+                );
+            }
+
+            for index in (common_len..previous.len()).rev() {
+                operations.push(json!({ "op": "remove", "path": format!("{path}/{index}") }));
+            }
+
+            for (index, current_value) in current.iter().enumerate().skip(common_len) {
+                operations.push(json!({
+                    "op": "add",
+                    "path": format!("{path}/{index}"),
+                    "value": current_value
+                }));
+            }
+        }
+        (previous, current) if previous != current => {
+            operations.push(json!({ "op": "replace", "path": path, "value": current }));
+        }
+        _ => {}
+    }
+}
+
+/// Escapes the JSON Pointer (RFC 6901) reference token: `~` becomes `~0` and `/` becomes `~1`.
+fn escape_json_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Takes multiple web page content revisions and calculates the diff in the specified format.
 pub fn tracker_data_revisions_diff(
     revisions: Vec<TrackerDataRevision>,
+    format: TrackerDataDiffFormat,
 ) -> anyhow::Result<Vec<TrackerDataRevision>> {
     if revisions.len() < 2 {
         return Ok(revisions);
@@ -27,20 +145,12 @@ pub fn tracker_data_revisions_diff(
     let mut peekable_revisions = revisions.into_iter().rev().peekable();
     while let Some(current_revision) = peekable_revisions.next() {
         if let Some(previous_revision) = peekable_revisions.peek() {
-            let current_value =
-                tracker_data_revision_pretty_print(&current_revision.data.value().to_string())?;
-            let previous_value =
-                tracker_data_revision_pretty_print(&previous_revision.data.value().to_string())?;
-
             revisions_diff.push(TrackerDataRevision {
-                data: TrackerDataValue::new(json!(TextDiff::from_lines(
-                    &previous_value,
-                    &current_value
-                )
-                .unified_diff()
-                .context_radius(10000)
-                .missing_newline_hint(false)
-                .to_string())),
+                data: TrackerDataValue::new(tracker_data_diff_value(
+                    Some(previous_revision.data.value()),
+                    current_revision.data.value(),
+                    format,
+                )?),
                 ..current_revision
             });
         } else {
@@ -53,9 +163,11 @@ pub fn tracker_data_revisions_diff(
 
 #[cfg(test)]
 mod tests {
-    use crate::trackers::tracker_data_revisions_diff::tracker_data_revisions_diff;
+    use crate::trackers::tracker_data_revisions_diff::{
+        tracker_data_diff_value, tracker_data_patch, tracker_data_revisions_diff,
+    };
     use insta::assert_debug_snapshot;
-    use retrack_types::trackers::{TrackerDataRevision, TrackerDataValue};
+    use retrack_types::trackers::{TrackerDataDiffFormat, TrackerDataRevision, TrackerDataValue};
     use serde_json::json;
     use time::OffsetDateTime;
     use uuid::uuid;
@@ -68,16 +180,20 @@ mod tests {
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: TrackerDataValue::new(json!("\"Hello World\"")),
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: None,
             },
             TrackerDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: TrackerDataValue::new(json!("\"Hello New World\"")),
                 created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                provenance: None,
+                sequence: None,
             },
         ];
 
-        let diff = tracker_data_revisions_diff(revisions)?;
+        let diff = tracker_data_revisions_diff(revisions, TrackerDataDiffFormat::Text)?;
         assert_debug_snapshot!(diff, @r###"
         [
             TrackerDataRevision {
@@ -88,6 +204,8 @@ mod tests {
                     mods: None,
                 },
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                provenance: None,
+                sequence: None,
             },
             TrackerDataRevision {
                 id: 00000000-0000-0000-0000-000000000002,
@@ -97,6 +215,8 @@ mod tests {
                     mods: None,
                 },
                 created_at: 2000-01-01 10:00:01.0 +00:00:00,
+                provenance: None,
+                sequence: None,
             },
         ]
         "###);
@@ -111,9 +231,11 @@ mod tests {
             tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
             data: TrackerDataValue::new(json!({ "property": "one", "secondProperty": "two" })),
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            provenance: None,
+            sequence: None,
         }];
 
-        let diff = tracker_data_revisions_diff(revisions)?;
+        let diff = tracker_data_revisions_diff(revisions, TrackerDataDiffFormat::Text)?;
         assert_debug_snapshot!(diff, @r###"
         [
             TrackerDataRevision {
@@ -127,6 +249,8 @@ mod tests {
                     mods: None,
                 },
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                provenance: None,
+                sequence: None,
             },
         ]
         "###);
@@ -137,12 +261,16 @@ mod tests {
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: TrackerDataValue::new(json!({ "property": "one", "secondProperty": "two" })),
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: None,
             },
             TrackerDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: TrackerDataValue::new(json!({ "property": "one" })),
                 created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                provenance: None,
+                sequence: None,
             },
             TrackerDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000003"),
@@ -151,10 +279,12 @@ mod tests {
                     json!({ "property": "one", "secondProperty": "two", "thirdProperty": "three" }),
                 ),
                 created_at: OffsetDateTime::from_unix_timestamp(946720802)?,
+                provenance: None,
+                sequence: None,
             },
         ];
 
-        let diff = tracker_data_revisions_diff(revisions)?;
+        let diff = tracker_data_revisions_diff(revisions, TrackerDataDiffFormat::Text)?;
         assert_debug_snapshot!(diff, @r###"
         [
             TrackerDataRevision {
@@ -168,6 +298,8 @@ mod tests {
                     mods: None,
                 },
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                provenance: None,
+                sequence: None,
             },
             TrackerDataRevision {
                 id: 00000000-0000-0000-0000-000000000002,
@@ -177,6 +309,8 @@ mod tests {
                     mods: None,
                 },
                 created_at: 2000-01-01 10:00:01.0 +00:00:00,
+                provenance: None,
+                sequence: None,
             },
             TrackerDataRevision {
                 id: 00000000-0000-0000-0000-000000000003,
@@ -186,10 +320,132 @@ mod tests {
                     mods: None,
                 },
                 created_at: 2000-01-01 10:00:02.0 +00:00:00,
+                provenance: None,
+                sequence: None,
             },
         ]
         "###);
 
         Ok(())
     }
+
+    #[test]
+    fn correctly_calculates_data_patch() -> anyhow::Result<()> {
+        assert_eq!(
+            tracker_data_patch(None, &json!({ "version": "1.0.0" })),
+            json!([{ "op": "add", "path": "", "value": { "version": "1.0.0" } }])
+        );
+        assert_eq!(
+            tracker_data_patch(
+                Some(&json!({ "version": "1.0.0" })),
+                &json!({ "version": "1.0.0" })
+            ),
+            json!([])
+        );
+        assert_eq!(
+            tracker_data_patch(Some(&json!("one")), &json!(["one"])),
+            json!([{ "op": "replace", "path": "", "value": ["one"] }])
+        );
+        assert_eq!(
+            tracker_data_patch(
+                Some(&json!({
+                    "a/b": 1,
+                    "m~n": 2,
+                    "name": "retrack",
+                    "nested": { "removed": true, "version": "1.0.0" },
+                    "tags": ["one", "two", "three"]
+                })),
+                &json!({
+                    "a/b": 10,
+                    "name": "retrack",
+                    "nested": { "added": [1], "version": "1.1.0" },
+                    "new": null,
+                    "tags": ["one", "2"]
+                })
+            ),
+            json!([
+                { "op": "replace", "path": "/a~1b", "value": 10 },
+                { "op": "remove", "path": "/m~0n" },
+                { "op": "remove", "path": "/nested/removed" },
+                { "op": "replace", "path": "/nested/version", "value": "1.1.0" },
+                { "op": "add", "path": "/nested/added", "value": [1] },
+                { "op": "replace", "path": "/tags/1", "value": "2" },
+                { "op": "remove", "path": "/tags/2" },
+                { "op": "add", "path": "/new", "value": null }
+            ])
+        );
+        assert_eq!(
+            tracker_data_patch(Some(&json!([1, 2, 3, 4])), &json!([0, 2])),
+            json!([
+                { "op": "replace", "path": "/0", "value": 0 },
+                { "op": "remove", "path": "/3" },
+                { "op": "remove", "path": "/2" }
+            ])
+        );
+        assert_eq!(
+            tracker_data_patch(Some(&json!([1])), &json!([1, 2, 3])),
+            json!([
+                { "op": "add", "path": "/1", "value": 2 },
+                { "op": "add", "path": "/2", "value": 3 }
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn correctly_calculates_data_diff_value() -> anyhow::Result<()> {
+        assert_eq!(
+            tracker_data_diff_value(
+                Some(&json!("one")),
+                &json!("two"),
+                TrackerDataDiffFormat::Text
+            )?,
+            json!("@@ -1 +1 @@\n-one\n+two\n")
+        );
+        assert_eq!(
+            tracker_data_diff_value(
+                Some(&json!("one")),
+                &json!("two"),
+                TrackerDataDiffFormat::JsonPatch
+            )?,
+            json!([{ "op": "replace", "path": "", "value": "two" }])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn correctly_calculates_data_revisions_patch() -> anyhow::Result<()> {
+        let revisions = vec![
+            TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: TrackerDataValue::new(json!({ "property": "one", "secondProperty": "two" })),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                provenance: None,
+                sequence: None,
+            },
+            TrackerDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: TrackerDataValue::new(json!({ "property": "one" })),
+                created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                provenance: None,
+                sequence: None,
+            },
+        ];
+
+        let diff = tracker_data_revisions_diff(revisions, TrackerDataDiffFormat::JsonPatch)?;
+        assert_eq!(
+            diff[0].data.value(),
+            &json!({ "property": "one", "secondProperty": "two" })
+        );
+        assert_eq!(
+            diff[1].data.value(),
+            &json!([{ "op": "remove", "path": "/secondProperty" }])
+        );
+
+        Ok(())
+    }
 }
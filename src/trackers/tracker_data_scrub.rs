@@ -0,0 +1,111 @@
+use regex::Regex;
+use retrack_types::trackers::TrackerDataValue;
+use ring::digest::{digest, SHA256};
+use serde_json::Value as JsonValue;
+use std::fmt::Write;
+
+/// Checks if any string, number, or object key within the JSON value matches the pattern. Values
+/// are matched one by one, so that the pattern isn't affected by the JSON escaping and formatting.
+pub fn json_value_matches(value: &JsonValue, pattern: &Regex) -> bool {
+    match value {
+        JsonValue::String(text) => pattern.is_match(text),
+        JsonValue::Number(number) => pattern.is_match(&number.to_string()),
+        JsonValue::Array(items) => items.iter().any(|item| json_value_matches(item, pattern)),
+        JsonValue::Object(map) => map
+            .iter()
+            .any(|(key, value)| pattern.is_match(key) || json_value_matches(value, pattern)),
+        JsonValue::Null | JsonValue::Bool(_) => false,
+    }
+}
+
+/// Checks if either the original tracker data value or any of its modifications (e.g.,
+/// translations or summaries) match the pattern.
+pub fn tracker_data_matches(data: &TrackerDataValue, pattern: &Regex) -> bool {
+    json_value_matches(data.original(), pattern)
+        || data
+            .mods()
+            .is_some_and(|mods| mods.iter().any(|value| json_value_matches(value, pattern)))
+}
+
+/// Returns the SHA-256 digest of the scrub pattern that can be safely kept in the audit log.
+pub fn scrub_pattern_digest(pattern: &str) -> String {
+    let hash = digest(&SHA256, pattern.as_bytes()).as_ref().iter().fold(
+        String::with_capacity(64),
+        |mut hash, byte| {
+            let _ = write!(hash, "{byte:02x}");
+            hash
+        },
+    );
+    format!("sha256:{hash}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_value_matches, scrub_pattern_digest, tracker_data_matches};
+    use regex::Regex;
+    use retrack_types::trackers::TrackerDataValue;
+    use serde_json::json;
+
+    #[test]
+    fn properly_matches_json_values() -> anyhow::Result<()> {
+        let pattern = Regex::new(r"(?i)john\.doe@retrack\.dev|\+1555")?;
+
+        assert!(json_value_matches(
+            &json!("Contact: John.Doe@retrack.dev"),
+            &pattern
+        ));
+        assert!(json_value_matches(
+            &json!({ "phone": "+15550100" }),
+            &pattern
+        ));
+        assert!(json_value_matches(
+            &json!({ "contacts": [{ "john.doe@retrack.dev": true }] }),
+            &pattern
+        ));
+        assert!(!json_value_matches(
+            &json!({ "email": "jane@retrack.dev" }),
+            &pattern
+        ));
+        assert!(!json_value_matches(&json!([null, true, 1555]), &pattern));
+
+        // Numbers are matched by their text representation.
+        assert!(json_value_matches(
+            &json!([null, true, 1555]),
+            &Regex::new("^1555$")?
+        ));
+
+        // JSON escaping doesn't affect matching.
+        assert!(json_value_matches(
+            &json!("Name: \"John\"\nLast name: Doe"),
+            &Regex::new(r#""John"\nLast"#)?
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_matches_tracker_data() -> anyhow::Result<()> {
+        let pattern = Regex::new("John Doe")?;
+
+        let mut data = TrackerDataValue::new(json!("Hello, world!"));
+        assert!(!tracker_data_matches(&data, &pattern));
+
+        data.add_mod(json!("Summary: John Doe says hello."));
+        assert!(tracker_data_matches(&data, &pattern));
+
+        assert!(tracker_data_matches(
+            &TrackerDataValue::new(json!({ "author": "John Doe" })),
+            &pattern
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_calculates_pattern_digest() {
+        assert_eq!(
+            scrub_pattern_digest("John Doe"),
+            "sha256:6cea57c2fb6cbc2a40411135005760f241fffc3e5e67ab99882726431037f908"
+        );
+    }
+}
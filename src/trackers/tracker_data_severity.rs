@@ -0,0 +1,64 @@
+use crate::trackers::tracker_data_path::TrackerDataPath;
+use retrack_types::trackers::{TrackerSeverity, TrackerSeverityRouting};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+/// Returns the severity of the tracker data change defined by the value at the routing path, or
+/// the default severity if the value doesn't exist or isn't a valid severity.
+pub fn get_tracker_data_severity(
+    routing: &TrackerSeverityRouting,
+    data: &JsonValue,
+) -> anyhow::Result<TrackerSeverity> {
+    Ok(TrackerDataPath::parse(&routing.path)?
+        .select(data)
+        .and_then(|value| TrackerSeverity::deserialize(value).ok())
+        .unwrap_or(routing.default_severity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_tracker_data_severity;
+    use retrack_types::trackers::{TrackerSeverity, TrackerSeverityRouting};
+    use serde_json::json;
+
+    #[test]
+    fn properly_gets_severity() -> anyhow::Result<()> {
+        let routing = TrackerSeverityRouting {
+            path: "$.change.severity".to_string(),
+            default_severity: TrackerSeverity::Minor,
+            routes: vec![],
+        };
+
+        assert_eq!(
+            get_tracker_data_severity(&routing, &json!({ "change": { "severity": "critical" } }))?,
+            TrackerSeverity::Critical
+        );
+        assert_eq!(
+            get_tracker_data_severity(&routing, &json!({ "change": { "severity": "info" } }))?,
+            TrackerSeverity::Info
+        );
+        assert_eq!(
+            get_tracker_data_severity(&routing, &json!({ "change": { "severity": "unknown" } }))?,
+            TrackerSeverity::Minor
+        );
+        assert_eq!(
+            get_tracker_data_severity(&routing, &json!({ "change": { "severity": 3 } }))?,
+            TrackerSeverity::Minor
+        );
+        assert_eq!(
+            get_tracker_data_severity(&routing, &json!({ "version": "1.0.0" }))?,
+            TrackerSeverity::Minor
+        );
+
+        assert!(get_tracker_data_severity(
+            &TrackerSeverityRouting {
+                path: "severity".to_string(),
+                ..routing
+            },
+            &json!({ "severity": "critical" })
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::{collections::BTreeMap, fmt};
+
+/// Coarse shape of a single JSON value: its type and, for strings and collections, whether it's
+/// empty.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TrackerDataValueShape {
+    Null,
+    Bool,
+    Number,
+    String { empty: bool },
+    Array { empty: bool },
+    Object { empty: bool },
+}
+
+impl TrackerDataValueShape {
+    fn is_empty(&self) -> bool {
+        matches!(
+            self,
+            Self::String { empty: true }
+                | Self::Array { empty: true }
+                | Self::Object { empty: true }
+        )
+    }
+
+    fn is_same_type(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl From<&JsonValue> for TrackerDataValueShape {
+    fn from(value: &JsonValue) -> Self {
+        match value {
+            JsonValue::Null => Self::Null,
+            JsonValue::Bool(_) => Self::Bool,
+            JsonValue::Number(_) => Self::Number,
+            JsonValue::String(value) => Self::String {
+                empty: value.is_empty(),
+            },
+            JsonValue::Array(value) => Self::Array {
+                empty: value.is_empty(),
+            },
+            JsonValue::Object(value) => Self::Object {
+                empty: value.is_empty(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for TrackerDataValueShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Null => "null",
+            Self::Bool => "boolean",
+            Self::Number => "number",
+            Self::String { .. } => "string",
+            Self::Array { .. } => "array",
+            Self::Object { .. } => "object",
+        };
+
+        if self.is_empty() {
+            write!(f, "empty {name}")
+        } else {
+            f.write_str(name)
+        }
+    }
+}
+
+/// Shape of the tracker data: the shape of the data value itself and shapes of its top-level keys,
+/// if the value is an object. Used to detect extractors that silently return garbage after the
+/// target layout changes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrackerDataShape {
+    pub value: TrackerDataValueShape,
+    pub keys: BTreeMap<String, TrackerDataValueShape>,
+}
+
+impl TrackerDataShape {
+    /// Describes how the specified shape deviates from the current one, if it does drastically:
+    /// value type changed, non-empty value became empty, top-level keys went missing or changed
+    /// their type. New keys and changes from or to `null` aren't considered a deviation.
+    pub fn deviation(&self, shape: &TrackerDataShape) -> Option<String> {
+        if !self.value.is_same_type(&shape.value) {
+            return Some(format!(
+                "Data type changed from {} to {}.",
+                self.value, shape.value
+            ));
+        }
+
+        if !self.value.is_empty() && shape.value.is_empty() {
+            return Some(format!("Data became an {}.", shape.value));
+        }
+
+        let mut deviations = vec![];
+        let missing_keys = self
+            .keys
+            .keys()
+            .filter(|key| !shape.keys.contains_key(*key))
+            .map(|key| format!("'{key}'"))
+            .collect::<Vec<_>>();
+        if !missing_keys.is_empty() {
+            deviations.push(format!(
+                "Data is missing keys: {}.",
+                missing_keys.join(", ")
+            ));
+        }
+
+        for (key, key_shape) in &self.keys {
+            let Some(new_key_shape) = shape.keys.get(key) else {
+                continue;
+            };
+
+            if *key_shape == TrackerDataValueShape::Null
+                || *new_key_shape == TrackerDataValueShape::Null
+            {
+                continue;
+            }
+
+            if !key_shape.is_same_type(new_key_shape) {
+                deviations.push(format!(
+                    "Key '{key}' changed type from {key_shape} to {new_key_shape}."
+                ));
+            } else if !key_shape.is_empty() && new_key_shape.is_empty() {
+                deviations.push(format!("Key '{key}' became an {new_key_shape}."));
+            }
+        }
+
+        if deviations.is_empty() {
+            None
+        } else {
+            Some(deviations.join(" "))
+        }
+    }
+}
+
+impl From<&JsonValue> for TrackerDataShape {
+    fn from(value: &JsonValue) -> Self {
+        Self {
+            value: TrackerDataValueShape::from(value),
+            keys: match value {
+                JsonValue::Object(object) => object
+                    .iter()
+                    .map(|(key, value)| (key.clone(), TrackerDataValueShape::from(value)))
+                    .collect(),
+                _ => BTreeMap::new(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerDataShape;
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+
+    fn deviation(previous: serde_json::Value, current: serde_json::Value) -> Option<String> {
+        TrackerDataShape::from(&previous).deviation(&TrackerDataShape::from(&current))
+    }
+
+    #[test]
+    fn properly_calculates_shape() -> anyhow::Result<()> {
+        assert_debug_snapshot!(TrackerDataShape::from(&json!({
+            "price": 10,
+            "title": "",
+            "items": [1, 2],
+            "meta": {},
+            "available": true,
+            "discount": null
+        })), @r###"
+        TrackerDataShape {
+            value: Object {
+                empty: false,
+            },
+            keys: {
+                "available": Bool,
+                "discount": Null,
+                "items": Array {
+                    empty: false,
+                },
+                "meta": Object {
+                    empty: true,
+                },
+                "price": Number,
+                "title": String {
+                    empty: true,
+                },
+            },
+        }
+        "###);
+
+        let shape = TrackerDataShape::from(&json!([1, 2, 3]));
+        assert_eq!(
+            postcard::from_bytes::<TrackerDataShape>(&postcard::to_stdvec(&shape)?)?,
+            shape
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_compatible_changes() {
+        assert_eq!(deviation(json!([1, 2]), json!([3])), None);
+        assert_eq!(deviation(json!([]), json!([1])), None);
+        assert_eq!(deviation(json!("one"), json!("two")), None);
+        assert_eq!(
+            deviation(
+                json!({ "price": 10, "items": [1], "discount": null }),
+                json!({ "price": 15, "items": [2, 3], "discount": 5, "new": true })
+            ),
+            None
+        );
+        assert_eq!(
+            deviation(json!({ "discount": 5 }), json!({ "discount": null })),
+            None
+        );
+    }
+
+    #[test]
+    fn detects_drastic_changes() {
+        assert_debug_snapshot!(deviation(json!([1, 2]), json!([])), @r###"
+        Some(
+            "Data became an empty array.",
+        )
+        "###);
+        assert_debug_snapshot!(deviation(json!("content"), json!("")), @r###"
+        Some(
+            "Data became an empty string.",
+        )
+        "###);
+        assert_debug_snapshot!(deviation(json!({ "price": 10 }), json!("Not found")), @r###"
+        Some(
+            "Data type changed from object to string.",
+        )
+        "###);
+        assert_debug_snapshot!(deviation(
+            json!({ "price": 10, "title": "Item", "items": [1], "rating": 5 }),
+            json!({ "price": "N/A", "items": [] })
+        ), @r###"
+        Some(
+            "Data is missing keys: 'rating', 'title'. Key 'items' became an empty array. Key 'price' changed type from number to string.",
+        )
+        "###);
+    }
+}
@@ -0,0 +1,65 @@
+use serde_json::Value as JsonValue;
+
+/// Returns the text representation of the tracker data used to calculate its embedding: strings
+/// are used as is, and any other values are pretty printed as JSON.
+pub fn tracker_data_text(data: &JsonValue) -> anyhow::Result<String> {
+    Ok(match data {
+        JsonValue::String(text) => text.clone(),
+        _ => serde_json::to_string_pretty(data)?,
+    })
+}
+
+/// Calculates the cosine similarity of two embedding vectors. Returns `None` if the vectors
+/// cannot be compared (different dimensions or zero length).
+pub fn cosine_similarity(left: &[f64], right: &[f64]) -> Option<f64> {
+    if left.len() != right.len() {
+        return None;
+    }
+
+    let (dot, left_norm, right_norm) = left
+        .iter()
+        .zip(right)
+        .fold((0.0, 0.0, 0.0), |(dot, left_norm, right_norm), (l, r)| {
+            (dot + l * r, left_norm + l * l, right_norm + r * r)
+        });
+    let norm = left_norm.sqrt() * right_norm.sqrt();
+    if norm == 0.0 || !norm.is_finite() {
+        return None;
+    }
+
+    Some((dot / norm).clamp(-1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cosine_similarity, tracker_data_text};
+    use serde_json::json;
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn properly_builds_text() -> anyhow::Result<()> {
+        assert_eq!(tracker_data_text(&json!("Hello world!"))?, "Hello world!");
+        assert_eq!(tracker_data_text(&json!(10))?, "10");
+        assert_eq!(
+            tracker_data_text(&json!({ "title": "Hello" }))?,
+            "{\n  \"title\": \"Hello\"\n}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_calculates_cosine_similarity() {
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[1.0, 0.0]), Some(1.0));
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]), Some(0.0));
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[-1.0, 0.0]), Some(-1.0));
+        assert_eq!(cosine_similarity(&[3.0, 4.0], &[6.0, 8.0]), Some(1.0));
+        assert!(
+            (cosine_similarity(&[1.0, 1.0], &[1.0, 0.0]).unwrap() - FRAC_1_SQRT_2).abs() < 1e-9
+        );
+
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[1.0]), None);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), None);
+        assert_eq!(cosine_similarity(&[], &[]), None);
+    }
+}
@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+
+/// Rough number of characters per token, used to estimate the number of tokens in a text
+/// without depending on the model-specific tokenizer.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Marker appended to the tracker data changes that were truncated to fit into the token limit.
+const TRUNCATED_MARKER: &str = "\n[...truncated]";
+
+/// Builds the system prompt for the LLM API to summarize tracker data changes.
+pub fn tracker_data_summary_prompt(instructions: Option<&str>, max_tokens: u32) -> String {
+    let mut prompt = format!(
+        "You summarize changes in the data tracked on a web page or API. The changes are provided \
+        as a unified diff, lines starting with `-` were removed and lines starting with `+` were \
+        added. Reply with a short plain-text explanation of what changed, mentioning both the old \
+        and new values of the changed fields, e.g. `Price dropped from €120 to €99; delivery time \
+        unchanged`. Don't describe the diff format itself. Keep the reply under {max_tokens} \
+        tokens."
+    );
+
+    if let Some(instructions) = instructions.map(str::trim).filter(|i| !i.is_empty()) {
+        prompt.push_str("\n\n");
+        prompt.push_str(instructions);
+    }
+
+    prompt
+}
+
+/// Estimates the number of tokens in the specified text.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Truncates the text so that it approximately fits into the specified number of tokens.
+pub fn truncate_to_tokens(text: &str, max_tokens: u32) -> Cow<'_, str> {
+    if estimate_tokens(text) <= max_tokens as usize {
+        return Cow::Borrowed(text);
+    }
+
+    let max_chars =
+        (max_tokens as usize * CHARS_PER_TOKEN).saturating_sub(TRUNCATED_MARKER.chars().count());
+    let truncated_len = text
+        .char_indices()
+        .nth(max_chars)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len());
+
+    Cow::Owned(format!("{}{TRUNCATED_MARKER}", &text[..truncated_len]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{estimate_tokens, tracker_data_summary_prompt, truncate_to_tokens};
+    use insta::assert_snapshot;
+    use std::borrow::Cow;
+
+    #[test]
+    fn properly_builds_prompt() {
+        assert_snapshot!(tracker_data_summary_prompt(None, 100), @"You summarize changes in the data tracked on a web page or API. The changes are provided as a unified diff, lines starting with `-` were removed and lines starting with `+` were added. Reply with a short plain-text explanation of what changed, mentioning both the old and new values of the changed fields, e.g. `Price dropped from €120 to €99; delivery time unchanged`. Don't describe the diff format itself. Keep the reply under 100 tokens.");
+        assert_eq!(
+            tracker_data_summary_prompt(Some(" "), 100),
+            tracker_data_summary_prompt(None, 100)
+        );
+        assert!(
+            tracker_data_summary_prompt(Some(" Focus on the price. "), 50)
+                .ends_with("Keep the reply under 50 tokens.\n\nFocus on the price.")
+        );
+    }
+
+    #[test]
+    fn properly_estimates_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abc"), 1);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        assert_eq!(estimate_tokens("€€€€€"), 2);
+    }
+
+    #[test]
+    fn properly_truncates_text() {
+        assert_eq!(
+            truncate_to_tokens("-1\n+2\n", 10),
+            Cow::Borrowed("-1\n+2\n")
+        );
+
+        let text = "€".repeat(100);
+        let truncated = truncate_to_tokens(&text, 10);
+        assert!(estimate_tokens(&truncated) <= 10);
+        assert_eq!(truncated, format!("{}\n[...truncated]", "€".repeat(25)));
+
+        assert_eq!(truncate_to_tokens(&text, 0), "\n[...truncated]");
+    }
+}
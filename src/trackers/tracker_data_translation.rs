@@ -0,0 +1,211 @@
+use anyhow::anyhow;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use whatlang::Lang;
+
+/// ISO 639-1 codes of the languages that can be detected in the tracker data.
+const LANGUAGES: [(Lang, &str); 69] = [
+    (Lang::Afr, "af"),
+    (Lang::Aka, "ak"),
+    (Lang::Amh, "am"),
+    (Lang::Ara, "ar"),
+    (Lang::Aze, "az"),
+    (Lang::Bel, "be"),
+    (Lang::Ben, "bn"),
+    (Lang::Bul, "bg"),
+    (Lang::Cat, "ca"),
+    (Lang::Ces, "cs"),
+    (Lang::Cmn, "zh"),
+    (Lang::Dan, "da"),
+    (Lang::Deu, "de"),
+    (Lang::Ell, "el"),
+    (Lang::Eng, "en"),
+    (Lang::Epo, "eo"),
+    (Lang::Est, "et"),
+    (Lang::Fin, "fi"),
+    (Lang::Fra, "fr"),
+    (Lang::Guj, "gu"),
+    (Lang::Heb, "he"),
+    (Lang::Hin, "hi"),
+    (Lang::Hrv, "hr"),
+    (Lang::Hun, "hu"),
+    (Lang::Hye, "hy"),
+    (Lang::Ind, "id"),
+    (Lang::Ita, "it"),
+    (Lang::Jav, "jv"),
+    (Lang::Jpn, "ja"),
+    (Lang::Kan, "kn"),
+    (Lang::Kat, "ka"),
+    (Lang::Khm, "km"),
+    (Lang::Kor, "ko"),
+    (Lang::Lat, "la"),
+    (Lang::Lav, "lv"),
+    (Lang::Lit, "lt"),
+    (Lang::Mal, "ml"),
+    (Lang::Mar, "mr"),
+    (Lang::Mkd, "mk"),
+    (Lang::Mya, "my"),
+    (Lang::Nep, "ne"),
+    (Lang::Nld, "nl"),
+    (Lang::Nob, "nb"),
+    (Lang::Ori, "or"),
+    (Lang::Pan, "pa"),
+    (Lang::Pes, "fa"),
+    (Lang::Pol, "pl"),
+    (Lang::Por, "pt"),
+    (Lang::Ron, "ro"),
+    (Lang::Rus, "ru"),
+    (Lang::Sin, "si"),
+    (Lang::Slk, "sk"),
+    (Lang::Slv, "sl"),
+    (Lang::Sna, "sn"),
+    (Lang::Spa, "es"),
+    (Lang::Srp, "sr"),
+    (Lang::Swe, "sv"),
+    (Lang::Tam, "ta"),
+    (Lang::Tel, "te"),
+    (Lang::Tgl, "tl"),
+    (Lang::Tha, "th"),
+    (Lang::Tuk, "tk"),
+    (Lang::Tur, "tr"),
+    (Lang::Ukr, "uk"),
+    (Lang::Urd, "ur"),
+    (Lang::Uzb, "uz"),
+    (Lang::Vie, "vi"),
+    (Lang::Yid, "yi"),
+    (Lang::Zul, "zu"),
+];
+
+/// Parses ISO 639-1 language code, e.g. `en`.
+pub fn parse_translation_language(code: &str) -> anyhow::Result<Lang> {
+    LANGUAGES
+        .iter()
+        .find(|(_, language_code)| language_code.eq_ignore_ascii_case(code.trim()))
+        .map(|(language, _)| *language)
+        .ok_or_else(|| anyhow!("Language `{code}` is not supported."))
+}
+
+/// Collects unique texts within the tracker data that need to be translated to the target
+/// language. Only texts with reliably detected language are translated, so that short or
+/// non-linguistic values (numbers, identifiers, URLs etc.) are left intact.
+pub fn collect_texts_to_translate(data: &JsonValue, target_language: Lang) -> Vec<&str> {
+    let mut texts = vec![];
+    collect_texts(data, target_language, &mut texts);
+    texts
+}
+
+fn collect_texts<'a>(data: &'a JsonValue, target_language: Lang, texts: &mut Vec<&'a str>) {
+    match data {
+        JsonValue::String(text) => {
+            let needs_translation = whatlang::detect(text)
+                .is_some_and(|info| info.is_reliable() && info.lang() != target_language);
+            if needs_translation && !texts.contains(&text.as_str()) {
+                texts.push(text);
+            }
+        }
+        JsonValue::Array(values) => values
+            .iter()
+            .for_each(|value| collect_texts(value, target_language, texts)),
+        JsonValue::Object(values) => values
+            .values()
+            .for_each(|value| collect_texts(value, target_language, texts)),
+        _ => {}
+    }
+}
+
+/// Returns a copy of the tracker data with the texts replaced with their translations.
+pub fn replace_translated_texts(
+    data: &JsonValue,
+    translations: &HashMap<&str, String>,
+) -> JsonValue {
+    match data {
+        JsonValue::String(text) => translations
+            .get(text.as_str())
+            .map(|translation| JsonValue::String(translation.clone()))
+            .unwrap_or_else(|| data.clone()),
+        JsonValue::Array(values) => JsonValue::Array(
+            values
+                .iter()
+                .map(|value| replace_translated_texts(value, translations))
+                .collect(),
+        ),
+        JsonValue::Object(values) => JsonValue::Object(
+            values
+                .iter()
+                .map(|(key, value)| (key.clone(), replace_translated_texts(value, translations)))
+                .collect(),
+        ),
+        _ => data.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        collect_texts_to_translate, parse_translation_language, replace_translated_texts, LANGUAGES,
+    };
+    use insta::assert_debug_snapshot;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use whatlang::Lang;
+
+    #[test]
+    fn can_parse_language() -> anyhow::Result<()> {
+        assert_eq!(parse_translation_language("en")?, Lang::Eng);
+        assert_eq!(parse_translation_language(" DE ")?, Lang::Deu);
+        assert_eq!(parse_translation_language("zh")?, Lang::Cmn);
+        assert!(Lang::all()
+            .iter()
+            .all(|lang| LANGUAGES.iter().any(|(language, _)| language == lang)));
+
+        assert_debug_snapshot!(parse_translation_language("eng").unwrap_err().to_string(), @r###""Language `eng` is not supported.""###);
+        assert_debug_snapshot!(parse_translation_language("").unwrap_err().to_string(), @r###""Language `` is not supported.""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_collects_texts_to_translate() {
+        let data = json!({
+            "title": "Neue Version mit vielen Verbesserungen und Fehlerbehebungen ist jetzt verfügbar.",
+            "description": "New version with many improvements and bug fixes is now available.",
+            "price": "9.99 EUR",
+            "count": 10,
+            "items": [
+                "Neue Version mit vielen Verbesserungen und Fehlerbehebungen ist jetzt verfügbar.",
+                "Le renard brun rapide saute par-dessus le chien paresseux."
+            ]
+        });
+        assert_eq!(
+            collect_texts_to_translate(&data, Lang::Eng),
+            vec![
+                "Neue Version mit vielen Verbesserungen und Fehlerbehebungen ist jetzt verfügbar.",
+                "Le renard brun rapide saute par-dessus le chien paresseux.",
+            ]
+        );
+        assert_eq!(
+            collect_texts_to_translate(&data, Lang::Deu),
+            vec![
+                "New version with many improvements and bug fixes is now available.",
+                "Le renard brun rapide saute par-dessus le chien paresseux.",
+            ]
+        );
+        assert!(collect_texts_to_translate(&json!(["1.0.0", 1, null]), Lang::Eng).is_empty());
+    }
+
+    #[test]
+    fn properly_replaces_translated_texts() {
+        let translations = HashMap::from([("Hallo Welt!", "Hello world!".to_string())]);
+        assert_eq!(
+            replace_translated_texts(
+                &json!({ "title": "Hallo Welt!", "items": ["Hallo Welt!", "Retrack", 1] }),
+                &translations
+            ),
+            json!({ "title": "Hello world!", "items": ["Hello world!", "Retrack", 1] })
+        );
+        assert_eq!(
+            replace_translated_texts(&json!("Hallo Welt!"), &translations),
+            json!("Hello world!")
+        );
+    }
+}
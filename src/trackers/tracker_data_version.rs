@@ -0,0 +1,199 @@
+use crate::trackers::tracker_data_path::TrackerDataPath;
+use anyhow::{bail, Context};
+use retrack_types::trackers::SemverAction;
+use semver::Version;
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+use serde_with::skip_serializing_none;
+
+/// Parses a version string leniently and normalizes it to a semantic version: surrounding
+/// whitespace and `v`/`V` prefix are dropped, missing minor and patch components default to `0`,
+/// and leading zeros are removed, e.g. `v1.02` is normalized to `1.2.0`.
+pub fn parse_tracker_data_version(version: &str) -> anyhow::Result<Version> {
+    let trimmed_version = version.trim();
+    let trimmed_version = trimmed_version
+        .strip_prefix(['v', 'V'])
+        .unwrap_or(trimmed_version);
+
+    // Split version core (`major.minor.patch`) from the pre-release and build metadata.
+    let (core, suffix) = trimmed_version.split_at(
+        trimmed_version
+            .find(['-', '+'])
+            .unwrap_or(trimmed_version.len()),
+    );
+    let components = core
+        .split('.')
+        .map(|component| component.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>();
+    let (major, minor, patch) = match components.as_deref() {
+        Some([major]) => (*major, 0, 0),
+        Some([major, minor]) => (*major, *minor, 0),
+        Some([major, minor, patch]) => (*major, *minor, *patch),
+        _ => bail!("Value `{version}` is not a valid semantic version."),
+    };
+
+    Version::parse(&format!("{major}.{minor}.{patch}{suffix}"))
+        .with_context(|| format!("Value `{version}` is not a valid semantic version."))
+}
+
+/// Details about the version extracted by the tracker semver action.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct TrackerDataVersion {
+    /// Normalized version within the tracker data.
+    value: String,
+    /// Normalized version within the previous tracker data, if it's available and valid.
+    previous: Option<String>,
+    /// Normalized version currently in use, if specified in the action.
+    current: Option<String>,
+    /// Indicates whether the version is newer than the version currently in use.
+    is_newer: Option<bool>,
+    /// Indicates whether the version is newer than the version within the previous tracker data.
+    is_newer_than_previous: Option<bool>,
+}
+
+/// Applies the semver action to the tracker data and returns the modified data value in the
+/// `{ "data": ..., "version": { ... } }` form, so that the subsequent actions can rely on the
+/// normalized version and comparisons (e.g. `version.isNewer`).
+pub fn apply_semver_action(
+    action: &SemverAction,
+    data: &JsonValue,
+    previous_data: Option<&JsonValue>,
+) -> anyhow::Result<JsonValue> {
+    let path = TrackerDataPath::parse(&action.path)?;
+    let Some(version) = path.select(data) else {
+        bail!("Tracker data doesn't have a value at `{}`.", action.path);
+    };
+    let Some(version) = version.as_str() else {
+        bail!("Value `{version}` is not a valid semantic version.");
+    };
+    let version = parse_tracker_data_version(version)?;
+
+    // Previous data might have been extracted before the action was configured, so invalid or
+    // missing previous version isn't considered an error.
+    let previous_version = previous_data
+        .and_then(|previous_data| path.select(previous_data)?.as_str())
+        .and_then(|previous_version| parse_tracker_data_version(previous_version).ok());
+    let current_version = action
+        .current_version
+        .as_deref()
+        .map(parse_tracker_data_version)
+        .transpose()?;
+
+    Ok(json!({
+        "data": data,
+        "version": TrackerDataVersion {
+            value: version.to_string(),
+            is_newer: current_version.as_ref().map(|current| version > *current),
+            is_newer_than_previous: previous_version.as_ref().map(|previous| version > *previous),
+            previous: previous_version.map(|previous| previous.to_string()),
+            current: current_version.map(|current| current.to_string()),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_semver_action, parse_tracker_data_version};
+    use insta::assert_debug_snapshot;
+    use retrack_types::trackers::SemverAction;
+    use semver::Version;
+    use serde_json::json;
+
+    #[test]
+    fn can_parse_and_normalize_version() -> anyhow::Result<()> {
+        assert_eq!(parse_tracker_data_version("1.2.3")?, Version::new(1, 2, 3));
+        assert_eq!(
+            parse_tracker_data_version(" v1.2.3 ")?,
+            Version::new(1, 2, 3)
+        );
+        assert_eq!(parse_tracker_data_version("V1")?, Version::new(1, 0, 0));
+        assert_eq!(parse_tracker_data_version("1.02")?, Version::new(1, 2, 0));
+        assert_eq!(
+            parse_tracker_data_version("v2.0-beta.1+build.5")?.to_string(),
+            "2.0.0-beta.1+build.5"
+        );
+        assert!(parse_tracker_data_version("2.0.0-rc.1")? < parse_tracker_data_version("2.0")?);
+        assert!(parse_tracker_data_version("1.10.0")? > parse_tracker_data_version("1.9.9")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_version() {
+        assert_debug_snapshot!(parse_tracker_data_version("latest").unwrap_err().to_string(), @r###""Value `latest` is not a valid semantic version.""###);
+        assert_debug_snapshot!(parse_tracker_data_version("1.2.3.4").unwrap_err().to_string(), @r###""Value `1.2.3.4` is not a valid semantic version.""###);
+        assert_debug_snapshot!(parse_tracker_data_version("").unwrap_err().to_string(), @r###""Value `` is not a valid semantic version.""###);
+        assert_debug_snapshot!(parse_tracker_data_version("1.2.3-").unwrap_err().to_string(), @r###""Value `1.2.3-` is not a valid semantic version.""###);
+    }
+
+    #[test]
+    fn properly_applies_semver_action() -> anyhow::Result<()> {
+        let action = SemverAction {
+            path: "$.tag".to_string(),
+            current_version: Some("1.9.5".to_string()),
+        };
+        assert_eq!(
+            apply_semver_action(
+                &action,
+                &json!({ "tag": "v1.10" }),
+                Some(&json!({ "tag": "v1.9" }))
+            )?,
+            json!({
+                "data": { "tag": "v1.10" },
+                "version": {
+                    "value": "1.10.0",
+                    "previous": "1.9.0",
+                    "current": "1.9.5",
+                    "isNewer": true,
+                    "isNewerThanPrevious": true
+                }
+            })
+        );
+        assert_eq!(
+            apply_semver_action(
+                &action,
+                &json!({ "tag": "1.9.0" }),
+                Some(&json!({ "tag": "1.9.0" }))
+            )?,
+            json!({
+                "data": { "tag": "1.9.0" },
+                "version": {
+                    "value": "1.9.0",
+                    "previous": "1.9.0",
+                    "current": "1.9.5",
+                    "isNewer": false,
+                    "isNewerThanPrevious": false
+                }
+            })
+        );
+
+        // Missing or invalid previous version and no current version.
+        let action = SemverAction {
+            path: "$".to_string(),
+            current_version: None,
+        };
+        assert_eq!(
+            apply_semver_action(&action, &json!("2.0.0"), None)?,
+            json!({ "data": "2.0.0", "version": { "value": "2.0.0" } })
+        );
+        assert_eq!(
+            apply_semver_action(&action, &json!("2.0.0"), Some(&json!("unknown")))?,
+            json!({ "data": "2.0.0", "version": { "value": "2.0.0" } })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_apply_semver_action_to_invalid_data() {
+        let action = SemverAction {
+            path: "$.tag".to_string(),
+            current_version: None,
+        };
+        assert_debug_snapshot!(apply_semver_action(&action, &json!({}), None).unwrap_err().to_string(), @r###""Tracker data doesn't have a value at `$.tag`.""###);
+        assert_debug_snapshot!(apply_semver_action(&action, &json!({ "tag": 1 }), None).unwrap_err().to_string(), @r###""Value `1` is not a valid semantic version.""###);
+        assert_debug_snapshot!(apply_semver_action(&action, &json!({ "tag": "next" }), None).unwrap_err().to_string(), @r###""Value `next` is not a valid semantic version.""###);
+    }
+}
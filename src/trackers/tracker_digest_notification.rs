@@ -0,0 +1,23 @@
+use crate::tasks::TaskType;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Notification of the tracker action that is pending delivery as a part of the digest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerDigestNotification {
+    /// Unique notification id (UUIDv7).
+    pub id: Uuid,
+    /// ID of the tracker that produced the notification.
+    pub tracker_id: Uuid,
+    /// Name of the tracker that produced the notification.
+    pub tracker_name: String,
+    /// Optional tag the digest is aggregated by. If not set, digest only includes notifications
+    /// of the same tracker.
+    pub tag: Option<String>,
+    /// Task that would have been scheduled for the notification if it wasn't a part of the digest.
+    pub task_type: TaskType,
+    /// Date and time when the digest with the notification should be sent.
+    pub send_at: OffsetDateTime,
+    /// Date and time when the notification was created.
+    pub created_at: OffsetDateTime,
+}
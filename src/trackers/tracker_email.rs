@@ -0,0 +1,300 @@
+use crate::tasks::{Email, EmailAttachment};
+use anyhow::Context;
+use handlebars::Handlebars;
+use retrack_types::{
+    templates::Template,
+    trackers::{EmailActionAttachmentFormat, Tracker},
+};
+use serde_json::{json, Value as JsonValue};
+
+/// Marker appended to the email content truncated in favor of the attached revision data.
+const TRUNCATED_CONTENT_MARKER: &str = "[...truncated, see the attached file]";
+
+/// Renders the custom email template with the tracker data changes described by the unified
+/// diff. Values are rendered as is into the subject and text body, and escaped in the HTML body.
+pub fn tracker_email(
+    tracker: &Tracker,
+    template: &Template,
+    value: &JsonValue,
+    diff: &str,
+) -> anyhow::Result<Email> {
+    let data = json!({
+        "tracker": { "id": tracker.id, "name": tracker.name },
+        "value": value,
+        "diff": diff,
+        "diffLines": diff.lines().map(diff_line).collect::<Vec<_>>()
+    });
+
+    let mut handlebars = Handlebars::new();
+    let html = template
+        .html
+        .as_deref()
+        .map(|html| handlebars.render_template(html, &data))
+        .transpose()
+        .context("Cannot render email template HTML.")?;
+
+    handlebars.register_escape_fn(handlebars::no_escape);
+    let subject = handlebars
+        .render_template(&template.subject, &data)
+        .context("Cannot render email template subject.")?;
+    let text = handlebars
+        .render_template(&template.text, &data)
+        .context("Cannot render email template text.")?;
+
+    Ok(match html {
+        Some(html) => Email::html(subject, text, html),
+        None => Email::text(subject, text),
+    })
+}
+
+/// Exports the complete tracker revision data as an email attachment in the specified format.
+pub fn tracker_email_attachment(
+    value: &JsonValue,
+    format: EmailActionAttachmentFormat,
+) -> anyhow::Result<EmailAttachment> {
+    Ok(match format {
+        EmailActionAttachmentFormat::Json => EmailAttachment::attachment(
+            "revision.json",
+            "application/json",
+            serde_json::to_vec_pretty(value)?,
+        ),
+        EmailActionAttachmentFormat::Csv => {
+            EmailAttachment::attachment("revision.csv", "text/csv", to_csv(value)?)
+        }
+    })
+}
+
+/// Truncates the email content to at most the specified number of bytes (not counting the
+/// truncation marker), if it's longer than that.
+pub fn truncate_email_content(content: &str, max_len: usize) -> String {
+    if content.len() <= max_len {
+        return content.to_string();
+    }
+
+    let mut len = max_len;
+    while !content.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    format!("{}\n{TRUNCATED_CONTENT_MARKER}", &content[..len])
+}
+
+/// Converts JSON value to CSV. Every item of the array (or the value itself, if it's not an array)
+/// is a row. If all rows are objects, columns are the object keys, otherwise there is a single
+/// `value` column. Strings are written as is, and other non-null values as JSON.
+fn to_csv(value: &JsonValue) -> anyhow::Result<Vec<u8>> {
+    let rows = match value {
+        JsonValue::Array(items) => items.iter().collect::<Vec<_>>(),
+        value => vec![value],
+    };
+
+    let columns = if !rows.is_empty() && rows.iter().all(|row| row.is_object()) {
+        let mut columns: Vec<&str> = vec![];
+        for key in rows
+            .iter()
+            .filter_map(|row| row.as_object())
+            .flat_map(|row| row.keys())
+        {
+            if !columns.contains(&key.as_str()) {
+                columns.push(key);
+            }
+        }
+        Some(columns)
+    } else {
+        None
+    };
+
+    let cell = |value: Option<&JsonValue>| match value {
+        None | Some(JsonValue::Null) => String::new(),
+        Some(JsonValue::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+    };
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    match columns {
+        Some(columns) => {
+            writer.write_record(&columns)?;
+            for row in rows {
+                writer.write_record(columns.iter().map(|column| cell(row.get(column))))?;
+            }
+        }
+        None => {
+            writer.write_record(["value"])?;
+            for row in rows {
+                writer.write_record([cell(Some(row))])?;
+            }
+        }
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+/// Describes the line of the unified diff, stripping the diff marker from the line content.
+fn diff_line(line: &str) -> JsonValue {
+    let (kind, line) =
+        if line.starts_with("---") || line.starts_with("+++") || line.starts_with("@@") {
+            ("header", line)
+        } else if let Some(line) = line.strip_prefix('+') {
+            ("added", line)
+        } else if let Some(line) = line.strip_prefix('-') {
+            ("removed", line)
+        } else {
+            ("unchanged", line.strip_prefix(' ').unwrap_or(line))
+        };
+
+    json!({ "kind": kind, "line": line })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tracker_email, tracker_email_attachment, truncate_email_content};
+    use crate::{
+        tasks::{Email, EmailAttachment},
+        tests::MockTrackerBuilder,
+    };
+    use retrack_types::{templates::Template, trackers::EmailActionAttachmentFormat};
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    const DIFF: &str =
+        "@@ -1,3 +1,3 @@\n {\n-  \"version\": \"1.0.0\"\n+  \"version\": \"2.0.0\"\n }\n";
+
+    fn mock_template(html: Option<&str>) -> anyhow::Result<Template> {
+        Ok(Template {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "changes".to_string(),
+            subject: "{{tracker.name}} changed".to_string(),
+            text: "Version: {{value.version}}\n{{diff}}".to_string(),
+            html: html.map(str::to_string),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    #[test]
+    fn renders_text_email() -> anyhow::Result<()> {
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "tracker <one>",
+            3,
+        )?
+        .build();
+        assert_eq!(
+            tracker_email(
+                &tracker,
+                &mock_template(None)?,
+                &json!({ "version": "2.0.0" }),
+                DIFF
+            )?,
+            Email::text("tracker <one> changed", format!("Version: 2.0.0\n{DIFF}"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_html_email_with_escaped_values() -> anyhow::Result<()> {
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "tracker <one>",
+            3,
+        )?
+        .build();
+        let template = mock_template(Some(
+            "<h1>{{tracker.name}}</h1>{{#each diffLines}}<p class=\"{{kind}}\">{{line}}</p>{{/each}}",
+        ))?;
+        assert_eq!(
+            tracker_email(&tracker, &template, &json!({ "version": "2.0.0" }), DIFF)?,
+            Email::html(
+                "tracker <one> changed",
+                format!("Version: 2.0.0\n{DIFF}"),
+                "<h1>tracker &lt;one&gt;</h1>\
+                 <p class=\"header\">@@ -1,3 +1,3 @@</p>\
+                 <p class=\"unchanged\">{</p>\
+                 <p class=\"removed\">  &quot;version&quot;: &quot;1.0.0&quot;</p>\
+                 <p class=\"added\">  &quot;version&quot;: &quot;2.0.0&quot;</p>\
+                 <p class=\"unchanged\">}</p>"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_render_invalid_template() -> anyhow::Result<()> {
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "tracker",
+            3,
+        )?
+        .build();
+        let template = Template {
+            subject: "{{#if tracker.name}}changed".to_string(),
+            ..mock_template(None)?
+        };
+        assert_eq!(
+            tracker_email(&tracker, &template, &json!({ "version": "2.0.0" }), DIFF)
+                .unwrap_err()
+                .to_string(),
+            "Cannot render email template subject."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn exports_json_attachment() -> anyhow::Result<()> {
+        assert_eq!(
+            tracker_email_attachment(
+                &json!({ "version": "2.0.0" }),
+                EmailActionAttachmentFormat::Json
+            )?,
+            EmailAttachment::attachment(
+                "revision.json",
+                "application/json",
+                "{\n  \"version\": \"2.0.0\"\n}"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn exports_csv_attachment() -> anyhow::Result<()> {
+        let csv = |value| -> anyhow::Result<String> {
+            let attachment = tracker_email_attachment(&value, EmailActionAttachmentFormat::Csv)?;
+            assert_eq!(attachment.content_type, "text/csv");
+            Ok(String::from_utf8(attachment.content)?)
+        };
+
+        assert_eq!(
+            csv(json!([
+                { "name": "one", "version": "1.0.0" },
+                { "name": "two, three", "tags": ["a"], "version": null }
+            ]))?,
+            "name,version,tags\none,1.0.0,\n\"two, three\",,\"[\"\"a\"\"]\"\n"
+        );
+        assert_eq!(csv(json!({ "version": "2.0.0" }))?, "version\n2.0.0\n");
+        assert_eq!(
+            csv(json!(["one", { "version": "2.0.0" }, 3]))?,
+            "value\none\n\"{\"\"version\"\":\"\"2.0.0\"\"}\"\n3\n"
+        );
+        assert_eq!(csv(json!("some-content"))?, "value\nsome-content\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncates_email_content() {
+        assert_eq!(truncate_email_content("some-content", 12), "some-content");
+        assert_eq!(
+            truncate_email_content("some-content", 4),
+            "some\n[...truncated, see the attached file]"
+        );
+        assert_eq!(
+            truncate_email_content("añb", 2),
+            "a\n[...truncated, see the attached file]"
+        );
+    }
+}
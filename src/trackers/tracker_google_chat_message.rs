@@ -0,0 +1,145 @@
+use serde_json::{json, Value as JsonValue};
+
+/// Google Chat limits the size of the message to 32,000 bytes, the diff is truncated to leave
+/// enough room for the rest of the card.
+const MAX_DIFF_SIZE: usize = 25000;
+
+/// Marker appended to the diff that was truncated to fit into the message.
+const TRUNCATED_MARKER: &str = "[...truncated]";
+
+/// Line break in the card text.
+const LINE_BREAK: &str = "<br>";
+
+/// Colors of the added and removed diff lines.
+const ADDED_LINE_COLOR: &str = "#188038";
+const REMOVED_LINE_COLOR: &str = "#d93025";
+
+/// Builds the Google Chat message (card) about the tracker data changes described by the unified
+/// diff. The diff is rendered as a text paragraph with the supported subset of HTML, and every
+/// line is colored according to whether the line was added or removed.
+pub fn tracker_google_chat_message(tracker_name: &str, diff: &str) -> JsonValue {
+    let diff = diff.trim_end();
+    let changes = if diff.is_empty() {
+        "<i>No changes in the extracted data, but the data source has changed.</i>".to_string()
+    } else {
+        diff_html(diff)
+    };
+
+    json!({
+        "fallbackText": format!("Tracker \"{tracker_name}\" detected changes."),
+        "cardsV2": [{
+            "cardId": "tracker-changes",
+            "card": {
+                "header": {
+                    "title": tracker_name,
+                    "subtitle": "Tracker detected changes in the extracted data"
+                },
+                "sections": [{ "widgets": [{ "textParagraph": { "text": changes } }] }]
+            }
+        }]
+    })
+}
+
+/// Converts the diff lines to HTML, until the lines no longer fit into the message.
+fn diff_html(diff: &str) -> String {
+    let mut lines = vec![];
+    let mut size = 0;
+    for line in diff.lines() {
+        let escaped_line = escape_html(line);
+        let line = if line.starts_with("@@") {
+            format!("<b>{escaped_line}</b>")
+        } else if line.starts_with('+') {
+            format!("<font color=\"{ADDED_LINE_COLOR}\">{escaped_line}</font>")
+        } else if line.starts_with('-') {
+            format!("<font color=\"{REMOVED_LINE_COLOR}\">{escaped_line}</font>")
+        } else {
+            escaped_line
+        };
+
+        size += line.len() + LINE_BREAK.len();
+        if size > MAX_DIFF_SIZE {
+            lines.push(TRUNCATED_MARKER.to_string());
+            break;
+        }
+
+        lines.push(line);
+    }
+
+    lines.join(LINE_BREAK)
+}
+
+/// Escapes the characters that have special meaning in the Google Chat card text.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tracker_google_chat_message, MAX_DIFF_SIZE, TRUNCATED_MARKER};
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn properly_builds_message() {
+        let diff = "@@ -1,3 +1,3 @@\n {\n-  \"price\": \"<120>\"\n+  \"price\": \"<99>\"\n }\n";
+        assert_json_snapshot!(tracker_google_chat_message("Prices & offers", diff), @r###"
+        {
+          "fallbackText": "Tracker \"Prices & offers\" detected changes.",
+          "cardsV2": [
+            {
+              "cardId": "tracker-changes",
+              "card": {
+                "header": {
+                  "title": "Prices & offers",
+                  "subtitle": "Tracker detected changes in the extracted data"
+                },
+                "sections": [
+                  {
+                    "widgets": [
+                      {
+                        "textParagraph": {
+                          "text": "<b>@@ -1,3 +1,3 @@</b><br> {<br><font color=\"#d93025\">-  \"price\": \"&lt;120&gt;\"</font><br><font color=\"#188038\">+  \"price\": \"&lt;99&gt;\"</font><br> }"
+                        }
+                      }
+                    ]
+                  }
+                ]
+              }
+            }
+          ]
+        }
+        "###);
+
+        let message = tracker_google_chat_message("Prices", "");
+        assert_json_snapshot!(message["cardsV2"][0]["card"]["sections"], @r###"
+        [
+          {
+            "widgets": [
+              {
+                "textParagraph": {
+                  "text": "<i>No changes in the extracted data, but the data source has changed.</i>"
+                }
+              }
+            ]
+          }
+        ]
+        "###);
+    }
+
+    #[test]
+    fn truncates_long_diff() {
+        let line = format!("+{}\n", "a".repeat(99));
+        let message = tracker_google_chat_message("Prices", &line.repeat(1000));
+        let text = message["cardsV2"][0]["card"]["sections"][0]["widgets"][0]["textParagraph"]
+            ["text"]
+            .as_str()
+            .unwrap();
+        assert!(text.len() <= MAX_DIFF_SIZE + TRUNCATED_MARKER.len());
+        assert!(text.starts_with(&format!(
+            "<font color=\"#188038\">+{}</font><br>",
+            "a".repeat(99)
+        )));
+        assert!(text.ends_with(&format!("</font><br>{TRUNCATED_MARKER}")));
+    }
+}
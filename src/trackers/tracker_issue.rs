@@ -0,0 +1,142 @@
+/// Marker appended to the issue body that was truncated to fit into the issue tracker limits.
+const TRUNCATED_MARKER: &str = "[...truncated]";
+
+/// GitHub and GitLab limit the issue title length to 255 characters.
+const MAX_ISSUE_TITLE_LENGTH: usize = 255;
+
+/// GitHub limits the issue body and comment length to 65536 characters.
+const MAX_ISSUE_BODY_LENGTH: usize = 65536;
+
+/// Issue (GitHub, GitLab) about the tracker data changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerIssue {
+    /// Single-line title of the issue, used to find the already open issue.
+    pub title: String,
+    /// Markdown body of the issue, or of the comment to the already open issue.
+    pub body: String,
+}
+
+impl TrackerIssue {
+    /// Builds the issue about the tracker data changes described by the unified diff. The title
+    /// and body rendered from the action templates, if any, replace the default ones. Both are
+    /// truncated to fit into the issue tracker limits.
+    pub fn new(
+        tracker_name: &str,
+        diff: &str,
+        title: Option<String>,
+        body: Option<String>,
+    ) -> Self {
+        let title = title
+            .map(|title| title.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| format!("Tracker \"{tracker_name}\" detected changes"));
+        let title = if title.chars().count() > MAX_ISSUE_TITLE_LENGTH {
+            format!(
+                "{}…",
+                title
+                    .chars()
+                    .take(MAX_ISSUE_TITLE_LENGTH - 1)
+                    .collect::<String>()
+            )
+        } else {
+            title
+        };
+
+        let body = match body {
+            Some(body) => truncate_body(&body, MAX_ISSUE_BODY_LENGTH),
+            None => {
+                let diff = diff.trim_end();
+                if diff.is_empty() {
+                    "No changes in the extracted data, but the data source has changed.".to_string()
+                } else {
+                    // Reserve space for the code block fences.
+                    format!(
+                        "```diff\n{}\n```",
+                        truncate_body(diff, MAX_ISSUE_BODY_LENGTH - 12)
+                    )
+                }
+            }
+        };
+
+        Self { title, body }
+    }
+}
+
+/// Truncates the text by whole lines to fit into the specified maximum length (in characters),
+/// unless the very first line is already too long.
+fn truncate_body(text: &str, max_length: usize) -> String {
+    if text.chars().count() <= max_length {
+        return text.to_string();
+    }
+
+    let truncated = text
+        .chars()
+        .take(max_length.saturating_sub(TRUNCATED_MARKER.len() + 1))
+        .collect::<String>();
+    let truncated = truncated
+        .rfind('\n')
+        .map_or(truncated.as_str(), |index| &truncated[..index]);
+    format!("{truncated}\n{TRUNCATED_MARKER}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerIssue;
+
+    #[test]
+    fn properly_builds_issue() {
+        let diff = "@@ -1,3 +1,3 @@\n {\n-  \"price\": \"€99\"\n+  \"price\": \"€89\"\n }\n";
+        assert_eq!(
+            TrackerIssue::new("Prices", diff, None, None),
+            TrackerIssue {
+                title: "Tracker \"Prices\" detected changes".to_string(),
+                body: format!("```diff\n{}\n```", diff.trim_end()),
+            }
+        );
+
+        assert_eq!(
+            TrackerIssue::new("Prices", "", None, None),
+            TrackerIssue {
+                title: "Tracker \"Prices\" detected changes".to_string(),
+                body: "No changes in the extracted data, but the data source has changed."
+                    .to_string(),
+            }
+        );
+
+        assert_eq!(
+            TrackerIssue::new(
+                "Prices",
+                diff,
+                Some("  Price\n changed: €89 ".to_string()),
+                Some("New price: €89".to_string())
+            ),
+            TrackerIssue {
+                title: "Price changed: €89".to_string(),
+                body: "New price: €89".to_string(),
+            }
+        );
+
+        // Blank titles are replaced with the default one.
+        assert_eq!(
+            TrackerIssue::new("Prices", diff, Some(" \n ".to_string()), None).title,
+            "Tracker \"Prices\" detected changes"
+        );
+    }
+
+    #[test]
+    fn truncates_long_title_and_body() {
+        let issue = TrackerIssue::new("Prices", "", Some("€".repeat(300)), None);
+        assert_eq!(issue.title, format!("{}…", "€".repeat(254)));
+
+        let line = format!("+{}\n", "a".repeat(99));
+        let issue = TrackerIssue::new("Prices", &line.repeat(1000), None, None);
+        assert_eq!(
+            issue.body,
+            format!("```diff\n{}[...truncated]\n```", line.repeat(648))
+        );
+        assert!(issue.body.chars().count() <= 65536);
+
+        let issue = TrackerIssue::new("Prices", "", None, Some(line.repeat(1000)));
+        assert_eq!(issue.body, format!("{}[...truncated]", line.repeat(648)));
+    }
+}
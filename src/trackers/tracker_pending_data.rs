@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Tracker data that differs from the last data revision, but hasn't yet been observed in enough
+/// consecutive checks to be persisted as a new revision.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrackerPendingData {
+    /// Original data value serialized to JSON, since arbitrary JSON values cannot be stored in a
+    /// non-self-describing format directly.
+    pub data: String,
+    /// Number of consecutive checks the data has been observed in.
+    pub confirmations: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerPendingData;
+
+    #[test]
+    fn can_serialize_and_deserialize() -> anyhow::Result<()> {
+        let pending_data = TrackerPendingData {
+            data: r#"{"price":10}"#.to_string(),
+            confirmations: 2,
+        };
+        assert_eq!(
+            postcard::from_bytes::<TrackerPendingData>(&postcard::to_stdvec(&pending_data)?)?,
+            pending_data
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+/// Marker appended to the diff that was truncated to fit into the notification message.
+const TRUNCATED_MARKER: &str = "[...truncated]";
+
+/// Push notification (ntfy, Pushover) about the tracker data changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerPushNotification {
+    /// Title of the notification.
+    pub title: String,
+    /// Plain text message of the notification with the diff of the tracker data.
+    pub message: String,
+}
+
+impl TrackerPushNotification {
+    /// Builds the push notification about the tracker data changes described by the unified diff.
+    /// The diff is truncated by whole lines to fit into the specified maximum message size (in
+    /// bytes), unless the very first line is already too long.
+    pub fn new(tracker_name: &str, diff: &str, max_message_size: usize) -> Self {
+        let diff = diff.trim_end();
+        let message = if diff.is_empty() {
+            "No changes in the extracted data, but the data source has changed.".to_string()
+        } else if diff.len() <= max_message_size {
+            diff.to_string()
+        } else {
+            let mut max_index = max_message_size.saturating_sub(TRUNCATED_MARKER.len() + 1);
+            while !diff.is_char_boundary(max_index) {
+                max_index -= 1;
+            }
+
+            let truncated = &diff[..max_index];
+            let truncated = truncated
+                .rfind('\n')
+                .map_or(truncated, |index| &truncated[..index]);
+            format!("{truncated}\n{TRUNCATED_MARKER}")
+        };
+
+        Self {
+            title: format!("Tracker \"{tracker_name}\" detected changes"),
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackerPushNotification;
+
+    #[test]
+    fn properly_builds_notification() {
+        let diff = "@@ -1,3 +1,3 @@\n {\n-  \"price\": \"€99\"\n+  \"price\": \"€89\"\n }\n";
+        assert_eq!(
+            TrackerPushNotification::new("Prices", diff, 1024),
+            TrackerPushNotification {
+                title: "Tracker \"Prices\" detected changes".to_string(),
+                message: diff.trim_end().to_string(),
+            }
+        );
+
+        assert_eq!(
+            TrackerPushNotification::new("Prices", "", 1024),
+            TrackerPushNotification {
+                title: "Tracker \"Prices\" detected changes".to_string(),
+                message: "No changes in the extracted data, but the data source has changed."
+                    .to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn truncates_long_diff() {
+        let line = format!("+{}\n", "a".repeat(99));
+        let notification = TrackerPushNotification::new("Prices", &line.repeat(20), 1024);
+        assert_eq!(
+            notification.message,
+            format!("{}[...truncated]", line.repeat(9))
+        );
+        assert!(notification.message.len() <= 1024);
+
+        // Multi-byte characters aren't split.
+        let notification = TrackerPushNotification::new("Prices", &"€".repeat(400), 1024);
+        assert_eq!(
+            notification.message,
+            format!("{}\n[...truncated]", "€".repeat(336))
+        );
+        assert!(notification.message.len() <= 1024);
+    }
+}
@@ -0,0 +1,171 @@
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+/// Slack limits the length of the header block text.
+const MAX_HEADER_LENGTH: usize = 150;
+
+/// Slack limits the length of the section block text, the diff is truncated to fit into it
+/// along with the code block markup.
+const MAX_DIFF_LENGTH: usize = 2900;
+
+/// Marker appended to the diff that was truncated to fit into the section block.
+const TRUNCATED_MARKER: &str = "[...truncated]";
+
+/// Builds the Slack message (Block Kit) about the tracker data changes described by the unified
+/// diff. The channel is only set for the messages posted with the bot token, since incoming
+/// webhooks are bound to a specific channel.
+pub fn tracker_slack_message(tracker_name: &str, diff: &str, channel: Option<&str>) -> JsonValue {
+    let diff = escape_slack_text(diff.trim_end());
+    let changes = if diff.is_empty() {
+        "_No changes in the extracted data, but the data source has changed._".to_string()
+    } else {
+        format!("```\n{}\n```", truncate_diff(&diff))
+    };
+
+    let mut message = JsonMap::new();
+    if let Some(channel) = channel {
+        message.insert("channel".to_string(), json!(channel));
+    }
+    message.insert(
+        "text".to_string(),
+        json!(format!(
+            "Tracker \"{}\" detected changes.",
+            escape_slack_text(tracker_name)
+        )),
+    );
+    message.insert(
+        "blocks".to_string(),
+        json!([
+            {
+                "type": "header",
+                "text": {
+                    "type": "plain_text",
+                    "text": tracker_name.chars().take(MAX_HEADER_LENGTH).collect::<String>()
+                }
+            },
+            {
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": "Tracker detected changes in the extracted data:" }
+            },
+            {
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": changes }
+            }
+        ]),
+    );
+
+    JsonValue::Object(message)
+}
+
+/// Escapes the characters that have special meaning in the Slack message text.
+fn escape_slack_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Truncates the diff to fit into the section block. Whole lines are preserved, unless the very
+/// first line is already too long.
+fn truncate_diff(diff: &str) -> String {
+    let Some((max_index, _)) = diff.char_indices().nth(MAX_DIFF_LENGTH) else {
+        return diff.to_string();
+    };
+
+    let truncated = &diff[..max_index];
+    let truncated = truncated
+        .rfind('\n')
+        .map_or(truncated, |index| &truncated[..index]);
+    format!("{truncated}\n{TRUNCATED_MARKER}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tracker_slack_message, MAX_DIFF_LENGTH, TRUNCATED_MARKER};
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn properly_builds_message() {
+        let diff = "@@ -1,3 +1,3 @@\n {\n-  \"price\": \"<120>\"\n+  \"price\": \"<99>\"\n }\n";
+        assert_json_snapshot!(tracker_slack_message("Prices & offers", diff, None), @r###"
+        {
+          "text": "Tracker \"Prices &amp; offers\" detected changes.",
+          "blocks": [
+            {
+              "type": "header",
+              "text": {
+                "type": "plain_text",
+                "text": "Prices & offers"
+              }
+            },
+            {
+              "type": "section",
+              "text": {
+                "type": "mrkdwn",
+                "text": "Tracker detected changes in the extracted data:"
+              }
+            },
+            {
+              "type": "section",
+              "text": {
+                "type": "mrkdwn",
+                "text": "```\n@@ -1,3 +1,3 @@\n {\n-  \"price\": \"&lt;120&gt;\"\n+  \"price\": \"&lt;99&gt;\"\n }\n```"
+              }
+            }
+          ]
+        }
+        "###);
+
+        assert_json_snapshot!(tracker_slack_message("Prices", "", Some("#releases")), @r###"
+        {
+          "channel": "#releases",
+          "text": "Tracker \"Prices\" detected changes.",
+          "blocks": [
+            {
+              "type": "header",
+              "text": {
+                "type": "plain_text",
+                "text": "Prices"
+              }
+            },
+            {
+              "type": "section",
+              "text": {
+                "type": "mrkdwn",
+                "text": "Tracker detected changes in the extracted data:"
+              }
+            },
+            {
+              "type": "section",
+              "text": {
+                "type": "mrkdwn",
+                "text": "_No changes in the extracted data, but the data source has changed._"
+              }
+            }
+          ]
+        }
+        "###);
+    }
+
+    #[test]
+    fn truncates_long_diff() {
+        let line = format!("+{}\n", "a".repeat(99));
+        let message = tracker_slack_message("Prices", &line.repeat(100), None);
+        let text = message["blocks"][2]["text"]["text"].as_str().unwrap();
+        assert_eq!(
+            text,
+            format!(
+                "```\n{}{TRUNCATED_MARKER}\n```",
+                line.repeat(MAX_DIFF_LENGTH / line.len())
+            )
+        );
+
+        let message = tracker_slack_message("Prices", &"a".repeat(MAX_DIFF_LENGTH + 1), None);
+        let text = message["blocks"][2]["text"]["text"].as_str().unwrap();
+        assert_eq!(
+            text,
+            format!(
+                "```\n{}\n{TRUNCATED_MARKER}\n```",
+                "a".repeat(MAX_DIFF_LENGTH)
+            )
+        );
+    }
+}
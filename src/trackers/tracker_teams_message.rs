@@ -0,0 +1,201 @@
+use serde_json::{json, Value as JsonValue};
+
+/// Teams limits the size of the message payload to roughly 28 KB, the diff is truncated to leave
+/// enough room for the rest of the Adaptive Card.
+const MAX_DIFF_SIZE: usize = 20000;
+
+/// Marker appended to the diff that was truncated to fit into the message.
+const TRUNCATED_MARKER: &str = "[...truncated]";
+
+/// Builds the Teams message (Adaptive Card) about the tracker data changes described by the
+/// unified diff. Every line of the diff is a separate monospace text run, colored according to
+/// whether the line was added or removed. Text runs aren't interpreted as Markdown, so neither
+/// the tracker name nor the diff need escaping.
+pub fn tracker_teams_message(tracker_name: &str, diff: &str) -> JsonValue {
+    let diff = diff.trim_end();
+    let changes = if diff.is_empty() {
+        json!({
+            "type": "TextBlock",
+            "text": "No changes in the extracted data, but the data source has changed.",
+            "isSubtle": true,
+            "wrap": true
+        })
+    } else {
+        json!({ "type": "RichTextBlock", "inlines": diff_text_runs(diff) })
+    };
+
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "contentUrl": null,
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "msteams": { "width": "Full" },
+                "body": [
+                    {
+                        "type": "RichTextBlock",
+                        "inlines": [{
+                            "type": "TextRun",
+                            "text": tracker_name,
+                            "size": "Large",
+                            "weight": "Bolder"
+                        }]
+                    },
+                    {
+                        "type": "TextBlock",
+                        "text": "Tracker detected changes in the extracted data:",
+                        "wrap": true
+                    },
+                    changes
+                ]
+            }
+        }]
+    })
+}
+
+/// Converts the diff lines to the text runs, until the runs no longer fit into the message.
+fn diff_text_runs(diff: &str) -> Vec<JsonValue> {
+    let mut runs = vec![];
+    let mut size = 0;
+    for line in diff.lines() {
+        let color = if line.starts_with("@@") {
+            Some("Accent")
+        } else if line.starts_with('+') {
+            Some("Good")
+        } else if line.starts_with('-') {
+            Some("Attention")
+        } else {
+            None
+        };
+
+        let run = text_run(&format!("{line}\n"), color);
+        size += run.to_string().len();
+        if size > MAX_DIFF_SIZE {
+            runs.push(text_run(TRUNCATED_MARKER, None));
+            break;
+        }
+
+        runs.push(run);
+    }
+
+    runs
+}
+
+fn text_run(text: &str, color: Option<&str>) -> JsonValue {
+    let mut run = json!({ "type": "TextRun", "text": text, "fontType": "Monospace" });
+    if let Some(color) = color {
+        run["color"] = json!(color);
+    }
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tracker_teams_message, MAX_DIFF_SIZE, TRUNCATED_MARKER};
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn properly_builds_message() {
+        let diff = "@@ -1,3 +1,3 @@\n {\n-  \"price\": \"<120>\"\n+  \"price\": \"**99**\"\n }\n";
+        assert_json_snapshot!(tracker_teams_message("Prices & offers", diff), @r###"
+        {
+          "type": "message",
+          "attachments": [
+            {
+              "contentType": "application/vnd.microsoft.card.adaptive",
+              "contentUrl": null,
+              "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "msteams": {
+                  "width": "Full"
+                },
+                "body": [
+                  {
+                    "type": "RichTextBlock",
+                    "inlines": [
+                      {
+                        "type": "TextRun",
+                        "text": "Prices & offers",
+                        "size": "Large",
+                        "weight": "Bolder"
+                      }
+                    ]
+                  },
+                  {
+                    "type": "TextBlock",
+                    "text": "Tracker detected changes in the extracted data:",
+                    "wrap": true
+                  },
+                  {
+                    "type": "RichTextBlock",
+                    "inlines": [
+                      {
+                        "type": "TextRun",
+                        "text": "@@ -1,3 +1,3 @@\n",
+                        "fontType": "Monospace",
+                        "color": "Accent"
+                      },
+                      {
+                        "type": "TextRun",
+                        "text": " {\n",
+                        "fontType": "Monospace"
+                      },
+                      {
+                        "type": "TextRun",
+                        "text": "-  \"price\": \"<120>\"\n",
+                        "fontType": "Monospace",
+                        "color": "Attention"
+                      },
+                      {
+                        "type": "TextRun",
+                        "text": "+  \"price\": \"**99**\"\n",
+                        "fontType": "Monospace",
+                        "color": "Good"
+                      },
+                      {
+                        "type": "TextRun",
+                        "text": " }\n",
+                        "fontType": "Monospace"
+                      }
+                    ]
+                  }
+                ]
+              }
+            }
+          ]
+        }
+        "###);
+
+        let message = tracker_teams_message("Prices", "");
+        assert_json_snapshot!(message["attachments"][0]["content"]["body"][2], @r###"
+        {
+          "type": "TextBlock",
+          "text": "No changes in the extracted data, but the data source has changed.",
+          "isSubtle": true,
+          "wrap": true
+        }
+        "###);
+    }
+
+    #[test]
+    fn truncates_long_diff() {
+        let line = format!("+{}\n", "a".repeat(99));
+        let message = tracker_teams_message("Prices", &line.repeat(1000));
+        let runs = message["attachments"][0]["content"]["body"][2]["inlines"]
+            .as_array()
+            .unwrap();
+        let size = runs[..runs.len() - 1]
+            .iter()
+            .map(|run| run.to_string().len())
+            .sum::<usize>();
+        assert!(size <= MAX_DIFF_SIZE);
+        assert!(runs.len() < 1000);
+        assert_eq!(runs[0]["text"], line);
+        assert_eq!(runs[runs.len() - 1]["text"], TRUNCATED_MARKER);
+    }
+}
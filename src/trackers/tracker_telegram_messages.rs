@@ -0,0 +1,176 @@
+/// Telegram limits the length of the message text (in UTF-16 code units).
+const MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Defines the maximum number of messages a single diff can be split into, the rest of the diff
+/// is truncated.
+const MAX_MESSAGES_COUNT: usize = 5;
+
+/// Marker appended to the diff that was truncated to fit into the maximum number of messages.
+const TRUNCATED_MARKER: &str = "[...truncated]\n";
+
+/// Markup of the pre-formatted code block the diff is wrapped into.
+const CODE_BLOCK_START: &str = "```diff\n";
+const CODE_BLOCK_END: &str = "```";
+
+/// Builds the Telegram messages (MarkdownV2) about the tracker data changes described by the
+/// unified diff. The diff that doesn't fit into a single message is split by lines into several
+/// messages, each with its own code block, and only the first message includes the header.
+pub fn tracker_telegram_messages(tracker_name: &str, diff: &str) -> Vec<String> {
+    let header = format!(
+        "*{}*\n",
+        escape_markdown(&format!("Tracker \"{tracker_name}\" detected changes:"))
+    );
+
+    let diff = diff.trim_end();
+    if diff.is_empty() {
+        return vec![format!(
+            "{header}_{}_",
+            escape_markdown("No changes in the extracted data, but the data source has changed.")
+        )];
+    }
+
+    // Reserve space for the code block markup and the truncation marker in every message.
+    let capacity = MAX_MESSAGE_LENGTH
+        - text_length(CODE_BLOCK_START)
+        - text_length(CODE_BLOCK_END)
+        - text_length(TRUNCATED_MARKER);
+    let first_message_capacity = capacity - text_length(&header);
+
+    let mut messages = vec![];
+    let mut code = String::new();
+    let mut code_length = 0;
+    for line in split_code_lines(diff, first_message_capacity) {
+        let line_length = text_length(&line);
+        let message_capacity = if messages.is_empty() {
+            first_message_capacity
+        } else {
+            capacity
+        };
+        if code_length + line_length > message_capacity {
+            if messages.len() + 1 == MAX_MESSAGES_COUNT {
+                code.push_str(TRUNCATED_MARKER);
+                break;
+            }
+
+            messages.push(code_block(&code));
+            code.clear();
+            code_length = 0;
+        }
+
+        code.push_str(&line);
+        code_length += line_length;
+    }
+    messages.push(code_block(&code));
+
+    messages[0].insert_str(0, &header);
+    messages
+}
+
+/// Escapes the characters that have special meaning in the MarkdownV2 text.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        if "_*[]()~`>#+-=|{}.!\\".contains(char) {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
+/// Splits the text into the lines escaped for the MarkdownV2 code block. Lines that are longer
+/// than the specified capacity are split into several lines.
+fn split_code_lines(text: &str, capacity: usize) -> Vec<String> {
+    let mut lines = vec![];
+    for line in text.lines() {
+        let mut escaped_line = String::new();
+        // Account for the trailing line break.
+        let mut escaped_line_length = 1;
+        for char in line.chars() {
+            let char_length = char.len_utf16() + usize::from(char == '`' || char == '\\');
+            if escaped_line_length + char_length > capacity {
+                escaped_line.push('\n');
+                lines.push(escaped_line);
+                escaped_line = String::new();
+                escaped_line_length = 1;
+            }
+
+            if char == '`' || char == '\\' {
+                escaped_line.push('\\');
+            }
+            escaped_line.push(char);
+            escaped_line_length += char_length;
+        }
+        escaped_line.push('\n');
+        lines.push(escaped_line);
+    }
+    lines
+}
+
+/// Wraps the already escaped code into the code block.
+fn code_block(code: &str) -> String {
+    format!("{CODE_BLOCK_START}{code}{CODE_BLOCK_END}")
+}
+
+/// Returns the length of the text as Telegram counts it.
+fn text_length(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tracker_telegram_messages, MAX_MESSAGES_COUNT, MAX_MESSAGE_LENGTH};
+    use insta::assert_debug_snapshot;
+
+    #[test]
+    fn properly_builds_messages() {
+        let diff = "@@ -1,3 +1,3 @@\n {\n-  \"price\": \"`99`\"\n+  \"price\": \"\\\\89\"\n }\n";
+        assert_debug_snapshot!(tracker_telegram_messages("Prices (v1.0)", diff), @r###"
+        [
+            "*Tracker \"Prices \\(v1\\.0\\)\" detected changes:*\n```diff\n@@ -1,3 +1,3 @@\n {\n-  \"price\": \"\\`99\\`\"\n+  \"price\": \"\\\\\\\\89\"\n }\n```",
+        ]
+        "###);
+
+        assert_debug_snapshot!(tracker_telegram_messages("Prices", ""), @r###"
+        [
+            "*Tracker \"Prices\" detected changes:*\n_No changes in the extracted data, but the data source has changed\\._",
+        ]
+        "###);
+    }
+
+    #[test]
+    fn splits_long_diff() {
+        let line = format!("+{}\n", "a".repeat(1999));
+        let messages = tracker_telegram_messages("Prices", &line.repeat(4));
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            messages[0],
+            format!(
+                "*Tracker \"Prices\" detected changes:*\n```diff\n{}```",
+                line.repeat(2)
+            )
+        );
+        assert_eq!(messages[1], format!("```diff\n{}```", line.repeat(2)));
+
+        // Lines longer than the message are split.
+        let messages = tracker_telegram_messages("Prices", &"a".repeat(MAX_MESSAGE_LENGTH));
+        assert_eq!(messages.len(), 2);
+        assert!(messages
+            .iter()
+            .all(|message| message.encode_utf16().count() <= MAX_MESSAGE_LENGTH));
+        let code = messages
+            .iter()
+            .filter_map(|message| message.split_once("```diff\n"))
+            .map(|(_, code)| code.trim_end_matches("```").trim_end())
+            .collect::<String>();
+        assert_eq!(code, "a".repeat(MAX_MESSAGE_LENGTH));
+
+        // Diff that doesn't fit into the maximum number of messages is truncated.
+        let messages = tracker_telegram_messages("Prices", &line.repeat(100));
+        assert_eq!(messages.len(), MAX_MESSAGES_COUNT);
+        assert!(messages
+            .iter()
+            .all(|message| message.encode_utf16().count() <= MAX_MESSAGE_LENGTH));
+        assert!(messages[MAX_MESSAGES_COUNT - 1].ends_with("[...truncated]\n```"));
+    }
+}
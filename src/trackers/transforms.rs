@@ -0,0 +1,44 @@
+mod jwe_transform;
+mod jws_transform;
+
+pub use self::{jwe_transform::JweTransform, jws_transform::JwsTransform};
+use anyhow::Context;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::Deserialize;
+
+/// Subset of the JOSE header parameters that are relevant for the response transforms.
+#[derive(Deserialize, Debug)]
+struct JoseHeader {
+    alg: String,
+    enc: Option<String>,
+    zip: Option<String>,
+    b64: Option<bool>,
+}
+
+/// Decodes base64url-encoded (without padding) value, e.g., compact serialization segment or key.
+fn decode_base64url(value: &[u8], name: &str) -> anyhow::Result<Vec<u8>> {
+    URL_SAFE_NO_PAD
+        .decode(value)
+        .with_context(|| format!("Cannot decode {name}, it must be base64url-encoded."))
+}
+
+/// Splits compact serialization into the exact number of segments and decodes the JOSE header.
+fn split_compact<const N: usize>(content: &[u8]) -> anyhow::Result<([&[u8]; N], JoseHeader)> {
+    let content = content.trim_ascii();
+    let segments = content.split(|byte| *byte == b'.').collect::<Vec<_>>();
+    let segments: [&[u8]; N] = segments.try_into().map_err(|segments: Vec<_>| {
+        anyhow::anyhow!(
+            "Compact serialization must have exactly {N} segments, but received {}.",
+            segments.len()
+        )
+    })?;
+
+    let header = serde_json::from_slice(&decode_base64url(segments[0], "JOSE header")?)
+        .context("Cannot deserialize JOSE header.")?;
+    Ok((segments, header))
+}
+
+#[cfg(test)]
+pub mod tests {
+    pub use super::{jwe_transform::tests::mock_jwe, jws_transform::tests::mock_jws};
+}
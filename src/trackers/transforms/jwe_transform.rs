@@ -0,0 +1,128 @@
+use crate::trackers::transforms::{decode_base64url, split_compact};
+use anyhow::{anyhow, bail};
+use bytes::Bytes;
+use ring::aead;
+
+/// Transform that decrypts JWE (compact serialization) and returns the plaintext. Only direct
+/// encryption with a shared symmetric key (`dir`) and AES GCM content encryption (`A128GCM` and
+/// `A256GCM`) are supported.
+pub struct JweTransform;
+impl JweTransform {
+    /// Decrypts JWE with the specified key and returns the plaintext.
+    pub fn apply(content: &[u8], key: &[u8]) -> anyhow::Result<Bytes> {
+        let ([header, encrypted_key, iv, ciphertext, tag], jose_header) =
+            split_compact::<5>(content)?;
+        if jose_header.alg != "dir" || !encrypted_key.is_empty() {
+            bail!(
+                "JWE key management algorithm `{}` isn't supported.",
+                jose_header.alg
+            );
+        }
+
+        if let Some(zip) = jose_header.zip {
+            bail!("JWE compression algorithm `{zip}` isn't supported.");
+        }
+
+        let algorithm = match jose_header.enc.as_deref() {
+            Some("A128GCM") => &aead::AES_128_GCM,
+            Some("A256GCM") => &aead::AES_256_GCM,
+            enc => bail!(
+                "JWE content encryption algorithm `{}` isn't supported.",
+                enc.unwrap_or_default()
+            ),
+        };
+
+        let key = aead::UnboundKey::new(algorithm, key).map_err(|_| {
+            anyhow!(
+                "JWE key must be {} bytes long for the `{}` encryption.",
+                algorithm.key_len(),
+                jose_header.enc.unwrap_or_default()
+            )
+        })?;
+        let nonce = aead::Nonce::try_assume_unique_for_key(&decode_base64url(
+            iv,
+            "JWE initialization vector",
+        )?)
+        .map_err(|_| anyhow!("JWE initialization vector is invalid."))?;
+
+        let mut in_out = decode_base64url(ciphertext, "JWE ciphertext")?;
+        in_out.extend(decode_base64url(tag, "JWE authentication tag")?);
+        let plaintext = aead::LessSafeKey::new(key)
+            .open_in_place(nonce, aead::Aad::from(header), &mut in_out)
+            .map_err(|_| anyhow!("JWE cannot be decrypted."))?;
+
+        Ok(Bytes::copy_from_slice(plaintext))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::JweTransform;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use insta::assert_debug_snapshot;
+    use ring::aead;
+
+    pub fn mock_jwe(enc: &str, key: &[u8], plaintext: &[u8]) -> String {
+        let algorithm = if enc == "A128GCM" {
+            &aead::AES_128_GCM
+        } else {
+            &aead::AES_256_GCM
+        };
+        let header = URL_SAFE_NO_PAD.encode(format!(r#"{{"alg":"dir","enc":"{enc}"}}"#));
+        let iv = [7u8; aead::NONCE_LEN];
+
+        let mut in_out = plaintext.to_vec();
+        let tag = aead::LessSafeKey::new(aead::UnboundKey::new(algorithm, key).unwrap())
+            .seal_in_place_separate_tag(
+                aead::Nonce::assume_unique_for_key(iv),
+                aead::Aad::from(header.as_bytes()),
+                &mut in_out,
+            )
+            .unwrap();
+
+        format!(
+            "{header}..{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(iv),
+            URL_SAFE_NO_PAD.encode(in_out),
+            URL_SAFE_NO_PAD.encode(tag.as_ref())
+        )
+    }
+
+    #[test]
+    fn decrypts_content() -> anyhow::Result<()> {
+        let jwe = mock_jwe("A128GCM", &[1; 16], br#"{"key":"value"}"#);
+        assert_eq!(
+            JweTransform::apply(jwe.as_bytes(), &[1; 16])?,
+            br#"{"key":"value"}"#.as_slice()
+        );
+
+        let jwe = mock_jwe("A256GCM", &[2; 32], br#"{"key":"value"}"#);
+        assert_eq!(
+            JweTransform::apply(jwe.as_bytes(), &[2; 32])?,
+            br#"{"key":"value"}"#.as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_invalid_jwe() -> anyhow::Result<()> {
+        let jwe = mock_jwe("A256GCM", &[2; 32], b"[1]");
+        assert_debug_snapshot!(
+            JweTransform::apply(jwe.as_bytes(), &[3; 32]).unwrap_err(),
+            @r###""JWE cannot be decrypted.""###
+        );
+        assert_debug_snapshot!(
+            JweTransform::apply(jwe.as_bytes(), &[2; 16]).unwrap_err(),
+            @r###""JWE key must be 32 bytes long for the `A256GCM` encryption.""###
+        );
+
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"RSA-OAEP","enc":"A256GCM"}"#);
+        assert_debug_snapshot!(
+            JweTransform::apply(format!("{header}.a.b.c.d").as_bytes(), &[2; 32]).unwrap_err(),
+            @r###""JWE key management algorithm `RSA-OAEP` isn't supported.""###
+        );
+
+        Ok(())
+    }
+}
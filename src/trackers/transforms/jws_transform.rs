@@ -0,0 +1,94 @@
+use crate::trackers::transforms::{decode_base64url, split_compact};
+use anyhow::bail;
+use bytes::Bytes;
+use ring::hmac;
+
+/// Transform that verifies JWS (compact serialization) and returns the signed payload. Only
+/// HMAC-based algorithms (`HS256`, `HS384`, and `HS512`) are supported.
+pub struct JwsTransform;
+impl JwsTransform {
+    /// Verifies JWS signature with the specified key and returns decoded payload.
+    pub fn apply(content: &[u8], key: &[u8]) -> anyhow::Result<Bytes> {
+        let ([header, payload, signature], jose_header) = split_compact::<3>(content)?;
+        if jose_header.b64 == Some(false) {
+            bail!("JWS with unencoded payload isn't supported.");
+        }
+
+        let algorithm = match jose_header.alg.as_str() {
+            "HS256" => hmac::HMAC_SHA256,
+            "HS384" => hmac::HMAC_SHA384,
+            "HS512" => hmac::HMAC_SHA512,
+            alg => bail!("JWS algorithm `{alg}` isn't supported."),
+        };
+
+        let signing_input = [header, b".", payload].concat();
+        let signature = decode_base64url(signature, "JWS signature")?;
+        if hmac::verify(&hmac::Key::new(algorithm, key), &signing_input, &signature).is_err() {
+            bail!("JWS signature is invalid.");
+        }
+
+        Ok(Bytes::from(decode_base64url(payload, "JWS payload")?))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::JwsTransform;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use insta::assert_debug_snapshot;
+    use ring::hmac;
+
+    pub fn mock_jws(alg: &str, key: &[u8], payload: &[u8]) -> String {
+        let algorithm = match alg {
+            "HS384" => hmac::HMAC_SHA384,
+            "HS512" => hmac::HMAC_SHA512,
+            _ => hmac::HMAC_SHA256,
+        };
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(format!(r#"{{"alg":"{alg}"}}"#)),
+            URL_SAFE_NO_PAD.encode(payload)
+        );
+        let signature = hmac::sign(&hmac::Key::new(algorithm, key), signing_input.as_bytes());
+        format!("{signing_input}.{}", URL_SAFE_NO_PAD.encode(signature))
+    }
+
+    #[test]
+    fn verifies_signature() -> anyhow::Result<()> {
+        for alg in ["HS256", "HS384", "HS512"] {
+            let jws = mock_jws(alg, b"secret", br#"{"key":"value"}"#);
+            assert_eq!(
+                JwsTransform::apply(jws.as_bytes(), b"secret")?,
+                br#"{"key":"value"}"#.as_slice()
+            );
+        }
+
+        // Surrounding whitespace is ignored.
+        let jws = format!("\n{}\n", mock_jws("HS256", b"secret", b"[1]"));
+        assert_eq!(
+            JwsTransform::apply(jws.as_bytes(), b"secret")?,
+            b"[1]".as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_invalid_jws() -> anyhow::Result<()> {
+        let jws = mock_jws("HS256", b"secret", b"[1]");
+        assert_debug_snapshot!(
+            JwsTransform::apply(jws.as_bytes(), b"other-secret").unwrap_err(),
+            @r###""JWS signature is invalid.""###
+        );
+        assert_debug_snapshot!(
+            JwsTransform::apply(mock_jws("ES256", b"secret", b"[1]").as_bytes(), b"secret").unwrap_err(),
+            @r###""JWS algorithm `ES256` isn't supported.""###
+        );
+        assert_debug_snapshot!(
+            JwsTransform::apply(b"header.payload", b"secret").unwrap_err(),
+            @r###""Compact serialization must have exactly 3 segments, but received 2.""###
+        );
+
+        Ok(())
+    }
+}
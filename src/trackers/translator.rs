@@ -0,0 +1,4 @@
+mod translator_request;
+mod translator_response;
+
+pub use self::{translator_request::TranslatorRequest, translator_response::TranslatorResponse};
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Represents request to translate texts (LibreTranslate-compatible).
+#[skip_serializing_none]
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct TranslatorRequest<'a> {
+    /// Texts to translate.
+    pub q: Vec<&'a str>,
+
+    /// Language of the texts, `auto` to let translation API detect it.
+    pub source: &'a str,
+
+    /// Language to translate texts to.
+    pub target: &'a str,
+
+    /// Format of the texts (`text` or `html`).
+    pub format: &'a str,
+
+    /// Optional API key to authenticate to the translation API.
+    pub api_key: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TranslatorRequest;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(TranslatorRequest {
+            q: vec!["Hallo Welt!", "Wie geht's?"],
+            source: "auto",
+            target: "en",
+            format: "text",
+            api_key: Some("some-key"),
+        }, @r###"
+        {
+          "q": [
+            "Hallo Welt!",
+            "Wie geht's?"
+          ],
+          "source": "auto",
+          "target": "en",
+          "format": "text",
+          "api_key": "some-key"
+        }
+        "###);
+
+        assert_json_snapshot!(TranslatorRequest {
+            q: vec!["Hallo Welt!"],
+            source: "auto",
+            target: "en",
+            format: "text",
+            api_key: None,
+        }, @r###"
+        {
+          "q": [
+            "Hallo Welt!"
+          ],
+          "source": "auto",
+          "target": "en",
+          "format": "text"
+        }
+        "###);
+
+        Ok(())
+    }
+}
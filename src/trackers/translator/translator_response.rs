@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a response of the translation API (LibreTranslate-compatible).
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslatorResponse {
+    /// Translated texts in the same order as in the request.
+    pub translated_text: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TranslatorResponse;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TranslatorResponse>(
+                r#"
+{
+    "translatedText": ["Hello world!", "How are you?"]
+}
+          "#
+            )?,
+            TranslatorResponse {
+                translated_text: vec!["Hello world!".to_string(), "How are you?".to_string()],
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+mod web_scraper_capabilities;
 mod web_scraper_content_request;
 mod web_scraper_error_response;
 
 pub use self::{
+    web_scraper_capabilities::{WebScraperCapabilities, WebScraperStatusResponse},
     web_scraper_content_request::WebScraperContentRequest,
     web_scraper_error_response::WebScraperErrorResponse,
 };
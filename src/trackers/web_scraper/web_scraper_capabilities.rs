@@ -0,0 +1,94 @@
+use retrack_types::trackers::PageTargetEngine;
+use serde::Deserialize;
+
+/// Represents features supported by the web scraper service, as reported by its status endpoint.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperCapabilities {
+    /// List of the browser engines the web scraper can load web pages with. Engines unknown to
+    /// Retrack are ignored.
+    #[serde(deserialize_with = "deserialize_engines")]
+    pub engines: Vec<PageTargetEngine>,
+}
+
+impl WebScraperCapabilities {
+    /// Checks whether the web scraper supports the specified browser engine.
+    pub fn supports_engine(&self, engine: PageTargetEngine) -> bool {
+        self.engines.contains(&engine)
+    }
+}
+
+impl Default for WebScraperCapabilities {
+    /// Web scraper versions that don't report capabilities only support Chromium.
+    fn default() -> Self {
+        Self {
+            engines: vec![PageTargetEngine::Chromium],
+        }
+    }
+}
+
+/// Represents the status returned by the web scraper service.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperStatusResponse {
+    /// Features supported by the web scraper.
+    #[serde(default)]
+    pub capabilities: WebScraperCapabilities,
+}
+
+fn deserialize_engines<'de, D>(deserializer: D) -> Result<Vec<PageTargetEngine>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Vec::<serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter_map(|engine| serde_json::from_value(engine).ok())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebScraperCapabilities, WebScraperStatusResponse};
+    use retrack_types::trackers::PageTargetEngine;
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<WebScraperStatusResponse>(json!({
+                "version": "1.0.0",
+                "capabilities": { "engines": ["chromium", "camoufox", "webkit"] }
+            }))?,
+            WebScraperStatusResponse {
+                capabilities: WebScraperCapabilities {
+                    engines: vec![PageTargetEngine::Chromium, PageTargetEngine::Camoufox],
+                },
+            }
+        );
+
+        // Older web scraper versions don't report capabilities.
+        assert_eq!(
+            serde_json::from_value::<WebScraperStatusResponse>(json!({ "version": "1.0.0" }))?,
+            WebScraperStatusResponse {
+                capabilities: WebScraperCapabilities {
+                    engines: vec![PageTargetEngine::Chromium],
+                },
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_checks_engine_support() {
+        let capabilities = WebScraperCapabilities::default();
+        assert!(capabilities.supports_engine(PageTargetEngine::Chromium));
+        assert!(!capabilities.supports_engine(PageTargetEngine::Camoufox));
+
+        let capabilities = WebScraperCapabilities {
+            engines: vec![PageTargetEngine::Chromium, PageTargetEngine::Camoufox],
+        };
+        assert!(capabilities.supports_engine(PageTargetEngine::Chromium));
+        assert!(capabilities.supports_engine(PageTargetEngine::Camoufox));
+    }
+}
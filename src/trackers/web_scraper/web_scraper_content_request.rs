@@ -1,8 +1,8 @@
-use retrack_types::trackers::TrackerDataValue;
+use retrack_types::trackers::{PageTargetEngine, TrackerDataValue};
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 use serde_with::{serde_as, skip_serializing_none, DurationMilliSeconds};
-use std::time::Duration;
+use std::{borrow::Cow, time::Duration};
 
 /// Represents request to scrap web page content.
 #[serde_as]
@@ -11,7 +11,7 @@ use std::time::Duration;
 #[serde(rename_all = "camelCase")]
 pub struct WebScraperContentRequest<'a> {
     /// A script (Playwright scenario) used to extract web page content that needs to be tracked.
-    pub extractor: &'a str,
+    pub extractor: Cow<'a, str>,
 
     /// Optional parameters to pass to the extractor scripts as part of the context.
     pub extractor_params: Option<&'a JsonValue>,
@@ -29,6 +29,15 @@ pub struct WebScraperContentRequest<'a> {
     )]
     pub ignore_https_errors: bool,
 
+    /// Indicates whether to extract the main article from the web page instead of using the value
+    /// returned by the extractor script.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub extract_article: bool,
+
+    /// Optional browser engine to load the web page with. If not set, the web scraper uses its
+    /// default engine.
+    pub engine: Option<PageTargetEngine>,
+
     /// Number of milliseconds to wait until extractor script finishes processing.
     #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
     pub timeout: Option<Duration>,
@@ -42,7 +51,10 @@ mod tests {
     use super::WebScraperContentRequest;
     use crate::tests::MockTrackerBuilder;
     use insta::assert_json_snapshot;
-    use retrack_types::trackers::{PageTarget, TrackerDataValue, TrackerTarget};
+    use retrack_types::{
+        test_utils::mock_web_scraper_content_request,
+        trackers::{PageTarget, PageTargetEngine, TrackerDataValue, TrackerTarget},
+    };
     use serde_json::json;
     use std::time::Duration;
     use uuid::uuid;
@@ -50,13 +62,15 @@ mod tests {
     #[test]
     fn serialization() -> anyhow::Result<()> {
         assert_json_snapshot!(WebScraperContentRequest {
-            extractor: "export async function execute(p) { await p.goto('http://localhost:1234/my/app?q=2'); return await p.content(); }",
+            extractor: "export async function execute(p) { await p.goto('http://localhost:1234/my/app?q=2'); return await p.content(); }".into(),
             extractor_params: Some(&json!({ "param": "value" })),
             tags: &vec!["tag1".to_string(), "tag2".to_string()],
             timeout: Some(Duration::from_millis(100)),
             previous_content: Some(&TrackerDataValue::new(json!("some content"))),
             user_agent: Some("Retrack/1.0.0"),
-            ignore_https_errors: true
+            ignore_https_errors: true,
+            extract_article: true,
+            engine: Some(PageTargetEngine::Camoufox)
         }, @r###"
         {
           "extractor": "export async function execute(p) { await p.goto('http://localhost:1234/my/app?q=2'); return await p.content(); }",
@@ -69,6 +83,8 @@ mod tests {
           ],
           "userAgent": "Retrack/1.0.0",
           "ignoreHTTPSErrors": true,
+          "extractArticle": true,
+          "engine": "camoufox",
           "timeout": 100,
           "previousContent": {
             "original": "some content"
@@ -86,6 +102,10 @@ mod tests {
             params: Some(json!({ "param": "value" })),
             user_agent: Some("Retrack/1.0.0".to_string()),
             ignore_https_errors: true,
+            extract_article: true,
+            engine: Some(PageTargetEngine::Camoufox),
+            steps: None,
+            selectors: None,
         };
         let tracker = MockTrackerBuilder::create(
             uuid!("00000000-0000-0000-0000-000000000001"),
@@ -104,6 +124,8 @@ mod tests {
         assert_eq!(request.extractor_params, target.params.as_ref());
         assert_eq!(request.user_agent, target.user_agent.as_deref());
         assert_eq!(request.ignore_https_errors, target.ignore_https_errors);
+        assert_eq!(request.extract_article, target.extract_article);
+        assert_eq!(request.engine, target.engine);
         assert_eq!(request.tags, &tracker.tags);
 
         // Config properties.
@@ -113,4 +135,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn matches_test_utils_request() -> anyhow::Result<()> {
+        let tracker = MockTrackerBuilder::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            3,
+        )?
+        .with_target(TrackerTarget::Page(PageTarget {
+            extractor: "export async function execute(p) { return await p.content(); }".to_string(),
+            params: Some(json!({ "param": "value" })),
+            user_agent: Some("Retrack/1.0.0".to_string()),
+            ignore_https_errors: true,
+            extract_article: true,
+            engine: Some(PageTargetEngine::Camoufox),
+            steps: None,
+            selectors: None,
+        }))
+        .with_tags(vec!["tag1".to_string(), "tag2".to_string()])
+        .build();
+        let previous_content = TrackerDataValue::new(json!("some content"));
+
+        // The request shape published for integrators should match the actual request.
+        assert_eq!(
+            serde_json::to_value(WebScraperContentRequest::try_from(&tracker)?)?,
+            mock_web_scraper_content_request(&tracker, None)?
+        );
+        assert_eq!(
+            serde_json::to_value(
+                WebScraperContentRequest::try_from(&tracker)?
+                    .set_previous_content(&previous_content)
+            )?,
+            mock_web_scraper_content_request(&tracker, Some(&previous_content))?
+        );
+
+        Ok(())
+    }
 }
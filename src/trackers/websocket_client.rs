@@ -0,0 +1,510 @@
+use anyhow::{anyhow, bail, Context};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use http::HeaderMap;
+use ring::{
+    digest::{digest, SHA1_FOR_LEGACY_USE_ONLY},
+    rand::{SecureRandom, SystemRandom},
+};
+use rustls::{crypto::ring as ring_provider, pki_types::ServerName, ClientConfig, RootCertStore};
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use tokio_rustls::TlsConnector;
+use tracing::warn;
+use url::Url;
+
+/// GUID that is concatenated with the handshake key to compute the accept key (RFC 6455, 1.3).
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Maximum size of the handshake response headers the client is willing to read.
+const MAX_HANDSHAKE_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// Maximum size of a single (possibly fragmented) message the client is willing to read, protects
+/// from the unbounded memory usage.
+const MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// Transport the WebSocket connection is established over: plain TCP or TLS stream.
+pub trait WebSocketTransport: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> WebSocketTransport for T {}
+
+/// Data message received from the WebSocket server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Lightweight WebSocket (RFC 6455) client that supports only the subset of the protocol needed to
+/// send text messages and receive data messages: extensions and subprotocols aren't negotiated.
+pub struct WebSocketClient<S> {
+    stream: BufReader<S>,
+    random: SystemRandom,
+}
+
+impl WebSocketClient<Box<dyn WebSocketTransport>> {
+    /// Connects to the WebSocket server (over TLS for `wss` URLs) and performs the opening
+    /// handshake.
+    pub async fn connect(url: &Url, headers: Option<&HeaderMap>) -> anyhow::Result<Self> {
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow!("WebSocket URL doesn't have a host ({url})."))?;
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| anyhow!("WebSocket URL doesn't have a port ({url})."))?;
+
+        let tcp_stream = TcpStream::connect((host, port))
+            .await
+            .with_context(|| format!("Cannot connect to WebSocket server ({host}:{port})."))?;
+        let stream: Box<dyn WebSocketTransport> = match url.scheme() {
+            "ws" => Box::new(tcp_stream),
+            "wss" => {
+                let mut root_store = RootCertStore::empty();
+                let native_certs = rustls_native_certs::load_native_certs();
+                for err in native_certs.errors {
+                    warn!("Failed to load native root certificate: {err}");
+                }
+                root_store.add_parsable_certificates(native_certs.certs);
+
+                let tls_config = ClientConfig::builder_with_provider(Arc::new(
+                    ring_provider::default_provider(),
+                ))
+                .with_safe_default_protocol_versions()?
+                .with_root_certificates(root_store)
+                .with_no_client_auth();
+                let server_name = ServerName::try_from(host.to_string())
+                    .with_context(|| format!("Invalid WebSocket server host name ({host})."))?;
+                Box::new(
+                    TlsConnector::from(Arc::new(tls_config))
+                        .connect(server_name, tcp_stream)
+                        .await
+                        .with_context(|| {
+                            format!("Cannot establish TLS session with WebSocket server ({host}).")
+                        })?,
+                )
+            }
+            scheme => bail!("Unsupported WebSocket URL scheme ({scheme})."),
+        };
+
+        Self::new(stream, url, headers).await
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> WebSocketClient<S> {
+    /// Creates WebSocket client for the already established connection and performs the opening
+    /// handshake.
+    pub async fn new(stream: S, url: &Url, headers: Option<&HeaderMap>) -> anyhow::Result<Self> {
+        let mut client = Self {
+            stream: BufReader::new(stream),
+            random: SystemRandom::new(),
+        };
+
+        let mut key = [0u8; 16];
+        client.fill_random(&mut key)?;
+        let key = BASE64_STANDARD.encode(key);
+
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow!("WebSocket URL doesn't have a host ({url})."))?;
+        let host = match url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+        let path = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        };
+
+        let mut request = format!(
+            "GET {path} HTTP/1.1\r\nHost: {host}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: 13\r\n"
+        )
+        .into_bytes();
+        for (name, value) in headers.into_iter().flatten() {
+            request.extend_from_slice(name.as_str().as_bytes());
+            request.extend_from_slice(b": ");
+            request.extend_from_slice(value.as_bytes());
+            request.extend_from_slice(b"\r\n");
+        }
+        request.extend_from_slice(b"\r\n");
+        client.stream.get_mut().write_all(&request).await?;
+        client.stream.get_mut().flush().await?;
+
+        let expected_accept = BASE64_STANDARD.encode(digest(
+            &SHA1_FOR_LEGACY_USE_ONLY,
+            format!("{key}{WEBSOCKET_GUID}").as_bytes(),
+        ));
+        client.read_handshake_response(&expected_accept).await?;
+
+        Ok(client)
+    }
+
+    /// Sends a text message to the server.
+    pub async fn send_text(&mut self, text: &str) -> anyhow::Result<()> {
+        self.write_frame(OPCODE_TEXT, text.as_bytes()).await
+    }
+
+    /// Waits for the next data message from the server. Control frames are handled transparently:
+    /// pings are answered with pongs, and `None` is returned once the server closes the connection.
+    pub async fn next_message(&mut self) -> anyhow::Result<Option<WebSocketMessage>> {
+        let mut message: Option<(u8, Vec<u8>)> = None;
+        loop {
+            let Some((fin, opcode, payload)) = self.read_frame().await? else {
+                return Ok(None);
+            };
+
+            match opcode {
+                OPCODE_PING => self.write_frame(OPCODE_PONG, &payload).await?,
+                OPCODE_PONG => {}
+                OPCODE_CLOSE => {
+                    // Echo the status code back to complete the closing handshake, the server may
+                    // drop the connection without waiting for it.
+                    let _ = self
+                        .write_frame(OPCODE_CLOSE, payload.get(..2).unwrap_or_default())
+                        .await;
+                    return Ok(None);
+                }
+                OPCODE_TEXT | OPCODE_BINARY if message.is_none() => {
+                    message = Some((opcode, payload));
+                }
+                OPCODE_CONTINUATION if message.is_some() => {
+                    if let Some((_, ref mut data)) = message {
+                        if data.len() + payload.len() > MAX_MESSAGE_SIZE {
+                            bail!("WebSocket message exceeds {MAX_MESSAGE_SIZE} bytes.");
+                        }
+                        data.extend(payload);
+                    }
+                }
+                opcode => bail!("Unexpected WebSocket frame (opcode {opcode:#x})."),
+            }
+
+            // Control frames can be interleaved with the fragments of the data message, and only
+            // the final data frame completes the message.
+            if fin && opcode & 0x8 == 0 {
+                if let Some((opcode, data)) = message.take() {
+                    return Ok(Some(if opcode == OPCODE_TEXT {
+                        WebSocketMessage::Text(
+                            String::from_utf8(data).context("Invalid WebSocket text message.")?,
+                        )
+                    } else {
+                        WebSocketMessage::Binary(data)
+                    }));
+                }
+            }
+        }
+    }
+
+    /// Sends the close frame to the server without waiting for the response.
+    pub async fn close(mut self) -> anyhow::Result<()> {
+        // Normal closure status code (1000).
+        self.write_frame(OPCODE_CLOSE, &1000u16.to_be_bytes())
+            .await?;
+        self.stream.get_mut().shutdown().await?;
+        Ok(())
+    }
+
+    /// Reads the handshake response and makes sure the server has accepted the upgrade.
+    async fn read_handshake_response(&mut self, expected_accept: &str) -> anyhow::Result<()> {
+        let mut response_size = 0;
+        let mut status_line = None;
+        let mut accept = None;
+        loop {
+            let mut line = String::new();
+            let line_size = (&mut self.stream)
+                .take((MAX_HANDSHAKE_RESPONSE_SIZE - response_size) as u64)
+                .read_line(&mut line)
+                .await?;
+            if line_size == 0 || !line.ends_with('\n') {
+                bail!("Unexpected end of WebSocket handshake response.");
+            }
+            response_size += line_size;
+
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                break;
+            }
+
+            if status_line.is_none() {
+                status_line = Some(line.to_string());
+            } else if let Some((name, value)) = line.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("sec-websocket-accept") {
+                    accept = Some(value.trim().to_string());
+                }
+            }
+        }
+
+        let status_line = status_line.unwrap_or_default();
+        if status_line.split_ascii_whitespace().nth(1) != Some("101") {
+            bail!("WebSocket server rejected the connection: {status_line}");
+        }
+
+        if accept.as_deref() != Some(expected_accept) {
+            bail!("WebSocket server returned invalid handshake accept key.");
+        }
+
+        Ok(())
+    }
+
+    /// Reads a single frame and returns its FIN flag, opcode, and unmasked payload, or `None` if
+    /// the connection has been closed.
+    async fn read_frame(&mut self) -> anyhow::Result<Option<(bool, u8, Vec<u8>)>> {
+        let mut header = [0u8; 2];
+        match self.stream.read_exact(&mut header).await {
+            Ok(_) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0F;
+        let masked = header[1] & 0x80 != 0;
+        let payload_size = match header[1] & 0x7F {
+            126 => self.stream.read_u16().await? as u64,
+            127 => self.stream.read_u64().await?,
+            size => size as u64,
+        };
+        if payload_size > MAX_MESSAGE_SIZE as u64 {
+            bail!("WebSocket frame exceeds {MAX_MESSAGE_SIZE} bytes.");
+        }
+
+        let mut mask = [0u8; 4];
+        if masked {
+            self.stream.read_exact(&mut mask).await?;
+        }
+
+        let mut payload = vec![0u8; payload_size as usize];
+        self.stream.read_exact(&mut payload).await?;
+        if masked {
+            apply_mask(&mut payload, mask);
+        }
+
+        Ok(Some((fin, opcode, payload)))
+    }
+
+    /// Writes a single unfragmented frame, client frames must always be masked.
+    async fn write_frame(&mut self, opcode: u8, payload: &[u8]) -> anyhow::Result<()> {
+        let mut frame = Vec::with_capacity(payload.len() + 14);
+        frame.push(0x80 | opcode);
+        match payload.len() {
+            size if size < 126 => frame.push(0x80 | size as u8),
+            size if size <= u16::MAX as usize => {
+                frame.push(0x80 | 126);
+                frame.extend_from_slice(&(size as u16).to_be_bytes());
+            }
+            size => {
+                frame.push(0x80 | 127);
+                frame.extend_from_slice(&(size as u64).to_be_bytes());
+            }
+        }
+
+        let mut mask = [0u8; 4];
+        self.fill_random(&mut mask)?;
+        frame.extend_from_slice(&mask);
+
+        let mut masked_payload = payload.to_vec();
+        apply_mask(&mut masked_payload, mask);
+        frame.extend(masked_payload);
+
+        self.stream.get_mut().write_all(&frame).await?;
+        self.stream.get_mut().flush().await?;
+        Ok(())
+    }
+
+    fn fill_random(&self, dest: &mut [u8]) -> anyhow::Result<()> {
+        self.random
+            .fill(dest)
+            .map_err(|_| anyhow!("Failed to generate random bytes for WebSocket client."))
+    }
+}
+
+/// Masks or unmasks the frame payload with the specified masking key (RFC 6455, 5.3).
+fn apply_mask(payload: &mut [u8], mask: [u8; 4]) {
+    for (index, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[index % 4];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_mask, WebSocketClient, WebSocketMessage};
+    use base64::{prelude::BASE64_STANDARD, Engine};
+    use http::{header::AUTHORIZATION, HeaderMap, HeaderValue};
+    use insta::assert_debug_snapshot;
+    use ring::digest::{digest, SHA1_FOR_LEGACY_USE_ONLY};
+    use tokio::io::{
+        duplex, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, DuplexStream,
+    };
+    use url::Url;
+
+    /// Reads the handshake request, and returns its lines.
+    async fn read_handshake_request(stream: &mut BufReader<DuplexStream>) -> Vec<String> {
+        let mut lines = vec![];
+        loop {
+            let mut line = String::new();
+            stream.read_line(&mut line).await.unwrap();
+            let line = line.trim_end().to_string();
+            if line.is_empty() {
+                return lines;
+            }
+            lines.push(line);
+        }
+    }
+
+    /// Reads a single masked client frame, and returns its opcode and unmasked payload.
+    async fn read_client_frame(stream: &mut BufReader<DuplexStream>) -> (u8, Vec<u8>) {
+        let mut header = [0u8; 2];
+        stream.read_exact(&mut header).await.unwrap();
+        assert_eq!(header[1] & 0x80, 0x80, "Client frames must be masked.");
+
+        let mut mask = [0u8; 4];
+        let mut payload = vec![0u8; (header[1] & 0x7F) as usize];
+        stream.read_exact(&mut mask).await.unwrap();
+        stream.read_exact(&mut payload).await.unwrap();
+        apply_mask(&mut payload, mask);
+
+        (header[0] & 0x0F, payload)
+    }
+
+    fn accept_key(request: &[String]) -> String {
+        let key = request
+            .iter()
+            .find_map(|line| line.strip_prefix("Sec-WebSocket-Key: "))
+            .unwrap();
+        BASE64_STANDARD.encode(digest(
+            &SHA1_FOR_LEGACY_USE_ONLY,
+            format!("{key}258EAFA5-E914-47DA-95CA-C5AB0DC85B11").as_bytes(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn can_send_and_receive_messages() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = tokio::spawn(async move {
+            let mut stream = BufReader::new(server_stream);
+            let request = read_handshake_request(&mut stream).await;
+            assert_eq!(request[0], "GET /ws?channel=prices HTTP/1.1");
+            assert_eq!(request[1], "Host: retrack.dev:8443");
+            assert!(request.contains(&"authorization: Bearer token".to_string()));
+
+            let response = format!(
+                "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                accept_key(&request)
+            );
+            stream
+                .get_mut()
+                .write_all(response.as_bytes())
+                .await
+                .unwrap();
+
+            // Subscription message.
+            assert_eq!(
+                read_client_frame(&mut stream).await,
+                (0x1, br#"{"subscribe":"prices"}"#.to_vec())
+            );
+
+            // Unfragmented text message, fragmented text message with interleaved ping, binary
+            // message, and close frame.
+            stream.get_mut().write_all(b"\x81\x03one").await.unwrap();
+            stream.get_mut().write_all(b"\x01\x02tw").await.unwrap();
+            stream.get_mut().write_all(b"\x89\x04ping").await.unwrap();
+            stream.get_mut().write_all(b"\x80\x01o").await.unwrap();
+            stream
+                .get_mut()
+                .write_all(b"\x82\x02\x01\x02")
+                .await
+                .unwrap();
+            stream
+                .get_mut()
+                .write_all(b"\x88\x02\x03\xE8")
+                .await
+                .unwrap();
+
+            assert_eq!(
+                read_client_frame(&mut stream).await,
+                (0xA, b"ping".to_vec())
+            );
+            assert_eq!(
+                read_client_frame(&mut stream).await,
+                (0x8, b"\x03\xE8".to_vec())
+            );
+        });
+
+        let headers =
+            HeaderMap::from_iter([(AUTHORIZATION, HeaderValue::from_static("Bearer token"))]);
+        let mut client = WebSocketClient::new(
+            client_stream,
+            &Url::parse("wss://retrack.dev:8443/ws?channel=prices")?,
+            Some(&headers),
+        )
+        .await?;
+        client.send_text(r#"{"subscribe":"prices"}"#).await?;
+
+        assert_eq!(
+            client.next_message().await?,
+            Some(WebSocketMessage::Text("one".to_string()))
+        );
+        assert_eq!(
+            client.next_message().await?,
+            Some(WebSocketMessage::Text("two".to_string()))
+        );
+        assert_eq!(
+            client.next_message().await?,
+            Some(WebSocketMessage::Binary(vec![1, 2]))
+        );
+        assert_eq!(client.next_message().await?, None);
+
+        server.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_if_server_rejects_handshake() -> anyhow::Result<()> {
+        let (client_stream, server_stream) = duplex(4096);
+        let server = tokio::spawn(async move {
+            let mut stream = BufReader::new(server_stream);
+            read_handshake_request(&mut stream).await;
+            stream
+                .get_mut()
+                .write_all(b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let url = Url::parse("ws://retrack.dev/ws")?;
+        assert_debug_snapshot!(
+            WebSocketClient::new(client_stream, &url, None).await.err().unwrap(),
+            @r###""WebSocket server rejected the connection: HTTP/1.1 403 Forbidden""###
+        );
+        server.await?;
+
+        // Invalid accept key.
+        let (client_stream, server_stream) = duplex(4096);
+        let server = tokio::spawn(async move {
+            let mut stream = BufReader::new(server_stream);
+            read_handshake_request(&mut stream).await;
+            stream
+                .get_mut()
+                .write_all(
+                    b"HTTP/1.1 101 Switching Protocols\r\nSec-WebSocket-Accept: invalid\r\n\r\n",
+                )
+                .await
+                .unwrap();
+        });
+
+        assert_debug_snapshot!(
+            WebSocketClient::new(client_stream, &url, None).await.err().unwrap(),
+            @r###""WebSocket server returned invalid handshake accept key.""###
+        );
+        server.await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{
+    api::Api,
+    database::Database,
+    error::Error as RetrackError,
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+};
+use anyhow::bail;
+use retrack_types::{
+    trackers::Tracker,
+    views::{View, ViewCreateParams, ViewUpdateParams},
+};
+use uuid::Uuid;
+
+/// Defines the maximum length of the view name.
+pub const MAX_VIEW_NAME_LENGTH: usize = 100;
+
+/// Describes the API to work with views.
+pub struct ViewsApi<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> ViewsApi<'a, DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Creates Views API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Returns all views.
+    pub async fn get_views(&self) -> anyhow::Result<Vec<View>> {
+        self.api.db.get_views().await
+    }
+
+    /// Returns view by its ID.
+    pub async fn get_view(&self, id: Uuid) -> anyhow::Result<Option<View>> {
+        self.api.db.get_view(id).await
+    }
+
+    /// Returns trackers that match the filters of the view, in the order defined by the view.
+    pub async fn get_view_trackers(
+        &self,
+        id: Uuid,
+        include_stats: bool,
+    ) -> anyhow::Result<Vec<Tracker>> {
+        let Some(view) = self.api.db.get_view(id).await? else {
+            bail!(RetrackError::client(format!("View ('{id}') is not found.")));
+        };
+
+        self.api
+            .trackers()
+            .get_trackers(view.trackers_list_params(include_stats))
+            .await
+    }
+
+    /// Creates a new view.
+    pub async fn create_view(&self, params: ViewCreateParams) -> anyhow::Result<View> {
+        let created_at = Database::utc_now()?;
+        let view = self.normalize_view(View {
+            id: Uuid::now_v7(),
+            name: params.name,
+            tags: params.tags,
+            query: params.query,
+            sort: params.sort,
+            include_archived: params.include_archived,
+            created_at,
+            updated_at: created_at,
+        })?;
+
+        self.api.db.insert_view(&view).await?;
+
+        Ok(view)
+    }
+
+    /// Updates existing view.
+    pub async fn update_view(&self, id: Uuid, params: ViewUpdateParams) -> anyhow::Result<View> {
+        if params == ViewUpdateParams::default() {
+            bail!(RetrackError::client(format!(
+                "At least one view property should be provided ({id})."
+            )));
+        }
+
+        let Some(existing_view) = self.api.db.get_view(id).await? else {
+            bail!(RetrackError::client(format!("View ('{id}') is not found.")));
+        };
+
+        let view = self.normalize_view(View {
+            name: params.name.unwrap_or(existing_view.name),
+            tags: params.tags.unwrap_or(existing_view.tags),
+            query: params.query.or(existing_view.query),
+            sort: params.sort.or(existing_view.sort),
+            include_archived: params
+                .include_archived
+                .unwrap_or(existing_view.include_archived),
+            updated_at: Database::utc_now()?,
+            ..existing_view
+        })?;
+
+        self.api.db.update_view(&view).await?;
+
+        Ok(view)
+    }
+
+    /// Removes existing view.
+    pub async fn remove_view(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api.db.remove_view(id).await
+    }
+
+    /// Validates view parameters and normalizes its filters the same way as the trackers list
+    /// parameters are normalized.
+    fn normalize_view(&self, view: View) -> anyhow::Result<View> {
+        if view.name.is_empty() {
+            bail!(RetrackError::client("View name cannot be empty."));
+        }
+
+        if view.name.len() > MAX_VIEW_NAME_LENGTH {
+            bail!(RetrackError::client(format!(
+                "View name cannot be longer than {MAX_VIEW_NAME_LENGTH} characters."
+            )));
+        }
+
+        let params = self
+            .api
+            .trackers()
+            .normalize_trackers_list_params(view.trackers_list_params(false))?;
+        Ok(View {
+            tags: params.tags,
+            query: params.query,
+            ..view
+        })
+    }
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET>
+where
+    ET::Error: EmailTransportError,
+{
+    /// Returns an API to work with views.
+    pub fn views(&self) -> ViewsApi<'_, DR, ET> {
+        ViewsApi::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error as RetrackError,
+        tests::{mock_api, TrackerCreateParamsBuilder},
+    };
+    use actix_web::{http::StatusCode, ResponseError};
+    use insta::assert_debug_snapshot;
+    use retrack_types::{
+        trackers::TrackersSort,
+        views::{ViewCreateParams, ViewUpdateParams},
+    };
+    use sqlx::PgPool;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn properly_creates_updates_and_removes_view(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let views = api.views();
+
+        let view = views
+            .create_view(ViewCreateParams {
+                name: "failing prod".to_string(),
+                tags: vec![" Env:Prod ".to_string(), "failing".to_string()],
+                query: Some(" api ".to_string()),
+                sort: Some(TrackersSort::UpdatedAtDesc),
+                include_archived: false,
+            })
+            .await?;
+        assert_eq!(
+            view.tags,
+            vec!["env:prod".to_string(), "failing".to_string()]
+        );
+        assert_eq!(view.query, Some("api".to_string()));
+        assert_eq!(views.get_views().await?, vec![view.clone()]);
+        assert_eq!(views.get_view(view.id).await?, Some(view.clone()));
+
+        let updated_view = views
+            .update_view(
+                view.id,
+                ViewUpdateParams {
+                    tags: Some(vec!["env:prod".to_string()]),
+                    query: Some("".to_string()),
+                    include_archived: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        assert_eq!(updated_view.name, "failing prod");
+        assert_eq!(updated_view.tags, vec!["env:prod".to_string()]);
+        assert_eq!(updated_view.query, None);
+        assert_eq!(updated_view.sort, Some(TrackersSort::UpdatedAtDesc));
+        assert!(updated_view.include_archived);
+        assert_eq!(views.get_view(view.id).await?, Some(updated_view.clone()));
+
+        views.remove_view(view.id).await?;
+        assert!(views.get_view(view.id).await?.is_none());
+        assert!(views.get_views().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_returns_view_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let trackers = api.trackers();
+
+        let tracker_one = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("Prod API")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+        trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("Staging API")
+                    .with_tags(vec!["env:staging".to_string()])
+                    .build(),
+            )
+            .await?;
+        let tracker_three = trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("Prod Admin API")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+        trackers
+            .create_tracker(
+                TrackerCreateParamsBuilder::new("Prod Page")
+                    .with_tags(vec!["env:prod".to_string()])
+                    .build(),
+            )
+            .await?;
+
+        let view = api
+            .views()
+            .create_view(ViewCreateParams {
+                name: "prod apis".to_string(),
+                tags: vec!["env:prod".to_string()],
+                query: Some("api".to_string()),
+                sort: Some(TrackersSort::Name),
+                include_archived: false,
+            })
+            .await?;
+        assert_eq!(
+            api.views().get_view_trackers(view.id, false).await?,
+            vec![tracker_three, tracker_one]
+        );
+
+        let error = api
+            .views()
+            .get_view_trackers(uuid!("00000000-0000-0000-0000-000000000001"), false)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+        assert_debug_snapshot!(error.root_cause.to_string(), @r###""View ('00000000-0000-0000-0000-000000000001') is not found.""###);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_view(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let views = &api.views();
+
+        let create_and_fail = move |params: ViewCreateParams| async move {
+            views
+                .create_view(params)
+                .await
+                .unwrap_err()
+                .downcast::<RetrackError>()
+                .unwrap()
+                .root_cause
+                .to_string()
+        };
+
+        let params = ViewCreateParams {
+            name: "prod".to_string(),
+            tags: vec!["env:prod".to_string()],
+            query: None,
+            sort: None,
+            include_archived: false,
+        };
+
+        assert_debug_snapshot!(create_and_fail(ViewCreateParams {
+            name: "".to_string(),
+            ..params.clone()
+        }).await, @r###""View name cannot be empty.""###);
+        assert_debug_snapshot!(create_and_fail(ViewCreateParams {
+            name: "a".repeat(101),
+            ..params.clone()
+        }).await, @r###""View name cannot be longer than 100 characters.""###);
+        assert_debug_snapshot!(create_and_fail(ViewCreateParams {
+            tags: (0..21).map(|i| format!("tag_{i}")).collect(),
+            ..params.clone()
+        }).await, @r###""Trackers filter params cannot use more than 20 tags.""###);
+        assert_debug_snapshot!(create_and_fail(ViewCreateParams {
+            tags: vec!["a".repeat(51)],
+            ..params.clone()
+        }).await, @r###""Tracker tags cannot be empty or longer than 50 characters.""###);
+        assert_debug_snapshot!(create_and_fail(ViewCreateParams {
+            query: Some("a".repeat(101)),
+            ..params.clone()
+        }).await, @r###""Trackers search query cannot be longer than 100 characters.""###);
+
+        views.create_view(params.clone()).await?;
+        assert_debug_snapshot!(create_and_fail(params).await, @r###""View with such name ('prod') already exists.""###);
+
+        let update_and_fail = |result: anyhow::Result<_>| -> String {
+            result
+                .unwrap_err()
+                .downcast::<RetrackError>()
+                .unwrap()
+                .root_cause
+                .to_string()
+        };
+        assert_debug_snapshot!(
+            update_and_fail(views.update_view(uuid!("00000000-0000-0000-0000-000000000001"), Default::default()).await),
+            @r###""At least one view property should be provided (00000000-0000-0000-0000-000000000001).""###
+        );
+        assert_debug_snapshot!(
+            update_and_fail(views.update_view(uuid!("00000000-0000-0000-0000-000000000001"), ViewUpdateParams {
+                name: Some("other".to_string()),
+                ..Default::default()
+            }).await),
+            @r###""View ('00000000-0000-0000-0000-000000000001') is not found.""###
+        );
+
+        Ok(())
+    }
+}
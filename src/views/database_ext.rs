@@ -0,0 +1,219 @@
+mod raw_view;
+
+use crate::{
+    database::Database, error::Error as RetrackError, views::database_ext::raw_view::RawView,
+};
+use anyhow::{anyhow, bail};
+use retrack_types::views::View;
+use sqlx::{query, query_as};
+use uuid::Uuid;
+
+/// Name of the database constraint that enforces view name uniqueness.
+const VIEWS_NAME_CONSTRAINT: &str = "views_name_key";
+
+/// Extends primary database with the views-related methods.
+impl Database {
+    /// Retrieves all views from the database, ordered by name.
+    pub async fn get_views(&self) -> anyhow::Result<Vec<View>> {
+        query_as!(
+            RawView,
+            r#"SELECT id, name, tags, filters, created_at, updated_at FROM views ORDER BY name"#
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(View::try_from)
+        .collect()
+    }
+
+    /// Retrieves view from the database using ID.
+    pub async fn get_view(&self, id: Uuid) -> anyhow::Result<Option<View>> {
+        query_as!(
+            RawView,
+            r#"SELECT id, name, tags, filters, created_at, updated_at FROM views WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(View::try_from)
+        .transpose()
+    }
+
+    /// Inserts a new view to the database.
+    pub async fn insert_view(&self, view: &View) -> anyhow::Result<()> {
+        let raw_view = RawView::try_from(view)?;
+        let result = query!(
+            r#"
+INSERT INTO views (id, name, tags, filters, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            raw_view.id,
+            raw_view.name,
+            &raw_view.tags,
+            raw_view.filters,
+            raw_view.created_at,
+            raw_view.updated_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        if let Err(err) = result {
+            bail!(match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    let message = if database_error.constraint() == Some(VIEWS_NAME_CONSTRAINT) {
+                        format!("View with such name ('{}') already exists.", view.name)
+                    } else {
+                        format!("View with such id ('{}') already exists.", view.id)
+                    };
+                    RetrackError::client_with_root_cause(anyhow!(err).context(message))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't create view ('{}') due to unknown reason.",
+                    view.name
+                ))),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Updates view in the database.
+    pub async fn update_view(&self, view: &View) -> anyhow::Result<()> {
+        let raw_view = RawView::try_from(view)?;
+        let result = query!(
+            r#"
+UPDATE views
+SET name = $2, tags = $3, filters = $4, updated_at = $5
+WHERE id = $1
+            "#,
+            raw_view.id,
+            raw_view.name,
+            &raw_view.tags,
+            raw_view.filters,
+            raw_view.updated_at
+        )
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => {
+                bail!(RetrackError::client(format!(
+                    "View ('{}') doesn't exist.",
+                    view.id
+                )));
+            }
+            Ok(_) => Ok(()),
+            Err(err) => bail!(match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    RetrackError::client_with_root_cause(anyhow!(err).context(format!(
+                        "View with such name ('{}') already exists.",
+                        view.name
+                    )))
+                }
+                _ => RetrackError::from(anyhow!(err).context(format!(
+                    "Couldn't update view ('{}') due to unknown reason.",
+                    view.name
+                ))),
+            }),
+        }
+    }
+
+    /// Removes view from the database.
+    pub async fn remove_view(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(r#"DELETE FROM views WHERE id = $1"#, id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{database::Database, error::Error as RetrackError};
+    use insta::assert_debug_snapshot;
+    use retrack_types::{trackers::TrackersSort, views::View};
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    fn mock_view(id: u128, name: &str) -> anyhow::Result<View> {
+        Ok(View {
+            id: Uuid::from_u128(id),
+            name: name.to_string(),
+            tags: vec!["env:prod".to_string()],
+            query: Some("api".to_string()),
+            sort: Some(TrackersSort::Name),
+            include_archived: false,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        })
+    }
+
+    #[sqlx::test]
+    async fn can_add_update_and_remove_view(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let view_one = mock_view(1, "prod")?;
+        let view_two = mock_view(2, "failing")?;
+        assert!(db.get_view(view_one.id).await?.is_none());
+        assert!(db.get_views().await?.is_empty());
+
+        db.insert_view(&view_one).await?;
+        db.insert_view(&view_two).await?;
+        assert_eq!(db.get_view(view_one.id).await?, Some(view_one.clone()));
+        assert_eq!(
+            db.get_views().await?,
+            vec![view_two.clone(), view_one.clone()]
+        );
+
+        let view_one = View {
+            name: "prod api".to_string(),
+            tags: vec![],
+            query: None,
+            sort: Some(TrackersSort::UpdatedAtDesc),
+            include_archived: true,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            ..view_one
+        };
+        db.update_view(&view_one).await?;
+        assert_eq!(db.get_view(view_one.id).await?, Some(view_one.clone()));
+
+        db.remove_view(view_one.id).await?;
+        assert!(db.get_view(view_one.id).await?.is_none());
+        assert_eq!(db.get_views().await?, vec![view_two]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_add_or_update_view_with_duplicate_name(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        db.insert_view(&mock_view(1, "prod")?).await?;
+        db.insert_view(&mock_view(2, "failing")?).await?;
+
+        let insert_error = db
+            .insert_view(&mock_view(3, "prod")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(insert_error.root_cause.to_string(), @r###""View with such name ('prod') already exists.""###);
+
+        let update_error = db
+            .update_view(&mock_view(2, "prod")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(update_error.root_cause.to_string(), @r###""View with such name ('prod') already exists.""###);
+
+        let update_error = db
+            .update_view(&mock_view(4, "other")?)
+            .await
+            .unwrap_err()
+            .downcast::<RetrackError>()?;
+        assert_debug_snapshot!(update_error.root_cause.to_string(), @r###""View ('00000000-0000-0000-0000-000000000004') doesn't exist.""###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use retrack_types::{trackers::TrackersSort, views::View};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawView {
+    pub id: Uuid,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub filters: Vec<u8>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+struct RawViewFilters<'s> {
+    query: Option<Cow<'s, str>>,
+    sort: Option<RawTrackersSort>,
+    include_archived: bool,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+enum RawTrackersSort {
+    Name,
+    NameDesc,
+    CreatedAt,
+    CreatedAtDesc,
+    UpdatedAt,
+    UpdatedAtDesc,
+}
+
+impl TryFrom<RawView> for View {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawView) -> Result<Self, Self::Error> {
+        let raw_filters = postcard::from_bytes::<RawViewFilters>(&raw.filters)?;
+        Ok(View {
+            id: raw.id,
+            name: raw.name,
+            tags: raw.tags,
+            query: raw_filters.query.map(Cow::into_owned),
+            sort: raw_filters.sort.map(|sort| match sort {
+                RawTrackersSort::Name => TrackersSort::Name,
+                RawTrackersSort::NameDesc => TrackersSort::NameDesc,
+                RawTrackersSort::CreatedAt => TrackersSort::CreatedAt,
+                RawTrackersSort::CreatedAtDesc => TrackersSort::CreatedAtDesc,
+                RawTrackersSort::UpdatedAt => TrackersSort::UpdatedAt,
+                RawTrackersSort::UpdatedAtDesc => TrackersSort::UpdatedAtDesc,
+            }),
+            include_archived: raw_filters.include_archived,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        })
+    }
+}
+
+impl TryFrom<&View> for RawView {
+    type Error = anyhow::Error;
+
+    fn try_from(item: &View) -> Result<Self, Self::Error> {
+        let raw_filters = RawViewFilters {
+            query: item.query.as_deref().map(Cow::Borrowed),
+            sort: item.sort.map(|sort| match sort {
+                TrackersSort::Name => RawTrackersSort::Name,
+                TrackersSort::NameDesc => RawTrackersSort::NameDesc,
+                TrackersSort::CreatedAt => RawTrackersSort::CreatedAt,
+                TrackersSort::CreatedAtDesc => RawTrackersSort::CreatedAtDesc,
+                TrackersSort::UpdatedAt => RawTrackersSort::UpdatedAt,
+                TrackersSort::UpdatedAtDesc => RawTrackersSort::UpdatedAtDesc,
+            }),
+            include_archived: item.include_archived,
+        };
+
+        Ok(RawView {
+            id: item.id,
+            name: item.name.clone(),
+            tags: item.tags.clone(),
+            filters: postcard::to_stdvec(&raw_filters)?,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawView;
+    use retrack_types::{trackers::TrackersSort, views::View};
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn can_convert_into_and_from_raw_view() -> anyhow::Result<()> {
+        let view = View {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "failing prod".to_string(),
+            tags: vec!["env:prod".to_string()],
+            query: None,
+            sort: None,
+            include_archived: false,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+        assert_eq!(
+            RawView::try_from(&view)?,
+            RawView {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "failing prod".to_string(),
+                tags: vec!["env:prod".to_string()],
+                filters: vec![0, 0, 0],
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            }
+        );
+        assert_eq!(View::try_from(RawView::try_from(&view)?)?, view);
+
+        let view = View {
+            query: Some("api".to_string()),
+            sort: Some(TrackersSort::UpdatedAtDesc),
+            include_archived: true,
+            ..view
+        };
+        assert_eq!(
+            RawView::try_from(&view)?.filters,
+            vec![1, 3, 97, 112, 105, 1, 5, 1]
+        );
+        assert_eq!(View::try_from(RawView::try_from(&view)?)?, view);
+
+        Ok(())
+    }
+}